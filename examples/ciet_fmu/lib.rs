@@ -0,0 +1,15 @@
+//! builds the CIET plant as a shared library with the FMI 2.0
+//! co-simulation entry points and the plain C API
+//!
+//! ```bash
+//! cargo build --release --example ciet_fmu
+//! ```
+//!
+//! the library is at target/release/examples/libciet_fmu.so (or
+//! ciet_fmu.dll on windows), and can be packaged into an FMU with
+//! tuas_boussinesq_solver::fmi_export::try_write_fmu using ciet_fmu
+//! as the model identifier
+use tuas_boussinesq_solver::pre_built_components::
+    ciet_three_branch_plus_dracs::ciet_plant::CIETPlant;
+
+tuas_boussinesq_solver::export_fmi2_co_simulation!(CIETPlant);
@@ -0,0 +1,16 @@
+//! builds the python extension module tuas_python
+//!
+//! ```bash
+//! cargo build --release --features python --example tuas_python
+//! cp target/release/examples/libtuas_python.so tuas_python.so
+//! python3 -c "import tuas_python; print(tuas_python.LiquidMaterial('HITEC').try_get_rho(500.0))"
+//! ```
+//!
+//! on windows, copy tuas_python.dll to tuas_python.pyd instead,
+//! and on macos copy libtuas_python.dylib to tuas_python.so
+use pyo3::prelude::*;
+
+#[pymodule]
+fn tuas_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    tuas_boussinesq_solver::python_bindings::register_python_module(m)
+}
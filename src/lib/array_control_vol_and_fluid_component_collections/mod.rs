@@ -24,6 +24,19 @@ pub mod conductance_array_functions;
 /// this is relevant for one dimension cartesian (x,y,z) coordinates
 /// you can't really couple these arrays laterally though
 pub mod one_dimension_cartesian_conducting_medium;
+/// contains a full struct which abstracts away calculation details 
+///
+/// this is relevant for one dimension radial (cylindrical or 
+/// spherical) coordinates, eg. fuel pins and pebbles with 
+/// multiple material regions, the outer surface can be 
+/// linked laterally to fluid arrays
+pub mod one_dimension_radial_conducting_medium;
+/// contains a full struct which abstracts away calculation details 
+///
+/// this is relevant for two dimension axisymmetric (r,z) coordinates,
+/// eg. thick pipe walls and insulation with several radial nodes per 
+/// layer, the inner surface can be linked laterally to fluid arrays
+pub mod two_dimension_rz_conducting_medium;
 
 
 /// contains a full struct which abstracts away calculation details 
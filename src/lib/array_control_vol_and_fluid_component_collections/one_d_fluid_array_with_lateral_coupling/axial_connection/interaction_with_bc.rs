@@ -5,6 +5,7 @@ use crate::single_control_vol::boundary_condition_interactions::constant_heat_ad
 use crate::single_control_vol::SingleCVNode;
 
 use crate::tuas_lib_error::TuasLibError;
+use crate::energy_audit::energy_tally::EnergyTallySource;
 
 use std::f64::consts::PI;
 
@@ -72,6 +73,22 @@ impl FluidArray {
                 ));
             },
 
+            HeatTransferInteractionType::DualCylindricalThermalConductanceWithInterfaceResistance(_) => 
+            {
+                return Err(TuasLibError::NotImplementedForBoundaryConditions(
+                        "please specify interaction type as \n 
+                    UserSpecifiedHeatFluxCustomArea or Similar".to_string()
+                ));
+            },
+
+            HeatTransferInteractionType::CylindricalConductionConvectionWithFouling(_) => 
+            {
+                return Err(TuasLibError::NotImplementedForBoundaryConditions(
+                        "please specify interaction type as \n 
+                    UserSpecifiedHeatFluxCustomArea or Similar".to_string()
+                ));
+            },
+
             HeatTransferInteractionType::UserSpecifiedHeatAddition => 
             {
                 return Err(TuasLibError::NotImplementedForBoundaryConditions(
@@ -147,8 +164,9 @@ impl FluidArray {
 
         // push the power power to the back cv 
 
-        cv_ref.rate_enthalpy_change_vector.push( 
-            heat_flowrate_into_control_vol);
+        cv_ref.push_rate_enthalpy_change( 
+            heat_flowrate_into_control_vol,
+            EnergyTallySource::HeatSource);
         // calculate conduction timescales
         cv_ref.calculate_mesh_stability_conduction_timestep_for_single_node_and_bc(
             interaction)?;
@@ -220,6 +238,22 @@ impl FluidArray {
                 ));
             },
 
+            HeatTransferInteractionType::DualCylindricalThermalConductanceWithInterfaceResistance(_) => 
+            {
+                return Err(TuasLibError::NotImplementedForBoundaryConditions(
+                        "please specify interaction type as \n 
+                    UserSpecifiedHeatFluxCustomArea or Similar".to_string()
+                ));
+            },
+
+            HeatTransferInteractionType::CylindricalConductionConvectionWithFouling(_) => 
+            {
+                return Err(TuasLibError::NotImplementedForBoundaryConditions(
+                        "please specify interaction type as \n 
+                    UserSpecifiedHeatFluxCustomArea or Similar".to_string()
+                ));
+            },
+
             HeatTransferInteractionType::UserSpecifiedHeatAddition => 
             {
                 return Err(TuasLibError::NotImplementedForBoundaryConditions(
@@ -298,8 +332,9 @@ impl FluidArray {
 
         // push the power power to the back cv 
 
-        cv_ref.rate_enthalpy_change_vector.push( 
-            heat_flowrate_into_control_vol);
+        cv_ref.push_rate_enthalpy_change( 
+            heat_flowrate_into_control_vol,
+            EnergyTallySource::HeatSource);
         // calculate conduction timescales
         cv_ref.calculate_mesh_stability_conduction_timestep_for_single_node_and_bc(
             interaction)?;
@@ -3,13 +3,17 @@ use ndarray_linalg::error::LinalgError;
 use uom::num_traits::Zero;
 use uom::si::f64::*;
 use uom::si::power::watt;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::temperature_interval::kelvin as interval_kelvin;
 
 use crate::array_control_vol_and_fluid_component_collections::standalone_fluid_nodes::solve_conductance_matrix_power_vector;
 use crate::boussinesq_thermophysical_properties::prandtl::try_get_prandtl;
-use crate::boussinesq_thermophysical_properties::specific_enthalpy::try_get_h;
+use crate::boussinesq_thermophysical_properties::specific_heat_capacity::try_get_cp;
 use crate::boussinesq_thermophysical_properties::thermal_conductivity::try_get_kappa_thermal_conductivity;
 use crate::boussinesq_thermophysical_properties::volumetric_heat_capacity::try_get_rho_cp;
 use crate::tuas_lib_error::TuasLibError;
+use crate::energy_audit::energy_tally::{EnergyTally, get_array_energy_tally_rates};
 
 use super::FluidArray;
 
@@ -123,6 +127,7 @@ impl FluidArray{
         let material = self.material_control_volume;
         let pressure = self.pressure_control_volume;
         let bulk_temperature = self.try_get_bulk_temperature()?;
+        let bulk_temperature = self.get_property_evaluation_temperature(bulk_temperature);
         let total_volume = self.total_length *  self.xs_area;
         let dt = timestep;
         let node_length = self.total_length / number_of_nodes as f64;
@@ -140,7 +145,9 @@ impl FluidArray{
         let rho_cp: Array1<VolumetricHeatCapacity> = 
         self.temperature_array_current_timestep.iter().map(
             |&temperature| {
-                try_get_rho_cp(material, temperature, pressure).unwrap()
+                try_get_rho_cp(material, 
+                    self.get_property_evaluation_temperature(temperature), 
+                    pressure).unwrap()
             }
         ).collect();
         // energy balance is: 
@@ -450,10 +457,7 @@ impl FluidArray{
                 // first, get enthalpy of the node in front 
 
                 let enthalpy_of_adjacent_node_to_the_front: AvailableEnergy = 
-                try_get_h(
-                    self.back_single_cv.material_control_volume,
-                    self.temperature_array_current_timestep[1],
-                    self.back_single_cv.pressure_control_volume).unwrap();
+                self.try_get_node_specific_enthalpy(1)?;
 
                 // now if mass flowrate is less than zero, then 
                 // we receive enthalpy from the front cv 
@@ -493,10 +497,7 @@ impl FluidArray{
                 // assume back cv and front cv material are the same
 
                 let h_fluid_last_timestep: AvailableEnergy = 
-                try_get_h(
-                    self.back_single_cv.material_control_volume,
-                    self.temperature_array_current_timestep[i],
-                    self.back_single_cv.pressure_control_volume).unwrap();
+                self.try_get_node_specific_enthalpy(i)?;
 
                 // basically, all the power terms remain 
                 power_source_vector[i] = 
@@ -513,10 +514,7 @@ impl FluidArray{
                     // enthalpy must be based on the the cv at i-1
 
                     let h_fluid_adjacent_node: AvailableEnergy = 
-                    try_get_h(
-                        self.back_single_cv.material_control_volume,
-                        self.temperature_array_current_timestep[i-1],
-                        self.back_single_cv.pressure_control_volume).unwrap();
+                    self.try_get_node_specific_enthalpy(i-1)?;
 
 
                     power_source_vector[i] += 
@@ -526,10 +524,7 @@ impl FluidArray{
 
                     // enthalpy must be based on cv at i+1
                     let h_fluid_adjacent_node: AvailableEnergy = 
-                    try_get_h(
-                        self.back_single_cv.material_control_volume,
-                        self.temperature_array_current_timestep[i+1],
-                        self.back_single_cv.pressure_control_volume).unwrap();
+                    self.try_get_node_specific_enthalpy(i+1)?;
 
 
                     power_source_vector[i] += 
@@ -583,10 +578,7 @@ impl FluidArray{
                 // back
 
                 let enthalpy_of_adjacent_node_to_the_rear: AvailableEnergy = 
-                try_get_h(
-                    self.back_single_cv.material_control_volume,
-                    self.temperature_array_current_timestep[i-1],
-                    self.back_single_cv.pressure_control_volume).unwrap();
+                self.try_get_node_specific_enthalpy(i-1)?;
 
                 // now if mass flowrate is less than zero, then 
                 // we receive enthalpy from the front cv 
@@ -769,29 +761,71 @@ impl FluidArray{
         new_temperature_array = 
             solve_conductance_matrix_power_vector(
                 coefficient_matrix,power_source_vector)?;
-        // update the single cvs at the front and back with new enthalpies 
-
-        // Todo: probably need to synchronise error types in future
-        let back_node_enthalpy_next_timestep: AvailableEnergy = 
-        try_get_h(
-            self.back_single_cv.material_control_volume,
-            new_temperature_array[0],
-            self.back_single_cv.pressure_control_volume).unwrap();
 
-        self.back_single_cv.current_timestep_control_volume_specific_enthalpy 
-            = back_node_enthalpy_next_timestep;
+        // report what was applied this timestep before the 
+        // vectors are cleared
+        if let Some(energy_tally) = self.energy_tally.as_mut() {
+            energy_tally.last_timestep = get_array_energy_tally_rates(
+                &self.back_single_cv,
+                &self.front_single_cv,
+                &self.q_vector,
+                &self.q_fraction_vector,
+                &self.lateral_adjacent_array_conductance_vector,
+                &self.lateral_adjacent_array_temperature_vector,
+                &new_temperature_array);
+        }
 
-        let front_node_enthalpy_next_timestep: AvailableEnergy = 
-        try_get_h(
-            self.front_single_cv.material_control_volume,
-            new_temperature_array[number_of_nodes-1],
-            self.front_single_cv.pressure_control_volume).unwrap();
+        // the temperatures obtained are based on sensible heat only,
+        // if the fluid can freeze or melt, the enthalpy change 
+        // implied by the new temperatures is added to the enthalpy 
+        // of each node, and the temperature and liquid fraction 
+        // are recovered from that enthalpy 
+        let mut new_liquid_fraction_vec: Vec<f64> = 
+            self.liquid_fraction_array.to_vec();
+
+        let new_temperature_array: Array1<ThermodynamicTemperature> = 
+        match self.phase_change_properties {
+            Some(phase_change) => {
+                let mut recovered_temperature_array = 
+                    new_temperature_array.clone();
+
+                for i in 0..number_of_nodes {
+                    let h_old: AvailableEnergy = 
+                        self.try_get_node_specific_enthalpy(i)?;
+
+                    let property_temperature = 
+                        self.get_property_evaluation_temperature(
+                            self.temperature_array_current_timestep[i]);
+
+                    let cp: SpecificHeatCapacity = try_get_cp(
+                        material, property_temperature, pressure)?;
+
+                    let temperature_change = TemperatureInterval::new::<
+                        interval_kelvin>(
+                        new_temperature_array[i].get::<kelvin>()
+                        - self.temperature_array_current_timestep[i]
+                        .get::<kelvin>());
+
+                    let h_new: AvailableEnergy = h_old + cp * temperature_change;
+
+                    let (temperature, liquid_fraction) = phase_change.
+                        try_get_temperature_and_liquid_fraction_from_h(
+                            material, h_new, pressure)?;
+
+                    recovered_temperature_array[i] = temperature;
+                    new_liquid_fraction_vec[i] = liquid_fraction.get::<ratio>();
+                }
 
-        self.front_single_cv.current_timestep_control_volume_specific_enthalpy 
-            = front_node_enthalpy_next_timestep;
-        // let's also update the previous temperature vector 
+                recovered_temperature_array
+            },
+            None => new_temperature_array,
+        };
 
-        self.set_temperature_array(new_temperature_array.clone())?;
+        // update the single cvs at the front and back with new enthalpies 
+        // and also update the previous temperature vector 
+        self.set_temperature_and_liquid_fraction_vector(
+            new_temperature_array.to_vec(),
+            new_liquid_fraction_vec)?;
 
 
         // need to also set the front and back single cv temperature 
@@ -837,4 +871,13 @@ impl FluidArray{
 
         Ok(())
     }
+
+    /// starts tallying the contributions to the rate of enthalpy 
+    /// change of the fluid array by source, for use in energy audits,
+    /// this also starts the tallies of the back and front single cvs
+    pub fn enable_energy_tally(&mut self){
+        self.energy_tally = Some(EnergyTally::default());
+        self.back_single_cv.enable_energy_tally();
+        self.front_single_cv.enable_energy_tally();
+    }
 }
@@ -146,6 +146,9 @@ impl FluidArray {
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
             q_fraction_vector: vec![],
+            phase_change_properties: None,
+            liquid_fraction_array: Array1::ones(number_of_temperature_nodes),
+            energy_tally: None,
         }
 
     }
@@ -292,6 +295,9 @@ impl FluidArray {
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
             q_fraction_vector: vec![],
+            phase_change_properties: None,
+            liquid_fraction_array: Array1::ones(number_of_temperature_nodes),
+            energy_tally: None,
         }
 
     }
@@ -549,6 +555,9 @@ impl FluidArray {
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
             q_fraction_vector: vec![],
+            phase_change_properties: None,
+            liquid_fraction_array: Array1::ones(number_of_temperature_nodes),
+            energy_tally: None,
         }
     }
 
@@ -665,6 +674,9 @@ impl FluidArray {
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
             q_fraction_vector: vec![],
+            phase_change_properties: None,
+            liquid_fraction_array: Array1::ones(number_of_temperature_nodes),
+            energy_tally: None,
         }
     }
 
@@ -117,6 +117,9 @@ impl Default for FluidArray {
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
             q_fraction_vector: vec![],
+            phase_change_properties: None,
+            liquid_fraction_array: array![1.0, 1.0],
+            energy_tally: None,
         }
 
     }
@@ -2,6 +2,8 @@ use crate::boussinesq_thermophysical_properties::density::try_get_rho;
 use crate::boussinesq_thermophysical_properties::dynamic_viscosity::try_get_mu_viscosity;
 use crate::tuas_lib_error::TuasLibError;
 use crate::fluid_mechanics_correlations::churchill_friction_factor;
+use crate::fluid_mechanics_correlations::enhanced_surface_friction_factor;
+use crate::fluid_mechanics_correlations::duct_friction_factor;
 use roots::*;
 use uom::typenum::P2;
 use uom::num_traits::Zero;
@@ -11,6 +13,8 @@ use uom::ConstZero;
 
 use super::FluidArray;
 
+use self::piecewise_reynolds_power::{PiecewiseReynoldsPowerFldk, ReynoldsPowerSegment};
+
 /// contains form loss or minor loss correlations for use 
 ///
 /// This will return a friction factor if one wishes it
@@ -34,7 +38,58 @@ pub enum DimensionlessDarcyLossCorrelations {
     /// & Engineering Chemistry, 41(6), 1179-1184.
     ///
     /// not done yet
-    Ergun
+    Ergun,
+
+    /// tube with a twisted tape insert, using the Manglik and 
+    /// Bergles friction factor for laminar, transition and 
+    /// turbulent flow
+    ///
+    /// The first in the tuple is the twist ratio y = H/d,
+    /// the second is the tape thickness to tube diameter ratio,
+    /// the third is the length to diameter ratio L/D,
+    /// and the fourth is a K ratio for generic form losses
+    ///
+    /// the reynolds number is based on the tube inner diameter,
+    /// so the hydraulic diameter of the fluid array should be 
+    /// the tube inner diameter
+    TwistedTape(Ratio, Ratio, Ratio, Ratio),
+
+    /// helical coil, using the Mishra and Gupta friction factor 
+    /// for laminar and turbulent flow, with transition at the 
+    /// critical reynolds number of Schmidt
+    ///
+    /// The first in the tuple is the tube to coil diameter 
+    /// ratio d/D,
+    /// the second is the length to diameter ratio L/D,
+    /// and the third is a K ratio for generic form losses
+    HelicalCoil(Ratio, Ratio, Ratio),
+
+    /// circular pipe including the entrance region, using the 
+    /// Shah and London apparent friction factor for laminar 
+    /// developing flow, and the Churchill friction factor for 
+    /// turbulent flow, blended smoothly in between
+    ///
+    /// The first in the tuple is the roughness ratio,
+    /// the second is the length to diameter ratio L/D,
+    /// and the third is a K ratio for generic form losses
+    DevelopingFlowPipe(Ratio, Ratio, Ratio),
+
+    /// non circular duct such as an annulus or rectangular duct,
+    /// using the Churchill friction factor with the laminar 
+    /// equivalent diameter method
+    ///
+    /// The first in the tuple is the roughness ratio based on 
+    /// hydraulic diameter,
+    /// the second is the laminar friction factor reynolds 
+    /// number product (f Re) of the duct, which is 64 for a 
+    /// circular pipe,
+    /// the third is the length to hydraulic diameter ratio L/D_h,
+    /// and the fourth is a K ratio for generic form losses
+    NonCircularDuct(Ratio, Ratio, Ratio, Ratio),
+
+    /// piecewise fldk over reynolds number ranges, each in 
+    /// the form A + B Re^(C), with smooth blending between ranges
+    PiecewiseReynoldsPower(PiecewiseReynoldsPowerFldk),
 }
 
 impl Default for DimensionlessDarcyLossCorrelations {
@@ -69,6 +124,128 @@ impl DimensionlessDarcyLossCorrelations {
             form_loss);
     }
 
+    /// creates a new twisted tape object, the twist pitch is the 
+    /// length of a 180 degree twist of the tape
+    pub fn new_twisted_tape(
+        tube_length: Length,
+        tube_inner_diameter: Length,
+        twist_pitch: Length,
+        tape_thickness: Length,
+        form_loss: Ratio
+    ) -> Self {
+
+        return Self::TwistedTape(
+            twist_pitch/tube_inner_diameter,
+            tape_thickness/tube_inner_diameter,
+            tube_length/tube_inner_diameter,
+            form_loss);
+    }
+
+    /// creates a new helical coil object, the coil diameter is 
+    /// measured between the centrelines of the tube on either side 
+    /// of the coil
+    pub fn new_helical_coil(
+        tube_length: Length,
+        tube_inner_diameter: Length,
+        coil_diameter: Length,
+        form_loss: Ratio
+    ) -> Self {
+
+        return Self::HelicalCoil(
+            tube_inner_diameter/coil_diameter,
+            tube_length/tube_inner_diameter,
+            form_loss);
+    }
+
+    /// creates a new circular pipe object including entrance 
+    /// region effects
+    pub fn new_developing_flow_pipe(
+        pipe_length: Length,
+        surface_roughness: Length,
+        hydraulic_diameter: Length,
+        form_loss: Ratio
+    ) -> Self {
+
+        return Self::DevelopingFlowPipe(
+            surface_roughness/hydraulic_diameter,
+            pipe_length/hydraulic_diameter,
+            form_loss);
+    }
+
+    /// creates a new concentric annulus object, the hydraulic 
+    /// diameter is the outer diameter minus the inner diameter
+    pub fn new_annulus(
+        annulus_length: Length,
+        inner_diameter: Length,
+        outer_diameter: Length,
+        surface_roughness: Length,
+        form_loss: Ratio
+    ) -> Result<Self, TuasLibError> {
+
+        let hydraulic_diameter: Length = outer_diameter - inner_diameter;
+        let laminar_darcy_reynolds_product = 
+            duct_friction_factor::annulus_laminar_darcy_reynolds_product(
+                (inner_diameter/outer_diameter).get::<ratio>())?;
+
+        return Ok(Self::NonCircularDuct(
+            surface_roughness/hydraulic_diameter,
+            Ratio::new::<ratio>(laminar_darcy_reynolds_product),
+            annulus_length/hydraulic_diameter,
+            form_loss));
+    }
+
+    /// creates a new rectangular duct object, the hydraulic 
+    /// diameter is 4A/P = 2 w h/(w + h)
+    pub fn new_rectangular_duct(
+        duct_length: Length,
+        duct_width: Length,
+        duct_height: Length,
+        surface_roughness: Length,
+        form_loss: Ratio
+    ) -> Result<Self, TuasLibError> {
+
+        let hydraulic_diameter: Length = 2.0 * duct_width * duct_height
+            /(duct_width + duct_height);
+        let laminar_darcy_reynolds_product = 
+            duct_friction_factor::rectangular_duct_laminar_darcy_reynolds_product(
+                (duct_height/duct_width).get::<ratio>())?;
+
+        return Ok(Self::NonCircularDuct(
+            surface_roughness/hydraulic_diameter,
+            Ratio::new::<ratio>(laminar_darcy_reynolds_product),
+            duct_length/hydraulic_diameter,
+            form_loss));
+    }
+
+    /// creates a new non circular duct object given the laminar 
+    /// friction factor reynolds number product (f Re) of the duct
+    /// shape, eg. 53.33 for an equilateral triangle
+    pub fn new_non_circular_duct(
+        duct_length: Length,
+        hydraulic_diameter: Length,
+        surface_roughness: Length,
+        laminar_darcy_reynolds_product: Ratio,
+        form_loss: Ratio
+    ) -> Self {
+
+        return Self::NonCircularDuct(
+            surface_roughness/hydraulic_diameter,
+            laminar_darcy_reynolds_product,
+            duct_length/hydraulic_diameter,
+            form_loss);
+    }
+
+    /// creates a new piecewise reynolds power correlation object,
+    /// segments must be given in increasing reynolds number order,
+    /// and are blended over a relative width around each boundary
+    pub fn new_piecewise_reynolds_power_component(
+        segments: &[ReynoldsPowerSegment],
+        blending_width: Ratio) -> Result<Self, TuasLibError> {
+
+        return Ok(Self::PiecewiseReynoldsPower(
+            PiecewiseReynoldsPowerFldk::try_new(segments, blending_width)?));
+    }
+
     /// creates a new simple reynolds power correlation object 
     /// in the form
     /// Reynold's power correlation in the form 
@@ -144,6 +321,55 @@ impl DimensionlessDarcyLossCorrelations {
             DimensionlessDarcyLossCorrelations::Ergun => {
                 todo!()
             },
+            DimensionlessDarcyLossCorrelations::TwistedTape(twist_ratio,
+                tape_thickness_to_diameter,
+                length_to_diameter,
+                form_loss) => {
+
+                    enhanced_surface_friction_factor::manglik_bergles_twisted_tape_darcy(
+                        reynolds.get::<ratio>(),
+                        twist_ratio.get::<ratio>(),
+                        tape_thickness_to_diameter.get::<ratio>())?
+                        * length_to_diameter.get::<ratio>()
+                    + form_loss.get::<ratio>()
+            },
+            DimensionlessDarcyLossCorrelations::HelicalCoil(tube_to_coil_diameter_ratio,
+                length_to_diameter,
+                form_loss) => {
+
+                    enhanced_surface_friction_factor::helical_coil_darcy(
+                        reynolds.get::<ratio>(),
+                        tube_to_coil_diameter_ratio.get::<ratio>())?
+                        * length_to_diameter.get::<ratio>()
+                    + form_loss.get::<ratio>()
+            },
+            DimensionlessDarcyLossCorrelations::DevelopingFlowPipe(roughness_ratio,
+                length_to_diameter,
+                form_loss) => {
+
+                    duct_friction_factor::developing_flow_pipe_darcy(
+                        reynolds.get::<ratio>(),
+                        roughness_ratio.get::<ratio>(),
+                        length_to_diameter.get::<ratio>())?
+                        * length_to_diameter.get::<ratio>()
+                    + form_loss.get::<ratio>()
+            },
+            DimensionlessDarcyLossCorrelations::NonCircularDuct(roughness_ratio,
+                laminar_darcy_reynolds_product,
+                length_to_diameter,
+                form_loss) => {
+
+                    duct_friction_factor::non_circular_duct_darcy(
+                        reynolds.get::<ratio>(),
+                        roughness_ratio.get::<ratio>(),
+                        laminar_darcy_reynolds_product.get::<ratio>())?
+                        * length_to_diameter.get::<ratio>()
+                    + form_loss.get::<ratio>()
+            },
+            // f L/D + K = A_i + B_i Re^(C_i) within each range
+            DimensionlessDarcyLossCorrelations::PiecewiseReynoldsPower(piecewise_fldk) => {
+                piecewise_fldk.get_fldk(reynolds.get::<ratio>())
+            },
         };
 
         Ok(Ratio::new::<ratio>(fldk_value))
@@ -155,6 +381,9 @@ impl DimensionlessDarcyLossCorrelations {
     /// the convention is to disregard directionality,
     /// so reverse flow will also return a positive friction_factor
     /// value
+    ///
+    /// piecewise reynolds power correlations return an error, 
+    /// as they only give the total fldk
     #[inline]
     pub fn darcy_friction_factor(&self, reynolds_input: Ratio) -> 
     Result<Ratio, TuasLibError> {
@@ -198,6 +427,52 @@ impl DimensionlessDarcyLossCorrelations {
 
                     darcy_friction_factor
             },
+            DimensionlessDarcyLossCorrelations::TwistedTape(twist_ratio,
+                tape_thickness_to_diameter,
+                _length_to_diameter,
+                _form_loss) => {
+
+                    enhanced_surface_friction_factor::manglik_bergles_twisted_tape_darcy(
+                        reynolds.get::<ratio>(),
+                        twist_ratio.get::<ratio>(),
+                        tape_thickness_to_diameter.get::<ratio>())?
+            },
+            DimensionlessDarcyLossCorrelations::HelicalCoil(tube_to_coil_diameter_ratio,
+                _length_to_diameter,
+                _form_loss) => {
+
+                    enhanced_surface_friction_factor::helical_coil_darcy(
+                        reynolds.get::<ratio>(),
+                        tube_to_coil_diameter_ratio.get::<ratio>())?
+            },
+            DimensionlessDarcyLossCorrelations::DevelopingFlowPipe(roughness_ratio,
+                length_to_diameter,
+                _form_loss) => {
+
+                    duct_friction_factor::developing_flow_pipe_darcy(
+                        reynolds.get::<ratio>(),
+                        roughness_ratio.get::<ratio>(),
+                        length_to_diameter.get::<ratio>())?
+            },
+            DimensionlessDarcyLossCorrelations::NonCircularDuct(roughness_ratio,
+                laminar_darcy_reynolds_product,
+                _length_to_diameter,
+                _form_loss) => {
+
+                    duct_friction_factor::non_circular_duct_darcy(
+                        reynolds.get::<ratio>(),
+                        roughness_ratio.get::<ratio>(),
+                        laminar_darcy_reynolds_product.get::<ratio>())?
+            },
+            // the piecewise fits are for the whole of f L/D + K,
+            // so there is no length to diameter ratio to 
+            // separate out a darcy friction factor
+            DimensionlessDarcyLossCorrelations::PiecewiseReynoldsPower(_) => {
+                return Err(TuasLibError::GenericStringError(
+                    "darcy friction factor is not defined for piecewise \
+                    reynolds power fldk correlations, use \
+                    fldk_based_on_darcy_friction_factor instead".to_owned()));
+            },
             // this is not implemented for other correlations
             _ => todo!()
         };
@@ -395,6 +670,17 @@ impl FluidArray {
         let fluid_density = self.get_fluid_density_immutable();
         let xs_area = self.xs_area;
 
+        // if any node is fully frozen, flow is blocked 
+        if self.is_flow_blocked_by_frozen_fluid() {
+            return MassRate::ZERO;
+        }
+
+        // partially frozen fluid has higher flow resistance,
+        // since the multiplier does not depend on Re, 
+        // pressure loss is scaled down before finding Re
+        let pressure_loss = pressure_loss / 
+            self.get_frozen_fluid_flow_resistance_multiplier();
+
         let reynolds_number: Ratio = self.fluid_component_loss_properties. 
             get_reynolds_from_pressure_loss(
                 pressure_loss,
@@ -456,10 +742,39 @@ impl FluidArray {
                 fluid_viscosity
             ).unwrap();
 
+        // partially frozen fluid has higher flow resistance
+        let pressure_loss = pressure_loss * 
+            self.get_frozen_fluid_flow_resistance_multiplier();
+
         // return pressure loss
         pressure_loss
     }
 
+    /// multiplier for the flow resistance (fldk) due to partially 
+    /// frozen fluid 
+    ///
+    /// a single node which is partially frozen restricts flow 
+    /// for the whole array, hence the node with the smallest 
+    /// liquid fraction is used
+    ///
+    /// returns 1.0 if phase change is not enabled
+    pub fn get_frozen_fluid_flow_resistance_multiplier(&self) -> Ratio {
+        match self.phase_change_properties {
+            Some(phase_change) => phase_change.get_flow_resistance_multiplier(
+                self.get_minimum_liquid_fraction()),
+            None => Ratio::new::<ratio>(1.0),
+        }
+    }
+
+    /// returns true if any node within the array is fully frozen,
+    /// in which case no flow is allowed through the array
+    pub fn is_flow_blocked_by_frozen_fluid(&self) -> bool {
+        match self.phase_change_properties {
+            Some(_) => self.get_minimum_liquid_fraction().get::<ratio>() <= 0.0,
+            None => false,
+        }
+    }
+
     /// gets cross sectional area using a mutable borrow
     pub fn get_cross_sectional_area(&mut self) -> Area {
         self.xs_area
@@ -485,7 +800,9 @@ impl FluidArray {
     /// gets fluid viscosity with a mutable borrow
     /// given the current average bulk temperature of fluid array
     pub fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
-        let temperature = self.try_get_bulk_temperature().unwrap();
+        let bulk_temperature = self.try_get_bulk_temperature().unwrap();
+        let temperature = self.get_property_evaluation_temperature(
+            bulk_temperature);
 
         let viscosity = try_get_mu_viscosity(
             self.material_control_volume,
@@ -498,7 +815,9 @@ impl FluidArray {
     /// gets fluid viscosity with a immutable borrow
     /// given the current average bulk temperature of fluid array
     pub fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
-        let temperature = self.clone().try_get_bulk_temperature().unwrap();
+        let bulk_temperature = self.clone().try_get_bulk_temperature().unwrap();
+        let temperature = self.get_property_evaluation_temperature(
+            bulk_temperature);
 
         let viscosity = try_get_mu_viscosity(
             self.material_control_volume,
@@ -511,7 +830,9 @@ impl FluidArray {
     /// gets fluid fluid density with a mutable borrow
     /// given the current average bulk temperature of fluid array
     pub fn get_fluid_density(&mut self) -> MassDensity {
-        let temperature = self.try_get_bulk_temperature().unwrap();
+        let bulk_temperature = self.try_get_bulk_temperature().unwrap();
+        let temperature = self.get_property_evaluation_temperature(
+            bulk_temperature);
 
         let density = try_get_rho(
             self.material_control_volume,
@@ -525,7 +846,9 @@ impl FluidArray {
     /// given the current average bulk temperature of fluid array
     /// uses an immutable borrow
     pub fn get_fluid_density_immutable(&self) -> MassDensity {
-        let temperature = self.clone().try_get_bulk_temperature().unwrap();
+        let bulk_temperature = self.clone().try_get_bulk_temperature().unwrap();
+        let temperature = self.get_property_evaluation_temperature(
+            bulk_temperature);
 
         let density = try_get_rho(
             self.material_control_volume,
@@ -586,6 +909,10 @@ impl FluidArray {
     }
 }
 
+/// piecewise fldk correlations over reynolds number ranges, 
+/// with smooth blending between ranges
+pub mod piecewise_reynolds_power;
+
 /// unit tests for DimensionlessDarcyLossCorrelations 
 pub mod unit_test_dimensionless_darcy_loss_correlations;
 
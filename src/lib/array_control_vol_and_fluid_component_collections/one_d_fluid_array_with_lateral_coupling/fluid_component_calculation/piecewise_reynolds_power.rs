@@ -0,0 +1,212 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::ConstZero;
+
+use crate::fluid_mechanics_correlations::duct_friction_factor::smooth_reynolds_blending_weight;
+use crate::tuas_lib_error::TuasLibError;
+
+/// maximum number of reynolds number ranges in a piecewise
+/// fldk correlation
+///
+/// the segments are kept in a fixed size array so that
+/// DimensionlessDarcyLossCorrelations remains Copy
+pub const MAX_PIECEWISE_FLDK_SEGMENTS: usize = 8;
+
+/// one reynolds number range of a piecewise fldk correlation
+/// in the form
+///
+/// (f L/D + K) = A + B Re^(C)
+///
+/// the segment applies from the upper limit of the previous
+/// segment up to its own upper limit
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct ReynoldsPowerSegment {
+    /// upper reynolds number limit for this segment, this is
+    /// ignored for the last segment, which extends to infinity
+    pub reynolds_upper_limit: Ratio,
+    /// A in A + B Re^(C)
+    pub a: Ratio,
+    /// B in A + B Re^(C)
+    pub b: Ratio,
+    /// C in A + B Re^(C)
+    pub c: f64,
+}
+
+impl ReynoldsPowerSegment {
+
+    /// creates a new segment, fldk = A + B Re^(C) up to
+    /// the upper reynolds number limit
+    pub fn new(reynolds_upper_limit: Ratio,
+        a: Ratio,
+        b: Ratio,
+        c: f64) -> Self {
+        Self { reynolds_upper_limit, a, b, c }
+    }
+
+    /// fldk for this segment given a positive reynolds number
+    #[inline]
+    pub fn get_fldk(&self, reynolds_number: f64) -> f64 {
+        self.a.get::<ratio>()
+            + self.b.get::<ratio>() * reynolds_number.powf(self.c)
+    }
+}
+
+/// piecewise fldk correlation defined over reynolds number ranges,
+/// each in the form A + B Re^(C), such as those fitted for CIET
+/// flowmeters and static mixers in SAM
+///
+/// to avoid jumps in pressure loss at the range boundaries (which
+/// make the reynolds number from bejan number root finding
+/// difficult), adjacent segments are blended smoothly over a band
+/// around each boundary. The blending width w is relative, so
+/// around a boundary at Re_b, blending is done between
+/// Re_b/(1+w) and Re_b (1+w)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PiecewiseReynoldsPowerFldk {
+    segments: [ReynoldsPowerSegment; MAX_PIECEWISE_FLDK_SEGMENTS],
+    number_of_segments: usize,
+    blending_width: Ratio,
+}
+
+impl PiecewiseReynoldsPowerFldk {
+
+    /// creates a new piecewise fldk correlation, segments must be
+    /// given in increasing reynolds number order
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::ratio::ratio;
+    /// use tuas_boussinesq_solver::array_control_vol_and_fluid_component_collections::
+    /// one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::
+    /// piecewise_reynolds_power::{PiecewiseReynoldsPowerFldk, ReynoldsPowerSegment};
+    ///
+    /// // laminar fit up to Re = 2000, then a turbulent fit
+    /// let laminar_segment = ReynoldsPowerSegment::new(
+    ///     Ratio::new::<ratio>(2000.0),
+    ///     Ratio::new::<ratio>(2.0),
+    ///     Ratio::new::<ratio>(6400.0),
+    ///     -1.0);
+    /// let turbulent_segment = ReynoldsPowerSegment::new(
+    ///     Ratio::new::<ratio>(f64::INFINITY),
+    ///     Ratio::new::<ratio>(2.0),
+    ///     Ratio::new::<ratio>(31.64),
+    ///     -0.25);
+    ///
+    /// let piecewise_fldk = PiecewiseReynoldsPowerFldk::try_new(
+    ///     &[laminar_segment, turbulent_segment],
+    ///     Ratio::new::<ratio>(0.1)).unwrap();
+    ///
+    /// // away from the boundary, each segment is used as is
+    /// approx::assert_relative_eq!(
+    ///     piecewise_fldk.get_fldk(1000.0),
+    ///     2.0 + 6400.0/1000.0,
+    ///     max_relative=1e-12);
+    /// ```
+    pub fn try_new(segments: &[ReynoldsPowerSegment],
+        blending_width: Ratio) -> Result<Self, TuasLibError> {
+
+        if segments.is_empty() || segments.len() > MAX_PIECEWISE_FLDK_SEGMENTS {
+            return Err(TuasLibError::GenericStringError(
+                format!("piecewise fldk needs between 1 and {} segments, got {}",
+                    MAX_PIECEWISE_FLDK_SEGMENTS, segments.len())));
+        }
+
+        let width = blending_width.get::<ratio>();
+
+        if !(width >= 0.0) || !width.is_finite() {
+            return Err(TuasLibError::GenericStringError(
+                "piecewise fldk blending width must not be negative".to_owned()));
+        }
+
+        let boundaries: Vec<f64> = segments[..segments.len()-1].iter()
+            .map(|segment| segment.reynolds_upper_limit.get::<ratio>())
+            .collect();
+
+        if boundaries.iter().any(|boundary| !(*boundary > 0.0) || !boundary.is_finite()) {
+            return Err(TuasLibError::GenericStringError(
+                "piecewise fldk reynolds number limits must be positive and finite"
+                .to_owned()));
+        }
+
+        // blending bands of neighbouring boundaries must not overlap
+        let minimum_boundary_ratio = (1.0 + width).powi(2);
+        if boundaries.windows(2).any(|pair| !(pair[1]/pair[0] > minimum_boundary_ratio)) {
+            return Err(TuasLibError::GenericStringError(
+                "piecewise fldk reynolds number limits must be increasing and \
+                further apart than the blending width".to_owned()));
+        }
+
+        let mut segment_array = [ReynoldsPowerSegment::default();
+            MAX_PIECEWISE_FLDK_SEGMENTS];
+        segment_array[..segments.len()].copy_from_slice(segments);
+
+        Ok(Self {
+            segments: segment_array,
+            number_of_segments: segments.len(),
+            blending_width,
+        })
+    }
+
+    /// returns the segments of the correlation
+    pub fn get_segments(&self) -> &[ReynoldsPowerSegment] {
+        &self.segments[..self.number_of_segments]
+    }
+
+    /// returns the relative blending width around segment boundaries
+    pub fn get_blending_width(&self) -> Ratio {
+        self.blending_width
+    }
+
+    /// gets the fldk for a given reynolds number, directionality
+    /// is disregarded
+    pub fn get_fldk(&self, reynolds_number: f64) -> f64 {
+
+        let reynolds_number = reynolds_number.abs();
+        let segments = self.get_segments();
+        let last_segment = segments.len() - 1;
+
+        let segment_index = segments[..last_segment].iter()
+            .position(|segment| reynolds_number < segment.reynolds_upper_limit.get::<ratio>())
+            .unwrap_or(last_segment);
+
+        let segment_fldk = segments[segment_index].get_fldk(reynolds_number);
+
+        if self.blending_width == Ratio::ZERO {
+            return segment_fldk;
+        }
+
+        let band_factor = 1.0 + self.blending_width.get::<ratio>();
+
+        // blending with the previous segment
+        if segment_index > 0 {
+            let boundary = segments[segment_index - 1]
+                .reynolds_upper_limit.get::<ratio>();
+
+            if reynolds_number < boundary * band_factor {
+                let weight = smooth_reynolds_blending_weight(reynolds_number,
+                    boundary/band_factor, boundary * band_factor);
+                let previous_fldk = segments[segment_index - 1]
+                    .get_fldk(reynolds_number);
+
+                return (1.0 - weight) * previous_fldk + weight * segment_fldk;
+            }
+        }
+
+        // blending with the next segment
+        if segment_index < last_segment {
+            let boundary = segments[segment_index]
+                .reynolds_upper_limit.get::<ratio>();
+
+            if reynolds_number > boundary / band_factor {
+                let weight = smooth_reynolds_blending_weight(reynolds_number,
+                    boundary/band_factor, boundary * band_factor);
+                let next_fldk = segments[segment_index + 1]
+                    .get_fldk(reynolds_number);
+
+                return (1.0 - weight) * segment_fldk + weight * next_fldk;
+            }
+        }
+
+        segment_fldk
+    }
+}
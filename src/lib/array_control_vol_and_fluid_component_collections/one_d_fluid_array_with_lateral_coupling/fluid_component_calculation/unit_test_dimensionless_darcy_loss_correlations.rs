@@ -85,3 +85,360 @@ pub fn dimensionless_darcy_loss_correlation_get_re_from_be() -> Result<(),
 
     Ok(())
 }
+
+/// twisted tape fldk should be (f L/D + K) with the Manglik and 
+/// Bergles friction factor, and should be well above that of a 
+/// smooth pipe of the same length
+///
+/// for y = 3 and delta/d = 0.05 at Re = 20000, the 
+/// darcy friction factor is 0.097590 compared to 0.026606 
+/// for a smooth tube (Blasius)
+#[test]
+pub fn twisted_tape_fldk_test() -> Result<(), 
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*; 
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::DimensionlessDarcyLossCorrelations;
+
+    let twisted_tape = DimensionlessDarcyLossCorrelations::new_twisted_tape(
+        Length::new::<meter>(2.0),
+        Length::new::<meter>(0.02),
+        Length::new::<meter>(0.06),
+        Length::new::<meter>(0.001),
+        Ratio::new::<ratio>(1.5));
+
+    let reynolds_number = Ratio::new::<ratio>(20000.0);
+
+    let fldk = twisted_tape.fldk_based_on_darcy_friction_factor(
+        reynolds_number)?;
+
+    approx::assert_relative_eq!(
+        fldk.get::<ratio>(),
+        0.097590 * 100.0 + 1.5,
+        max_relative = 1e-4);
+
+    approx::assert_relative_eq!(
+        twisted_tape.darcy_friction_factor(reynolds_number)?.get::<ratio>(),
+        0.097590,
+        max_relative = 1e-4);
+
+    // reverse flow gives the same fldk
+    let fldk_reverse = twisted_tape.fldk_based_on_darcy_friction_factor(
+        -reynolds_number)?;
+
+    approx::assert_relative_eq!(
+        fldk_reverse.get::<ratio>(),
+        fldk.get::<ratio>(),
+        max_relative = 1e-12);
+
+    // the bejan to reynolds inversion works in the transition 
+    // regime too
+    let transition_reynolds = Ratio::new::<ratio>(5000.0);
+    let bejan_number = twisted_tape.get_bejan_number_from_reynolds(
+        transition_reynolds)?;
+    let reynolds_test = twisted_tape.get_reynolds_number_from_bejan(
+        bejan_number)?;
+
+    approx::assert_relative_eq!(
+        reynolds_test.get::<ratio>(),
+        5000.0,
+        max_relative = 1e-5);
+
+    // unphysical tapes give errors
+    let blocked_tube = DimensionlessDarcyLossCorrelations::TwistedTape(
+        Ratio::new::<ratio>(3.0),
+        Ratio::new::<ratio>(0.8),
+        Ratio::new::<ratio>(100.0),
+        Ratio::new::<ratio>(0.0));
+
+    assert!(blocked_tube.fldk_based_on_darcy_friction_factor(
+        reynolds_number).is_err());
+
+    Ok(())
+}
+
+/// helical coils in laminar flow have higher friction than 
+/// straight tubes due to secondary flow
+///
+/// for d/D = 0.05 and Re = 1000, De = 223.6 and the Mishra and 
+/// Gupta friction factor is 64/Re (1 + 0.033 (log10 De)^4)
+#[test]
+pub fn helical_coil_fldk_test() -> Result<(), 
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*; 
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::DimensionlessDarcyLossCorrelations;
+
+    let helical_coil = DimensionlessDarcyLossCorrelations::new_helical_coil(
+        Length::new::<meter>(2.0),
+        Length::new::<meter>(0.01),
+        Length::new::<meter>(0.2),
+        Ratio::new::<ratio>(0.0));
+
+    let reynolds_number = Ratio::new::<ratio>(1000.0);
+
+    let fldk = helical_coil.fldk_based_on_darcy_friction_factor(
+        reynolds_number)?;
+
+    let dean_number: f64 = 1000.0 * 0.05_f64.sqrt();
+    let expected_darcy = 64.0/1000.0 
+        * (1.0 + 0.033 * dean_number.log10().powi(4));
+
+    approx::assert_relative_eq!(
+        fldk.get::<ratio>(),
+        expected_darcy * 200.0,
+        max_relative = 1e-10);
+
+    assert!(expected_darcy > 64.0/1000.0);
+
+    // turbulent flow, above the critical reynolds number 
+    // of about 7460
+    let turbulent_reynolds_number = Ratio::new::<ratio>(20000.0);
+
+    approx::assert_relative_eq!(
+        helical_coil.darcy_friction_factor(turbulent_reynolds_number)?
+            .get::<ratio>(),
+        0.3164/20000.0_f64.powf(0.25) + 0.03 * 0.05_f64.sqrt(),
+        max_relative = 1e-10);
+
+    Ok(())
+}
+
+/// developing flow pipes should tend to the fully developed 
+/// laminar friction factor 64/Re for long pipes, have higher 
+/// friction in short pipes due to entrance effects, and match 
+/// the Churchill correlation in turbulent flow
+#[test]
+pub fn developing_flow_pipe_fldk_test() -> Result<(), 
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*; 
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::DimensionlessDarcyLossCorrelations;
+
+    let hydraulic_diameter = Length::new::<meter>(0.01);
+    let roughness = Length::new::<meter>(1.0e-6);
+    let form_loss = Ratio::new::<ratio>(0.5);
+
+    let long_pipe = DimensionlessDarcyLossCorrelations::new_developing_flow_pipe(
+        Length::new::<meter>(1000.0), roughness, hydraulic_diameter, form_loss);
+    let short_pipe = DimensionlessDarcyLossCorrelations::new_developing_flow_pipe(
+        Length::new::<meter>(0.1), roughness, hydraulic_diameter, form_loss);
+    let fully_developed_short_pipe = DimensionlessDarcyLossCorrelations::new_pipe(
+        Length::new::<meter>(0.1), roughness, hydraulic_diameter, form_loss);
+
+    let laminar_reynolds = Ratio::new::<ratio>(500.0);
+
+    approx::assert_relative_eq!(
+        long_pipe.darcy_friction_factor(laminar_reynolds)?.get::<ratio>(),
+        64.0/500.0,
+        max_relative = 0.01);
+
+    // L/D = 10 at Re = 500 is well within the entrance region
+    assert!(short_pipe.fldk_based_on_darcy_friction_factor(laminar_reynolds)?
+        > fully_developed_short_pipe.fldk_based_on_darcy_friction_factor(
+            laminar_reynolds)?);
+
+    // turbulent flow is taken as fully developed
+    let turbulent_reynolds = Ratio::new::<ratio>(20000.0);
+
+    approx::assert_relative_eq!(
+        short_pipe.fldk_based_on_darcy_friction_factor(turbulent_reynolds)?
+            .get::<ratio>(),
+        fully_developed_short_pipe.fldk_based_on_darcy_friction_factor(
+            turbulent_reynolds)?.get::<ratio>(),
+        max_relative = 1e-12);
+
+    // the blend is continuous at the laminar and turbulent limits
+    for reynolds_limit in [2300.0, 4000.0] {
+        let below = short_pipe.fldk_based_on_darcy_friction_factor(
+            Ratio::new::<ratio>(reynolds_limit - 1e-6))?.get::<ratio>();
+        let above = short_pipe.fldk_based_on_darcy_friction_factor(
+            Ratio::new::<ratio>(reynolds_limit + 1e-6))?.get::<ratio>();
+
+        approx::assert_relative_eq!(below, above, max_relative = 1e-6);
+    }
+
+    // and the bejan to reynolds inversion works within the blend
+    let bejan_number = short_pipe.get_bejan_number_from_reynolds(
+        Ratio::new::<ratio>(3000.0))?;
+
+    approx::assert_relative_eq!(
+        short_pipe.get_reynolds_number_from_bejan(bejan_number)?.get::<ratio>(),
+        3000.0,
+        max_relative = 1e-5);
+
+    Ok(())
+}
+
+/// laminar friction in non circular ducts follows the exact 
+/// f Re products,
+///
+/// for an annulus with D_i/D_o = 0.5, f Re = 95.25,
+/// and for a square duct, f Re = 56.91
+///
+/// Shah, R. K., & London, A. L. (1978). Laminar flow forced
+/// convection in ducts. Advances in Heat Transfer, Supplement 1.
+/// Academic Press.
+#[test]
+pub fn annulus_and_rectangular_duct_fldk_test() -> Result<(), 
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*; 
+    use uom::si::ratio::ratio;
+    use uom::si::length::meter;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::DimensionlessDarcyLossCorrelations;
+    use crate::fluid_mechanics_correlations::churchill_friction_factor;
+
+    let smooth = Length::new::<meter>(0.0);
+    let no_form_loss = Ratio::new::<ratio>(0.0);
+    let laminar_reynolds = Ratio::new::<ratio>(1000.0);
+
+    let annulus = DimensionlessDarcyLossCorrelations::new_annulus(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.02),
+        Length::new::<meter>(0.04),
+        smooth, no_form_loss)?;
+
+    approx::assert_relative_eq!(
+        annulus.darcy_friction_factor(laminar_reynolds)?.get::<ratio>() * 1000.0,
+        95.25,
+        max_relative = 0.001);
+
+    // L/D_h = 1.0/0.02 = 50
+    approx::assert_relative_eq!(
+        annulus.fldk_based_on_darcy_friction_factor(laminar_reynolds)?
+            .get::<ratio>(),
+        95.25/1000.0 * 50.0,
+        max_relative = 0.001);
+
+    let square_duct = DimensionlessDarcyLossCorrelations::new_rectangular_duct(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.02),
+        Length::new::<meter>(0.02),
+        smooth, no_form_loss)?;
+
+    approx::assert_relative_eq!(
+        square_duct.darcy_friction_factor(laminar_reynolds)?.get::<ratio>() * 1000.0,
+        56.91,
+        max_relative = 0.001);
+
+    // in turbulent flow, the laminar equivalent diameter is used,
+    // so the annulus friction factor is the Churchill friction 
+    // factor at Re/z with z = 95.25/64
+    let turbulent_reynolds: f64 = 50000.0;
+    let shape_factor = 95.25/64.0;
+
+    approx::assert_relative_eq!(
+        annulus.darcy_friction_factor(
+            Ratio::new::<ratio>(turbulent_reynolds))?.get::<ratio>(),
+        churchill_friction_factor::darcy(turbulent_reynolds/shape_factor, 0.0)?,
+        max_relative = 0.001);
+
+    // an inner diameter larger than the outer diameter is an error
+    assert!(DimensionlessDarcyLossCorrelations::new_annulus(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.05),
+        Length::new::<meter>(0.04),
+        smooth, no_form_loss).is_err());
+
+    Ok(())
+}
+
+/// piecewise fldk should use each segment as is away from 
+/// boundaries, blend smoothly and continuously across boundaries,
+/// and reject segments which are out of order
+#[test]
+pub fn piecewise_reynolds_power_fldk_test() -> Result<(), 
+    crate::tuas_lib_error::TuasLibError>
+{
+    use uom::si::f64::*; 
+    use uom::si::ratio::ratio;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::DimensionlessDarcyLossCorrelations;
+    use crate::array_control_vol_and_fluid_component_collections::
+        one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::
+        piecewise_reynolds_power::ReynoldsPowerSegment;
+
+    // a flowmeter style fit, with a different fit at high Re
+    let low_reynolds_segment = ReynoldsPowerSegment::new(
+        Ratio::new::<ratio>(3000.0),
+        Ratio::new::<ratio>(18.0),
+        Ratio::new::<ratio>(93000.0),
+        -1.35);
+    let high_reynolds_segment = ReynoldsPowerSegment::new(
+        Ratio::new::<ratio>(f64::INFINITY),
+        Ratio::new::<ratio>(15.0),
+        Ratio::new::<ratio>(500.0),
+        -0.5);
+
+    let blending_width = Ratio::new::<ratio>(0.2);
+
+    let piecewise = DimensionlessDarcyLossCorrelations::
+        new_piecewise_reynolds_power_component(
+            &[low_reynolds_segment, high_reynolds_segment],
+            blending_width)?;
+
+    let fldk_at = |reynolds: f64| -> f64 {
+        piecewise.fldk_based_on_darcy_friction_factor(
+            Ratio::new::<ratio>(reynolds)).unwrap().get::<ratio>()
+    };
+
+    // away from the boundary, each segment is used as is
+    approx::assert_relative_eq!(fldk_at(1000.0),
+        18.0 + 93000.0 * 1000.0_f64.powf(-1.35), max_relative = 1e-12);
+    approx::assert_relative_eq!(fldk_at(10000.0),
+        15.0 + 500.0 * 10000.0_f64.powf(-0.5), max_relative = 1e-12);
+
+    // at the edges of the blending band, the blend matches 
+    // the segments
+    approx::assert_relative_eq!(fldk_at(3000.0/1.2),
+        18.0 + 93000.0 * (3000.0_f64/1.2).powf(-1.35), max_relative = 1e-9);
+    approx::assert_relative_eq!(fldk_at(3000.0 * 1.2),
+        15.0 + 500.0 * (3000.0_f64 * 1.2).powf(-0.5), max_relative = 1e-9);
+
+    // and is continuous at the boundary
+    approx::assert_relative_eq!(fldk_at(3000.0 - 1e-6), fldk_at(3000.0 + 1e-6),
+        max_relative = 1e-8);
+
+    // reverse flow gives the same fldk
+    approx::assert_relative_eq!(fldk_at(-3100.0), fldk_at(3100.0),
+        max_relative = 1e-12);
+
+    // bejan to reynolds inversion within the blending band
+    let bejan_number = piecewise.get_bejan_number_from_reynolds(
+        Ratio::new::<ratio>(2900.0))?;
+
+    approx::assert_relative_eq!(
+        piecewise.get_reynolds_number_from_bejan(bejan_number)?.get::<ratio>(),
+        2900.0,
+        max_relative = 1e-5);
+
+    // segments out of order are rejected
+    let out_of_order_segment = ReynoldsPowerSegment::new(
+        Ratio::new::<ratio>(1000.0),
+        Ratio::new::<ratio>(15.0),
+        Ratio::new::<ratio>(500.0),
+        -0.5);
+
+    assert!(DimensionlessDarcyLossCorrelations::
+        new_piecewise_reynolds_power_component(
+            &[low_reynolds_segment, out_of_order_segment, high_reynolds_segment],
+            blending_width).is_err());
+
+    // there is no darcy friction factor for a fit of the whole 
+    // fldk, so this is an error rather than a panic
+    assert!(piecewise.darcy_friction_factor(
+        Ratio::new::<ratio>(2900.0)).is_err());
+
+    Ok(())
+}
@@ -65,6 +65,39 @@ impl FluidArray{
 
         Ok(())
     }
+    /// connects adjacent solid or fluid nodes laterally 
+    /// with a conductance for each node
+    ///
+    /// this is useful when only some nodes are linked, 
+    /// for example, a fuel pin linked to one fluid node, where 
+    /// the other nodes have zero conductance
+    pub fn lateral_link_new_temperature_vector_and_conductance_vector(&mut self,
+    conductance_vec: Vec<ThermalConductance>,
+    temperature_vec: Vec<ThermodynamicTemperature>) 
+        -> Result<(), TuasLibError>{
+
+        let number_of_temperature_nodes = self.len();
+
+        if temperature_vec.len() !=  number_of_temperature_nodes ||
+            conductance_vec.len() != number_of_temperature_nodes {
+            let shape_error = ShapeError::from_kind(
+                ErrorKind::IncompatibleShape
+            );
+
+            let linalg_error = LinalgError::Shape(shape_error);
+
+            return Err(TuasLibError::LinalgError
+                (linalg_error));
+
+        }
+
+        self.lateral_adjacent_array_temperature_vector.push(
+            Array1::from_vec(temperature_vec));
+        self.lateral_adjacent_array_conductance_vector.push(
+            Array1::from_vec(conductance_vec));
+
+        Ok(())
+    }
     /// connects an adjacent solid or fluid node laterally 
     /// with a given power source with an axial power distribution
     #[inline]
@@ -1,11 +1,18 @@
 use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
 use crate::single_control_vol::SingleCVNode;
 use crate::boussinesq_thermophysical_properties::Material;
+use crate::boussinesq_thermophysical_properties::LiquidMaterial;
 use crate::boussinesq_thermophysical_properties::specific_enthalpy::try_get_h;
+use crate::boussinesq_thermophysical_properties::phase_change::PhaseChangeProperties;
+use crate::boussinesq_thermophysical_properties::weakly_compressible::try_get_pressure_work_rate;
 use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::temperature_coefficient::per_kelvin;
+use uom::si::thermodynamic_temperature::kelvin;
 use ndarray::*;
 
 use crate::tuas_lib_error::TuasLibError;
+use crate::energy_audit::energy_tally::EnergyTally;
 use ndarray_linalg::error::LinalgError;
 
 use self::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
@@ -136,6 +143,19 @@ pub struct FluidArray {
     /// to their nodes 
     pub q_fraction_vector: Vec<Array1<f64>>,
 
+    /// freezing and melting properties of the fluid,
+    /// if None, the fluid is not allowed to freeze 
+    pub phase_change_properties: Option<PhaseChangeProperties>,
+
+    /// liquid fraction of each node at the current timestep
+    /// only accessible via get and set methods
+    pub (crate) liquid_fraction_array: Array1<f64>,
+
+    /// tally of the contributions to the rate of enthalpy change 
+    /// by source, summed over all nodes, for energy audits, 
+    /// if None, contributions are not tallied
+    pub energy_tally: Option<EnergyTally>,
+
 }
 
 impl FluidArray {
@@ -184,11 +204,40 @@ impl FluidArray {
 
         }
 
+        // if the fluid can freeze, the liquid fraction is taken 
+        // to be in equilibrium with the temperature
+        let liquid_fraction_vec: Vec<f64> = temperature_vec.iter().map(
+            |&temperature| {
+                match self.phase_change_properties {
+                    Some(phase_change) => phase_change
+                        .get_liquid_fraction(temperature).value,
+                    None => 1.0,
+                }
+            }
+        ).collect();
+
+        self.set_temperature_and_liquid_fraction_vector(
+            temperature_vec, liquid_fraction_vec)
+    }
+
+    /// sets both temperature and liquid fraction for each node,
+    /// and updates the enthalpies of the front and back cvs
+    ///
+    /// vectors are assumed to be of the correct length
+    pub (crate) fn set_temperature_and_liquid_fraction_vector(&mut self,
+    temperature_vec: Vec<ThermodynamicTemperature>,
+    liquid_fraction_vec: Vec<f64>) -> Result<(), TuasLibError>{
+
         for (index,temperature) in 
             self.temperature_array_current_timestep.iter_mut().enumerate() {
             *temperature = temperature_vec[index];
         }
 
+        for (index,liquid_fraction) in 
+            self.liquid_fraction_array.iter_mut().enumerate() {
+            *liquid_fraction = liquid_fraction_vec[index];
+        }
+
         // we also need to ensure that the front and end nodes are 
         // properly synchronised in terms of temperature
         //
@@ -202,19 +251,12 @@ impl FluidArray {
 
         // update enthalpies of control volumes withing
 
-        let material = self.material_control_volume;
-        let pressure = self.pressure_control_volume;
-
-        let back_cv_enthalpy = try_get_h(
-            material,
-            back_cv_temperature,
-            pressure
+        let back_cv_enthalpy = self.try_get_node_specific_enthalpy(
+            0
         )?;
 
-        let front_cv_enthalpy = try_get_h(
-            material,
-            front_cv_temperature,
-            pressure
+        let front_cv_enthalpy = self.try_get_node_specific_enthalpy(
+            self.len() - 1
         )?;
 
         self.back_single_cv.current_timestep_control_volume_specific_enthalpy
@@ -223,11 +265,99 @@ impl FluidArray {
         self.front_single_cv.current_timestep_control_volume_specific_enthalpy
             = front_cv_enthalpy;
 
+        self.back_single_cv.temperature = back_cv_temperature;
+        self.front_single_cv.temperature = front_cv_temperature;
+
+        self.back_single_cv.liquid_fraction = 
+            Ratio::new::<ratio>(liquid_fraction_vec[0]);
+        self.front_single_cv.liquid_fraction = 
+            Ratio::new::<ratio>(*liquid_fraction_vec.last().unwrap());
 
         
         Ok(())
     }
 
+    /// specific enthalpy of a node given its temperature, 
+    /// and liquid fraction if the fluid can freeze
+    #[inline]
+    pub fn try_get_node_specific_enthalpy(&self, 
+    node_index: usize) -> Result<AvailableEnergy, TuasLibError> {
+
+        let material = self.material_control_volume;
+        let pressure = self.pressure_control_volume;
+        let temperature = self.temperature_array_current_timestep[node_index];
+
+        match self.phase_change_properties {
+            Some(phase_change) => phase_change
+                .try_get_h_from_temperature_and_liquid_fraction(
+                    material,
+                    temperature,
+                    Ratio::new::<ratio>(self.liquid_fraction_array[node_index]),
+                    pressure),
+            None => try_get_h(material, temperature, pressure),
+        }
+    }
+
+    /// temperature at which thermophysical properties (density, 
+    /// viscosity etc.) are evaluated
+    ///
+    /// when phase change is enabled, nodes may be colder than 
+    /// the range of the liquid correlations, so the properties 
+    /// are evaluated at the lower bound of the correlations instead
+    #[inline]
+    pub fn get_property_evaluation_temperature(&self, 
+    temperature: ThermodynamicTemperature) -> ThermodynamicTemperature {
+
+        match self.phase_change_properties {
+            Some(_) => PhaseChangeProperties::get_property_evaluation_temperature(
+                self.material_control_volume, temperature),
+            None => temperature,
+        }
+    }
+
+    /// allows the fluid array to freeze and melt using the 
+    /// enthalpy method 
+    ///
+    /// the front and back cvs will also have phase change enabled,
+    /// liquid fractions are initialised from the current temperatures
+    pub fn enable_phase_change(&mut self,
+        phase_change_properties: PhaseChangeProperties) 
+    -> Result<(), TuasLibError>{
+
+        self.back_single_cv.enable_phase_change(phase_change_properties)?;
+        self.front_single_cv.enable_phase_change(phase_change_properties)?;
+        self.phase_change_properties = Some(phase_change_properties);
+
+        let temperature_vec = self.get_temperature_vector()?;
+        self.set_temperature_vector(temperature_vec)
+    }
+
+    /// allows the fluid array to freeze and melt using 
+    /// freezing data from the liquid database
+    pub fn enable_phase_change_from_database(&mut self) 
+    -> Result<(), TuasLibError>{
+
+        let liquid: LiquidMaterial
+        = self.material_control_volume.try_into()?;
+
+        self.enable_phase_change(liquid.try_get_phase_change_properties()?)
+    }
+
+    /// obtains a clone of the liquid fraction array,
+    /// nodes are all 1.0 (fully liquid) if phase change is not enabled
+    pub fn get_liquid_fraction_array(&self) -> Array1<f64> {
+        self.liquid_fraction_array.clone()
+    }
+
+    /// obtains the smallest liquid fraction within the array,
+    /// a single frozen node is enough to block flow
+    pub fn get_minimum_liquid_fraction(&self) -> Ratio {
+        let min_liquid_fraction = self.liquid_fraction_array.iter().fold(
+            1.0_f64, |min_value, &liquid_fraction| min_value.min(liquid_fraction));
+
+        Ratio::new::<ratio>(min_liquid_fraction)
+    }
+
     /// obtains a clone of the temperature array in Array1 ndarray 
     /// form 
     pub fn set_temperature_array(&mut self,
@@ -248,6 +378,64 @@ impl FluidArray {
 
     }
 
+    /// sets the pressure of the fluid array and its front and back 
+    /// cvs, and adds the pressure work done on the fluid over the 
+    /// next timestep as a power source
+    ///
+    /// the fluid array solves for temperature rather than 
+    /// enthalpy, so the pressure work heats each node by 
+    /// beta T V dp/dt, where beta is the thermal expansion 
+    /// coefficient. Pressure work is only added for weakly 
+    /// compressible liquids, for other materials, only the 
+    /// pressure is set
+    pub fn try_set_pressure_with_pressure_work(&mut self,
+        pressure: Pressure,
+        timestep: Time) -> Result<(), TuasLibError>{
+
+        if let Material::Liquid(LiquidMaterial::WeaklyCompressible(liquid)) 
+            = self.material_control_volume {
+
+            let total_volume = self.total_length * self.xs_area;
+            let pressure_change = pressure - self.pressure_control_volume;
+
+            let mut node_power_vec: Vec<Power> = vec![];
+
+            for (temperature, volume_fraction) in self.temperature_array_current_timestep
+                .iter().zip(self.volume_fraction_array.iter()) {
+
+                let property_temperature = 
+                    self.get_property_evaluation_temperature(*temperature);
+                let thermal_expansion = liquid
+                    .try_get_thermal_expansion_coefficient(property_temperature)?;
+
+                let pressure_work_rate = try_get_pressure_work_rate(
+                    total_volume * *volume_fraction, 
+                    pressure_change, 
+                    timestep)?;
+
+                node_power_vec.push(pressure_work_rate 
+                    * thermal_expansion.get::<per_kelvin>() 
+                    * temperature.get::<kelvin>());
+            }
+
+            let total_power: Power = node_power_vec.iter().copied().sum();
+
+            if total_power.value != 0.0 {
+                let q_fraction_arr: Array1<f64> = node_power_vec.iter()
+                    .map(|node_power| (*node_power / total_power).get::<ratio>())
+                    .collect();
+
+                self.lateral_link_new_power_vector(total_power, q_fraction_arr)?;
+            }
+        }
+
+        self.pressure_control_volume = pressure;
+        self.back_single_cv.pressure_control_volume = pressure;
+        self.front_single_cv.pressure_control_volume = pressure;
+
+        Ok(())
+    }
+
     /// length of the fluid array 
     pub fn len(&self) -> usize {
         self.inner_nodes + 2
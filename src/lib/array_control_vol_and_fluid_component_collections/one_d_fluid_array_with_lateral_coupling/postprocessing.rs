@@ -1,4 +1,5 @@
 
+use crate::boussinesq_thermophysical_properties::density::try_get_rho;
 use crate::tuas_lib_error::TuasLibError;
 
 use super::FluidArray;
@@ -30,4 +31,44 @@ impl FluidArray {
         Ok(reversed_vec)
     }
 
+    /// obtains the mass of fluid within each node, 
+    /// density is evaluated at the current node temperature
+    pub fn try_get_node_mass_vector(&self) -> Result<Vec<Mass>,TuasLibError>{
+
+        let total_volume = self.total_length * self.xs_area;
+        let mut mass_vec: Vec<Mass> = vec![];
+
+        for (temperature, volume_fraction) in self.temperature_array_current_timestep
+            .iter().zip(self.volume_fraction_array.iter()) {
+
+            let density = try_get_rho(self.material_control_volume, 
+                self.get_property_evaluation_temperature(*temperature), 
+                self.pressure_control_volume)?;
+
+            mass_vec.push(density * total_volume * *volume_fraction);
+        }
+
+        Ok(mass_vec)
+    }
+
+    /// obtains the mass of fluid within the array, 
+    ///
+    /// the volume of the array is fixed, so as the fluid expands, 
+    /// the fall in its mass is the fluid pushed out into the rest 
+    /// of the loop (eg. into an expansion tank)
+    pub fn try_get_fluid_mass(&self) -> Result<Mass,TuasLibError>{
+
+        Ok(self.try_get_node_mass_vector()?.into_iter().sum())
+    }
+
+    /// obtains the specific enthalpy of each node, 
+    /// including latent heat if phase change is enabled
+    pub fn try_get_node_specific_enthalpy_vector(&self) -> 
+    Result<Vec<AvailableEnergy>,TuasLibError>{
+
+        (0..self.len()).map(|node_index| {
+            self.try_get_node_specific_enthalpy(node_index)
+        }).collect()
+    }
+
 }
@@ -63,7 +63,8 @@ impl FluidArray {
             |temperature_reference| {
 
                 try_get_alpha_thermal_diffusivity(control_vol_material, 
-                    *temperature_reference, 
+                    self.get_property_evaluation_temperature(
+                        *temperature_reference), 
                     control_vol_pressure).unwrap()
             }
 
@@ -114,6 +115,7 @@ impl FluidArray {
             });
 
         let bulk_temp = self.try_get_bulk_temperature()?;
+        let bulk_temp = self.get_property_evaluation_temperature(bulk_temp);
         let thermal_diffusivity_coeff: DiffusionCoefficient = 
         match thermal_diffusivity_coeff_opt {
             Some(alpha_reference) => *alpha_reference,
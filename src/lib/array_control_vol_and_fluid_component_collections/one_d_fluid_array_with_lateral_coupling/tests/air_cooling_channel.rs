@@ -0,0 +1,112 @@
+/// air flowing through a heated channel at low speed,
+///
+/// at steady state, the outlet temperature should satisfy the 
+/// energy balance 
+///
+/// Q = m (h_out - h_in)
+///
+/// this checks that gases (as ideal gases with temperature and 
+/// pressure dependent density) can be used within the FluidArray
+#[cfg(test)]
+#[test]
+pub fn heated_air_channel_energy_balance(){
+
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::boundary_conditions::BCType;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, Material, SolidMaterial};
+    use crate::boussinesq_thermophysical_properties::specific_enthalpy::try_get_h;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+    use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+    use ndarray::Array1;
+    use uom::si::angle::degree;
+    use uom::si::available_energy::joule_per_kilogram;
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::power::watt;
+    use uom::si::pressure::atmosphere;
+    use uom::si::ratio::ratio;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::time::second;
+
+    let air = LiquidMaterial::Air;
+    let inner_nodes: usize = 3;
+    let number_of_nodes = inner_nodes + 2;
+    let pressure = Pressure::new::<atmosphere>(1.0);
+    let inlet_temperature = ThermodynamicTemperature::new::<kelvin>(300.0);
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(1.0e-3);
+    let heater_power = Power::new::<watt>(100.0);
+
+    let mut air_channel: HeatTransferEntity = FluidArray::new_cylinder(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.02),
+        inlet_temperature,
+        pressure,
+        SolidMaterial::SteelSS304L,
+        air,
+        Ratio::new::<ratio>(0.0),
+        inner_nodes,
+        Angle::new::<degree>(0.0),
+    ).into();
+
+    let mut inlet_bc: HeatTransferEntity = BCType::new_const_temperature(
+        inlet_temperature).into();
+    let mut outlet_bc: HeatTransferEntity = BCType::new_adiabatic_bc().into();
+
+    // air has very little thermal inertia, so the timestep must be 
+    // small compared to the residence time of air in each node 
+    // (about 0.07 s here)
+    let timestep = Time::new::<second>(0.01);
+
+    for _ in 0..300 {
+
+        let temperature_vector = air_channel.get_temperature_vector().unwrap();
+        let back_cv_temperature = temperature_vector[0];
+        let front_cv_temperature = *temperature_vector.iter().last().unwrap();
+
+        let inlet_interaction = HeatTransferInteractionType::
+            new_advection_interaction(
+                mass_flowrate,
+                air.try_get_density(inlet_temperature).unwrap(),
+                air.try_get_density(back_cv_temperature).unwrap());
+
+        let front_cv_density = air.try_get_density(front_cv_temperature)
+            .unwrap();
+        let outlet_interaction = HeatTransferInteractionType::
+            new_advection_interaction(
+                mass_flowrate,
+                front_cv_density,
+                front_cv_density);
+
+        air_channel.try_set_flowrate_for_fluid_array(mass_flowrate).unwrap();
+        {
+            let mut q_fraction_arr: Array1<f64> = Array1::zeros(number_of_nodes);
+            q_fraction_arr.fill(1.0/number_of_nodes as f64);
+
+            let mut air_channel_clone: FluidArray =
+                air_channel.clone().try_into().unwrap();
+            air_channel_clone.lateral_link_new_power_vector(
+                heater_power, q_fraction_arr).unwrap();
+            air_channel = air_channel_clone.into();
+        }
+
+        air_channel.link_to_back(&mut inlet_bc, inlet_interaction).unwrap();
+        air_channel.link_to_front(&mut outlet_bc, outlet_interaction).unwrap();
+
+        air_channel.advance_timestep_mut_self(timestep).unwrap();
+    }
+
+    let outlet_temperature = *air_channel.get_temperature_vector().unwrap()
+        .iter().last().unwrap();
+
+    let air_material: Material = air.into();
+    let enthalpy_rise = try_get_h(air_material, outlet_temperature, pressure)
+        .unwrap() 
+        - try_get_h(air_material, inlet_temperature, pressure).unwrap();
+
+    // about 100 K of temperature rise
+    approx::assert_relative_eq!(
+        enthalpy_rise.get::<joule_per_kilogram>(),
+        (heater_power/mass_flowrate).get::<joule_per_kilogram>(),
+        max_relative=0.01);
+}
@@ -0,0 +1,97 @@
+/// cools a stagnant FLiBe filled fluid array until it freezes,
+///
+/// FLiBe is a eutectic, so the temperature should stay at the
+/// melting point while latent heat is removed. Flow resistance
+/// should rise sharply once the fluid is partially frozen and
+/// flow should be blocked once it is fully frozen
+#[cfg(test)]
+#[test]
+pub fn flibe_fluid_array_freezing_blocks_flow(){
+
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use ndarray::Array1;
+    use uom::si::angle::degree;
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::power::watt;
+    use uom::si::pressure::atmosphere;
+    use uom::si::ratio::ratio;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::time::second;
+
+    let inner_nodes: usize = 3;
+    let number_of_nodes = inner_nodes + 2;
+
+    let mut flibe_pipe = FluidArray::new_cylinder(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.02),
+        ThermodynamicTemperature::new::<kelvin>(740.0),
+        Pressure::new::<atmosphere>(1.0),
+        SolidMaterial::SteelSS304L,
+        LiquidMaterial::FLiBe,
+        Ratio::new::<ratio>(0.0),
+        inner_nodes,
+        Angle::new::<degree>(0.0),
+    );
+
+    flibe_pipe.enable_phase_change_from_database().unwrap();
+
+    let test_mass_flowrate = MassRate::new::<kilogram_per_second>(0.01);
+    let liquid_pressure_loss = flibe_pipe
+        .get_pressure_loss_immutable(test_mass_flowrate);
+
+    let melting_temperature = flibe_pipe.phase_change_properties
+        .unwrap().liquidus_temperature;
+
+    let cooling_power = Power::new::<watt>(-2000.0);
+    let timestep = Time::new::<second>(1.0);
+
+    let cool_pipe = |pipe: &mut FluidArray, number_of_steps: usize| {
+        for _ in 0..number_of_steps {
+            let mut q_fraction_arr: Array1<f64> = Array1::zeros(number_of_nodes);
+            q_fraction_arr.fill(1.0/number_of_nodes as f64);
+
+            pipe.lateral_link_new_power_vector(
+                cooling_power, q_fraction_arr).unwrap();
+            pipe.advance_timestep(timestep).unwrap();
+        }
+    };
+
+    // partially frozen, about half the latent heat is removed
+    cool_pipe(&mut flibe_pipe, 100);
+
+    for temperature in flibe_pipe.get_temperature_vector().unwrap() {
+        approx::assert_abs_diff_eq!(
+            temperature.get::<kelvin>(),
+            melting_temperature.get::<kelvin>(),
+            epsilon=1e-6);
+    }
+
+    let min_liquid_fraction = flibe_pipe.get_minimum_liquid_fraction()
+        .get::<ratio>();
+    assert!(min_liquid_fraction > 0.0 && min_liquid_fraction < 1.0);
+
+    let mushy_pressure_loss = flibe_pipe
+        .get_pressure_loss_immutable(test_mass_flowrate);
+
+    assert!(mushy_pressure_loss > 10.0 * liquid_pressure_loss);
+
+    // fully frozen, the salt should now cool below the melting point
+    cool_pipe(&mut flibe_pipe, 200);
+
+    for temperature in flibe_pipe.get_temperature_vector().unwrap() {
+        assert!(temperature < melting_temperature);
+    }
+
+    assert!(flibe_pipe.is_flow_blocked_by_frozen_fluid());
+
+    let frozen_mass_flowrate = flibe_pipe
+        .get_mass_flowrate_from_pressure_loss_immutable(
+            Pressure::new::<atmosphere>(1.0));
+
+    approx::assert_abs_diff_eq!(
+        frozen_mass_flowrate.get::<kilogram_per_second>(),
+        0.0);
+}
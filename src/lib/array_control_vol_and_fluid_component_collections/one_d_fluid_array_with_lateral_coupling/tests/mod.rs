@@ -29,3 +29,15 @@ pub mod joint_mixing;
 ///
 ///
 pub mod parasitic_heat_loss;
+
+/// freezing of salts within the fluid array, 
+/// where flow should be blocked once salt is frozen
+pub mod freezing;
+
+/// air as an ideal gas flowing through a heated channel,
+/// checked against a steady state energy balance
+pub mod air_cooling_channel;
+
+/// pressure work and compression of weakly compressible liquids
+/// within the fluid array
+pub mod weakly_compressible;
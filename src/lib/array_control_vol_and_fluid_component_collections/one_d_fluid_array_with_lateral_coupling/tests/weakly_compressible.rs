@@ -0,0 +1,86 @@
+/// stagnant water in a rigid pipe is compressed adiabatically from 
+/// 1 atm by 150 bar over 10 s,
+///
+/// every node should be heated by beta T dp/(rho cp), about 0.28 K,
+/// the same as for a single control volume, and the mass of water 
+/// in the pipe should rise as it is compressed
+#[cfg(test)]
+#[test]
+pub fn adiabatic_compression_of_weakly_compressible_water_in_pipe(){
+
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, Material, SolidMaterial};
+    use crate::boussinesq_thermophysical_properties::density::try_get_rho;
+    use crate::boussinesq_thermophysical_properties::specific_heat_capacity::try_get_cp;
+    use crate::boussinesq_thermophysical_properties::weakly_compressible::WeaklyCompressibleLiquid;
+    use uom::si::angle::degree;
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::{atmosphere, bar};
+    use uom::si::ratio::ratio;
+    use uom::si::temperature_coefficient::per_kelvin;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::time::second;
+
+    let compressible_water = WeaklyCompressibleLiquid::try_new_from_database(
+        LiquidMaterial::Water).unwrap();
+
+    let initial_temperature = ThermodynamicTemperature::new::<kelvin>(300.0);
+    let initial_pressure = Pressure::new::<atmosphere>(1.0);
+
+    let mut water_pipe = FluidArray::new_cylinder(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.02),
+        initial_temperature,
+        initial_pressure,
+        SolidMaterial::SteelSS304L,
+        compressible_water.into(),
+        Ratio::new::<ratio>(0.0),
+        3,
+        Angle::new::<degree>(0.0),
+    );
+
+    let initial_mass = water_pipe.try_get_fluid_mass().unwrap();
+
+    let pressure_rise = Pressure::new::<bar>(150.0);
+    let timestep = Time::new::<second>(1.0);
+    let number_of_steps = 10;
+
+    for step in 1..=number_of_steps {
+        let pressure = initial_pressure
+            + pressure_rise * (step as f64 / number_of_steps as f64);
+        water_pipe.try_set_pressure_with_pressure_work(pressure, timestep).unwrap();
+        water_pipe.advance_timestep_with_mass_flowrate(timestep, 
+            MassRate::new::<kilogram_per_second>(0.0)).unwrap();
+    }
+
+    let water: Material = LiquidMaterial::from(compressible_water).into();
+    let thermal_expansion = compressible_water
+        .try_get_thermal_expansion_coefficient(initial_temperature).unwrap();
+    let density = try_get_rho(water, initial_temperature, initial_pressure).unwrap();
+    let cp = try_get_cp(water, initial_temperature, initial_pressure).unwrap();
+
+    let expected_temperature_rise: f64 = thermal_expansion.get::<per_kelvin>()
+        * initial_temperature.get::<kelvin>()
+        * (pressure_rise / density / cp).value;
+
+    for temperature in water_pipe.get_temperature_vector().unwrap() {
+        approx::assert_relative_eq!(
+            temperature.get::<kelvin>() - 300.0,
+            expected_temperature_rise,
+            max_relative=0.02);
+    }
+
+    // compressing water adds about 0.68% to its mass
+    approx::assert_relative_eq!(
+        (water_pipe.try_get_fluid_mass().unwrap() / initial_mass).get::<ratio>(),
+        1.0068,
+        max_relative=2e-4);
+
+    // the front and back cvs should be at the new pressure too
+    assert_eq!(water_pipe.back_single_cv.pressure_control_volume, 
+        initial_pressure + pressure_rise);
+    assert_eq!(water_pipe.front_single_cv.pressure_control_volume, 
+        initial_pressure + pressure_rise);
+}
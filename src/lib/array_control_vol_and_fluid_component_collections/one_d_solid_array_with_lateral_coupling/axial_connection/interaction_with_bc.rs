@@ -8,6 +8,7 @@ use crate::single_control_vol::boundary_condition_interactions::constant_heat_ad
 use crate::single_control_vol::SingleCVNode;
 
 use crate::tuas_lib_error::TuasLibError;
+use crate::energy_audit::energy_tally::EnergyTallySource;
 
 impl SolidColumn {
 
@@ -64,6 +65,20 @@ impl SolidColumn {
                 return Err( TuasLibError::WrongHeatTransferInteractionType);
             },
 
+            HeatTransferInteractionType::DualCylindricalThermalConductanceWithInterfaceResistance(_) => 
+            {
+                println!("please specify interaction type as \n 
+                    UserSpecifiedHeatFluxCustomArea or Similar");
+                return Err( TuasLibError::WrongHeatTransferInteractionType);
+            },
+
+            HeatTransferInteractionType::CylindricalConductionConvectionWithFouling(_) => 
+            {
+                println!("please specify interaction type as \n 
+                    UserSpecifiedHeatFluxCustomArea or Similar");
+                return Err( TuasLibError::WrongHeatTransferInteractionType);
+            },
+
             HeatTransferInteractionType::UserSpecifiedHeatAddition => 
             {
                 println!("please specify interaction type as \n 
@@ -135,8 +150,9 @@ impl SolidColumn {
 
         // push the power power to the back cv 
 
-        back_cv_ref.rate_enthalpy_change_vector.push( 
-            heat_flowrate_into_control_vol);
+        back_cv_ref.push_rate_enthalpy_change( 
+            heat_flowrate_into_control_vol,
+            EnergyTallySource::HeatSource);
         // calculate conduction timescales
         back_cv_ref.calculate_mesh_stability_conduction_timestep_for_single_node_and_bc(
             interaction)?;
@@ -202,6 +218,20 @@ impl SolidColumn {
                 return Err( TuasLibError::WrongHeatTransferInteractionType);
             },
 
+            HeatTransferInteractionType::DualCylindricalThermalConductanceWithInterfaceResistance(_) => 
+            {
+                println!("please specify interaction type as \n 
+                    UserSpecifiedHeatFluxCustomArea or Similar");
+                return Err( TuasLibError::WrongHeatTransferInteractionType);
+            },
+
+            HeatTransferInteractionType::CylindricalConductionConvectionWithFouling(_) => 
+            {
+                println!("please specify interaction type as \n 
+                    UserSpecifiedHeatFluxCustomArea or Similar");
+                return Err( TuasLibError::WrongHeatTransferInteractionType);
+            },
+
             HeatTransferInteractionType::UserSpecifiedHeatAddition => 
             {
                 println!("please specify interaction type as \n 
@@ -273,8 +303,9 @@ impl SolidColumn {
 
         // push the power power to the back cv 
 
-        back_cv_ref.rate_enthalpy_change_vector.push( 
-            heat_flowrate_into_control_vol);
+        back_cv_ref.push_rate_enthalpy_change( 
+            heat_flowrate_into_control_vol,
+            EnergyTallySource::HeatSource);
         // calculate conduction timescales
         back_cv_ref.calculate_mesh_stability_conduction_timestep_for_single_node_and_bc(
             interaction)?;
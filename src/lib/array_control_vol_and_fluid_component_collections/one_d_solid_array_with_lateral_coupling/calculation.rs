@@ -11,6 +11,7 @@ use uom::si::power::watt;
 
 
 use crate::tuas_lib_error::TuasLibError;
+use crate::energy_audit::energy_tally::{EnergyTally, get_array_energy_tally_rates};
 use ndarray_linalg::error::LinalgError;
 
 use super::SolidColumn;
@@ -699,6 +700,19 @@ impl SolidColumn {
             solve_conductance_matrix_power_vector(
                 coefficient_matrix,power_source_vector)?;
 
+        // report what was applied this timestep before the 
+        // vectors are cleared
+        if let Some(energy_tally) = self.energy_tally.as_mut() {
+            energy_tally.last_timestep = get_array_energy_tally_rates(
+                &self.back_single_cv,
+                &self.front_single_cv,
+                &self.q_vector,
+                &self.q_fraction_vector,
+                &self.lateral_adjacent_array_conductance_vector,
+                &self.lateral_adjacent_array_temperature_vector,
+                &new_temperature_array);
+        }
+
         // update the single cvs at the front and back with new enthalpies 
 
         // Todo: probably need to synchronise error types in future
@@ -765,4 +779,13 @@ impl SolidColumn {
 
         Ok(())
     }
+
+    /// starts tallying the contributions to the rate of enthalpy 
+    /// change of the solid column by source, for use in energy audits,
+    /// this also starts the tallies of the back and front single cvs
+    pub fn enable_energy_tally(&mut self){
+        self.energy_tally = Some(EnergyTally::default());
+        self.back_single_cv.enable_energy_tally();
+        self.front_single_cv.enable_energy_tally();
+    }
 }
@@ -89,6 +89,7 @@ impl SolidColumn {
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
             q_fraction_vector: vec![],
+            energy_tally: None,
         }
 
     }
@@ -165,6 +166,7 @@ impl SolidColumn {
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
             q_fraction_vector: vec![],
+            energy_tally: None,
         }
 
     }
@@ -238,6 +240,7 @@ impl SolidColumn {
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
             q_fraction_vector: vec![],
+            energy_tally: None,
         }
 
     }
@@ -321,6 +324,7 @@ impl SolidColumn {
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
             q_fraction_vector: vec![],
+            energy_tally: None,
         }
 
     }
@@ -68,6 +68,7 @@ impl Default for SolidColumn {
             lateral_adjacent_array_conductance_vector: vec![],
             q_vector: vec![],
             q_fraction_vector: vec![],
+            energy_tally: None,
         }
 
     }
@@ -5,6 +5,7 @@ use uom::si::f64::*;
 use ndarray::*;
 
 use crate::tuas_lib_error::TuasLibError;
+use crate::energy_audit::energy_tally::EnergyTally;
 use ndarray_linalg::error::LinalgError;
 
 
@@ -112,6 +113,11 @@ pub struct SolidColumn {
     /// to their nodes 
     pub q_fraction_vector: Vec<Array1<f64>>,
 
+    /// tally of the contributions to the rate of enthalpy change 
+    /// by source, summed over all nodes, for energy audits, 
+    /// if None, contributions are not tallied
+    pub energy_tally: Option<EnergyTally>,
+
 }
 
 impl SolidColumn {
@@ -1,6 +1,8 @@
 use super::SolidColumn;
 use uom::si::f64::*;
 use crate::tuas_lib_error::TuasLibError;
+use crate::boussinesq_thermophysical_properties::density::try_get_rho;
+use crate::boussinesq_thermophysical_properties::specific_enthalpy::try_get_h;
 
 impl SolidColumn { 
     /// obtains a clone of the temperature vector within the CV 
@@ -26,5 +28,35 @@ impl SolidColumn {
         Ok(reversed_vec)
     }
 
+    /// obtains the mass of solid within each node, 
+    /// density is evaluated at the current node temperature
+    pub fn try_get_node_mass_vector(&self) -> Result<Vec<Mass>,TuasLibError>{
+
+        let total_volume = self.total_length * self.xs_area;
+        let mut mass_vec: Vec<Mass> = vec![];
+
+        for (temperature, volume_fraction) in self.temperature_array_current_timestep
+            .iter().zip(self.volume_fraction_array.iter()) {
+
+            let density = try_get_rho(self.material_control_volume, 
+                *temperature, 
+                self.pressure_control_volume)?;
+
+            mass_vec.push(density * total_volume * *volume_fraction);
+        }
+
+        Ok(mass_vec)
+    }
+
+    /// obtains the specific enthalpy of each node
+    pub fn try_get_node_specific_enthalpy_vector(&self) -> 
+    Result<Vec<AvailableEnergy>,TuasLibError>{
+
+        self.temperature_array_current_timestep.iter().map(|temperature| {
+            try_get_h(self.material_control_volume, 
+                *temperature, 
+                self.pressure_control_volume)
+        }).collect()
+    }
 
 }
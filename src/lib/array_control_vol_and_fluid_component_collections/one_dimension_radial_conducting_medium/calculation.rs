@@ -0,0 +1,179 @@
+use crate::array_control_vol_and_fluid_component_collections::standalone_fluid_nodes::solve_conductance_matrix_power_vector;
+use crate::boussinesq_thermophysical_properties::thermal_conductivity::try_get_kappa_thermal_conductivity;
+use crate::boussinesq_thermophysical_properties::volumetric_heat_capacity::try_get_rho_cp;
+use crate::tuas_lib_error::TuasLibError;
+use uom::si::f64::*;
+use ndarray::*;
+
+use super::RadialConduction1DArray;
+
+impl RadialConduction1DArray {
+
+    /// thermal conductivity of each node at the current timestep
+    /// temperature
+    pub(crate) fn try_get_thermal_conductivity_vector(&self) ->
+    Result<Vec<ThermalConductivity>, TuasLibError> {
+
+        self.temperature_array_current_timestep.iter()
+            .zip(self.node_region_indices.iter())
+            .map(|(temperature, region_index)| {
+                try_get_kappa_thermal_conductivity(
+                    self.regions[*region_index].material,
+                    *temperature,
+                    self.pressure_control_volume)
+            }).collect()
+    }
+
+    /// conductances between the middle of each node and the middle
+    /// of the next node (outwards), including contact conductances
+    /// at region interfaces
+    ///
+    /// there is one fewer conductance than nodes
+    pub(crate) fn try_get_internode_conductance_vector(&self) ->
+    Result<Vec<ThermalConductance>, TuasLibError> {
+
+        let thermal_conductivity_vector = self.try_get_thermal_conductivity_vector()?;
+        let node_radii = self.get_node_radii();
+
+        let mut conductance_vector: Vec<ThermalConductance> = vec![];
+
+        for inner_node in 0..self.len().saturating_sub(1) {
+            let outer_node = inner_node + 1;
+            let interface_radius = self.node_outer_radii[inner_node];
+
+            // thermal resistances in series, from the middle of the
+            // inner node to the interface, then to the middle of
+            // the outer node
+            let inner_half_conductance = self.geometry.get_shell_conductance(
+                node_radii[inner_node],
+                interface_radius,
+                thermal_conductivity_vector[inner_node]);
+
+            let outer_half_conductance = self.geometry.get_shell_conductance(
+                interface_radius,
+                node_radii[outer_node],
+                thermal_conductivity_vector[outer_node]);
+
+            let mut thermal_resistance = 1.0/inner_half_conductance
+                + 1.0/outer_half_conductance;
+
+            let inner_region = self.node_region_indices[inner_node];
+            if inner_region != self.node_region_indices[outer_node] {
+                if let Some(contact_conductance) =
+                    self.interface_contact_conductances[inner_region] {
+                    thermal_resistance += 1.0/(contact_conductance
+                        * self.geometry.get_surface_area(interface_radius));
+                }
+            }
+
+            conductance_vector.push(1.0/thermal_resistance);
+        }
+
+        Ok(conductance_vector)
+    }
+
+    /// conductance from the middle of the outermost node
+    /// to the outer surface
+    pub(crate) fn try_get_outermost_node_to_surface_conductance(&self) ->
+    Result<ThermalConductance, TuasLibError> {
+
+        let outermost_node = self.len() - 1;
+        let thermal_conductivity_vector = self.try_get_thermal_conductivity_vector()?;
+
+        Ok(self.geometry.get_shell_conductance(
+            self.get_node_radii()[outermost_node],
+            self.get_outer_radius(),
+            thermal_conductivity_vector[outermost_node]))
+    }
+
+    /// calculates the temperature array for the next timestep
+    /// and updates the temperatures of the current timestep
+    /// to be that of the next timestep
+    ///
+    /// the temperatures and heat transfer coefficients linked to the
+    /// outer surface are cleared afterwards
+    pub fn advance_timestep(&mut self,
+        timestep: Time) -> Result<(), TuasLibError> {
+
+        let number_of_nodes = self.len();
+        let dt = timestep;
+
+        let internode_conductance_vector =
+            self.try_get_internode_conductance_vector()?;
+
+        // conductances from the outermost node to each temperature
+        // linked to the outer surface,
+        // node to surface and surface to fluid in series
+        let outermost_node_to_surface_conductance =
+            self.try_get_outermost_node_to_surface_conductance()?;
+        let outer_surface_area = self.get_outer_surface_area();
+
+        let outer_link_conductance_vector: Vec<ThermalConductance> =
+            self.outer_surface_heat_transfer_coefficient_vector.iter()
+            .map(|heat_transfer_coeff| {
+                1.0/(1.0/outermost_node_to_surface_conductance
+                    + 1.0/(*heat_transfer_coeff * outer_surface_area))
+            }).collect();
+
+        let mut coefficient_matrix: Array2<ThermalConductance> =
+            Array::default((number_of_nodes, number_of_nodes));
+        let mut power_source_vector: Array1<Power> =
+            Array::default(number_of_nodes);
+
+        // energy balance for each node is:
+        //
+        // rho cp V dT/dt = \sum H (T_adjacent - T) + q''' V
+        //
+        // which is discretised with implicit Euler
+        //
+        // rho cp V T / dt + \sum H T - \sum H T_adjacent
+        // = rho cp V T_old / dt + q''' V
+        for node in 0..number_of_nodes {
+
+            let region = self.regions[self.node_region_indices[node]];
+            let node_volume: Volume = self.geometry.get_shell_volume(
+                self.node_inner_radii[node],
+                self.node_outer_radii[node]);
+            let rho_cp: VolumetricHeatCapacity = try_get_rho_cp(
+                region.material,
+                self.temperature_array_current_timestep[node],
+                self.pressure_control_volume)?;
+
+            coefficient_matrix[[node,node]] = rho_cp * node_volume / dt;
+
+            power_source_vector[node] =
+                self.temperature_array_current_timestep[node]
+                * rho_cp * node_volume / dt
+                + region.volumetric_heat_generation * node_volume;
+        }
+
+        for (inner_node, conductance) in internode_conductance_vector
+            .iter().enumerate() {
+            let outer_node = inner_node + 1;
+
+            coefficient_matrix[[inner_node,inner_node]] += *conductance;
+            coefficient_matrix[[outer_node,outer_node]] += *conductance;
+            coefficient_matrix[[inner_node,outer_node]] -= *conductance;
+            coefficient_matrix[[outer_node,inner_node]] -= *conductance;
+        }
+
+        let outermost_node = number_of_nodes - 1;
+        for (conductance, linked_temperature) in outer_link_conductance_vector
+            .iter().zip(self.outer_surface_temperature_vector.iter()) {
+
+            coefficient_matrix[[outermost_node,outermost_node]] += *conductance;
+            power_source_vector[outermost_node] += *conductance * *linked_temperature;
+        }
+
+        let new_temperature_array: Array1<ThermodynamicTemperature> =
+            solve_conductance_matrix_power_vector(
+                coefficient_matrix, power_source_vector)?;
+
+        self.temperature_array_current_timestep = new_temperature_array;
+
+        self.outer_surface_temperature_vector.clear();
+        self.outer_surface_heat_transfer_coefficient_vector.clear();
+
+        Ok(())
+    }
+}
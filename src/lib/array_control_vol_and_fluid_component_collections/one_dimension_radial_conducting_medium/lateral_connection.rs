@@ -0,0 +1,86 @@
+use uom::si::f64::*;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::RadialConduction1DArray;
+
+/// this implementation deals with lateral connections
+/// to the outer surface
+///
+/// at the end of the connection phase, one can then use
+/// the advance_timestep method to calculate the new
+/// temperature array
+impl RadialConduction1DArray {
+
+    /// connects the outer surface to a fluid (or other) temperature
+    /// with a given heat transfer coefficient
+    pub fn lateral_link_new_outer_surface_temperature(&mut self,
+        heat_transfer_coeff: HeatTransfer,
+        temperature: ThermodynamicTemperature) {
+
+        self.outer_surface_heat_transfer_coefficient_vector.push(heat_transfer_coeff);
+        self.outer_surface_temperature_vector.push(temperature);
+    }
+
+    /// conductance from the middle of the outermost node to a fluid
+    /// at the outer surface, given the heat transfer coefficient
+    /// at the surface
+    pub fn try_get_outer_surface_conductance(&self,
+        heat_transfer_coeff: HeatTransfer) -> Result<ThermalConductance, TuasLibError> {
+
+        let outermost_node_to_surface_conductance =
+            self.try_get_outermost_node_to_surface_conductance()?;
+
+        Ok(1.0/(1.0/outermost_node_to_surface_conductance
+            + 1.0/(heat_transfer_coeff * self.get_outer_surface_area())))
+    }
+
+    /// links the outer surface of this array to one node of a
+    /// FluidArray, in both directions
+    ///
+    /// number_of_elements is the number of identical pins or pebbles
+    /// represented by this array within the fluid node, the fluid
+    /// node sees the combined conductance of all of them
+    ///
+    /// like other lateral links, temperatures from the current
+    /// timestep are exchanged, so this should be done before advancing
+    /// either the fluid array or this array
+    pub fn try_lateral_link_to_fluid_array_node(&mut self,
+        fluid_array: &mut FluidArray,
+        node_index: usize,
+        heat_transfer_coeff: HeatTransfer,
+        number_of_elements: f64) -> Result<(), TuasLibError> {
+
+        let fluid_temperature_vector = fluid_array.get_temperature_vector()?;
+
+        let fluid_temperature = match fluid_temperature_vector.get(node_index) {
+            Some(temperature) => *temperature,
+            None => return Err(TuasLibError::GenericStringError(
+                format!("fluid array has no node {}", node_index))),
+        };
+
+        let outer_surface_conductance =
+            self.try_get_outer_surface_conductance(heat_transfer_coeff)?;
+        let outermost_node_temperature =
+            *self.temperature_array_current_timestep.last().unwrap();
+
+        // only the linked fluid node sees a conductance,
+        // the other nodes are linked to their own temperatures
+        // with zero conductance
+        let mut conductance_vector: Vec<ThermalConductance> =
+            vec![ThermalConductance::default(); fluid_temperature_vector.len()];
+        conductance_vector[node_index] = number_of_elements * outer_surface_conductance;
+
+        let mut temperature_vector = fluid_temperature_vector;
+        temperature_vector[node_index] = outermost_node_temperature;
+
+        fluid_array.lateral_link_new_temperature_vector_and_conductance_vector(
+            conductance_vector, temperature_vector)?;
+
+        self.lateral_link_new_outer_surface_temperature(
+            heat_transfer_coeff, fluid_temperature);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,364 @@
+use std::f64::consts::PI;
+
+use ndarray::*;
+use uom::si::f64::*;
+use uom::si::length::meter;
+use uom::si::volumetric_power_density::watt_per_cubic_meter;
+
+use crate::boussinesq_thermophysical_properties::Material;
+use crate::tuas_lib_error::TuasLibError;
+
+/// radial coordinate system of the conduction array
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum RadialGeometry {
+    /// cylinder (eg. fuel pin) of a given axial length,
+    /// heat conducts radially only
+    Cylindrical {
+        /// axial length of the cylinder
+        length: Length
+    },
+    /// sphere (eg. TRISO pebble), heat conducts radially only
+    Spherical,
+}
+
+impl RadialGeometry {
+
+    /// volume of a shell between two radii
+    #[inline]
+    pub fn get_shell_volume(&self, inner_radius: Length,
+        outer_radius: Length) -> Volume {
+        match self {
+            RadialGeometry::Cylindrical { length } => {
+                PI * (outer_radius * outer_radius
+                    - inner_radius * inner_radius) * *length
+            },
+            RadialGeometry::Spherical => {
+                4.0/3.0 * PI * (outer_radius * outer_radius * outer_radius
+                    - inner_radius * inner_radius * inner_radius)
+            },
+        }
+    }
+
+    /// area of the surface at a given radius
+    #[inline]
+    pub fn get_surface_area(&self, radius: Length) -> Area {
+        match self {
+            RadialGeometry::Cylindrical { length } => {
+                2.0 * PI * radius * *length
+            },
+            RadialGeometry::Spherical => {
+                4.0 * PI * radius * radius
+            },
+        }
+    }
+
+    /// conductance of a shell of uniform thermal conductivity
+    /// between two radii
+    ///
+    /// cylinder: 2 pi k L / ln (r_outer/r_inner)
+    ///
+    /// sphere: 4 pi k / (1/r_inner - 1/r_outer)
+    #[inline]
+    pub fn get_shell_conductance(&self, inner_radius: Length,
+        outer_radius: Length,
+        thermal_conductivity: ThermalConductivity) -> ThermalConductance {
+        match self {
+            RadialGeometry::Cylindrical { length } => {
+                let log_radius_ratio: f64 = (outer_radius/inner_radius).value.ln();
+                2.0 * PI * thermal_conductivity * *length / log_radius_ratio
+            },
+            RadialGeometry::Spherical => {
+                4.0 * PI * thermal_conductivity
+                    / (1.0/inner_radius - 1.0/outer_radius)
+            },
+        }
+    }
+}
+
+/// a region of uniform material within the radial conduction array,
+/// for example, the fuel, gap and cladding of a fuel pin, or
+/// the kernel, matrix and shell of a pebble
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct RadialConductionRegion {
+    /// material of the region
+    pub material: Material,
+    /// inner radius of the region
+    pub inner_radius: Length,
+    /// outer radius of the region
+    pub outer_radius: Length,
+    /// number of nodes the region is divided into,
+    /// nodes are of equal thickness
+    pub number_of_nodes: usize,
+    /// heat generated per unit volume within the region
+    pub volumetric_heat_generation: VolumetricPowerDensity,
+}
+
+impl RadialConductionRegion {
+    /// creates a new region without heat generation
+    pub fn new(material: Material,
+        inner_radius: Length,
+        outer_radius: Length,
+        number_of_nodes: usize) -> Self {
+        Self {
+            material,
+            inner_radius,
+            outer_radius,
+            number_of_nodes,
+            volumetric_heat_generation:
+                VolumetricPowerDensity::new::<watt_per_cubic_meter>(0.0),
+        }
+    }
+}
+
+/// for 1D radial conduction arrays, in cylindrical or
+/// spherical coordinates
+///
+/// the structure is made of one or more material regions
+/// (eg. fuel, gap and cladding), each divided into nodes of equal
+/// thickness. Each node may generate heat, and a contact
+/// conductance (W/(m^2 K)) may be set at the interfaces
+/// between regions to account for contact resistance
+///
+/// nodes are finite volumes, conductances between nodes are
+/// calculated from the exact shell conductance from the middle
+/// radius of one node to the interface, and from there to the
+/// middle radius of the next node
+///
+/// the inner surface is adiabatic (eg. the centreline of a pin
+/// or pebble), while the outer surface can be laterally linked to
+/// fluid temperatures (eg. from a FluidArray node) with a heat
+/// transfer coefficient
+///
+/// the scheme used is the implicit Euler scheme with material
+/// properties evaluated at the current timestep temperatures,
+/// the same as the other array cvs
+#[derive(Debug,Clone,PartialEq)]
+pub struct RadialConduction1DArray {
+
+    /// cylindrical or spherical coordinates
+    pub geometry: RadialGeometry,
+
+    /// material regions from the innermost to outermost
+    pub regions: Vec<RadialConductionRegion>,
+
+    /// contact conductance at the interface between region i and
+    /// region i+1, None means perfect contact
+    pub interface_contact_conductances: Vec<Option<HeatTransfer>>,
+
+    /// control volume pressure
+    pub pressure_control_volume: Pressure,
+
+    /// temperature array current timestep, from innermost
+    /// to outermost node
+    pub(crate) temperature_array_current_timestep: Array1<ThermodynamicTemperature>,
+
+    // region which each node belongs to
+    node_region_indices: Vec<usize>,
+
+    // inner and outer radii of each node
+    node_inner_radii: Vec<Length>,
+    node_outer_radii: Vec<Length>,
+
+    /// temperatures laterally linked to the outer surface
+    /// (eg. fluid temperatures), cleared every timestep
+    pub outer_surface_temperature_vector: Vec<ThermodynamicTemperature>,
+
+    /// heat transfer coefficients for the temperatures linked to the
+    /// outer surface, cleared every timestep
+    pub outer_surface_heat_transfer_coefficient_vector: Vec<HeatTransfer>,
+}
+
+impl RadialConduction1DArray {
+
+    /// constructs a new radial conduction array from its regions,
+    /// which must be contiguous and ordered from innermost to outermost
+    pub fn new(geometry: RadialGeometry,
+        regions: Vec<RadialConductionRegion>,
+        initial_uniform_temperature: ThermodynamicTemperature,
+        uniform_pressure: Pressure) -> Result<Self, TuasLibError> {
+
+        let (node_region_indices, node_inner_radii, node_outer_radii) =
+            try_get_radial_node_boundaries(&regions)?;
+
+        let number_of_nodes = node_region_indices.len();
+        let mut temperature_array: Array1<ThermodynamicTemperature> =
+            Array::default(number_of_nodes);
+        temperature_array.fill(initial_uniform_temperature);
+
+        Ok(Self {
+            geometry,
+            interface_contact_conductances: vec![None; regions.len()-1],
+            regions,
+            pressure_control_volume: uniform_pressure,
+            temperature_array_current_timestep: temperature_array,
+            node_region_indices,
+            node_inner_radii,
+            node_outer_radii,
+            outer_surface_temperature_vector: vec![],
+            outer_surface_heat_transfer_coefficient_vector: vec![],
+        })
+    }
+
+    /// number of temperature nodes
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.node_region_indices.len()
+    }
+
+    /// sets the contact conductance at the interface between region
+    /// interface_index and region interface_index + 1
+    ///
+    /// for example, a fuel pellet to cladding gap conductance
+    pub fn try_set_interface_contact_conductance(&mut self,
+        interface_index: usize,
+        contact_conductance: HeatTransfer) -> Result<(), TuasLibError> {
+
+        match self.interface_contact_conductances.get_mut(interface_index) {
+            Some(conductance) => {
+                *conductance = Some(contact_conductance);
+                Ok(())
+            },
+            None => Err(TuasLibError::GenericStringError(
+                format!("interface {} does not exist", interface_index))),
+        }
+    }
+
+    /// sets the heat generated per unit volume within a region
+    pub fn try_set_volumetric_heat_generation(&mut self,
+        region_index: usize,
+        volumetric_heat_generation: VolumetricPowerDensity) -> Result<(), TuasLibError> {
+
+        match self.regions.get_mut(region_index) {
+            Some(region) => {
+                region.volumetric_heat_generation = volumetric_heat_generation;
+                Ok(())
+            },
+            None => Err(TuasLibError::GenericStringError(
+                format!("region {} does not exist", region_index))),
+        }
+    }
+
+    /// sets the total heat generated within a region,
+    /// which is spread uniformly over its volume
+    pub fn try_set_region_power(&mut self,
+        region_index: usize,
+        power: Power) -> Result<(), TuasLibError> {
+
+        let region_volume: Volume = match self.regions.get(region_index) {
+            Some(region) => self.geometry.get_shell_volume(
+                region.inner_radius, region.outer_radius),
+            None => return Err(TuasLibError::GenericStringError(
+                format!("region {} does not exist", region_index))),
+        };
+
+        self.try_set_volumetric_heat_generation(region_index,
+            power/region_volume)
+    }
+
+    /// total heat generated over all regions
+    pub fn get_total_heat_generation(&self) -> Power {
+        self.regions.iter().map(|region| {
+            region.volumetric_heat_generation
+                * self.geometry.get_shell_volume(
+                    region.inner_radius, region.outer_radius)
+        }).sum()
+    }
+
+    /// outer radius of the array
+    #[inline]
+    pub fn get_outer_radius(&self) -> Length {
+        *self.node_outer_radii.last().unwrap()
+    }
+
+    /// area of the outer surface of the array
+    #[inline]
+    pub fn get_outer_surface_area(&self) -> Area {
+        self.geometry.get_surface_area(self.get_outer_radius())
+    }
+
+    /// region which each node belongs to
+    pub fn get_node_region_indices(&self) -> Vec<usize> {
+        self.node_region_indices.clone()
+    }
+
+    /// radius at the middle of each node, temperatures are
+    /// taken to be at these radii
+    pub fn get_node_radii(&self) -> Vec<Length> {
+        self.node_inner_radii.iter().zip(self.node_outer_radii.iter())
+            .map(|(inner_radius, outer_radius)| 0.5 * (*inner_radius + *outer_radius))
+            .collect()
+    }
+
+}
+
+/// divides contiguous regions, ordered from innermost to outermost,
+/// into nodes of equal thickness within each region
+///
+/// returns the region index, inner radius and outer radius
+/// of each node
+pub(crate) fn try_get_radial_node_boundaries(
+    regions: &[RadialConductionRegion]) ->
+Result<(Vec<usize>, Vec<Length>, Vec<Length>), TuasLibError> {
+
+    if regions.is_empty() {
+        return Err(TuasLibError::GenericStringError(
+            "radial conduction array needs at least one region".to_owned()));
+    }
+
+    let mut node_region_indices: Vec<usize> = vec![];
+    let mut node_inner_radii: Vec<Length> = vec![];
+    let mut node_outer_radii: Vec<Length> = vec![];
+
+    for (region_index, region) in regions.iter().enumerate() {
+
+        if region.number_of_nodes == 0 {
+            return Err(TuasLibError::GenericStringError(
+                format!("region {} has no nodes", region_index)));
+        }
+
+        if region.inner_radius.get::<meter>() < 0.0 ||
+            region.outer_radius <= region.inner_radius {
+            return Err(TuasLibError::GenericStringError(
+                format!("region {} must have 0 <= inner radius < outer radius",
+                    region_index)));
+        }
+
+        if region_index > 0 {
+            let previous_outer_radius = regions[region_index-1].outer_radius;
+            let gap: f64 = ((region.inner_radius - previous_outer_radius)
+                / region.outer_radius).value;
+            if gap.abs() > 1e-9 {
+                return Err(TuasLibError::GenericStringError(
+                    format!("region {} does not start where region {} ends",
+                        region_index, region_index-1)));
+            }
+        }
+
+        let node_thickness: Length = (region.outer_radius - region.inner_radius)
+            / region.number_of_nodes as f64;
+
+        for node in 0..region.number_of_nodes {
+            node_region_indices.push(region_index);
+            node_inner_radii.push(region.inner_radius + node as f64 * node_thickness);
+            node_outer_radii.push(region.inner_radius + (node+1) as f64 * node_thickness);
+        }
+    }
+
+    Ok((node_region_indices, node_inner_radii, node_outer_radii))
+}
+
+/// Functions or methods to retrieve temperature and other such
+/// data from the array
+pub mod postprocessing;
+
+/// Contains functions which advance the timestep
+/// it's the bulk of calculation
+pub mod calculation;
+
+/// Functions to link the outer surface of the array laterally
+/// to fluid temperatures, including FluidArray nodes
+pub mod lateral_connection;
+
+/// contains tests for the radial conduction array
+#[cfg(test)]
+pub mod tests;
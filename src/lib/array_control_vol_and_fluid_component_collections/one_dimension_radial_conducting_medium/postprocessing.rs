@@ -0,0 +1,63 @@
+use uom::si::f64::*;
+use uom::si::thermodynamic_temperature::kelvin;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::RadialConduction1DArray;
+
+impl RadialConduction1DArray {
+
+    /// obtains a clone of the temperature vector, from the
+    /// innermost to the outermost node
+    pub fn get_temperature_vector(&self) -> Result<
+    Vec<ThermodynamicTemperature>,TuasLibError>{
+
+        Ok(self.temperature_array_current_timestep.to_vec())
+    }
+
+    /// obtains the maximum node temperature, eg. the fuel
+    /// centreline temperature
+    pub fn get_maximum_temperature(&self) -> ThermodynamicTemperature {
+
+        self.temperature_array_current_timestep.iter()
+            .fold(self.temperature_array_current_timestep[0],
+                |max_temperature, temperature| {
+                    if *temperature > max_temperature {
+                        *temperature
+                    } else {
+                        max_temperature
+                    }
+                })
+    }
+
+    /// obtains the volume averaged temperature of a region,
+    /// eg. the average fuel temperature for Doppler feedback
+    pub fn try_get_region_average_temperature(&self,
+        region_index: usize) -> Result<ThermodynamicTemperature, TuasLibError> {
+
+        let mut region_volume: Volume = Volume::default();
+        let mut volume_weighted_temperature_sum: f64 = 0.0;
+
+        for node in 0..self.len() {
+            if self.node_region_indices[node] != region_index {
+                continue;
+            }
+
+            let node_volume = self.geometry.get_shell_volume(
+                self.node_inner_radii[node],
+                self.node_outer_radii[node]);
+
+            region_volume += node_volume;
+            volume_weighted_temperature_sum += node_volume.value
+                * self.temperature_array_current_timestep[node].get::<kelvin>();
+        }
+
+        if region_volume.value == 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                format!("region {} does not exist", region_index)));
+        }
+
+        Ok(ThermodynamicTemperature::new::<kelvin>(
+            volume_weighted_temperature_sum / region_volume.value))
+    }
+}
@@ -0,0 +1,337 @@
+use std::f64::consts::PI;
+
+use uom::si::f64::*;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::length::{meter, millimeter};
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::power::watt;
+use uom::si::pressure::atmosphere;
+use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::time::second;
+use uom::si::volumetric_power_density::watt_per_cubic_meter;
+
+use crate::boussinesq_thermophysical_properties::{Material, SolidMaterial};
+
+use super::*;
+
+fn constant_cp(_temperature: ThermodynamicTemperature) -> SpecificHeatCapacity {
+    SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(300.0)
+}
+
+fn constant_rho(_temperature: ThermodynamicTemperature) -> MassDensity {
+    MassDensity::new::<kilogram_per_cubic_meter>(10000.0)
+}
+
+fn kernel_conductivity(_temperature: ThermodynamicTemperature) -> ThermalConductivity {
+    ThermalConductivity::new::<watt_per_meter_kelvin>(3.0)
+}
+
+fn shell_conductivity(_temperature: ThermodynamicTemperature) -> ThermalConductivity {
+    ThermalConductivity::new::<watt_per_meter_kelvin>(30.0)
+}
+
+/// constant property solid so that results can be compared with
+/// analytical solutions
+fn constant_property_solid(
+    thermal_conductivity: fn(ThermodynamicTemperature) -> ThermalConductivity)
+-> Material {
+    SolidMaterial::CustomSolid(
+        (ThermodynamicTemperature::new::<kelvin>(200.0),
+        ThermodynamicTemperature::new::<kelvin>(3000.0)),
+        constant_cp,
+        thermal_conductivity,
+        constant_rho,
+        Length::new::<millimeter>(0.001),
+    ).into()
+}
+
+/// a fuel pin with uniform heat generation should reach the
+/// steady state profile
+///
+/// T(r) = T_fluid + Q/(hA) + q''' (R^2 - r^2) / (4 k)
+#[test]
+pub fn cylindrical_pin_steady_state_matches_analytical(){
+
+    let pin_radius = Length::new::<meter>(0.005);
+    let geometry = RadialGeometry::Cylindrical { length: Length::new::<meter>(1.0) };
+    let fuel = RadialConductionRegion::new(
+        constant_property_solid(kernel_conductivity),
+        Length::new::<meter>(0.0),
+        pin_radius,
+        20);
+
+    let fluid_temperature = ThermodynamicTemperature::new::<kelvin>(600.0);
+    let heat_transfer_coeff = HeatTransfer::new::<watt_per_square_meter_kelvin>(30000.0);
+    let q_triple_prime = VolumetricPowerDensity::new::<watt_per_cubic_meter>(2.0e8);
+
+    let mut pin = RadialConduction1DArray::new(geometry,
+        vec![fuel],
+        fluid_temperature,
+        Pressure::new::<atmosphere>(1.0)).unwrap();
+    pin.try_set_volumetric_heat_generation(0, q_triple_prime).unwrap();
+
+    let pin_power = pin.get_total_heat_generation();
+    approx::assert_relative_eq!(pin_power.get::<watt>(),
+        2.0e8 * PI * 0.005_f64.powi(2), max_relative=1e-12);
+
+    for _ in 0..50 {
+        pin.lateral_link_new_outer_surface_temperature(
+            heat_transfer_coeff, fluid_temperature);
+        pin.advance_timestep(Time::new::<second>(100.0)).unwrap();
+    }
+
+    let film_temperature_rise = (pin_power
+        / (heat_transfer_coeff * pin.get_outer_surface_area())).value;
+    let temperature_vector = pin.get_temperature_vector().unwrap();
+    let centreline_temperature_rise = film_temperature_rise
+        + 2.0e8 * 0.005_f64.powi(2) / (4.0 * 3.0);
+
+    // within 1% of the centreline temperature rise
+    for (radius, temperature) in pin.get_node_radii().iter()
+        .zip(temperature_vector.iter()) {
+
+        let r = radius.get::<meter>();
+        let analytical_temperature_rise = film_temperature_rise
+            + 2.0e8 * (0.005_f64.powi(2) - r * r) / (4.0 * 3.0);
+
+        approx::assert_abs_diff_eq!(
+            temperature.get::<kelvin>() - 600.0,
+            analytical_temperature_rise,
+            epsilon=0.01 * centreline_temperature_rise);
+    }
+
+    // about 430 K above the fluid at the centreline
+    assert_eq!(pin.get_maximum_temperature(), temperature_vector[0]);
+    approx::assert_relative_eq!(
+        pin.get_maximum_temperature().get::<kelvin>(),
+        600.0 + centreline_temperature_rise,
+        max_relative=0.01);
+
+    // at steady state, all the heat generated leaves the surface
+    let surface_conductance = pin.try_get_outer_surface_conductance(
+        heat_transfer_coeff).unwrap();
+    let surface_temperature_difference = TemperatureInterval::new::<
+        uom::si::temperature_interval::kelvin>(
+        temperature_vector.last().unwrap().get::<kelvin>()
+        - fluid_temperature.get::<kelvin>());
+    let heat_out: Power = surface_conductance * surface_temperature_difference;
+    approx::assert_relative_eq!(heat_out.get::<watt>(),
+        pin_power.get::<watt>(), max_relative=1e-6);
+}
+
+/// a pebble with a heat generating kernel and a non heat generating
+/// shell, with a contact resistance between them, should reach
+/// the steady state profile
+///
+/// shell: T(r) = T_surface + Q/(4 pi k_shell) (1/r - 1/R_pebble)
+///
+/// kernel: T(r) = T(R_kernel) + Q/(h_c A_kernel)
+/// + q''' (R_kernel^2 - r^2) / (6 k_kernel)
+#[test]
+pub fn spherical_pebble_with_contact_resistance_matches_analytical(){
+
+    let kernel_radius = Length::new::<meter>(0.025);
+    let pebble_radius = Length::new::<meter>(0.030);
+
+    let kernel = RadialConductionRegion::new(
+        constant_property_solid(kernel_conductivity),
+        Length::new::<meter>(0.0),
+        kernel_radius,
+        20);
+    let shell = RadialConductionRegion::new(
+        constant_property_solid(shell_conductivity),
+        kernel_radius,
+        pebble_radius,
+        5);
+
+    let fluid_temperature = ThermodynamicTemperature::new::<kelvin>(900.0);
+    let heat_transfer_coeff = HeatTransfer::new::<watt_per_square_meter_kelvin>(2000.0);
+    let contact_conductance = HeatTransfer::new::<watt_per_square_meter_kelvin>(5000.0);
+    let pebble_power = Power::new::<watt>(1000.0);
+
+    let mut pebble = RadialConduction1DArray::new(RadialGeometry::Spherical,
+        vec![kernel, shell],
+        fluid_temperature,
+        Pressure::new::<atmosphere>(1.0)).unwrap();
+    pebble.try_set_interface_contact_conductance(0, contact_conductance).unwrap();
+    pebble.try_set_region_power(0, pebble_power).unwrap();
+
+    // there is only one interface
+    assert!(pebble.try_set_interface_contact_conductance(1,
+        contact_conductance).is_err());
+    approx::assert_relative_eq!(pebble.get_total_heat_generation().get::<watt>(),
+        1000.0, max_relative=1e-12);
+
+    for _ in 0..100 {
+        pebble.lateral_link_new_outer_surface_temperature(
+            heat_transfer_coeff, fluid_temperature);
+        pebble.advance_timestep(Time::new::<second>(100.0)).unwrap();
+    }
+
+    let q: f64 = 1000.0;
+    let r_kernel: f64 = 0.025;
+    let r_pebble: f64 = 0.030;
+    let q_triple_prime = q / (4.0/3.0 * PI * r_kernel.powi(3));
+    let surface_temperature = 900.0 + q / (2000.0 * 4.0 * PI * r_pebble * r_pebble);
+    let shell_temperature = |r: f64| {
+        surface_temperature + q / (4.0 * PI * 30.0) * (1.0/r - 1.0/r_pebble)
+    };
+    let kernel_surface_temperature = shell_temperature(r_kernel)
+        + q / (5000.0 * 4.0 * PI * r_kernel * r_kernel);
+    let kernel_temperature = |r: f64| {
+        kernel_surface_temperature
+            + q_triple_prime * (r_kernel * r_kernel - r * r) / (6.0 * 3.0)
+    };
+
+    let temperature_vector = pebble.get_temperature_vector().unwrap();
+    let region_indices = pebble.get_node_region_indices();
+
+    for ((radius, temperature), region_index) in pebble.get_node_radii().iter()
+        .zip(temperature_vector.iter()).zip(region_indices.iter()) {
+
+        let r = radius.get::<meter>();
+        let analytical_temperature = match region_index {
+            0 => kernel_temperature(r),
+            _ => shell_temperature(r),
+        };
+
+        approx::assert_relative_eq!(
+            temperature.get::<kelvin>() - 900.0,
+            analytical_temperature - 900.0,
+            max_relative=0.01);
+    }
+
+    // the kernel is hotter than the shell on average
+    let kernel_average = pebble.try_get_region_average_temperature(0).unwrap();
+    let shell_average = pebble.try_get_region_average_temperature(1).unwrap();
+    assert!(kernel_average > shell_average);
+    assert!(pebble.try_get_region_average_temperature(2).is_err());
+}
+
+/// regions must be contiguous
+#[test]
+pub fn radial_regions_must_be_contiguous(){
+
+    let kernel = RadialConductionRegion::new(
+        constant_property_solid(kernel_conductivity),
+        Length::new::<meter>(0.0),
+        Length::new::<meter>(0.025),
+        5);
+    let shell = RadialConductionRegion::new(
+        constant_property_solid(shell_conductivity),
+        Length::new::<meter>(0.026),
+        Length::new::<meter>(0.030),
+        5);
+
+    assert!(RadialConduction1DArray::new(RadialGeometry::Spherical,
+        vec![kernel, shell],
+        ThermodynamicTemperature::new::<kelvin>(900.0),
+        Pressure::new::<atmosphere>(1.0)).is_err());
+}
+
+/// a heated pin linked to the middle node of a fluid array should,
+/// at steady state, raise the fluid enthalpy by the pin power
+#[test]
+pub fn pin_linked_to_fluid_array_node_energy_balance(){
+
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::boundary_conditions::BCType;
+    use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+    use crate::boussinesq_thermophysical_properties::specific_enthalpy::try_get_h;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+    use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+    use uom::si::angle::degree;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::ratio::ratio;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+
+    let therminol = LiquidMaterial::TherminolVP1;
+    let pressure = Pressure::new::<atmosphere>(1.0);
+    let inlet_temperature = ThermodynamicTemperature::new::<degree_celsius>(80.0);
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.02);
+    let heat_transfer_coeff = HeatTransfer::new::<watt_per_square_meter_kelvin>(1000.0);
+    let linked_node: usize = 2;
+
+    let mut channel: HeatTransferEntity = FluidArray::new_cylinder(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.02),
+        inlet_temperature,
+        pressure,
+        SolidMaterial::SteelSS304L,
+        therminol,
+        Ratio::new::<ratio>(0.0),
+        3,
+        Angle::new::<degree>(0.0),
+    ).into();
+
+    let mut pin = RadialConduction1DArray::new(
+        RadialGeometry::Cylindrical { length: Length::new::<meter>(0.2) },
+        vec![RadialConductionRegion::new(
+            constant_property_solid(kernel_conductivity),
+            Length::new::<meter>(0.0),
+            Length::new::<meter>(0.005),
+            10)],
+        inlet_temperature,
+        pressure).unwrap();
+    pin.try_set_region_power(0, Power::new::<watt>(500.0)).unwrap();
+
+    let mut inlet_bc: HeatTransferEntity = BCType::new_const_temperature(
+        inlet_temperature).into();
+    let mut outlet_bc: HeatTransferEntity = BCType::new_adiabatic_bc().into();
+    let timestep = Time::new::<second>(0.5);
+
+    for _ in 0..2000 {
+
+        let temperature_vector = channel.get_temperature_vector().unwrap();
+        let back_cv_temperature = temperature_vector[0];
+        let front_cv_temperature = *temperature_vector.iter().last().unwrap();
+
+        let inlet_interaction = HeatTransferInteractionType::
+            new_advection_interaction(
+                mass_flowrate,
+                therminol.try_get_density(inlet_temperature).unwrap(),
+                therminol.try_get_density(back_cv_temperature).unwrap());
+        let front_cv_density = therminol.try_get_density(front_cv_temperature)
+            .unwrap();
+        let outlet_interaction = HeatTransferInteractionType::
+            new_advection_interaction(
+                mass_flowrate,
+                front_cv_density,
+                front_cv_density);
+
+        channel.try_set_flowrate_for_fluid_array(mass_flowrate).unwrap();
+        {
+            let mut channel_clone: FluidArray =
+                channel.clone().try_into().unwrap();
+            pin.try_lateral_link_to_fluid_array_node(&mut channel_clone,
+                linked_node, heat_transfer_coeff, 1.0).unwrap();
+            channel = channel_clone.into();
+        }
+
+        channel.link_to_back(&mut inlet_bc, inlet_interaction).unwrap();
+        channel.link_to_front(&mut outlet_bc, outlet_interaction).unwrap();
+
+        channel.advance_timestep_mut_self(timestep).unwrap();
+        pin.advance_timestep(timestep).unwrap();
+    }
+
+    let fluid_temperature_vector = channel.get_temperature_vector().unwrap();
+    let outlet_temperature = *fluid_temperature_vector.last().unwrap();
+
+    // nodes upstream of the pin are not heated
+    approx::assert_relative_eq!(fluid_temperature_vector[1].get::<kelvin>(),
+        inlet_temperature.get::<kelvin>(), max_relative=1e-6);
+    assert!(pin.get_maximum_temperature() > fluid_temperature_vector[linked_node]);
+
+    let therminol_material: Material = therminol.into();
+    let enthalpy_rise = try_get_h(therminol_material, outlet_temperature, pressure)
+        .unwrap()
+        - try_get_h(therminol_material, inlet_temperature, pressure).unwrap();
+
+    approx::assert_relative_eq!(
+        (mass_flowrate * enthalpy_rise).get::<watt>(),
+        500.0,
+        max_relative=0.01);
+}
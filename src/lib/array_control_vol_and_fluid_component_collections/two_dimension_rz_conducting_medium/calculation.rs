@@ -0,0 +1,256 @@
+use crate::array_control_vol_and_fluid_component_collections::standalone_fluid_nodes::solve_conductance_matrix_power_vector;
+use crate::boussinesq_thermophysical_properties::thermal_conductivity::try_get_kappa_thermal_conductivity;
+use crate::boussinesq_thermophysical_properties::volumetric_heat_capacity::try_get_rho_cp;
+use crate::tuas_lib_error::TuasLibError;
+use std::f64::consts::PI;
+use uom::si::f64::*;
+use ndarray::*;
+
+use super::RZConduction2DMesh;
+
+impl RZConduction2DMesh {
+
+    /// thermal conductivity of each node at the current timestep
+    /// temperature, indexed by [radial node, axial node]
+    pub(crate) fn try_get_thermal_conductivity_array(&self) ->
+    Result<Array2<ThermalConductivity>, TuasLibError> {
+
+        let mut thermal_conductivity_array: Array2<ThermalConductivity> =
+            Array::default((self.radial_len(), self.axial_len()));
+
+        for ((radial_node, axial_node), temperature) in
+            self.temperature_array_current_timestep.indexed_iter() {
+
+            thermal_conductivity_array[[radial_node, axial_node]] =
+                try_get_kappa_thermal_conductivity(
+                    self.regions[self.node_region_indices[radial_node]].material,
+                    *temperature,
+                    self.pressure_control_volume)?;
+        }
+
+        Ok(thermal_conductivity_array)
+    }
+
+    /// conductance from the middle of each innermost node to a
+    /// fluid at the inner surface, one per axial node, given the
+    /// heat transfer coefficients at the surface
+    pub fn try_get_inner_surface_conductance_vector(&self,
+        heat_transfer_coeff_arr: &Array1<HeatTransfer>) ->
+    Result<Array1<ThermalConductance>, TuasLibError> {
+
+        let geometry = self.get_axial_slice_geometry();
+        let thermal_conductivity_array = self.try_get_thermal_conductivity_array()?;
+        let inner_radius = self.get_inner_radius();
+        let inner_surface_area = geometry.get_surface_area(inner_radius);
+        let node_radius = self.get_node_radii()[0];
+
+        Ok(heat_transfer_coeff_arr.iter().enumerate().map(
+            |(axial_node, heat_transfer_coeff)| {
+                let node_to_surface_conductance = geometry.get_shell_conductance(
+                    inner_radius,
+                    node_radius,
+                    thermal_conductivity_array[[0, axial_node]]);
+
+                1.0/(1.0/node_to_surface_conductance
+                    + 1.0/(*heat_transfer_coeff * inner_surface_area))
+            }).collect())
+    }
+
+    /// conductance from the middle of each outermost node to a
+    /// fluid at the outer surface, one per axial node, given the
+    /// heat transfer coefficients at the surface
+    pub fn try_get_outer_surface_conductance_vector(&self,
+        heat_transfer_coeff_arr: &Array1<HeatTransfer>) ->
+    Result<Array1<ThermalConductance>, TuasLibError> {
+
+        let geometry = self.get_axial_slice_geometry();
+        let thermal_conductivity_array = self.try_get_thermal_conductivity_array()?;
+        let outer_radius = self.get_outer_radius();
+        let outer_surface_area = geometry.get_surface_area(outer_radius);
+        let outermost_node = self.radial_len() - 1;
+        let node_radius = self.get_node_radii()[outermost_node];
+
+        Ok(heat_transfer_coeff_arr.iter().enumerate().map(
+            |(axial_node, heat_transfer_coeff)| {
+                let node_to_surface_conductance = geometry.get_shell_conductance(
+                    node_radius,
+                    outer_radius,
+                    thermal_conductivity_array[[outermost_node, axial_node]]);
+
+                1.0/(1.0/node_to_surface_conductance
+                    + 1.0/(*heat_transfer_coeff * outer_surface_area))
+            }).collect())
+    }
+
+    /// calculates the temperature array for the next timestep
+    /// and updates the temperatures of the current timestep
+    /// to be that of the next timestep
+    ///
+    /// the temperatures and heat transfer coefficients linked to the
+    /// inner and outer surfaces are cleared afterwards
+    pub fn advance_timestep(&mut self,
+        timestep: Time) -> Result<(), TuasLibError> {
+
+        let radial_nodes = self.radial_len();
+        let axial_nodes = self.axial_len();
+        let number_of_nodes = radial_nodes * axial_nodes;
+        let dt = timestep;
+
+        // nodes are numbered axially within each radial layer
+        let node_index = |radial_node: usize, axial_node: usize| {
+            radial_node * axial_nodes + axial_node
+        };
+
+        let geometry = self.get_axial_slice_geometry();
+        let axial_node_length = self.get_axial_node_length();
+        let node_radii = self.get_node_radii();
+        let node_volume_array = self.get_node_volume_array();
+        let thermal_conductivity_array = self.try_get_thermal_conductivity_array()?;
+
+        let mut coefficient_matrix: Array2<ThermalConductance> =
+            Array::default((number_of_nodes, number_of_nodes));
+        let mut power_source_vector: Array1<Power> =
+            Array::default(number_of_nodes);
+
+        let add_conductance = |coefficient_matrix: &mut Array2<ThermalConductance>,
+            first_node: usize, second_node: usize,
+            conductance: ThermalConductance| {
+            coefficient_matrix[[first_node,first_node]] += conductance;
+            coefficient_matrix[[second_node,second_node]] += conductance;
+            coefficient_matrix[[first_node,second_node]] -= conductance;
+            coefficient_matrix[[second_node,first_node]] -= conductance;
+        };
+
+        // energy balance for each node is:
+        //
+        // rho cp V dT/dt = \sum H (T_adjacent - T) + q''' V
+        //
+        // which is discretised with implicit Euler
+        for radial_node in 0..radial_nodes {
+
+            let region = self.regions[self.node_region_indices[radial_node]];
+
+            for axial_node in 0..axial_nodes {
+                let node = node_index(radial_node, axial_node);
+                let node_volume = node_volume_array[[radial_node, axial_node]];
+                let node_temperature =
+                    self.temperature_array_current_timestep[[radial_node, axial_node]];
+
+                let rho_cp: VolumetricHeatCapacity = try_get_rho_cp(
+                    region.material,
+                    node_temperature,
+                    self.pressure_control_volume)?;
+
+                coefficient_matrix[[node,node]] += rho_cp * node_volume / dt;
+                power_source_vector[node] +=
+                    node_temperature * rho_cp * node_volume / dt
+                    + region.volumetric_heat_generation * node_volume;
+            }
+        }
+
+        // radial conduction, node middle to interface to node middle,
+        // including contact conductances between regions
+        for inner_node in 0..radial_nodes.saturating_sub(1) {
+            let outer_node = inner_node + 1;
+            let interface_radius = self.node_outer_radii[inner_node];
+            let inner_region = self.node_region_indices[inner_node];
+
+            for axial_node in 0..axial_nodes {
+                let mut thermal_resistance =
+                    1.0/geometry.get_shell_conductance(
+                        node_radii[inner_node],
+                        interface_radius,
+                        thermal_conductivity_array[[inner_node, axial_node]])
+                    + 1.0/geometry.get_shell_conductance(
+                        interface_radius,
+                        node_radii[outer_node],
+                        thermal_conductivity_array[[outer_node, axial_node]]);
+
+                if inner_region != self.node_region_indices[outer_node] {
+                    if let Some(contact_conductance) =
+                        self.interface_contact_conductances[inner_region] {
+                        thermal_resistance += 1.0/(contact_conductance
+                            * geometry.get_surface_area(interface_radius));
+                    }
+                }
+
+                add_conductance(&mut coefficient_matrix,
+                    node_index(inner_node, axial_node),
+                    node_index(outer_node, axial_node),
+                    1.0/thermal_resistance);
+            }
+        }
+
+        // axial conduction, half a node length on each side
+        for radial_node in 0..radial_nodes {
+            let inner_radius = self.node_inner_radii[radial_node];
+            let outer_radius = self.node_outer_radii[radial_node];
+            let annulus_area: Area = PI *
+                (outer_radius * outer_radius - inner_radius * inner_radius);
+
+            for back_node in 0..axial_nodes.saturating_sub(1) {
+                let front_node = back_node + 1;
+
+                let thermal_resistance =
+                    0.5 * axial_node_length / (annulus_area *
+                        thermal_conductivity_array[[radial_node, back_node]])
+                    + 0.5 * axial_node_length / (annulus_area *
+                        thermal_conductivity_array[[radial_node, front_node]]);
+
+                add_conductance(&mut coefficient_matrix,
+                    node_index(radial_node, back_node),
+                    node_index(radial_node, front_node),
+                    1.0/thermal_resistance);
+            }
+        }
+
+        // inner and outer surface links
+        let outermost_node = radial_nodes - 1;
+        for (heat_transfer_coeff_arr, temperature_arr) in
+            self.inner_surface_heat_transfer_coefficient_vector.iter()
+            .zip(self.inner_surface_temperature_vector.iter()) {
+
+            let conductance_arr = self.try_get_inner_surface_conductance_vector(
+                heat_transfer_coeff_arr)?;
+
+            for axial_node in 0..axial_nodes {
+                let node = node_index(0, axial_node);
+                coefficient_matrix[[node,node]] += conductance_arr[axial_node];
+                power_source_vector[node] +=
+                    conductance_arr[axial_node] * temperature_arr[axial_node];
+            }
+        }
+
+        for (heat_transfer_coeff_arr, temperature_arr) in
+            self.outer_surface_heat_transfer_coefficient_vector.iter()
+            .zip(self.outer_surface_temperature_vector.iter()) {
+
+            let conductance_arr = self.try_get_outer_surface_conductance_vector(
+                heat_transfer_coeff_arr)?;
+
+            for axial_node in 0..axial_nodes {
+                let node = node_index(outermost_node, axial_node);
+                coefficient_matrix[[node,node]] += conductance_arr[axial_node];
+                power_source_vector[node] +=
+                    conductance_arr[axial_node] * temperature_arr[axial_node];
+            }
+        }
+
+        let new_temperature_vector: Array1<ThermodynamicTemperature> =
+            solve_conductance_matrix_power_vector(
+                coefficient_matrix, power_source_vector)?;
+
+        self.temperature_array_current_timestep = Array2::from_shape_fn(
+            (radial_nodes, axial_nodes),
+            |(radial_node, axial_node)| {
+                new_temperature_vector[node_index(radial_node, axial_node)]
+            });
+
+        self.inner_surface_heat_transfer_coefficient_vector.clear();
+        self.inner_surface_temperature_vector.clear();
+        self.outer_surface_heat_transfer_coefficient_vector.clear();
+        self.outer_surface_temperature_vector.clear();
+
+        Ok(())
+    }
+}
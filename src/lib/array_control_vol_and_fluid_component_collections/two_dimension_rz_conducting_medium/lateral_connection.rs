@@ -0,0 +1,109 @@
+use ndarray::*;
+use uom::si::f64::*;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::RZConduction2DMesh;
+
+/// this implementation deals with lateral connections
+/// to the inner and outer surfaces
+///
+/// the convention is to supply a heat transfer coefficient and 
+/// a temperature for each axial node
+///
+/// at the end of the connection phase, one can then use
+/// the advance_timestep method to calculate the new
+/// temperature array
+impl RZConduction2DMesh {
+
+    fn check_axial_vector_length(&self, vector_length: usize) 
+        -> Result<(), TuasLibError> {
+
+        if vector_length != self.axial_len() {
+            return Err(TuasLibError::GenericStringError(
+                format!("r-z mesh has {} axial nodes, got a vector of length {}",
+                    self.axial_len(), vector_length)));
+        }
+
+        Ok(())
+    }
+
+    /// connects the inner surface to a fluid temperature at each 
+    /// axial node with a heat transfer coefficient at each axial node
+    pub fn lateral_link_new_inner_surface_temperature_vector(&mut self,
+        heat_transfer_coeff_vec: Vec<HeatTransfer>,
+        temperature_vec: Vec<ThermodynamicTemperature>) 
+        -> Result<(), TuasLibError>{
+
+        self.check_axial_vector_length(heat_transfer_coeff_vec.len())?;
+        self.check_axial_vector_length(temperature_vec.len())?;
+
+        self.inner_surface_heat_transfer_coefficient_vector.push(
+            Array1::from_vec(heat_transfer_coeff_vec));
+        self.inner_surface_temperature_vector.push(
+            Array1::from_vec(temperature_vec));
+
+        Ok(())
+    }
+
+    /// connects the outer surface to a fluid temperature at each 
+    /// axial node with a heat transfer coefficient at each axial node
+    pub fn lateral_link_new_outer_surface_temperature_vector(&mut self,
+        heat_transfer_coeff_vec: Vec<HeatTransfer>,
+        temperature_vec: Vec<ThermodynamicTemperature>) 
+        -> Result<(), TuasLibError>{
+
+        self.check_axial_vector_length(heat_transfer_coeff_vec.len())?;
+        self.check_axial_vector_length(temperature_vec.len())?;
+
+        self.outer_surface_heat_transfer_coefficient_vector.push(
+            Array1::from_vec(heat_transfer_coeff_vec));
+        self.outer_surface_temperature_vector.push(
+            Array1::from_vec(temperature_vec));
+
+        Ok(())
+    }
+
+    /// connects the whole outer surface to ambient with a uniform 
+    /// heat transfer coefficient
+    pub fn lateral_link_to_ambient(&mut self,
+        heat_transfer_to_ambient: HeatTransfer,
+        ambient_temperature: ThermodynamicTemperature) {
+
+        self.outer_surface_heat_transfer_coefficient_vector.push(
+            Array1::from_elem(self.axial_len(), heat_transfer_to_ambient));
+        self.outer_surface_temperature_vector.push(
+            Array1::from_elem(self.axial_len(), ambient_temperature));
+    }
+
+    /// links the inner surface of this mesh to a FluidArray,
+    /// axial node by axial node, in both directions
+    ///
+    /// the heat transfer coefficient is usually obtained from a 
+    /// Nusselt number correlation
+    ///
+    /// like other lateral links, temperatures from the current
+    /// timestep are exchanged, so this should be done before advancing
+    /// either the fluid array or this mesh
+    pub fn try_lateral_link_to_fluid_array(&mut self,
+        fluid_array: &mut FluidArray,
+        heat_transfer_coeff: HeatTransfer) -> Result<(), TuasLibError> {
+
+        let fluid_temperature_vector = fluid_array.get_temperature_vector()?;
+        self.check_axial_vector_length(fluid_temperature_vector.len())?;
+
+        let heat_transfer_coeff_arr: Array1<HeatTransfer> = 
+            Array1::from_elem(self.axial_len(), heat_transfer_coeff);
+        let conductance_arr = self.try_get_inner_surface_conductance_vector(
+            &heat_transfer_coeff_arr)?;
+
+        fluid_array.lateral_link_new_temperature_vector_and_conductance_vector(
+            conductance_arr.to_vec(), 
+            self.get_inner_node_temperature_vector())?;
+
+        self.lateral_link_new_inner_surface_temperature_vector(
+            heat_transfer_coeff_arr.to_vec(), 
+            fluid_temperature_vector)
+    }
+}
@@ -0,0 +1,266 @@
+use ndarray::*;
+use uom::si::f64::*;
+
+use crate::boussinesq_thermophysical_properties::{Material, SolidMaterial};
+use crate::tuas_lib_error::TuasLibError;
+
+use super::one_dimension_radial_conducting_medium::{try_get_radial_node_boundaries, RadialConductionRegion, RadialGeometry};
+
+/// for 2D axisymmetric (r,z) conduction meshes,
+/// eg. thick pipe walls with insulation, or heater heads
+///
+/// radially, the mesh is made of one or more material regions
+/// (eg. pipe shell and insulation) each with a configurable number
+/// of radial subdivisions, and a contact conductance may be set at
+/// the interfaces between regions.
+/// Axially, the mesh is divided into nodes of equal length, the
+/// same as a FluidArray, so that each ring of nodes at the inner
+/// surface may be linked to a FluidArray node. Axial ends are
+/// adiabatic
+///
+/// the inner and outer surfaces can be laterally linked to fluid
+/// or ambient temperatures with heat transfer coefficients. This can
+/// replace the shell and insulation SolidColumn pair of insulated
+/// components where steep radial gradients need to be resolved,
+/// for example in thick insulation during fast heat-up transients
+///
+/// the scheme used is the implicit Euler scheme with material
+/// properties evaluated at the current timestep temperatures,
+/// the same as the other array cvs
+#[derive(Debug,Clone,PartialEq)]
+pub struct RZConduction2DMesh {
+
+    /// axial length of the mesh
+    pub total_length: Length,
+
+    // number of axial nodes
+    axial_nodes: usize,
+
+    /// material regions from the innermost to outermost
+    pub regions: Vec<RadialConductionRegion>,
+
+    /// contact conductance at the interface between region i and
+    /// region i+1, None means perfect contact
+    pub interface_contact_conductances: Vec<Option<HeatTransfer>>,
+
+    /// control volume pressure
+    pub pressure_control_volume: Pressure,
+
+    /// temperature array current timestep,
+    /// indexed by [radial node, axial node],
+    /// radial nodes go from innermost to outermost
+    pub(crate) temperature_array_current_timestep: Array2<ThermodynamicTemperature>,
+
+    // region which each radial node belongs to
+    node_region_indices: Vec<usize>,
+
+    // inner and outer radii of each radial node
+    node_inner_radii: Vec<Length>,
+    node_outer_radii: Vec<Length>,
+
+    /// heat transfer coefficients for each axial node at the
+    /// inner surface, one array per link, cleared every timestep
+    pub inner_surface_heat_transfer_coefficient_vector: Vec<Array1<HeatTransfer>>,
+
+    /// temperatures linked to each axial node at the inner surface,
+    /// one array per link, cleared every timestep
+    pub inner_surface_temperature_vector: Vec<Array1<ThermodynamicTemperature>>,
+
+    /// heat transfer coefficients for each axial node at the
+    /// outer surface, one array per link, cleared every timestep
+    pub outer_surface_heat_transfer_coefficient_vector: Vec<Array1<HeatTransfer>>,
+
+    /// temperatures linked to each axial node at the outer surface,
+    /// one array per link, cleared every timestep
+    pub outer_surface_temperature_vector: Vec<Array1<ThermodynamicTemperature>>,
+}
+
+impl RZConduction2DMesh {
+
+    /// constructs a new r-z mesh from its radial regions, which must
+    /// be contiguous and ordered from innermost to outermost
+    pub fn new(total_length: Length,
+        axial_nodes: usize,
+        regions: Vec<RadialConductionRegion>,
+        initial_uniform_temperature: ThermodynamicTemperature,
+        uniform_pressure: Pressure) -> Result<Self, TuasLibError> {
+
+        if axial_nodes == 0 {
+            return Err(TuasLibError::GenericStringError(
+                "r-z mesh needs at least one axial node".to_owned()));
+        }
+
+        let (node_region_indices, node_inner_radii, node_outer_radii) =
+            try_get_radial_node_boundaries(&regions)?;
+
+        let temperature_array: Array2<ThermodynamicTemperature> =
+            Array2::from_elem((node_region_indices.len(), axial_nodes),
+                initial_uniform_temperature);
+
+        Ok(Self {
+            total_length,
+            axial_nodes,
+            interface_contact_conductances: vec![None; regions.len()-1],
+            regions,
+            pressure_control_volume: uniform_pressure,
+            temperature_array_current_timestep: temperature_array,
+            node_region_indices,
+            node_inner_radii,
+            node_outer_radii,
+            inner_surface_heat_transfer_coefficient_vector: vec![],
+            inner_surface_temperature_vector: vec![],
+            outer_surface_heat_transfer_coefficient_vector: vec![],
+            outer_surface_temperature_vector: vec![],
+        })
+    }
+
+    /// constructs the pipe shell and insulation of an insulated
+    /// pipe, with the same axial nodalisation as the pipe fluid array
+    /// (inner_nodes + 2 axial nodes)
+    ///
+    /// the insulation inner diameter is the shell outer diameter
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_insulated_pipe_wall(total_length: Length,
+        inner_nodes: usize,
+        shell_id: Length,
+        shell_od: Length,
+        insulation_thickness: Length,
+        pipe_shell_material: SolidMaterial,
+        insulation_material: SolidMaterial,
+        shell_radial_nodes: usize,
+        insulation_radial_nodes: usize,
+        initial_uniform_temperature: ThermodynamicTemperature,
+        solid_pressure: Pressure) -> Result<Self, TuasLibError> {
+
+        let shell: Material = pipe_shell_material.into();
+        let insulation: Material = insulation_material.into();
+
+        let regions = vec![
+            RadialConductionRegion::new(shell,
+                0.5 * shell_id,
+                0.5 * shell_od,
+                shell_radial_nodes),
+            RadialConductionRegion::new(insulation,
+                0.5 * shell_od,
+                0.5 * shell_od + insulation_thickness,
+                insulation_radial_nodes),
+        ];
+
+        Self::new(total_length,
+            inner_nodes + 2,
+            regions,
+            initial_uniform_temperature,
+            solid_pressure)
+    }
+
+    /// number of axial nodes
+    #[inline]
+    pub fn axial_len(&self) -> usize {
+        self.axial_nodes
+    }
+
+    /// number of radial nodes
+    #[inline]
+    pub fn radial_len(&self) -> usize {
+        self.node_region_indices.len()
+    }
+
+    /// length of each axial node
+    #[inline]
+    pub fn get_axial_node_length(&self) -> Length {
+        self.total_length / self.axial_nodes as f64
+    }
+
+    /// geometry of one axial slice of the mesh
+    #[inline]
+    pub(crate) fn get_axial_slice_geometry(&self) -> RadialGeometry {
+        RadialGeometry::Cylindrical { length: self.get_axial_node_length() }
+    }
+
+    /// sets the contact conductance at the interface between region
+    /// interface_index and region interface_index + 1
+    pub fn try_set_interface_contact_conductance(&mut self,
+        interface_index: usize,
+        contact_conductance: HeatTransfer) -> Result<(), TuasLibError> {
+
+        match self.interface_contact_conductances.get_mut(interface_index) {
+            Some(conductance) => {
+                *conductance = Some(contact_conductance);
+                Ok(())
+            },
+            None => Err(TuasLibError::GenericStringError(
+                format!("interface {} does not exist", interface_index))),
+        }
+    }
+
+    /// sets the total heat generated within a region, which is spread
+    /// uniformly over its volume, eg. a heated pipe shell
+    pub fn try_set_region_power(&mut self,
+        region_index: usize,
+        power: Power) -> Result<(), TuasLibError> {
+
+        let geometry = RadialGeometry::Cylindrical { length: self.total_length };
+
+        match self.regions.get_mut(region_index) {
+            Some(region) => {
+                region.volumetric_heat_generation = power /
+                    geometry.get_shell_volume(region.inner_radius, region.outer_radius);
+                Ok(())
+            },
+            None => Err(TuasLibError::GenericStringError(
+                format!("region {} does not exist", region_index))),
+        }
+    }
+
+    /// region which each radial node belongs to
+    pub fn get_node_region_indices(&self) -> Vec<usize> {
+        self.node_region_indices.clone()
+    }
+
+    /// radius at the middle of each radial node
+    pub fn get_node_radii(&self) -> Vec<Length> {
+        self.node_inner_radii.iter().zip(self.node_outer_radii.iter())
+            .map(|(inner_radius, outer_radius)| 0.5 * (*inner_radius + *outer_radius))
+            .collect()
+    }
+
+    /// inner radius of the mesh
+    #[inline]
+    pub fn get_inner_radius(&self) -> Length {
+        self.node_inner_radii[0]
+    }
+
+    /// outer radius of the mesh
+    #[inline]
+    pub fn get_outer_radius(&self) -> Length {
+        *self.node_outer_radii.last().unwrap()
+    }
+
+    /// volume of each node, indexed by [radial node, axial node]
+    pub fn get_node_volume_array(&self) -> Array2<Volume> {
+        let geometry = self.get_axial_slice_geometry();
+
+        Array2::from_shape_fn((self.radial_len(), self.axial_len()),
+            |(radial_node, _axial_node)| {
+                geometry.get_shell_volume(
+                    self.node_inner_radii[radial_node],
+                    self.node_outer_radii[radial_node])
+            })
+    }
+}
+
+/// Functions or methods to retrieve temperature and other such
+/// data from the mesh
+pub mod postprocessing;
+
+/// Contains functions which advance the timestep
+/// it's the bulk of calculation
+pub mod calculation;
+
+/// Functions to link the inner and outer surfaces of the mesh
+/// laterally to fluid or ambient temperatures
+pub mod lateral_connection;
+
+/// contains tests for the r-z conduction mesh
+#[cfg(test)]
+pub mod tests;
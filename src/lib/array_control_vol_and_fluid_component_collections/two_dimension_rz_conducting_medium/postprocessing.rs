@@ -0,0 +1,89 @@
+use ndarray::*;
+use uom::si::f64::*;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::volume::cubic_meter;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::RZConduction2DMesh;
+
+impl RZConduction2DMesh {
+
+    /// obtains a clone of the temperature array, indexed by 
+    /// [radial node, axial node]
+    pub fn get_temperature_array(&self) -> Result<
+    Array2<ThermodynamicTemperature>,TuasLibError>{
+
+        Ok(self.temperature_array_current_timestep.clone())
+    }
+
+    /// obtains the temperatures of the innermost radial nodes 
+    /// along the axial direction, eg. pipe shell temperatures
+    /// next to the fluid
+    pub fn get_inner_node_temperature_vector(&self) -> 
+    Vec<ThermodynamicTemperature> {
+
+        self.temperature_array_current_timestep.row(0).to_vec()
+    }
+
+    /// obtains the temperatures of the outermost radial nodes 
+    /// along the axial direction, eg. insulation temperatures
+    /// next to ambient air
+    pub fn get_outer_node_temperature_vector(&self) -> 
+    Vec<ThermodynamicTemperature> {
+
+        self.temperature_array_current_timestep
+            .row(self.radial_len() - 1).to_vec()
+    }
+
+    /// obtains the radial temperature profile at an axial node
+    pub fn try_get_radial_temperature_vector(&self, 
+        axial_node: usize) -> Result<Vec<ThermodynamicTemperature>, TuasLibError> {
+
+        if axial_node >= self.axial_len() {
+            return Err(TuasLibError::GenericStringError(
+                format!("r-z mesh has no axial node {}", axial_node)));
+        }
+
+        Ok(self.temperature_array_current_timestep.column(axial_node).to_vec())
+    }
+
+    /// obtains the volume averaged temperature of a region along 
+    /// the axial direction, eg. the pipe shell temperatures 
+    /// to compare with a SolidColumn
+    pub fn try_get_region_mean_temperature_vector(&self,
+        region_index: usize) -> Result<Vec<ThermodynamicTemperature>, TuasLibError> {
+
+        if region_index >= self.regions.len() {
+            return Err(TuasLibError::GenericStringError(
+                format!("region {} does not exist", region_index)));
+        }
+
+        let node_volume_array = self.get_node_volume_array();
+
+        let mean_temperature_vector = (0..self.axial_len())
+            .map(|axial_node| {
+                let mut region_volume = 0.0;
+                let mut volume_temperature_sum = 0.0;
+
+                for (radial_node, node_region_index) in 
+                    self.node_region_indices.iter().enumerate() {
+                    if *node_region_index != region_index {
+                        continue;
+                    }
+                    let node_volume = node_volume_array[[radial_node, axial_node]]
+                        .get::<cubic_meter>();
+                    region_volume += node_volume;
+                    volume_temperature_sum += node_volume * 
+                        self.temperature_array_current_timestep[[radial_node, axial_node]]
+                        .get::<kelvin>();
+                }
+
+                ThermodynamicTemperature::new::<kelvin>(
+                    volume_temperature_sum/region_volume)
+            })
+            .collect();
+
+        Ok(mean_temperature_vector)
+    }
+}
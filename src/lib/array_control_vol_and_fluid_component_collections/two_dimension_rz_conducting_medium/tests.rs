@@ -0,0 +1,386 @@
+use std::f64::consts::PI;
+
+use ndarray::*;
+use uom::si::f64::*;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::length::{meter, millimeter};
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::power::watt;
+use uom::si::pressure::atmosphere;
+use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::time::second;
+
+use crate::boussinesq_thermophysical_properties::SolidMaterial;
+
+use super::*;
+
+fn steel_like_cp(_temperature: ThermodynamicTemperature) -> SpecificHeatCapacity {
+    SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(500.0)
+}
+
+fn steel_like_k(_temperature: ThermodynamicTemperature) -> ThermalConductivity {
+    ThermalConductivity::new::<watt_per_meter_kelvin>(16.0)
+}
+
+fn steel_like_rho(_temperature: ThermodynamicTemperature) -> MassDensity {
+    MassDensity::new::<kilogram_per_cubic_meter>(8000.0)
+}
+
+fn insulation_like_cp(_temperature: ThermodynamicTemperature) -> SpecificHeatCapacity {
+    SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1000.0)
+}
+
+fn insulation_like_k(_temperature: ThermodynamicTemperature) -> ThermalConductivity {
+    ThermalConductivity::new::<watt_per_meter_kelvin>(0.05)
+}
+
+fn insulation_like_rho(_temperature: ThermodynamicTemperature) -> MassDensity {
+    MassDensity::new::<kilogram_per_cubic_meter>(100.0)
+}
+
+/// constant property solids so that results can be compared with
+/// analytical solutions
+fn constant_property_shell() -> SolidMaterial {
+    SolidMaterial::CustomSolid(
+        (ThermodynamicTemperature::new::<kelvin>(200.0),
+        ThermodynamicTemperature::new::<kelvin>(1500.0)),
+        steel_like_cp, steel_like_k, steel_like_rho,
+        Length::new::<millimeter>(0.015))
+}
+
+fn constant_property_insulation() -> SolidMaterial {
+    SolidMaterial::CustomSolid(
+        (ThermodynamicTemperature::new::<kelvin>(200.0),
+        ThermodynamicTemperature::new::<kelvin>(1500.0)),
+        insulation_like_cp, insulation_like_k, insulation_like_rho,
+        Length::new::<millimeter>(0.015))
+}
+
+/// pipe wall with a 20 mm id, 25 mm od shell and 50 mm of insulation,
+/// 7 axial nodes
+fn insulated_pipe_wall(shell_radial_nodes: usize,
+    insulation_radial_nodes: usize,
+    initial_temperature: ThermodynamicTemperature) -> RZConduction2DMesh {
+
+    RZConduction2DMesh::new_insulated_pipe_wall(
+        Length::new::<meter>(1.0),
+        5,
+        Length::new::<meter>(0.020),
+        Length::new::<meter>(0.025),
+        Length::new::<meter>(0.050),
+        constant_property_shell(),
+        constant_property_insulation(),
+        shell_radial_nodes,
+        insulation_radial_nodes,
+        initial_temperature,
+        Pressure::new::<atmosphere>(1.0)).unwrap()
+}
+
+fn get_stored_energy_change(mesh: &RZConduction2DMesh,
+    initial_temperature: ThermodynamicTemperature) -> f64 {
+
+    let rho_cp_vec: Vec<f64> = mesh.get_node_region_indices().iter()
+        .map(|region_index| match region_index {
+            0 => 8000.0 * 500.0,
+            _ => 100.0 * 1000.0,
+        }).collect();
+    let volume_array = mesh.get_node_volume_array();
+
+    mesh.get_temperature_array().unwrap().indexed_iter()
+        .map(|((radial_node, axial_node), temperature)| {
+            rho_cp_vec[radial_node]
+                * volume_array[[radial_node, axial_node]].value
+                * (temperature.get::<kelvin>() - initial_temperature.get::<kelvin>())
+        }).sum()
+}
+
+/// at steady state, the heat lost through the shell and insulation
+/// should match the series resistances of a composite cylinder
+///
+/// Q = (T_fluid - T_ambient) / (1/(h_i 2 pi r_1 L) + ln(r_2/r_1)/(2 pi k_s L)
+/// + ln(r_3/r_2)/(2 pi k_ins L) + 1/(h_o 2 pi r_3 L))
+#[test]
+pub fn insulated_pipe_wall_steady_heat_loss_matches_analytical(){
+
+    let fluid_temperature = ThermodynamicTemperature::new::<kelvin>(400.0);
+    let ambient_temperature = ThermodynamicTemperature::new::<kelvin>(300.0);
+    let inner_heat_transfer_coeff = HeatTransfer::new::<watt_per_square_meter_kelvin>(1000.0);
+    let outer_heat_transfer_coeff = HeatTransfer::new::<watt_per_square_meter_kelvin>(10.0);
+
+    let mut pipe_wall = insulated_pipe_wall(2, 10, ambient_temperature);
+    let axial_nodes = pipe_wall.axial_len();
+    assert_eq!(axial_nodes, 7);
+    assert_eq!(pipe_wall.radial_len(), 12);
+
+    for _ in 0..100 {
+        pipe_wall.lateral_link_new_inner_surface_temperature_vector(
+            vec![inner_heat_transfer_coeff; axial_nodes],
+            vec![fluid_temperature; axial_nodes]).unwrap();
+        pipe_wall.lateral_link_to_ambient(outer_heat_transfer_coeff,
+            ambient_temperature);
+        pipe_wall.advance_timestep(Time::new::<second>(1000.0)).unwrap();
+    }
+
+    let (r_1, r_2, r_3): (f64, f64, f64) = (0.010, 0.0125, 0.0625);
+    let total_resistance = 1.0/(1000.0 * 2.0 * PI * r_1)
+        + (r_2/r_1).ln()/(2.0 * PI * 16.0)
+        + (r_3/r_2).ln()/(2.0 * PI * 0.05)
+        + 1.0/(10.0 * 2.0 * PI * r_3);
+    let analytical_heat_loss = 100.0 / total_resistance;
+
+    let outer_conductance_arr = pipe_wall.try_get_outer_surface_conductance_vector(
+        &Array1::from_elem(axial_nodes, outer_heat_transfer_coeff)).unwrap();
+    let heat_loss: f64 = outer_conductance_arr.iter()
+        .zip(pipe_wall.get_outer_node_temperature_vector().iter())
+        .map(|(conductance, temperature)| {
+            conductance.value * (temperature.get::<kelvin>() - 300.0)
+        }).sum();
+
+    let inner_conductance_arr = pipe_wall.try_get_inner_surface_conductance_vector(
+        &Array1::from_elem(axial_nodes, inner_heat_transfer_coeff)).unwrap();
+    let heat_gain: f64 = inner_conductance_arr.iter()
+        .zip(pipe_wall.get_inner_node_temperature_vector().iter())
+        .map(|(conductance, temperature)| {
+            conductance.value * (400.0 - temperature.get::<kelvin>())
+        }).sum();
+
+    // about 5 W over a metre of pipe
+    approx::assert_relative_eq!(heat_loss, analytical_heat_loss, max_relative=0.01);
+    approx::assert_relative_eq!(heat_gain, heat_loss, max_relative=1e-6);
+
+    // temperatures decrease outwards at every axial node
+    let radial_temperature_vector =
+        pipe_wall.try_get_radial_temperature_vector(3).unwrap();
+    for pair in radial_temperature_vector.windows(2) {
+        assert!(pair[0] > pair[1]);
+    }
+    assert!(pipe_wall.try_get_radial_temperature_vector(7).is_err());
+}
+
+/// with only the ends of the inner surface linked to hot and cold
+/// fluids, heat conducts axially along the wall and the steady state
+/// temperature profile should be linear
+#[test]
+pub fn axial_conduction_between_hot_and_cold_ends(){
+
+    let regions = vec![RadialConductionRegion::new(
+        constant_property_shell().into(),
+        Length::new::<meter>(0.010),
+        Length::new::<meter>(0.0125),
+        1)];
+
+    let mut wall = RZConduction2DMesh::new(Length::new::<meter>(1.0),
+        10,
+        regions,
+        ThermodynamicTemperature::new::<kelvin>(350.0),
+        Pressure::new::<atmosphere>(1.0)).unwrap();
+
+    let zero_heat_transfer = HeatTransfer::new::<watt_per_square_meter_kelvin>(0.0);
+    let end_heat_transfer = HeatTransfer::new::<watt_per_square_meter_kelvin>(1000.0);
+
+    let mut heat_transfer_coeff_vec = vec![zero_heat_transfer; 10];
+    heat_transfer_coeff_vec[0] = end_heat_transfer;
+    heat_transfer_coeff_vec[9] = end_heat_transfer;
+
+    let mut fluid_temperature_vec = vec![ThermodynamicTemperature::new::<kelvin>(350.0); 10];
+    fluid_temperature_vec[0] = ThermodynamicTemperature::new::<kelvin>(400.0);
+    fluid_temperature_vec[9] = ThermodynamicTemperature::new::<kelvin>(300.0);
+
+    for _ in 0..200 {
+        wall.lateral_link_new_inner_surface_temperature_vector(
+            heat_transfer_coeff_vec.clone(), fluid_temperature_vec.clone()).unwrap();
+        wall.advance_timestep(Time::new::<second>(1000.0)).unwrap();
+    }
+
+    let temperature_vec: Vec<f64> = wall.get_inner_node_temperature_vector()
+        .iter().map(|temperature| temperature.get::<kelvin>()).collect();
+
+    // linear, and symmetric about 350 K
+    for index in 1..9 {
+        approx::assert_relative_eq!(
+            temperature_vec[index+1] - temperature_vec[index],
+            temperature_vec[1] - temperature_vec[0],
+            max_relative=1e-6);
+    }
+    approx::assert_relative_eq!(temperature_vec[0] + temperature_vec[9],
+        700.0, max_relative=1e-9);
+
+    // heat entering at the hot end is conducted along the wall,
+    // Q = k A (T_0 - T_9) / (9 dz)
+    let annulus_area = PI * (0.0125_f64.powi(2) - 0.010_f64.powi(2));
+    let axial_heat_flow = 16.0 * annulus_area
+        * (temperature_vec[0] - temperature_vec[9]) / (9.0 * 0.1);
+    let inner_conductance_arr = wall.try_get_inner_surface_conductance_vector(
+        &Array1::from_vec(heat_transfer_coeff_vec)).unwrap();
+    let heat_in = inner_conductance_arr[0].value * (400.0 - temperature_vec[0]);
+
+    approx::assert_relative_eq!(heat_in, axial_heat_flow, max_relative=1e-6);
+}
+
+/// during a fast heat-up, the insulation next to the shell heats up
+/// much faster than the outer insulation, one radial node in the
+/// insulation cannot resolve this and underpredicts heat absorbed
+///
+/// energy absorbed by the wall must equal the heat transferred
+/// in from the fluid regardless of nodalisation
+#[test]
+pub fn fast_heat_up_resolves_insulation_gradient(){
+
+    let initial_temperature = ThermodynamicTemperature::new::<kelvin>(300.0);
+    let fluid_temperature = ThermodynamicTemperature::new::<kelvin>(500.0);
+    let inner_heat_transfer_coeff = HeatTransfer::new::<watt_per_square_meter_kelvin>(5000.0);
+    let timestep = Time::new::<second>(0.5);
+
+    let heat_up = |insulation_radial_nodes: usize| -> (f64, f64) {
+
+        let mut pipe_wall = insulated_pipe_wall(1, insulation_radial_nodes,
+            initial_temperature);
+        let axial_nodes = pipe_wall.axial_len();
+        let heat_transfer_coeff_arr = Array1::from_elem(axial_nodes,
+            inner_heat_transfer_coeff);
+        let mut heat_transferred_in: f64 = 0.0;
+
+        for _ in 0..240 {
+            pipe_wall.lateral_link_new_inner_surface_temperature_vector(
+                heat_transfer_coeff_arr.to_vec(),
+                vec![fluid_temperature; axial_nodes]).unwrap();
+            pipe_wall.advance_timestep(timestep).unwrap();
+
+            // implicit Euler, so heat transfer uses temperatures
+            // at the end of the timestep
+            let inner_conductance_arr = pipe_wall
+                .try_get_inner_surface_conductance_vector(
+                    &heat_transfer_coeff_arr).unwrap();
+            heat_transferred_in += inner_conductance_arr.iter()
+                .zip(pipe_wall.get_inner_node_temperature_vector().iter())
+                .map(|(conductance, temperature)| {
+                    conductance.value * (500.0 - temperature.get::<kelvin>())
+                        * timestep.get::<second>()
+                }).sum::<f64>();
+        }
+
+        (get_stored_energy_change(&pipe_wall, initial_temperature),
+            heat_transferred_in)
+    };
+
+    let (coarse_energy, coarse_heat_in) = heat_up(1);
+    let (fine_energy, fine_heat_in) = heat_up(16);
+    let (finer_energy, finer_heat_in) = heat_up(32);
+
+    approx::assert_relative_eq!(coarse_energy, coarse_heat_in, max_relative=1e-6);
+    approx::assert_relative_eq!(fine_energy, fine_heat_in, max_relative=1e-6);
+    approx::assert_relative_eq!(finer_energy, finer_heat_in, max_relative=1e-6);
+
+    // radial refinement converges, while one node per layer does not,
+    // most of the heat goes into the shell, so the difference is
+    // about 6% of the total
+    assert!(((fine_energy - finer_energy)/finer_energy).abs() < 0.005);
+    assert!((finer_energy - coarse_energy)/finer_energy > 0.05);
+}
+
+/// the r-z pipe wall linked to a FluidArray in place of the shell and
+/// insulation SolidColumn pair, at steady state, the fluid enthalpy
+/// drop should equal the heat lost to ambient
+#[test]
+pub fn pipe_wall_linked_to_fluid_array_energy_balance(){
+
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::boundary_conditions::BCType;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, Material};
+    use crate::boussinesq_thermophysical_properties::specific_enthalpy::try_get_h;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+    use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+    use uom::si::angle::degree;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::ratio::ratio;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+
+    let therminol = LiquidMaterial::TherminolVP1;
+    let pressure = Pressure::new::<atmosphere>(1.0);
+    let inlet_temperature = ThermodynamicTemperature::new::<degree_celsius>(150.0);
+    let ambient_temperature = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.002);
+    let inner_heat_transfer_coeff = HeatTransfer::new::<watt_per_square_meter_kelvin>(500.0);
+    let outer_heat_transfer_coeff = HeatTransfer::new::<watt_per_square_meter_kelvin>(10.0);
+
+    let mut channel: HeatTransferEntity = FluidArray::new_cylinder(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.02),
+        inlet_temperature,
+        pressure,
+        SolidMaterial::SteelSS304L,
+        therminol,
+        Ratio::new::<ratio>(0.0),
+        5,
+        Angle::new::<degree>(0.0),
+    ).into();
+
+    let mut pipe_wall = insulated_pipe_wall(2, 8, inlet_temperature);
+
+    let mut inlet_bc: HeatTransferEntity = BCType::new_const_temperature(
+        inlet_temperature).into();
+    let mut outlet_bc: HeatTransferEntity = BCType::new_adiabatic_bc().into();
+    let timestep = Time::new::<second>(5.0);
+
+    for _ in 0..4000 {
+
+        let temperature_vector = channel.get_temperature_vector().unwrap();
+        let back_cv_temperature = temperature_vector[0];
+        let front_cv_temperature = *temperature_vector.iter().last().unwrap();
+
+        let inlet_interaction = HeatTransferInteractionType::
+            new_advection_interaction(
+                mass_flowrate,
+                therminol.try_get_density(inlet_temperature).unwrap(),
+                therminol.try_get_density(back_cv_temperature).unwrap());
+        let front_cv_density = therminol.try_get_density(front_cv_temperature)
+            .unwrap();
+        let outlet_interaction = HeatTransferInteractionType::
+            new_advection_interaction(
+                mass_flowrate,
+                front_cv_density,
+                front_cv_density);
+
+        channel.try_set_flowrate_for_fluid_array(mass_flowrate).unwrap();
+        {
+            let mut channel_clone: FluidArray =
+                channel.clone().try_into().unwrap();
+            pipe_wall.try_lateral_link_to_fluid_array(&mut channel_clone,
+                inner_heat_transfer_coeff).unwrap();
+            channel = channel_clone.into();
+        }
+        pipe_wall.lateral_link_to_ambient(outer_heat_transfer_coeff,
+            ambient_temperature);
+
+        channel.link_to_back(&mut inlet_bc, inlet_interaction).unwrap();
+        channel.link_to_front(&mut outlet_bc, outlet_interaction).unwrap();
+
+        channel.advance_timestep_mut_self(timestep).unwrap();
+        pipe_wall.advance_timestep(timestep).unwrap();
+    }
+
+    let outer_conductance_arr = pipe_wall.try_get_outer_surface_conductance_vector(
+        &Array1::from_elem(pipe_wall.axial_len(), outer_heat_transfer_coeff)).unwrap();
+    let heat_loss: Power = outer_conductance_arr.iter()
+        .zip(pipe_wall.get_outer_node_temperature_vector().iter())
+        .map(|(conductance, temperature)| {
+            Power::new::<watt>(conductance.value *
+                (temperature.get::<kelvin>() - ambient_temperature.get::<kelvin>()))
+        }).sum();
+
+    let outlet_temperature = *channel.get_temperature_vector().unwrap()
+        .last().unwrap();
+    assert!(outlet_temperature < inlet_temperature);
+
+    let therminol_material: Material = therminol.into();
+    let enthalpy_drop = try_get_h(therminol_material, inlet_temperature, pressure)
+        .unwrap()
+        - try_get_h(therminol_material, outlet_temperature, pressure).unwrap();
+
+    approx::assert_relative_eq!(
+        (mass_flowrate * enthalpy_drop).get::<watt>(),
+        heat_loss.get::<watt>(),
+        max_relative=0.01);
+}
@@ -1,11 +1,17 @@
 use uom::si::f64::MassDensity;
 use uom::si::f64::Pressure;
 use uom::si::f64::ThermodynamicTemperature;
+use uom::si::pressure::atmosphere;
 use crate::tuas_lib_error::TuasLibError;
 
 use super::liquid_database;
 use super::liquid_database::flibe::get_flibe_density;
 use super::liquid_database::flinak::get_flinak_density;
+use super::liquid_database::solar_salt::get_solar_salt_density;
+use super::liquid_database::nacl_mgcl2::get_nacl_mgcl2_density;
+use super::liquid_database::naf_zrf4::get_naf_zrf4_density;
+use super::liquid_database::water::get_water_density;
+use super::liquid_database::ideal_gases::*;
 use super::liquid_database::hitec_nitrate_salt::get_hitec_density;
 use super::liquid_database::yd_325_heat_transfer_oil::get_yd325_density;
 use super::solid_database::copper::copper_density;
@@ -48,11 +54,11 @@ use super::liquid_database::dowtherm_a::get_dowtherm_a_density;
 #[inline]
 pub fn try_get_rho(material: Material, 
     temperature: ThermodynamicTemperature,
-    _pressure: Pressure) -> Result<MassDensity, TuasLibError> {
+    pressure: Pressure) -> Result<MassDensity, TuasLibError> {
 
     let density: MassDensity = match material {
         Material::Solid(_) => solid_density(material, temperature)?,
-        Material::Liquid(_) => liquid_density(material, temperature)?
+        Material::Liquid(_) => liquid_density(material, temperature, pressure)?
     };
 
     return Ok(density);
@@ -62,11 +68,11 @@ impl Material {
     /// returns density of the material
     pub fn density(&self,
         temperature: ThermodynamicTemperature,
-        _pressure: Pressure) -> Result<MassDensity, TuasLibError>{
+        pressure: Pressure) -> Result<MassDensity, TuasLibError>{
 
     let density: MassDensity = match self {
         Material::Solid(_) => solid_density(self.clone(), temperature)?,
-        Material::Liquid(_) => liquid_density(self.clone(), temperature)?
+        Material::Liquid(_) => liquid_density(self.clone(), temperature, pressure)?
     };
 
     return Ok(density);
@@ -90,6 +96,9 @@ fn solid_density(material: Material,
         Material::Solid(CustomSolid((low_bound_temp,high_bound_temp),cp,k,rho,roughness)) => {
             CustomSolid((low_bound_temp,high_bound_temp), cp, k, rho,roughness)
         },
+        Material::Solid(SolidMaterial::SelectedCorrelations(correlations)) => {
+            SolidMaterial::SelectedCorrelations(correlations)
+        },
         Material::Liquid(_) => {
             println!("solid_density, use SolidMaterial enums only");
             return Err(TuasLibError::TypeConversionErrorMaterial);
@@ -108,6 +117,9 @@ fn solid_density(material: Material,
                 high_bound_temp, 
                 low_bound_temp)?
         },
+        SolidMaterial::SelectedCorrelations(correlations) => {
+            correlations.try_get_density(solid_temp)?
+        },
     };
 
     return Ok(density);
@@ -119,8 +131,12 @@ fn solid_density(material: Material,
 
 
 // should the material happen to be a liquid, use this function
+//
+// pressure is only used for gases and weakly compressible liquids,
+// other liquids are taken to be incompressible
 fn liquid_density(material: Material, 
-    fluid_temp: ThermodynamicTemperature) -> Result<MassDensity,TuasLibError> {
+    fluid_temp: ThermodynamicTemperature,
+    pressure: Pressure) -> Result<MassDensity,TuasLibError> {
 
     let liquid_material: LiquidMaterial = match material {
         Material::Liquid(DowthermA) => DowthermA,
@@ -129,9 +145,23 @@ fn liquid_density(material: Material,
         Material::Liquid(YD325) => YD325,
         Material::Liquid(FLiBe) => FLiBe,
         Material::Liquid(FLiNaK) => FLiNaK,
+        Material::Liquid(SolarSalt) => SolarSalt,
+        Material::Liquid(NaClMgCl2) => NaClMgCl2,
+        Material::Liquid(NaFZrF4) => NaFZrF4,
+        Material::Liquid(Water) => Water,
+        Material::Liquid(Air) => Air,
+        Material::Liquid(Nitrogen) => Nitrogen,
+        Material::Liquid(Helium) => Helium,
+        Material::Liquid(Argon) => Argon,
         Material::Liquid(CustomLiquid((low_bound_temp,high_bound_temp),cp,k,mu,rho)) => {
             CustomLiquid((low_bound_temp,high_bound_temp), cp, k, mu, rho)
         },
+        Material::Liquid(LiquidMaterial::SelectedCorrelations(correlations)) => {
+            LiquidMaterial::SelectedCorrelations(correlations)
+        },
+        Material::Liquid(LiquidMaterial::WeaklyCompressible(liquid)) => {
+            LiquidMaterial::WeaklyCompressible(liquid)
+        },
 
         Material::Solid(_) => panic!("liquid_density, use LiquidMaterial enums only")
     };
@@ -143,6 +173,14 @@ fn liquid_density(material: Material,
         YD325 => get_yd325_density(fluid_temp)?,
         FLiBe => get_flibe_density(fluid_temp)?,
         FLiNaK => get_flinak_density(fluid_temp)?,
+        SolarSalt => get_solar_salt_density(fluid_temp)?,
+        NaClMgCl2 => get_nacl_mgcl2_density(fluid_temp)?,
+        NaFZrF4 => get_naf_zrf4_density(fluid_temp)?,
+        Water => get_water_density(fluid_temp)?,
+        Air => get_ideal_gas_density(&AIR, fluid_temp, pressure)?,
+        Nitrogen => get_ideal_gas_density(&NITROGEN, fluid_temp, pressure)?,
+        Helium => get_ideal_gas_density(&HELIUM, fluid_temp, pressure)?,
+        Argon => get_ideal_gas_density(&ARGON, fluid_temp, pressure)?,
         CustomLiquid((low_bound_temp,high_bound_temp), _cp, _k, _mu, rho_fn) => {
             liquid_database::custom_liquid_material
                 ::get_custom_fluid_density(fluid_temp, 
@@ -150,6 +188,12 @@ fn liquid_density(material: Material,
                     high_bound_temp, 
                     low_bound_temp)?
         },
+        LiquidMaterial::SelectedCorrelations(correlations) => {
+            correlations.try_get_density(fluid_temp, pressure)?
+        },
+        LiquidMaterial::WeaklyCompressible(liquid) => {
+            liquid.try_get_density(fluid_temp, pressure)?
+        },
     };
 
     return Ok(density);
@@ -158,6 +202,10 @@ fn liquid_density(material: Material,
 impl LiquidMaterial {
 
     /// returns density of liquid material
+    ///
+    /// for gases, the density is evaluated at 1 atm, and for 
+    /// weakly compressible liquids at their reference pressure, 
+    /// use try_get_rho if the pressure is known
     pub fn try_get_density(&self,
         fluid_temp: ThermodynamicTemperature,) -> 
     Result<MassDensity,TuasLibError> {
@@ -169,6 +217,14 @@ impl LiquidMaterial {
             YD325 => get_yd325_density(fluid_temp)?,
             FLiBe => get_flibe_density(fluid_temp)?,
             FLiNaK => get_flinak_density(fluid_temp)?,
+            SolarSalt => get_solar_salt_density(fluid_temp)?,
+            NaClMgCl2 => get_nacl_mgcl2_density(fluid_temp)?,
+            NaFZrF4 => get_naf_zrf4_density(fluid_temp)?,
+            Water => get_water_density(fluid_temp)?,
+            Air => get_ideal_gas_density_atmospheric(&AIR, fluid_temp)?,
+            Nitrogen => get_ideal_gas_density_atmospheric(&NITROGEN, fluid_temp)?,
+            Helium => get_ideal_gas_density_atmospheric(&HELIUM, fluid_temp)?,
+            Argon => get_ideal_gas_density_atmospheric(&ARGON, fluid_temp)?,
             CustomLiquid((low_bound_temp,high_bound_temp), _cp, _k, _mu, rho_fn) => {
                 liquid_database::custom_liquid_material
                     ::get_custom_fluid_density(fluid_temp, 
@@ -176,6 +232,13 @@ impl LiquidMaterial {
                         *high_bound_temp, 
                         *low_bound_temp)?
             },
+            LiquidMaterial::SelectedCorrelations(correlations) => {
+                correlations.try_get_density(fluid_temp,
+                    Pressure::new::<atmosphere>(1.0))?
+            },
+            LiquidMaterial::WeaklyCompressible(liquid) => {
+                liquid.try_get_density(fluid_temp, liquid.reference_pressure)?
+            },
         };
 
         Ok(density)
@@ -3,6 +3,11 @@ use crate::tuas_lib_error::TuasLibError;
 
 use super::liquid_database::flibe::get_flibe_dynamic_viscosity;
 use super::liquid_database::flinak::get_flinak_dynamic_viscosity;
+use super::liquid_database::solar_salt::get_solar_salt_dynamic_viscosity;
+use super::liquid_database::nacl_mgcl2::get_nacl_mgcl2_dynamic_viscosity;
+use super::liquid_database::naf_zrf4::get_naf_zrf4_dynamic_viscosity;
+use super::liquid_database::water::get_water_dynamic_viscosity;
+use super::liquid_database::ideal_gases::*;
 use super::liquid_database::hitec_nitrate_salt::get_hitec_dynamic_viscosity;
 use super::liquid_database::yd_325_heat_transfer_oil::get_yd325_dynamic_viscosity;
 use super::LiquidMaterial;
@@ -70,9 +75,23 @@ fn liquid_dynamic_viscosity(material: Material,
         Material::Liquid(YD325) => YD325,
         Material::Liquid(FLiBe) => FLiBe,
         Material::Liquid(FLiNaK) => FLiNaK,
+        Material::Liquid(SolarSalt) => SolarSalt,
+        Material::Liquid(NaClMgCl2) => NaClMgCl2,
+        Material::Liquid(NaFZrF4) => NaFZrF4,
+        Material::Liquid(Water) => Water,
+        Material::Liquid(Air) => Air,
+        Material::Liquid(Nitrogen) => Nitrogen,
+        Material::Liquid(Helium) => Helium,
+        Material::Liquid(Argon) => Argon,
         Material::Liquid(CustomLiquid((low_bound_temp,high_bound_temp),cp,k,mu,rho)) => {
             CustomLiquid((low_bound_temp,high_bound_temp), cp, k, mu, rho)
         },
+        Material::Liquid(LiquidMaterial::SelectedCorrelations(correlations)) => {
+            LiquidMaterial::SelectedCorrelations(correlations)
+        },
+        Material::Liquid(LiquidMaterial::WeaklyCompressible(liquid)) => {
+            LiquidMaterial::WeaklyCompressible(liquid)
+        },
         Material::Solid(_) => panic!("liquid_dynamic_viscosity, use LiquidMaterial enums only")
     };
 
@@ -83,6 +102,14 @@ fn liquid_dynamic_viscosity(material: Material,
         YD325 => get_yd325_dynamic_viscosity(fluid_temp)?,
         FLiBe => get_flibe_dynamic_viscosity(fluid_temp)?,
         FLiNaK => get_flinak_dynamic_viscosity(fluid_temp)?,
+        SolarSalt => get_solar_salt_dynamic_viscosity(fluid_temp)?,
+        NaClMgCl2 => get_nacl_mgcl2_dynamic_viscosity(fluid_temp)?,
+        NaFZrF4 => get_naf_zrf4_dynamic_viscosity(fluid_temp)?,
+        Water => get_water_dynamic_viscosity(fluid_temp)?,
+        Air => get_ideal_gas_dynamic_viscosity(&AIR, fluid_temp)?,
+        Nitrogen => get_ideal_gas_dynamic_viscosity(&NITROGEN, fluid_temp)?,
+        Helium => get_ideal_gas_dynamic_viscosity(&HELIUM, fluid_temp)?,
+        Argon => get_ideal_gas_dynamic_viscosity(&ARGON, fluid_temp)?,
         CustomLiquid((low_bound_temp,high_bound_temp), _cp, _k, mu_fn, _rho_fn) => {
             liquid_database::custom_liquid_material
                 ::get_custom_fluid_viscosity(fluid_temp, 
@@ -90,6 +117,12 @@ fn liquid_dynamic_viscosity(material: Material,
                     high_bound_temp, 
                     low_bound_temp)?
         },
+        LiquidMaterial::SelectedCorrelations(correlations) => {
+            correlations.try_get_dynamic_viscosity(fluid_temp)?
+        },
+        LiquidMaterial::WeaklyCompressible(liquid) => {
+            liquid.correlations.try_get_dynamic_viscosity(fluid_temp)?
+        },
     };
 
     return Ok(dynamic_viscosity);
@@ -109,6 +142,14 @@ impl LiquidMaterial {
             YD325 => get_yd325_dynamic_viscosity(fluid_temp)?,
             FLiBe => get_flibe_dynamic_viscosity(fluid_temp)?,
             FLiNaK => get_flinak_dynamic_viscosity(fluid_temp)?,
+            SolarSalt => get_solar_salt_dynamic_viscosity(fluid_temp)?,
+            NaClMgCl2 => get_nacl_mgcl2_dynamic_viscosity(fluid_temp)?,
+            NaFZrF4 => get_naf_zrf4_dynamic_viscosity(fluid_temp)?,
+            Water => get_water_dynamic_viscosity(fluid_temp)?,
+            Air => get_ideal_gas_dynamic_viscosity(&AIR, fluid_temp)?,
+            Nitrogen => get_ideal_gas_dynamic_viscosity(&NITROGEN, fluid_temp)?,
+            Helium => get_ideal_gas_dynamic_viscosity(&HELIUM, fluid_temp)?,
+            Argon => get_ideal_gas_dynamic_viscosity(&ARGON, fluid_temp)?,
             CustomLiquid((low_bound_temp,high_bound_temp), _cp, _k, mu_fn, _rho_fn) => {
                 
                 liquid_database::custom_liquid_material
@@ -117,6 +158,12 @@ impl LiquidMaterial {
                         *high_bound_temp, 
                         *low_bound_temp)?
             },
+            LiquidMaterial::SelectedCorrelations(correlations) => {
+                correlations.try_get_dynamic_viscosity(fluid_temp)?
+            },
+            LiquidMaterial::WeaklyCompressible(liquid) => {
+                liquid.correlations.try_get_dynamic_viscosity(fluid_temp)?
+            },
         };
 
         Ok(dynamic_viscosity)
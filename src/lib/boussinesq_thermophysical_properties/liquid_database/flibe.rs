@@ -237,6 +237,141 @@ pub fn flibe_salt_test_viscosity(){
 
 }
 
+/// function to obtain flibe salt density given a temperature,
+/// using the Janz correlation recommended in the review by
+/// Romatoski and Hu
+///
+/// Janz, G. J. (1988). Thermodynamic and transport properties for 
+/// molten salts: correlation equations for critically evaluated 
+/// density, surface tension, electrical conductance, and viscosity 
+/// data. J. Phys. Chem. Ref. Data, 17(Suppl. 2).
+///
+/// Romatoski, R. R., & Hu, L. W. (2017). Fluoride salt coolant properties 
+/// for nuclear reactor applications: A review. Annals 
+/// of Nuclear Energy, 109, 635-647.
+///
+/// rho (kg/m3) = 2413 - 0.4884 T(K)
+///
+/// This is within 0.1% of get_flibe_density over the 
+/// liquid range
+pub fn get_flibe_density_janz(
+    fluid_temp: ThermodynamicTemperature) -> Result<MassDensity,TuasLibError> {
+
+    range_check_flibe_salt(fluid_temp)?;
+    let fluid_temp_kelvin = fluid_temp.get::<kelvin>();
+
+    let density_value_kg_per_m3 = 2413.0 - 0.4884 * fluid_temp_kelvin;
+
+    Ok(MassDensity::new::<kilogram_per_cubic_meter>(density_value_kg_per_m3))
+}
+
+/// function to obtain flibe salt viscosity given a temperature,
+/// using the correlation of Cantor et al. only 
+///
+/// Cantor, S., Cooke, J. W., Dworkin, A. S., Robbins, G. D., 
+/// Thoma, R. E., & Watson, G. M. (1968). Physical properties of 
+/// molten-salt reactor fuel, coolant, and flush salts 
+/// (No. ORNL-TM-2316). Oak Ridge National Lab.(ORNL), 
+/// Oak Ridge, TN (United States).
+///
+/// mu (cP) = 0.116 exp(3755/T(K))
+///
+/// This is almost the same as Gierszewski's correlation used 
+/// in get_flibe_dynamic_viscosity below 1200 K, but has no 
+/// switch to Abe's correlation above 1200 K
+pub fn get_flibe_dynamic_viscosity_cantor(
+    fluid_temp: ThermodynamicTemperature) -> Result<DynamicViscosity,
+TuasLibError>{
+
+    range_check_flibe_salt(fluid_temp)?;
+    let fluid_temp_kelvin = fluid_temp.get::<kelvin>();
+
+    let viscosity_value_centipoise = 0.116 * (3755.0/fluid_temp_kelvin).exp();
+
+    Ok(DynamicViscosity::new::<centipoise>(viscosity_value_centipoise))
+}
+
+/// function to obtain flibe salt viscosity given a temperature,
+/// using the correlation of Abe et al. (as quoted by Romatoski) 
+/// over the whole liquid range 
+///
+/// Romatoski, R. R., & Hu, L. W. (2017). Fluoride salt coolant properties 
+/// for nuclear reactor applications: A review. Annals 
+/// of Nuclear Energy, 109, 635-647.
+///
+/// mu (cP) = 0.07803 exp(4022/T(K))
+///
+/// The data was for 812.5 - 1573 K, so below this, the 
+/// correlation is extrapolated down to the freezing point
+pub fn get_flibe_dynamic_viscosity_abe(
+    fluid_temp: ThermodynamicTemperature) -> Result<DynamicViscosity,
+TuasLibError>{
+
+    range_check_flibe_salt(fluid_temp)?;
+    let fluid_temp_kelvin = fluid_temp.get::<kelvin>();
+
+    let viscosity_value_centipoise = 0.07803 * (4022.0/fluid_temp_kelvin).exp();
+
+    Ok(DynamicViscosity::new::<centipoise>(viscosity_value_centipoise))
+}
+
+/// function to obtain flibe salt thermal conductivity 
+/// given a temperature, using a constant value of 1.1 W/(m K)
+///
+/// Romatoski, R. R., & Hu, L. W. (2017). Fluoride salt coolant properties 
+/// for nuclear reactor applications: A review. Annals 
+/// of Nuclear Energy, 109, 635-647.
+///
+/// Romatoski's data is around 1.1 W/(m K) from 873 K to 1073 K, 
+/// with no clear temperature dependence, so this is an alternative
+/// to the fit of Sohal's correlation in get_flibe_thermal_conductivity
+pub fn get_flibe_thermal_conductivity_romatoski(
+    fluid_temp: ThermodynamicTemperature) -> Result<ThermalConductivity,TuasLibError> {
+
+    range_check_flibe_salt(fluid_temp)?;
+
+    Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(1.1))
+}
+
+/// the alternative correlations should agree with the default 
+/// ones to within the spread of the literature data
+#[test]
+pub fn flibe_alternative_correlations(){
+
+    use uom::si::thermodynamic_temperature::kelvin;
+    extern crate approx;
+
+    let temperature_900_k = ThermodynamicTemperature::new::<kelvin>(900.0);
+
+    approx::assert_relative_eq!(
+        get_flibe_density_janz(temperature_900_k).unwrap().value,
+        get_flibe_density(temperature_900_k).unwrap().value,
+        max_relative=0.003);
+
+    // Cantor and Gierszewski are within 1% of each other 
+    approx::assert_relative_eq!(
+        get_flibe_dynamic_viscosity_cantor(temperature_900_k).unwrap().value,
+        get_flibe_dynamic_viscosity(temperature_900_k).unwrap().value,
+        max_relative=0.01);
+
+    // above 1200 K, get_flibe_dynamic_viscosity uses Abe's correlation
+    let temperature_1300_k = ThermodynamicTemperature::new::<kelvin>(1300.0);
+    assert_eq!(
+        get_flibe_dynamic_viscosity_abe(temperature_1300_k).unwrap(),
+        get_flibe_dynamic_viscosity(temperature_1300_k).unwrap());
+
+    // Abe's correlation at 900 K is about 10% lower than Gierszewski's
+    approx::assert_relative_eq!(
+        get_flibe_dynamic_viscosity_abe(temperature_900_k).unwrap().value,
+        get_flibe_dynamic_viscosity(temperature_900_k).unwrap().value,
+        max_relative=0.12);
+
+    approx::assert_relative_eq!(
+        get_flibe_thermal_conductivity_romatoski(temperature_900_k).unwrap().value,
+        get_flibe_thermal_conductivity(temperature_900_k).unwrap().value,
+        max_relative=0.1);
+}
+
 /// function to obtain flibe salt specific heat capacity
 /// given a temperature
 /// Romatoski, R. R., & Hu, L. W. (2017). Fluoride salt coolant properties 
@@ -0,0 +1,421 @@
+use uom::si::f64::*;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::dynamic_viscosity::pascal_second;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use uom::si::available_energy::joule_per_kilogram;
+use uom::si::pressure::{atmosphere, pascal};
+
+// this is for the root finding algorithms
+extern crate peroxide;
+use peroxide::prelude::*;
+
+use crate::boussinesq_thermophysical_properties::{range_check, LiquidMaterial, Material};
+use crate::tuas_lib_error::TuasLibError;
+
+/// universal gas constant in J/(mol K)
+const UNIVERSAL_GAS_CONSTANT: f64 = 8.314462618;
+
+/// data for a gas which behaves as an ideal gas
+///
+/// density is obtained from the ideal gas law,
+/// rho = p M / (R T)
+///
+/// viscosity and thermal conductivity are obtained from
+/// Sutherland's law:
+///
+/// mu = mu_0 (T/T_0)^(3/2) (T_0 + S_mu)/(T + S_mu)
+/// k = k_0 (T/T_0)^(3/2) (T_0 + S_k)/(T + S_k)
+///
+/// and molar heat capacity is a cubic polynomial,
+///
+/// cp (J/(mol K)) = a + bT + cT^2 + dT^3
+///
+/// White, F. M. (2006). Viscous fluid flow (Vol. 3, pp. 28-32).
+/// New York: McGraw-Hill.
+///
+/// Cengel, Y. A., & Boles, M. A. (2015). Thermodynamics: An
+/// Engineering Approach, 8th Edition (Table A-2c). McGraw-Hill.
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct IdealGasData {
+    /// the material this data belongs to, used for range checks
+    pub material: LiquidMaterial,
+    /// molar mass in kg/mol
+    pub molar_mass_kg_per_mol: f64,
+    /// reference temperature for Sutherland's law in K
+    pub sutherland_reference_temperature_kelvin: f64,
+    /// viscosity at reference temperature in Pa s
+    pub sutherland_reference_viscosity_pascal_second: f64,
+    /// Sutherland constant for viscosity in K
+    pub sutherland_viscosity_constant_kelvin: f64,
+    /// thermal conductivity at reference temperature in W/(m K)
+    pub sutherland_reference_thermal_conductivity: f64,
+    /// Sutherland constant for thermal conductivity in K
+    pub sutherland_thermal_conductivity_constant_kelvin: f64,
+    /// molar heat capacity coefficients a, b, c, d in J/(mol K)
+    pub molar_cp_coefficients: [f64; 4],
+}
+
+/// air, treated as an ideal gas of molar mass 28.97 g/mol
+pub const AIR: IdealGasData = IdealGasData {
+    material: LiquidMaterial::Air,
+    molar_mass_kg_per_mol: 28.97e-3,
+    sutherland_reference_temperature_kelvin: 273.15,
+    sutherland_reference_viscosity_pascal_second: 1.716e-5,
+    sutherland_viscosity_constant_kelvin: 110.4,
+    sutherland_reference_thermal_conductivity: 0.0241,
+    sutherland_thermal_conductivity_constant_kelvin: 194.0,
+    molar_cp_coefficients: [28.11, 0.1967e-2, 0.4802e-5, -1.966e-9],
+};
+
+/// nitrogen (N2)
+pub const NITROGEN: IdealGasData = IdealGasData {
+    material: LiquidMaterial::Nitrogen,
+    molar_mass_kg_per_mol: 28.013e-3,
+    sutherland_reference_temperature_kelvin: 273.15,
+    sutherland_reference_viscosity_pascal_second: 1.663e-5,
+    sutherland_viscosity_constant_kelvin: 107.0,
+    sutherland_reference_thermal_conductivity: 0.0242,
+    sutherland_thermal_conductivity_constant_kelvin: 150.0,
+    molar_cp_coefficients: [28.90, -0.1571e-2, 0.8081e-5, -2.873e-9],
+};
+
+/// helium, monatomic so cp = 5/2 R
+///
+/// White does not tabulate helium, the Sutherland constant of
+/// 79.4 K is commonly quoted for helium viscosity, and is also used
+/// for thermal conductivity since k is proportional to mu for
+/// monatomic gases (Eucken)
+pub const HELIUM: IdealGasData = IdealGasData {
+    material: LiquidMaterial::Helium,
+    molar_mass_kg_per_mol: 4.0026e-3,
+    sutherland_reference_temperature_kelvin: 273.15,
+    sutherland_reference_viscosity_pascal_second: 1.87e-5,
+    sutherland_viscosity_constant_kelvin: 79.4,
+    sutherland_reference_thermal_conductivity: 0.1425,
+    sutherland_thermal_conductivity_constant_kelvin: 79.4,
+    molar_cp_coefficients: [2.5 * UNIVERSAL_GAS_CONSTANT, 0.0, 0.0, 0.0],
+};
+
+/// argon, monatomic so cp = 5/2 R
+pub const ARGON: IdealGasData = IdealGasData {
+    material: LiquidMaterial::Argon,
+    molar_mass_kg_per_mol: 39.948e-3,
+    sutherland_reference_temperature_kelvin: 273.15,
+    sutherland_reference_viscosity_pascal_second: 2.125e-5,
+    sutherland_viscosity_constant_kelvin: 114.0,
+    sutherland_reference_thermal_conductivity: 0.0163,
+    sutherland_thermal_conductivity_constant_kelvin: 170.0,
+    molar_cp_coefficients: [2.5 * UNIVERSAL_GAS_CONSTANT, 0.0, 0.0, 0.0],
+};
+
+impl IdealGasData {
+
+    /// specific gas constant, R/M in J/(kg K)
+    #[inline]
+    pub fn specific_gas_constant(&self) -> SpecificHeatCapacity {
+        SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+            UNIVERSAL_GAS_CONSTANT/self.molar_mass_kg_per_mol)
+    }
+
+    /// function checks if a gas temperature falls in a range
+    pub fn range_check(&self, fluid_temp: ThermodynamicTemperature)
+        -> Result<bool,TuasLibError>{
+
+        range_check(&Material::Liquid(self.material),
+            fluid_temp,
+            max_temp_ideal_gas(),
+            min_temp_ideal_gas()
+        )?;
+
+        return Ok(true);
+    }
+}
+
+/// function to obtain ideal gas density given a temperature
+/// and pressure
+///
+/// rho = p M / (R T)
+pub fn get_ideal_gas_density(gas: &IdealGasData,
+    fluid_temp: ThermodynamicTemperature,
+    pressure: Pressure) -> Result<MassDensity,TuasLibError> {
+
+    gas.range_check(fluid_temp)?;
+
+    let density_value_kg_per_m3 = pressure.get::<pascal>()
+        / gas.specific_gas_constant().get::<joule_per_kilogram_kelvin>()
+        / fluid_temp.get::<kelvin>();
+
+    Ok(MassDensity::new::<kilogram_per_cubic_meter>(density_value_kg_per_m3))
+}
+
+/// function to obtain ideal gas density at 1 atm given a temperature
+///
+/// this is for property functions which do not take in pressure
+pub fn get_ideal_gas_density_atmospheric(gas: &IdealGasData,
+    fluid_temp: ThermodynamicTemperature) -> Result<MassDensity,TuasLibError> {
+
+    get_ideal_gas_density(gas, fluid_temp, Pressure::new::<atmosphere>(1.0))
+}
+
+/// Sutherland's law,
+/// f = f_0 (T/T_0)^(3/2) (T_0 + S)/(T + S)
+#[inline]
+fn sutherland_law(reference_value: f64,
+    reference_temperature_kelvin: f64,
+    sutherland_constant_kelvin: f64,
+    temperature_kelvin: f64) -> f64 {
+
+    reference_value
+        * (temperature_kelvin/reference_temperature_kelvin).powf(1.5)
+        * (reference_temperature_kelvin + sutherland_constant_kelvin)
+        / (temperature_kelvin + sutherland_constant_kelvin)
+}
+
+/// function to obtain ideal gas viscosity given a temperature
+/// using Sutherland's law
+pub fn get_ideal_gas_dynamic_viscosity(gas: &IdealGasData,
+    fluid_temp: ThermodynamicTemperature) -> Result<DynamicViscosity,TuasLibError> {
+
+    gas.range_check(fluid_temp)?;
+
+    let viscosity_value_pascal_second = sutherland_law(
+        gas.sutherland_reference_viscosity_pascal_second,
+        gas.sutherland_reference_temperature_kelvin,
+        gas.sutherland_viscosity_constant_kelvin,
+        fluid_temp.get::<kelvin>());
+
+    Ok(DynamicViscosity::new::<pascal_second>(viscosity_value_pascal_second))
+}
+
+/// function to obtain ideal gas thermal conductivity given a
+/// temperature using Sutherland's law
+pub fn get_ideal_gas_thermal_conductivity(gas: &IdealGasData,
+    fluid_temp: ThermodynamicTemperature) -> Result<ThermalConductivity,TuasLibError> {
+
+    gas.range_check(fluid_temp)?;
+
+    let thermal_conductivity_value = sutherland_law(
+        gas.sutherland_reference_thermal_conductivity,
+        gas.sutherland_reference_temperature_kelvin,
+        gas.sutherland_thermal_conductivity_constant_kelvin,
+        fluid_temp.get::<kelvin>());
+
+    Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
+        thermal_conductivity_value))
+}
+
+/// function to obtain ideal gas specific heat capacity given a
+/// temperature
+///
+/// cp (J/(kg K)) = (a + bT + cT^2 + dT^3)/M
+pub fn get_ideal_gas_constant_pressure_specific_heat_capacity(
+    gas: &IdealGasData,
+    fluid_temp: ThermodynamicTemperature) -> Result<SpecificHeatCapacity,TuasLibError> {
+
+    gas.range_check(fluid_temp)?;
+    let t = fluid_temp.get::<kelvin>();
+    let [a, b, c, d] = gas.molar_cp_coefficients;
+
+    let cp_value_joule_per_kg =
+        (a + b * t + c * t.powf(2.0) + d * t.powf(3.0))
+        / gas.molar_mass_kg_per_mol;
+
+    Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+        cp_value_joule_per_kg))
+}
+
+/// function to obtain ideal gas specific enthalpy
+/// given a temperature
+///
+/// This is from manual integration of the cp polynomial,
+/// with 0 J/kg set at the low bound temperature (250 K)
+///
+/// h (J/kg) = (aT + b/2 T^2 + c/3 T^3 + d/4 T^4)/M + Constant
+///
+/// enthalpy of an ideal gas does not depend on pressure
+pub fn get_ideal_gas_specific_enthalpy(gas: &IdealGasData,
+    fluid_temp: ThermodynamicTemperature) ->
+Result<AvailableEnergy,TuasLibError>{
+
+    gas.range_check(fluid_temp)?;
+    let reference_temp_kelvin = min_temp_ideal_gas().get::<kelvin>();
+    let temp_kelvin_value = fluid_temp.get::<kelvin>();
+    let [a, b, c, d] = gas.molar_cp_coefficients;
+
+    let enthalpy_antiderivative = |t: f64| -> f64 {
+        (a * t
+            + b/2.0 * t.powf(2.0)
+            + c/3.0 * t.powf(3.0)
+            + d/4.0 * t.powf(4.0)) / gas.molar_mass_kg_per_mol
+    };
+
+    let enthalpy_value_joule_per_kg
+        = enthalpy_antiderivative(temp_kelvin_value)
+        - enthalpy_antiderivative(reference_temp_kelvin);
+
+    return Ok(AvailableEnergy::new::<joule_per_kilogram>(
+        enthalpy_value_joule_per_kg));
+}
+
+/// function to obtain ideal gas temperature from specific enthalpy
+///
+/// Note that the enthalpy equation was derived from manual
+/// integration of cp assuming 0 J/kg at 250 K (the minimum temperature)
+pub fn get_ideal_gas_temperature_from_enthalpy(gas: &IdealGasData,
+    fluid_enthalpy: AvailableEnergy) -> Result<ThermodynamicTemperature,TuasLibError> {
+
+    if fluid_enthalpy.value < 0_f64 {
+        panic!("Ideal gas : get_temperature_from_enthalpy \n
+               enthalpy < 0.0 , out of correlation range");
+    }
+
+    let enthalpy_value_joule_per_kg =
+        fluid_enthalpy.get::<joule_per_kilogram>();
+
+    let enthalpy_root = |temp_degrees_kelvin_value : f64| -> f64 {
+        let lhs_value = enthalpy_value_joule_per_kg;
+
+        let fluid_temperature =
+            ThermodynamicTemperature::new::<kelvin>(
+                temp_degrees_kelvin_value);
+        let rhs = get_ideal_gas_specific_enthalpy(gas,
+            fluid_temperature).unwrap();
+        let rhs_value = rhs.get::<joule_per_kilogram>();
+
+        return lhs_value-rhs_value;
+    };
+
+    // now solve using bisection
+    // the range is from 250 K - 1000 K
+
+    use anyhow::Result;
+    let fluid_temperature_degrees_kelvin_result
+        = bisection!(enthalpy_root,
+                    (min_temp_ideal_gas().get::<kelvin>(),
+                    max_temp_ideal_gas().get::<kelvin>()),
+                    100,
+                    1e-8);
+
+    let fluid_temperature_degrees_kelvin = fluid_temperature_degrees_kelvin_result.unwrap();
+
+    return Ok(ThermodynamicTemperature::
+        new::<kelvin>(fluid_temperature_degrees_kelvin));
+}
+
+#[inline]
+/// ideal gas max temp,
+/// Sutherland's law for thermal conductivity of air
+/// starts to deviate by more than a few percent above 1000 K
+pub fn max_temp_ideal_gas() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(1000.0)
+}
+
+#[inline]
+/// ideal gas min temp
+pub fn min_temp_ideal_gas() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(250.0)
+}
+
+/// Incropera's textbook has a table of air properties at 1 atm
+/// (Table A.4), at 300 K:
+///
+/// cp = 1007 J/(kg K)
+/// mu = 184.6e-7 Pa-s
+/// k = 0.0263 W/(m K)
+/// Pr = 0.707
+///
+/// and at 800 K:
+///
+/// cp = 1099 J/(kg K)
+/// mu = 369.8e-7 Pa-s
+/// k = 0.0573 W/(m K)
+///
+/// Bergman, T. L., Lavine, A. S., Incropera, F. P., & DeWitt, D. P.
+/// (2011). Introduction to heat transfer. John Wiley & Sons.
+///
+/// The tabulated densities in Incropera are about 1.3% lower than 
+/// the ideal gas law, so density is checked against the 
+/// NIST Chemistry WebBook values of 1.1769 kg/m3 (300 K) 
+/// and 0.4412 kg/m3 (800 K) instead
+#[test]
+pub fn air_test_properties_300_k_and_800_k(){
+
+    let pressure = Pressure::new::<atmosphere>(1.0);
+
+    for (temperature_kelvin, rho_ref, cp_ref, mu_ref, k_ref) in [
+        (300.0, 1.1769, 1007.0, 184.6e-7, 0.0263),
+        (800.0, 0.4412, 1099.0, 369.8e-7, 0.0573)] {
+
+        let temperature = ThermodynamicTemperature::new::<kelvin>(
+            temperature_kelvin);
+
+        let rho = get_ideal_gas_density(&AIR, temperature, pressure).unwrap();
+        let mu = get_ideal_gas_dynamic_viscosity(&AIR, temperature).unwrap();
+        let cp = get_ideal_gas_constant_pressure_specific_heat_capacity(
+            &AIR, temperature).unwrap();
+        let k = get_ideal_gas_thermal_conductivity(&AIR, temperature).unwrap();
+
+        approx::assert_relative_eq!(
+            rho_ref,
+            rho.get::<kilogram_per_cubic_meter>(),
+            max_relative=0.002);
+
+        approx::assert_relative_eq!(
+            mu_ref,
+            mu.get::<pascal_second>(),
+            max_relative=0.025);
+
+        approx::assert_relative_eq!(
+            cp_ref,
+            cp.get::<joule_per_kilogram_kelvin>(),
+            max_relative=0.005);
+
+        approx::assert_relative_eq!(
+            k_ref,
+            k.get::<watt_per_meter_kelvin>(),
+            max_relative=0.02);
+    }
+}
+
+/// ideal gas density should double when pressure doubles,
+/// and the enthalpy round trip should return the original temperature
+#[test]
+pub fn ideal_gas_pressure_dependence_and_enthalpy_round_trip(){
+
+    let temperature = ThermodynamicTemperature::new::<kelvin>(600.0);
+
+    for gas in [AIR, NITROGEN, HELIUM, ARGON] {
+
+        let rho_1_atm = get_ideal_gas_density(&gas, temperature,
+            Pressure::new::<atmosphere>(1.0)).unwrap();
+        let rho_2_atm = get_ideal_gas_density(&gas, temperature,
+            Pressure::new::<atmosphere>(2.0)).unwrap();
+
+        approx::assert_relative_eq!(
+            2.0 * rho_1_atm.get::<kilogram_per_cubic_meter>(),
+            rho_2_atm.get::<kilogram_per_cubic_meter>(),
+            max_relative=1e-12);
+
+        let enthalpy = get_ideal_gas_specific_enthalpy(&gas,
+            temperature).unwrap();
+        let temperature_test = get_ideal_gas_temperature_from_enthalpy(
+            &gas, enthalpy).unwrap();
+
+        approx::assert_abs_diff_eq!(
+            temperature_test.get::<kelvin>(),
+            600.0,
+            epsilon=0.001);
+    }
+
+    // monatomic gases have cp = 5/2 R/M,
+    // about 5193 J/(kg K) for helium
+    let cp_helium = get_ideal_gas_constant_pressure_specific_heat_capacity(
+        &HELIUM, temperature).unwrap();
+
+    approx::assert_relative_eq!(
+        5193.0,
+        cp_helium.get::<joule_per_kilogram_kelvin>(),
+        max_relative=0.001);
+}
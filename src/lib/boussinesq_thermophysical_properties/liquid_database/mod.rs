@@ -81,6 +81,49 @@ pub mod flibe;
 ///
 pub mod flinak;
 
+/// Solar Salt
+/// 60-40 wt% of NaNO3 and KNO3 respectively,
+/// commonly used in concentrated solar power plants
+///
+/// Zavoico, A. B. (2001). Solar power tower design basis document
+/// (No. SAND2001-2100). Sandia National Labs., Albuquerque,
+/// NM (United States).
+pub mod solar_salt;
+
+/// NaCl-MgCl2
+/// 58-42 mol% of NaCl and MgCl2 respectively (eutectic),
+/// melting temperature is 445 C
+///
+/// Williams, D. F. (2006). Assessment of candidate molten salt
+/// coolants for the NGNP/NHI heat-transfer loop
+/// (No. ORNL/TM-2006/69). Oak Ridge National Lab.(ORNL),
+/// Oak Ridge, TN (United States).
+pub mod nacl_mgcl2;
+
+/// NaF-ZrF4
+/// 59.5-40.5 mol% of NaF and ZrF4 respectively,
+/// melting temperature is 500 C
+///
+/// Williams, D. F., Toth, L. M., & Clarno, K. T. (2006). Assessment
+/// of candidate molten salt coolants for the advanced high temperature
+/// reactor (AHTR) (No. ORNL/TM-2006/12). Oak Ridge National
+/// Lab.(ORNL), Oak Ridge, TN (United States).
+pub mod naf_zrf4;
+
+/// subcooled liquid water,
+/// at about 1 atm from 0.01 C to 100 C
+///
+/// useful for validating components against standard
+/// textbook benchmarks
+pub mod water;
+
+/// air, nitrogen, helium and argon as ideal gases,
+/// with Sutherland's law for viscosity and thermal conductivity
+///
+/// White, F. M. (2006). Viscous fluid flow (Vol. 3, pp. 28-32).
+/// New York: McGraw-Hill.
+pub mod ideal_gases;
+
 /// properties for a custom liquid material 
 /// not covered in the database
 /// You'll need to define your own functions for this to work
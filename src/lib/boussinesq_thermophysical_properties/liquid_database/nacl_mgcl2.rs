@@ -0,0 +1,343 @@
+#[warn(missing_docs)]
+
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This is file is part of a thermal hydraulics library written 
+//    in rust meant to help with the
+//    fluid mechanics and heat transfer aspects of the calculations
+//    for the Compact Integral Effects Tests (CIET) and hopefully 
+//    Gen IV Reactors such as the Fluoride Salt cooled High Temperature 
+//    Reactor (FHR)
+//     
+//    Copyright (C) 2022-2024  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    thermal_hydrualics_rs is free software; you can 
+//    redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    thermal_hydrualics_rs is distributed in the hope 
+//    that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This thermal hydraulics library 
+//    contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+//
+// Btw, I have no affiliation with the Rust foundation.
+use uom::si::f64::*;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::dynamic_viscosity::centipoise;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use uom::si::available_energy::joule_per_kilogram;
+
+// this is for the root finding algorithms
+extern crate peroxide;
+use peroxide::prelude::*;
+
+use crate::boussinesq_thermophysical_properties::{range_check, LiquidMaterial, Material};
+use crate::tuas_lib_error::TuasLibError;
+
+/// function to obtain NaCl-MgCl2 (58-42 mol%) salt density
+/// given a temperature
+///
+/// Williams, D. F. (2006). Assessment of candidate molten salt 
+/// coolants for the NGNP/NHI heat-transfer loop 
+/// (No. ORNL/TM-2006/69). Oak Ridge National Lab.(ORNL), 
+/// Oak Ridge, TN (United States).
+///
+/// Williams tabulates a density of 1.68 g/cm3 at 700 C. 
+/// The temperature coefficient is taken as -5.07e-4 g/(cm3 K), 
+/// which gives:
+///
+/// rho (kg/m3) = 2173.4 - 0.507 T(K)
+pub fn get_nacl_mgcl2_density(
+    fluid_temp: ThermodynamicTemperature) -> Result<MassDensity,TuasLibError> {
+
+    range_check_nacl_mgcl2_salt(fluid_temp)?;
+    let fluid_temp_kelvin = fluid_temp.get::<kelvin>();
+    let a = 2173.4;
+    let b = -0.507;
+    // generic correlation is:
+    // a + bT + cT^2 + dT^3 + eT^4;
+
+    let density_value_kg_per_m3 = 
+        a 
+        + b * fluid_temp_kelvin;
+
+    return Ok(MassDensity::new::<
+              kilogram_per_cubic_meter>(density_value_kg_per_m3));
+}
+
+/// function to obtain NaCl-MgCl2 (58-42 mol%) salt viscosity
+/// given a temperature
+///
+/// Williams, D. F. (2006). Assessment of candidate molten salt 
+/// coolants for the NGNP/NHI heat-transfer loop 
+/// (No. ORNL/TM-2006/69). Oak Ridge National Lab.(ORNL), 
+/// Oak Ridge, TN (United States).
+///
+/// Williams only gives an estimate of 1.36 cP at 700 C.
+/// To give some temperature dependence, I'm using an Arrhenius 
+/// form with an activation temperature of 2000 K, which is in the 
+/// range typical of chloride melts. This is anchored at the 700 C 
+/// value:
+///
+/// mu (cP) = 1.36 exp(2000 (1/T(K) - 1/973.15))
+///
+/// Treat this as an estimate with about 20% uncertainty, especially 
+/// near the melting point
+pub fn get_nacl_mgcl2_dynamic_viscosity(
+    fluid_temp: ThermodynamicTemperature) -> Result<DynamicViscosity,
+TuasLibError>{
+
+    range_check_nacl_mgcl2_salt(fluid_temp)?;
+    let fluid_temp_kelvin = fluid_temp.get::<kelvin>();
+    let reference_viscosity_centipoise = 1.36;
+    let reference_temp_kelvin = 973.15;
+    let activation_temp_kelvin = 2000.0;
+
+    let viscosity_value_centipoise = reference_viscosity_centipoise 
+        * (activation_temp_kelvin * 
+            (1.0/fluid_temp_kelvin - 1.0/reference_temp_kelvin)).exp();
+
+    Ok(DynamicViscosity::new::<centipoise>(viscosity_value_centipoise))
+}
+
+/// function to obtain NaCl-MgCl2 (58-42 mol%) salt specific heat 
+/// capacity given a temperature
+///
+/// Williams, D. F. (2006). Assessment of candidate molten salt 
+/// coolants for the NGNP/NHI heat-transfer loop 
+/// (No. ORNL/TM-2006/69). Oak Ridge National Lab.(ORNL), 
+/// Oak Ridge, TN (United States).
+///
+/// cp is taken to be constant, 1090 J/(kg K)
+pub fn get_nacl_mgcl2_constant_pressure_specific_heat_capacity(
+    fluid_temp: ThermodynamicTemperature) -> Result<SpecificHeatCapacity,
+TuasLibError>{
+
+    range_check_nacl_mgcl2_salt(fluid_temp)?;
+    let cp_value_joule_per_kg = 1090.0;
+
+    Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+        cp_value_joule_per_kg))
+}
+
+/// function to obtain NaCl-MgCl2 (58-42 mol%) salt thermal conductivity
+/// given a temperature
+///
+/// Williams, D. F. (2006). Assessment of candidate molten salt 
+/// coolants for the NGNP/NHI heat-transfer loop 
+/// (No. ORNL/TM-2006/69). Oak Ridge National Lab.(ORNL), 
+/// Oak Ridge, TN (United States).
+///
+/// Williams gives a predicted value of 0.50 W/(m K) at 700 C,
+/// there is not enough data for a temperature dependence, so this 
+/// is held constant
+pub fn get_nacl_mgcl2_thermal_conductivity(
+    fluid_temp: ThermodynamicTemperature) -> Result<ThermalConductivity,TuasLibError> {
+
+    range_check_nacl_mgcl2_salt(fluid_temp)?;
+    let thermal_conductivity_value = 0.50;
+
+    return Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
+        thermal_conductivity_value));
+}
+
+/// Williams (2006) tabulates the NaCl-MgCl2 properties at 700 C:
+///
+/// rho = 1.68 g/cm3 
+/// mu = 1.36 cP
+/// cp = 1.09 J/(g K)
+/// k = 0.50 W/(m K)
+///
+/// These are the values I'm using to test the NaCl-MgCl2 correlations
+#[test]
+pub fn nacl_mgcl2_test_properties_700_c(){
+
+    use uom::si::thermodynamic_temperature::degree_celsius;
+
+    let temperature_700_c = 
+        ThermodynamicTemperature::new::<degree_celsius>(700.0);
+
+    let rho = get_nacl_mgcl2_density(temperature_700_c).unwrap();
+    let mu = get_nacl_mgcl2_dynamic_viscosity(temperature_700_c).unwrap();
+    let cp = get_nacl_mgcl2_constant_pressure_specific_heat_capacity(
+        temperature_700_c).unwrap();
+    let k = get_nacl_mgcl2_thermal_conductivity(temperature_700_c).unwrap();
+
+    approx::assert_relative_eq!(
+        1680.0,
+        rho.get::<kilogram_per_cubic_meter>(),
+        max_relative=0.01);
+
+    approx::assert_relative_eq!(
+        1.36,
+        mu.get::<centipoise>(),
+        max_relative=0.01);
+
+    approx::assert_relative_eq!(
+        1090.0,
+        cp.get::<joule_per_kilogram_kelvin>(),
+        max_relative=0.01);
+
+    approx::assert_relative_eq!(
+        0.50,
+        k.get::<watt_per_meter_kelvin>(),
+        max_relative=0.01);
+}
+
+/// function to obtain NaCl-MgCl2 (58-42 mol%) salt specific enthalpy
+/// given a temperature
+///
+/// cp (J/kg/K) = 1090.0 
+///
+/// Manual integration with temperature yields:
+///
+/// h (J/kg) = 1090.0 T(K) + Constant
+///
+/// I can just adjust the enthalpy to be 0 J/kg at 718.15 K, which is 
+/// the low bound temperature for NaCl-MgCl2
+pub fn get_nacl_mgcl2_specific_enthalpy(
+    fluid_temp: ThermodynamicTemperature) -> 
+Result<AvailableEnergy,TuasLibError>{
+
+    range_check_nacl_mgcl2_salt(fluid_temp)?;
+
+    // delta h = cp (delta T)
+    let cp = get_nacl_mgcl2_constant_pressure_specific_heat_capacity(fluid_temp)?;
+
+    let reference_temperature_kelvin = min_temp_nacl_mgcl2().get::<kelvin>();
+
+    let delta_t_from_ref_temperature: TemperatureInterval = 
+        TemperatureInterval::new::<uom::si::temperature_interval::kelvin>
+        (
+            fluid_temp.get::<kelvin>()
+            -reference_temperature_kelvin
+        );
+
+    let delta_h: AvailableEnergy = 
+        cp * delta_t_from_ref_temperature;
+
+    return Ok(delta_h);
+}
+
+/// function to obtain NaCl-MgCl2 (58-42 mol%) salt temperature 
+/// from specific enthalpy
+///
+/// Note that the enthalpy equation was derived from manual 
+/// integration of cp assuming 0 J/kg at 718.15 K (the minimum temperature)
+pub fn get_temperature_from_enthalpy(
+    fluid_enthalpy: AvailableEnergy) -> Result<ThermodynamicTemperature,TuasLibError> {
+
+    // if enthalpy value below zero,
+    // based on me setting zero enthalpy at the lower end of the 
+    // temperature validity range for enthalpy,
+    // then enthalpy is technically out of range
+    if fluid_enthalpy.value < 0_f64 {
+        panic!("NaCl-MgCl2 : get_temperature_from_enthalpy \n
+               enthalpy < 0.0 , out of correlation range");
+    }
+
+    let enthalpy_value_joule_per_kg = 
+        fluid_enthalpy.get::<joule_per_kilogram>();
+
+    let enthalpy_root = |temp_degrees_kelvin_value : f64| -> f64 {
+        let lhs_value = enthalpy_value_joule_per_kg;
+
+        let fluid_temperature = 
+            ThermodynamicTemperature::new::<kelvin>(
+                temp_degrees_kelvin_value);
+        let rhs = get_nacl_mgcl2_specific_enthalpy(fluid_temperature).unwrap();
+        let rhs_value = rhs.get::<joule_per_kilogram>();
+
+        return lhs_value-rhs_value;
+    };
+    
+    // now solve using bisection
+    // the range is from 718.15 K - 1173.15 K
+    
+    use anyhow::Result;
+    let fluid_temperature_degrees_kelvin_result 
+        = bisection!(enthalpy_root,
+                    (718.15,1173.15),
+                    100,
+                    1e-8);
+
+    let fluid_temperature_degrees_kelvin = fluid_temperature_degrees_kelvin_result.unwrap();
+
+    return Ok(ThermodynamicTemperature::
+        new::<kelvin>(fluid_temperature_degrees_kelvin));
+
+}
+
+/// function checks if a fluid temperature falls in a range 
+///
+/// If it falls outside this range, it will panic
+/// or throw an error, and the program will not run
+///
+/// Williams, D. F. (2006). Assessment of candidate molten salt 
+/// coolants for the NGNP/NHI heat-transfer loop 
+/// (No. ORNL/TM-2006/69). Oak Ridge National Lab.(ORNL), 
+/// Oak Ridge, TN (United States).
+///
+/// The melting point of the eutectic is 445 C (718.15 K).
+/// I'm limiting the upper bound to 900 C (1173.15 K) since the 
+/// correlations are anchored at 700 C values and should not be 
+/// extrapolated too far
+pub fn range_check_nacl_mgcl2_salt(fluid_temp: ThermodynamicTemperature) 
+    -> Result<bool,TuasLibError>{
+
+        range_check(&Material::Liquid(LiquidMaterial::NaClMgCl2), 
+            fluid_temp, 
+            max_temp_nacl_mgcl2(), 
+            min_temp_nacl_mgcl2()
+            )?;
+
+        return Ok(true);
+
+    }
+
+#[inline]
+/// NaCl-MgCl2 max temp 
+pub fn max_temp_nacl_mgcl2() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(1173.15)
+
+}
+#[inline]
+/// NaCl-MgCl2 min temp 
+pub fn min_temp_nacl_mgcl2() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(718.15)
+}
@@ -0,0 +1,317 @@
+#[warn(missing_docs)]
+
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This is file is part of a thermal hydraulics library written 
+//    in rust meant to help with the
+//    fluid mechanics and heat transfer aspects of the calculations
+//    for the Compact Integral Effects Tests (CIET) and hopefully 
+//    Gen IV Reactors such as the Fluoride Salt cooled High Temperature 
+//    Reactor (FHR)
+//     
+//    Copyright (C) 2022-2024  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    thermal_hydrualics_rs is free software; you can 
+//    redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    thermal_hydrualics_rs is distributed in the hope 
+//    that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This thermal hydraulics library 
+//    contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+//
+// Btw, I have no affiliation with the Rust foundation.
+use uom::si::f64::*;
+use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::dynamic_viscosity::centipoise;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use uom::si::available_energy::joule_per_kilogram;
+
+// this is for the root finding algorithms
+extern crate peroxide;
+use peroxide::prelude::*;
+
+use crate::boussinesq_thermophysical_properties::{range_check, LiquidMaterial, Material};
+use crate::tuas_lib_error::TuasLibError;
+
+/// function to obtain NaF-ZrF4 (59.5-40.5 mol%) salt density
+/// given a temperature
+///
+/// Williams, D. F., Toth, L. M., & Clarno, K. T. (2006). Assessment 
+/// of candidate molten salt coolants for the advanced high temperature 
+/// reactor (AHTR) (No. ORNL/TM-2006/12). Oak Ridge National 
+/// Lab.(ORNL), Oak Ridge, TN (United States).
+///
+/// rho (g/cm3) = 3.79 - 9.3e-4 T(C)
+/// rho (kg/m3) = 3790 - 0.93 T(C)
+pub fn get_naf_zrf4_density(
+    fluid_temp: ThermodynamicTemperature) -> Result<MassDensity,TuasLibError> {
+
+    range_check_naf_zrf4_salt(fluid_temp)?;
+    let fluid_temp_degrees_c = fluid_temp.get::<degree_celsius>();
+    let a = 3790.0;
+    let b = -0.93;
+    // generic correlation is:
+    // a + bT + cT^2 + dT^3 + eT^4;
+
+    let density_value_kg_per_m3 = 
+        a 
+        + b * fluid_temp_degrees_c;
+
+    return Ok(MassDensity::new::<
+              kilogram_per_cubic_meter>(density_value_kg_per_m3));
+}
+
+/// function to obtain NaF-ZrF4 (59.5-40.5 mol%) salt viscosity
+/// given a temperature
+///
+/// Williams, D. F., Toth, L. M., & Clarno, K. T. (2006). Assessment 
+/// of candidate molten salt coolants for the advanced high temperature 
+/// reactor (AHTR) (No. ORNL/TM-2006/12). Oak Ridge National 
+/// Lab.(ORNL), Oak Ridge, TN (United States).
+///
+/// mu (cP) = 0.0767 exp(3977/T(K))
+pub fn get_naf_zrf4_dynamic_viscosity(
+    fluid_temp: ThermodynamicTemperature) -> Result<DynamicViscosity,
+TuasLibError>{
+
+    range_check_naf_zrf4_salt(fluid_temp)?;
+    let fluid_temp_kelvin = fluid_temp.get::<kelvin>();
+    // generic form:  
+    // mu = a * exp (b/T[K])
+    let a = 0.0767;
+    let b = 3977_f64;
+    let viscosity_value_centipoise = a * (b/fluid_temp_kelvin).exp();
+
+    Ok(DynamicViscosity::new::<centipoise>(viscosity_value_centipoise))
+}
+
+/// function to obtain NaF-ZrF4 (59.5-40.5 mol%) salt specific heat 
+/// capacity given a temperature
+///
+/// Williams, D. F., Toth, L. M., & Clarno, K. T. (2006). Assessment 
+/// of candidate molten salt coolants for the advanced high temperature 
+/// reactor (AHTR) (No. ORNL/TM-2006/12). Oak Ridge National 
+/// Lab.(ORNL), Oak Ridge, TN (United States).
+///
+/// cp is taken to be constant, 1170 J/(kg K)
+pub fn get_naf_zrf4_constant_pressure_specific_heat_capacity(
+    fluid_temp: ThermodynamicTemperature) -> Result<SpecificHeatCapacity,
+TuasLibError>{
+
+    range_check_naf_zrf4_salt(fluid_temp)?;
+    let cp_value_joule_per_kg = 1170.0;
+
+    Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+        cp_value_joule_per_kg))
+}
+
+/// function to obtain NaF-ZrF4 (59.5-40.5 mol%) salt thermal conductivity
+/// given a temperature
+///
+/// Williams, D. F., Toth, L. M., & Clarno, K. T. (2006). Assessment 
+/// of candidate molten salt coolants for the advanced high temperature 
+/// reactor (AHTR) (No. ORNL/TM-2006/12). Oak Ridge National 
+/// Lab.(ORNL), Oak Ridge, TN (United States).
+///
+/// Williams gives a value of 0.49 W/(m K), there is not enough 
+/// data for a temperature dependence, so this is held constant
+pub fn get_naf_zrf4_thermal_conductivity(
+    fluid_temp: ThermodynamicTemperature) -> Result<ThermalConductivity,TuasLibError> {
+
+    range_check_naf_zrf4_salt(fluid_temp)?;
+    let thermal_conductivity_value = 0.49;
+
+    return Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
+        thermal_conductivity_value));
+}
+
+/// Williams (2006) tabulates the NaF-ZrF4 properties at 700 C:
+///
+/// rho = 3.14 g/cm3 
+/// cp = 1.17 J/(g K)
+/// k = 0.49 W/(m K)
+///
+/// These are the values I'm using to test the NaF-ZrF4 correlations
+#[test]
+pub fn naf_zrf4_test_properties_700_c(){
+
+    let temperature_700_c = 
+        ThermodynamicTemperature::new::<degree_celsius>(700.0);
+
+    let rho = get_naf_zrf4_density(temperature_700_c).unwrap();
+    let cp = get_naf_zrf4_constant_pressure_specific_heat_capacity(
+        temperature_700_c).unwrap();
+    let k = get_naf_zrf4_thermal_conductivity(temperature_700_c).unwrap();
+
+    approx::assert_relative_eq!(
+        3140.0,
+        rho.get::<kilogram_per_cubic_meter>(),
+        max_relative=0.01);
+
+    approx::assert_relative_eq!(
+        1170.0,
+        cp.get::<joule_per_kilogram_kelvin>(),
+        max_relative=0.01);
+
+    approx::assert_relative_eq!(
+        0.49,
+        k.get::<watt_per_meter_kelvin>(),
+        max_relative=0.01);
+}
+
+/// function to obtain NaF-ZrF4 (59.5-40.5 mol%) salt specific enthalpy
+/// given a temperature
+///
+/// cp (J/kg/K) = 1170.0 
+///
+/// Manual integration with temperature yields:
+///
+/// h (J/kg) = 1170.0 T(K) + Constant
+///
+/// I can just adjust the enthalpy to be 0 J/kg at 773.15 K, which is 
+/// the low bound temperature for NaF-ZrF4
+pub fn get_naf_zrf4_specific_enthalpy(
+    fluid_temp: ThermodynamicTemperature) -> 
+Result<AvailableEnergy,TuasLibError>{
+
+    range_check_naf_zrf4_salt(fluid_temp)?;
+
+    // delta h = cp (delta T)
+    let cp = get_naf_zrf4_constant_pressure_specific_heat_capacity(fluid_temp)?;
+
+    let reference_temperature_kelvin = min_temp_naf_zrf4().get::<kelvin>();
+
+    let delta_t_from_ref_temperature: TemperatureInterval = 
+        TemperatureInterval::new::<uom::si::temperature_interval::kelvin>
+        (
+            fluid_temp.get::<kelvin>()
+            -reference_temperature_kelvin
+        );
+
+    let delta_h: AvailableEnergy = 
+        cp * delta_t_from_ref_temperature;
+
+    return Ok(delta_h);
+}
+
+/// function to obtain NaF-ZrF4 (59.5-40.5 mol%) salt temperature 
+/// from specific enthalpy
+///
+/// Note that the enthalpy equation was derived from manual 
+/// integration of cp assuming 0 J/kg at 773.15 K (the minimum temperature)
+pub fn get_temperature_from_enthalpy(
+    fluid_enthalpy: AvailableEnergy) -> Result<ThermodynamicTemperature,TuasLibError> {
+
+    // if enthalpy value below zero,
+    // based on me setting zero enthalpy at the lower end of the 
+    // temperature validity range for enthalpy,
+    // then enthalpy is technically out of range
+    if fluid_enthalpy.value < 0_f64 {
+        panic!("NaF-ZrF4 : get_temperature_from_enthalpy \n
+               enthalpy < 0.0 , out of correlation range");
+    }
+
+    let enthalpy_value_joule_per_kg = 
+        fluid_enthalpy.get::<joule_per_kilogram>();
+
+    let enthalpy_root = |temp_degrees_kelvin_value : f64| -> f64 {
+        let lhs_value = enthalpy_value_joule_per_kg;
+
+        let fluid_temperature = 
+            ThermodynamicTemperature::new::<kelvin>(
+                temp_degrees_kelvin_value);
+        let rhs = get_naf_zrf4_specific_enthalpy(fluid_temperature).unwrap();
+        let rhs_value = rhs.get::<joule_per_kilogram>();
+
+        return lhs_value-rhs_value;
+    };
+    
+    // now solve using bisection
+    // the range is from 773.15 K - 1173.15 K
+    
+    use anyhow::Result;
+    let fluid_temperature_degrees_kelvin_result 
+        = bisection!(enthalpy_root,
+                    (773.15,1173.15),
+                    100,
+                    1e-8);
+
+    let fluid_temperature_degrees_kelvin = fluid_temperature_degrees_kelvin_result.unwrap();
+
+    return Ok(ThermodynamicTemperature::
+        new::<kelvin>(fluid_temperature_degrees_kelvin));
+
+}
+
+/// function checks if a fluid temperature falls in a range 
+///
+/// If it falls outside this range, it will panic
+/// or throw an error, and the program will not run
+///
+/// Williams, D. F., Toth, L. M., & Clarno, K. T. (2006). Assessment 
+/// of candidate molten salt coolants for the advanced high temperature 
+/// reactor (AHTR) (No. ORNL/TM-2006/12). Oak Ridge National 
+/// Lab.(ORNL), Oak Ridge, TN (United States).
+///
+/// The melting point of NaF-ZrF4 is 500 C (773.15 K).
+/// I'm limiting the upper bound to 900 C (1173.15 K)
+pub fn range_check_naf_zrf4_salt(fluid_temp: ThermodynamicTemperature) 
+    -> Result<bool,TuasLibError>{
+
+        range_check(&Material::Liquid(LiquidMaterial::NaFZrF4), 
+            fluid_temp, 
+            max_temp_naf_zrf4(), 
+            min_temp_naf_zrf4()
+            )?;
+
+        return Ok(true);
+
+    }
+
+#[inline]
+/// NaF-ZrF4 max temp 
+pub fn max_temp_naf_zrf4() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(1173.15)
+
+}
+#[inline]
+/// NaF-ZrF4 min temp 
+pub fn min_temp_naf_zrf4() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(773.15)
+}
@@ -0,0 +1,359 @@
+#[warn(missing_docs)]
+
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This is file is part of a thermal hydraulics library written 
+//    in rust meant to help with the
+//    fluid mechanics and heat transfer aspects of the calculations
+//    for the Compact Integral Effects Tests (CIET) and hopefully 
+//    Gen IV Reactors such as the Fluoride Salt cooled High Temperature 
+//    Reactor (FHR)
+//     
+//    Copyright (C) 2022-2024  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    thermal_hydrualics_rs is free software; you can 
+//    redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    thermal_hydrualics_rs is distributed in the hope 
+//    that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This thermal hydraulics library 
+//    contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+//
+// Btw, I have no affiliation with the Rust foundation.
+use uom::si::f64::*;
+use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::dynamic_viscosity::millipascal_second;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use uom::si::available_energy::joule_per_kilogram;
+
+// this is for the root finding algorithms
+extern crate peroxide;
+use peroxide::prelude::*;
+
+use crate::boussinesq_thermophysical_properties::{range_check, LiquidMaterial, Material};
+use crate::tuas_lib_error::TuasLibError;
+
+/// function to obtain solar salt density
+/// given a temperature
+///
+/// Zavoico, A. B. (2001). Solar power tower design basis document 
+/// (No. SAND2001-2100). Sandia National Labs., Albuquerque, 
+/// NM (United States).
+///
+/// rho (kg/m3) = 2090 - 0.636 T(C)
+pub fn get_solar_salt_density(
+    fluid_temp: ThermodynamicTemperature) -> Result<MassDensity,TuasLibError> {
+
+    range_check_solar_salt(fluid_temp)?;
+    let fluid_temp_degrees_c = fluid_temp.get::<degree_celsius>();
+    let a = 2090.0;
+    let b = -0.636;
+    // generic correlation is:
+    // a + bT + cT^2 + dT^3 + eT^4;
+
+    let density_value_kg_per_m3 = 
+        a 
+        + b * fluid_temp_degrees_c;
+
+    return Ok(MassDensity::new::<
+              kilogram_per_cubic_meter>(density_value_kg_per_m3));
+}
+
+/// function to obtain solar salt viscosity
+/// given a temperature
+///
+/// Zavoico, A. B. (2001). Solar power tower design basis document 
+/// (No. SAND2001-2100). Sandia National Labs., Albuquerque, 
+/// NM (United States).
+///
+/// mu (mPa-s) = 22.714 
+/// - 0.120 T(C) 
+/// + 2.281e-4 T(C)^2 
+/// - 1.474e-7 T(C)^3
+///
+pub fn get_solar_salt_dynamic_viscosity(
+    fluid_temp: ThermodynamicTemperature) -> Result<DynamicViscosity,
+TuasLibError>{
+
+    range_check_solar_salt(fluid_temp)?;
+    let fluid_temp_degrees_c = fluid_temp.get::<degree_celsius>();
+    let a = 22.714;
+    let b = -0.120;
+    let c = 2.281e-4;
+    let d = -1.474e-7;
+
+    // generic correlation is:
+    // a + bT + cT^2 + dT^3 + eT^4;
+    let viscosity_value_millipascal_second = 
+        a 
+        + b * fluid_temp_degrees_c
+        + c * fluid_temp_degrees_c.powf(2.0)
+        + d * fluid_temp_degrees_c.powf(3.0);
+
+    Ok(DynamicViscosity::new::<millipascal_second>(
+            viscosity_value_millipascal_second))
+}
+
+/// function to obtain solar salt specific heat capacity
+/// given a temperature
+///
+/// Zavoico, A. B. (2001). Solar power tower design basis document 
+/// (No. SAND2001-2100). Sandia National Labs., Albuquerque, 
+/// NM (United States).
+///
+/// cp (J/(kg K)) = 1443 + 0.172 T(C)
+pub fn get_solar_salt_constant_pressure_specific_heat_capacity(
+    fluid_temp: ThermodynamicTemperature) -> Result<SpecificHeatCapacity,
+TuasLibError>{
+
+    range_check_solar_salt(fluid_temp)?;
+    let fluid_temp_degrees_c = fluid_temp.get::<degree_celsius>();
+    let cp_value_joule_per_kg = 1443.0 + 0.172 * fluid_temp_degrees_c;
+
+    Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+        cp_value_joule_per_kg))
+}
+
+/// function to obtain solar salt thermal conductivity
+/// given a temperature
+///
+/// Zavoico, A. B. (2001). Solar power tower design basis document 
+/// (No. SAND2001-2100). Sandia National Labs., Albuquerque, 
+/// NM (United States).
+///
+/// k (W/(m K)) = 0.443 + 1.9e-4 T(C)
+pub fn get_solar_salt_thermal_conductivity(
+    fluid_temp: ThermodynamicTemperature) -> Result<ThermalConductivity,TuasLibError> {
+
+    range_check_solar_salt(fluid_temp)?;
+    let fluid_temp_degrees_c = fluid_temp.get::<degree_celsius>();
+    let thermal_conductivity_value = 0.443 + 1.9e-4 * fluid_temp_degrees_c;
+
+    return Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
+        thermal_conductivity_value));
+}
+
+/// Zavoico tabulates the solar salt properties in section 2 of the 
+/// design basis document. At 400 C:
+///
+/// rho = 1836 kg/m3 
+/// mu = 1.78 mPa-s (cP)
+/// cp = 1512 J/(kg K)
+/// k = 0.519 W/(m K)
+///
+/// These are the values I'm using to test the solar salt correlations
+#[test]
+pub fn solar_salt_test_properties_400_c(){
+
+    use uom::si::dynamic_viscosity::centipoise;
+    let temperature_400_c = 
+        ThermodynamicTemperature::new::<degree_celsius>(400.0);
+
+    let rho = get_solar_salt_density(temperature_400_c).unwrap();
+    let mu = get_solar_salt_dynamic_viscosity(temperature_400_c).unwrap();
+    let cp = get_solar_salt_constant_pressure_specific_heat_capacity(
+        temperature_400_c).unwrap();
+    let k = get_solar_salt_thermal_conductivity(temperature_400_c).unwrap();
+
+    approx::assert_relative_eq!(
+        1836.0,
+        rho.get::<kilogram_per_cubic_meter>(),
+        max_relative=0.01);
+
+    approx::assert_relative_eq!(
+        1.78,
+        mu.get::<centipoise>(),
+        max_relative=0.02);
+
+    approx::assert_relative_eq!(
+        1512.0,
+        cp.get::<joule_per_kilogram_kelvin>(),
+        max_relative=0.01);
+
+    approx::assert_relative_eq!(
+        0.519,
+        k.get::<watt_per_meter_kelvin>(),
+        max_relative=0.01);
+}
+
+/// function to obtain solar salt specific enthalpy
+/// given a temperature
+///
+/// Zavoico, A. B. (2001). Solar power tower design basis document 
+/// (No. SAND2001-2100). Sandia National Labs., Albuquerque, 
+/// NM (United States).
+///
+/// cp (J/(kg K)) = 1443 + 0.172 T(C)
+///
+/// Manual integration with temperature yields:
+///
+/// h (J/kg) = 1443 T(C) + 0.086 T(C)^2 + Constant
+///
+/// I can just adjust the enthalpy to be 0 J/kg at 260 C, which is 
+/// the low bound temperature for solar salt
+///
+/// 0 = 1443 * 260 + 0.086 * 260^2 + Constant
+///
+pub fn get_solar_salt_specific_enthalpy(
+    fluid_temp: ThermodynamicTemperature) -> 
+Result<AvailableEnergy,TuasLibError>{
+
+    range_check_solar_salt(fluid_temp)?;
+    let reference_temp_degrees_c = min_temp_solar_salt().get::<degree_celsius>();
+    let temp_degrees_c_value = fluid_temp.get::<degree_celsius>();
+
+    let enthalpy_antiderivative = |temp_degrees_c: f64| -> f64 {
+        1443.0 * temp_degrees_c + 0.086 * temp_degrees_c.powf(2.0)
+    };
+
+    let enthalpy_value_joule_per_kg 
+        = enthalpy_antiderivative(temp_degrees_c_value)
+        - enthalpy_antiderivative(reference_temp_degrees_c);
+
+    return Ok(AvailableEnergy::new::<joule_per_kilogram>(
+        enthalpy_value_joule_per_kg));
+}
+
+/// function to obtain solar salt temperature from specific enthalpy
+///
+/// Note that the enthalpy equation was derived from manual 
+/// integration of cp assuming 0 J/kg at 260 C (the minimum temperature)
+///
+/// h (J/kg) = 1443 T(C) + 0.086 T(C)^2 + Constant
+///
+pub fn get_temperature_from_enthalpy(
+    fluid_enthalpy: AvailableEnergy) -> Result<ThermodynamicTemperature,TuasLibError> {
+
+    // if enthalpy value below zero,
+    // based on me setting zero enthalpy at the lower end of the 
+    // temperature validity range for enthalpy,
+    // then enthalpy is technically out of range
+    if fluid_enthalpy.value < 0_f64 {
+        panic!("Solar Salt : get_temperature_from_enthalpy \n
+               enthalpy < 0.0 , out of correlation range");
+    }
+
+    let enthalpy_value_joule_per_kg = 
+        fluid_enthalpy.get::<joule_per_kilogram>();
+
+    let enthalpy_root = |temp_degrees_kelvin_value : f64| -> f64 {
+        let lhs_value = enthalpy_value_joule_per_kg;
+
+        let fluid_temperature = 
+            ThermodynamicTemperature::new::<kelvin>(
+                temp_degrees_kelvin_value);
+        let rhs = get_solar_salt_specific_enthalpy(fluid_temperature).unwrap();
+        let rhs_value = rhs.get::<joule_per_kilogram>();
+
+        return lhs_value-rhs_value;
+    };
+    
+    // now solve using bisection
+    // the range is from 533.15 K - 873.15 K
+    
+    use anyhow::Result;
+    let fluid_temperature_degrees_kelvin_result 
+        = bisection!(enthalpy_root,
+                    (533.15,873.15),
+                    100,
+                    1e-8);
+
+    let fluid_temperature_degrees_kelvin = fluid_temperature_degrees_kelvin_result.unwrap();
+
+    return Ok(ThermodynamicTemperature::
+        new::<kelvin>(fluid_temperature_degrees_kelvin));
+
+}
+
+/// checks that the temperature obtained from the solar salt 
+/// enthalpy is the same as the original temperature
+#[test]
+pub fn solar_salt_enthalpy_round_trip(){
+
+    let temperature_450_c = 
+        ThermodynamicTemperature::new::<degree_celsius>(450.0);
+
+    let enthalpy = get_solar_salt_specific_enthalpy(
+        temperature_450_c).unwrap();
+
+    let temperature_test = get_temperature_from_enthalpy(
+        enthalpy).unwrap();
+
+    approx::assert_abs_diff_eq!(
+        temperature_test.get::<degree_celsius>(),
+        450.0,
+        epsilon=0.001);
+}
+
+/// function checks if a fluid temperature falls in a range 
+///
+/// If it falls outside this range, it will panic
+/// or throw an error, and the program will not run
+///
+/// Zavoico, A. B. (2001). Solar power tower design basis document 
+/// (No. SAND2001-2100). Sandia National Labs., Albuquerque, 
+/// NM (United States).
+///
+/// Solar salt starts freezing at about 238 C, and Zavoico's 
+/// correlations are meant for 260 - 600 C (533.15 - 873.15 K) 
+/// Above 600 C, the nitrate decomposes to nitrite
+pub fn range_check_solar_salt(fluid_temp: ThermodynamicTemperature) 
+    -> Result<bool,TuasLibError>{
+
+        range_check(&Material::Liquid(LiquidMaterial::SolarSalt), 
+            fluid_temp, 
+            max_temp_solar_salt(), 
+            min_temp_solar_salt()
+            )?;
+
+        return Ok(true);
+
+    }
+
+#[inline]
+/// solar salt max temp 
+pub fn max_temp_solar_salt() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(873.15)
+
+}
+#[inline]
+/// solar salt min temp 
+pub fn min_temp_solar_salt() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(533.15)
+}
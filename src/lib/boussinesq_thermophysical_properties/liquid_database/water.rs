@@ -0,0 +1,399 @@
+#[warn(missing_docs)]
+
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This is file is part of a thermal hydraulics library written 
+//    in rust meant to help with the
+//    fluid mechanics and heat transfer aspects of the calculations
+//    for the Compact Integral Effects Tests (CIET) and hopefully 
+//    Gen IV Reactors such as the Fluoride Salt cooled High Temperature 
+//    Reactor (FHR)
+//     
+//    Copyright (C) 2022-2024  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    thermal_hydrualics_rs is free software; you can 
+//    redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    thermal_hydrualics_rs is distributed in the hope 
+//    that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This thermal hydraulics library 
+//    contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+//
+// Btw, I have no affiliation with the Rust foundation.
+use uom::si::f64::*;
+use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::dynamic_viscosity::pascal_second;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use uom::si::available_energy::joule_per_kilogram;
+
+// this is for the root finding algorithms
+extern crate peroxide;
+use peroxide::prelude::*;
+
+use crate::boussinesq_thermophysical_properties::{range_check, LiquidMaterial, Material};
+use crate::tuas_lib_error::TuasLibError;
+
+/// function to obtain liquid water density
+/// given a temperature
+///
+/// Kell, G. S. (1975). Density, thermal expansivity, and 
+/// compressibility of liquid water from 0. deg. to 150. deg.. 
+/// Correlations and tables for atmospheric pressure and saturation 
+/// reviewed and expressed on 1968 temperature scale. Journal of 
+/// Chemical and Engineering Data, 20(1), 97-105.
+///
+/// rho (kg/m3) = (999.83952 + 16.945176 T(C) 
+/// - 7.9870401e-3 T(C)^2 
+/// - 46.170461e-6 T(C)^3 
+/// + 105.56302e-9 T(C)^4 
+/// - 280.54253e-12 T(C)^5) / (1 + 16.879850e-3 T(C))
+///
+pub fn get_water_density(
+    fluid_temp: ThermodynamicTemperature) -> Result<MassDensity,TuasLibError> {
+
+    range_check_water(fluid_temp)?;
+    let t = fluid_temp.get::<degree_celsius>();
+
+    let numerator = 999.83952 
+        + 16.945176 * t
+        - 7.9870401e-3 * t.powf(2.0)
+        - 46.170461e-6 * t.powf(3.0)
+        + 105.56302e-9 * t.powf(4.0)
+        - 280.54253e-12 * t.powf(5.0);
+
+    let denominator = 1.0 + 16.879850e-3 * t;
+
+    let density_value_kg_per_m3 = numerator/denominator;
+
+    return Ok(MassDensity::new::<
+              kilogram_per_cubic_meter>(density_value_kg_per_m3));
+}
+
+/// function to obtain liquid water viscosity
+/// given a temperature
+///
+/// uses the Vogel equation, which is commonly used to fit water 
+/// viscosity data, and is within 1% of the NIST data from 10-100 C
+///
+/// mu (Pa-s) = 2.414e-5 * 10^(247.8/(T(K) - 140))
+pub fn get_water_dynamic_viscosity(
+    fluid_temp: ThermodynamicTemperature) -> Result<DynamicViscosity,
+TuasLibError>{
+
+    range_check_water(fluid_temp)?;
+    let fluid_temp_kelvin = fluid_temp.get::<kelvin>();
+    let a = 2.414e-5;
+    let b = 247.8;
+    let c = 140.0;
+
+    let viscosity_value_pascal_second = 
+        a * 10.0_f64.powf(b/(fluid_temp_kelvin - c));
+
+    Ok(DynamicViscosity::new::<pascal_second>(viscosity_value_pascal_second))
+}
+
+/// function to obtain liquid water specific heat capacity
+/// given a temperature
+///
+/// This is a fourth order polynomial I fitted to the 
+/// NIST Chemistry WebBook values for liquid water at 1 atm 
+/// from 0.01 C to 100 C. The fit is within 0.03% of the data.
+///
+/// Lemmon, E. W., McLinden, M. O., & Friend, D. G. Thermophysical 
+/// Properties of Fluid Systems in NIST Chemistry WebBook, NIST 
+/// Standard Reference Database Number 69.
+///
+/// cp (J/(kg K)) = 4219.44 
+/// - 2.94423 T(C)
+/// + 7.28096e-2 T(C)^2
+/// - 7.24544e-4 T(C)^3
+/// + 2.87490e-6 T(C)^4
+pub fn get_water_constant_pressure_specific_heat_capacity(
+    fluid_temp: ThermodynamicTemperature) -> Result<SpecificHeatCapacity,
+TuasLibError>{
+
+    range_check_water(fluid_temp)?;
+    let t = fluid_temp.get::<degree_celsius>();
+    let a = 4219.44;
+    let b = -2.94423;
+    let c = 7.28096e-2;
+    let d = -7.24544e-4;
+    let e = 2.87490e-6;
+
+    // generic correlation is:
+    // a + bT + cT^2 + dT^3 + eT^4;
+    let cp_value_joule_per_kg = 
+        a 
+        + b * t
+        + c * t.powf(2.0)
+        + d * t.powf(3.0)
+        + e * t.powf(4.0);
+
+    Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+        cp_value_joule_per_kg))
+}
+
+/// function to obtain liquid water thermal conductivity
+/// given a temperature
+///
+/// quadratic fit commonly used for water thermal conductivity at 
+/// 1 atm, within 0.5% of NIST data from 0-100 C
+///
+/// k (W/(m K)) = -0.5752 + 6.397e-3 T(K) - 8.151e-6 T(K)^2
+pub fn get_water_thermal_conductivity(
+    fluid_temp: ThermodynamicTemperature) -> Result<ThermalConductivity,TuasLibError> {
+
+    range_check_water(fluid_temp)?;
+    let fluid_temp_kelvin = fluid_temp.get::<kelvin>();
+    let a = -0.5752;
+    let b = 6.397e-3;
+    let c = -8.151e-6;
+
+    let thermal_conductivity_value = 
+        a 
+        + b * fluid_temp_kelvin
+        + c * fluid_temp_kelvin.powf(2.0);
+
+    return Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
+        thermal_conductivity_value));
+}
+
+/// Incropera's textbook has a table of saturated water properties 
+/// (Table A.6), at 300 K:
+///
+/// rho = 1/(1.003e-3) kg/m3 
+/// cp = 4179 J/(kg K)
+/// mu = 855e-6 Pa-s
+/// k = 0.613 W/(m K)
+/// Pr = 5.83
+///
+/// Bergman, T. L., Lavine, A. S., Incropera, F. P., & DeWitt, D. P. 
+/// (2011). Introduction to heat transfer. John Wiley & Sons.
+///
+/// These are the values I'm using to test the water correlations
+/// since they are what most textbook benchmarks use
+#[test]
+pub fn water_test_properties_300_k(){
+
+    let temperature_300_k = 
+        ThermodynamicTemperature::new::<kelvin>(300.0);
+
+    let rho = get_water_density(temperature_300_k).unwrap();
+    let mu = get_water_dynamic_viscosity(temperature_300_k).unwrap();
+    let cp = get_water_constant_pressure_specific_heat_capacity(
+        temperature_300_k).unwrap();
+    let k = get_water_thermal_conductivity(temperature_300_k).unwrap();
+
+    approx::assert_relative_eq!(
+        1.0/1.003e-3,
+        rho.get::<kilogram_per_cubic_meter>(),
+        max_relative=0.002);
+
+    approx::assert_relative_eq!(
+        855e-6,
+        mu.get::<pascal_second>(),
+        max_relative=0.01);
+
+    approx::assert_relative_eq!(
+        4179.0,
+        cp.get::<joule_per_kilogram_kelvin>(),
+        max_relative=0.002);
+
+    approx::assert_relative_eq!(
+        0.613,
+        k.get::<watt_per_meter_kelvin>(),
+        max_relative=0.01);
+
+    let prandtl_number: Ratio = mu * cp / k;
+
+    approx::assert_relative_eq!(
+        5.83,
+        prandtl_number.get::<uom::si::ratio::ratio>(),
+        max_relative=0.02);
+}
+
+/// function to obtain liquid water specific enthalpy
+/// given a temperature
+///
+/// This is from manual integration of the cp polynomial,
+/// with 0 J/kg set at 0.01 C (the triple point), which is the low 
+/// bound temperature for water
+///
+/// h (J/kg) = 4219.44 T(C) 
+/// - 2.94423/2 T(C)^2
+/// + 7.28096e-2/3 T(C)^3
+/// - 7.24544e-4/4 T(C)^4
+/// + 2.87490e-6/5 T(C)^5
+/// + Constant
+pub fn get_water_specific_enthalpy(
+    fluid_temp: ThermodynamicTemperature) -> 
+Result<AvailableEnergy,TuasLibError>{
+
+    range_check_water(fluid_temp)?;
+    let reference_temp_degrees_c = min_temp_water().get::<degree_celsius>();
+    let temp_degrees_c_value = fluid_temp.get::<degree_celsius>();
+
+    let enthalpy_antiderivative = |t: f64| -> f64 {
+        4219.44 * t
+            - 2.94423/2.0 * t.powf(2.0)
+            + 7.28096e-2/3.0 * t.powf(3.0)
+            - 7.24544e-4/4.0 * t.powf(4.0)
+            + 2.87490e-6/5.0 * t.powf(5.0)
+    };
+
+    let enthalpy_value_joule_per_kg 
+        = enthalpy_antiderivative(temp_degrees_c_value)
+        - enthalpy_antiderivative(reference_temp_degrees_c);
+
+    return Ok(AvailableEnergy::new::<joule_per_kilogram>(
+        enthalpy_value_joule_per_kg));
+}
+
+/// function to obtain liquid water temperature from specific enthalpy
+///
+/// Note that the enthalpy equation was derived from manual 
+/// integration of cp assuming 0 J/kg at 0.01 C (the minimum temperature)
+pub fn get_temperature_from_enthalpy(
+    fluid_enthalpy: AvailableEnergy) -> Result<ThermodynamicTemperature,TuasLibError> {
+
+    // if enthalpy value below zero,
+    // based on me setting zero enthalpy at the lower end of the 
+    // temperature validity range for enthalpy,
+    // then enthalpy is technically out of range
+    if fluid_enthalpy.value < 0_f64 {
+        panic!("Water : get_temperature_from_enthalpy \n
+               enthalpy < 0.0 , out of correlation range");
+    }
+
+    let enthalpy_value_joule_per_kg = 
+        fluid_enthalpy.get::<joule_per_kilogram>();
+
+    let enthalpy_root = |temp_degrees_kelvin_value : f64| -> f64 {
+        let lhs_value = enthalpy_value_joule_per_kg;
+
+        let fluid_temperature = 
+            ThermodynamicTemperature::new::<kelvin>(
+                temp_degrees_kelvin_value);
+        let rhs = get_water_specific_enthalpy(fluid_temperature).unwrap();
+        let rhs_value = rhs.get::<joule_per_kilogram>();
+
+        return lhs_value-rhs_value;
+    };
+    
+    // now solve using bisection
+    // the range is from 273.16 K - 373.15 K
+    
+    use anyhow::Result;
+    let fluid_temperature_degrees_kelvin_result 
+        = bisection!(enthalpy_root,
+                    (273.16,373.15),
+                    100,
+                    1e-8);
+
+    let fluid_temperature_degrees_kelvin = fluid_temperature_degrees_kelvin_result.unwrap();
+
+    return Ok(ThermodynamicTemperature::
+        new::<kelvin>(fluid_temperature_degrees_kelvin));
+
+}
+
+/// the enthalpy change of water from 25 C to 75 C in the NIST 
+/// Chemistry WebBook (1 atm) is 313.93 kJ/kg - 104.92 kJ/kg 
+/// = 209.01 kJ/kg
+#[test]
+pub fn water_enthalpy_change_and_round_trip(){
+
+    let temperature_25_c = 
+        ThermodynamicTemperature::new::<degree_celsius>(25.0);
+    let temperature_75_c = 
+        ThermodynamicTemperature::new::<degree_celsius>(75.0);
+
+    let enthalpy_25_c = get_water_specific_enthalpy(
+        temperature_25_c).unwrap();
+    let enthalpy_75_c = get_water_specific_enthalpy(
+        temperature_75_c).unwrap();
+
+    approx::assert_relative_eq!(
+        209.01e3,
+        (enthalpy_75_c - enthalpy_25_c).get::<joule_per_kilogram>(),
+        max_relative=0.002);
+
+    let temperature_test = get_temperature_from_enthalpy(
+        enthalpy_75_c).unwrap();
+
+    approx::assert_abs_diff_eq!(
+        temperature_test.get::<degree_celsius>(),
+        75.0,
+        epsilon=0.001);
+}
+
+/// function checks if a fluid temperature falls in a range 
+///
+/// If it falls outside this range, it will panic
+/// or throw an error, and the program will not run
+///
+/// The correlations are for subcooled liquid water at about 1 atm,
+/// from the triple point (273.16 K) to the normal boiling point 
+/// (373.15 K). For pressurised water the liquid properties are only 
+/// weakly pressure dependent, but these correlations should not be 
+/// used above 100 C
+pub fn range_check_water(fluid_temp: ThermodynamicTemperature) 
+    -> Result<bool,TuasLibError>{
+
+        range_check(&Material::Liquid(LiquidMaterial::Water), 
+            fluid_temp, 
+            max_temp_water(), 
+            min_temp_water()
+            )?;
+
+        return Ok(true);
+
+    }
+
+#[inline]
+/// water max temp 
+pub fn max_temp_water() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(373.15)
+
+}
+#[inline]
+/// water min temp 
+pub fn min_temp_water() -> ThermodynamicTemperature {
+    ThermodynamicTemperature::new::<kelvin>(273.16)
+}
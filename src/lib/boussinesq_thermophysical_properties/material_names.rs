@@ -0,0 +1,60 @@
+use crate::tuas_lib_error::TuasLibError;
+
+use super::{LiquidMaterial, SolidMaterial};
+
+/// liquids in the database, by the name of their LiquidMaterial
+/// variant
+pub const LIQUID_MATERIALS: [(&str, LiquidMaterial); 14] = [
+    ("TherminolVP1", LiquidMaterial::TherminolVP1),
+    ("DowthermA", LiquidMaterial::DowthermA),
+    ("HITEC", LiquidMaterial::HITEC),
+    ("YD325", LiquidMaterial::YD325),
+    ("FLiBe", LiquidMaterial::FLiBe),
+    ("FLiNaK", LiquidMaterial::FLiNaK),
+    ("SolarSalt", LiquidMaterial::SolarSalt),
+    ("NaClMgCl2", LiquidMaterial::NaClMgCl2),
+    ("NaFZrF4", LiquidMaterial::NaFZrF4),
+    ("Water", LiquidMaterial::Water),
+    ("Air", LiquidMaterial::Air),
+    ("Nitrogen", LiquidMaterial::Nitrogen),
+    ("Helium", LiquidMaterial::Helium),
+    ("Argon", LiquidMaterial::Argon),
+];
+
+/// solids in the database, by the name of their SolidMaterial
+/// variant
+pub const SOLID_MATERIALS: [(&str, SolidMaterial); 4] = [
+    ("SteelSS304L", SolidMaterial::SteelSS304L),
+    ("Copper", SolidMaterial::Copper),
+    ("Fiberglass", SolidMaterial::Fiberglass),
+    ("PyrogelHPS", SolidMaterial::PyrogelHPS),
+];
+
+/// finds a liquid by its variant name, eg. "TherminolVP1"
+///
+/// custom liquids have no name since their correlations are
+/// function pointers
+pub fn try_get_liquid_material_from_name(name: &str)
+    -> Result<LiquidMaterial, TuasLibError> {
+
+    LIQUID_MATERIALS.iter()
+        .find(|(liquid_name, _)| *liquid_name == name)
+        .map(|(_, liquid)| *liquid)
+        .ok_or_else(|| TuasLibError::GenericStringError(format!(
+            "no liquid named {}, available liquids are {:?}", name,
+            LIQUID_MATERIALS.iter().map(|(liquid_name, _)| *liquid_name)
+            .collect::<Vec<&str>>())))
+}
+
+/// finds a solid by its variant name, eg. "SteelSS304L"
+pub fn try_get_solid_material_from_name(name: &str)
+    -> Result<SolidMaterial, TuasLibError> {
+
+    SOLID_MATERIALS.iter()
+        .find(|(solid_name, _)| *solid_name == name)
+        .map(|(_, solid)| *solid)
+        .ok_or_else(|| TuasLibError::GenericStringError(format!(
+            "no solid named {}, available solids are {:?}", name,
+            SOLID_MATERIALS.iter().map(|(solid_name, _)| *solid_name)
+            .collect::<Vec<&str>>())))
+}
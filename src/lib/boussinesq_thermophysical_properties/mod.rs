@@ -86,6 +86,10 @@ pub enum SolidMaterial {
         // surface_roughness
         Length,
     ),
+    /// a solid from the database with explicitly chosen 
+    /// correlations and perturbed properties, for sensitivity
+    /// studies
+    SelectedCorrelations(property_correlations::SolidCorrelations),
 }
 
 impl Into<Material> for SolidMaterial {
@@ -142,6 +146,57 @@ pub enum LiquidMaterial {
     /// Idaho Falls, ID (United States).
     FLiNaK,
 
+    /// Solar Salt, 60-40 wt% NaNO3-KNO3
+    ///
+    /// Data taken from:
+    ///
+    /// Zavoico, A. B. (2001). Solar power tower design basis document
+    /// (No. SAND2001-2100). Sandia National Labs., Albuquerque,
+    /// NM (United States).
+    SolarSalt,
+
+    /// NaCl-MgCl2 eutectic, 58-42 mol%
+    ///
+    /// Data taken from:
+    ///
+    /// Williams, D. F. (2006). Assessment of candidate molten salt
+    /// coolants for the NGNP/NHI heat-transfer loop
+    /// (No. ORNL/TM-2006/69). Oak Ridge National Lab.(ORNL),
+    /// Oak Ridge, TN (United States).
+    NaClMgCl2,
+
+    /// NaF-ZrF4, 59.5-40.5 mol%
+    ///
+    /// Data taken from:
+    ///
+    /// Williams, D. F., Toth, L. M., & Clarno, K. T. (2006). Assessment
+    /// of candidate molten salt coolants for the advanced high temperature
+    /// reactor (AHTR) (No. ORNL/TM-2006/12). Oak Ridge National
+    /// Lab.(ORNL), Oak Ridge, TN (United States).
+    NaFZrF4,
+
+    /// subcooled liquid water at around 1 atm, 0.01-100 C
+    ///
+    /// meant mainly for validation against textbook benchmarks
+    Water,
+
+    /// air as an ideal gas, 250-1000 K
+    ///
+    /// density depends on temperature and pressure, so it is meant 
+    /// for low speed flows (eg. annular air gaps and cooling 
+    /// channels) where the pressure in the channel does not change 
+    /// much
+    Air,
+
+    /// nitrogen (N2) as an ideal gas, 250-1000 K
+    Nitrogen,
+
+    /// helium as an ideal gas, 250-1000 K
+    Helium,
+
+    /// argon as an ideal gas, 250-1000 K
+    Argon,
+
 
     /// Custom fluid, for the user to decide the correlations himself 
     /// or herself
@@ -158,7 +213,15 @@ pub enum LiquidMaterial {
         fn(ThermodynamicTemperature) -> MassDensity,
     ),
 
+    /// a liquid from the database with explicitly chosen 
+    /// correlations (eg. FLiBe viscosity from Cantor or Abe) 
+    /// and perturbed properties, for sensitivity studies
+    SelectedCorrelations(property_correlations::LiquidCorrelations),
 
+    /// a liquid from the database whose density and specific 
+    /// enthalpy depend on pressure through its isothermal 
+    /// compressibility, for pressurised or high head loops
+    WeaklyCompressible(weakly_compressible::WeaklyCompressibleLiquid),
 
 }
 
@@ -269,6 +332,24 @@ pub mod liquid_database;
 /// database for solids 
 pub mod solid_database;
 
+/// freezing and melting of liquids (enthalpy method),
+/// including latent heat and mushy zones
+pub mod phase_change;
+
+/// looks up liquids and solids in the database by name, for
+/// input files and bindings
+pub mod material_names;
+
+/// explicit choice of correlations for a material, with their
+/// published uncertainties and perturbations for sensitivity 
+/// studies
+pub mod property_correlations;
+
+/// liquids whose density and specific enthalpy depend on 
+/// pressure through an isothermal compressibility, rather than 
+/// on temperature alone
+pub mod weakly_compressible;
+
 
 
 
@@ -0,0 +1,507 @@
+//! Freezing and melting for liquids (mainly salts) using an
+//! enthalpy method
+//!
+//! The liquid correlations in the liquid database only hold above
+//! the melting point. When salts freeze in a loop (eg. during
+//! loss of heating or overcooling transients), we need to know
+//! how much latent heat is released and how much of the node is
+//! solid.
+//!
+//! The specific enthalpy is referenced to the liquid correlations,
+//! such that above the liquidus temperature, the specific enthalpy
+//! is exactly that of try_get_h. Below the liquidus temperature:
+//!
+//! h(T) = h_l(T_l) - (1 - f_l) L - cp (T_l - T)
+//!
+//! where f_l is the liquid fraction, L is latent heat of fusion and
+//! cp is the heat capacity at the lower bound of the liquid
+//! correlations. Within the mushy zone (solidus to liquidus),
+//! f_l varies linearly with temperature. For eutectics and pure
+//! substances, the solidus and liquidus temperatures are the same,
+//! and the phase change happens on an isothermal plateau.
+//!
+//! Voller, V. R., & Prakash, C. (1987). A fixed grid numerical
+//! modelling methodology for convection-diffusion mushy region
+//! phase-change problems. International journal of heat and mass
+//! transfer, 30(8), 1709-1719.
+use uom::si::f64::*;
+use uom::si::available_energy::{joule_per_kilogram, kilojoule_per_kilogram};
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::temperature_interval::kelvin as interval_kelvin;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::LiquidMaterial;
+use super::Material;
+use super::specific_enthalpy::try_get_h;
+use super::specific_enthalpy::try_get_temperature_from_h;
+use super::specific_heat_capacity::try_get_cp;
+
+/// contains the solidus temperature, liquidus temperature
+/// and latent heat of fusion for a liquid which may freeze
+///
+/// also contains a constant used to penalise flow through
+/// partially frozen (mushy) fluid
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct PhaseChangeProperties {
+    /// temperature below which the material is fully solid
+    pub solidus_temperature: ThermodynamicTemperature,
+    /// temperature above which the material is fully liquid
+    pub liquidus_temperature: ThermodynamicTemperature,
+    /// latent heat of fusion
+    pub latent_heat_of_fusion: AvailableEnergy,
+    /// constant for the Carman-Kozeny type flow resistance
+    /// multiplier in the mushy zone,
+    ///
+    /// multiplier = 1 + C (1-f_l)^2/(f_l^3 + epsilon)
+    pub mushy_zone_flow_resistance_constant: Ratio,
+}
+
+/// default mushy zone constant for the flow resistance multiplier
+/// at 10% solid, friction goes up by about 15 times,
+/// at 50% solid, by about 2000 times
+const DEFAULT_MUSHY_ZONE_FLOW_RESISTANCE_CONSTANT: f64 = 1.0e3;
+
+/// prevents division by zero in the flow resistance multiplier
+/// when the fluid is fully frozen
+const MUSHY_ZONE_FLOW_RESISTANCE_EPSILON: f64 = 1.0e-3;
+
+impl PhaseChangeProperties {
+
+    /// constructs a new set of phase change properties,
+    /// solidus temperature must not be higher than liquidus
+    /// temperature, and latent heat cannot be negative
+    pub fn new(solidus_temperature: ThermodynamicTemperature,
+        liquidus_temperature: ThermodynamicTemperature,
+        latent_heat_of_fusion: AvailableEnergy)
+        -> Result<Self, TuasLibError> {
+
+        if solidus_temperature > liquidus_temperature {
+            return Err(TuasLibError::GenericStringError(
+                    "solidus temperature must not be above liquidus temperature"
+                    .to_string()));
+        }
+
+        if latent_heat_of_fusion.value < 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                    "latent heat of fusion cannot be negative"
+                    .to_string()));
+        }
+
+        return Ok(Self {
+            solidus_temperature,
+            liquidus_temperature,
+            latent_heat_of_fusion,
+            mushy_zone_flow_resistance_constant:
+                Ratio::new::<ratio>(DEFAULT_MUSHY_ZONE_FLOW_RESISTANCE_CONSTANT),
+        });
+    }
+
+    /// constructs phase change properties for materials melting
+    /// at one temperature, eg. eutectics and pure substances
+    pub fn new_isothermal(melting_temperature: ThermodynamicTemperature,
+        latent_heat_of_fusion: AvailableEnergy)
+        -> Result<Self, TuasLibError> {
+        Self::new(melting_temperature, melting_temperature,
+            latent_heat_of_fusion)
+    }
+
+    /// gets the equilibrium liquid fraction at a given temperature
+    ///
+    /// for isothermal phase change, the liquid fraction at
+    /// the melting temperature is ambiguous, and so this returns 1.0
+    /// (fully liquid) at the melting temperature
+    #[inline]
+    pub fn get_liquid_fraction(&self,
+        temperature: ThermodynamicTemperature) -> Ratio {
+
+        if temperature >= self.liquidus_temperature {
+            return Ratio::new::<ratio>(1.0);
+        }
+
+        if temperature <= self.solidus_temperature {
+            return Ratio::new::<ratio>(0.0);
+        }
+
+        let mushy_zone_width_kelvin =
+            self.liquidus_temperature.get::<kelvin>()
+            - self.solidus_temperature.get::<kelvin>();
+
+        let liquid_fraction =
+            (temperature.get::<kelvin>()
+             - self.solidus_temperature.get::<kelvin>())
+            / mushy_zone_width_kelvin;
+
+        return Ratio::new::<ratio>(liquid_fraction);
+    }
+
+    /// the liquid correlations only hold above the minimum
+    /// temperature of the material. Below that, properties
+    /// (density, viscosity etc.) are evaluated at the minimum
+    /// temperature
+    #[inline]
+    pub fn get_property_evaluation_temperature(material: Material,
+        temperature: ThermodynamicTemperature) -> ThermodynamicTemperature {
+
+        let min_temperature = material.min_temperature();
+
+        if temperature < min_temperature {
+            return min_temperature;
+        }
+
+        return temperature;
+    }
+
+    /// specific enthalpy of the liquid branch,
+    /// below the lower bound of the liquid correlations,
+    /// the heat capacity at the lower bound is used to extrapolate
+    fn try_get_liquid_branch_h(material: Material,
+        temperature: ThermodynamicTemperature,
+        pressure: Pressure) -> Result<AvailableEnergy, TuasLibError> {
+
+        let min_temperature = material.min_temperature();
+
+        if temperature >= min_temperature {
+            return try_get_h(material, temperature, pressure);
+        }
+
+        let h_min = try_get_h(material, min_temperature, pressure)?;
+        let cp_min = try_get_cp(material, min_temperature, pressure)?;
+
+        let delta_t = TemperatureInterval::new::<interval_kelvin>(
+            min_temperature.get::<kelvin>() - temperature.get::<kelvin>());
+
+        return Ok(h_min - cp_min * delta_t);
+    }
+
+    /// returns specific enthalpy for a given temperature and
+    /// liquid fraction
+    ///
+    /// the liquid fraction is needed to tell how far along the
+    /// isothermal plateau a node is, for materials with a finite
+    /// mushy zone width, it should be consistent with the temperature
+    pub fn try_get_h_from_temperature_and_liquid_fraction(&self,
+        material: Material,
+        temperature: ThermodynamicTemperature,
+        liquid_fraction: Ratio,
+        pressure: Pressure) -> Result<AvailableEnergy, TuasLibError> {
+
+        if temperature > self.liquidus_temperature {
+            return Self::try_get_liquid_branch_h(material,
+                temperature, pressure);
+        }
+
+        let h_liquidus = Self::try_get_liquid_branch_h(material,
+            self.liquidus_temperature, pressure)?;
+        let cp_min = try_get_cp(material,
+            material.min_temperature(), pressure)?;
+
+        let liquid_fraction = liquid_fraction.get::<ratio>()
+            .max(0.0).min(1.0);
+
+        let subcooling = TemperatureInterval::new::<interval_kelvin>(
+            self.liquidus_temperature.get::<kelvin>()
+            - temperature.get::<kelvin>());
+
+        let specific_enthalpy = h_liquidus
+            - (1.0 - liquid_fraction) * self.latent_heat_of_fusion
+            - cp_min * subcooling;
+
+        return Ok(specific_enthalpy);
+    }
+
+    /// returns specific enthalpy for a given temperature assuming
+    /// phase equilibrium
+    #[inline]
+    pub fn try_get_h(&self,
+        material: Material,
+        temperature: ThermodynamicTemperature,
+        pressure: Pressure) -> Result<AvailableEnergy, TuasLibError> {
+
+        let liquid_fraction = self.get_liquid_fraction(temperature);
+
+        self.try_get_h_from_temperature_and_liquid_fraction(
+            material, temperature, liquid_fraction, pressure)
+    }
+
+    /// inverts the specific enthalpy to obtain both temperature
+    /// and liquid fraction
+    ///
+    /// within the mushy zone, the temperature is found directly
+    /// since enthalpy is linear in temperature. For isothermal
+    /// phase change, temperature stays at the melting point and
+    /// the liquid fraction is found from the enthalpy instead
+    pub fn try_get_temperature_and_liquid_fraction_from_h(&self,
+        material: Material,
+        specific_enthalpy: AvailableEnergy,
+        pressure: Pressure)
+        -> Result<(ThermodynamicTemperature, Ratio), TuasLibError> {
+
+        let min_temperature = material.min_temperature();
+        let h_liquidus = Self::try_get_liquid_branch_h(material,
+            self.liquidus_temperature, pressure)?;
+        let cp_min = try_get_cp(material, min_temperature, pressure)?;
+
+        // fully liquid
+        if specific_enthalpy >= h_liquidus {
+
+            let h_min = try_get_h(material, min_temperature, pressure)?;
+
+            // within the range of the liquid correlations
+            if specific_enthalpy >= h_min {
+                let temperature = try_get_temperature_from_h(
+                    material, specific_enthalpy, pressure)?;
+                return Ok((temperature, Ratio::new::<ratio>(1.0)));
+            }
+
+            // subcooled liquid below the correlation range
+            let temperature_kelvin = min_temperature.get::<kelvin>()
+                - ((h_min - specific_enthalpy)/cp_min).value;
+
+            return Ok((ThermodynamicTemperature::new::<kelvin>(
+                        temperature_kelvin), Ratio::new::<ratio>(1.0)));
+        }
+
+        let mushy_zone_width_kelvin =
+            self.liquidus_temperature.get::<kelvin>()
+            - self.solidus_temperature.get::<kelvin>();
+
+        let h_solidus = h_liquidus - self.latent_heat_of_fusion
+            - cp_min * TemperatureInterval::new::<interval_kelvin>(mushy_zone_width_kelvin);
+
+        // fully solid
+        if specific_enthalpy <= h_solidus {
+            let temperature_kelvin = self.solidus_temperature.get::<kelvin>()
+                - ((h_solidus - specific_enthalpy)/cp_min).value;
+
+            return Ok((ThermodynamicTemperature::new::<kelvin>(
+                        temperature_kelvin), Ratio::new::<ratio>(0.0)));
+        }
+
+        // isothermal plateau
+        if mushy_zone_width_kelvin <= 0.0 {
+            let liquid_fraction =
+                ((specific_enthalpy - h_solidus)
+                 /self.latent_heat_of_fusion).get::<ratio>();
+
+            return Ok((self.liquidus_temperature,
+                    Ratio::new::<ratio>(liquid_fraction)));
+        }
+
+        // mushy zone,
+        // h = h_l - (L/(T_l - T_s) + cp) (T_l - T)
+        let apparent_heat_capacity_value =
+            self.latent_heat_of_fusion.get::<joule_per_kilogram>()
+            / mushy_zone_width_kelvin
+            + cp_min.value;
+
+        let temperature_kelvin = self.liquidus_temperature.get::<kelvin>()
+            - (h_liquidus - specific_enthalpy).value
+            / apparent_heat_capacity_value;
+
+        let temperature = ThermodynamicTemperature::new::<kelvin>(
+            temperature_kelvin);
+
+        return Ok((temperature, self.get_liquid_fraction(temperature)));
+    }
+
+    /// multiplier for flow resistance (fldk) when fluid is partially
+    /// frozen
+    ///
+    /// This is a Carman-Kozeny type penalty as is commonly done
+    /// for mushy zones in enthalpy-porosity methods,
+    ///
+    /// multiplier = 1 + C (1-f_l)^2/(f_l^3 + epsilon)
+    ///
+    /// so that fully liquid fluid has a multiplier of one, and
+    /// the multiplier rises sharply as solid fraction increases
+    #[inline]
+    pub fn get_flow_resistance_multiplier(&self,
+        liquid_fraction: Ratio) -> Ratio {
+
+        let liquid_fraction = liquid_fraction.get::<ratio>()
+            .max(0.0).min(1.0);
+        let solid_fraction = 1.0 - liquid_fraction;
+
+        let multiplier = 1.0
+            + self.mushy_zone_flow_resistance_constant.get::<ratio>()
+            * solid_fraction * solid_fraction
+            / (liquid_fraction.powi(3) + MUSHY_ZONE_FLOW_RESISTANCE_EPSILON);
+
+        return Ratio::new::<ratio>(multiplier);
+    }
+}
+
+impl LiquidMaterial {
+
+    /// returns freezing and melting data for liquids in the database
+    ///
+    /// For eutectic salts, latent heat of fusion is often not
+    /// measured directly. Where this is the case, it is estimated
+    /// by an ideal mixing rule of the latent heats of the pure
+    /// components in the salt (in kJ/mol), and should be treated
+    /// as an estimate only.
+    ///
+    /// Liquids without freezing data will return an error, in which
+    /// case, construct the PhaseChangeProperties manually
+    pub fn try_get_phase_change_properties(&self)
+        -> Result<PhaseChangeProperties, TuasLibError> {
+
+        match self {
+            // 333.55 kJ/kg at 0 C
+            LiquidMaterial::Water => {
+                PhaseChangeProperties::new_isothermal(
+                    ThermodynamicTemperature::new::<kelvin>(273.15),
+                    AvailableEnergy::new::<kilojoule_per_kilogram>(333.55))
+            },
+            // melting point 459 C (Romatoski and Hu, 2017)
+            // latent heat is an ideal mixing estimate based on
+            // LiF (27.09 kJ/mol) and BeF2 (4.77 kJ/mol)
+            LiquidMaterial::FLiBe => {
+                PhaseChangeProperties::new_isothermal(
+                    ThermodynamicTemperature::new::<kelvin>(732.15),
+                    AvailableEnergy::new::<kilojoule_per_kilogram>(600.0))
+            },
+            // melting point 454 C (Romatoski and Hu, 2017)
+            // latent heat is an ideal mixing estimate based on
+            // LiF (27.09 kJ/mol), NaF (33.35 kJ/mol) and KF (27.2 kJ/mol)
+            LiquidMaterial::FLiNaK => {
+                PhaseChangeProperties::new_isothermal(
+                    ThermodynamicTemperature::new::<kelvin>(727.15),
+                    AvailableEnergy::new::<kilojoule_per_kilogram>(675.0))
+            },
+            // solidus 221 C, liquidus 238 C (Zavoico, 2001)
+            // latent heat is a mass weighted estimate based on
+            // NaNO3 (15.7 kJ/mol) and KNO3 (10.1 kJ/mol)
+            LiquidMaterial::SolarSalt => {
+                PhaseChangeProperties::new(
+                    ThermodynamicTemperature::new::<kelvin>(494.15),
+                    ThermodynamicTemperature::new::<kelvin>(511.15),
+                    AvailableEnergy::new::<kilojoule_per_kilogram>(150.0))
+            },
+            // melting point 445 C (Williams, 2006)
+            // latent heat is an ideal mixing estimate based on
+            // NaCl (28.16 kJ/mol) and MgCl2 (43.1 kJ/mol)
+            LiquidMaterial::NaClMgCl2 => {
+                PhaseChangeProperties::new_isothermal(
+                    ThermodynamicTemperature::new::<kelvin>(718.15),
+                    AvailableEnergy::new::<kilojoule_per_kilogram>(466.0))
+            },
+            // freezing data of the liquid in the database
+            LiquidMaterial::SelectedCorrelations(correlations) => {
+                LiquidMaterial::from(correlations.liquid)
+                    .try_get_phase_change_properties()
+            },
+            LiquidMaterial::WeaklyCompressible(liquid) => {
+                LiquidMaterial::from(liquid.correlations.liquid)
+                    .try_get_phase_change_properties()
+            },
+            _ => {
+                Err(TuasLibError::GenericStringError(
+                        "no freezing data for this liquid in the database, \
+                        please construct PhaseChangeProperties manually"
+                        .to_string()))
+            },
+        }
+    }
+}
+
+/// checks that enthalpy to temperature inversion gets back
+/// the temperatures in the solid, mushy and liquid regions
+/// for solar salt
+#[test]
+pub fn solar_salt_phase_change_round_trip(){
+
+    use uom::si::pressure::atmosphere;
+
+    let solar_salt = LiquidMaterial::SolarSalt;
+    let material: Material = solar_salt.into();
+    let pressure = Pressure::new::<atmosphere>(1.0);
+    let phase_change = solar_salt.try_get_phase_change_properties().unwrap();
+
+    // solid, mushy, subcooled liquid, and liquid within correlation range
+    for temperature_kelvin in [450.0, 500.0, 520.0, 600.0] {
+
+        let temperature = ThermodynamicTemperature::new::<kelvin>(
+            temperature_kelvin);
+
+        let h = phase_change.try_get_h(material, temperature, pressure)
+            .unwrap();
+
+        let (temperature_test, liquid_fraction) = phase_change
+            .try_get_temperature_and_liquid_fraction_from_h(
+                material, h, pressure).unwrap();
+
+        approx::assert_abs_diff_eq!(
+            temperature_test.get::<kelvin>(),
+            temperature_kelvin,
+            epsilon=0.01);
+
+        approx::assert_abs_diff_eq!(
+            liquid_fraction.get::<ratio>(),
+            phase_change.get_liquid_fraction(temperature).get::<ratio>(),
+            epsilon=1e-6);
+    }
+
+    // the enthalpy difference across the mushy zone should be the
+    // latent heat plus sensible heat
+    let h_liquidus = phase_change.try_get_h(material,
+        phase_change.liquidus_temperature, pressure).unwrap();
+    let h_solidus = phase_change.try_get_h(material,
+        phase_change.solidus_temperature, pressure).unwrap();
+    let cp_min = try_get_cp(material,
+        material.min_temperature(), pressure).unwrap();
+
+    approx::assert_relative_eq!(
+        (h_liquidus - h_solidus).get::<kilojoule_per_kilogram>(),
+        150.0 + cp_min.value * 17.0 / 1000.0,
+        max_relative=1e-9);
+}
+
+/// for isothermal phase change (FLiBe), enthalpy in between solid
+/// and liquid should give the melting temperature with a partial
+/// liquid fraction, and flow resistance should rise as it freezes
+#[test]
+pub fn flibe_isothermal_plateau_liquid_fraction(){
+
+    use uom::si::pressure::atmosphere;
+
+    let flibe = LiquidMaterial::FLiBe;
+    let material: Material = flibe.into();
+    let pressure = Pressure::new::<atmosphere>(1.0);
+    let phase_change = flibe.try_get_phase_change_properties().unwrap();
+
+    let melting_temperature = phase_change.liquidus_temperature;
+
+    let h_quarter_liquid = phase_change
+        .try_get_h_from_temperature_and_liquid_fraction(
+            material, melting_temperature,
+            Ratio::new::<ratio>(0.25), pressure).unwrap();
+
+    let (temperature_test, liquid_fraction) = phase_change
+        .try_get_temperature_and_liquid_fraction_from_h(
+            material, h_quarter_liquid, pressure).unwrap();
+
+    approx::assert_abs_diff_eq!(
+        temperature_test.get::<kelvin>(),
+        melting_temperature.get::<kelvin>(),
+        epsilon=1e-9);
+
+    approx::assert_abs_diff_eq!(
+        liquid_fraction.get::<ratio>(),
+        0.25,
+        epsilon=1e-9);
+
+    // flow resistance
+    let fully_liquid = phase_change.get_flow_resistance_multiplier(
+        Ratio::new::<ratio>(1.0));
+    let mostly_liquid = phase_change.get_flow_resistance_multiplier(
+        Ratio::new::<ratio>(0.9));
+    let mostly_solid = phase_change.get_flow_resistance_multiplier(
+        Ratio::new::<ratio>(0.1));
+
+    approx::assert_relative_eq!(fully_liquid.get::<ratio>(), 1.0);
+    assert!(mostly_liquid.get::<ratio>() > 10.0);
+    assert!(mostly_solid.get::<ratio>() > 1.0e5);
+}
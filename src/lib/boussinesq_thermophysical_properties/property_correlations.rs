@@ -0,0 +1,1044 @@
+//! Explicit choice of property correlations for a material, along
+//! with their published uncertainties
+//!
+//! Each LiquidMaterial and SolidMaterial in the database uses one
+//! correlation per property (eg. FLiBe viscosity switches from
+//! Gierszewski's to Abe's correlation at 1200 K, and steel thermal
+//! conductivity falls back from one spline to another). Where more
+//! than one correlation is available in the database,
+//! LiquidCorrelations and SolidCorrelations let the user choose
+//! which one to use for a material instance:
+//!
+//! ```rust
+//! use uom::si::f64::*;
+//! use uom::si::thermodynamic_temperature::kelvin;
+//! use tuas_boussinesq_solver::boussinesq_thermophysical_properties::*;
+//! use tuas_boussinesq_solver::boussinesq_thermophysical_properties
+//! ::property_correlations::*;
+//!
+//! let flibe_cantor: LiquidMaterial = LiquidCorrelations::try_new(LiquidMaterial::FLiBe)
+//!     .unwrap()
+//!     .try_with_dynamic_viscosity(LiquidDynamicViscosityCorrelation::FLiBeCantor)
+//!     .unwrap()
+//!     .into();
+//!
+//! let temperature = ThermodynamicTemperature::new::<kelvin>(900.0);
+//! let viscosity = flibe_cantor.try_get_dynamic_viscosity(temperature).unwrap();
+//! ```
+//!
+//! They also carry a relative perturbation for each property, so that
+//! properties can be perturbed systematically (eg. by one published
+//! uncertainty) for sensitivity studies. Perturbations scale the
+//! property from the chosen correlation, and a perturbation of the
+//! specific heat capacity scales the specific enthalpy by the same
+//! factor so that enthalpy and heat capacity remain consistent.
+//!
+//! Published uncertainties are given in percent where the source (or
+//! the fit used in this library) states one, and are None otherwise.
+use uom::si::f64::*;
+use uom::si::pressure::atmosphere;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::density::try_get_rho;
+use super::dynamic_viscosity::try_get_mu_viscosity;
+use super::liquid_database::flibe::*;
+use super::solid_database::ss_304_l::*;
+use super::specific_enthalpy::{try_get_h, try_get_temperature_from_h};
+use super::specific_heat_capacity::try_get_cp;
+use super::thermal_conductivity::try_get_kappa_thermal_conductivity;
+use super::{LiquidMaterial, Material, SolidMaterial};
+
+/// properties which have correlations and uncertainties
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum ThermophysicalProperty {
+    /// density
+    Density,
+    /// isobaric specific heat capacity (and with it, specific enthalpy)
+    SpecificHeatCapacity,
+    /// thermal conductivity
+    ThermalConductivity,
+    /// dynamic viscosity, liquids only
+    DynamicViscosity,
+}
+
+/// relative perturbation of each property, eg. 0.1 multiplies the
+/// property by 1.1 and -0.1 multiplies it by 0.9
+///
+/// perturbations must be greater than -1
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub struct PropertyPerturbation {
+    /// relative perturbation of density
+    pub density: f64,
+    /// relative perturbation of specific heat capacity and
+    /// specific enthalpy
+    pub specific_heat_capacity: f64,
+    /// relative perturbation of thermal conductivity
+    pub thermal_conductivity: f64,
+    /// relative perturbation of dynamic viscosity
+    pub dynamic_viscosity: f64,
+}
+
+impl PropertyPerturbation {
+
+    /// relative perturbation of a property
+    pub fn get(&self, property: ThermophysicalProperty) -> f64 {
+        match property {
+            ThermophysicalProperty::Density => self.density,
+            ThermophysicalProperty::SpecificHeatCapacity => self.specific_heat_capacity,
+            ThermophysicalProperty::ThermalConductivity => self.thermal_conductivity,
+            ThermophysicalProperty::DynamicViscosity => self.dynamic_viscosity,
+        }
+    }
+
+    /// sets the relative perturbation of a property
+    pub fn set(&mut self, property: ThermophysicalProperty, relative_perturbation: f64) {
+        match property {
+            ThermophysicalProperty::Density => self.density = relative_perturbation,
+            ThermophysicalProperty::SpecificHeatCapacity =>
+                self.specific_heat_capacity = relative_perturbation,
+            ThermophysicalProperty::ThermalConductivity =>
+                self.thermal_conductivity = relative_perturbation,
+            ThermophysicalProperty::DynamicViscosity =>
+                self.dynamic_viscosity = relative_perturbation,
+        }
+    }
+
+    /// factor which the property is multiplied by
+    pub fn try_get_factor(&self, property: ThermophysicalProperty)
+        -> Result<f64, TuasLibError> {
+
+        let relative_perturbation = self.get(property);
+        if !relative_perturbation.is_finite() || relative_perturbation <= -1.0 {
+            return Err(TuasLibError::GenericStringError(format!(
+                "perturbation of {:?} must be finite and greater than -1, got {}",
+                property, relative_perturbation)));
+        }
+        Ok(1.0 + relative_perturbation)
+    }
+}
+
+/// published uncertainties of the chosen correlations in percent,
+/// None where no uncertainty is given
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub struct PropertyUncertainties {
+    /// uncertainty of density (%)
+    pub density_percent: Option<f64>,
+    /// uncertainty of specific heat capacity (%)
+    pub specific_heat_capacity_percent: Option<f64>,
+    /// uncertainty of thermal conductivity (%)
+    pub thermal_conductivity_percent: Option<f64>,
+    /// uncertainty of dynamic viscosity (%)
+    pub dynamic_viscosity_percent: Option<f64>,
+}
+
+impl PropertyUncertainties {
+
+    /// uncertainty of a property in percent
+    pub fn get_percent(&self, property: ThermophysicalProperty) -> Option<f64> {
+        match property {
+            ThermophysicalProperty::Density => self.density_percent,
+            ThermophysicalProperty::SpecificHeatCapacity => self.specific_heat_capacity_percent,
+            ThermophysicalProperty::ThermalConductivity => self.thermal_conductivity_percent,
+            ThermophysicalProperty::DynamicViscosity => self.dynamic_viscosity_percent,
+        }
+    }
+}
+
+/// liquids in the database which correlations can be chosen for,
+/// the same as the LiquidMaterial variants without custom liquids
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum CorrelatedLiquid {
+    /// therminol VP1
+    TherminolVP1,
+    /// DowthermA
+    DowthermA,
+    /// HITEC salt
+    HITEC,
+    /// YD-325 heat transfer oil
+    YD325,
+    /// FLiBe
+    FLiBe,
+    /// FLiNaK
+    FLiNaK,
+    /// solar salt
+    SolarSalt,
+    /// NaCl-MgCl2 eutectic
+    NaClMgCl2,
+    /// NaF-ZrF4
+    NaFZrF4,
+    /// liquid water
+    Water,
+    /// air
+    Air,
+    /// nitrogen
+    Nitrogen,
+    /// helium
+    Helium,
+    /// argon
+    Argon,
+}
+
+impl From<CorrelatedLiquid> for LiquidMaterial {
+    fn from(liquid: CorrelatedLiquid) -> Self {
+        match liquid {
+            CorrelatedLiquid::TherminolVP1 => LiquidMaterial::TherminolVP1,
+            CorrelatedLiquid::DowthermA => LiquidMaterial::DowthermA,
+            CorrelatedLiquid::HITEC => LiquidMaterial::HITEC,
+            CorrelatedLiquid::YD325 => LiquidMaterial::YD325,
+            CorrelatedLiquid::FLiBe => LiquidMaterial::FLiBe,
+            CorrelatedLiquid::FLiNaK => LiquidMaterial::FLiNaK,
+            CorrelatedLiquid::SolarSalt => LiquidMaterial::SolarSalt,
+            CorrelatedLiquid::NaClMgCl2 => LiquidMaterial::NaClMgCl2,
+            CorrelatedLiquid::NaFZrF4 => LiquidMaterial::NaFZrF4,
+            CorrelatedLiquid::Water => LiquidMaterial::Water,
+            CorrelatedLiquid::Air => LiquidMaterial::Air,
+            CorrelatedLiquid::Nitrogen => LiquidMaterial::Nitrogen,
+            CorrelatedLiquid::Helium => LiquidMaterial::Helium,
+            CorrelatedLiquid::Argon => LiquidMaterial::Argon,
+        }
+    }
+}
+
+impl TryFrom<LiquidMaterial> for CorrelatedLiquid {
+    type Error = TuasLibError;
+
+    fn try_from(liquid: LiquidMaterial) -> Result<Self, Self::Error> {
+        match liquid {
+            LiquidMaterial::TherminolVP1 => Ok(CorrelatedLiquid::TherminolVP1),
+            LiquidMaterial::DowthermA => Ok(CorrelatedLiquid::DowthermA),
+            LiquidMaterial::HITEC => Ok(CorrelatedLiquid::HITEC),
+            LiquidMaterial::YD325 => Ok(CorrelatedLiquid::YD325),
+            LiquidMaterial::FLiBe => Ok(CorrelatedLiquid::FLiBe),
+            LiquidMaterial::FLiNaK => Ok(CorrelatedLiquid::FLiNaK),
+            LiquidMaterial::SolarSalt => Ok(CorrelatedLiquid::SolarSalt),
+            LiquidMaterial::NaClMgCl2 => Ok(CorrelatedLiquid::NaClMgCl2),
+            LiquidMaterial::NaFZrF4 => Ok(CorrelatedLiquid::NaFZrF4),
+            LiquidMaterial::Water => Ok(CorrelatedLiquid::Water),
+            LiquidMaterial::Air => Ok(CorrelatedLiquid::Air),
+            LiquidMaterial::Nitrogen => Ok(CorrelatedLiquid::Nitrogen),
+            LiquidMaterial::Helium => Ok(CorrelatedLiquid::Helium),
+            LiquidMaterial::Argon => Ok(CorrelatedLiquid::Argon),
+            LiquidMaterial::SelectedCorrelations(correlations) => Ok(correlations.liquid),
+            LiquidMaterial::WeaklyCompressible(liquid) => Ok(liquid.correlations.liquid),
+            LiquidMaterial::CustomLiquid(..) => Err(TuasLibError::GenericStringError(
+                "custom liquids have no correlations to choose from, \
+                change their functions instead".to_owned())),
+        }
+    }
+}
+
+/// solids in the database which correlations can be chosen for,
+/// the same as the SolidMaterial variants without custom solids
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum CorrelatedSolid {
+    /// stainless steel 304 L
+    SteelSS304L,
+    /// copper
+    Copper,
+    /// fiberglass
+    Fiberglass,
+    /// Pyrogel HPS
+    PyrogelHPS,
+}
+
+impl From<CorrelatedSolid> for SolidMaterial {
+    fn from(solid: CorrelatedSolid) -> Self {
+        match solid {
+            CorrelatedSolid::SteelSS304L => SolidMaterial::SteelSS304L,
+            CorrelatedSolid::Copper => SolidMaterial::Copper,
+            CorrelatedSolid::Fiberglass => SolidMaterial::Fiberglass,
+            CorrelatedSolid::PyrogelHPS => SolidMaterial::PyrogelHPS,
+        }
+    }
+}
+
+impl TryFrom<SolidMaterial> for CorrelatedSolid {
+    type Error = TuasLibError;
+
+    fn try_from(solid: SolidMaterial) -> Result<Self, Self::Error> {
+        match solid {
+            SolidMaterial::SteelSS304L => Ok(CorrelatedSolid::SteelSS304L),
+            SolidMaterial::Copper => Ok(CorrelatedSolid::Copper),
+            SolidMaterial::Fiberglass => Ok(CorrelatedSolid::Fiberglass),
+            SolidMaterial::PyrogelHPS => Ok(CorrelatedSolid::PyrogelHPS),
+            SolidMaterial::SelectedCorrelations(correlations) => Ok(correlations.solid),
+            SolidMaterial::CustomSolid(..) => Err(TuasLibError::GenericStringError(
+                "custom solids have no correlations to choose from, \
+                change their functions instead".to_owned())),
+        }
+    }
+}
+
+/// published uncertainties (%) of the correlations which the
+/// LiquidMaterial variants use
+///
+/// these are from the sources cited in the liquid database:
+/// Romatoski and Hu (2017) for FLiBe and FLiNaK, Sohal et al. (2010)
+/// for HITEC viscosity (Janz's error bars), Williams (2006) for the
+/// NaCl-MgCl2 viscosity estimate, and the deviation of the fits from
+/// NIST data for water
+fn get_database_liquid_uncertainties(liquid: CorrelatedLiquid) -> PropertyUncertainties {
+    match liquid {
+        CorrelatedLiquid::FLiBe => PropertyUncertainties {
+            density_percent: Some(2.0),
+            specific_heat_capacity_percent: Some(3.0),
+            thermal_conductivity_percent: Some(10.0),
+            dynamic_viscosity_percent: Some(20.0),
+        },
+        CorrelatedLiquid::FLiNaK => PropertyUncertainties {
+            density_percent: Some(2.0),
+            specific_heat_capacity_percent: Some(10.0),
+            ..Default::default()
+        },
+        CorrelatedLiquid::HITEC => PropertyUncertainties {
+            dynamic_viscosity_percent: Some(16.0),
+            ..Default::default()
+        },
+        CorrelatedLiquid::NaClMgCl2 => PropertyUncertainties {
+            dynamic_viscosity_percent: Some(20.0),
+            ..Default::default()
+        },
+        CorrelatedLiquid::Water => PropertyUncertainties {
+            specific_heat_capacity_percent: Some(0.03),
+            thermal_conductivity_percent: Some(0.5),
+            dynamic_viscosity_percent: Some(1.0),
+            ..Default::default()
+        },
+        _ => PropertyUncertainties::default(),
+    }
+}
+
+/// density correlations for liquids
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum LiquidDensityCorrelation {
+    /// the correlation the LiquidMaterial variant uses
+    Database,
+    /// FLiBe, Romatoski and Sohal, 2415.6 - 0.49072 T(K),
+    /// the FLiBe default
+    FLiBeRomatoski,
+    /// FLiBe, Janz, 2413 - 0.4884 T(K)
+    FLiBeJanz,
+}
+
+/// dynamic viscosity correlations for liquids
+///
+/// there is no FLiBe viscosity correlation from Janz (1988), which
+/// is only used here for FLiBe density. Its viscosity coefficients
+/// for 66 mol% LiF, 34 mol% BeF2 have not been checked against the
+/// source, and the Cantor, Gierszewski and Abe correlations quoted
+/// by Romatoski and Hu already span the 20% spread of the FLiBe
+/// viscosity data over the liquid range
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum LiquidDynamicViscosityCorrelation {
+    /// the correlation the LiquidMaterial variant uses
+    Database,
+    /// FLiBe, correlations reviewed by Romatoski, Gierszewski's
+    /// up to 1200 K and Abe's above it, the FLiBe default
+    FLiBeRomatoski,
+    /// FLiBe, Cantor et al. (ORNL-TM-2316)
+    FLiBeCantor,
+    /// FLiBe, Abe et al. over the whole liquid range
+    FLiBeAbe,
+}
+
+/// thermal conductivity correlations for liquids
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum LiquidThermalConductivityCorrelation {
+    /// the correlation the LiquidMaterial variant uses
+    Database,
+    /// FLiBe, Sohal's correlation fitted to Romatoski's data in
+    /// Ong's thesis, the FLiBe default
+    FLiBeSohal,
+    /// FLiBe, a constant 1.1 W/(m K) from Romatoski's data
+    FLiBeRomatoski,
+}
+
+impl LiquidDensityCorrelation {
+    /// liquid which the correlation is for, None if it is the
+    /// database correlation of any liquid
+    pub fn get_liquid(&self) -> Option<CorrelatedLiquid> {
+        match self {
+            Self::Database => None,
+            Self::FLiBeRomatoski | Self::FLiBeJanz => Some(CorrelatedLiquid::FLiBe),
+        }
+    }
+
+    /// published uncertainty in percent
+    pub fn get_uncertainty_percent(&self, liquid: CorrelatedLiquid) -> Option<f64> {
+        match self {
+            Self::Database => get_database_liquid_uncertainties(liquid).density_percent,
+            Self::FLiBeRomatoski => Some(2.0),
+            Self::FLiBeJanz => Some(2.0),
+        }
+    }
+}
+
+impl LiquidDynamicViscosityCorrelation {
+    /// liquid which the correlation is for, None if it is the
+    /// database correlation of any liquid
+    pub fn get_liquid(&self) -> Option<CorrelatedLiquid> {
+        match self {
+            Self::Database => None,
+            Self::FLiBeRomatoski | Self::FLiBeCantor | Self::FLiBeAbe =>
+                Some(CorrelatedLiquid::FLiBe),
+        }
+    }
+
+    /// published uncertainty in percent
+    ///
+    /// the FLiBe data sets reviewed by Romatoski and Hu are spread
+    /// by about 20%, which is used for each of them
+    pub fn get_uncertainty_percent(&self, liquid: CorrelatedLiquid) -> Option<f64> {
+        match self {
+            Self::Database => get_database_liquid_uncertainties(liquid).dynamic_viscosity_percent,
+            Self::FLiBeRomatoski | Self::FLiBeCantor | Self::FLiBeAbe => Some(20.0),
+        }
+    }
+}
+
+impl LiquidThermalConductivityCorrelation {
+    /// liquid which the correlation is for, None if it is the
+    /// database correlation of any liquid
+    pub fn get_liquid(&self) -> Option<CorrelatedLiquid> {
+        match self {
+            Self::Database => None,
+            Self::FLiBeSohal | Self::FLiBeRomatoski => Some(CorrelatedLiquid::FLiBe),
+        }
+    }
+
+    /// published uncertainty in percent
+    pub fn get_uncertainty_percent(&self, liquid: CorrelatedLiquid) -> Option<f64> {
+        match self {
+            Self::Database => get_database_liquid_uncertainties(liquid).thermal_conductivity_percent,
+            Self::FLiBeSohal | Self::FLiBeRomatoski => Some(10.0),
+        }
+    }
+}
+
+/// a liquid from the database with a chosen correlation for each
+/// property and relative perturbations of the properties
+///
+/// specific heat capacity, specific enthalpy and temperature ranges
+/// always come from the database
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct LiquidCorrelations {
+    /// liquid from the database
+    pub liquid: CorrelatedLiquid,
+    /// density correlation
+    pub density: LiquidDensityCorrelation,
+    /// dynamic viscosity correlation
+    pub dynamic_viscosity: LiquidDynamicViscosityCorrelation,
+    /// thermal conductivity correlation
+    pub thermal_conductivity: LiquidThermalConductivityCorrelation,
+    /// relative perturbation of each property
+    pub perturbation: PropertyPerturbation,
+}
+
+impl From<LiquidCorrelations> for LiquidMaterial {
+    fn from(correlations: LiquidCorrelations) -> Self {
+        LiquidMaterial::SelectedCorrelations(correlations)
+    }
+}
+
+impl LiquidCorrelations {
+
+    /// database correlations of a liquid with no perturbation,
+    /// which give the same properties as the liquid itself
+    ///
+    /// for liquids which already have selected correlations,
+    /// these are kept, and for weakly compressible liquids, the
+    /// correlations at their reference pressure are used
+    pub fn try_new(liquid: LiquidMaterial) -> Result<Self, TuasLibError> {
+
+        match liquid {
+            LiquidMaterial::SelectedCorrelations(correlations) => return Ok(correlations),
+            LiquidMaterial::WeaklyCompressible(liquid) => return Ok(liquid.correlations),
+            _ => (),
+        }
+
+        Ok(Self {
+            liquid: liquid.try_into()?,
+            density: LiquidDensityCorrelation::Database,
+            dynamic_viscosity: LiquidDynamicViscosityCorrelation::Database,
+            thermal_conductivity: LiquidThermalConductivityCorrelation::Database,
+            perturbation: PropertyPerturbation::default(),
+        })
+    }
+
+    /// checks that a correlation is meant for this liquid
+    fn try_check_liquid(&self, correlation_liquid: Option<CorrelatedLiquid>,
+        correlation: &str) -> Result<(), TuasLibError> {
+
+        match correlation_liquid {
+            Some(correlation_liquid) if correlation_liquid != self.liquid => {
+                Err(TuasLibError::GenericStringError(format!(
+                    "{} is a correlation for {:?}, not {:?}",
+                    correlation, correlation_liquid, self.liquid)))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// chooses the density correlation
+    pub fn try_with_density(mut self, correlation: LiquidDensityCorrelation)
+        -> Result<Self, TuasLibError> {
+        self.try_check_liquid(correlation.get_liquid(), &format!("{:?}", correlation))?;
+        self.density = correlation;
+        Ok(self)
+    }
+
+    /// chooses the dynamic viscosity correlation
+    pub fn try_with_dynamic_viscosity(mut self,
+        correlation: LiquidDynamicViscosityCorrelation)
+        -> Result<Self, TuasLibError> {
+        self.try_check_liquid(correlation.get_liquid(), &format!("{:?}", correlation))?;
+        self.dynamic_viscosity = correlation;
+        Ok(self)
+    }
+
+    /// chooses the thermal conductivity correlation
+    pub fn try_with_thermal_conductivity(mut self,
+        correlation: LiquidThermalConductivityCorrelation)
+        -> Result<Self, TuasLibError> {
+        self.try_check_liquid(correlation.get_liquid(), &format!("{:?}", correlation))?;
+        self.thermal_conductivity = correlation;
+        Ok(self)
+    }
+
+    /// published uncertainties of the chosen correlations
+    pub fn get_uncertainties(&self) -> PropertyUncertainties {
+        PropertyUncertainties {
+            density_percent: self.density.get_uncertainty_percent(self.liquid),
+            specific_heat_capacity_percent:
+                get_database_liquid_uncertainties(self.liquid).specific_heat_capacity_percent,
+            thermal_conductivity_percent:
+                self.thermal_conductivity.get_uncertainty_percent(self.liquid),
+            dynamic_viscosity_percent:
+                self.dynamic_viscosity.get_uncertainty_percent(self.liquid),
+        }
+    }
+
+    /// perturbs a property by a multiple of its published
+    /// uncertainty, eg. 1.0 raises it by one uncertainty and -2.0
+    /// lowers it by two. This replaces any earlier perturbation of
+    /// the property
+    ///
+    /// fails if the correlation has no published uncertainty, in
+    /// which case the perturbation can be set directly
+    pub fn try_perturb_by_uncertainty(mut self,
+        property: ThermophysicalProperty,
+        multiple_of_uncertainty: f64) -> Result<Self, TuasLibError> {
+
+        let uncertainty_percent = self.get_uncertainties().get_percent(property)
+            .ok_or_else(|| TuasLibError::GenericStringError(format!(
+                "{:?} of {:?} has no published uncertainty", property, self.liquid)))?;
+
+        self.perturbation.set(property, multiple_of_uncertainty * uncertainty_percent / 100.0);
+        self.perturbation.try_get_factor(property)?;
+        Ok(self)
+    }
+
+    /// material of the liquid itself, without the chosen correlations
+    fn get_database_material(&self) -> Material {
+        Material::Liquid(self.liquid.into())
+    }
+
+    /// density from the chosen correlation
+    ///
+    /// pressure is only used by the database correlations of gases
+    pub fn try_get_density(&self,
+        fluid_temp: ThermodynamicTemperature,
+        pressure: Pressure) -> Result<MassDensity, TuasLibError> {
+
+        let density = match self.density {
+            LiquidDensityCorrelation::Database =>
+                try_get_rho(self.get_database_material(), fluid_temp, pressure)?,
+            LiquidDensityCorrelation::FLiBeRomatoski => get_flibe_density(fluid_temp)?,
+            LiquidDensityCorrelation::FLiBeJanz => get_flibe_density_janz(fluid_temp)?,
+        };
+
+        Ok(density * self.perturbation.try_get_factor(ThermophysicalProperty::Density)?)
+    }
+
+    /// dynamic viscosity from the chosen correlation
+    pub fn try_get_dynamic_viscosity(&self,
+        fluid_temp: ThermodynamicTemperature) -> Result<DynamicViscosity, TuasLibError> {
+
+        let dynamic_viscosity = match self.dynamic_viscosity {
+            LiquidDynamicViscosityCorrelation::Database => try_get_mu_viscosity(
+                self.get_database_material(),
+                fluid_temp,
+                Pressure::new::<atmosphere>(1.0))?,
+            LiquidDynamicViscosityCorrelation::FLiBeRomatoski =>
+                get_flibe_dynamic_viscosity(fluid_temp)?,
+            LiquidDynamicViscosityCorrelation::FLiBeCantor =>
+                get_flibe_dynamic_viscosity_cantor(fluid_temp)?,
+            LiquidDynamicViscosityCorrelation::FLiBeAbe =>
+                get_flibe_dynamic_viscosity_abe(fluid_temp)?,
+        };
+
+        Ok(dynamic_viscosity
+            * self.perturbation.try_get_factor(ThermophysicalProperty::DynamicViscosity)?)
+    }
+
+    /// thermal conductivity from the chosen correlation
+    pub fn try_get_thermal_conductivity(&self,
+        fluid_temp: ThermodynamicTemperature) -> Result<ThermalConductivity, TuasLibError> {
+
+        let thermal_conductivity = match self.thermal_conductivity {
+            LiquidThermalConductivityCorrelation::Database => try_get_kappa_thermal_conductivity(
+                self.get_database_material(),
+                fluid_temp,
+                Pressure::new::<atmosphere>(1.0))?,
+            LiquidThermalConductivityCorrelation::FLiBeSohal =>
+                get_flibe_thermal_conductivity(fluid_temp)?,
+            LiquidThermalConductivityCorrelation::FLiBeRomatoski =>
+                get_flibe_thermal_conductivity_romatoski(fluid_temp)?,
+        };
+
+        Ok(thermal_conductivity
+            * self.perturbation.try_get_factor(ThermophysicalProperty::ThermalConductivity)?)
+    }
+
+    /// specific heat capacity from the database
+    pub fn try_get_cp(&self,
+        fluid_temp: ThermodynamicTemperature) -> Result<SpecificHeatCapacity, TuasLibError> {
+
+        let cp = try_get_cp(self.get_database_material(),
+            fluid_temp,
+            Pressure::new::<atmosphere>(1.0))?;
+
+        Ok(cp * self.perturbation.try_get_factor(ThermophysicalProperty::SpecificHeatCapacity)?)
+    }
+
+    /// specific enthalpy from the database, scaled by the
+    /// perturbation of the specific heat capacity
+    pub fn try_get_specific_enthalpy(&self,
+        fluid_temp: ThermodynamicTemperature) -> Result<AvailableEnergy, TuasLibError> {
+
+        let h = try_get_h(self.get_database_material(),
+            fluid_temp,
+            Pressure::new::<atmosphere>(1.0))?;
+
+        Ok(h * self.perturbation.try_get_factor(ThermophysicalProperty::SpecificHeatCapacity)?)
+    }
+
+    /// temperature from specific enthalpy, the inverse of
+    /// try_get_specific_enthalpy
+    pub fn try_get_temperature_from_specific_enthalpy(&self,
+        fluid_enthalpy: AvailableEnergy) -> Result<ThermodynamicTemperature, TuasLibError> {
+
+        let factor = self.perturbation.try_get_factor(
+            ThermophysicalProperty::SpecificHeatCapacity)?;
+
+        try_get_temperature_from_h(self.get_database_material(),
+            fluid_enthalpy / factor,
+            Pressure::new::<atmosphere>(1.0))
+    }
+}
+
+/// thermal conductivity correlations for solids
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum SolidThermalConductivityCorrelation {
+    /// the correlation the SolidMaterial variant uses
+    Database,
+    /// steel 304 L, the linear fit of the CIET spline (Zou, Zweibaum),
+    /// falling back to the CIET spline outside its range, the steel
+    /// default
+    SteelSS304LZweibaumWithSplineFallback,
+    /// steel 304 L, the linear fit of the CIET spline (Zou, Zweibaum)
+    /// without fallback
+    SteelSS304LZweibaum,
+    /// steel 304 L, the CIET cubic spline (Zou, Hu and Charpentier)
+    SteelSS304LZouSpline,
+    /// steel 304 L, Graves et al. (1991) from ORNL, 300-700 K
+    SteelSS304LGraves,
+}
+
+impl SolidThermalConductivityCorrelation {
+    /// solid which the correlation is for, None if it is the
+    /// database correlation of any solid
+    pub fn get_solid(&self) -> Option<CorrelatedSolid> {
+        match self {
+            Self::Database => None,
+            _ => Some(CorrelatedSolid::SteelSS304L),
+        }
+    }
+
+    /// published uncertainty in percent
+    ///
+    /// the residuals of the least squares fit of Graves et al.
+    /// were about 3%. No uncertainty is given for the CIET splines
+    pub fn get_uncertainty_percent(&self) -> Option<f64> {
+        match self {
+            Self::SteelSS304LGraves => Some(3.0),
+            _ => None,
+        }
+    }
+}
+
+/// a solid from the database with a chosen thermal conductivity
+/// correlation and relative perturbations of the properties
+///
+/// density, specific heat capacity, specific enthalpy, surface
+/// roughness and temperature ranges always come from the database.
+/// Perturbations of dynamic viscosity have no effect
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct SolidCorrelations {
+    /// solid from the database
+    pub solid: CorrelatedSolid,
+    /// thermal conductivity correlation
+    pub thermal_conductivity: SolidThermalConductivityCorrelation,
+    /// relative perturbation of each property
+    pub perturbation: PropertyPerturbation,
+}
+
+impl From<SolidCorrelations> for SolidMaterial {
+    fn from(correlations: SolidCorrelations) -> Self {
+        SolidMaterial::SelectedCorrelations(correlations)
+    }
+}
+
+impl SolidCorrelations {
+
+    /// database correlations of a solid with no perturbation,
+    /// which give the same properties as the solid itself
+    ///
+    /// for solids which already have selected correlations,
+    /// these are kept
+    pub fn try_new(solid: SolidMaterial) -> Result<Self, TuasLibError> {
+
+        if let SolidMaterial::SelectedCorrelations(correlations) = solid {
+            return Ok(correlations);
+        }
+
+        Ok(Self {
+            solid: solid.try_into()?,
+            thermal_conductivity: SolidThermalConductivityCorrelation::Database,
+            perturbation: PropertyPerturbation::default(),
+        })
+    }
+
+    /// chooses the thermal conductivity correlation
+    pub fn try_with_thermal_conductivity(mut self,
+        correlation: SolidThermalConductivityCorrelation)
+        -> Result<Self, TuasLibError> {
+
+        match correlation.get_solid() {
+            Some(correlation_solid) if correlation_solid != self.solid => {
+                return Err(TuasLibError::GenericStringError(format!(
+                    "{:?} is a correlation for {:?}, not {:?}",
+                    correlation, correlation_solid, self.solid)));
+            },
+            _ => (),
+        }
+        self.thermal_conductivity = correlation;
+        Ok(self)
+    }
+
+    /// published uncertainties of the chosen correlations
+    pub fn get_uncertainties(&self) -> PropertyUncertainties {
+        PropertyUncertainties {
+            thermal_conductivity_percent: self.thermal_conductivity.get_uncertainty_percent(),
+            ..Default::default()
+        }
+    }
+
+    /// perturbs a property by a multiple of its published
+    /// uncertainty, see LiquidCorrelations::try_perturb_by_uncertainty
+    pub fn try_perturb_by_uncertainty(mut self,
+        property: ThermophysicalProperty,
+        multiple_of_uncertainty: f64) -> Result<Self, TuasLibError> {
+
+        let uncertainty_percent = self.get_uncertainties().get_percent(property)
+            .ok_or_else(|| TuasLibError::GenericStringError(format!(
+                "{:?} of {:?} has no published uncertainty", property, self.solid)))?;
+
+        self.perturbation.set(property, multiple_of_uncertainty * uncertainty_percent / 100.0);
+        self.perturbation.try_get_factor(property)?;
+        Ok(self)
+    }
+
+    /// material of the solid itself, without the chosen correlations
+    fn get_database_material(&self) -> Material {
+        Material::Solid(self.solid.into())
+    }
+
+    /// density from the database
+    pub fn try_get_density(&self,
+        solid_temp: ThermodynamicTemperature) -> Result<MassDensity, TuasLibError> {
+
+        let density = try_get_rho(self.get_database_material(),
+            solid_temp,
+            Pressure::new::<atmosphere>(1.0))?;
+
+        Ok(density * self.perturbation.try_get_factor(ThermophysicalProperty::Density)?)
+    }
+
+    /// thermal conductivity from the chosen correlation
+    pub fn try_get_thermal_conductivity(&self,
+        solid_temp: ThermodynamicTemperature) -> Result<ThermalConductivity, TuasLibError> {
+
+        let thermal_conductivity = match self.thermal_conductivity {
+            SolidThermalConductivityCorrelation::Database
+                | SolidThermalConductivityCorrelation::SteelSS304LZweibaumWithSplineFallback =>
+                SolidMaterial::from(self.solid).try_get_thermal_conductivity(solid_temp)?,
+            SolidThermalConductivityCorrelation::SteelSS304LZweibaum =>
+                steel_304_l_libreoffice_spline_thermal_conductivity_zweibaum(solid_temp)?,
+            SolidThermalConductivityCorrelation::SteelSS304LZouSpline =>
+                steel_304_l_spline_thermal_conductivity(solid_temp)?,
+            SolidThermalConductivityCorrelation::SteelSS304LGraves =>
+                steel_ss_304_l_ornl_thermal_conductivity(solid_temp)?,
+        };
+
+        Ok(thermal_conductivity
+            * self.perturbation.try_get_factor(ThermophysicalProperty::ThermalConductivity)?)
+    }
+
+    /// specific heat capacity from the database
+    pub fn try_get_cp(&self,
+        solid_temp: ThermodynamicTemperature) -> Result<SpecificHeatCapacity, TuasLibError> {
+
+        let cp = try_get_cp(self.get_database_material(),
+            solid_temp,
+            Pressure::new::<atmosphere>(1.0))?;
+
+        Ok(cp * self.perturbation.try_get_factor(ThermophysicalProperty::SpecificHeatCapacity)?)
+    }
+
+    /// specific enthalpy from the database, scaled by the
+    /// perturbation of the specific heat capacity
+    pub fn try_get_specific_enthalpy(&self,
+        solid_temp: ThermodynamicTemperature) -> Result<AvailableEnergy, TuasLibError> {
+
+        let h = try_get_h(self.get_database_material(),
+            solid_temp,
+            Pressure::new::<atmosphere>(1.0))?;
+
+        Ok(h * self.perturbation.try_get_factor(ThermophysicalProperty::SpecificHeatCapacity)?)
+    }
+
+    /// temperature from specific enthalpy, the inverse of
+    /// try_get_specific_enthalpy
+    pub fn try_get_temperature_from_specific_enthalpy(&self,
+        solid_enthalpy: AvailableEnergy) -> Result<ThermodynamicTemperature, TuasLibError> {
+
+        let factor = self.perturbation.try_get_factor(
+            ThermophysicalProperty::SpecificHeatCapacity)?;
+
+        try_get_temperature_from_h(self.get_database_material(),
+            solid_enthalpy / factor,
+            Pressure::new::<atmosphere>(1.0))
+    }
+}
+
+/// with the database correlations and no perturbation, selected
+/// correlations give the same properties as the material itself
+#[test]
+pub fn database_correlations_match_the_database(){
+
+    use uom::si::thermodynamic_temperature::kelvin;
+    use super::prandtl::try_get_prandtl;
+
+    let pressure = Pressure::new::<atmosphere>(1.0);
+
+    for (liquid, temperature_kelvin) in [(LiquidMaterial::FLiBe, 900.0),
+        (LiquidMaterial::HITEC, 600.0), (LiquidMaterial::Air, 400.0)] {
+
+        let temperature = ThermodynamicTemperature::new::<kelvin>(temperature_kelvin);
+        let database: Material = liquid.into();
+        let selected: Material = LiquidMaterial::from(
+            LiquidCorrelations::try_new(liquid).unwrap()).into();
+
+        assert_eq!(try_get_rho(database, temperature, pressure).unwrap(),
+            try_get_rho(selected, temperature, pressure).unwrap());
+        assert_eq!(try_get_prandtl(database, temperature, pressure).unwrap(),
+            try_get_prandtl(selected, temperature, pressure).unwrap());
+
+        let h = try_get_h(database, temperature, pressure).unwrap();
+        assert_eq!(h, try_get_h(selected, temperature, pressure).unwrap());
+        assert_eq!(try_get_temperature_from_h(database, h, pressure).unwrap(),
+            try_get_temperature_from_h(selected, h, pressure).unwrap());
+        assert_eq!(database.max_temperature(), selected.max_temperature());
+    }
+
+    let temperature = ThermodynamicTemperature::new::<kelvin>(400.0);
+    let steel: Material = SolidMaterial::SteelSS304L.into();
+    let selected_steel: Material = SolidMaterial::from(
+        SolidCorrelations::try_new(SolidMaterial::SteelSS304L).unwrap()
+        .try_with_thermal_conductivity(
+            SolidThermalConductivityCorrelation::SteelSS304LZweibaumWithSplineFallback)
+        .unwrap()).into();
+
+    assert_eq!(try_get_kappa_thermal_conductivity(steel, temperature, pressure).unwrap(),
+        try_get_kappa_thermal_conductivity(selected_steel, temperature, pressure).unwrap());
+    assert_eq!(try_get_cp(steel, temperature, pressure).unwrap(),
+        try_get_cp(selected_steel, temperature, pressure).unwrap());
+    assert_eq!(steel.surface_roughness().unwrap(),
+        selected_steel.surface_roughness().unwrap());
+}
+
+/// correlations can only be chosen for the liquid or solid they
+/// are meant for, and change the property they are chosen for only
+#[test]
+pub fn choosing_correlations(){
+
+    use uom::si::thermodynamic_temperature::kelvin;
+
+    let temperature_1300_k = ThermodynamicTemperature::new::<kelvin>(1300.0);
+
+    let flibe_cantor = LiquidCorrelations::try_new(LiquidMaterial::FLiBe).unwrap()
+        .try_with_dynamic_viscosity(LiquidDynamicViscosityCorrelation::FLiBeCantor)
+        .unwrap();
+    let flibe_cantor_material = LiquidMaterial::from(flibe_cantor);
+
+    // above 1200 K, the default switches to Abe's correlation
+    // which is about 20% lower than Cantor's
+    let default_viscosity = LiquidMaterial::FLiBe
+        .try_get_dynamic_viscosity(temperature_1300_k).unwrap();
+    let cantor_viscosity = flibe_cantor_material
+        .try_get_dynamic_viscosity(temperature_1300_k).unwrap();
+    assert_eq!(cantor_viscosity,
+        get_flibe_dynamic_viscosity_cantor(temperature_1300_k).unwrap());
+    assert!(cantor_viscosity > 1.15 * default_viscosity);
+    assert_eq!(flibe_cantor_material.try_get_density(temperature_1300_k).unwrap(),
+        LiquidMaterial::FLiBe.try_get_density(temperature_1300_k).unwrap());
+
+    // and existing choices are kept
+    assert_eq!(LiquidCorrelations::try_new(flibe_cantor_material).unwrap(), flibe_cantor);
+
+    let hitec = LiquidCorrelations::try_new(LiquidMaterial::HITEC).unwrap();
+    assert!(hitec.try_with_density(LiquidDensityCorrelation::FLiBeJanz).is_err());
+    assert!(SolidCorrelations::try_new(SolidMaterial::Copper).unwrap()
+        .try_with_thermal_conductivity(SolidThermalConductivityCorrelation::SteelSS304LGraves)
+        .is_err());
+
+    let custom_liquid = LiquidMaterial::CustomLiquid(
+        (ThermodynamicTemperature::new::<kelvin>(300.0),
+        ThermodynamicTemperature::new::<kelvin>(400.0)),
+        |_| SpecificHeatCapacity::new::<uom::si::specific_heat_capacity::joule_per_kilogram_kelvin>(1000.0),
+        |_| ThermalConductivity::new::<uom::si::thermal_conductivity::watt_per_meter_kelvin>(0.1),
+        |_| DynamicViscosity::new::<uom::si::dynamic_viscosity::pascal_second>(0.001),
+        |_| MassDensity::new::<uom::si::mass_density::kilogram_per_cubic_meter>(1000.0));
+    assert!(LiquidCorrelations::try_new(custom_liquid).is_err());
+
+    // Graves et al. is within 3% of the CIET correlation at 350 K
+    let temperature_350_k = ThermodynamicTemperature::new::<kelvin>(350.0);
+    let steel_graves = SolidCorrelations::try_new(SolidMaterial::SteelSS304L).unwrap()
+        .try_with_thermal_conductivity(SolidThermalConductivityCorrelation::SteelSS304LGraves)
+        .unwrap();
+    approx::assert_relative_eq!(
+        steel_graves.try_get_thermal_conductivity(temperature_350_k).unwrap().value,
+        SolidMaterial::SteelSS304L.try_get_thermal_conductivity(temperature_350_k)
+        .unwrap().value,
+        max_relative=0.03);
+    // Graves et al. only holds up to 700 K
+    assert!(steel_graves.try_get_thermal_conductivity(
+        ThermodynamicTemperature::new::<kelvin>(800.0)).is_err());
+}
+
+/// perturbing by the published uncertainties scales the properties,
+/// and scaling the heat capacity keeps enthalpy consistent
+#[test]
+pub fn perturbing_properties_by_their_uncertainty(){
+
+    use uom::si::thermodynamic_temperature::kelvin;
+
+    let temperature = ThermodynamicTemperature::new::<kelvin>(900.0);
+    let flibe = LiquidCorrelations::try_new(LiquidMaterial::FLiBe).unwrap();
+
+    let uncertainties = flibe.get_uncertainties();
+    assert_eq!(uncertainties.dynamic_viscosity_percent, Some(20.0));
+    assert_eq!(uncertainties.specific_heat_capacity_percent, Some(3.0));
+
+    let perturbed_flibe = flibe
+        .try_perturb_by_uncertainty(ThermophysicalProperty::DynamicViscosity, 1.0).unwrap()
+        .try_perturb_by_uncertainty(ThermophysicalProperty::SpecificHeatCapacity, -2.0).unwrap();
+
+    approx::assert_relative_eq!(
+        perturbed_flibe.try_get_dynamic_viscosity(temperature).unwrap().value,
+        1.2 * flibe.try_get_dynamic_viscosity(temperature).unwrap().value,
+        max_relative=1e-12);
+    approx::assert_relative_eq!(
+        perturbed_flibe.try_get_cp(temperature).unwrap().value,
+        0.94 * flibe.try_get_cp(temperature).unwrap().value,
+        max_relative=1e-12);
+    assert_eq!(perturbed_flibe.try_get_thermal_conductivity(temperature).unwrap(),
+        flibe.try_get_thermal_conductivity(temperature).unwrap());
+
+    let h = perturbed_flibe.try_get_specific_enthalpy(temperature).unwrap();
+    approx::assert_relative_eq!(
+        perturbed_flibe.try_get_temperature_from_specific_enthalpy(h).unwrap().value,
+        900.0,
+        max_relative=1e-6);
+
+    // HITEC density has no published uncertainty, so it can only be
+    // perturbed directly
+    let hitec = LiquidCorrelations::try_new(LiquidMaterial::HITEC).unwrap();
+    assert!(hitec.try_perturb_by_uncertainty(ThermophysicalProperty::Density, 1.0).is_err());
+    let mut perturbed_hitec = hitec;
+    perturbed_hitec.perturbation.density = 0.05;
+    let temperature_600_k = ThermodynamicTemperature::new::<kelvin>(600.0);
+    approx::assert_relative_eq!(
+        perturbed_hitec.try_get_density(temperature_600_k,
+            Pressure::new::<atmosphere>(1.0)).unwrap().value,
+        1.05 * LiquidMaterial::HITEC.try_get_density(temperature_600_k).unwrap().value,
+        max_relative=1e-12);
+
+    // a property cannot be scaled to zero or below
+    perturbed_hitec.perturbation.density = -1.0;
+    assert!(perturbed_hitec.try_get_density(temperature_600_k,
+        Pressure::new::<atmosphere>(1.0)).is_err());
+    assert!(flibe.try_perturb_by_uncertainty(
+        ThermophysicalProperty::DynamicViscosity, -5.0).is_err());
+}
+
+/// perturbations plug into uncertainty studies as parameters, here
+/// a Morris screening of the FLiBe Prandtl number at 900 K, which
+/// rises with viscosity and heat capacity and falls with thermal
+/// conductivity, while density has no effect
+#[test]
+pub fn screening_flibe_prandtl_number_by_property_uncertainties(){
+
+    use uom::si::ratio::ratio;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use super::prandtl::try_get_prandtl;
+    use crate::calibration::TunableParameter;
+    use crate::uncertainty_quantification::*;
+
+    let mut problem = UncertaintyQuantificationProblem::new(
+        LiquidCorrelations::try_new(LiquidMaterial::FLiBe).unwrap(),
+        |flibe: &mut LiquidCorrelations| {
+            let prandtl = try_get_prandtl(LiquidMaterial::from(*flibe).into(),
+                ThermodynamicTemperature::new::<kelvin>(900.0),
+                Pressure::new::<atmosphere>(1.0))?;
+            Ok(vec![prandtl.get::<ratio>()])
+        });
+
+    for property in [ThermophysicalProperty::Density,
+        ThermophysicalProperty::SpecificHeatCapacity,
+        ThermophysicalProperty::ThermalConductivity,
+        ThermophysicalProperty::DynamicViscosity] {
+        problem.add_parameter(UncertainParameter::try_new_normal(
+            TunableParameter::new(&format!("{:?} (uncertainties)", property),
+                -3.0, 3.0, 0.0,
+                move |flibe: &mut LiquidCorrelations, multiple: f64| {
+                    *flibe = flibe.try_perturb_by_uncertainty(property, multiple)?;
+                    Ok(())
+                }).unwrap(),
+            0.0, 1.0).unwrap());
+    }
+    problem.add_output("Prandtl number");
+
+    let screening = problem.try_get_morris_screening(MorrisSettings::default())
+        .unwrap();
+    let effects = &screening.mean[0];
+
+    approx::assert_abs_diff_eq!(effects[0], 0.0, epsilon=1e-12);
+    assert!(effects[1] > 0.0);
+    assert!(effects[2] < 0.0);
+    assert!(effects[3] > 0.0);
+    // viscosity is the most uncertain property
+    assert!(effects[3] > effects[1] && effects[3] > -effects[2]);
+}
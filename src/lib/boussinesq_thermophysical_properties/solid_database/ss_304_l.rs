@@ -166,6 +166,8 @@ pub fn specific_heat_capacity_test_steel(){
 ///
 /// data taken from ORNL
 ///
+/// k (W/(m K)) = 7.9318 + 0.023051 T(K) - 6.4166e-6 T(K)^2
+///
 /// It's only good for range of 300K to 700K
 #[inline]
 pub fn steel_ss_304_l_ornl_thermal_conductivity(
@@ -180,7 +182,7 @@ pub fn steel_ss_304_l_ornl_thermal_conductivity(
     let temperature_value_kelvin: f64 = temperature.get::<kelvin>();
     let thermal_conductivity_val = 7.9318 
     + 0.023051 * temperature_value_kelvin
-    - 6.4166 * f64::powf(10.0, -6.0);
+    - 6.4166 * f64::powf(10.0, -6.0) * temperature_value_kelvin.powf(2.0);
 
     Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
         thermal_conductivity_val))
@@ -56,6 +56,10 @@ impl SolidMaterial {
             ) => {
                 *roughness
             },
+            // surface roughness of the solid in the database
+            SolidMaterial::SelectedCorrelations(correlations) => {
+                SolidMaterial::from(correlations.solid).surface_roughness()?
+            },
         };
 
         Ok(roughness)
@@ -2,6 +2,11 @@ use uom::si::f64::*;
 
 use crate::boussinesq_thermophysical_properties::liquid_database::flibe::get_flibe_specific_enthalpy;
 use crate::boussinesq_thermophysical_properties::liquid_database::flinak::get_flinak_specific_enthalpy;
+use crate::boussinesq_thermophysical_properties::liquid_database::solar_salt::get_solar_salt_specific_enthalpy;
+use crate::boussinesq_thermophysical_properties::liquid_database::nacl_mgcl2::get_nacl_mgcl2_specific_enthalpy;
+use crate::boussinesq_thermophysical_properties::liquid_database::naf_zrf4::get_naf_zrf4_specific_enthalpy;
+use crate::boussinesq_thermophysical_properties::liquid_database::water::get_water_specific_enthalpy;
+use crate::boussinesq_thermophysical_properties::liquid_database::ideal_gases::*;
 use crate::boussinesq_thermophysical_properties::liquid_database::yd_325_heat_transfer_oil::get_yd325_specific_enthalpy;
 use crate::boussinesq_thermophysical_properties::liquid_database::{self, dowtherm_a};
 use crate::boussinesq_thermophysical_properties::liquid_database::hitec_nitrate_salt::get_hitec_specific_enthalpy;
@@ -41,6 +46,9 @@ fn solid_specific_enthalpy(material: Material,
         Material::Solid(CustomSolid((low_bound_temp,high_bound_temp),cp,k,rho,roughness)) => {
             CustomSolid((low_bound_temp,high_bound_temp), cp, k, rho,roughness)
         },
+        Material::Solid(SolidMaterial::SelectedCorrelations(correlations)) => {
+            SolidMaterial::SelectedCorrelations(correlations)
+        },
         Material::Liquid(_) => panic!("solid_specific_enthalpy, use SolidMaterial enums only")
     };
 
@@ -56,6 +64,9 @@ fn solid_specific_enthalpy(material: Material,
                 high_bound_temp, 
                 low_bound_temp).unwrap()
         },
+        SolidMaterial::SelectedCorrelations(correlations) => {
+            correlations.try_get_specific_enthalpy(solid_temp).unwrap()
+        },
     };
 
     return specific_enthalpy;
@@ -79,9 +90,23 @@ fn liquid_specific_enthalpy(material: Material,
         Material::Liquid(YD325) => YD325,
         Material::Liquid(FLiBe) => FLiBe,
         Material::Liquid(FLiNaK) => FLiNaK,
+        Material::Liquid(SolarSalt) => SolarSalt,
+        Material::Liquid(NaClMgCl2) => NaClMgCl2,
+        Material::Liquid(NaFZrF4) => NaFZrF4,
+        Material::Liquid(Water) => Water,
+        Material::Liquid(Air) => Air,
+        Material::Liquid(Nitrogen) => Nitrogen,
+        Material::Liquid(Helium) => Helium,
+        Material::Liquid(Argon) => Argon,
         Material::Liquid(CustomLiquid((low_bound_temp,high_bound_temp),cp,k,mu,rho)) => {
             CustomLiquid((low_bound_temp,high_bound_temp), cp, k, mu, rho)
         },
+        Material::Liquid(LiquidMaterial::SelectedCorrelations(correlations)) => {
+            LiquidMaterial::SelectedCorrelations(correlations)
+        },
+        Material::Liquid(LiquidMaterial::WeaklyCompressible(liquid)) => {
+            LiquidMaterial::WeaklyCompressible(liquid)
+        },
         Material::Solid(_) => panic!(
         "liquid_specific_enthalpy, use LiquidMaterial enums only")
     };
@@ -93,6 +118,14 @@ fn liquid_specific_enthalpy(material: Material,
         YD325 => get_yd325_specific_enthalpy(fluid_temp).unwrap(),
         FLiBe => get_flibe_specific_enthalpy(fluid_temp).unwrap(),
         FLiNaK => get_flinak_specific_enthalpy(fluid_temp).unwrap(),
+        SolarSalt => get_solar_salt_specific_enthalpy(fluid_temp).unwrap(),
+        NaClMgCl2 => get_nacl_mgcl2_specific_enthalpy(fluid_temp).unwrap(),
+        NaFZrF4 => get_naf_zrf4_specific_enthalpy(fluid_temp).unwrap(),
+        Water => get_water_specific_enthalpy(fluid_temp).unwrap(),
+        Air => get_ideal_gas_specific_enthalpy(&AIR, fluid_temp).unwrap(),
+        Nitrogen => get_ideal_gas_specific_enthalpy(&NITROGEN, fluid_temp).unwrap(),
+        Helium => get_ideal_gas_specific_enthalpy(&HELIUM, fluid_temp).unwrap(),
+        Argon => get_ideal_gas_specific_enthalpy(&ARGON, fluid_temp).unwrap(),
         CustomLiquid((low_bound_temp,high_bound_temp), cp_fn, _k, _mu_fn, _rho_fn) => {
             liquid_database::custom_liquid_material
                 ::get_custom_fluid_enthalpy(fluid_temp, 
@@ -100,6 +133,13 @@ fn liquid_specific_enthalpy(material: Material,
                     high_bound_temp, 
                     low_bound_temp).unwrap()
         },
+        LiquidMaterial::SelectedCorrelations(correlations) => {
+            correlations.try_get_specific_enthalpy(fluid_temp).unwrap()
+        },
+        LiquidMaterial::WeaklyCompressible(liquid) => {
+            liquid.try_get_specific_enthalpy(fluid_temp, 
+                liquid.reference_pressure).unwrap()
+        },
     };
 
     return specific_enthalpy;
@@ -98,9 +98,12 @@ use temperature_from_specific_enthalpy::*;
 /// ``` 
 pub fn try_get_h(material: Material, 
     temperature: ThermodynamicTemperature,
-    _pressure: Pressure) -> Result<AvailableEnergy, TuasLibError> {
+    pressure: Pressure) -> Result<AvailableEnergy, TuasLibError> {
 
     let specific_enthalpy: AvailableEnergy = match material {
+        // only weakly compressible liquids depend on pressure
+        Material::Liquid(LiquidMaterial::WeaklyCompressible(liquid)) => 
+            liquid.try_get_specific_enthalpy(temperature, pressure)?,
         Material::Solid(_) => solid_specific_enthalpy(material, temperature),
         Material::Liquid(_) => liquid_specific_enthalpy(material, temperature)
     };
@@ -169,9 +172,12 @@ pub fn try_get_h(material: Material,
 /// ```
 pub fn try_get_temperature_from_h(material: Material, 
     material_enthalpy: AvailableEnergy,
-    _pressure: Pressure) -> Result<ThermodynamicTemperature, TuasLibError> {
+    pressure: Pressure) -> Result<ThermodynamicTemperature, TuasLibError> {
 
     let specific_enthalpy: ThermodynamicTemperature = match material {
+        Material::Liquid(LiquidMaterial::WeaklyCompressible(liquid)) => 
+            liquid.try_get_temperature_from_specific_enthalpy(
+                material_enthalpy, pressure)?,
         Material::Solid(_) => 
             get_solid_temperature_from_specific_enthalpy(
                 material, material_enthalpy),
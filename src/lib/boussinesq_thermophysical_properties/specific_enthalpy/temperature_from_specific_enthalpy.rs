@@ -9,6 +9,11 @@ use crate::boussinesq_thermophysical_properties::liquid_database;
 use crate::boussinesq_thermophysical_properties::liquid_database::dowtherm_a;
 use crate::boussinesq_thermophysical_properties::liquid_database::flibe;
 use crate::boussinesq_thermophysical_properties::liquid_database::flinak;
+use crate::boussinesq_thermophysical_properties::liquid_database::solar_salt;
+use crate::boussinesq_thermophysical_properties::liquid_database::nacl_mgcl2;
+use crate::boussinesq_thermophysical_properties::liquid_database::naf_zrf4;
+use crate::boussinesq_thermophysical_properties::liquid_database::water;
+use crate::boussinesq_thermophysical_properties::liquid_database::ideal_gases;
 use crate::boussinesq_thermophysical_properties::liquid_database::hitec_nitrate_salt;
 use crate::boussinesq_thermophysical_properties::liquid_database::yd_325_heat_transfer_oil;
 use crate::boussinesq_thermophysical_properties::solid_database::copper::copper_spline_temp_attempt_2_from_specific_enthalpy;
@@ -44,6 +49,9 @@ fn get_solid_temperature_from_specific_enthalpy(material: Material,
         Material::Solid(CustomSolid((low_bound_temp,high_bound_temp),cp,k,rho,roughness)) => {
             CustomSolid((low_bound_temp,high_bound_temp), cp, k, rho,roughness)
         },
+        Material::Solid(SolidMaterial::SelectedCorrelations(correlations)) => {
+            SolidMaterial::SelectedCorrelations(correlations)
+        },
         Material::Liquid(_) => panic!("solid_specific_enthalpy, use SolidMaterial enums only")
     };
 
@@ -76,6 +84,9 @@ fn get_solid_temperature_from_specific_enthalpy(material: Material,
                     high_bound_temp, 
                     low_bound_temp).unwrap()
             },
+            SolidMaterial::SelectedCorrelations(correlations) => {
+                correlations.try_get_temperature_from_specific_enthalpy(h_material).unwrap()
+            },
 
         };
 
@@ -100,9 +111,23 @@ fn get_liquid_temperature_from_specific_enthalpy(material: Material,
         Material::Liquid(YD325) => YD325,
         Material::Liquid(FLiBe) => FLiBe,
         Material::Liquid(FLiNaK) => FLiNaK,
+        Material::Liquid(SolarSalt) => SolarSalt,
+        Material::Liquid(NaClMgCl2) => NaClMgCl2,
+        Material::Liquid(NaFZrF4) => NaFZrF4,
+        Material::Liquid(Water) => Water,
+        Material::Liquid(Air) => Air,
+        Material::Liquid(Nitrogen) => Nitrogen,
+        Material::Liquid(Helium) => Helium,
+        Material::Liquid(Argon) => Argon,
         Material::Liquid(CustomLiquid((low_bound_temp,high_bound_temp),cp,k,mu,rho)) => {
             CustomLiquid((low_bound_temp,high_bound_temp), cp, k, mu, rho)
         },
+        Material::Liquid(LiquidMaterial::SelectedCorrelations(correlations)) => {
+            LiquidMaterial::SelectedCorrelations(correlations)
+        },
+        Material::Liquid(LiquidMaterial::WeaklyCompressible(liquid)) => {
+            LiquidMaterial::WeaklyCompressible(liquid)
+        },
         Material::Solid(_) => panic!(
         "liquid_specific_enthalpy, use LiquidMaterial enums only")
     };
@@ -114,6 +139,14 @@ fn get_liquid_temperature_from_specific_enthalpy(material: Material,
         YD325 => yd_325_heat_transfer_oil::get_temperature_from_enthalpy(fluid_enthalpy).unwrap(),
         FLiBe => flibe::get_temperature_from_enthalpy(fluid_enthalpy).unwrap(),
         FLiNaK => flinak::get_temperature_from_enthalpy(fluid_enthalpy).unwrap(),
+        SolarSalt => solar_salt::get_temperature_from_enthalpy(fluid_enthalpy).unwrap(),
+        NaClMgCl2 => nacl_mgcl2::get_temperature_from_enthalpy(fluid_enthalpy).unwrap(),
+        NaFZrF4 => naf_zrf4::get_temperature_from_enthalpy(fluid_enthalpy).unwrap(),
+        Water => water::get_temperature_from_enthalpy(fluid_enthalpy).unwrap(),
+        Air => ideal_gases::get_ideal_gas_temperature_from_enthalpy(&ideal_gases::AIR, fluid_enthalpy).unwrap(),
+        Nitrogen => ideal_gases::get_ideal_gas_temperature_from_enthalpy(&ideal_gases::NITROGEN, fluid_enthalpy).unwrap(),
+        Helium => ideal_gases::get_ideal_gas_temperature_from_enthalpy(&ideal_gases::HELIUM, fluid_enthalpy).unwrap(),
+        Argon => ideal_gases::get_ideal_gas_temperature_from_enthalpy(&ideal_gases::ARGON, fluid_enthalpy).unwrap(),
         CustomLiquid((low_bound_temp,high_bound_temp), cp_fn, _k, _mu_fn, _rho_fn) => {
             liquid_database::custom_liquid_material
                 ::get_custom_fluid_temperature_from_enthalpy(fluid_enthalpy, 
@@ -121,6 +154,13 @@ fn get_liquid_temperature_from_specific_enthalpy(material: Material,
                     high_bound_temp, 
                     low_bound_temp).unwrap()
         },
+        LiquidMaterial::SelectedCorrelations(correlations) => {
+            correlations.try_get_temperature_from_specific_enthalpy(fluid_enthalpy).unwrap()
+        },
+        LiquidMaterial::WeaklyCompressible(liquid) => {
+            liquid.try_get_temperature_from_specific_enthalpy(fluid_enthalpy, 
+                liquid.reference_pressure).unwrap()
+        },
     };
 
     return specific_enthalpy;
@@ -4,6 +4,11 @@ use crate::tuas_lib_error::TuasLibError;
 use super::liquid_database;
 use super::liquid_database::flibe::get_flibe_constant_pressure_specific_heat_capacity;
 use super::liquid_database::flinak::get_flinak_constant_pressure_specific_heat_capacity;
+use super::liquid_database::solar_salt::get_solar_salt_constant_pressure_specific_heat_capacity;
+use super::liquid_database::nacl_mgcl2::get_nacl_mgcl2_constant_pressure_specific_heat_capacity;
+use super::liquid_database::naf_zrf4::get_naf_zrf4_constant_pressure_specific_heat_capacity;
+use super::liquid_database::water::get_water_constant_pressure_specific_heat_capacity;
+use super::liquid_database::ideal_gases::*;
 use super::liquid_database::hitec_nitrate_salt::get_hitec_constant_pressure_specific_heat_capacity;
 use super::liquid_database::yd_325_heat_transfer_oil::get_yd325_constant_pressure_specific_heat_capacity;
 use super::solid_database::copper::copper_specific_heat_capacity_zou_zweibaum_spline;
@@ -78,6 +83,9 @@ fn solid_specific_heat_capacity(material: Material,
         Material::Solid( CustomSolid((low_bound_temp,high_bound_temp),cp,k,rho_fn,roughness))=> {
             CustomSolid((low_bound_temp,high_bound_temp), cp, k, rho_fn,roughness)
         },
+        Material::Solid(SolidMaterial::SelectedCorrelations(correlations)) => {
+            SolidMaterial::SelectedCorrelations(correlations)
+        },
         Material::Liquid(_) => panic!("solid_specific_heat_capacity, use SolidMaterial enums only")
     };
 
@@ -93,6 +101,9 @@ fn solid_specific_heat_capacity(material: Material,
                 high_bound_temp, 
                 low_bound_temp)?
         },
+        SolidMaterial::SelectedCorrelations(correlations) => {
+            correlations.try_get_cp(solid_temp)?
+        },
     };
 
     return Ok(specific_heat_capacity);
@@ -144,9 +155,23 @@ TuasLibError>{
         Material::Liquid(YD325) => YD325,
         Material::Liquid(FLiBe) => FLiBe,
         Material::Liquid(FLiNaK) => FLiNaK,
+        Material::Liquid(SolarSalt) => SolarSalt,
+        Material::Liquid(NaClMgCl2) => NaClMgCl2,
+        Material::Liquid(NaFZrF4) => NaFZrF4,
+        Material::Liquid(Water) => Water,
+        Material::Liquid(Air) => Air,
+        Material::Liquid(Nitrogen) => Nitrogen,
+        Material::Liquid(Helium) => Helium,
+        Material::Liquid(Argon) => Argon,
         Material::Liquid(CustomLiquid((low_bound_temp,high_bound_temp),cp,k,mu,rho)) => {
             CustomLiquid((low_bound_temp,high_bound_temp), cp, k, mu, rho)
         },
+        Material::Liquid(LiquidMaterial::SelectedCorrelations(correlations)) => {
+            LiquidMaterial::SelectedCorrelations(correlations)
+        },
+        Material::Liquid(LiquidMaterial::WeaklyCompressible(liquid)) => {
+            LiquidMaterial::WeaklyCompressible(liquid)
+        },
         Material::Solid(_) => panic!(
         "liquid_specific_heat_capacity, use LiquidMaterial enums only")
     };
@@ -158,6 +183,14 @@ TuasLibError>{
         YD325 => get_yd325_constant_pressure_specific_heat_capacity(fluid_temp)?,
         FLiBe => get_flibe_constant_pressure_specific_heat_capacity(fluid_temp)?,
         FLiNaK => get_flinak_constant_pressure_specific_heat_capacity(fluid_temp)?,
+        SolarSalt => get_solar_salt_constant_pressure_specific_heat_capacity(fluid_temp)?,
+        NaClMgCl2 => get_nacl_mgcl2_constant_pressure_specific_heat_capacity(fluid_temp)?,
+        NaFZrF4 => get_naf_zrf4_constant_pressure_specific_heat_capacity(fluid_temp)?,
+        Water => get_water_constant_pressure_specific_heat_capacity(fluid_temp)?,
+        Air => get_ideal_gas_constant_pressure_specific_heat_capacity(&AIR, fluid_temp)?,
+        Nitrogen => get_ideal_gas_constant_pressure_specific_heat_capacity(&NITROGEN, fluid_temp)?,
+        Helium => get_ideal_gas_constant_pressure_specific_heat_capacity(&HELIUM, fluid_temp)?,
+        Argon => get_ideal_gas_constant_pressure_specific_heat_capacity(&ARGON, fluid_temp)?,
         CustomLiquid((low_bound_temp,high_bound_temp), cp_fn, _k, _mu_fn, _rho_fn) => {
             liquid_database::custom_liquid_material
                 ::get_custom_fluid_constant_pressure_specific_heat_capacity(fluid_temp, 
@@ -165,6 +198,12 @@ TuasLibError>{
                     high_bound_temp, 
                     low_bound_temp)?
         },
+        LiquidMaterial::SelectedCorrelations(correlations) => {
+            correlations.try_get_cp(fluid_temp)?
+        },
+        LiquidMaterial::WeaklyCompressible(liquid) => {
+            liquid.correlations.try_get_cp(fluid_temp)?
+        },
     };
 
     return Ok(specific_heat_capacity);
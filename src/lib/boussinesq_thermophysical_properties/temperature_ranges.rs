@@ -5,6 +5,16 @@ use super::liquid_database::flibe::max_temp_flibe;
 use super::liquid_database::flibe::min_temp_flibe;
 use super::liquid_database::flinak::max_temp_flinak;
 use super::liquid_database::flinak::min_temp_flinak;
+use super::liquid_database::solar_salt::max_temp_solar_salt;
+use super::liquid_database::solar_salt::min_temp_solar_salt;
+use super::liquid_database::nacl_mgcl2::max_temp_nacl_mgcl2;
+use super::liquid_database::nacl_mgcl2::min_temp_nacl_mgcl2;
+use super::liquid_database::naf_zrf4::max_temp_naf_zrf4;
+use super::liquid_database::naf_zrf4::min_temp_naf_zrf4;
+use super::liquid_database::water::max_temp_water;
+use super::liquid_database::water::min_temp_water;
+use super::liquid_database::ideal_gases::max_temp_ideal_gas;
+use super::liquid_database::ideal_gases::min_temp_ideal_gas;
 use super::liquid_database::hitec_nitrate_salt::max_temp_hitec;
 use super::liquid_database::hitec_nitrate_salt::min_temp_hitec;
 use super::liquid_database::yd_325_heat_transfer_oil::max_temp_yd325_oil;
@@ -75,10 +85,32 @@ impl LiquidMaterial {
             LiquidMaterial::FLiNaK => {
                 max_temp_flinak()
             },
+            LiquidMaterial::SolarSalt => {
+                max_temp_solar_salt()
+            },
+            LiquidMaterial::NaClMgCl2 => {
+                max_temp_nacl_mgcl2()
+            },
+            LiquidMaterial::NaFZrF4 => {
+                max_temp_naf_zrf4()
+            },
+            LiquidMaterial::Water => {
+                max_temp_water()
+            },
+            LiquidMaterial::Air | LiquidMaterial::Nitrogen
+                | LiquidMaterial::Helium | LiquidMaterial::Argon => {
+                max_temp_ideal_gas()
+            },
             LiquidMaterial::CustomLiquid((_lower_bound, upper_bound)
                 , _, _, _, _) => {
                 *upper_bound
             },
+            LiquidMaterial::SelectedCorrelations(correlations) => {
+                LiquidMaterial::from(correlations.liquid).max_temperature()
+            },
+            LiquidMaterial::WeaklyCompressible(liquid) => {
+                LiquidMaterial::from(liquid.correlations.liquid).max_temperature()
+            },
         }
     }
     /// gives the maximum temperature for the correlations in the
@@ -103,10 +135,32 @@ impl LiquidMaterial {
             LiquidMaterial::FLiNaK => {
                 min_temp_flinak()
             },
+            LiquidMaterial::SolarSalt => {
+                min_temp_solar_salt()
+            },
+            LiquidMaterial::NaClMgCl2 => {
+                min_temp_nacl_mgcl2()
+            },
+            LiquidMaterial::NaFZrF4 => {
+                min_temp_naf_zrf4()
+            },
+            LiquidMaterial::Water => {
+                min_temp_water()
+            },
+            LiquidMaterial::Air | LiquidMaterial::Nitrogen
+                | LiquidMaterial::Helium | LiquidMaterial::Argon => {
+                min_temp_ideal_gas()
+            },
             LiquidMaterial::CustomLiquid((lower_bound, _upper_bound)
                 , _, _, _, _) => {
                 *lower_bound
             },
+            LiquidMaterial::SelectedCorrelations(correlations) => {
+                LiquidMaterial::from(correlations.liquid).min_temperature()
+            },
+            LiquidMaterial::WeaklyCompressible(liquid) => {
+                LiquidMaterial::from(liquid.correlations.liquid).min_temperature()
+            },
         }
 
     }
@@ -125,6 +179,9 @@ impl SolidMaterial {
                 _, _, _, _) => {
                 *upper_bound
             },
+            SolidMaterial::SelectedCorrelations(correlations) => {
+                SolidMaterial::from(correlations.solid).max_temperature()
+            },
         }
     }
     /// gives the maximum temperature for the correlations in the
@@ -139,6 +196,9 @@ impl SolidMaterial {
                 _, _, _, _) => {
                 *lower_bound
             },
+            SolidMaterial::SelectedCorrelations(correlations) => {
+                SolidMaterial::from(correlations.solid).min_temperature()
+            },
         }
 
     }
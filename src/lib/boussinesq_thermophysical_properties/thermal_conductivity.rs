@@ -4,6 +4,11 @@ use crate::tuas_lib_error::TuasLibError;
 use super::liquid_database;
 use super::liquid_database::flibe::get_flibe_thermal_conductivity;
 use super::liquid_database::flinak::get_flinak_thermal_conductivity;
+use super::liquid_database::solar_salt::get_solar_salt_thermal_conductivity;
+use super::liquid_database::nacl_mgcl2::get_nacl_mgcl2_thermal_conductivity;
+use super::liquid_database::naf_zrf4::get_naf_zrf4_thermal_conductivity;
+use super::liquid_database::water::get_water_thermal_conductivity;
+use super::liquid_database::ideal_gases::*;
 use super::liquid_database::hitec_nitrate_salt::get_hitec_thermal_conductivity;
 use super::liquid_database::yd_325_heat_transfer_oil::get_yd325_thermal_conductivity;
 use super::solid_database::copper::copper_thermal_conductivity_zou_zweibaum_spline;
@@ -106,6 +111,9 @@ fn solid_thermal_conductivity(material: Material,
         Material::Solid(CustomSolid((low_bound_temp,high_bound_temp),cp,k,rho,roughness)) => {
             CustomSolid((low_bound_temp,high_bound_temp), cp, k, rho,roughness)
         },
+        Material::Solid(SolidMaterial::SelectedCorrelations(correlations)) => {
+            SolidMaterial::SelectedCorrelations(correlations)
+        },
         Material::Liquid(_) => panic!("solid_thermal_conductivity, use SolidMaterial enums only")
     };
 
@@ -131,6 +139,14 @@ impl LiquidMaterial {
             YD325 => get_yd325_thermal_conductivity(fluid_temp)?,
             FLiBe => get_flibe_thermal_conductivity(fluid_temp)?,
             FLiNaK => get_flinak_thermal_conductivity(fluid_temp)?,
+            SolarSalt => get_solar_salt_thermal_conductivity(fluid_temp)?,
+            NaClMgCl2 => get_nacl_mgcl2_thermal_conductivity(fluid_temp)?,
+            NaFZrF4 => get_naf_zrf4_thermal_conductivity(fluid_temp)?,
+            Water => get_water_thermal_conductivity(fluid_temp)?,
+            Air => get_ideal_gas_thermal_conductivity(&AIR, fluid_temp)?,
+            Nitrogen => get_ideal_gas_thermal_conductivity(&NITROGEN, fluid_temp)?,
+            Helium => get_ideal_gas_thermal_conductivity(&HELIUM, fluid_temp)?,
+            Argon => get_ideal_gas_thermal_conductivity(&ARGON, fluid_temp)?,
             CustomLiquid((low_bound_temp,high_bound_temp), _cp, k_fn, _mu_fn, _rho_fn) => {
                 liquid_database::custom_liquid_material
                     ::get_custom_fluid_thermal_conductivity(fluid_temp, 
@@ -138,6 +154,12 @@ impl LiquidMaterial {
                         *high_bound_temp, 
                         *low_bound_temp)?
             },
+            LiquidMaterial::SelectedCorrelations(correlations) => {
+                correlations.try_get_thermal_conductivity(fluid_temp)?
+            },
+            LiquidMaterial::WeaklyCompressible(liquid) => {
+                liquid.correlations.try_get_thermal_conductivity(fluid_temp)?
+            },
         };
 
         Ok(thermal_conductivity)
@@ -184,6 +206,9 @@ impl SolidMaterial {
                         *high_bound_temp, 
                         *low_bound_temp)?
                 },
+                SolidMaterial::SelectedCorrelations(correlations) => {
+                    correlations.try_get_thermal_conductivity(solid_temp)?
+                },
             };
 
             Ok(thermal_conductivity)
@@ -202,9 +227,23 @@ fn liquid_thermal_conductivity(material: Material,
         Material::Liquid(YD325)=> YD325,
         Material::Liquid(FLiBe) => FLiBe,
         Material::Liquid(FLiNaK) => FLiNaK,
+        Material::Liquid(SolarSalt) => SolarSalt,
+        Material::Liquid(NaClMgCl2) => NaClMgCl2,
+        Material::Liquid(NaFZrF4) => NaFZrF4,
+        Material::Liquid(Water) => Water,
+        Material::Liquid(Air) => Air,
+        Material::Liquid(Nitrogen) => Nitrogen,
+        Material::Liquid(Helium) => Helium,
+        Material::Liquid(Argon) => Argon,
         Material::Liquid(CustomLiquid((low_bound_temp,high_bound_temp),cp,k,mu,rho)) => {
             CustomLiquid((low_bound_temp,high_bound_temp), cp, k, mu, rho)
         },
+        Material::Liquid(LiquidMaterial::SelectedCorrelations(correlations)) => {
+            LiquidMaterial::SelectedCorrelations(correlations)
+        },
+        Material::Liquid(LiquidMaterial::WeaklyCompressible(liquid)) => {
+            LiquidMaterial::WeaklyCompressible(liquid)
+        },
         Material::Solid(_) => panic!(
         "liquid_thermal_conductivity, use LiquidMaterial enums only")
     };
@@ -252,6 +291,18 @@ pub fn thermal_conductivity_test_steel(){
         thermal_cond_graves_et_al_1991.unwrap().value,
         max_relative=0.028);
 
+    // the quadratic term of Graves et al. goes as T^2, which pins
+    // the correlation at 500K to
+    // 7.9318 + 0.023051 (500) - 6.4166e-6 (500)^2 = 17.85315 W/(m K)
+    //
+    // the 2.8% tolerance above does not tell this apart from a
+    // constant quadratic term
+    approx::assert_relative_eq!(
+        17.85315,
+        steel_ss_304_l_ornl_thermal_conductivity(
+            ThermodynamicTemperature::new::<kelvin>(500.0)).unwrap().value,
+        max_relative=1e-12);
+
     // let's try now at 1000K 
     // we expect thermal thermal_conductivity to be at 23.83
 
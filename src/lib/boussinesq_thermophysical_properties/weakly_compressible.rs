@@ -0,0 +1,410 @@
+//! Weakly compressible liquids, where density and specific enthalpy
+//! depend on pressure as well as temperature
+//!
+//! The liquid correlations in the database only depend on
+//! temperature, which is the Boussinesq approach. For pressurised
+//! water or high head salt loops, the pressure dependence may be
+//! needed. A WeaklyCompressibleLiquid takes the correlations of a
+//! liquid to hold at a reference pressure p_ref, and uses a constant
+//! isothermal compressibility kappa to extend them to other
+//! pressures:
+//!
+//! rho(T,p) = rho_ref(T) exp(kappa (p - p_ref))
+//!
+//! The thermal expansion coefficient beta = -(1/rho)(d rho/dT) comes
+//! from the density correlation and, for constant kappa, does not
+//! depend on pressure. Specific enthalpy follows from
+//! (dh/dp)_T = v (1 - beta T), integrated at constant temperature:
+//!
+//! h(T,p) = h_ref(T) + (1 - beta T) (1 - exp(-kappa (p - p_ref)))
+//! / (kappa rho_ref(T))
+//!
+//! which tends to (1 - beta T)(p - p_ref)/rho_ref(T) for an
+//! incompressible liquid.
+//!
+//! Because specific enthalpy depends on pressure, a control volume
+//! whose pressure changes must also have the pressure work V dp/dt
+//! added to its energy balance (see try_set_pressure_with_pressure_work
+//! for SingleCVNode and FluidArray), which heats the liquid by about
+//! beta T dp/(rho cp) on compression.
+//! Masses of control volumes are set from rho(T,p), so the mass
+//! which leaves the control volumes of a loop as the liquid expands
+//! can be tracked, eg. to find the level of an expansion tank.
+//!
+//! Viscosity, thermal conductivity and heat capacity are taken to
+//! be independent of pressure.
+//!
+//! Kell, G. S. (1975). Density, thermal expansivity, and
+//! compressibility of liquid water from 0. deg. to 150. deg..
+//! Correlations and tables for atmospheric pressure and saturation
+//! reviewed and expressed on 1968 temperature scale. Journal of
+//! Chemical and Engineering Data, 20(1), 97-105.
+use uom::si::f64::*;
+use uom::si::pressure::{atmosphere, pascal};
+use uom::si::temperature_coefficient::per_kelvin;
+use uom::si::temperature_interval::kelvin as interval_kelvin;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::time::second;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::property_correlations::{CorrelatedLiquid, LiquidCorrelations};
+use super::LiquidMaterial;
+
+/// temperature step for the finite difference of the density
+/// correlation used to find the thermal expansion coefficient
+const THERMAL_EXPANSION_TEMPERATURE_STEP_KELVIN: f64 = 0.5;
+
+/// temperatures found from specific enthalpy and pressure are
+/// converged to within this tolerance
+const TEMPERATURE_FROM_ENTHALPY_TOLERANCE_KELVIN: f64 = 1.0e-9;
+
+/// maximum iterations to find temperature from specific enthalpy
+/// and pressure
+const TEMPERATURE_FROM_ENTHALPY_MAX_ITERATIONS: usize = 50;
+
+/// a liquid from the database whose correlations hold at a
+/// reference pressure, with a constant isothermal compressibility
+/// for other pressures
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct WeaklyCompressibleLiquid {
+    /// the liquid and its correlations at the reference pressure
+    pub correlations: LiquidCorrelations,
+    /// isothermal compressibility -(1/v)(dv/dp) at constant
+    /// temperature, in 1/Pa
+    pub isothermal_compressibility_per_pascal: f64,
+    /// pressure at which the correlations hold
+    pub reference_pressure: Pressure,
+}
+
+impl From<WeaklyCompressibleLiquid> for LiquidMaterial {
+    fn from(liquid: WeaklyCompressibleLiquid) -> Self {
+        LiquidMaterial::WeaklyCompressible(liquid)
+    }
+}
+
+impl WeaklyCompressibleLiquid {
+
+    /// makes a liquid weakly compressible with a given isothermal
+    /// compressibility in 1/Pa, taking the correlations to hold at
+    /// 1 atm
+    ///
+    /// chosen correlations and perturbations of the liquid are kept.
+    /// Gases are already compressible (ideal gas) and custom liquids
+    /// have no correlations to extend, so both are rejected
+    pub fn try_new(liquid: LiquidMaterial,
+        isothermal_compressibility_per_pascal: f64) -> Result<Self, TuasLibError> {
+
+        if !isothermal_compressibility_per_pascal.is_finite()
+            || isothermal_compressibility_per_pascal < 0.0 {
+            return Err(TuasLibError::GenericStringError(format!(
+                "isothermal compressibility must be finite and not negative, got {}",
+                isothermal_compressibility_per_pascal)));
+        }
+
+        let correlations = LiquidCorrelations::try_new(liquid)?;
+
+        match correlations.liquid {
+            CorrelatedLiquid::Air | CorrelatedLiquid::Nitrogen
+                | CorrelatedLiquid::Helium | CorrelatedLiquid::Argon => {
+                return Err(TuasLibError::GenericStringError(format!(
+                    "{:?} is an ideal gas, its density already depends on pressure",
+                    correlations.liquid)));
+            },
+            _ => (),
+        }
+
+        let reference_pressure = match liquid {
+            LiquidMaterial::WeaklyCompressible(weakly_compressible_liquid) => {
+                weakly_compressible_liquid.reference_pressure
+            },
+            _ => Pressure::new::<atmosphere>(1.0),
+        };
+
+        Ok(Self {
+            correlations,
+            isothermal_compressibility_per_pascal,
+            reference_pressure,
+        })
+    }
+
+    /// makes a liquid weakly compressible using the isothermal
+    /// compressibility in the database
+    pub fn try_new_from_database(liquid: LiquidMaterial) -> Result<Self, TuasLibError> {
+
+        let correlations = LiquidCorrelations::try_new(liquid)?;
+
+        let isothermal_compressibility_per_pascal = match correlations.liquid {
+            // 4.4 to 4.6e-10 1/Pa between 10 C and 90 C (Kell, 1975)
+            CorrelatedLiquid::Water => 4.5e-10,
+            _ => {
+                return Err(TuasLibError::GenericStringError(format!(
+                    "no isothermal compressibility for {:?} in the database, \
+                    please supply one with WeaklyCompressibleLiquid::try_new",
+                    correlations.liquid)));
+            },
+        };
+
+        Self::try_new(liquid, isothermal_compressibility_per_pascal)
+    }
+
+    /// the liquid with its correlations, which hold at the
+    /// reference pressure
+    pub fn get_reference_liquid(&self) -> LiquidMaterial {
+        LiquidMaterial::SelectedCorrelations(self.correlations)
+    }
+
+    /// -kappa (p - p_ref), the log of the specific volume ratio
+    /// v(T,p)/v(T,p_ref)
+    fn get_log_specific_volume_ratio(&self, pressure: Pressure) -> f64 {
+        -self.isothermal_compressibility_per_pascal
+            * (pressure - self.reference_pressure).get::<pascal>()
+    }
+
+    /// density at a given temperature and pressure
+    pub fn try_get_density(&self,
+        fluid_temp: ThermodynamicTemperature,
+        pressure: Pressure) -> Result<MassDensity, TuasLibError> {
+
+        let reference_density = self.correlations.try_get_density(
+            fluid_temp, self.reference_pressure)?;
+
+        Ok(reference_density * (-self.get_log_specific_volume_ratio(pressure)).exp())
+    }
+
+    /// thermal expansion coefficient -(1/rho)(d rho/dT), from the
+    /// density correlation
+    ///
+    /// central differences are used, or one sided differences at
+    /// the ends of the range of the density correlation
+    pub fn try_get_thermal_expansion_coefficient(&self,
+        fluid_temp: ThermodynamicTemperature) -> Result<TemperatureCoefficient, TuasLibError> {
+
+        let temperature_step = TemperatureInterval::new::<interval_kelvin>(
+            THERMAL_EXPANSION_TEMPERATURE_STEP_KELVIN);
+
+        let get_reference_density = |temperature: ThermodynamicTemperature| {
+            self.correlations.try_get_density(temperature, self.reference_pressure)
+        };
+
+        let density = get_reference_density(fluid_temp)?;
+        let density_above = get_reference_density(fluid_temp + temperature_step).ok();
+        let density_below = get_reference_density(fluid_temp - temperature_step).ok();
+
+        let (density_change, temperature_change) = match (density_above, density_below) {
+            (Some(above), Some(below)) => (above - below, 2.0 * THERMAL_EXPANSION_TEMPERATURE_STEP_KELVIN),
+            (Some(above), None) => (above - density, THERMAL_EXPANSION_TEMPERATURE_STEP_KELVIN),
+            (None, Some(below)) => (density - below, THERMAL_EXPANSION_TEMPERATURE_STEP_KELVIN),
+            (None, None) => return Err(TuasLibError::ThermophysicalPropertyTemperatureRangeError),
+        };
+
+        Ok(TemperatureCoefficient::new::<per_kelvin>(
+            -(density_change / density).value / temperature_change))
+    }
+
+    /// h(T,p) - h_ref(T), the change in specific enthalpy when the
+    /// liquid is compressed from the reference pressure at constant
+    /// temperature
+    fn try_get_specific_enthalpy_pressure_correction(&self,
+        fluid_temp: ThermodynamicTemperature,
+        pressure: Pressure) -> Result<AvailableEnergy, TuasLibError> {
+
+        let reference_density = self.correlations.try_get_density(
+            fluid_temp, self.reference_pressure)?;
+        let thermal_expansion = self.try_get_thermal_expansion_coefficient(fluid_temp)?;
+
+        // (1 - exp(-kappa dp))/kappa, which is dp for kappa = 0
+        let log_specific_volume_ratio = self.get_log_specific_volume_ratio(pressure);
+        let integrated_pressure = if self.isothermal_compressibility_per_pascal > 0.0 {
+            Pressure::new::<pascal>(-log_specific_volume_ratio.exp_m1()
+                / self.isothermal_compressibility_per_pascal)
+        } else {
+            pressure - self.reference_pressure
+        };
+
+        let expansion_factor = 1.0
+            - thermal_expansion.get::<per_kelvin>() * fluid_temp.get::<kelvin>();
+
+        Ok(expansion_factor * integrated_pressure / reference_density)
+    }
+
+    /// specific enthalpy at a given temperature and pressure
+    pub fn try_get_specific_enthalpy(&self,
+        fluid_temp: ThermodynamicTemperature,
+        pressure: Pressure) -> Result<AvailableEnergy, TuasLibError> {
+
+        Ok(self.correlations.try_get_specific_enthalpy(fluid_temp)?
+            + self.try_get_specific_enthalpy_pressure_correction(fluid_temp, pressure)?)
+    }
+
+    /// temperature from specific enthalpy and pressure, the inverse
+    /// of try_get_specific_enthalpy
+    ///
+    /// the pressure correction depends only weakly on temperature,
+    /// so it is found by fixed point iteration
+    pub fn try_get_temperature_from_specific_enthalpy(&self,
+        fluid_enthalpy: AvailableEnergy,
+        pressure: Pressure) -> Result<ThermodynamicTemperature, TuasLibError> {
+
+        let mut temperature = self.correlations
+            .try_get_temperature_from_specific_enthalpy(fluid_enthalpy)?;
+
+        for _ in 0..TEMPERATURE_FROM_ENTHALPY_MAX_ITERATIONS {
+            let pressure_correction = self.try_get_specific_enthalpy_pressure_correction(
+                temperature, pressure)?;
+            let new_temperature = self.correlations
+                .try_get_temperature_from_specific_enthalpy(
+                    fluid_enthalpy - pressure_correction)?;
+
+            let temperature_change = (new_temperature.get::<kelvin>()
+                - temperature.get::<kelvin>()).abs();
+            temperature = new_temperature;
+
+            if temperature_change < TEMPERATURE_FROM_ENTHALPY_TOLERANCE_KELVIN {
+                return Ok(temperature);
+            }
+        }
+
+        Err(TuasLibError::GenericStringError(format!(
+            "temperature from specific enthalpy of {:?} did not converge \
+            at {} Pa", self.correlations.liquid, pressure.get::<pascal>())))
+    }
+}
+
+/// rate of pressure work done on a control volume of fixed volume
+/// whose pressure changes by pressure_change over a timestep, V dp/dt
+///
+/// this is the term added to the energy balance of a control volume
+/// in enthalpy form, d(mh)/dt = sum(m_flow h) + Q + V dp/dt
+pub fn try_get_pressure_work_rate(volume: Volume,
+    pressure_change: Pressure,
+    timestep: Time) -> Result<Power, TuasLibError> {
+
+    if !timestep.get::<second>().is_finite() || timestep.get::<second>() <= 0.0 {
+        return Err(TuasLibError::GenericStringError(format!(
+            "timestep for pressure work must be positive, got {} s",
+            timestep.get::<second>())));
+    }
+
+    Ok(volume * pressure_change / timestep)
+}
+
+/// rise in the liquid level of an expansion tank, given the mass of
+/// liquid in the rest of the loop at a reference state and now
+///
+/// as the liquid in the loop expands (or is compressed), its mass
+/// in the fixed volume loop falls (or rises), and the difference
+/// goes into (or comes out of) the expansion tank
+pub fn get_expansion_tank_level_change(reference_loop_liquid_mass: Mass,
+    loop_liquid_mass: Mass,
+    tank_liquid_density: MassDensity,
+    tank_cross_sectional_area: Area) -> Length {
+
+    (reference_loop_liquid_mass - loop_liquid_mass)
+        / (tank_liquid_density * tank_cross_sectional_area)
+}
+
+/// water at reference pressure should have the same properties as
+/// the database, and its density should rise by about 0.45% per
+/// 100 bar, enthalpy and temperature should also be inverses of
+/// each other at high pressure
+#[test]
+pub fn weakly_compressible_water_properties(){
+
+    use approx::assert_relative_eq;
+    use uom::si::pressure::bar;
+
+    use super::Material;
+    use super::density::try_get_rho;
+    use super::specific_enthalpy::{try_get_h, try_get_temperature_from_h};
+
+    let water = LiquidMaterial::Water;
+    let compressible_water: LiquidMaterial =
+        WeaklyCompressibleLiquid::try_new_from_database(water).unwrap().into();
+
+    let temperature = ThermodynamicTemperature::new::<kelvin>(330.0);
+    let atmospheric_pressure = Pressure::new::<atmosphere>(1.0);
+    let high_pressure = atmospheric_pressure + Pressure::new::<bar>(100.0);
+
+    // same as the database at 1 atm
+    assert_eq!(
+        try_get_rho(Material::Liquid(water), temperature, atmospheric_pressure).unwrap(),
+        try_get_rho(Material::Liquid(compressible_water), temperature,
+            atmospheric_pressure).unwrap());
+    assert_eq!(
+        try_get_h(Material::Liquid(water), temperature, atmospheric_pressure).unwrap(),
+        try_get_h(Material::Liquid(compressible_water), temperature,
+            atmospheric_pressure).unwrap());
+
+    // incompressible water does not change density with pressure
+    assert_eq!(
+        try_get_rho(Material::Liquid(water), temperature, high_pressure).unwrap(),
+        try_get_rho(Material::Liquid(water), temperature, atmospheric_pressure).unwrap());
+
+    let density_ratio: Ratio =
+        try_get_rho(Material::Liquid(compressible_water), temperature, high_pressure).unwrap()
+        / try_get_rho(Material::Liquid(compressible_water), temperature,
+            atmospheric_pressure).unwrap();
+    assert_relative_eq!(density_ratio.value, 1.0045, max_relative = 1e-4);
+
+    // water near 330 K expands by about 5e-4 per kelvin
+    let thermal_expansion = WeaklyCompressibleLiquid::try_new_from_database(water).unwrap()
+        .try_get_thermal_expansion_coefficient(temperature).unwrap();
+    assert_relative_eq!(thermal_expansion.get::<per_kelvin>(), 5.0e-4, max_relative = 0.15);
+
+    // compressing at constant temperature raises enthalpy by
+    // (1 - beta T)(1 - exp(-kappa dp))/(kappa rho), about
+    // 0.83 dp/rho for water at 330 K
+    let enthalpy_rise: AvailableEnergy =
+        try_get_h(Material::Liquid(compressible_water), temperature, high_pressure).unwrap()
+        - try_get_h(Material::Liquid(compressible_water), temperature,
+            atmospheric_pressure).unwrap();
+    let water_density = try_get_rho(Material::Liquid(water), temperature,
+        atmospheric_pressure).unwrap();
+    let flow_work: AvailableEnergy = Pressure::new::<bar>(100.0) / water_density;
+    let kappa_dp = WeaklyCompressibleLiquid::try_new_from_database(water).unwrap()
+        .isothermal_compressibility_per_pascal * Pressure::new::<bar>(100.0).get::<pascal>();
+    assert_relative_eq!((enthalpy_rise / flow_work).value,
+        (1.0 - thermal_expansion.get::<per_kelvin>() * 330.0) * -(-kappa_dp).exp_m1() / kappa_dp,
+        max_relative = 1e-9);
+
+    let high_pressure_enthalpy = try_get_h(
+        Material::Liquid(compressible_water), temperature, high_pressure).unwrap();
+    let recovered_temperature = try_get_temperature_from_h(
+        Material::Liquid(compressible_water), high_pressure_enthalpy, high_pressure).unwrap();
+    assert_relative_eq!(recovered_temperature.get::<kelvin>(), 330.0, epsilon = 1e-6);
+}
+
+/// gases and custom liquids cannot be made weakly compressible,
+/// and only water has a compressibility in the database
+#[test]
+pub fn weakly_compressible_liquid_construction(){
+
+    use super::property_correlations::LiquidDynamicViscosityCorrelation;
+
+    assert!(WeaklyCompressibleLiquid::try_new(LiquidMaterial::Air, 1.0e-10).is_err());
+    assert!(WeaklyCompressibleLiquid::try_new(LiquidMaterial::FLiBe, -1.0e-10).is_err());
+    assert!(WeaklyCompressibleLiquid::try_new_from_database(LiquidMaterial::FLiBe).is_err());
+
+    // selected correlations are kept
+    let flibe_cantor: LiquidMaterial = LiquidCorrelations::try_new(LiquidMaterial::FLiBe)
+        .unwrap()
+        .try_with_dynamic_viscosity(LiquidDynamicViscosityCorrelation::FLiBeCantor)
+        .unwrap()
+        .into();
+    let compressible_flibe: LiquidMaterial =
+        WeaklyCompressibleLiquid::try_new(flibe_cantor, 2.0e-10).unwrap().into();
+
+    let temperature = ThermodynamicTemperature::new::<kelvin>(900.0);
+    assert_eq!(flibe_cantor.try_get_dynamic_viscosity(temperature).unwrap(),
+        compressible_flibe.try_get_dynamic_viscosity(temperature).unwrap());
+
+    // the reference pressure is kept when the compressibility
+    // is changed
+    let mut pressurised_flibe =
+        WeaklyCompressibleLiquid::try_new(LiquidMaterial::FLiBe, 2.0e-10).unwrap();
+    pressurised_flibe.reference_pressure = Pressure::new::<atmosphere>(5.0);
+    let stiffer_flibe = WeaklyCompressibleLiquid::try_new(
+        pressurised_flibe.into(), 1.0e-10).unwrap();
+    assert_eq!(stiffer_flibe.reference_pressure, Pressure::new::<atmosphere>(5.0));
+}
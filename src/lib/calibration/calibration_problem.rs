@@ -0,0 +1,371 @@
+use crate::tuas_lib_error::TuasLibError;
+
+use super::levenberg_marquardt::levenberg_marquardt_minimise;
+use super::nelder_mead::nelder_mead_minimise;
+
+/// a parameter which the optimiser is allowed to vary,
+/// bound to a setter on the model, for example
+/// calibrate_insulation_thickness, calibrate_heat_transfer_to_ambient
+/// or try_calibrate_gnielinski_nusselt
+///
+/// parameter values are plain f64 in SI units (eg. meters for
+/// insulation thickness, W/(m^2 K) for heat transfer coefficients),
+/// the setter converts them into the appropriate uom quantity
+pub struct TunableParameter<M> {
+    /// name of the parameter, used in reporting
+    pub name: String,
+    /// lower bound of the parameter
+    pub lower_bound: f64,
+    /// upper bound of the parameter
+    pub upper_bound: f64,
+    /// initial guess for the parameter
+    pub initial_value: f64,
+    setter: Box<dyn Fn(&mut M, f64) -> Result<(), TuasLibError>>,
+}
+
+impl<M> TunableParameter<M> {
+
+    /// creates a new tunable parameter bound to a setter
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::length::meter;
+    /// use tuas_boussinesq_solver::calibration::TunableParameter;
+    /// use tuas_boussinesq_solver::pre_built_components::
+    /// insulated_pipes_and_fluid_components::InsulatedFluidComponent;
+    ///
+    /// let insulation_thickness: TunableParameter<InsulatedFluidComponent>
+    ///     = TunableParameter::new(
+    ///     "insulation thickness (m)",
+    ///     0.001, 0.1, 0.0381,
+    ///     |pipe: &mut InsulatedFluidComponent, thickness: f64| {
+    ///         pipe.calibrate_insulation_thickness(
+    ///             Length::new::<meter>(thickness));
+    ///         Ok(())
+    ///     }).unwrap();
+    ///
+    /// assert_eq!(insulation_thickness.initial_value, 0.0381);
+    /// ```
+    ///
+    /// returns an error if the bounds are not ordered or the
+    /// initial value lies outside the bounds
+    pub fn new<F>(name: &str,
+        lower_bound: f64,
+        upper_bound: f64,
+        initial_value: f64,
+        setter: F) -> Result<Self, TuasLibError>
+    where F: Fn(&mut M, f64) -> Result<(), TuasLibError> + 'static {
+
+        if !(lower_bound < upper_bound) {
+            return Err(TuasLibError::GenericStringError(
+                format!("tunable parameter {}: lower bound must be \
+                    less than upper bound", name)));
+        }
+
+        if initial_value < lower_bound || initial_value > upper_bound {
+            return Err(TuasLibError::GenericStringError(
+                format!("tunable parameter {}: initial value {} is \
+                    outside bounds [{}, {}]",
+                    name, initial_value, lower_bound, upper_bound)));
+        }
+
+        Ok(Self {
+            name: name.to_owned(),
+            lower_bound,
+            upper_bound,
+            initial_value,
+            setter: Box::new(setter),
+        })
+    }
+
+    /// applies the parameter value to the model
+    pub fn try_apply(&self, model: &mut M, value: f64)
+        -> Result<(), TuasLibError> {
+        (self.setter)(model, value)
+    }
+
+    /// clamps a value to within the bounds of this parameter
+    pub fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.lower_bound, self.upper_bound)
+    }
+}
+
+/// an experimental observation to compare against
+/// simulation output
+#[derive(Debug,Clone,PartialEq)]
+pub struct Observation {
+    /// name of the observation, used in reporting
+    pub name: String,
+    /// measured value (SI units)
+    pub measured_value: f64,
+    /// measurement uncertainty (SI units), residuals are
+    /// divided by this, so observations with smaller uncertainty
+    /// are weighted more heavily
+    pub uncertainty: f64,
+}
+
+impl Observation {
+    /// creates a new observation with unit uncertainty
+    pub fn new(name: &str, measured_value: f64) -> Self {
+        Self {
+            name: name.to_owned(),
+            measured_value,
+            uncertainty: 1.0,
+        }
+    }
+
+    /// creates a new observation with a given measurement uncertainty,
+    /// which must be positive and finite since residuals are divided
+    /// by it
+    pub fn try_new_with_uncertainty(name: &str,
+        measured_value: f64,
+        uncertainty: f64) -> Result<Self, TuasLibError> {
+
+        let observation = Self {
+            name: name.to_owned(),
+            measured_value,
+            uncertainty,
+        };
+        observation.try_validate()?;
+
+        Ok(observation)
+    }
+
+    /// checks that the measured value is finite and the
+    /// uncertainty is positive and finite
+    pub fn try_validate(&self) -> Result<(), TuasLibError> {
+
+        if !self.measured_value.is_finite() {
+            return Err(TuasLibError::GenericStringError(
+                format!("observation {}: measured value must be finite",
+                    self.name)));
+        }
+
+        if !self.uncertainty.is_finite() || self.uncertainty <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                format!("observation {}: uncertainty {} must be positive \
+                    and finite", self.name, self.uncertainty)));
+        }
+
+        Ok(())
+    }
+}
+
+/// optimiser used for calibration
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum CalibrationMethod {
+    /// bounded Nelder-Mead simplex, derivative free and robust,
+    /// but needs more simulation runs
+    NelderMead,
+    /// Levenberg-Marquardt with a forward difference jacobian,
+    /// converges in fewer iterations for smooth problems
+    LevenbergMarquardt,
+}
+
+/// settings for the optimiser
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct CalibrationSettings {
+    /// optimiser used
+    pub method: CalibrationMethod,
+    /// maximum number of iterations
+    pub max_iterations: usize,
+    /// convergence tolerance, relative change in the sum of
+    /// squared residuals
+    pub tolerance: f64,
+}
+
+impl Default for CalibrationSettings {
+    fn default() -> Self {
+        Self {
+            method: CalibrationMethod::LevenbergMarquardt,
+            max_iterations: 200,
+            tolerance: 1e-10,
+        }
+    }
+}
+
+/// result of a calibration run
+#[derive(Debug,Clone,PartialEq)]
+pub struct CalibrationResult {
+    /// names of the tunable parameters
+    pub parameter_names: Vec<String>,
+    /// fitted parameter values (SI units)
+    pub fitted_values: Vec<f64>,
+    /// names of the observations
+    pub observation_names: Vec<String>,
+    /// simulated values at the fitted parameters
+    pub predicted_values: Vec<f64>,
+    /// residuals at the fitted parameters,
+    /// (predicted - measured)/uncertainty
+    pub residuals: Vec<f64>,
+    /// sum of squared residuals at the fitted parameters
+    pub sum_of_squared_residuals: f64,
+    /// number of iterations used
+    pub iterations: usize,
+    /// number of simulation runs used
+    pub simulation_runs: usize,
+    /// whether the tolerance was reached before the maximum
+    /// number of iterations
+    pub converged: bool,
+}
+
+impl CalibrationResult {
+    /// root mean square of the residuals
+    pub fn get_rms_residual(&self) -> f64 {
+        if self.residuals.is_empty() {
+            return 0.0;
+        }
+        (self.sum_of_squared_residuals / self.residuals.len() as f64).sqrt()
+    }
+}
+
+/// a calibration problem, which consists of a model
+/// (eg. a component or a whole loop), the tunable parameters bound to
+/// its setters, experimental observations, and a simulation closure
+/// which runs the model (steady state or transient) and returns
+/// the simulated values in the same order as the observations
+///
+/// the model is cloned for every simulation run, so the
+/// simulation always starts from the same initial state
+pub struct CalibrationProblem<M: Clone> {
+    /// the model in its initial state
+    pub model: M,
+    /// tunable parameters
+    pub parameters: Vec<TunableParameter<M>>,
+    /// experimental observations
+    pub observations: Vec<Observation>,
+    simulation: Box<dyn Fn(&mut M) -> Result<Vec<f64>, TuasLibError>>,
+}
+
+impl<M: Clone> CalibrationProblem<M> {
+
+    /// creates a new calibration problem with no parameters
+    /// or observations
+    pub fn new<F>(model: M, simulation: F) -> Self
+    where F: Fn(&mut M) -> Result<Vec<f64>, TuasLibError> + 'static {
+        Self {
+            model,
+            parameters: vec![],
+            observations: vec![],
+            simulation: Box::new(simulation),
+        }
+    }
+
+    /// adds a tunable parameter
+    pub fn add_parameter(&mut self, parameter: TunableParameter<M>) {
+        self.parameters.push(parameter);
+    }
+
+    /// adds an observation
+    pub fn add_observation(&mut self, observation: Observation) {
+        self.observations.push(observation);
+    }
+
+    /// clamps parameter values to within their bounds
+    pub fn clamp_to_bounds(&self, parameter_values: &[f64]) -> Vec<f64> {
+        self.parameters.iter().zip(parameter_values.iter())
+            .map(|(parameter, value)| parameter.clamp(*value))
+            .collect()
+    }
+
+    /// runs the simulation with the given parameter values
+    /// and returns the simulated values
+    pub fn try_get_predicted_values(&self, parameter_values: &[f64])
+        -> Result<Vec<f64>, TuasLibError> {
+
+        if parameter_values.len() != self.parameters.len() {
+            return Err(TuasLibError::GenericStringError(
+                format!("expected {} parameter values, got {}",
+                    self.parameters.len(), parameter_values.len())));
+        }
+
+        let mut model = self.model.clone();
+
+        for (parameter, value) in self.parameters.iter()
+            .zip(parameter_values.iter()) {
+            parameter.try_apply(&mut model, *value)?;
+        }
+
+        let predicted_values = (self.simulation)(&mut model)?;
+
+        if predicted_values.len() != self.observations.len() {
+            return Err(TuasLibError::GenericStringError(
+                format!("simulation returned {} values for {} observations",
+                    predicted_values.len(), self.observations.len())));
+        }
+
+        Ok(predicted_values)
+    }
+
+    /// residuals from predicted values,
+    /// (predicted - measured)/uncertainty
+    pub fn get_residuals_from_predictions(&self,
+        predicted_values: &[f64]) -> Vec<f64> {
+        self.observations.iter().zip(predicted_values.iter())
+            .map(|(observation, predicted)| {
+                (predicted - observation.measured_value)
+                    / observation.uncertainty
+            }).collect()
+    }
+
+    /// runs the simulation with the given parameter values
+    /// and returns the residuals
+    pub fn try_get_residuals(&self, parameter_values: &[f64])
+        -> Result<Vec<f64>, TuasLibError> {
+        let predicted_values = self.try_get_predicted_values(
+            parameter_values)?;
+        Ok(self.get_residuals_from_predictions(&predicted_values))
+    }
+
+    /// fits the tunable parameters to the observations by
+    /// minimising the sum of squared residuals, starting
+    /// from the initial values of the parameters
+    pub fn try_calibrate(&self, settings: CalibrationSettings)
+        -> Result<CalibrationResult, TuasLibError> {
+
+        if self.parameters.is_empty() {
+            return Err(TuasLibError::GenericStringError(
+                "calibration problem has no tunable parameters".to_owned()));
+        }
+        if self.observations.is_empty() {
+            return Err(TuasLibError::GenericStringError(
+                "calibration problem has no observations".to_owned()));
+        }
+        for observation in self.observations.iter() {
+            observation.try_validate()?;
+        }
+
+        let initial_values: Vec<f64> = self.parameters.iter()
+            .map(|parameter| parameter.initial_value)
+            .collect();
+
+        let (fitted_values, iterations, simulation_runs, converged) =
+        match settings.method {
+            CalibrationMethod::NelderMead => {
+                nelder_mead_minimise(self, initial_values, settings)?
+            },
+            CalibrationMethod::LevenbergMarquardt => {
+                levenberg_marquardt_minimise(self, initial_values, settings)?
+            },
+        };
+
+        let predicted_values = self.try_get_predicted_values(&fitted_values)?;
+        let residuals = self.get_residuals_from_predictions(&predicted_values);
+        let sum_of_squared_residuals: f64 = residuals.iter()
+            .map(|residual| residual * residual).sum();
+
+        Ok(CalibrationResult {
+            parameter_names: self.parameters.iter()
+                .map(|parameter| parameter.name.clone()).collect(),
+            fitted_values,
+            observation_names: self.observations.iter()
+                .map(|observation| observation.name.clone()).collect(),
+            predicted_values,
+            residuals,
+            sum_of_squared_residuals,
+            iterations,
+            simulation_runs: simulation_runs + 1,
+            converged,
+        })
+    }
+}
@@ -0,0 +1,146 @@
+use peroxide::fuga::{matrix, LinearAlgebra, Matrix, Shape, SolveKind};
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::calibration_problem::{CalibrationProblem, CalibrationSettings};
+
+/// minimises the sum of squared residuals of a calibration problem
+/// using the Levenberg-Marquardt method,
+///
+/// (J^T J + lambda diag(J^T J)) delta = - J^T r
+///
+/// the jacobian J is obtained by forward differences since the
+/// simulations are black boxes to the optimiser (peroxide's own
+/// Optimizer needs automatic differentiation of the model).
+/// Bounds are enforced by clamping every trial point to within the
+/// parameter bounds.
+///
+/// returns the fitted values, iterations, simulation runs and
+/// whether the method converged
+///
+/// Marquardt, D. W. (1963). An algorithm for least-squares estimation
+/// of nonlinear parameters. Journal of the society for Industrial and
+/// Applied Mathematics, 11(2), 431-441.
+pub fn levenberg_marquardt_minimise<M: Clone>(
+    problem: &CalibrationProblem<M>,
+    initial_values: Vec<f64>,
+    settings: CalibrationSettings,
+) -> Result<(Vec<f64>, usize, usize, bool), TuasLibError> {
+
+    let number_of_parameters = initial_values.len();
+    let number_of_observations = problem.observations.len();
+
+    let mut simulation_runs: usize = 0;
+    let mut parameter_values = problem.clamp_to_bounds(&initial_values);
+
+    let mut residuals = problem.try_get_residuals(&parameter_values)?;
+    simulation_runs += 1;
+    let mut sum_of_squares: f64 = residuals.iter().map(|r| r * r).sum();
+
+    let mut lambda: f64 = 1e-3;
+    let lambda_max: f64 = 1e10;
+
+    let mut iterations: usize = 0;
+    let mut converged = false;
+
+    while iterations < settings.max_iterations {
+        iterations += 1;
+
+        // forward difference jacobian, stepping backwards if the
+        // forward step would go past the upper bound
+        let mut jacobian_data: Vec<f64> =
+            vec![0.0; number_of_observations * number_of_parameters];
+
+        for (j, parameter) in problem.parameters.iter().enumerate() {
+            let scale = parameter_values[j].abs()
+                .max(1e-3 * (parameter.upper_bound - parameter.lower_bound));
+            let mut step = f64::EPSILON.sqrt() * scale;
+
+            if parameter_values[j] + step > parameter.upper_bound {
+                step = -step;
+            }
+
+            let mut perturbed_values = parameter_values.clone();
+            perturbed_values[j] += step;
+
+            let perturbed_residuals = problem.try_get_residuals(
+                &perturbed_values)?;
+            simulation_runs += 1;
+
+            for i in 0..number_of_observations {
+                jacobian_data[i * number_of_parameters + j] =
+                    (perturbed_residuals[i] - residuals[i]) / step;
+            }
+        }
+
+        let jacobian: Matrix = matrix(jacobian_data,
+            number_of_observations, number_of_parameters, Shape::Row);
+        let jacobian_transpose = jacobian.t();
+        let jtj: Matrix = &jacobian_transpose * &jacobian;
+        let residual_vector: Matrix = matrix(residuals.clone(),
+            number_of_observations, 1, Shape::Col);
+        let gradient: Vec<f64> = (&jacobian_transpose * &residual_vector).data;
+
+        // try increasing damping until the sum of squares decreases
+        let mut step_accepted = false;
+
+        while lambda <= lambda_max {
+
+            let mut damped_jtj = jtj.clone();
+            for k in 0..number_of_parameters {
+                // a small floor keeps the system solvable when a
+                // parameter has no effect on the residuals
+                damped_jtj[(k, k)] += lambda * jtj[(k, k)].max(1e-12);
+            }
+
+            let negative_gradient: Vec<f64> = gradient.iter()
+                .map(|g| -g).collect();
+            let delta: Vec<f64> = damped_jtj.solve(&negative_gradient,
+                SolveKind::LU);
+
+            if delta.iter().any(|d| !d.is_finite()) {
+                lambda *= 10.0;
+                continue;
+            }
+
+            let trial_values: Vec<f64> = problem.clamp_to_bounds(
+                &parameter_values.iter().zip(delta.iter())
+                .map(|(p, d)| p + d).collect::<Vec<f64>>());
+
+            let trial_residuals = problem.try_get_residuals(&trial_values)?;
+            simulation_runs += 1;
+            let trial_sum_of_squares: f64 = trial_residuals.iter()
+                .map(|r| r * r).sum();
+
+            if trial_sum_of_squares < sum_of_squares {
+                let relative_decrease = (sum_of_squares - trial_sum_of_squares)
+                    / (sum_of_squares + settings.tolerance);
+
+                parameter_values = trial_values;
+                residuals = trial_residuals;
+                sum_of_squares = trial_sum_of_squares;
+                lambda = (lambda * 0.1).max(1e-12);
+                step_accepted = true;
+
+                if relative_decrease < settings.tolerance {
+                    converged = true;
+                }
+                break;
+            }
+
+            lambda *= 10.0;
+        }
+
+        // no step reduces the sum of squares, so we are at
+        // a (possibly bounded) minimum
+        if !step_accepted {
+            converged = true;
+        }
+
+        if converged {
+            break;
+        }
+    }
+
+    Ok((parameter_values, iterations, simulation_runs, converged))
+}
@@ -0,0 +1,16 @@
+/// tunable parameters, observations and the calibration problem
+/// which binds them to a model and a simulation run
+pub mod calibration_problem;
+pub use calibration_problem::*;
+
+/// bounded Nelder-Mead simplex minimisation of the sum of
+/// squared residuals
+pub mod nelder_mead;
+
+/// Levenberg-Marquardt least squares with a finite difference
+/// jacobian
+pub mod levenberg_marquardt;
+
+/// tests for the calibration problem and optimisers
+#[cfg(test)]
+pub mod tests;
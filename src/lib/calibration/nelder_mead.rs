@@ -0,0 +1,164 @@
+use crate::tuas_lib_error::TuasLibError;
+
+use super::calibration_problem::{CalibrationProblem, CalibrationSettings};
+
+/// minimises the sum of squared residuals of a calibration problem
+/// using the Nelder-Mead simplex method,
+///
+/// reflection, expansion, contraction and shrink coefficients are
+/// the standard 1, 2, 0.5 and 0.5. Bounds are enforced by clamping
+/// every trial point to within the parameter bounds.
+///
+/// returns the fitted values, iterations, simulation runs and
+/// whether the method converged
+///
+/// Nelder, J. A., & Mead, R. (1965). A simplex method for function
+/// minimization. The computer journal, 7(4), 308-313.
+pub fn nelder_mead_minimise<M: Clone>(
+    problem: &CalibrationProblem<M>,
+    initial_values: Vec<f64>,
+    settings: CalibrationSettings,
+) -> Result<(Vec<f64>, usize, usize, bool), TuasLibError> {
+
+    let reflection_coefficient: f64 = 1.0;
+    let expansion_coefficient: f64 = 2.0;
+    let contraction_coefficient: f64 = 0.5;
+    let shrink_coefficient: f64 = 0.5;
+
+    let number_of_parameters = initial_values.len();
+    let mut simulation_runs: usize = 0;
+
+    let mut sum_of_squares = |values: &[f64]| -> Result<f64, TuasLibError> {
+        simulation_runs += 1;
+        let residuals = problem.try_get_residuals(values)?;
+        Ok(residuals.iter().map(|residual| residual * residual).sum())
+    };
+
+    // initial simplex, each vertex is displaced by 10% of the
+    // parameter range in one direction, going towards the
+    // lower bound if the upper bound is too close
+    let initial_values = problem.clamp_to_bounds(&initial_values);
+    let mut simplex: Vec<Vec<f64>> = vec![initial_values.clone()];
+
+    for (index, parameter) in problem.parameters.iter().enumerate() {
+        let mut vertex = initial_values.clone();
+        let step = 0.1 * (parameter.upper_bound - parameter.lower_bound);
+
+        if vertex[index] + step <= parameter.upper_bound {
+            vertex[index] += step;
+        } else {
+            vertex[index] -= step;
+        }
+        simplex.push(vertex);
+    }
+
+    let mut function_values: Vec<f64> = vec![];
+    for vertex in simplex.iter() {
+        function_values.push(sum_of_squares(vertex)?);
+    }
+
+    let mut iterations: usize = 0;
+    let mut converged = false;
+
+    while iterations < settings.max_iterations {
+        iterations += 1;
+
+        // sort vertices from best to worst
+        let mut order: Vec<usize> = (0..simplex.len()).collect();
+        order.sort_by(|a, b| function_values[*a]
+            .partial_cmp(&function_values[*b])
+            .unwrap_or(std::cmp::Ordering::Equal));
+        simplex = order.iter().map(|i| simplex[*i].clone()).collect();
+        function_values = order.iter().map(|i| function_values[*i]).collect();
+
+        let best_value = function_values[0];
+        let worst_value = function_values[number_of_parameters];
+
+        // converged when the spread of function values is small
+        // compared to the function values themselves
+        if (worst_value - best_value).abs()
+            <= settings.tolerance * (best_value.abs() + settings.tolerance) {
+            converged = true;
+            break;
+        }
+
+        // centroid of all vertices except the worst
+        let centroid: Vec<f64> = (0..number_of_parameters).map(|j| {
+            simplex[..number_of_parameters].iter()
+                .map(|vertex| vertex[j]).sum::<f64>()
+                / number_of_parameters as f64
+        }).collect();
+
+        let worst_vertex = simplex[number_of_parameters].clone();
+
+        let point_along_line = |coefficient: f64| -> Vec<f64> {
+            let point: Vec<f64> = centroid.iter().zip(worst_vertex.iter())
+                .map(|(c, w)| c + coefficient * (c - w))
+                .collect();
+            problem.clamp_to_bounds(&point)
+        };
+
+        let reflected = point_along_line(reflection_coefficient);
+        let reflected_value = sum_of_squares(&reflected)?;
+
+        if reflected_value < best_value {
+            let expanded = point_along_line(
+                reflection_coefficient * expansion_coefficient);
+            let expanded_value = sum_of_squares(&expanded)?;
+
+            if expanded_value < reflected_value {
+                simplex[number_of_parameters] = expanded;
+                function_values[number_of_parameters] = expanded_value;
+            } else {
+                simplex[number_of_parameters] = reflected;
+                function_values[number_of_parameters] = reflected_value;
+            }
+            continue;
+        }
+
+        if reflected_value < function_values[number_of_parameters - 1] {
+            simplex[number_of_parameters] = reflected;
+            function_values[number_of_parameters] = reflected_value;
+            continue;
+        }
+
+        // contraction, outside if the reflected point is better
+        // than the worst point, inside otherwise
+        let (contracted, contracted_value) =
+        if reflected_value < worst_value {
+            let contracted = point_along_line(
+                reflection_coefficient * contraction_coefficient);
+            let contracted_value = sum_of_squares(&contracted)?;
+            (contracted, contracted_value)
+        } else {
+            let contracted = point_along_line(-contraction_coefficient);
+            let contracted_value = sum_of_squares(&contracted)?;
+            (contracted, contracted_value)
+        };
+
+        if contracted_value < reflected_value.min(worst_value) {
+            simplex[number_of_parameters] = contracted;
+            function_values[number_of_parameters] = contracted_value;
+            continue;
+        }
+
+        // shrink all vertices towards the best vertex
+        let best_vertex = simplex[0].clone();
+        for index in 1..simplex.len() {
+            let shrunk: Vec<f64> = best_vertex.iter()
+                .zip(simplex[index].iter())
+                .map(|(b, v)| b + shrink_coefficient * (v - b))
+                .collect();
+            function_values[index] = sum_of_squares(&shrunk)?;
+            simplex[index] = shrunk;
+        }
+    }
+
+    let best_index = function_values.iter().enumerate()
+        .min_by(|a, b| a.1.partial_cmp(b.1)
+            .unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+
+    Ok((simplex[best_index].clone(), iterations, simulation_runs, converged))
+}
@@ -0,0 +1,390 @@
+use uom::si::f64::*;
+use uom::si::angle::degree;
+use uom::si::area::square_meter;
+use uom::si::heat_capacity::joule_per_kelvin;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::length::{meter, millimeter};
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::power::watt;
+use uom::si::pressure::atmosphere;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+use uom::si::time::second;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::boundary_conditions::BCType;
+use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+use crate::pre_built_components::insulated_pipes_and_fluid_components::InsulatedFluidComponent;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::*;
+
+/// a lumped body cooling to ambient,
+///
+/// C dT/dt = - h A (T - T_amb)
+///
+/// used to test calibration against transient data
+#[derive(Debug,Clone,PartialEq)]
+struct LumpedBodyCooling {
+    heat_capacity: HeatCapacity,
+    heat_transfer_to_ambient: HeatTransfer,
+    surface_area: Area,
+    ambient_temperature: ThermodynamicTemperature,
+    temperature: ThermodynamicTemperature,
+}
+
+impl LumpedBodyCooling {
+
+    fn new() -> Self {
+        Self {
+            heat_capacity: HeatCapacity::new::<joule_per_kelvin>(5000.0),
+            heat_transfer_to_ambient:
+                HeatTransfer::new::<watt_per_square_meter_kelvin>(10.0),
+            surface_area: Area::new::<square_meter>(1.0),
+            ambient_temperature: ThermodynamicTemperature::new::<kelvin>(300.0),
+            temperature: ThermodynamicTemperature::new::<kelvin>(400.0),
+        }
+    }
+
+    fn calibrate_heat_transfer_to_ambient(&mut self,
+        heat_transfer_to_ambient: HeatTransfer){
+        self.heat_transfer_to_ambient = heat_transfer_to_ambient;
+    }
+
+    /// explicit euler, returns temperatures (K) at the sample times
+    fn run_transient(&mut self, sample_times: &[f64]) -> Vec<f64> {
+        let timestep = Time::new::<second>(0.1);
+        let mut simulation_time = Time::new::<second>(0.0);
+        let mut samples = vec![];
+
+        for sample_time in sample_times.iter() {
+            while simulation_time.get::<second>() < *sample_time - 1e-9 {
+                let temperature_difference: TemperatureInterval =
+                    TemperatureInterval::new::<uom::si::temperature_interval::kelvin>(
+                        self.temperature.get::<kelvin>()
+                        - self.ambient_temperature.get::<kelvin>());
+
+                let heat_loss: Power = self.heat_transfer_to_ambient
+                    * self.surface_area * temperature_difference;
+
+                let temperature_change: TemperatureInterval =
+                    heat_loss * timestep / self.heat_capacity;
+
+                self.temperature -= temperature_change;
+                simulation_time += timestep;
+            }
+            samples.push(self.temperature.get::<kelvin>());
+        }
+
+        samples
+    }
+}
+
+fn lumped_cooling_calibration_problem() -> CalibrationProblem<LumpedBodyCooling> {
+
+    let sample_times: Vec<f64> = vec![100.0, 200.0, 400.0, 800.0];
+
+    // synthetic experimental data generated with h = 25 W/(m^2 K)
+    let mut experiment = LumpedBodyCooling::new();
+    experiment.calibrate_heat_transfer_to_ambient(
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(25.0));
+    let measured_temperatures = experiment.run_transient(&sample_times);
+
+    let mut problem = CalibrationProblem::new(
+        LumpedBodyCooling::new(),
+        move |body: &mut LumpedBodyCooling| {
+            Ok(body.run_transient(&sample_times))
+        });
+
+    problem.add_parameter(TunableParameter::new(
+        "heat transfer to ambient (W/(m^2 K))",
+        1.0, 100.0, 10.0,
+        |body: &mut LumpedBodyCooling, htc: f64| {
+            body.calibrate_heat_transfer_to_ambient(
+                HeatTransfer::new::<watt_per_square_meter_kelvin>(htc));
+            Ok(())
+        }).unwrap());
+
+    for (index, measured_temperature) in measured_temperatures.iter().enumerate() {
+        problem.add_observation(Observation::try_new_with_uncertainty(
+            &format!("body temperature sample {}", index),
+            *measured_temperature,
+            0.5).unwrap());
+    }
+
+    problem
+}
+
+/// both optimisers should recover the heat transfer coefficient
+/// used to generate synthetic transient cooling data
+#[test]
+pub fn calibrate_heat_transfer_to_ambient_from_transient_data(){
+
+    let problem = lumped_cooling_calibration_problem();
+
+    for method in [CalibrationMethod::LevenbergMarquardt,
+        CalibrationMethod::NelderMead] {
+
+        let settings = CalibrationSettings {
+            method,
+            ..Default::default()
+        };
+
+        let result = problem.try_calibrate(settings).unwrap();
+
+        assert!(result.converged);
+        approx::assert_relative_eq!(result.fitted_values[0],
+            25.0, max_relative=1e-4);
+        assert!(result.get_rms_residual() < 1e-3);
+        assert_eq!(result.residuals.len(), 4);
+    }
+}
+
+/// fitting y = a exp(-b x) + c to exact data with three
+/// parameters, where the initial guess is far from the answer
+#[test]
+pub fn calibrate_three_parameter_exponential_decay(){
+
+    let x_data: Vec<f64> = (0..10).map(|i| i as f64 * 0.5).collect();
+    let exact = |a: f64, b: f64, c: f64, x: f64| a * (-b * x).exp() + c;
+
+    let mut problem = CalibrationProblem::new(
+        [1.0_f64, 1.0, 0.0],
+        move |coefficients: &mut [f64; 3]| {
+            Ok(x_data.iter().map(|x| exact(
+                coefficients[0], coefficients[1], coefficients[2], *x))
+                .collect())
+        });
+
+    let names = ["a", "b", "c"];
+    for (index, name) in names.iter().enumerate() {
+        problem.add_parameter(TunableParameter::new(
+            name, -10.0, 10.0, [1.0, 1.0, 0.0][index],
+            move |coefficients: &mut [f64; 3], value: f64| {
+                coefficients[index] = value;
+                Ok(())
+            }).unwrap());
+    }
+
+    for i in 0..10 {
+        let x = i as f64 * 0.5;
+        problem.add_observation(Observation::new(
+            &format!("y at x = {}", x), exact(4.0, 0.7, 1.5, x)));
+    }
+
+    let nelder_mead_settings = CalibrationSettings {
+        method: CalibrationMethod::NelderMead,
+        max_iterations: 2000,
+        tolerance: 1e-14,
+    };
+
+    for settings in [CalibrationSettings::default(), nelder_mead_settings] {
+        let result = problem.try_calibrate(settings).unwrap();
+
+        approx::assert_relative_eq!(result.fitted_values[0], 4.0,
+            max_relative=1e-3);
+        approx::assert_relative_eq!(result.fitted_values[1], 0.7,
+            max_relative=1e-3);
+        approx::assert_relative_eq!(result.fitted_values[2], 1.5,
+            max_relative=1e-3);
+    }
+}
+
+/// the fitted value should stay within bounds if the
+/// unconstrained optimum lies outside them,
+/// and invalid bounds should be rejected
+#[test]
+pub fn calibration_respects_parameter_bounds(){
+
+    let mut problem = CalibrationProblem::new(
+        0.0_f64,
+        |value: &mut f64| Ok(vec![*value]));
+
+    problem.add_parameter(TunableParameter::new(
+        "x", 0.0, 2.0, 1.0,
+        |value: &mut f64, x: f64| { *value = x; Ok(()) }).unwrap());
+    problem.add_observation(Observation::new("x measured", 5.0));
+
+    for method in [CalibrationMethod::LevenbergMarquardt,
+        CalibrationMethod::NelderMead] {
+        let result = problem.try_calibrate(CalibrationSettings {
+            method,
+            ..Default::default()
+        }).unwrap();
+
+        approx::assert_relative_eq!(result.fitted_values[0], 2.0,
+            max_relative=1e-6);
+        approx::assert_relative_eq!(result.residuals[0], -3.0,
+            max_relative=1e-6);
+    }
+
+    assert!(TunableParameter::new("bad bounds", 2.0, 1.0, 1.5,
+        |value: &mut f64, x: f64| { *value = x; Ok(()) }).is_err());
+    assert!(TunableParameter::new("bad initial value", 0.0, 1.0, 1.5,
+        |value: &mut f64, x: f64| { *value = x; Ok(()) }).is_err());
+}
+
+/// observations with zero, negative or NaN uncertainty would give
+/// infinite or NaN residuals, so they should be rejected both when
+/// constructed and when set directly before calibrating
+#[test]
+pub fn observations_with_invalid_uncertainty_are_rejected(){
+
+    for uncertainty in [0.0, -0.5, f64::NAN, f64::INFINITY] {
+        assert!(Observation::try_new_with_uncertainty(
+            "x measured", 5.0, uncertainty).is_err());
+    }
+    assert!(Observation::try_new_with_uncertainty(
+        "x measured", f64::NAN, 0.5).is_err());
+
+    let mut problem = CalibrationProblem::new(
+        0.0_f64,
+        |value: &mut f64| Ok(vec![*value]));
+
+    problem.add_parameter(TunableParameter::new(
+        "x", 0.0, 10.0, 1.0,
+        |value: &mut f64, x: f64| { *value = x; Ok(()) }).unwrap());
+
+    let mut observation = Observation::new("x measured", 5.0);
+    observation.uncertainty = 0.0;
+    problem.add_observation(observation);
+
+    assert!(problem.try_calibrate(CalibrationSettings::default()).is_err());
+
+    problem.observations[0].uncertainty = 0.5;
+    let result = problem.try_calibrate(CalibrationSettings::default()).unwrap();
+    approx::assert_relative_eq!(result.fitted_values[0], 5.0,
+        max_relative=1e-6);
+}
+
+/// a 1 m insulated pipe with the pipe 6a cross section,
+/// initially at 80 degC, with 20 degC ambient air
+fn new_insulated_pipe_at_80_degc(insulation_thickness: Length)
+    -> InsulatedFluidComponent {
+
+    let hydraulic_diameter = Length::new::<meter>(2.79e-2);
+    let shell_id = hydraulic_diameter;
+    let shell_od = shell_id + 2.0 * Length::new::<meter>(0.0027686);
+
+    InsulatedFluidComponent::new_insulated_pipe(
+        ThermodynamicTemperature::new::<degree_celsius>(80.0),
+        ThermodynamicTemperature::new::<degree_celsius>(20.0),
+        Pressure::new::<atmosphere>(1.0),
+        Pressure::new::<atmosphere>(1.0),
+        Area::new::<square_meter>(6.11e-4),
+        Angle::new::<degree>(0.0),
+        Ratio::new::<ratio>(0.0),
+        shell_id,
+        shell_od,
+        insulation_thickness,
+        Length::new::<meter>(1.0),
+        hydraulic_diameter,
+        SolidMaterial::SteelSS304L,
+        SolidMaterial::Fiberglass,
+        LiquidMaterial::TherminolVP1,
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0),
+        2,
+        Length::new::<millimeter>(0.015))
+}
+
+/// runs therminol at 80 degC through the pipe and returns the
+/// outlet temperatures (K) at the sample times (s)
+fn run_insulated_pipe_heat_loss_transient(pipe: &mut InsulatedFluidComponent,
+    sample_times: &[f64]) -> Result<Vec<f64>, TuasLibError> {
+
+    let therminol = LiquidMaterial::TherminolVP1;
+    let inlet_temperature = ThermodynamicTemperature::new::<degree_celsius>(80.0);
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.02);
+    let timestep = Time::new::<second>(1.0);
+
+    let mut inlet_bc: HeatTransferEntity =
+        BCType::new_const_temperature(inlet_temperature).into();
+    let mut outlet_bc: HeatTransferEntity = BCType::new_adiabatic_bc().into();
+
+    let mut simulation_time = Time::new::<second>(0.0);
+    let mut samples = vec![];
+
+    for sample_time in sample_times.iter() {
+        while simulation_time.get::<second>() < *sample_time - 1e-9 {
+            let fluid_array: FluidArray = pipe.pipe_fluid_array.clone()
+                .try_into()?;
+            let fluid_temperature_vector = fluid_array.get_temperature_vector()?;
+            let back_cv_density = therminol.try_get_density(
+                fluid_temperature_vector[0])?;
+            let front_cv_density = therminol.try_get_density(
+                *fluid_temperature_vector.last().unwrap())?;
+
+            pipe.pipe_fluid_array.link_to_back(&mut inlet_bc,
+                HeatTransferInteractionType::new_advection_interaction(
+                    mass_flowrate,
+                    therminol.try_get_density(inlet_temperature)?,
+                    back_cv_density))?;
+            pipe.pipe_fluid_array.link_to_front(&mut outlet_bc,
+                HeatTransferInteractionType::new_advection_interaction(
+                    mass_flowrate, front_cv_density, front_cv_density))?;
+
+            pipe.lateral_and_miscellaneous_connections_no_wall_correction(
+                mass_flowrate, Power::new::<watt>(0.0))?;
+            pipe.advance_timestep(timestep)?;
+            simulation_time += timestep;
+        }
+
+        let outlet_temperature = *pipe.pipe_fluid_array_temperature()?
+            .last().unwrap();
+        samples.push(outlet_temperature.get::<kelvin>());
+    }
+
+    Ok(samples)
+}
+
+/// the insulation thickness of an InsulatedFluidComponent, bound
+/// to calibrate_insulation_thickness, should be recovered from
+/// synthetic outlet temperatures generated by the same component
+/// with a known insulation thickness
+#[test]
+pub fn calibrate_insulated_pipe_insulation_thickness(){
+
+    let sample_times: Vec<f64> = vec![100.0, 200.0, 400.0];
+
+    // synthetic experimental data with 25.4 mm of insulation,
+    // calibrate_insulation_thickness does not change thermal inertia,
+    // so the experiment is built the same way as the model
+    let mut experiment = new_insulated_pipe_at_80_degc(
+        Length::new::<meter>(0.0508));
+    experiment.calibrate_insulation_thickness(Length::new::<meter>(0.0254));
+    let measured_temperatures = run_insulated_pipe_heat_loss_transient(
+        &mut experiment, &sample_times).unwrap();
+
+    // the heat loss should be measurable
+    assert!(measured_temperatures[2] <
+        ThermodynamicTemperature::new::<degree_celsius>(79.9).get::<kelvin>());
+
+    let mut problem = CalibrationProblem::new(
+        new_insulated_pipe_at_80_degc(Length::new::<meter>(0.0508)),
+        move |pipe: &mut InsulatedFluidComponent| {
+            run_insulated_pipe_heat_loss_transient(pipe, &sample_times)
+        });
+
+    problem.add_parameter(TunableParameter::new(
+        "insulation thickness (m)",
+        0.005, 0.1, 0.0508,
+        |pipe: &mut InsulatedFluidComponent, thickness: f64| {
+            pipe.calibrate_insulation_thickness(
+                Length::new::<meter>(thickness));
+            Ok(())
+        }).unwrap());
+
+    for (index, measured_temperature) in measured_temperatures.iter().enumerate() {
+        problem.add_observation(Observation::try_new_with_uncertainty(
+            &format!("outlet temperature sample {}", index),
+            *measured_temperature,
+            0.01).unwrap());
+    }
+
+    let result = problem.try_calibrate(CalibrationSettings::default()).unwrap();
+    approx::assert_relative_eq!(result.fitted_values[0],
+        0.0254, max_relative=1e-3);
+    assert!(result.get_rms_residual() < 1e-2);
+}
@@ -0,0 +1,278 @@
+use uom::si::f64::*;
+
+use crate::scenario::ScenarioPlant;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::FeedbackController;
+
+/// a single control loop, which every timestep reads a probe on the
+/// plant (any node temperature or flowrate), passes it to a
+/// controller together with the set point, and actuates a plant
+/// parameter (heat transfer coefficient to ambient, heater power,
+/// pump pressure, valve opening) with the controller output
+///
+/// probes and actuators are closures, so they can reach into any
+/// component of the plant
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::time::second;
+/// use tuas_boussinesq_solver::controls::{ControlLoop, PIDController};
+///
+/// // a lumped heated tank, C dT/dt = P - hA (T - T_amb)
+/// struct Tank { temperature: f64, heater_power: f64 }
+///
+/// let controller = PIDController::try_new_pi(200.0,
+///     Time::new::<second>(20.0)).unwrap();
+///
+/// let mut temperature_loop = ControlLoop::new("tank_temperature",
+///     320.0,
+///     controller,
+///     |tank: &mut Tank| Ok(tank.temperature),
+///     |tank: &mut Tank, power| { tank.heater_power = power.max(0.0); Ok(()) });
+///
+/// let mut tank = Tank { temperature: 300.0, heater_power: 0.0 };
+/// let timestep = Time::new::<second>(0.5);
+///
+/// for _ in 0..2000 {
+///     temperature_loop.try_update(&mut tank, timestep).unwrap();
+///     tank.temperature += 0.5 * (tank.heater_power
+///         - 10.0 * (tank.temperature - 300.0))/1000.0;
+/// }
+///
+/// approx::assert_abs_diff_eq!(tank.temperature, 320.0, epsilon=0.01);
+/// ```
+pub struct ControlLoop<P> {
+    /// name of the loop, used to look it up in a [ControlSystem]
+    pub name: String,
+    /// set point, in the units of the probe
+    pub set_point: f64,
+    /// when disabled, the actuator is left at its last value
+    pub is_enabled: bool,
+
+    controller: Box<dyn FeedbackController>,
+    probe: Box<dyn Fn(&mut P) -> Result<f64, TuasLibError>>,
+    actuator: Box<dyn Fn(&mut P, f64) -> Result<(), TuasLibError>>,
+    last_measurement: Option<f64>,
+    last_output: Option<f64>,
+}
+
+impl<P> ControlLoop<P> {
+
+    /// creates a new control loop from a controller, a probe which
+    /// measures the plant and an actuator which sets a plant
+    /// parameter to the controller output
+    pub fn new<C, F, G>(name: &str,
+        set_point: f64,
+        controller: C,
+        probe: F,
+        actuator: G) -> Self
+    where C: FeedbackController + 'static,
+    F: Fn(&mut P) -> Result<f64, TuasLibError> + 'static,
+    G: Fn(&mut P, f64) -> Result<(), TuasLibError> + 'static {
+        Self {
+            name: name.to_owned(),
+            set_point,
+            is_enabled: true,
+            controller: Box::new(controller),
+            probe: Box::new(probe),
+            actuator: Box::new(actuator),
+            last_measurement: None,
+            last_output: None,
+        }
+    }
+
+    /// reads the probe, computes the controller output and actuates
+    /// the plant, returns the controller output
+    ///
+    /// returns None without touching the plant if the loop is
+    /// disabled
+    pub fn try_update(&mut self, plant: &mut P, timestep: Time)
+        -> Result<Option<f64>, TuasLibError> {
+
+        if !self.is_enabled {
+            return Ok(None);
+        }
+
+        let measurement = (self.probe)(plant)?;
+        let output = self.controller.try_get_output(
+            self.set_point, measurement, timestep)?;
+        (self.actuator)(plant, output)?;
+
+        self.last_measurement = Some(measurement);
+        self.last_output = Some(output);
+
+        Ok(Some(output))
+    }
+
+    /// measurement read on the last update
+    pub fn get_last_measurement(&self) -> Option<f64> {
+        self.last_measurement
+    }
+
+    /// controller output on the last update
+    pub fn get_last_output(&self) -> Option<f64> {
+        self.last_output
+    }
+
+    /// clears the controller state
+    pub fn reset(&mut self) {
+        self.controller.reset();
+        self.last_measurement = None;
+        self.last_output = None;
+    }
+}
+
+impl<P: ScenarioPlant> ControlLoop<P> {
+
+    /// creates a control loop which reads a named plant output and
+    /// sets a named plant input, using the names of the
+    /// [ScenarioPlant] interface
+    ///
+    /// the names are only checked when the loop is updated
+    pub fn new_for_named_signals<C>(name: &str,
+        set_point: f64,
+        controller: C,
+        output_name: &str,
+        input_name: &str) -> Self
+    where C: FeedbackController + 'static {
+
+        let output_name = output_name.to_owned();
+        let input_name = input_name.to_owned();
+
+        Self::new(name, set_point, controller,
+            move |plant: &mut P| plant.try_get_output(&output_name),
+            move |plant: &mut P, value| plant.try_set_input(&input_name, value))
+    }
+}
+
+impl<P> std::fmt::Debug for ControlLoop<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ControlLoop")
+            .field("name", &self.name)
+            .field("set_point", &self.set_point)
+            .field("is_enabled", &self.is_enabled)
+            .field("last_measurement", &self.last_measurement)
+            .field("last_output", &self.last_output)
+            .finish()
+    }
+}
+
+/// a set of control loops which are updated together, once per
+/// timestep before the plant is advanced
+#[derive(Debug)]
+pub struct ControlSystem<P> {
+    /// control loops, updated in order
+    pub loops: Vec<ControlLoop<P>>,
+}
+
+impl<P> Default for ControlSystem<P> {
+    fn default() -> Self {
+        Self { loops: vec![] }
+    }
+}
+
+impl<P> ControlSystem<P> {
+
+    /// creates an empty control system
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adds a control loop, loop names must be unique
+    pub fn try_add_loop(&mut self, control_loop: ControlLoop<P>)
+        -> Result<(), TuasLibError> {
+
+        if self.loops.iter().any(|existing| existing.name == control_loop.name) {
+            return Err(TuasLibError::GenericStringError(
+                format!("control loop {} already exists", control_loop.name)));
+        }
+
+        self.loops.push(control_loop);
+        Ok(())
+    }
+
+    /// gets a control loop by name, eg. to change its set point
+    pub fn try_get_loop_mut(&mut self, name: &str)
+        -> Result<&mut ControlLoop<P>, TuasLibError> {
+        self.loops.iter_mut()
+            .find(|control_loop| control_loop.name == name)
+            .ok_or(TuasLibError::GenericStringError(
+                format!("no control loop named {}", name)))
+    }
+
+    /// updates every enabled loop in order
+    pub fn try_update(&mut self, plant: &mut P, timestep: Time)
+        -> Result<(), TuasLibError> {
+        for control_loop in self.loops.iter_mut() {
+            control_loop.try_update(plant, timestep)?;
+        }
+        Ok(())
+    }
+}
+
+/// a plant together with its control system, so that the scenario
+/// driver updates the controllers every timestep
+///
+/// the controllers are updated just before the plant is advanced.
+/// Control loop set points appear as extra inputs named
+/// `<loop name>.set_point`, so scenarios can step or ramp them, and
+/// the last controller outputs as extra outputs named
+/// `<loop name>.output`. All other names are passed to the plant
+#[derive(Debug)]
+pub struct ControlledPlant<P> {
+    /// the plant
+    pub plant: P,
+    /// control loops acting on the plant
+    pub controls: ControlSystem<P>,
+}
+
+impl<P> ControlledPlant<P> {
+
+    /// wraps a plant with its control system
+    pub fn new(plant: P, controls: ControlSystem<P>) -> Self {
+        Self { plant, controls }
+    }
+}
+
+impl<P: ScenarioPlant> ScenarioPlant for ControlledPlant<P> {
+
+    fn try_set_input(&mut self, input_name: &str, value: f64)
+        -> Result<(), TuasLibError> {
+        match input_name.strip_suffix(".set_point") {
+            Some(loop_name) => {
+                self.controls.try_get_loop_mut(loop_name)?.set_point = value;
+                Ok(())
+            },
+            None => self.plant.try_set_input(input_name, value),
+        }
+    }
+
+    fn try_get_input(&self, input_name: &str) -> Result<f64, TuasLibError> {
+        match input_name.strip_suffix(".set_point") {
+            Some(loop_name) => self.controls.loops.iter()
+                .find(|control_loop| control_loop.name == loop_name)
+                .map(|control_loop| control_loop.set_point)
+                .ok_or(TuasLibError::GenericStringError(
+                    format!("no control loop named {}", loop_name))),
+            None => self.plant.try_get_input(input_name),
+        }
+    }
+
+    fn try_get_output(&mut self, output_name: &str) -> Result<f64, TuasLibError> {
+        if let Some(loop_name) = output_name.strip_suffix(".set_point") {
+            return self.try_get_input(&format!("{}.set_point", loop_name));
+        }
+        match output_name.strip_suffix(".output") {
+            Some(loop_name) => Ok(self.controls.try_get_loop_mut(loop_name)?
+                .get_last_output()
+                .unwrap_or(f64::NAN)),
+            None => self.plant.try_get_output(output_name),
+        }
+    }
+
+    fn try_advance_timestep(&mut self, timestep: Time) -> Result<(), TuasLibError> {
+        self.controls.try_update(&mut self.plant, timestep)?;
+        self.plant.try_advance_timestep(timestep)
+    }
+}
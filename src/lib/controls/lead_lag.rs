@@ -0,0 +1,121 @@
+use uom::si::f64::*;
+use uom::si::time::second;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::FeedbackController;
+
+/// lead-lag compensator acting on the error e = set point - measurement,
+///
+/// G(s) = K (T_lead s + 1)/(T_lag s + 1)
+///
+/// discretised with backward euler. It has a steady state gain of K,
+/// and an instantaneous gain of K T_lead/T_lag for fast changes
+///
+/// a lead time longer than the lag time gives phase lead (like a
+/// filtered PD controller), a shorter lead time gives phase lag
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeadLagController {
+    /// steady state gain K, output units per measurement unit
+    pub gain: f64,
+    /// lead time constant T_lead
+    pub lead_time: Time,
+    /// lag time constant T_lag
+    pub lag_time: Time,
+    /// output when the error is zero
+    pub output_bias: f64,
+    /// lower limit of the output
+    pub output_minimum: f64,
+    /// upper limit of the output
+    pub output_maximum: f64,
+
+    compensator_output: f64,
+    previous_error: Option<f64>,
+}
+
+impl LeadLagController {
+
+    /// creates a new lead-lag compensator with no output limits
+    ///
+    /// the compensator starts at steady state for the first error
+    /// it sees, so there is no kick on the first timestep
+    pub fn try_new(gain: f64,
+        lead_time: Time,
+        lag_time: Time) -> Result<Self, TuasLibError> {
+
+        if !gain.is_finite() {
+            return Err(TuasLibError::GenericStringError(
+                "controller gain must be finite".to_owned()));
+        }
+
+        if !(lead_time.get::<second>() >= 0.0) || !(lag_time.get::<second>() >= 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                "lead and lag times must not be negative".to_owned()));
+        }
+
+        Ok(Self {
+            gain,
+            lead_time,
+            lag_time,
+            output_bias: 0.0,
+            output_minimum: f64::NEG_INFINITY,
+            output_maximum: f64::INFINITY,
+            compensator_output: 0.0,
+            previous_error: None,
+        })
+    }
+
+    /// sets the output limits
+    pub fn try_set_output_limits(&mut self,
+        output_minimum: f64,
+        output_maximum: f64) -> Result<(), TuasLibError> {
+
+        if !(output_minimum < output_maximum) {
+            return Err(TuasLibError::GenericStringError(
+                "controller output minimum must be less than maximum".to_owned()));
+        }
+
+        self.output_minimum = output_minimum;
+        self.output_maximum = output_maximum;
+
+        Ok(())
+    }
+}
+
+impl FeedbackController for LeadLagController {
+
+    fn try_get_output(&mut self,
+        set_point: f64,
+        measurement: f64,
+        timestep: Time) -> Result<f64, TuasLibError> {
+
+        let dt = timestep.get::<second>();
+        if !(dt > 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                "controller timestep must be positive".to_owned()));
+        }
+
+        let error = set_point - measurement;
+
+        // T_lag dy/dt + y = K (T_lead de/dt + e)
+        self.compensator_output = match self.previous_error {
+            Some(previous_error) => {
+                let lead = self.lead_time.get::<second>();
+                let lag = self.lag_time.get::<second>();
+                (lag * self.compensator_output
+                 + self.gain * (lead * (error - previous_error) + dt * error))
+                    /(lag + dt)
+            },
+            None => self.gain * error,
+        };
+        self.previous_error = Some(error);
+
+        Ok((self.output_bias + self.compensator_output)
+            .clamp(self.output_minimum, self.output_maximum))
+    }
+
+    fn reset(&mut self) {
+        self.compensator_output = 0.0;
+        self.previous_error = None;
+    }
+}
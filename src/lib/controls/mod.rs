@@ -0,0 +1,46 @@
+use uom::si::f64::*;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// PI and PID controllers with anti windup, derivative filtering
+/// and output limits
+pub mod pid_controller;
+pub use pid_controller::*;
+
+/// lead-lag compensators
+pub mod lead_lag;
+pub use lead_lag::*;
+
+/// on/off controllers with hysteresis
+pub mod on_off;
+pub use on_off::*;
+
+/// control loops which read a probe and actuate a plant parameter
+/// every timestep
+pub mod control_loop;
+pub use control_loop::*;
+
+/// a controller which computes its output from a set point and a
+/// measurement once every timestep
+///
+/// set points, measurements and outputs are plain floats in
+/// whatever units the probe and actuator use (SI units by
+/// convention), so the same controller can act on temperatures,
+/// flowrates, heater powers or valve openings
+pub trait FeedbackController {
+
+    /// advances the controller state by one timestep and returns
+    /// its output
+    fn try_get_output(&mut self,
+        set_point: f64,
+        measurement: f64,
+        timestep: Time) -> Result<f64, TuasLibError>;
+
+    /// clears the controller state (integrals, filters and previous
+    /// measurements)
+    fn reset(&mut self);
+}
+
+/// tests for controllers and control loops
+#[cfg(test)]
+pub mod tests;
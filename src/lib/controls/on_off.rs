@@ -0,0 +1,112 @@
+use uom::si::f64::*;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::FeedbackController;
+
+/// whether an on/off controller switches on when the measurement
+/// falls below or rises above its set point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnOffMode {
+    /// eg. a heater thermostat, on when too cold
+    OnBelowSetPoint,
+    /// eg. a cooling fan, on when too hot
+    OnAboveSetPoint,
+}
+
+/// on/off (bang-bang) controller with hysteresis
+///
+/// the controller switches on once the measurement passes half the
+/// deadband beyond the set point, and switches off once it passes
+/// half the deadband on the other side, so it does not chatter
+/// about the set point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OnOffController {
+    /// output when switched on
+    pub on_output: f64,
+    /// output when switched off
+    pub off_output: f64,
+    /// width of the hysteresis band, centred on the set point
+    pub deadband: f64,
+    /// switching direction
+    pub mode: OnOffMode,
+
+    is_on: bool,
+}
+
+impl OnOffController {
+
+    /// creates a new on/off controller, initially switched off
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::time::second;
+    /// use tuas_boussinesq_solver::controls::{FeedbackController,
+    ///     OnOffController, OnOffMode};
+    ///
+    /// // heater thermostat, 2 kW when on, 1 K deadband
+    /// let mut thermostat = OnOffController::try_new(2000.0, 0.0, 1.0,
+    ///     OnOffMode::OnBelowSetPoint).unwrap();
+    /// let timestep = Time::new::<second>(1.0);
+    ///
+    /// assert_eq!(thermostat.try_get_output(350.0, 349.0, timestep).unwrap(), 2000.0);
+    /// // stays on within the deadband
+    /// assert_eq!(thermostat.try_get_output(350.0, 350.4, timestep).unwrap(), 2000.0);
+    /// assert_eq!(thermostat.try_get_output(350.0, 350.6, timestep).unwrap(), 0.0);
+    /// ```
+    pub fn try_new(on_output: f64,
+        off_output: f64,
+        deadband: f64,
+        mode: OnOffMode) -> Result<Self, TuasLibError> {
+
+        if !(deadband >= 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                "on/off controller deadband must not be negative".to_owned()));
+        }
+
+        Ok(Self {
+            on_output,
+            off_output,
+            deadband,
+            mode,
+            is_on: false,
+        })
+    }
+
+    /// whether the controller is switched on
+    pub fn is_on(&self) -> bool {
+        self.is_on
+    }
+}
+
+impl FeedbackController for OnOffController {
+
+    fn try_get_output(&mut self,
+        set_point: f64,
+        measurement: f64,
+        _timestep: Time) -> Result<f64, TuasLibError> {
+
+        // positive when the measurement is on the switch on side
+        let excursion = match self.mode {
+            OnOffMode::OnBelowSetPoint => set_point - measurement,
+            OnOffMode::OnAboveSetPoint => measurement - set_point,
+        };
+
+        let half_deadband = 0.5 * self.deadband;
+
+        if excursion > half_deadband {
+            self.is_on = true;
+        } else if excursion < -half_deadband {
+            self.is_on = false;
+        }
+
+        match self.is_on {
+            true => Ok(self.on_output),
+            false => Ok(self.off_output),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.is_on = false;
+    }
+}
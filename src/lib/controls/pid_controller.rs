@@ -0,0 +1,236 @@
+use uom::si::f64::*;
+use uom::si::time::second;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::FeedbackController;
+
+/// how the integral term is kept from winding up while the output
+/// is saturated at its limits
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AntiWindup {
+    /// the integral term keeps integrating regardless of saturation
+    None,
+    /// the integral term is frozen whenever the output is saturated
+    /// and the error would drive it further into saturation
+    ConditionalIntegration,
+    /// the integral term is driven back towards the saturated output
+    /// with the given tracking time constant
+    BackCalculation(Time),
+}
+
+impl Default for AntiWindup {
+    fn default() -> Self {
+        Self::ConditionalIntegration
+    }
+}
+
+/// PI or PID controller in the ideal (ISA) form,
+///
+/// u = Kc [ e + 1/T_i ∫ e dt + T_d de/dt ]
+///
+/// where e = set point - measurement. The gain Kc is in output units
+/// per measurement unit, a negative gain gives a direct acting
+/// controller (eg. a cooler whose heat transfer coefficient rises
+/// as the temperature rises above set point)
+///
+/// the derivative acts on the measurement rather than the error, so
+/// set point changes do not cause a derivative kick, and it is
+/// filtered with a first order lag of time constant alpha T_d,
+/// as in the filtered PID controller of the
+/// chem-eng-real-time-process-control-simulator crate
+///
+/// the integral and derivative are discretised with backward euler,
+/// so the controller is stable for any timestep
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PIDController {
+    /// controller gain Kc, output units per measurement unit
+    pub gain: f64,
+    /// integral time T_i, None for no integral action
+    pub integral_time: Option<Time>,
+    /// derivative time T_d, None for no derivative action
+    pub derivative_time: Option<Time>,
+    /// ratio of derivative filter time constant to derivative time
+    pub derivative_filter_ratio: f64,
+    /// output when the error is zero and the integral is empty
+    pub output_bias: f64,
+    /// lower limit of the output
+    pub output_minimum: f64,
+    /// upper limit of the output
+    pub output_maximum: f64,
+    /// anti windup scheme for the integral term
+    pub anti_windup: AntiWindup,
+
+    integral_term: f64,
+    derivative_term: f64,
+    previous_measurement: Option<f64>,
+}
+
+impl PIDController {
+
+    /// creates a new PI controller with no output limits
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::time::second;
+    /// use tuas_boussinesq_solver::controls::{FeedbackController, PIDController};
+    ///
+    /// let mut controller = PIDController::try_new_pi(2.0,
+    ///     Time::new::<second>(10.0)).unwrap();
+    ///
+    /// // backward euler integral, u = Kc (e + e dt/T_i)
+    /// let output = controller.try_get_output(1.0, 0.0,
+    ///     Time::new::<second>(1.0)).unwrap();
+    ///
+    /// approx::assert_relative_eq!(output, 2.0 * (1.0 + 0.1));
+    /// ```
+    pub fn try_new_pi(gain: f64, integral_time: Time) -> Result<Self, TuasLibError> {
+        Self::try_new(gain, Some(integral_time), None, 0.1)
+    }
+
+    /// creates a new PID controller with a filtered derivative
+    /// and no output limits
+    pub fn try_new_pid(gain: f64,
+        integral_time: Time,
+        derivative_time: Time,
+        derivative_filter_ratio: f64) -> Result<Self, TuasLibError> {
+        Self::try_new(gain, Some(integral_time), Some(derivative_time),
+            derivative_filter_ratio)
+    }
+
+    /// creates a new controller, integral and derivative actions
+    /// are optional
+    pub fn try_new(gain: f64,
+        integral_time: Option<Time>,
+        derivative_time: Option<Time>,
+        derivative_filter_ratio: f64) -> Result<Self, TuasLibError> {
+
+        if !gain.is_finite() {
+            return Err(TuasLibError::GenericStringError(
+                "controller gain must be finite".to_owned()));
+        }
+
+        if let Some(integral_time) = integral_time {
+            if !(integral_time.get::<second>() > 0.0) {
+                return Err(TuasLibError::GenericStringError(
+                    "controller integral time must be positive".to_owned()));
+            }
+        }
+
+        if let Some(derivative_time) = derivative_time {
+            if !(derivative_time.get::<second>() >= 0.0) {
+                return Err(TuasLibError::GenericStringError(
+                    "controller derivative time must not be negative".to_owned()));
+            }
+        }
+
+        if !(derivative_filter_ratio >= 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                "derivative filter ratio must not be negative".to_owned()));
+        }
+
+        Ok(Self {
+            gain,
+            integral_time,
+            derivative_time,
+            derivative_filter_ratio,
+            output_bias: 0.0,
+            output_minimum: f64::NEG_INFINITY,
+            output_maximum: f64::INFINITY,
+            anti_windup: AntiWindup::default(),
+            integral_term: 0.0,
+            derivative_term: 0.0,
+            previous_measurement: None,
+        })
+    }
+
+    /// sets the output limits
+    pub fn try_set_output_limits(&mut self,
+        output_minimum: f64,
+        output_maximum: f64) -> Result<(), TuasLibError> {
+
+        if !(output_minimum < output_maximum) {
+            return Err(TuasLibError::GenericStringError(
+                "controller output minimum must be less than maximum".to_owned()));
+        }
+
+        self.output_minimum = output_minimum;
+        self.output_maximum = output_maximum;
+
+        Ok(())
+    }
+
+    /// current value of the integral term, in output units
+    pub fn get_integral_term(&self) -> f64 {
+        self.integral_term
+    }
+}
+
+impl FeedbackController for PIDController {
+
+    fn try_get_output(&mut self,
+        set_point: f64,
+        measurement: f64,
+        timestep: Time) -> Result<f64, TuasLibError> {
+
+        let dt = timestep.get::<second>();
+        if !(dt > 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                "controller timestep must be positive".to_owned()));
+        }
+
+        let error = set_point - measurement;
+        let proportional_term = self.gain * error;
+
+        // filtered derivative on measurement,
+        // T_f dD/dt + D = - Kc T_d dy/dt
+        if let Some(derivative_time) = self.derivative_time {
+            let td = derivative_time.get::<second>();
+            let filter_time = self.derivative_filter_ratio * td;
+            let measurement_change = match self.previous_measurement {
+                Some(previous_measurement) => measurement - previous_measurement,
+                None => 0.0,
+            };
+            self.derivative_term = (filter_time * self.derivative_term
+                - self.gain * td * measurement_change)/(filter_time + dt);
+        }
+        self.previous_measurement = Some(measurement);
+
+        let integral_increment = match self.integral_time {
+            Some(integral_time) => self.gain * error * dt / integral_time.get::<second>(),
+            None => 0.0,
+        };
+
+        let trial_integral_term = self.integral_term + integral_increment;
+        let unsaturated_output = self.output_bias + proportional_term
+            + trial_integral_term + self.derivative_term;
+        let output = unsaturated_output.clamp(self.output_minimum, self.output_maximum);
+
+        self.integral_term = match self.anti_windup {
+            AntiWindup::None => trial_integral_term,
+            AntiWindup::ConditionalIntegration => {
+                let winding_up = (unsaturated_output > self.output_maximum
+                    && integral_increment > 0.0)
+                    || (unsaturated_output < self.output_minimum
+                    && integral_increment < 0.0);
+                if winding_up {
+                    self.integral_term
+                } else {
+                    trial_integral_term
+                }
+            },
+            AntiWindup::BackCalculation(tracking_time) => {
+                trial_integral_term + dt/tracking_time.get::<second>()
+                    * (output - unsaturated_output)
+            },
+        };
+
+        Ok(output)
+    }
+
+    fn reset(&mut self) {
+        self.integral_term = 0.0;
+        self.derivative_term = 0.0;
+        self.previous_measurement = None;
+    }
+}
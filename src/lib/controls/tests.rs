@@ -0,0 +1,262 @@
+use uom::si::f64::*;
+use uom::si::time::second;
+
+use crate::scenario::scenario_parser::try_parse_scenario;
+use crate::scenario::test_plants::HeatedTank;
+use crate::scenario::ScenarioPlant;
+
+use super::*;
+
+/// runs a heater power control loop on the tank and returns the
+/// temperature history
+fn run_heater_control_loop(controller: PIDController,
+    set_point: f64,
+    number_of_timesteps: usize) -> Vec<f64> {
+
+    let mut control_loop = ControlLoop::new("tank_temperature",
+        set_point,
+        controller,
+        |tank: &mut HeatedTank| Ok(tank.temperature_kelvin),
+        |tank: &mut HeatedTank, power| {
+            tank.heater_power_watts = power;
+            Ok(())
+        });
+
+    let mut tank = HeatedTank::default();
+    let timestep = Time::new::<second>(0.5);
+    let mut temperatures = vec![];
+
+    for _ in 0..number_of_timesteps {
+        control_loop.try_update(&mut tank, timestep).unwrap();
+        tank.advance(timestep);
+        temperatures.push(tank.temperature_kelvin);
+    }
+
+    temperatures
+}
+
+/// a PI controller on the heater brings the tank to its set point
+/// with no steady state offset, whereas a P only controller leaves
+/// the offset 10 W/K dT/(Kc) predicted by the steady state balance
+#[test]
+pub fn pi_controller_removes_steady_state_offset(){
+
+    let pi_controller = PIDController::try_new_pi(200.0,
+        Time::new::<second>(20.0)).unwrap();
+    let temperatures = run_heater_control_loop(pi_controller, 320.0, 2000);
+    approx::assert_abs_diff_eq!(*temperatures.last().unwrap(), 320.0, epsilon=1e-3);
+
+    // P only, Kc (320 - T) = 10 (T - 300), so T = 300 + 20 Kc/(Kc + 10)
+    let p_controller = PIDController::try_new(200.0, None, None, 0.1).unwrap();
+    let temperatures = run_heater_control_loop(p_controller, 320.0, 2000);
+    approx::assert_relative_eq!(*temperatures.last().unwrap(),
+        300.0 + 20.0 * 200.0/210.0, max_relative=1e-6);
+}
+
+/// with the heater power limited, the integral term winds up during
+/// the long saturated warm up unless anti windup is used, and the
+/// tank then overshoots its set point
+#[test]
+pub fn anti_windup_reduces_overshoot(){
+
+    let set_point = 340.0;
+    let get_overshoot = |anti_windup: AntiWindup| {
+        let mut controller = PIDController::try_new_pi(200.0,
+            Time::new::<second>(20.0)).unwrap();
+        controller.try_set_output_limits(0.0, 1500.0).unwrap();
+        controller.anti_windup = anti_windup;
+
+        let temperatures = run_heater_control_loop(controller, set_point, 4000);
+        approx::assert_abs_diff_eq!(*temperatures.last().unwrap(),
+            set_point, epsilon=1e-2);
+
+        temperatures.iter().cloned().fold(f64::NEG_INFINITY, f64::max) - set_point
+    };
+
+    let no_anti_windup_overshoot = get_overshoot(AntiWindup::None);
+    let conditional_integration_overshoot =
+        get_overshoot(AntiWindup::ConditionalIntegration);
+    let back_calculation_overshoot =
+        get_overshoot(AntiWindup::BackCalculation(Time::new::<second>(5.0)));
+
+    dbg!(&(no_anti_windup_overshoot,
+        conditional_integration_overshoot,
+        back_calculation_overshoot));
+
+    assert!(no_anti_windup_overshoot > 1.0);
+    assert!(conditional_integration_overshoot < 0.2 * no_anti_windup_overshoot);
+    assert!(back_calculation_overshoot < 0.2 * no_anti_windup_overshoot);
+}
+
+/// the filtered derivative responds to a unit step in measurement
+/// with -Kc T_d/(alpha T_d + dt), then decays with time constant
+/// alpha T_d, and set point steps cause no derivative kick
+#[test]
+pub fn filtered_derivative_response(){
+
+    let gain = 2.0;
+    let derivative_time = Time::new::<second>(4.0);
+    let timestep = Time::new::<second>(1.0);
+
+    let mut controller = PIDController::try_new(gain, None,
+        Some(derivative_time), 0.25).unwrap();
+
+    // first call only stores the measurement
+    assert_eq!(controller.try_get_output(0.0, 0.0, timestep).unwrap(), 0.0);
+
+    // P = -2, D = -2 * 4/(1 + 1) = -4
+    let output = controller.try_get_output(0.0, 1.0, timestep).unwrap();
+    approx::assert_relative_eq!(output, -2.0 - 4.0, max_relative=1e-12);
+
+    // D decays as 1/(1 + dt/(alpha T_d)) = 1/2 per timestep
+    let output = controller.try_get_output(0.0, 1.0, timestep).unwrap();
+    approx::assert_relative_eq!(output, -2.0 - 2.0, max_relative=1e-12);
+
+    // set point step, proportional kick only
+    let output = controller.try_get_output(1.0, 1.0, timestep).unwrap();
+    approx::assert_relative_eq!(output, -1.0, max_relative=1e-12);
+
+    // an unfiltered derivative gives -Kc T_d/dt
+    let mut unfiltered_controller = PIDController::try_new(gain, None,
+        Some(derivative_time), 0.0).unwrap();
+    unfiltered_controller.try_get_output(0.0, 0.0, timestep).unwrap();
+    let output = unfiltered_controller.try_get_output(0.0, 1.0, timestep).unwrap();
+    approx::assert_relative_eq!(output, -2.0 - 8.0, max_relative=1e-12);
+}
+
+/// a lead-lag compensator has a steady state gain of K and, after a
+/// step in error, an initial jump of K (T_lead + dt)/(T_lag + dt)
+#[test]
+pub fn lead_lag_step_response(){
+
+    let timestep = Time::new::<second>(0.1);
+    let mut compensator = LeadLagController::try_new(3.0,
+        Time::new::<second>(10.0),
+        Time::new::<second>(1.0)).unwrap();
+
+    assert_eq!(compensator.try_get_output(0.0, 0.0, timestep).unwrap(), 0.0);
+
+    let initial_output = compensator.try_get_output(1.0, 0.0, timestep).unwrap();
+    approx::assert_relative_eq!(initial_output, 3.0 * 10.1/1.1, max_relative=1e-12);
+
+    let mut output = initial_output;
+    for _ in 0..1000 {
+        output = compensator.try_get_output(1.0, 0.0, timestep).unwrap();
+    }
+    approx::assert_relative_eq!(output, 3.0, max_relative=1e-9);
+
+    compensator.try_set_output_limits(-1.0, 1.0).unwrap();
+    assert_eq!(compensator.try_get_output(1.0, 0.0, timestep).unwrap(), 1.0);
+}
+
+/// an on/off heater keeps the tank temperature cycling within a
+/// band around its set point, switching only at the deadband edges
+#[test]
+pub fn on_off_controller_cycles_within_deadband(){
+
+    let thermostat = OnOffController::try_new(2000.0, 0.0, 2.0,
+        OnOffMode::OnBelowSetPoint).unwrap();
+
+    let mut control_loop = ControlLoop::new("thermostat",
+        330.0,
+        thermostat,
+        |tank: &mut HeatedTank| Ok(tank.temperature_kelvin),
+        |tank: &mut HeatedTank, power| {
+            tank.heater_power_watts = power;
+            Ok(())
+        });
+
+    let mut tank = HeatedTank::default();
+    let timestep = Time::new::<second>(0.1);
+    let mut number_of_switches = 0;
+    let mut previous_power = 0.0;
+
+    for timestep_number in 0..20000 {
+        control_loop.try_update(&mut tank, timestep).unwrap();
+        if tank.heater_power_watts != previous_power {
+            number_of_switches += 1;
+            previous_power = tank.heater_power_watts;
+        }
+        tank.advance(timestep);
+
+        // after the warm up, stay within the deadband plus one
+        // timestep of heating
+        if timestep_number > 1000 {
+            assert!(tank.temperature_kelvin > 329.0 - 0.05);
+            assert!(tank.temperature_kelvin < 331.0 + 0.05);
+        }
+    }
+
+    // heats at about 1.7 K/s and cools at about 0.3 K/s,
+    // so each cycle is about 8 s long
+    assert!(number_of_switches > 400);
+    assert!(number_of_switches < 600);
+}
+
+/// a cooler control loop acting through the scenario plant names is
+/// updated by the scenario driver, and its set point can be stepped
+/// by the scenario
+#[test]
+pub fn controlled_plant_driven_by_scenario(){
+
+    // cooler heat loss coefficient rises as the tank gets hotter,
+    // so the gain is negative
+    let mut cooler_controller = PIDController::try_new_pid(-20.0,
+        Time::new::<second>(10.0),
+        Time::new::<second>(1.0),
+        0.1).unwrap();
+    cooler_controller.try_set_output_limits(0.0, 200.0).unwrap();
+
+    let mut controls = ControlSystem::new();
+    controls.try_add_loop(ControlLoop::new_for_named_signals(
+        "cooler", 340.0, cooler_controller,
+        "temperature", "cooler_heat_loss_coefficient")).unwrap();
+
+    // duplicate names are rejected
+    let duplicate_loop = ControlLoop::new_for_named_signals(
+        "cooler", 340.0, OnOffController::try_new(1.0, 0.0, 0.0,
+            OnOffMode::OnAboveSetPoint).unwrap(),
+        "temperature", "cooler_heat_loss_coefficient");
+    assert!(controls.try_add_loop(duplicate_loop).is_err());
+
+    let mut plant = ControlledPlant::new(HeatedTank::default(), controls);
+
+    let scenario = try_parse_scenario("
+        timestep 0.5 s
+        end_time 1000 s
+        record temperature
+        record cooler.output
+        at 0 s step heater_power 2 kW
+        at 500 s step cooler.set_point 330 K
+    ").unwrap();
+
+    let record = scenario.try_run(&mut plant).unwrap();
+    let temperature = record.try_get_output_history("temperature").unwrap();
+    let cooler_output = record.try_get_output_history("cooler.output").unwrap();
+
+    // at steady state, 2000 W = (10 + h_cooler) (T - 300 K)
+    approx::assert_abs_diff_eq!(temperature[999], 340.0, epsilon=1e-2);
+    approx::assert_relative_eq!(cooler_output[999], 2000.0/40.0 - 10.0,
+        max_relative=1e-3);
+
+    approx::assert_abs_diff_eq!(*temperature.last().unwrap(), 330.0, epsilon=1e-2);
+    approx::assert_relative_eq!(plant.try_get_input("cooler.set_point").unwrap(), 330.0);
+    assert!(plant.try_get_input("heater.set_point").is_err());
+}
+
+/// invalid controller settings are rejected
+#[test]
+pub fn invalid_controller_settings(){
+
+    let one_second = Time::new::<second>(1.0);
+
+    assert!(PIDController::try_new_pi(f64::NAN, one_second).is_err());
+    assert!(PIDController::try_new_pi(1.0, Time::new::<second>(0.0)).is_err());
+    assert!(PIDController::try_new_pid(1.0, one_second, one_second, -1.0).is_err());
+    assert!(LeadLagController::try_new(1.0, Time::new::<second>(-1.0), one_second).is_err());
+    assert!(OnOffController::try_new(1.0, 0.0, -1.0, OnOffMode::OnAboveSetPoint).is_err());
+
+    let mut controller = PIDController::try_new_pi(1.0, one_second).unwrap();
+    assert!(controller.try_set_output_limits(1.0, 0.0).is_err());
+    assert!(controller.try_get_output(0.0, 0.0, Time::new::<second>(0.0)).is_err());
+}
@@ -0,0 +1,176 @@
+use ndarray::Array1;
+use uom::si::f64::*;
+use uom::si::power::watt;
+
+use crate::single_control_vol::SingleCVNode;
+
+/// where a contribution to the rate of enthalpy change of a
+/// control volume comes from
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum EnergyTallySource {
+    /// heat added by a heater or other power source,
+    /// eg. constant heat addition BCs or lateral power sources
+    HeatSource,
+    /// heat transfer to or from a constant temperature or
+    /// heat flux boundary condition, eg. ambient air
+    BoundaryHeatTransfer,
+    /// enthalpy advected to or from a boundary condition,
+    /// eg. an inlet or outlet
+    BoundaryAdvection,
+    /// heat transfer or advection to or from another control
+    /// volume, including laterally linked temperature arrays
+    ExchangeWithEntities,
+}
+
+/// contributions to the rate of enthalpy change of a control
+/// volume, summed by source
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct EnergyTallyRates {
+    /// heat added by sources
+    pub heat_source: Power,
+    /// heat transferred in from boundary conditions,
+    /// negative if heat is lost to ambient
+    pub boundary_heat_transfer: Power,
+    /// enthalpy advected in from boundary conditions, inflow
+    /// minus outflow
+    pub boundary_advection: Power,
+    /// heat and enthalpy received from other control volumes
+    pub exchange_with_entities: Power,
+    /// contributions applied to the control volume whose source
+    /// was not tallied, eg. pushed directly into the
+    /// rate_enthalpy_change_vector
+    pub untallied: Power,
+}
+
+impl Default for EnergyTallyRates {
+    fn default() -> Self {
+        let zero_power = Power::new::<watt>(0.0);
+        Self {
+            heat_source: zero_power,
+            boundary_heat_transfer: zero_power,
+            boundary_advection: zero_power,
+            exchange_with_entities: zero_power,
+            untallied: zero_power,
+        }
+    }
+}
+
+impl EnergyTallyRates {
+
+    /// adds a contribution from the given source
+    pub fn add(&mut self, source: EnergyTallySource, rate: Power) {
+        match source {
+            EnergyTallySource::HeatSource => self.heat_source += rate,
+            EnergyTallySource::BoundaryHeatTransfer =>
+                self.boundary_heat_transfer += rate,
+            EnergyTallySource::BoundaryAdvection =>
+                self.boundary_advection += rate,
+            EnergyTallySource::ExchangeWithEntities =>
+                self.exchange_with_entities += rate,
+        }
+    }
+
+    /// adds every contribution of another set of rates
+    pub fn add_rates(&mut self, other: &EnergyTallyRates) {
+        self.heat_source += other.heat_source;
+        self.boundary_heat_transfer += other.boundary_heat_transfer;
+        self.boundary_advection += other.boundary_advection;
+        self.exchange_with_entities += other.exchange_with_entities;
+        self.untallied += other.untallied;
+    }
+
+    /// sum of all contributions
+    pub fn get_total(&self) -> Power {
+        self.heat_source
+            + self.boundary_heat_transfer
+            + self.boundary_advection
+            + self.exchange_with_entities
+            + self.untallied
+    }
+}
+
+/// optional per control volume tally of the contributions to its
+/// rate of enthalpy change, kept by the solver itself
+///
+/// contributions are added to the pending rates as interactions
+/// are calculated. When the control volume is advanced, the
+/// pending rates are moved to the rates of the last timestep
+/// before the vectors are cleared, with anything applied but not
+/// tallied counted as untallied. The energy audit reads the
+/// rates of the last timestep
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub struct EnergyTally {
+    /// contributions calculated since the last timestep
+    pub pending: EnergyTallyRates,
+    /// contributions applied in the last timestep
+    pub last_timestep: EnergyTallyRates,
+}
+
+impl EnergyTally {
+
+    /// moves the pending contributions to the last timestep,
+    /// given the total rate of enthalpy change actually applied
+    /// by the solver
+    pub fn finish_timestep(&mut self, total_applied: Power) {
+        let mut applied = self.pending;
+        applied.untallied += total_applied - self.pending.get_total();
+        self.last_timestep = applied;
+        self.pending = EnergyTallyRates::default();
+    }
+}
+
+/// contributions applied to an array control volume (FluidArray or 
+/// SolidColumn) over a timestep, summed over all nodes
+///
+/// the back and front single cvs carry the axial contributions,
+/// the lateral power sources are heat sources, and the lateral 
+/// conductances are exchanges with adjacent arrays, evaluated at 
+/// the new node temperatures as in the implicit solve
+pub(crate) fn get_array_energy_tally_rates(
+    back_single_cv: &SingleCVNode,
+    front_single_cv: &SingleCVNode,
+    q_vector: &[Power],
+    q_fraction_vector: &[Array1<f64>],
+    lateral_adjacent_array_conductance_vector: &[Array1<ThermalConductance>],
+    lateral_adjacent_array_temperature_vector: &[Array1<ThermodynamicTemperature>],
+    new_temperature_array: &Array1<ThermodynamicTemperature>) 
+    -> EnergyTallyRates {
+
+    let mut rates = EnergyTallyRates::default();
+
+    for single_cv in [back_single_cv, front_single_cv] {
+
+        let total_applied: Power = single_cv.rate_enthalpy_change_vector
+            .iter()
+            .fold(Power::new::<watt>(0.0), |sum, rate| sum + *rate);
+
+        let pending = single_cv.energy_tally
+            .map(|energy_tally| energy_tally.pending)
+            .unwrap_or_default();
+
+        rates.add_rates(&pending);
+        rates.untallied += total_applied - pending.get_total();
+    }
+
+    for (power_source, q_fraction_arr) in q_vector.iter()
+        .zip(q_fraction_vector.iter()) {
+        rates.heat_source += *power_source * q_fraction_arr.sum();
+    }
+
+    for (conductance_arr, temperature_arr) in 
+        lateral_adjacent_array_conductance_vector.iter()
+        .zip(lateral_adjacent_array_temperature_vector.iter()) {
+
+        for ((conductance, lateral_temperature), node_temperature) in 
+            conductance_arr.iter()
+            .zip(temperature_arr.iter())
+            .zip(new_temperature_array.iter()) {
+
+            rates.exchange_with_entities += 
+                *conductance * *lateral_temperature 
+                - *conductance * *node_temperature;
+        }
+    }
+
+    rates
+}
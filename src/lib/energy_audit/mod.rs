@@ -0,0 +1,297 @@
+use uom::si::f64::*;
+use uom::si::energy::joule;
+use uom::si::power::watt;
+use uom::si::ratio::ratio;
+
+use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+use crate::tuas_lib_error::TuasLibError;
+
+use self::energy_tally::EnergyTallyRates;
+
+/// energy accounting for one timestep
+#[derive(Debug,Clone,PartialEq)]
+pub struct EnergyAuditRecord {
+    /// timestep size
+    pub timestep: Time,
+    /// total heat added by sources (eg. heaters),
+    /// tallied by the solver and recorded
+    pub heat_added: Power,
+    /// total heat lost to ambient boundary conditions,
+    /// tallied by the solver and recorded
+    pub heat_lost_to_ambient: Power,
+    /// net enthalpy advected into the audited entities,
+    /// inflow minus outflow, tallied by the solver and recorded
+    pub net_advected_enthalpy: Power,
+    /// net heat and enthalpy the audited entities report receiving 
+    /// from other control volumes
+    ///
+    /// if every control volume linked to the audited entities is
+    /// audited, this should be close to zero, as what one entity 
+    /// receives another loses. Otherwise, a link was only applied 
+    /// to one side
+    pub net_exchange_between_entities: Power,
+    /// contributions the solver applied to the audited entities 
+    /// without tallying their source
+    pub untallied: Power,
+    /// change in stored enthalpy of each audited entity over
+    /// the timestep, in the order the entities were named
+    ///
+    /// node masses are frozen at the start of the timestep,
+    /// consistent with the Boussinesq approximation used within
+    /// the control volumes, so density changes do not show up
+    /// as stored enthalpy changes
+    pub stored_enthalpy_change_by_entity: Vec<(String, Energy)>,
+    /// total change in stored enthalpy
+    pub stored_enthalpy_change: Energy,
+    /// stored enthalpy change minus net energy input,
+    ///
+    /// dE - (Q_added - Q_lost + H_in - H_out) dt
+    ///
+    /// this should be close to zero if energy is conserved
+    pub imbalance: Energy,
+}
+
+/// tracks heat added by sources, heat lost to ambient,
+/// enthalpy advected across the boundaries of a set of
+/// HeatTransferEntities and the change in their stored enthalpy,
+/// per timestep and cumulatively, and reports the imbalance
+///
+/// usage every timestep:
+///
+/// 1. try_begin_timestep with the audited entities, before
+///    advancing them
+/// 2. record heat sources, heat losses and advection across the
+///    boundary of the audited entities which the solver does not 
+///    tally (advection between audited entities is internal and 
+///    should not be recorded)
+/// 3. advance the entities
+/// 4. try_end_timestep with the same entities in the same order
+///
+/// entities with an energy tally (see try_enable_energy_tally)
+/// report the heat sources, boundary condition heat transfer and 
+/// advection the solver applied to them, and these are read at 
+/// try_end_timestep, so they should not be recorded as well. 
+/// Exchanges with other control volumes are not counted as energy 
+/// input, so heat received by one entity which was not taken 
+/// from another shows up as an imbalance. Laterally linked 
+/// temperature arrays count as other control volumes, even if 
+/// they are ambient temperatures, so such heat losses still need 
+/// recording
+///
+/// this is meant to catch linkage bugs, for example, where heat
+/// is added to two nodes when it should only be added to one,
+/// or where advection between a SingleCVNode and a FluidArray
+/// is not linked in both directions
+#[derive(Debug,Clone,PartialEq,Default)]
+pub struct EnergyAudit {
+    /// names of the audited entities
+    pub entity_names: Vec<String>,
+    /// records for every completed timestep
+    pub records: Vec<EnergyAuditRecord>,
+    mass_vectors_at_timestep_start: Vec<Vec<Mass>>,
+    specific_enthalpy_vectors_at_timestep_start: Vec<Vec<AvailableEnergy>>,
+    heat_added: Power,
+    heat_lost_to_ambient: Power,
+    net_advected_enthalpy: Power,
+}
+
+impl EnergyAudit {
+
+    /// creates an energy audit for entities with the given names
+    pub fn new(entity_names: Vec<&str>) -> Self {
+        Self {
+            entity_names: entity_names.iter()
+                .map(|name| name.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn check_number_of_entities(&self,
+        entities: &[&HeatTransferEntity]) -> Result<(), TuasLibError> {
+
+        if entities.len() != self.entity_names.len() {
+            return Err(TuasLibError::GenericStringError(
+                format!("energy audit expects {} entities, got {}",
+                    self.entity_names.len(), entities.len())));
+        }
+
+        Ok(())
+    }
+
+    /// records the node masses and specific enthalpies of the
+    /// audited entities at the start of the timestep, and resets
+    /// heat source, heat loss and advection tallies
+    pub fn try_begin_timestep(&mut self,
+        entities: &[&HeatTransferEntity]) -> Result<(), TuasLibError> {
+
+        self.check_number_of_entities(entities)?;
+
+        self.mass_vectors_at_timestep_start = entities.iter()
+            .map(|entity| entity.try_get_mass_vector())
+            .collect::<Result<_, _>>()?;
+        self.specific_enthalpy_vectors_at_timestep_start = entities.iter()
+            .map(|entity| entity.try_get_specific_enthalpy_vector())
+            .collect::<Result<_, _>>()?;
+
+        self.heat_added = Power::new::<watt>(0.0);
+        self.heat_lost_to_ambient = Power::new::<watt>(0.0);
+        self.net_advected_enthalpy = Power::new::<watt>(0.0);
+
+        Ok(())
+    }
+
+    /// records heat added by a source during this timestep
+    pub fn record_heat_added(&mut self, heat_added: Power) {
+        self.heat_added += heat_added;
+    }
+
+    /// records heat lost to ambient during this timestep
+    pub fn record_heat_lost_to_ambient(&mut self, heat_lost: Power) {
+        self.heat_lost_to_ambient += heat_lost;
+    }
+
+    /// records enthalpy advected into the audited entities,
+    /// m h_in
+    pub fn record_enthalpy_inflow(&mut self, mass_flowrate: MassRate,
+        specific_enthalpy: AvailableEnergy) {
+        self.net_advected_enthalpy += mass_flowrate * specific_enthalpy;
+    }
+
+    /// records enthalpy advected out of the audited entities,
+    /// m h_out
+    pub fn record_enthalpy_outflow(&mut self, mass_flowrate: MassRate,
+        specific_enthalpy: AvailableEnergy) {
+        self.net_advected_enthalpy -= mass_flowrate * specific_enthalpy;
+    }
+
+    /// computes the change in stored enthalpy of the audited
+    /// entities after they have been advanced, and stores the
+    /// energy balance for this timestep
+    pub fn try_end_timestep(&mut self, timestep: Time,
+        entities: &[&HeatTransferEntity])
+        -> Result<EnergyAuditRecord, TuasLibError> {
+
+        if self.mass_vectors_at_timestep_start.is_empty() {
+            return Err(TuasLibError::GenericStringError(
+                "energy audit: try_begin_timestep must be called \
+                before try_end_timestep".to_owned()));
+        }
+
+        self.check_number_of_entities(entities)?;
+
+        // contributions reported by the solver over the timestep
+        let mut tallied_rates = EnergyTallyRates::default();
+
+        for entity in entities.iter() {
+            if let Some(energy_tally) = entity.try_get_energy_tally()? {
+                tallied_rates.add_rates(&energy_tally.last_timestep);
+            }
+        }
+
+        let heat_added = self.heat_added + tallied_rates.heat_source;
+        let heat_lost_to_ambient = self.heat_lost_to_ambient 
+            - tallied_rates.boundary_heat_transfer;
+        let net_advected_enthalpy = self.net_advected_enthalpy 
+            + tallied_rates.boundary_advection;
+
+        let mut stored_enthalpy_change_by_entity: Vec<(String, Energy)> = vec![];
+
+        for (entity_index, entity) in entities.iter().enumerate() {
+
+            let specific_enthalpy_vector_at_timestep_end =
+                entity.try_get_specific_enthalpy_vector()?;
+
+            let stored_enthalpy_change: Energy =
+                self.mass_vectors_at_timestep_start[entity_index].iter()
+                .zip(self.specific_enthalpy_vectors_at_timestep_start[entity_index].iter())
+                .zip(specific_enthalpy_vector_at_timestep_end.iter())
+                .map(|((mass, h_start), h_end)| *mass * (*h_end - *h_start))
+                .sum();
+
+            stored_enthalpy_change_by_entity.push(
+                (self.entity_names[entity_index].clone(), stored_enthalpy_change));
+        }
+
+        let stored_enthalpy_change: Energy = stored_enthalpy_change_by_entity
+            .iter()
+            .fold(Energy::new::<joule>(0.0), |sum, (_, change)| sum + *change);
+
+        let net_energy_input: Energy = (heat_added
+            - heat_lost_to_ambient
+            + net_advected_enthalpy) * timestep;
+
+        let record = EnergyAuditRecord {
+            timestep,
+            heat_added,
+            heat_lost_to_ambient,
+            net_advected_enthalpy,
+            net_exchange_between_entities: tallied_rates.exchange_with_entities,
+            untallied: tallied_rates.untallied,
+            stored_enthalpy_change_by_entity,
+            stored_enthalpy_change,
+            imbalance: stored_enthalpy_change - net_energy_input,
+        };
+
+        self.records.push(record.clone());
+        self.mass_vectors_at_timestep_start.clear();
+        self.specific_enthalpy_vectors_at_timestep_start.clear();
+
+        Ok(record)
+    }
+
+    /// total heat added by sources over all timesteps
+    pub fn get_cumulative_heat_added(&self) -> Energy {
+        self.records.iter().fold(Energy::new::<joule>(0.0),
+            |sum, record| sum + record.heat_added * record.timestep)
+    }
+
+    /// total heat lost to ambient over all timesteps
+    pub fn get_cumulative_heat_lost_to_ambient(&self) -> Energy {
+        self.records.iter().fold(Energy::new::<joule>(0.0),
+            |sum, record| sum + record.heat_lost_to_ambient * record.timestep)
+    }
+
+    /// total net enthalpy advected in over all timesteps
+    pub fn get_cumulative_net_advected_enthalpy(&self) -> Energy {
+        self.records.iter().fold(Energy::new::<joule>(0.0),
+            |sum, record| sum + record.net_advected_enthalpy * record.timestep)
+    }
+
+    /// total change in stored enthalpy over all timesteps
+    pub fn get_cumulative_stored_enthalpy_change(&self) -> Energy {
+        self.records.iter().fold(Energy::new::<joule>(0.0),
+            |sum, record| sum + record.stored_enthalpy_change)
+    }
+
+    /// total imbalance over all timesteps
+    pub fn get_cumulative_imbalance(&self) -> Energy {
+        self.records.iter().fold(Energy::new::<joule>(0.0),
+            |sum, record| sum + record.imbalance)
+    }
+
+    /// cumulative imbalance relative to the cumulative energy which
+    /// crossed the boundary of the audited entities
+    /// (heat added, heat lost and advected enthalpy in absolute value)
+    pub fn get_cumulative_relative_imbalance(&self) -> Ratio {
+        let energy_throughput: Energy = self.records.iter()
+            .fold(Energy::new::<joule>(0.0), |sum, record| {
+                sum + (record.heat_added.abs()
+                    + record.heat_lost_to_ambient.abs()
+                    + record.net_advected_enthalpy.abs()) * record.timestep
+            });
+
+        if energy_throughput.get::<joule>() == 0.0 {
+            return Ratio::new::<ratio>(0.0);
+        }
+
+        (self.get_cumulative_imbalance() / energy_throughput).abs()
+    }
+}
+
+/// per control volume tallies of the heat sources, boundary 
+/// condition heat transfer and advection applied by the solver
+pub mod energy_tally;
+
+/// tests for the energy audit
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,333 @@
+use super::*;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::boundary_conditions::BCType;
+use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+use crate::boussinesq_thermophysical_properties::specific_enthalpy::try_get_h;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+use crate::single_control_vol::SingleCVNode;
+use ndarray::Array1;
+use uom::si::angle::degree;
+use uom::si::length::meter;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::pressure::atmosphere;
+use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::time::second;
+
+/// runs a heated therminol channel fed by a constant temperature
+/// inlet for a number of timesteps, auditing the channel
+///
+/// the heater power is linked to the channel the given number
+/// of times per timestep, but only recorded once in the audit
+fn audit_heated_therminol_channel(number_of_timesteps: usize,
+    power_links_per_timestep: usize) -> EnergyAudit {
+
+    let therminol = LiquidMaterial::TherminolVP1;
+    let inner_nodes: usize = 3;
+    let number_of_nodes = inner_nodes + 2;
+    let pressure = Pressure::new::<atmosphere>(1.0);
+    let inlet_temperature = ThermodynamicTemperature::new::<degree_celsius>(80.0);
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.02);
+    let heater_power = Power::new::<watt>(1000.0);
+
+    let mut channel: HeatTransferEntity = FluidArray::new_cylinder(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.02),
+        inlet_temperature,
+        pressure,
+        SolidMaterial::SteelSS304L,
+        therminol,
+        Ratio::new::<ratio>(0.0),
+        inner_nodes,
+        Angle::new::<degree>(0.0),
+    ).into();
+
+    let mut inlet_bc: HeatTransferEntity = BCType::new_const_temperature(
+        inlet_temperature).into();
+    let mut outlet_bc: HeatTransferEntity = BCType::new_adiabatic_bc().into();
+
+    let timestep = Time::new::<second>(0.5);
+    let mut audit = EnergyAudit::new(vec!["heated channel"]);
+
+    for _ in 0..number_of_timesteps {
+
+        audit.try_begin_timestep(&[&channel]).unwrap();
+
+        let temperature_vector = channel.get_temperature_vector().unwrap();
+        let back_cv_temperature = temperature_vector[0];
+        let front_cv_temperature = *temperature_vector.iter().last().unwrap();
+
+        let inlet_interaction = HeatTransferInteractionType::
+            new_advection_interaction(
+                mass_flowrate,
+                therminol.try_get_density(inlet_temperature).unwrap(),
+                therminol.try_get_density(back_cv_temperature).unwrap());
+
+        let front_cv_density = therminol.try_get_density(front_cv_temperature)
+            .unwrap();
+        let outlet_interaction = HeatTransferInteractionType::
+            new_advection_interaction(
+                mass_flowrate,
+                front_cv_density,
+                front_cv_density);
+
+        channel.try_set_flowrate_for_fluid_array(mass_flowrate).unwrap();
+        {
+            let mut q_fraction_arr: Array1<f64> = Array1::zeros(number_of_nodes);
+            q_fraction_arr.fill(1.0/number_of_nodes as f64);
+
+            let mut channel_clone: FluidArray =
+                channel.clone().try_into().unwrap();
+            for _ in 0..power_links_per_timestep {
+                channel_clone.lateral_link_new_power_vector(
+                    heater_power, q_fraction_arr.clone()).unwrap();
+            }
+            channel = channel_clone.into();
+        }
+
+        audit.record_heat_added(heater_power);
+
+        // enthalpy flows in from the inlet bc, and out of the
+        // last node at its temperature at the start of the timestep
+        let outlet_specific_enthalpy = *channel
+            .try_get_specific_enthalpy_vector().unwrap()
+            .iter().last().unwrap();
+        audit.record_enthalpy_inflow(mass_flowrate,
+            try_get_h(therminol.into(), inlet_temperature, pressure).unwrap());
+        audit.record_enthalpy_outflow(mass_flowrate, outlet_specific_enthalpy);
+
+        channel.link_to_back(&mut inlet_bc, inlet_interaction).unwrap();
+        channel.link_to_front(&mut outlet_bc, outlet_interaction).unwrap();
+
+        channel.advance_timestep_mut_self(timestep).unwrap();
+
+        audit.try_end_timestep(timestep, &[&channel]).unwrap();
+    }
+
+    audit
+}
+
+/// a heated channel should conserve energy through the transient
+/// (heat up) and at steady state, where heat added equals
+/// net enthalpy advected out
+#[test]
+pub fn heated_fluid_array_conserves_energy(){
+
+    let audit = audit_heated_therminol_channel(200, 1);
+
+    assert_eq!(audit.records.len(), 200);
+
+    // the channel heats up, so stored enthalpy rises
+    assert!(audit.get_cumulative_stored_enthalpy_change().get::<joule>() > 0.0);
+
+    let relative_imbalance = audit.get_cumulative_relative_imbalance();
+    assert!(relative_imbalance.get::<ratio>() < 0.01,
+        "relative imbalance: {:?}", relative_imbalance);
+
+    // near steady state, almost all the heat is advected out
+    let last_record = audit.records.last().unwrap();
+    approx::assert_relative_eq!(
+        -last_record.net_advected_enthalpy.get::<watt>(),
+        last_record.heat_added.get::<watt>(),
+        max_relative=0.02);
+
+    let stored_change_by_entity = &last_record.stored_enthalpy_change_by_entity;
+    assert_eq!(stored_change_by_entity[0].0, "heated channel");
+}
+
+/// linking the heater power twice (a linkage bug) should show up
+/// as a large imbalance, about as large as the heater power itself
+#[test]
+pub fn double_linked_heater_shows_imbalance(){
+
+    let audit = audit_heated_therminol_channel(200, 2);
+
+    let imbalance = audit.get_cumulative_imbalance();
+    let heat_added = audit.get_cumulative_heat_added();
+
+    approx::assert_relative_eq!(
+        imbalance.get::<joule>(),
+        heat_added.get::<joule>(),
+        max_relative=0.05);
+    assert!(audit.get_cumulative_relative_imbalance().get::<ratio>() > 0.3);
+}
+
+/// the audit should reject mismatched entity lists and
+/// boundary conditions, which store no enthalpy
+#[test]
+pub fn energy_audit_usage_errors(){
+
+    let mut audit = EnergyAudit::new(vec!["ambient"]);
+
+    let ambient: HeatTransferEntity = BCType::new_const_temperature(
+        ThermodynamicTemperature::new::<degree_celsius>(20.0)).into();
+
+    assert!(audit.try_begin_timestep(&[&ambient]).is_err());
+    assert!(audit.try_begin_timestep(&[]).is_err());
+    assert!(audit.try_end_timestep(Time::new::<second>(1.0), &[&ambient])
+        .is_err());
+}
+
+/// runs a heated therminol channel fed by a constant temperature
+/// inlet, whose outlet flows through a mixing node into an
+/// adiabatic outlet, auditing the channel and the mixing node
+/// from the contributions tallied by the solver alone
+///
+/// if the mixing node is not linked both ways, the channel is
+/// linked to a copy of the mixing node, so the enthalpy leaving the
+/// channel never arrives in the mixing node
+fn audit_tallied_channel_and_mixing_node(number_of_timesteps: usize,
+    mixing_node_linked_both_ways: bool) -> EnergyAudit {
+
+    let therminol = LiquidMaterial::TherminolVP1;
+    let inner_nodes: usize = 3;
+    let number_of_nodes = inner_nodes + 2;
+    let pressure = Pressure::new::<atmosphere>(1.0);
+    let inlet_temperature = ThermodynamicTemperature::new::<degree_celsius>(80.0);
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.02);
+    let heater_power = Power::new::<watt>(1000.0);
+
+    let mut channel: HeatTransferEntity = FluidArray::new_cylinder(
+        Length::new::<meter>(1.0),
+        Length::new::<meter>(0.02),
+        inlet_temperature,
+        pressure,
+        SolidMaterial::SteelSS304L,
+        therminol,
+        Ratio::new::<ratio>(0.0),
+        inner_nodes,
+        Angle::new::<degree>(0.0),
+    ).into();
+
+    let mut mixing_node: HeatTransferEntity = SingleCVNode::new_cylinder(
+        Length::new::<meter>(0.05),
+        Length::new::<meter>(0.02),
+        therminol.into(),
+        inlet_temperature,
+        pressure,
+    ).unwrap().into();
+
+    channel.try_enable_energy_tally().unwrap();
+    mixing_node.try_enable_energy_tally().unwrap();
+
+    let mut inlet_bc: HeatTransferEntity = BCType::new_const_temperature(
+        inlet_temperature).into();
+    let mut outlet_bc: HeatTransferEntity = BCType::new_adiabatic_bc().into();
+
+    let timestep = Time::new::<second>(0.5);
+    let mut audit = EnergyAudit::new(vec!["heated channel", "mixing node"]);
+
+    for _ in 0..number_of_timesteps {
+
+        audit.try_begin_timestep(&[&channel, &mixing_node]).unwrap();
+
+        let temperature_vector = channel.get_temperature_vector().unwrap();
+        let back_cv_density = therminol.try_get_density(
+            temperature_vector[0]).unwrap();
+        let front_cv_density = therminol.try_get_density(
+            *temperature_vector.iter().last().unwrap()).unwrap();
+        let mixing_node_density = therminol.try_get_density(
+            mixing_node.try_get_bulk_temperature().unwrap()).unwrap();
+
+        let inlet_interaction = HeatTransferInteractionType::
+            new_advection_interaction(
+                mass_flowrate,
+                therminol.try_get_density(inlet_temperature).unwrap(),
+                back_cv_density);
+        let channel_to_mixing_node_interaction = HeatTransferInteractionType::
+            new_advection_interaction(
+                mass_flowrate,
+                front_cv_density,
+                mixing_node_density);
+        let outlet_interaction = HeatTransferInteractionType::
+            new_advection_interaction(
+                mass_flowrate,
+                mixing_node_density,
+                mixing_node_density);
+
+        channel.try_set_flowrate_for_fluid_array(mass_flowrate).unwrap();
+        {
+            let mut q_fraction_arr: Array1<f64> = Array1::zeros(number_of_nodes);
+            q_fraction_arr.fill(1.0/number_of_nodes as f64);
+
+            let mut channel_clone: FluidArray =
+                channel.clone().try_into().unwrap();
+            channel_clone.lateral_link_new_power_vector(
+                heater_power, q_fraction_arr).unwrap();
+            channel = channel_clone.into();
+        }
+
+        channel.link_to_back(&mut inlet_bc, inlet_interaction).unwrap();
+
+        if mixing_node_linked_both_ways {
+            channel.link_to_front(&mut mixing_node,
+                channel_to_mixing_node_interaction).unwrap();
+        } else {
+            let mut mixing_node_copy = mixing_node.clone();
+            channel.link_to_front(&mut mixing_node_copy,
+                channel_to_mixing_node_interaction).unwrap();
+        }
+
+        mixing_node.link_to_front(&mut outlet_bc, outlet_interaction).unwrap();
+
+        channel.advance_timestep_mut_self(timestep).unwrap();
+        mixing_node.advance_timestep_mut_self(timestep).unwrap();
+
+        audit.try_end_timestep(timestep, &[&channel, &mixing_node]).unwrap();
+    }
+
+    audit
+}
+
+/// with the channel and mixing node linked properly, the heat
+/// added and advected enthalpy reported by the solver should
+/// balance the stored enthalpy change, and what the mixing node
+/// receives from the channel should equal what the channel loses
+#[test]
+pub fn tallied_channel_and_mixing_node_conserve_energy(){
+
+    let audit = audit_tallied_channel_and_mixing_node(200, true);
+
+    for record in audit.records.iter() {
+        approx::assert_relative_eq!(record.heat_added.get::<watt>(),
+            1000.0, max_relative=1e-9);
+        assert!(record.net_advected_enthalpy.get::<watt>() <= 0.0);
+        approx::assert_abs_diff_eq!(
+            record.net_exchange_between_entities.get::<watt>(),
+            0.0, epsilon=1e-9);
+        assert_eq!(record.untallied.get::<watt>(), 0.0);
+    }
+
+    let relative_imbalance = audit.get_cumulative_relative_imbalance();
+    assert!(relative_imbalance.get::<ratio>() < 0.01,
+        "relative imbalance: {:?}", relative_imbalance);
+
+    // near steady state, almost all the heat is advected out
+    let last_record = audit.records.last().unwrap();
+    approx::assert_relative_eq!(
+        -last_record.net_advected_enthalpy.get::<watt>(),
+        last_record.heat_added.get::<watt>(),
+        max_relative=0.02);
+}
+
+/// if the channel outlet is only linked on the channel side (the
+/// SingleCVNode and FluidArray linkage bug), the enthalpy leaving
+/// the channel is lost, and the audit should show it both as a net
+/// exchange between entities and as an imbalance
+#[test]
+pub fn one_sided_mixing_node_link_shows_imbalance(){
+
+    let audit = audit_tallied_channel_and_mixing_node(200, false);
+
+    let cumulative_net_exchange: Energy = audit.records.iter()
+        .fold(Energy::new::<joule>(0.0), |sum, record| {
+            sum + record.net_exchange_between_entities * record.timestep
+        });
+
+    assert!(cumulative_net_exchange.get::<joule>() < 0.0);
+    approx::assert_relative_eq!(
+        audit.get_cumulative_imbalance().get::<joule>(),
+        cumulative_net_exchange.get::<joule>(),
+        max_relative=0.05);
+    assert!(audit.get_cumulative_relative_imbalance().get::<ratio>() > 0.3);
+}
@@ -0,0 +1,224 @@
+//! friction factors for developing flow in pipes and for
+//! non circular ducts such as annuli and rectangular ducts
+//!
+//! all friction factors returned here are darcy friction factors,
+//! so that they can be used directly in the fldk term,
+//!
+//! (f L/D + K)
+use crate::tuas_lib_error::TuasLibError;
+
+use super::churchill_friction_factor;
+
+/// below this reynolds number, flow in a pipe is taken to be laminar
+/// for developing flow friction factors
+pub const DEVELOPING_FLOW_LAMINAR_REYNOLDS_LIMIT: f64 = 2300.0;
+
+/// above this reynolds number, flow in a pipe is taken to be
+/// turbulent for developing flow friction factors
+pub const DEVELOPING_FLOW_TURBULENT_REYNOLDS_LIMIT: f64 = 4000.0;
+
+/// smooth blending weight between two correlations, this goes
+/// from 0 at the lower reynolds number to 1 at the upper reynolds
+/// number with zero slope at both ends,
+///
+/// w = 3t^2 - 2t^3
+///
+/// where t is the fractional distance between the two reynolds
+/// numbers on a logarithmic scale
+pub fn smooth_reynolds_blending_weight(reynolds_number: f64,
+    lower_reynolds_number: f64,
+    upper_reynolds_number: f64) -> f64 {
+
+    let t = ((reynolds_number.ln() - lower_reynolds_number.ln())
+        /(upper_reynolds_number.ln() - lower_reynolds_number.ln()))
+        .clamp(0.0, 1.0);
+
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// apparent darcy friction factor for laminar developing flow in the
+/// entrance region of a circular pipe based on Shah,
+///
+/// f_app Re = 4 { 3.44/(x+)^0.5
+/// + [1.25/(4 x+) + 16 - 3.44/(x+)^0.5] / [1 + 0.00021 (x+)^(-2)] }
+///
+/// where x+ = (L/D)/Re. The apparent friction factor includes
+/// both wall shear and the change in momentum flux as the velocity
+/// profile develops, so it is higher than the fully developed value
+/// of 64/Re for short pipes, and tends to 64/Re for long pipes
+///
+/// Shah, R. K., & London, A. L. (1978). Laminar flow forced
+/// convection in ducts. Advances in Heat Transfer, Supplement 1.
+/// Academic Press.
+pub fn shah_london_apparent_darcy_laminar(reynolds_number: f64,
+    length_to_diameter: f64) -> f64 {
+
+    let reynolds_number = reynolds_number.abs();
+    let dimensionless_length = length_to_diameter/reynolds_number;
+    let entrance_term = 3.44/dimensionless_length.sqrt();
+
+    let fanning_times_reynolds = entrance_term
+        + (1.25/(4.0 * dimensionless_length) + 16.0 - entrance_term)
+        /(1.0 + 0.00021/dimensionless_length.powi(2));
+
+    4.0 * fanning_times_reynolds / reynolds_number
+}
+
+/// darcy friction factor for developing flow in a circular pipe
+///
+/// below Re = 2300, the Shah and London apparent friction factor is
+/// used. Above Re = 4000, the fully developed Churchill friction
+/// factor is used since the entrance length in turbulent flow is
+/// short. In between, the two are blended smoothly
+///
+/// ```rust
+/// use tuas_boussinesq_solver::fluid_mechanics_correlations::
+/// duct_friction_factor::developing_flow_pipe_darcy;
+///
+/// // long pipes in laminar flow are fully developed
+/// let darcy = developing_flow_pipe_darcy(1000.0, 0.0, 1.0e5).unwrap();
+///
+/// approx::assert_relative_eq!(darcy, 64.0/1000.0, max_relative=0.01);
+///
+/// // short pipes have higher apparent friction
+/// let darcy_short = developing_flow_pipe_darcy(1000.0, 0.0, 10.0).unwrap();
+///
+/// assert!(darcy_short > 1.5 * darcy);
+/// ```
+pub fn developing_flow_pipe_darcy(reynolds_number: f64,
+    roughness_ratio: f64,
+    length_to_diameter: f64) -> Result<f64, TuasLibError> {
+
+    if !(length_to_diameter > 0.0) {
+        return Err(TuasLibError::GenericStringError(
+            "length to diameter ratio must be positive".to_owned()));
+    }
+
+    let reynolds_number = reynolds_number.abs();
+
+    if reynolds_number == 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "Re = 0 will not work for developing flow friction factor".to_owned()));
+    }
+
+    if reynolds_number <= DEVELOPING_FLOW_LAMINAR_REYNOLDS_LIMIT {
+        return Ok(shah_london_apparent_darcy_laminar(reynolds_number,
+            length_to_diameter));
+    }
+
+    let fully_developed_darcy = churchill_friction_factor::darcy(
+        reynolds_number, roughness_ratio)?;
+
+    if reynolds_number >= DEVELOPING_FLOW_TURBULENT_REYNOLDS_LIMIT {
+        return Ok(fully_developed_darcy);
+    }
+
+    let laminar_darcy = shah_london_apparent_darcy_laminar(
+        reynolds_number, length_to_diameter);
+
+    let turbulent_weight = smooth_reynolds_blending_weight(reynolds_number,
+        DEVELOPING_FLOW_LAMINAR_REYNOLDS_LIMIT,
+        DEVELOPING_FLOW_TURBULENT_REYNOLDS_LIMIT);
+
+    Ok((1.0 - turbulent_weight) * laminar_darcy
+        + turbulent_weight * fully_developed_darcy)
+}
+
+/// laminar fully developed darcy friction factor times reynolds
+/// number (f Re) for a concentric annulus, based on the hydraulic
+/// diameter D_o - D_i,
+///
+/// f Re = 64 (1 - k)^2 / [1 + k^2 - (1 - k^2)/ln(1/k)]
+///
+/// where k = D_i/D_o is the diameter ratio. This tends to 64 for a
+/// circular pipe (k = 0) and 96 for parallel plates (k = 1)
+///
+/// White, F. M. (2006). Viscous fluid flow (3rd ed.). McGraw-Hill.
+pub fn annulus_laminar_darcy_reynolds_product(
+    diameter_ratio: f64) -> Result<f64, TuasLibError> {
+
+    if !(diameter_ratio > 0.0) || !(diameter_ratio < 1.0) {
+        return Err(TuasLibError::GenericStringError(
+            format!("annulus inner to outer diameter ratio must be between 0 and 1, got {}",
+                diameter_ratio)));
+    }
+
+    let k = diameter_ratio;
+
+    Ok(64.0 * (1.0 - k).powi(2)
+        /(1.0 + k * k - (1.0 - k * k)/(1.0/k).ln()))
+}
+
+/// laminar fully developed darcy friction factor times reynolds
+/// number (f Re) for a rectangular duct, based on the hydraulic
+/// diameter 4A/P,
+///
+/// f Re = 96 (1 - 1.3553 a + 1.9467 a^2 - 1.7012 a^3
+/// + 0.9564 a^4 - 0.2537 a^5)
+///
+/// where a is the aspect ratio, short side over long side, from 0 for
+/// parallel plates to 1 for a square duct
+///
+/// Shah, R. K., & London, A. L. (1978). Laminar flow forced
+/// convection in ducts. Advances in Heat Transfer, Supplement 1.
+/// Academic Press.
+pub fn rectangular_duct_laminar_darcy_reynolds_product(
+    aspect_ratio: f64) -> Result<f64, TuasLibError> {
+
+    if !(aspect_ratio > 0.0) || !aspect_ratio.is_finite() {
+        return Err(TuasLibError::GenericStringError(
+            format!("rectangular duct aspect ratio must be positive, got {}",
+                aspect_ratio)));
+    }
+
+    // short side over long side
+    let a = aspect_ratio.min(1.0/aspect_ratio);
+
+    Ok(96.0 * (1.0 - 1.3553 * a + 1.9467 * a.powi(2) - 1.7012 * a.powi(3)
+        + 0.9564 * a.powi(4) - 0.2537 * a.powi(5)))
+}
+
+/// darcy friction factor for a non circular duct using the laminar
+/// equivalent diameter method
+///
+/// the shape factor z = (f Re)_duct/64 compares the laminar friction
+/// of the duct to that of a circular pipe. The Churchill correlation
+/// is then evaluated at an effective reynolds number Re/z with an
+/// effective roughness ratio z e/D_h, which reproduces the exact
+/// laminar friction factor and gives a better turbulent friction
+/// factor than using the hydraulic diameter alone
+///
+/// Jones Jr, O. C. (1976). An improvement in the calculation of
+/// turbulent friction in rectangular ducts. Journal of Fluids
+/// Engineering, 98(2), 173-180.
+///
+/// ```rust
+/// use tuas_boussinesq_solver::fluid_mechanics_correlations::
+/// duct_friction_factor::{non_circular_duct_darcy,
+/// annulus_laminar_darcy_reynolds_product};
+///
+/// let annulus_darcy_reynolds_product =
+///     annulus_laminar_darcy_reynolds_product(0.5).unwrap();
+///
+/// let darcy = non_circular_duct_darcy(1000.0, 0.0,
+///     annulus_darcy_reynolds_product).unwrap();
+///
+/// approx::assert_relative_eq!(darcy, annulus_darcy_reynolds_product/1000.0,
+///     max_relative=0.001);
+/// ```
+pub fn non_circular_duct_darcy(reynolds_number: f64,
+    roughness_ratio: f64,
+    laminar_darcy_reynolds_product: f64) -> Result<f64, TuasLibError> {
+
+    if !(laminar_darcy_reynolds_product > 0.0) {
+        return Err(TuasLibError::GenericStringError(
+            "laminar darcy friction factor reynolds number product must be positive"
+            .to_owned()));
+    }
+
+    let shape_factor = laminar_darcy_reynolds_product/64.0;
+
+    churchill_friction_factor::darcy(
+        reynolds_number.abs()/shape_factor,
+        roughness_ratio * shape_factor)
+}
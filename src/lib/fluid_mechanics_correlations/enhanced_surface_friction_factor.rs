@@ -0,0 +1,296 @@
+//! friction factors for tubes with enhanced surfaces or
+//! inserts, such as twisted tapes and helical coils
+//!
+//! all friction factors returned here are darcy friction factors,
+//! so that they can be used directly in the fldk term,
+//!
+//! (f L/D + K)
+use std::f64::consts::PI;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// below this reynolds number, flow through a tube with a twisted
+/// tape insert is taken to be laminar
+pub const TWISTED_TAPE_LAMINAR_REYNOLDS_LIMIT: f64 = 2000.0;
+
+/// above this reynolds number, flow through a tube with a twisted
+/// tape insert is taken to be turbulent, between the laminar
+/// and turbulent limits, correlations are linearly interpolated
+pub const TWISTED_TAPE_TURBULENT_REYNOLDS_LIMIT: f64 = 10000.0;
+
+/// checks that the twisted tape geometry is physical
+///
+/// the twist ratio y = H/d is the length of a 180 degree twist
+/// of the tape divided by the tube inner diameter, and the tape
+/// thickness ratio is delta/d. The tape thickness ratio must be
+/// below pi/4, otherwise the tape blocks the whole tube
+pub fn try_validate_twisted_tape_geometry(twist_ratio: f64,
+    tape_thickness_to_diameter: f64) -> Result<(), TuasLibError> {
+
+    if !(twist_ratio > 0.0) || !twist_ratio.is_finite() {
+        return Err(TuasLibError::GenericStringError(
+            format!("twisted tape twist ratio must be positive and finite, got {}",
+                twist_ratio)));
+    }
+
+    if !(tape_thickness_to_diameter >= 0.0)
+        || !(tape_thickness_to_diameter < PI/4.0) {
+        return Err(TuasLibError::GenericStringError(
+            format!("twisted tape thickness to diameter ratio must be between 0 and pi/4, got {}",
+                tape_thickness_to_diameter)));
+    }
+
+    Ok(())
+}
+
+/// flow blockage factor for a twisted tape of thickness delta,
+///
+/// pi/(pi - 4 delta/d)
+#[inline]
+fn twisted_tape_blockage_factor(tape_thickness_to_diameter: f64) -> f64 {
+    PI/(PI - 4.0 * tape_thickness_to_diameter)
+}
+
+/// wetted perimeter factor for a twisted tape of thickness delta,
+///
+/// (pi + 2 - 2 delta/d)/(pi - 4 delta/d)
+#[inline]
+fn twisted_tape_perimeter_factor(tape_thickness_to_diameter: f64) -> f64 {
+    (PI + 2.0 - 2.0 * tape_thickness_to_diameter)
+        /(PI - 4.0 * tape_thickness_to_diameter)
+}
+
+/// swirl number for flow through a tube with a twisted tape,
+///
+/// Sw = Re_s / sqrt(y)
+///
+/// where Re_s = Re (1 + (pi/2y)^2)^0.5 is the reynolds number based on
+/// the swirl velocity at the tube wall
+///
+/// Manglik, R. M., & Bergles, A. E. (1993). Heat transfer and
+/// pressure drop correlations for twisted-tape inserts in
+/// isothermal tubes: Part I—Laminar flows. Journal of Heat
+/// Transfer, 115(4), 881-889.
+pub fn twisted_tape_swirl_number(reynolds_number: f64,
+    twist_ratio: f64) -> f64 {
+
+    let swirl_reynolds_number = reynolds_number.abs()
+        * (1.0 + (PI/(2.0 * twist_ratio)).powi(2)).sqrt();
+
+    swirl_reynolds_number / twist_ratio.sqrt()
+}
+
+/// laminar darcy friction factor for a tube with a twisted tape
+/// insert based on Manglik and Bergles,
+///
+/// f_fanning Re = 15.767 ((pi + 2 - 2 delta/d)/(pi - 4 delta/d))^2
+/// (1 + 10^(-6) Sw^2.55)^(1/6)
+///
+/// the reynolds number is based on the tube inner diameter d and
+/// the mean axial velocity in the tape blocked cross section
+///
+/// Manglik, R. M., & Bergles, A. E. (1993). Heat transfer and
+/// pressure drop correlations for twisted-tape inserts in
+/// isothermal tubes: Part I—Laminar flows. Journal of Heat
+/// Transfer, 115(4), 881-889.
+pub fn manglik_bergles_twisted_tape_darcy_laminar(reynolds_number: f64,
+    twist_ratio: f64,
+    tape_thickness_to_diameter: f64) -> f64 {
+
+    let reynolds_number = reynolds_number.abs();
+    let swirl_number = twisted_tape_swirl_number(reynolds_number,
+        twist_ratio);
+
+    let fanning_times_reynolds = 15.767
+        * twisted_tape_perimeter_factor(tape_thickness_to_diameter).powi(2)
+        * (1.0 + 1.0e-6 * swirl_number.powf(2.55)).powf(1.0/6.0);
+
+    4.0 * fanning_times_reynolds / reynolds_number
+}
+
+/// turbulent darcy friction factor for a tube with a twisted tape
+/// insert based on Manglik and Bergles,
+///
+/// f_fanning = 0.0791/Re^0.25 (1 + 2.752/y^1.29)
+/// (pi/(pi - 4 delta/d))^1.75
+/// ((pi + 2 - 2 delta/d)/(pi - 4 delta/d))^1.25
+///
+/// the reynolds number is based on the tube inner diameter d and
+/// the mean axial velocity in the tape blocked cross section
+///
+/// Manglik, R. M., & Bergles, A. E. (1993). Heat transfer and
+/// pressure drop correlations for twisted-tape inserts in
+/// isothermal tubes: Part II—Transition and turbulent flows.
+/// Journal of Heat Transfer, 115(4), 890-896.
+pub fn manglik_bergles_twisted_tape_darcy_turbulent(reynolds_number: f64,
+    twist_ratio: f64,
+    tape_thickness_to_diameter: f64) -> f64 {
+
+    let reynolds_number = reynolds_number.abs();
+
+    let fanning_friction_factor = 0.0791 / reynolds_number.powf(0.25)
+        * (1.0 + 2.752/twist_ratio.powf(1.29))
+        * twisted_tape_blockage_factor(tape_thickness_to_diameter).powf(1.75)
+        * twisted_tape_perimeter_factor(tape_thickness_to_diameter).powf(1.25);
+
+    4.0 * fanning_friction_factor
+}
+
+/// darcy friction factor for a tube with a twisted tape insert
+/// based on Manglik and Bergles, for laminar, transition and
+/// turbulent flow
+///
+/// the laminar correlation is used below Re = 2000 and the
+/// turbulent correlation above Re = 10000, in between, the
+/// friction factor is linearly interpolated
+///
+/// ```rust
+/// use tuas_boussinesq_solver::fluid_mechanics_correlations::
+/// enhanced_surface_friction_factor::manglik_bergles_twisted_tape_darcy;
+///
+/// // a very loosely twisted and thin tape behaves like a straight 
+/// // plate splitting the tube in two, so friction is that of a 
+/// // smooth tube (Blasius) with extra wetted perimeter 
+/// let perimeter_factor = (std::f64::consts::PI + 2.0)/std::f64::consts::PI;
+/// let darcy = manglik_bergles_twisted_tape_darcy(
+///     20000.0, 1.0e6, 0.0).unwrap();
+///
+/// approx::assert_relative_eq!(darcy, 
+///     0.3164/20000.0_f64.powf(0.25) * perimeter_factor.powf(1.25),
+///     max_relative=0.001);
+/// ```
+pub fn manglik_bergles_twisted_tape_darcy(reynolds_number: f64,
+    twist_ratio: f64,
+    tape_thickness_to_diameter: f64) -> Result<f64, TuasLibError> {
+
+    try_validate_twisted_tape_geometry(twist_ratio,
+        tape_thickness_to_diameter)?;
+
+    let reynolds_number = reynolds_number.abs();
+
+    if reynolds_number == 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "Re = 0 will not work for twisted tape friction factor".to_owned()));
+    }
+
+    if reynolds_number <= TWISTED_TAPE_LAMINAR_REYNOLDS_LIMIT {
+        return Ok(manglik_bergles_twisted_tape_darcy_laminar(
+            reynolds_number, twist_ratio, tape_thickness_to_diameter));
+    }
+
+    if reynolds_number >= TWISTED_TAPE_TURBULENT_REYNOLDS_LIMIT {
+        return Ok(manglik_bergles_twisted_tape_darcy_turbulent(
+            reynolds_number, twist_ratio, tape_thickness_to_diameter));
+    }
+
+    let laminar_darcy = manglik_bergles_twisted_tape_darcy_laminar(
+        TWISTED_TAPE_LAMINAR_REYNOLDS_LIMIT, twist_ratio,
+        tape_thickness_to_diameter);
+    let turbulent_darcy = manglik_bergles_twisted_tape_darcy_turbulent(
+        TWISTED_TAPE_TURBULENT_REYNOLDS_LIMIT, twist_ratio,
+        tape_thickness_to_diameter);
+
+    let interpolation_fraction = (reynolds_number - TWISTED_TAPE_LAMINAR_REYNOLDS_LIMIT)
+        /(TWISTED_TAPE_TURBULENT_REYNOLDS_LIMIT - TWISTED_TAPE_LAMINAR_REYNOLDS_LIMIT);
+
+    Ok(laminar_darcy + interpolation_fraction * (turbulent_darcy - laminar_darcy))
+}
+
+/// checks that the helical coil geometry is physical, the
+/// ratio of tube inner diameter to coil diameter d/D must be
+/// between 0 and 1
+pub fn try_validate_helical_coil_geometry(
+    tube_to_coil_diameter_ratio: f64) -> Result<(), TuasLibError> {
+
+    if !(tube_to_coil_diameter_ratio > 0.0)
+        || !(tube_to_coil_diameter_ratio < 1.0) {
+        return Err(TuasLibError::GenericStringError(
+            format!("helical coil tube to coil diameter ratio must be between 0 and 1, got {}",
+                tube_to_coil_diameter_ratio)));
+    }
+
+    Ok(())
+}
+
+/// dean number for flow through a helical coil,
+///
+/// De = Re (d/D)^0.5
+///
+/// where d is the tube inner diameter and D is the coil diameter
+pub fn dean_number(reynolds_number: f64,
+    tube_to_coil_diameter_ratio: f64) -> f64 {
+    reynolds_number.abs() * tube_to_coil_diameter_ratio.sqrt()
+}
+
+/// critical reynolds number for laminar to turbulent transition in
+/// helical coils based on Schmidt,
+///
+/// Re_crit = 2300 (1 + 8.6 (d/D)^0.45)
+///
+/// secondary flow in the coil stabilises laminar flow, so transition
+/// happens at higher reynolds numbers than straight tubes
+///
+/// Schmidt, E. F. (1967). Wärmeübergang und Druckverlust in
+/// Rohrschlangen. Chemie Ingenieur Technik, 39(13), 781-789.
+pub fn helical_coil_critical_reynolds_number(
+    tube_to_coil_diameter_ratio: f64) -> f64 {
+    2300.0 * (1.0 + 8.6 * tube_to_coil_diameter_ratio.powf(0.45))
+}
+
+/// darcy friction factor for a helical coil based on
+/// Mishra and Gupta, neglecting the effect of coil pitch
+///
+/// laminar (Re below Re_crit):
+///
+/// f = 64/Re (1 + 0.033 (log10 De)^4)
+///
+/// turbulent (Re above Re_crit):
+///
+/// f = 0.3164/Re^0.25 + 0.03 (d/D)^0.5
+///
+/// for De below 1, the straight tube laminar friction factor is used
+///
+/// Mishra, P., & Gupta, S. N. (1979). Momentum transfer in curved
+/// pipes. 1. Newtonian fluids. Industrial & Engineering Chemistry
+/// Process Design and Development, 18(1), 130-137.
+///
+/// ```rust
+/// use tuas_boussinesq_solver::fluid_mechanics_correlations::
+/// enhanced_surface_friction_factor::helical_coil_darcy;
+///
+/// // a gently curved coil in laminar flow is close to a straight tube
+/// let darcy = helical_coil_darcy(100.0, 1.0e-6).unwrap();
+///
+/// approx::assert_relative_eq!(darcy, 64.0/100.0, max_relative=0.001);
+/// ```
+pub fn helical_coil_darcy(reynolds_number: f64,
+    tube_to_coil_diameter_ratio: f64) -> Result<f64, TuasLibError> {
+
+    try_validate_helical_coil_geometry(tube_to_coil_diameter_ratio)?;
+
+    let reynolds_number = reynolds_number.abs();
+
+    if reynolds_number == 0.0 {
+        return Err(TuasLibError::GenericStringError(
+            "Re = 0 will not work for helical coil friction factor".to_owned()));
+    }
+
+    let critical_reynolds_number =
+        helical_coil_critical_reynolds_number(tube_to_coil_diameter_ratio);
+
+    if reynolds_number < critical_reynolds_number {
+        let dean_number = dean_number(reynolds_number,
+            tube_to_coil_diameter_ratio);
+
+        let curvature_factor = if dean_number > 1.0 {
+            1.0 + 0.033 * dean_number.log10().powi(4)
+        } else {
+            1.0
+        };
+
+        return Ok(64.0/reynolds_number * curvature_factor);
+    }
+
+    Ok(0.3164/reynolds_number.powf(0.25)
+        + 0.03 * tube_to_coil_diameter_ratio.sqrt())
+}
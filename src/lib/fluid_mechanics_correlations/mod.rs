@@ -59,6 +59,14 @@ pub mod churchill_friction_factor;
 /// K is the form loss
 pub mod custom_fldk;
 
+/// darcy friction factors for tubes with enhanced surfaces
+/// or inserts, eg. twisted tapes and helical coils
+pub mod enhanced_surface_friction_factor;
+
+/// darcy friction factors for developing flow in pipes and 
+/// for non circular ducts, eg. annuli and rectangular ducts
+pub mod duct_friction_factor;
+
 
 /// contains functions and/or structs
 /// which help you dimensionalise and nondimensionalise variables
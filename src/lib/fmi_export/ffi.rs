@@ -0,0 +1,478 @@
+//! the fmi2* and tuas_plant_* entry points are generated by
+//! [export_fmi2_co_simulation](crate::export_fmi2_co_simulation) in
+//! the crate which builds the shared library, they call the generic
+//! functions here
+//!
+//! these convert the C arguments, catch errors and panics so that
+//! they do not cross the C boundary, and report errors through the
+//! logger callback of the importing tool
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use uom::si::f64::*;
+use uom::si::time::second;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::{Fmi2Instance, Fmi2Model};
+
+/// fmi2Status
+pub type Fmi2Status = c_int;
+/// fmi2OK
+pub const FMI2_OK: Fmi2Status = 0;
+/// fmi2Warning
+pub const FMI2_WARNING: Fmi2Status = 1;
+/// fmi2Discard
+pub const FMI2_DISCARD: Fmi2Status = 2;
+/// fmi2Error
+pub const FMI2_ERROR: Fmi2Status = 3;
+/// fmi2Fatal
+pub const FMI2_FATAL: Fmi2Status = 4;
+
+/// fmi2Boolean, zero is false
+pub type Fmi2Boolean = c_int;
+
+/// fmi2Type
+pub type Fmi2Type = c_int;
+/// fmi2ModelExchange
+pub const FMI2_MODEL_EXCHANGE: Fmi2Type = 0;
+/// fmi2CoSimulation
+pub const FMI2_CO_SIMULATION: Fmi2Type = 1;
+
+/// fmi2LastSuccessfulTime, for fmi2GetRealStatus
+pub const FMI2_LAST_SUCCESSFUL_TIME: c_int = 2;
+
+/// fmi2CallbackLogger, the message is a printf style format string
+pub type Fmi2CallbackLogger = unsafe extern "C" fn(
+    component_environment: *mut c_void,
+    instance_name: *const c_char,
+    status: Fmi2Status,
+    category: *const c_char,
+    message: *const c_char, ...);
+
+/// fmi2CallbackFunctions
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Fmi2CallbackFunctions {
+    /// logger
+    pub logger: Option<Fmi2CallbackLogger>,
+    /// allocateMemory, unused since the FMU uses the rust allocator
+    pub allocate_memory: Option<unsafe extern "C" fn(usize, usize) -> *mut c_void>,
+    /// freeMemory, unused since the FMU uses the rust allocator
+    pub free_memory: Option<unsafe extern "C" fn(*mut c_void)>,
+    /// stepFinished, unused since steps are never asynchronous
+    pub step_finished: Option<unsafe extern "C" fn(*mut c_void, Fmi2Status)>,
+    /// componentEnvironment, passed back to the logger
+    pub component_environment: *mut c_void,
+}
+
+static TYPES_PLATFORM: &[u8] = b"default\0";
+static FMI_VERSION: &[u8] = b"2.0\0";
+
+/// an FMU instance as seen by the importing tool
+#[derive(Debug)]
+pub struct Fmi2Component<M: Fmi2Model> {
+    /// the model instance
+    pub instance: Fmi2Instance<M>,
+    /// whether debug logging is on
+    pub logging_on: bool,
+
+    instance_name: CString,
+    logger: Option<Fmi2CallbackLogger>,
+    component_environment: *mut c_void,
+}
+
+impl<M: Fmi2Model> Fmi2Component<M> {
+
+    fn log(&self, status: Fmi2Status, category: &str, message: &str) {
+        if let Some(logger) = self.logger {
+            log_with(logger, self.component_environment,
+                &self.instance_name, status, category, message);
+        }
+    }
+}
+
+fn log_with(logger: Fmi2CallbackLogger,
+    component_environment: *mut c_void,
+    instance_name: &CStr,
+    status: Fmi2Status,
+    category: &str,
+    message: &str) {
+
+    let category = CString::new(category).unwrap_or_default();
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+
+    // the message goes through %s so that it is not taken as
+    // a format string
+    unsafe {
+        logger(component_environment, instance_name.as_ptr(), status,
+            category.as_ptr(), c"%s".as_ptr(), message.as_ptr());
+    }
+}
+
+unsafe fn try_read_c_string(text: *const c_char) -> Result<String, TuasLibError> {
+    if text.is_null() {
+        return Err(TuasLibError::GenericStringError(
+            "null string pointer".to_owned()));
+    }
+    Ok(CStr::from_ptr(text).to_string_lossy().into_owned())
+}
+
+/// runs a closure on a component, converting errors and panics to
+/// an fmi2Status
+unsafe fn with_component<M, F>(c: *mut c_void, function_name: &str, function: F)
+    -> Fmi2Status
+where M: Fmi2Model,
+F: FnOnce(&mut Fmi2Component<M>) -> Result<Fmi2Status, TuasLibError> {
+
+    if c.is_null() {
+        return FMI2_ERROR;
+    }
+    let component = &mut *(c as *mut Fmi2Component<M>);
+
+    match catch_unwind(AssertUnwindSafe(|| function(component))) {
+        Ok(Ok(status)) => status,
+        Ok(Err(error)) => {
+            component.log(FMI2_ERROR, "logStatusError",
+                &format!("{}: {:?}", function_name, error));
+            FMI2_ERROR
+        },
+        Err(_) => {
+            component.log(FMI2_FATAL, "logStatusFatal",
+                &format!("{}: the model panicked", function_name));
+            FMI2_FATAL
+        },
+    }
+}
+
+/// fmi2GetTypesPlatform
+pub fn get_types_platform() -> *const c_char {
+    TYPES_PLATFORM.as_ptr() as *const c_char
+}
+
+/// fmi2GetVersion
+pub fn get_version() -> *const c_char {
+    FMI_VERSION.as_ptr() as *const c_char
+}
+
+/// fmi2SetDebugLogging, log categories are ignored
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate]
+pub unsafe fn set_debug_logging<M: Fmi2Model>(c: *mut c_void,
+    logging_on: Fmi2Boolean,
+    _n_categories: usize,
+    _categories: *const *const c_char) -> Fmi2Status {
+    with_component::<M, _>(c, "fmi2SetDebugLogging", |component| {
+        component.logging_on = logging_on != 0;
+        Ok(FMI2_OK)
+    })
+}
+
+/// fmi2Instantiate, only co-simulation is supported and the guid
+/// must match [Fmi2Model::get_guid]
+///
+/// # Safety
+///
+/// strings must be null or null terminated, functions must be null
+/// or point to valid callbacks
+pub unsafe fn instantiate<M: Fmi2Model>(instance_name: *const c_char,
+    fmu_type: Fmi2Type,
+    fmu_guid: *const c_char,
+    _fmu_resource_location: *const c_char,
+    functions: *const Fmi2CallbackFunctions,
+    _visible: Fmi2Boolean,
+    logging_on: Fmi2Boolean) -> *mut c_void {
+
+    let instance_name = match try_read_c_string(instance_name) {
+        Ok(instance_name) => instance_name,
+        Err(_) => return std::ptr::null_mut(),
+    };
+    let instance_name_c = CString::new(instance_name.clone()).unwrap_or_default();
+
+    let (logger, component_environment) = match functions.as_ref() {
+        Some(functions) => (functions.logger, functions.component_environment),
+        None => (None, std::ptr::null_mut()),
+    };
+
+    let log_error = |message: &str| {
+        if let Some(logger) = logger {
+            log_with(logger, component_environment, &instance_name_c,
+                FMI2_ERROR, "logStatusError", message);
+        }
+    };
+
+    if fmu_type != FMI2_CO_SIMULATION {
+        log_error("only co-simulation is supported");
+        return std::ptr::null_mut();
+    }
+
+    match try_read_c_string(fmu_guid) {
+        Ok(guid) if guid == M::get_guid() => (),
+        _ => {
+            log_error(&format!("guid does not match {}", M::get_guid()));
+            return std::ptr::null_mut();
+        },
+    }
+
+    let component: Fmi2Component<M> = Fmi2Component {
+        instance: Fmi2Instance::new(&instance_name),
+        logging_on: logging_on != 0,
+        instance_name: instance_name_c,
+        logger,
+        component_environment,
+    };
+
+    Box::into_raw(Box::new(component)) as *mut c_void
+}
+
+/// fmi2FreeInstance
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate] which
+/// has not been freed
+pub unsafe fn free_instance<M: Fmi2Model>(c: *mut c_void) {
+    if !c.is_null() {
+        drop(Box::from_raw(c as *mut Fmi2Component<M>));
+    }
+}
+
+/// fmi2SetupExperiment, the tolerance and stop time are ignored
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate]
+pub unsafe fn setup_experiment<M: Fmi2Model>(c: *mut c_void,
+    _tolerance_defined: Fmi2Boolean,
+    _tolerance: f64,
+    start_time: f64,
+    _stop_time_defined: Fmi2Boolean,
+    _stop_time: f64) -> Fmi2Status {
+    with_component::<M, _>(c, "fmi2SetupExperiment", |component| {
+        component.instance.try_set_start_time(Time::new::<second>(start_time))?;
+        Ok(FMI2_OK)
+    })
+}
+
+/// fmi2EnterInitializationMode
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate]
+pub unsafe fn enter_initialization_mode<M: Fmi2Model>(c: *mut c_void) -> Fmi2Status {
+    with_component::<M, _>(c, "fmi2EnterInitializationMode", |_| Ok(FMI2_OK))
+}
+
+/// fmi2ExitInitializationMode, this constructs the plant
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate]
+pub unsafe fn exit_initialization_mode<M: Fmi2Model>(c: *mut c_void) -> Fmi2Status {
+    with_component::<M, _>(c, "fmi2ExitInitializationMode", |component| {
+        component.instance.try_initialize()?;
+        Ok(FMI2_OK)
+    })
+}
+
+/// fmi2Terminate
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate]
+pub unsafe fn terminate<M: Fmi2Model>(c: *mut c_void) -> Fmi2Status {
+    with_component::<M, _>(c, "fmi2Terminate", |_| Ok(FMI2_OK))
+}
+
+/// fmi2Reset
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate]
+pub unsafe fn reset<M: Fmi2Model>(c: *mut c_void) -> Fmi2Status {
+    with_component::<M, _>(c, "fmi2Reset", |component| {
+        component.instance.reset();
+        Ok(FMI2_OK)
+    })
+}
+
+/// fmi2GetReal
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate], vr and
+/// value must point to nvr elements
+pub unsafe fn get_real<M: Fmi2Model>(c: *mut c_void,
+    vr: *const u32,
+    nvr: usize,
+    value: *mut f64) -> Fmi2Status {
+    with_component::<M, _>(c, "fmi2GetReal", |component| {
+        if nvr == 0 {
+            return Ok(FMI2_OK);
+        }
+        let value_references = std::slice::from_raw_parts(vr, nvr);
+        let values = std::slice::from_raw_parts_mut(value, nvr);
+        for (value_reference, value) in value_references.iter().zip(values.iter_mut()) {
+            *value = component.instance.try_get_real(*value_reference)?;
+        }
+        Ok(FMI2_OK)
+    })
+}
+
+/// fmi2SetReal
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate], vr and
+/// value must point to nvr elements
+pub unsafe fn set_real<M: Fmi2Model>(c: *mut c_void,
+    vr: *const u32,
+    nvr: usize,
+    value: *const f64) -> Fmi2Status {
+    with_component::<M, _>(c, "fmi2SetReal", |component| {
+        if nvr == 0 {
+            return Ok(FMI2_OK);
+        }
+        let value_references = std::slice::from_raw_parts(vr, nvr);
+        let values = std::slice::from_raw_parts(value, nvr);
+        for (value_reference, value) in value_references.iter().zip(values.iter()) {
+            component.instance.try_set_real(*value_reference, *value)?;
+        }
+        Ok(FMI2_OK)
+    })
+}
+
+/// fmi2DoStep, steps always complete synchronously
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate]
+pub unsafe fn do_step<M: Fmi2Model>(c: *mut c_void,
+    _current_communication_point: f64,
+    communication_step_size: f64,
+    _no_set_fmu_state_prior_to_current_point: Fmi2Boolean) -> Fmi2Status {
+    with_component::<M, _>(c, "fmi2DoStep", |component| {
+        component.instance.try_do_step(Time::new::<second>(communication_step_size))?;
+        Ok(FMI2_OK)
+    })
+}
+
+/// fmi2GetRealStatus, only fmi2LastSuccessfulTime is supported
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate], value
+/// must be a valid pointer
+pub unsafe fn get_real_status<M: Fmi2Model>(c: *mut c_void,
+    kind: c_int,
+    value: *mut f64) -> Fmi2Status {
+    with_component::<M, _>(c, "fmi2GetRealStatus", |component| {
+        if kind != FMI2_LAST_SUCCESSFUL_TIME || value.is_null() {
+            return Ok(FMI2_DISCARD);
+        }
+        *value = component.instance.get_current_time().get::<second>();
+        Ok(FMI2_OK)
+    })
+}
+
+/// any fmi2* function for a capability the model does not declare
+///
+/// # Safety
+///
+/// c must be null or a component returned by [instantiate]
+pub unsafe fn unsupported<M: Fmi2Model>(c: *mut c_void,
+    _vr: *const u32,
+    nvr: usize,
+    function_name: &str) -> Fmi2Status {
+    with_component::<M, _>(c, function_name, |_| {
+        Err(TuasLibError::GenericStringError(format!(
+            "not supported by this FMU (called with {} value references)", nvr)))
+    })
+}
+
+/// tuas_plant_create, returns an instance with every parameter and
+/// input at its start value
+pub fn plant_create<M: Fmi2Model>() -> *mut c_void {
+    let instance: Fmi2Instance<M> = Fmi2Instance::new("tuas_plant");
+    Box::into_raw(Box::new(instance)) as *mut c_void
+}
+
+/// runs a closure on a plain C API instance, returns 0 on success
+unsafe fn with_plant<M, F>(plant: *mut c_void, function: F) -> c_int
+where M: Fmi2Model,
+F: FnOnce(&mut Fmi2Instance<M>) -> Result<(), TuasLibError> {
+
+    if plant.is_null() {
+        return 1;
+    }
+    let instance = &mut *(plant as *mut Fmi2Instance<M>);
+
+    match catch_unwind(AssertUnwindSafe(|| function(instance))) {
+        Ok(Ok(())) => 0,
+        _ => 1,
+    }
+}
+
+/// tuas_plant_set, sets a parameter or input by name
+///
+/// # Safety
+///
+/// plant must be null or returned by [plant_create], name must be
+/// null or null terminated
+pub unsafe fn plant_set<M: Fmi2Model>(plant: *mut c_void,
+    name: *const c_char,
+    value: f64) -> c_int {
+    with_plant::<M, _>(plant, |instance| {
+        let value_reference = instance.try_get_value_reference(
+            &try_read_c_string(name)?)?;
+        instance.try_set_real(value_reference, value)
+    })
+}
+
+/// tuas_plant_get, gets any variable by name
+///
+/// # Safety
+///
+/// plant must be null or returned by [plant_create], name must be
+/// null or null terminated, value must be null or valid
+pub unsafe fn plant_get<M: Fmi2Model>(plant: *mut c_void,
+    name: *const c_char,
+    value: *mut f64) -> c_int {
+    with_plant::<M, _>(plant, |instance| {
+        if value.is_null() {
+            return Err(TuasLibError::GenericStringError(
+                "null value pointer".to_owned()));
+        }
+        let value_reference = instance.try_get_value_reference(
+            &try_read_c_string(name)?)?;
+        *value = instance.try_get_real(value_reference)?;
+        Ok(())
+    })
+}
+
+/// tuas_plant_do_step
+///
+/// # Safety
+///
+/// plant must be null or returned by [plant_create]
+pub unsafe fn plant_do_step<M: Fmi2Model>(plant: *mut c_void,
+    timestep_seconds: f64) -> c_int {
+    with_plant::<M, _>(plant, |instance| {
+        instance.try_do_step(Time::new::<second>(timestep_seconds))
+    })
+}
+
+/// tuas_plant_free
+///
+/// # Safety
+///
+/// plant must be null or returned by [plant_create] and not yet
+/// freed
+pub unsafe fn plant_free<M: Fmi2Model>(plant: *mut c_void) {
+    if !plant.is_null() {
+        drop(Box::from_raw(plant as *mut Fmi2Instance<M>));
+    }
+}
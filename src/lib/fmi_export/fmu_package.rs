@@ -0,0 +1,159 @@
+use std::fs;
+use std::path::Path;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::{generate_model_description_xml, Fmi2Model};
+
+/// FMI 2.0 platform folder name for binaries built for this target,
+/// eg. linux64 or win64
+pub fn get_fmi2_platform() -> &'static str {
+    let operating_system = if cfg!(target_os = "windows") {
+        "win"
+    } else if cfg!(target_os = "macos") {
+        "darwin"
+    } else {
+        "linux"
+    };
+
+    match (operating_system, cfg!(target_pointer_width = "64")) {
+        ("win", true) => "win64",
+        ("win", false) => "win32",
+        ("darwin", _) => "darwin64",
+        (_, true) => "linux64",
+        (_, false) => "linux32",
+    }
+}
+
+/// shared library extension for this target, without the dot
+pub fn get_shared_library_extension() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "dll"
+    } else if cfg!(target_os = "macos") {
+        "dylib"
+    } else {
+        "so"
+    }
+}
+
+/// packages a model as an FMI 2.0 co-simulation FMU
+///
+/// the FMU contains the generated modelDescription.xml and the
+/// shared library built with
+/// [export_fmi2_co_simulation](crate::export_fmi2_co_simulation),
+/// renamed to binaries/<platform>/<model identifier>.<extension>
+/// as the standard requires
+pub fn try_write_fmu<M: Fmi2Model, P: AsRef<Path>, Q: AsRef<Path>>(
+    fmu_path: P,
+    model_identifier: &str,
+    shared_library_path: Q) -> Result<(), TuasLibError> {
+
+    let model_description = generate_model_description_xml::<M>(model_identifier);
+
+    let shared_library = fs::read(shared_library_path.as_ref())
+        .map_err(|error| TuasLibError::GenericStringError(
+            format!("could not read {}: {}",
+                shared_library_path.as_ref().display(), error)))?;
+
+    let binary_name = format!("binaries/{}/{}.{}",
+        get_fmi2_platform(), model_identifier, get_shared_library_extension());
+
+    let archive = create_stored_zip_archive(&[
+        ("modelDescription.xml", model_description.as_bytes()),
+        (&binary_name, &shared_library),
+    ]);
+
+    fs::write(fmu_path.as_ref(), archive)
+        .map_err(|error| TuasLibError::GenericStringError(
+            format!("could not write {}: {}", fmu_path.as_ref().display(), error)))
+}
+
+/// CRC-32 (IEEE 802.3) checksum, as used in zip archives
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffff_u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+/// writes a zip archive with the files stored uncompressed, which
+/// every FMU importer can read
+///
+/// file times are set to 1980-01-01 so that the archive only
+/// depends on its contents
+pub fn create_stored_zip_archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+
+    let mut archive: Vec<u8> = vec![];
+    let mut central_directory: Vec<u8> = vec![];
+
+    let push_u16 = |buffer: &mut Vec<u8>, value: u16| {
+        buffer.extend_from_slice(&value.to_le_bytes())
+    };
+    let push_u32 = |buffer: &mut Vec<u8>, value: u32| {
+        buffer.extend_from_slice(&value.to_le_bytes())
+    };
+
+    let version_needed = 20;
+    let dos_time = 0;
+    let dos_date = (1 << 5) | 1;
+
+    for (name, data) in files.iter() {
+
+        let local_header_offset = archive.len() as u32;
+        let checksum = crc32(data);
+        let size = data.len() as u32;
+
+        push_u32(&mut archive, 0x0403_4b50);
+        push_u16(&mut archive, version_needed);
+        push_u16(&mut archive, 0);
+        push_u16(&mut archive, 0);
+        push_u16(&mut archive, dos_time);
+        push_u16(&mut archive, dos_date);
+        push_u32(&mut archive, checksum);
+        push_u32(&mut archive, size);
+        push_u32(&mut archive, size);
+        push_u16(&mut archive, name.len() as u16);
+        push_u16(&mut archive, 0);
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(data);
+
+        push_u32(&mut central_directory, 0x0201_4b50);
+        push_u16(&mut central_directory, version_needed);
+        push_u16(&mut central_directory, version_needed);
+        push_u16(&mut central_directory, 0);
+        push_u16(&mut central_directory, 0);
+        push_u16(&mut central_directory, dos_time);
+        push_u16(&mut central_directory, dos_date);
+        push_u32(&mut central_directory, checksum);
+        push_u32(&mut central_directory, size);
+        push_u32(&mut central_directory, size);
+        push_u16(&mut central_directory, name.len() as u16);
+        push_u16(&mut central_directory, 0);
+        push_u16(&mut central_directory, 0);
+        push_u16(&mut central_directory, 0);
+        push_u16(&mut central_directory, 0);
+        push_u32(&mut central_directory, 0);
+        push_u32(&mut central_directory, local_header_offset);
+        central_directory.extend_from_slice(name.as_bytes());
+    }
+
+    let central_directory_offset = archive.len() as u32;
+    let central_directory_size = central_directory.len() as u32;
+    archive.extend_from_slice(&central_directory);
+
+    push_u32(&mut archive, 0x0605_4b50);
+    push_u16(&mut archive, 0);
+    push_u16(&mut archive, 0);
+    push_u16(&mut archive, files.len() as u16);
+    push_u16(&mut archive, files.len() as u16);
+    push_u32(&mut archive, central_directory_size);
+    push_u32(&mut archive, central_directory_offset);
+    push_u16(&mut archive, 0);
+
+    archive
+}
@@ -0,0 +1,633 @@
+use std::collections::HashMap;
+
+use uom::si::f64::*;
+use uom::si::time::second;
+use uom::ConstZero;
+
+use crate::scenario::ScenarioPlant;
+use crate::tuas_lib_error::TuasLibError;
+
+/// generates the FMI 2.0 modelDescription.xml for a model
+pub mod model_description;
+pub use model_description::*;
+
+/// packages a model description and shared library into an FMU
+pub mod fmu_package;
+pub use fmu_package::*;
+
+/// C types of the FMI 2.0 standard and the generic implementations
+/// of the fmi2* and tuas_plant_* entry points
+pub mod ffi;
+
+/// causality of a model variable, as in the FMI 2.0 standard
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FmiCausality {
+    /// fixed before initialisation, eg. the initial temperature
+    Parameter,
+    /// set by the importing tool every communication step
+    Input,
+    /// computed by the model
+    Output,
+}
+
+impl FmiCausality {
+    /// name of the causality in the model description
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            FmiCausality::Parameter => "parameter",
+            FmiCausality::Input => "input",
+            FmiCausality::Output => "output",
+        }
+    }
+}
+
+/// a real valued model variable exposed by an FMU
+///
+/// value references are the index of the variable in the list
+/// returned by [Fmi2Model::get_model_variables]. Inputs and outputs
+/// use the names of the [ScenarioPlant] interface, parameters are
+/// passed to [Fmi2Model::try_new_from_parameters]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FmiVariable {
+    /// variable name
+    pub name: String,
+    /// causality of the variable
+    pub causality: FmiCausality,
+    /// start value, parameters and inputs must have one
+    pub start: Option<f64>,
+    /// human readable description, including units
+    pub description: String,
+}
+
+impl FmiVariable {
+
+    /// creates a parameter with a start value
+    pub fn new_parameter(name: &str, start: f64, description: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            causality: FmiCausality::Parameter,
+            start: Some(start),
+            description: description.to_owned(),
+        }
+    }
+
+    /// creates an input with a start value
+    pub fn new_input(name: &str, start: f64, description: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            causality: FmiCausality::Input,
+            start: Some(start),
+            description: description.to_owned(),
+        }
+    }
+
+    /// creates an output
+    pub fn new_output(name: &str, description: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            causality: FmiCausality::Output,
+            start: None,
+            description: description.to_owned(),
+        }
+    }
+}
+
+/// a plant which can be exported as an FMI 2.0 co-simulation FMU
+/// or through the plain C API
+///
+/// the plant is driven through its [ScenarioPlant] interface, so
+/// any loop built from components (eg. a CIET or FLiBe loop) which
+/// implements it can be exported with
+/// [export_fmi2_co_simulation](crate::export_fmi2_co_simulation)
+pub trait Fmi2Model: ScenarioPlant + Sized {
+
+    /// model name in the model description
+    fn get_model_name() -> String;
+
+    /// parameters, inputs and outputs of the model
+    fn get_model_variables() -> Vec<FmiVariable>;
+
+    /// constructs the plant once the parameters are fixed, the
+    /// map contains every parameter by name
+    fn try_new_from_parameters(parameters: &HashMap<String, f64>)
+        -> Result<Self, TuasLibError>;
+
+    /// one line description of the model
+    fn get_model_description() -> String {
+        format!("{} exported from tuas_boussinesq_solver", Self::get_model_name())
+    }
+
+    /// communication steps longer than this are split into
+    /// several plant timesteps
+    fn get_maximum_internal_timestep() -> Option<Time> {
+        None
+    }
+
+    /// guid of the model, this changes whenever the model name,
+    /// variables or crate version change so that an FMU cannot be
+    /// used with a mismatched binary
+    fn get_guid() -> String {
+
+        // FNV-1a 64 bit, twice with different offsets for 128 bits
+        let mut text = format!("{}:{}", Self::get_model_name(),
+            env!("CARGO_PKG_VERSION"));
+        for variable in Self::get_model_variables() {
+            text.push_str(&format!(":{}:{}", variable.name,
+                variable.causality.get_name()));
+        }
+
+        let fnv_1a = |offset: u64| {
+            text.bytes().fold(offset, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            })
+        };
+        let high = fnv_1a(0xcbf29ce484222325);
+        let low = fnv_1a(0x84222325cbf29ce4);
+
+        format!("{{{:08x}-{:04x}-{:04x}-{:04x}-{:012x}}}",
+            high >> 32,
+            (high >> 16) & 0xffff,
+            high & 0xffff,
+            low >> 48,
+            low & 0xffff_ffff_ffff)
+    }
+}
+
+/// an instance of an exported model, this holds the parameter and
+/// input values set by the importing tool and constructs the plant
+/// when initialisation is finished
+///
+/// this is the safe part of the FMU, the fmi2* entry points only
+/// convert C arguments and call these methods
+#[derive(Debug)]
+pub struct Fmi2Instance<M: Fmi2Model> {
+    /// instance name given by the importing tool
+    pub instance_name: String,
+
+    variables: Vec<FmiVariable>,
+    values: Vec<f64>,
+    model: Option<M>,
+    current_time: Time,
+}
+
+impl<M: Fmi2Model> Fmi2Instance<M> {
+
+    /// creates an instance with every parameter and input at its
+    /// start value
+    pub fn new(instance_name: &str) -> Self {
+
+        let variables = M::get_model_variables();
+        let values = variables.iter()
+            .map(|variable| variable.start.unwrap_or(0.0))
+            .collect();
+
+        Self {
+            instance_name: instance_name.to_owned(),
+            variables,
+            values,
+            model: None,
+            current_time: Time::ZERO,
+        }
+    }
+
+    /// looks up the value reference of a variable by name
+    pub fn try_get_value_reference(&self, name: &str) -> Result<u32, TuasLibError> {
+        self.variables.iter()
+            .position(|variable| variable.name == name)
+            .map(|index| index as u32)
+            .ok_or(TuasLibError::GenericStringError(
+                format!("{} has no variable named {}", M::get_model_name(), name)))
+    }
+
+    fn try_get_variable(&self, value_reference: u32)
+        -> Result<&FmiVariable, TuasLibError> {
+        self.variables.get(value_reference as usize)
+            .ok_or(TuasLibError::GenericStringError(
+                format!("{} has no value reference {}",
+                    M::get_model_name(), value_reference)))
+    }
+
+    /// whether the plant has been constructed
+    pub fn is_initialized(&self) -> bool {
+        self.model.is_some()
+    }
+
+    /// simulation time at the end of the last communication step
+    pub fn get_current_time(&self) -> Time {
+        self.current_time
+    }
+
+    /// sets the simulation time of the start of the experiment, this
+    /// only affects the reported time and must be done before the
+    /// plant is constructed
+    pub fn try_set_start_time(&mut self, start_time: Time) -> Result<(), TuasLibError> {
+        if self.model.is_some() {
+            return Err(TuasLibError::GenericStringError(
+                "start time cannot be set after initialisation".to_owned()));
+        }
+        self.current_time = start_time;
+        Ok(())
+    }
+
+    /// constructs the plant from the parameters and applies the
+    /// inputs, this does nothing if the plant already exists
+    pub fn try_initialize(&mut self) -> Result<&mut M, TuasLibError> {
+
+        let model = match self.model.take() {
+            Some(model) => model,
+            None => self.try_construct_model()?,
+        };
+
+        Ok(self.model.insert(model))
+    }
+
+    fn try_construct_model(&self) -> Result<M, TuasLibError> {
+
+        let mut parameters = HashMap::new();
+        for (variable, value) in self.variables.iter().zip(self.values.iter()) {
+            if variable.causality == FmiCausality::Parameter {
+                parameters.insert(variable.name.clone(), *value);
+            }
+        }
+
+        let mut model = M::try_new_from_parameters(&parameters)?;
+        for (variable, value) in self.variables.iter().zip(self.values.iter()) {
+            if variable.causality == FmiCausality::Input {
+                model.try_set_input(&variable.name, *value)?;
+            }
+        }
+
+        Ok(model)
+    }
+
+    /// sets a parameter or input, parameters cannot be changed
+    /// once the plant is constructed
+    pub fn try_set_real(&mut self, value_reference: u32, value: f64)
+        -> Result<(), TuasLibError> {
+
+        let variable = self.try_get_variable(value_reference)?.clone();
+
+        match variable.causality {
+            FmiCausality::Parameter => {
+                if self.model.is_some() {
+                    return Err(TuasLibError::GenericStringError(
+                        format!("parameter {} cannot be set after initialisation",
+                            variable.name)));
+                }
+            },
+            FmiCausality::Input => {
+                if let Some(model) = self.model.as_mut() {
+                    model.try_set_input(&variable.name, value)?;
+                }
+            },
+            FmiCausality::Output => {
+                return Err(TuasLibError::GenericStringError(
+                    format!("output {} cannot be set", variable.name)));
+            },
+        }
+
+        self.values[value_reference as usize] = value;
+        Ok(())
+    }
+
+    /// gets any variable, getting an output constructs the plant
+    /// if needed
+    pub fn try_get_real(&mut self, value_reference: u32) -> Result<f64, TuasLibError> {
+
+        let variable = self.try_get_variable(value_reference)?.clone();
+
+        match variable.causality {
+            FmiCausality::Parameter | FmiCausality::Input => {
+                Ok(self.values[value_reference as usize])
+            },
+            FmiCausality::Output => {
+                self.try_initialize()?.try_get_output(&variable.name)
+            },
+        }
+    }
+
+    /// advances the plant over one communication step, which is
+    /// split into equal timesteps no longer than the maximum
+    /// internal timestep
+    pub fn try_do_step(&mut self, communication_step_size: Time)
+        -> Result<(), TuasLibError> {
+
+        let step_seconds = communication_step_size.get::<second>();
+        if !(step_seconds > 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                "communication step size must be positive".to_owned()));
+        }
+
+        let number_of_timesteps = match M::get_maximum_internal_timestep() {
+            Some(maximum_timestep) => {
+                (step_seconds/maximum_timestep.get::<second>() - 1e-9).ceil().max(1.0) as usize
+            },
+            None => 1,
+        };
+        let timestep = communication_step_size/(number_of_timesteps as f64);
+
+        let model = self.try_initialize()?;
+        for _ in 0..number_of_timesteps {
+            model.try_advance_timestep(timestep)?;
+        }
+
+        self.current_time += communication_step_size;
+        Ok(())
+    }
+
+    /// discards the plant and returns every parameter and input to
+    /// its start value
+    pub fn reset(&mut self) {
+        *self = Self::new(&self.instance_name);
+    }
+}
+
+/// exports a model implementing [Fmi2Model] through the FMI 2.0
+/// co-simulation entry points (fmi2Instantiate, fmi2DoStep, ...) and
+/// a plain C API, for use in a crate built as a cdylib
+///
+/// ```rust,ignore
+/// use tuas_boussinesq_solver::pre_built_components::
+///     ciet_three_branch_plus_dracs::ciet_plant::CIETPlant;
+///
+/// tuas_boussinesq_solver::export_fmi2_co_simulation!(CIETPlant);
+/// ```
+///
+/// the plain C API is
+///
+/// ```c
+/// void* tuas_plant_create(void);
+/// int tuas_plant_set(void* plant, const char* name, double value);
+/// int tuas_plant_get(void* plant, const char* name, double* value);
+/// int tuas_plant_do_step(void* plant, double timestep_seconds);
+/// void tuas_plant_free(void* plant);
+/// ```
+///
+/// where names are those of the model variables, values are in SI
+/// units, and functions return 0 on success. Parameters may only be
+/// set before the first step or output is read
+///
+/// only one model may be exported per shared library, since the
+/// entry point names are fixed by the standard
+#[macro_export]
+macro_rules! export_fmi2_co_simulation {
+    ($model:ty) => {
+
+        mod fmi2_co_simulation_entry_points {
+            #![allow(non_snake_case)]
+            #![allow(clippy::missing_safety_doc)]
+
+            use super::*;
+            use std::os::raw::{c_char, c_int, c_void};
+            use $crate::fmi_export::ffi::*;
+
+            #[no_mangle]
+            pub extern "C" fn fmi2GetTypesPlatform() -> *const c_char {
+                get_types_platform()
+            }
+
+            #[no_mangle]
+            pub extern "C" fn fmi2GetVersion() -> *const c_char {
+                get_version()
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2SetDebugLogging(c: *mut c_void,
+                logging_on: Fmi2Boolean, n_categories: usize,
+                categories: *const *const c_char) -> Fmi2Status {
+                set_debug_logging::<$model>(c, logging_on, n_categories, categories)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2Instantiate(instance_name: *const c_char,
+                fmu_type: Fmi2Type, fmu_guid: *const c_char,
+                fmu_resource_location: *const c_char,
+                functions: *const Fmi2CallbackFunctions,
+                visible: Fmi2Boolean, logging_on: Fmi2Boolean) -> *mut c_void {
+                instantiate::<$model>(instance_name, fmu_type, fmu_guid,
+                    fmu_resource_location, functions, visible, logging_on)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2FreeInstance(c: *mut c_void) {
+                free_instance::<$model>(c)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2SetupExperiment(c: *mut c_void,
+                tolerance_defined: Fmi2Boolean, tolerance: f64,
+                start_time: f64, stop_time_defined: Fmi2Boolean,
+                stop_time: f64) -> Fmi2Status {
+                setup_experiment::<$model>(c, tolerance_defined, tolerance,
+                    start_time, stop_time_defined, stop_time)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2EnterInitializationMode(c: *mut c_void)
+                -> Fmi2Status {
+                enter_initialization_mode::<$model>(c)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2ExitInitializationMode(c: *mut c_void)
+                -> Fmi2Status {
+                exit_initialization_mode::<$model>(c)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2Terminate(c: *mut c_void) -> Fmi2Status {
+                terminate::<$model>(c)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2Reset(c: *mut c_void) -> Fmi2Status {
+                reset::<$model>(c)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetReal(c: *mut c_void,
+                vr: *const u32, nvr: usize, value: *mut f64) -> Fmi2Status {
+                get_real::<$model>(c, vr, nvr, value)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2SetReal(c: *mut c_void,
+                vr: *const u32, nvr: usize, value: *const f64) -> Fmi2Status {
+                set_real::<$model>(c, vr, nvr, value)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetInteger(c: *mut c_void,
+                vr: *const u32, nvr: usize, _value: *mut c_int) -> Fmi2Status {
+                unsupported::<$model>(c, vr, nvr, "fmi2GetInteger")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2SetInteger(c: *mut c_void,
+                vr: *const u32, nvr: usize, _value: *const c_int) -> Fmi2Status {
+                unsupported::<$model>(c, vr, nvr, "fmi2SetInteger")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetBoolean(c: *mut c_void,
+                vr: *const u32, nvr: usize, _value: *mut Fmi2Boolean) -> Fmi2Status {
+                unsupported::<$model>(c, vr, nvr, "fmi2GetBoolean")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2SetBoolean(c: *mut c_void,
+                vr: *const u32, nvr: usize, _value: *const Fmi2Boolean) -> Fmi2Status {
+                unsupported::<$model>(c, vr, nvr, "fmi2SetBoolean")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetString(c: *mut c_void,
+                vr: *const u32, nvr: usize, _value: *mut *const c_char) -> Fmi2Status {
+                unsupported::<$model>(c, vr, nvr, "fmi2GetString")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2SetString(c: *mut c_void,
+                vr: *const u32, nvr: usize, _value: *const *const c_char) -> Fmi2Status {
+                unsupported::<$model>(c, vr, nvr, "fmi2SetString")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetFMUstate(c: *mut c_void,
+                _state: *mut *mut c_void) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0, "fmi2GetFMUstate")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2SetFMUstate(c: *mut c_void,
+                _state: *mut c_void) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0, "fmi2SetFMUstate")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2FreeFMUstate(c: *mut c_void,
+                _state: *mut *mut c_void) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0, "fmi2FreeFMUstate")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2SerializedFMUstateSize(c: *mut c_void,
+                _state: *mut c_void, _size: *mut usize) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0, "fmi2SerializedFMUstateSize")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2SerializeFMUstate(c: *mut c_void,
+                _state: *mut c_void, _serialized_state: *mut c_char,
+                _size: usize) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0, "fmi2SerializeFMUstate")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2DeSerializeFMUstate(c: *mut c_void,
+                _serialized_state: *const c_char, _size: usize,
+                _state: *mut *mut c_void) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0, "fmi2DeSerializeFMUstate")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetDirectionalDerivative(c: *mut c_void,
+                _unknown_refs: *const u32, _n_unknown: usize,
+                _known_refs: *const u32, _n_known: usize,
+                _known_deltas: *const f64, _unknown_deltas: *mut f64) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0,
+                    "fmi2GetDirectionalDerivative")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2SetRealInputDerivatives(c: *mut c_void,
+                vr: *const u32, nvr: usize, _order: *const c_int,
+                _value: *const f64) -> Fmi2Status {
+                unsupported::<$model>(c, vr, nvr, "fmi2SetRealInputDerivatives")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetRealOutputDerivatives(c: *mut c_void,
+                vr: *const u32, nvr: usize, _order: *const c_int,
+                _value: *mut f64) -> Fmi2Status {
+                unsupported::<$model>(c, vr, nvr, "fmi2GetRealOutputDerivatives")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2DoStep(c: *mut c_void,
+                current_communication_point: f64,
+                communication_step_size: f64,
+                no_set_fmu_state_prior_to_current_point: Fmi2Boolean) -> Fmi2Status {
+                do_step::<$model>(c, current_communication_point,
+                    communication_step_size, no_set_fmu_state_prior_to_current_point)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2CancelStep(c: *mut c_void) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0, "fmi2CancelStep")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetStatus(c: *mut c_void,
+                _kind: c_int, _value: *mut Fmi2Status) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0, "fmi2GetStatus")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetRealStatus(c: *mut c_void,
+                kind: c_int, value: *mut f64) -> Fmi2Status {
+                get_real_status::<$model>(c, kind, value)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetIntegerStatus(c: *mut c_void,
+                _kind: c_int, _value: *mut c_int) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0, "fmi2GetIntegerStatus")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetBooleanStatus(c: *mut c_void,
+                _kind: c_int, _value: *mut Fmi2Boolean) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0, "fmi2GetBooleanStatus")
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn fmi2GetStringStatus(c: *mut c_void,
+                _kind: c_int, _value: *mut *const c_char) -> Fmi2Status {
+                unsupported::<$model>(c, std::ptr::null(), 0, "fmi2GetStringStatus")
+            }
+
+            #[no_mangle]
+            pub extern "C" fn tuas_plant_create() -> *mut c_void {
+                plant_create::<$model>()
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn tuas_plant_set(plant: *mut c_void,
+                name: *const c_char, value: f64) -> c_int {
+                plant_set::<$model>(plant, name, value)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn tuas_plant_get(plant: *mut c_void,
+                name: *const c_char, value: *mut f64) -> c_int {
+                plant_get::<$model>(plant, name, value)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn tuas_plant_do_step(plant: *mut c_void,
+                timestep_seconds: f64) -> c_int {
+                plant_do_step::<$model>(plant, timestep_seconds)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn tuas_plant_free(plant: *mut c_void) {
+                plant_free::<$model>(plant)
+            }
+        }
+    };
+}
+
+/// tests for model descriptions, packaging and instances
+#[cfg(test)]
+pub mod tests;
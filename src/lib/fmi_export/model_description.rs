@@ -0,0 +1,116 @@
+use uom::si::time::second;
+
+use super::{FmiCausality, Fmi2Model};
+
+/// escapes the characters which cannot appear in XML attributes
+fn escape_xml_attribute(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+/// generates the FMI 2.0 co-simulation modelDescription.xml of a
+/// model
+///
+/// the model identifier must be the file name of the shared library
+/// without its extension (or lib prefix), eg. ciet_fmu for
+/// libciet_fmu.so
+///
+/// all variables are reals, value references are their index in
+/// [Fmi2Model::get_model_variables], parameters are fixed, and
+/// inputs and outputs are continuous
+pub fn generate_model_description_xml<M: Fmi2Model>(model_identifier: &str) -> String {
+
+    let variables = M::get_model_variables();
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<fmiModelDescription fmiVersion=\"2.0\" modelName=\"{}\" guid=\"{}\" \
+        description=\"{}\" generationTool=\"tuas_boussinesq_solver {}\" \
+        variableNamingConvention=\"flat\" numberOfEventIndicators=\"0\">\n",
+        escape_xml_attribute(&M::get_model_name()),
+        escape_xml_attribute(&M::get_guid()),
+        escape_xml_attribute(&M::get_model_description()),
+        env!("CARGO_PKG_VERSION")));
+
+    xml.push_str(&format!(
+        "  <CoSimulation modelIdentifier=\"{}\" \
+        canHandleVariableCommunicationStepSize=\"true\" \
+        canInterpolateInputs=\"false\" \
+        canRunAsynchronuously=\"false\" \
+        canBeInstantiatedOnlyOncePerProcess=\"false\" \
+        canNotUseMemoryManagementFunctions=\"true\" \
+        canGetAndSetFMUstate=\"false\" \
+        canSerializeFMUstate=\"false\" \
+        providesDirectionalDerivative=\"false\"/>\n",
+        escape_xml_attribute(model_identifier)));
+
+    if let Some(maximum_timestep) = M::get_maximum_internal_timestep() {
+        xml.push_str(&format!(
+            "  <DefaultExperiment startTime=\"0.0\" stepSize=\"{:?}\"/>\n",
+            maximum_timestep.get::<second>()));
+    }
+
+    xml.push_str("  <ModelVariables>\n");
+    for (value_reference, variable) in variables.iter().enumerate() {
+
+        let (variability, initial) = match variable.causality {
+            FmiCausality::Parameter => ("fixed", Some("exact")),
+            FmiCausality::Input => ("continuous", None),
+            FmiCausality::Output => ("continuous", Some("calculated")),
+        };
+
+        xml.push_str(&format!(
+            "    <ScalarVariable name=\"{}\" valueReference=\"{}\" \
+            description=\"{}\" causality=\"{}\" variability=\"{}\"",
+            escape_xml_attribute(&variable.name),
+            value_reference,
+            escape_xml_attribute(&variable.description),
+            variable.causality.get_name(),
+            variability));
+        if let Some(initial) = initial {
+            xml.push_str(&format!(" initial=\"{}\"", initial));
+        }
+        xml.push_str(">\n");
+
+        match variable.start {
+            Some(start) => xml.push_str(&format!("      <Real start=\"{:?}\"/>\n", start)),
+            None => xml.push_str("      <Real/>\n"),
+        }
+        xml.push_str("    </ScalarVariable>\n");
+    }
+    xml.push_str("  </ModelVariables>\n");
+
+    // indices in the model structure start from one
+    let output_indices: Vec<usize> = variables.iter()
+        .enumerate()
+        .filter(|(_, variable)| variable.causality == FmiCausality::Output)
+        .map(|(value_reference, _)| value_reference + 1)
+        .collect();
+
+    xml.push_str("  <ModelStructure>\n");
+    for section in ["Outputs", "InitialUnknowns"] {
+        if output_indices.is_empty() {
+            continue;
+        }
+        xml.push_str(&format!("    <{}>\n", section));
+        for index in output_indices.iter() {
+            xml.push_str(&format!("      <Unknown index=\"{}\"/>\n", index));
+        }
+        xml.push_str(&format!("    </{}>\n", section));
+    }
+    xml.push_str("  </ModelStructure>\n");
+    xml.push_str("</fmiModelDescription>\n");
+
+    xml
+}
@@ -0,0 +1,200 @@
+use std::ffi::CString;
+
+use uom::si::f64::*;
+use uom::si::time::second;
+
+use crate::scenario::test_plants::HeatedTank;
+
+use super::ffi::*;
+use super::*;
+
+/// the model description lists every variable with its value
+/// reference, escapes the model name and lists the outputs in the
+/// model structure with one based indices
+#[test]
+pub fn model_description_lists_variables(){
+
+    let xml = generate_model_description_xml::<HeatedTank>("heated_tank");
+
+    assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    assert!(xml.contains("fmiVersion=\"2.0\""));
+    assert!(xml.contains("modelName=\"Heated &lt;Tank&gt;\""));
+    assert!(xml.contains(&format!("guid=\"{}\"", HeatedTank::get_guid())));
+    assert!(xml.contains("<CoSimulation modelIdentifier=\"heated_tank\""));
+    assert!(xml.contains("<DefaultExperiment startTime=\"0.0\" stepSize=\"0.5\"/>"));
+
+    assert!(xml.contains("<ScalarVariable name=\"initial_temperature\" valueReference=\"0\" \
+        description=\"(K)\" causality=\"parameter\" variability=\"fixed\" initial=\"exact\">"));
+    assert!(xml.contains("<ScalarVariable name=\"heater_power\" valueReference=\"1\""));
+    assert!(xml.contains("<ScalarVariable name=\"number_of_timesteps\" valueReference=\"3\""));
+    assert!(xml.contains("<Real start=\"300.0\"/>"));
+
+    assert_eq!(xml.matches("<Unknown index=\"3\"/>").count(), 2);
+    assert_eq!(xml.matches("<Unknown index=\"4\"/>").count(), 2);
+    assert!(!xml.contains("<Unknown index=\"2\"/>"));
+
+    // guids look like guids and are stable
+    let guid = HeatedTank::get_guid();
+    assert_eq!(guid.len(), 38);
+    assert_eq!(guid, HeatedTank::get_guid());
+}
+
+/// parameters are fixed once the plant is constructed, inputs set
+/// before construction are applied to the plant, and communication
+/// steps are split into timesteps no longer than the maximum
+#[test]
+pub fn instance_initialisation_and_stepping(){
+
+    let mut instance: Fmi2Instance<HeatedTank> = Fmi2Instance::new("tank");
+
+    let initial_temperature = instance.try_get_value_reference("initial_temperature").unwrap();
+    let heater_power = instance.try_get_value_reference("heater_power").unwrap();
+    let temperature = instance.try_get_value_reference("temperature").unwrap();
+    let number_of_timesteps = instance.try_get_value_reference("number_of_timesteps").unwrap();
+    assert!(instance.try_get_value_reference("pump_pressure").is_err());
+
+    instance.try_set_real(initial_temperature, 350.0).unwrap();
+    instance.try_set_real(heater_power, 500.0).unwrap();
+    assert!(instance.try_set_real(temperature, 1.0).is_err());
+    assert!(!instance.is_initialized());
+
+    // reading an output constructs the plant
+    assert_eq!(instance.try_get_real(temperature).unwrap(), 350.0);
+    assert!(instance.is_initialized());
+    assert!(instance.try_set_real(initial_temperature, 300.0).is_err());
+
+    // 1.2 s is three timesteps of 0.4 s
+    instance.try_do_step(Time::new::<second>(1.2)).unwrap();
+    assert_eq!(instance.try_get_real(number_of_timesteps).unwrap(), 3.0);
+    approx::assert_relative_eq!(instance.get_current_time().get::<second>(), 1.2);
+
+    // steady state at 300 K + 500 W/(10 W/K)
+    for _ in 0..2000 {
+        instance.try_do_step(Time::new::<second>(1.0)).unwrap();
+    }
+    approx::assert_relative_eq!(instance.try_get_real(temperature).unwrap(),
+        350.0, max_relative=1e-9);
+
+    instance.reset();
+    assert!(!instance.is_initialized());
+    assert_eq!(instance.try_get_real(initial_temperature).unwrap(), 300.0);
+    assert_eq!(instance.try_get_real(heater_power).unwrap(), 0.0);
+}
+
+/// the generic fmi2 entry points check the fmu type and guid, and
+/// set, step and get through C pointers
+#[test]
+pub fn fmi2_entry_points_round_trip(){
+
+    let instance_name = CString::new("tank").unwrap();
+    let guid = CString::new(HeatedTank::get_guid()).unwrap();
+    let wrong_guid = CString::new("{not-the-guid}").unwrap();
+
+    unsafe {
+        let wrong_guid_component = instantiate::<HeatedTank>(instance_name.as_ptr(),
+            FMI2_CO_SIMULATION, wrong_guid.as_ptr(), std::ptr::null(),
+            std::ptr::null(), 0, 0);
+        assert!(wrong_guid_component.is_null());
+
+        let model_exchange_component = instantiate::<HeatedTank>(instance_name.as_ptr(),
+            FMI2_MODEL_EXCHANGE, guid.as_ptr(), std::ptr::null(),
+            std::ptr::null(), 0, 0);
+        assert!(model_exchange_component.is_null());
+
+        let c = instantiate::<HeatedTank>(instance_name.as_ptr(),
+            FMI2_CO_SIMULATION, guid.as_ptr(), std::ptr::null(),
+            std::ptr::null(), 0, 0);
+        assert!(!c.is_null());
+
+        assert_eq!(setup_experiment::<HeatedTank>(c, 0, 0.0, 10.0, 0, 0.0), FMI2_OK);
+        assert_eq!(enter_initialization_mode::<HeatedTank>(c), FMI2_OK);
+        let value_references = [0, 1];
+        let values = [320.0, 1000.0];
+        assert_eq!(set_real::<HeatedTank>(c, value_references.as_ptr(), 2,
+            values.as_ptr()), FMI2_OK);
+        assert_eq!(exit_initialization_mode::<HeatedTank>(c), FMI2_OK);
+
+        assert_eq!(do_step::<HeatedTank>(c, 10.0, 2.0, 1), FMI2_OK);
+
+        let output_references = [2, 3];
+        let mut outputs = [0.0; 2];
+        assert_eq!(get_real::<HeatedTank>(c, output_references.as_ptr(), 2,
+            outputs.as_mut_ptr()), FMI2_OK);
+        assert!(outputs[0] > 320.0);
+        assert_eq!(outputs[1], 4.0);
+
+        let mut last_successful_time = 0.0;
+        assert_eq!(get_real_status::<HeatedTank>(c, FMI2_LAST_SUCCESSFUL_TIME,
+            &mut last_successful_time), FMI2_OK);
+        approx::assert_relative_eq!(last_successful_time, 12.0);
+
+        // unknown value references and unsupported functions fail
+        // without panicking
+        let bad_reference = [17];
+        assert_eq!(get_real::<HeatedTank>(c, bad_reference.as_ptr(), 1,
+            outputs.as_mut_ptr()), FMI2_ERROR);
+        assert_eq!(unsupported::<HeatedTank>(c, std::ptr::null(), 0,
+            "fmi2GetFMUstate"), FMI2_ERROR);
+        assert_eq!(do_step::<HeatedTank>(std::ptr::null_mut(), 0.0, 1.0, 1), FMI2_ERROR);
+
+        assert_eq!(terminate::<HeatedTank>(c), FMI2_OK);
+        free_instance::<HeatedTank>(c);
+    }
+}
+
+/// the plain C API sets and gets variables by name
+#[test]
+pub fn plain_c_api_round_trip(){
+
+    let heater_power = CString::new("heater_power").unwrap();
+    let temperature = CString::new("temperature").unwrap();
+    let unknown = CString::new("pump_pressure").unwrap();
+
+    unsafe {
+        let plant = plant_create::<HeatedTank>();
+
+        assert_eq!(plant_set::<HeatedTank>(plant, heater_power.as_ptr(), 100.0), 0);
+        assert_eq!(plant_set::<HeatedTank>(plant, unknown.as_ptr(), 100.0), 1);
+        assert_eq!(plant_do_step::<HeatedTank>(plant, 1.0), 0);
+        assert_eq!(plant_do_step::<HeatedTank>(plant, -1.0), 1);
+
+        let mut value = 0.0;
+        assert_eq!(plant_get::<HeatedTank>(plant, temperature.as_ptr(), &mut value), 0);
+        // two explicit timesteps of 0.5 s
+        let first_temperature = 300.0 + 0.5 * 100.0/1000.0;
+        let second_temperature = first_temperature
+            + 0.5 * (100.0 - 10.0 * (first_temperature - 300.0))/1000.0;
+        approx::assert_relative_eq!(value, second_temperature, max_relative=1e-12);
+
+        plant_free::<HeatedTank>(plant);
+    }
+}
+
+/// the zip writer uses the standard CRC-32 and lays out the local
+/// headers, central directory and end record so that the stored
+/// files can be found again
+#[test]
+pub fn stored_zip_archive_layout(){
+
+    assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    assert_eq!(crc32(b""), 0);
+
+    let archive = create_stored_zip_archive(&[
+        ("modelDescription.xml", b"<xml/>"),
+        ("binaries/linux64/model.so", b"\x7fELF"),
+    ]);
+
+    assert_eq!(&archive[0..4], b"PK\x03\x04");
+    // local header is 30 bytes followed by the name and data
+    assert_eq!(&archive[30..50], b"modelDescription.xml");
+    assert_eq!(&archive[50..56], b"<xml/>");
+
+    let end_record = &archive[archive.len() - 22..];
+    assert_eq!(&end_record[0..4], b"PK\x05\x06");
+    assert_eq!(u16::from_le_bytes([end_record[10], end_record[11]]), 2);
+
+    let central_directory_offset = u32::from_le_bytes(
+        [end_record[16], end_record[17], end_record[18], end_record[19]]) as usize;
+    assert_eq!(&archive[central_directory_offset..central_directory_offset + 4],
+        b"PK\x01\x02");
+}
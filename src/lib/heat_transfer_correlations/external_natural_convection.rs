@@ -0,0 +1,303 @@
+//! this module contains a heat transfer coefficient model for
+//! external natural convection (and optionally radiation)
+//! from pipes and components to ambient air
+//!
+//! this is an alternative to specifying a constant heat transfer
+//! coefficient to ambient. The heat transfer coefficient is
+//! recalculated every timestep from the surface temperature,
+//! ambient temperature and the component geometry
+//!
+//! air properties are evaluated at the film temperature,
+//! T_film = (T_surface + T_ambient)/2
+//!
+//! using LiquidMaterial::Air (an ideal gas from 250 K to 1000 K),
+//! and the thermal expansion coefficient for air is taken as
+//! that of an ideal gas, beta = 1/T_film
+//!
+//! Incropera, F. P., DeWitt, D. P., Bergman, T. L., & Lavine, A. S.
+//! (1996). Fundamentals of heat and mass transfer (Vol. 6, p. 116).
+//! New York: Wiley.
+use uom::si::f64::*;
+use uom::si::area::square_meter;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::diffusion_coefficient::square_meter_per_second;
+use uom::si::length::meter;
+use uom::si::pressure::atmosphere;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::kelvin;
+
+use crate::boussinesq_thermophysical_properties::{LiquidMaterial, Material};
+use crate::boussinesq_thermophysical_properties::density::try_get_rho;
+use crate::boussinesq_thermophysical_properties::dynamic_viscosity::try_get_mu_viscosity;
+use crate::boussinesq_thermophysical_properties::specific_heat_capacity::try_get_cp;
+use crate::boussinesq_thermophysical_properties::thermal_conductivity::try_get_kappa_thermal_conductivity;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::heat_transfer_interactions::simple_radiation_conductance;
+use super::nusselt_number_correlations::natural_convection_correlations::*;
+
+/// the correlation used to obtain the nusselt number for
+/// natural convection from the outer surface of a component
+/// to ambient air
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum ExternalNaturalConvectionCorrelation {
+    /// Churchill and Chu correlation for horizontal cylinders,
+    /// characteristic length is the outer diameter
+    ChurchillChuHorizontalCylinder,
+    /// Morgan correlation for horizontal cylinders,
+    /// characteristic length is the outer diameter
+    MorganHorizontalCylinder,
+    /// Churchill and Chu correlation for vertical plates applied
+    /// to vertical cylinders,
+    /// characteristic length is the cylinder height
+    ///
+    /// valid only if D/L >= 35/Gr_L^(1/4)
+    ChurchillChuVerticalCylinder,
+}
+
+/// natural convection (and optionally radiation) from the
+/// outer surface of a component to ambient air
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct AmbientNaturalConvection {
+    /// nusselt number correlation used
+    pub correlation: ExternalNaturalConvectionCorrelation,
+
+    /// outer diameter for horizontal cylinders,
+    /// height for vertical cylinders
+    pub characteristic_length: Length,
+
+    /// emissivity of the outer surface,
+    /// if None, radiation heat transfer is neglected
+    pub surface_emissivity: Option<Ratio>,
+
+    /// ambient air pressure
+    pub ambient_pressure: Pressure,
+}
+
+impl AmbientNaturalConvection {
+
+    /// natural convection from a horizontal cylinder to air at
+    /// 1 atm using the Churchill and Chu correlation
+    pub fn new_horizontal_cylinder(outer_diameter: Length,
+        surface_emissivity: Option<Ratio>) -> Self {
+        Self {
+            correlation: ExternalNaturalConvectionCorrelation::
+                ChurchillChuHorizontalCylinder,
+            characteristic_length: outer_diameter,
+            surface_emissivity,
+            ambient_pressure: Pressure::new::<atmosphere>(1.0),
+        }
+    }
+
+    /// natural convection from a vertical cylinder to air at
+    /// 1 atm using the Churchill and Chu correlation
+    pub fn new_vertical_cylinder(cylinder_height: Length,
+        surface_emissivity: Option<Ratio>) -> Self {
+        Self {
+            correlation: ExternalNaturalConvectionCorrelation::
+                ChurchillChuVerticalCylinder,
+            characteristic_length: cylinder_height,
+            surface_emissivity,
+            ambient_pressure: Pressure::new::<atmosphere>(1.0),
+        }
+    }
+
+    /// obtains the Rayleigh number and Prandtl number of the air
+    /// film at the surface
+    ///
+    /// Ra = g beta |T_surface - T_ambient| L^3 / (nu alpha)
+    pub fn try_get_rayleigh_and_prandtl_number(&self,
+        surface_temperature: ThermodynamicTemperature,
+        ambient_temperature: ThermodynamicTemperature,
+    ) -> Result<(Ratio, Ratio), TuasLibError> {
+
+        let film_temperature = get_film_temperature(
+            surface_temperature, ambient_temperature);
+        let air: Material = LiquidMaterial::Air.into();
+
+        let rho: MassDensity = try_get_rho(
+            air, film_temperature, self.ambient_pressure)?;
+        let mu: DynamicViscosity = try_get_mu_viscosity(
+            air, film_temperature, self.ambient_pressure)?;
+        let k: ThermalConductivity = try_get_kappa_thermal_conductivity(
+            air, film_temperature, self.ambient_pressure)?;
+        let cp: SpecificHeatCapacity = try_get_cp(
+            air, film_temperature, self.ambient_pressure)?;
+
+        let nu: DiffusionCoefficient = mu/rho;
+        let alpha: DiffusionCoefficient = k/(rho * cp);
+
+        // ideal gas, beta = 1/T_film
+        let beta: f64 = 1.0/film_temperature.get::<kelvin>();
+        // gravitational acceleration, m/s^2
+        let g: f64 = 9.81;
+
+        let temperature_difference: f64 = 
+            (surface_temperature.get::<kelvin>() 
+             - ambient_temperature.get::<kelvin>()).abs();
+
+        let mut rayleigh_number = g * beta * temperature_difference
+            * self.characteristic_length.get::<meter>().powi(3) 
+            / (nu.get::<square_meter_per_second>() 
+                * alpha.get::<square_meter_per_second>());
+
+        // the lower bound of the Morgan correlation, this avoids
+        // a zero heat transfer coefficient when the surface and
+        // ambient are at the same temperature
+        if rayleigh_number < 1.0e-10 {
+            rayleigh_number = 1.0e-10;
+        }
+
+        let prandtl_number: Ratio = mu * cp / k;
+
+        Ok((Ratio::new::<ratio>(rayleigh_number),
+            prandtl_number))
+    }
+
+    /// obtains the natural convection heat transfer coefficient
+    /// from the surface to ambient air
+    pub fn try_get_convection_heat_transfer_coefficient(&self,
+        surface_temperature: ThermodynamicTemperature,
+        ambient_temperature: ThermodynamicTemperature,
+    ) -> Result<HeatTransfer, TuasLibError> {
+
+        let (rayleigh_number, prandtl_number) =
+            self.try_get_rayleigh_and_prandtl_number(
+                surface_temperature, ambient_temperature)?;
+
+        let rayleigh_number = rayleigh_number.get::<ratio>();
+        let prandtl_number = prandtl_number.get::<ratio>();
+
+        let nusselt_number: f64 = match self.correlation {
+            ExternalNaturalConvectionCorrelation::
+                ChurchillChuHorizontalCylinder => {
+                churchill_chu_horizontal_cylinder(
+                    rayleigh_number, prandtl_number)
+            },
+            ExternalNaturalConvectionCorrelation::
+                MorganHorizontalCylinder => {
+                morgan_horizontal_cylinder(rayleigh_number)
+            },
+            ExternalNaturalConvectionCorrelation::
+                ChurchillChuVerticalCylinder => {
+                churchill_chu_vertical_plate(
+                    rayleigh_number, prandtl_number)
+            },
+        };
+
+        let film_temperature = get_film_temperature(
+            surface_temperature, ambient_temperature);
+        let k: ThermalConductivity = try_get_kappa_thermal_conductivity(
+            LiquidMaterial::Air.into(), film_temperature, self.ambient_pressure)?;
+
+        let h: HeatTransfer = nusselt_number * k 
+            / self.characteristic_length;
+
+        Ok(h)
+    }
+
+    /// obtains the linearised radiation heat transfer coefficient
+    /// from the surface to the surroundings, which are assumed to
+    /// be at ambient temperature and much larger than the surface
+    ///
+    /// h_rad = epsilon sigma (T_s^2 + T_amb^2)(T_s + T_amb)
+    ///
+    /// if no emissivity is given, this returns zero
+    pub fn get_radiation_heat_transfer_coefficient(&self,
+        surface_temperature: ThermodynamicTemperature,
+        ambient_temperature: ThermodynamicTemperature,
+    ) -> HeatTransfer {
+
+        let emissivity: Ratio = match self.surface_emissivity {
+            Some(emissivity) => emissivity,
+            None => {
+                return HeatTransfer::new::<watt_per_square_meter_kelvin>(0.0);
+            },
+        };
+
+        let unit_area = Area::new::<square_meter>(1.0);
+
+        let radiation_conductance_per_unit_area: ThermalConductance =
+            simple_radiation_conductance(
+                unit_area * emissivity,
+                surface_temperature,
+                ambient_temperature);
+
+        radiation_conductance_per_unit_area / unit_area
+    }
+
+    /// obtains the total heat transfer coefficient to ambient,
+    /// which is the sum of the natural convection and radiation
+    /// heat transfer coefficients
+    pub fn try_get_heat_transfer_coefficient(&self,
+        surface_temperature: ThermodynamicTemperature,
+        ambient_temperature: ThermodynamicTemperature,
+    ) -> Result<HeatTransfer, TuasLibError> {
+
+        let h_convection = self.try_get_convection_heat_transfer_coefficient(
+            surface_temperature, ambient_temperature)?;
+
+        let h_radiation = self.get_radiation_heat_transfer_coefficient(
+            surface_temperature, ambient_temperature);
+
+        Ok(h_convection + h_radiation)
+    }
+}
+
+/// film temperature, T_film = (T_surface + T_ambient)/2
+#[inline]
+fn get_film_temperature(surface_temperature: ThermodynamicTemperature,
+    ambient_temperature: ThermodynamicTemperature) -> ThermodynamicTemperature {
+
+    ThermodynamicTemperature::new::<kelvin>(
+        0.5 * (surface_temperature.get::<kelvin>() 
+            + ambient_temperature.get::<kelvin>()))
+}
+
+/// Example 9.3 from Incropera,
+///
+/// a 0.1 m diameter horizontal steam pipe with surface temperature
+/// 165 C in air at 23 C. The textbook answer is Nu_D = 23.1,
+/// h = 7.2 W/(m^2 K) for convection, and the radiation heat loss
+/// for an emissivity of 0.85 corresponds to h_rad = 9.9 W/(m^2 K)
+///
+/// Incropera, F. P., DeWitt, D. P., Bergman, T. L., & Lavine, A. S.
+/// (1996). Fundamentals of heat and mass transfer (Vol. 6, p. 116).
+/// New York: Wiley.
+#[test]
+pub fn incropera_example_horizontal_steam_pipe(){
+    use uom::si::thermodynamic_temperature::degree_celsius;
+
+    let pipe_convection = AmbientNaturalConvection::new_horizontal_cylinder(
+        Length::new::<meter>(0.1),
+        Some(Ratio::new::<ratio>(0.85)));
+
+    let surface_temperature = ThermodynamicTemperature::new::<degree_celsius>(165.0);
+    let ambient_temperature = ThermodynamicTemperature::new::<degree_celsius>(23.0);
+
+    let h_convection = pipe_convection
+        .try_get_convection_heat_transfer_coefficient(
+            surface_temperature, ambient_temperature).unwrap();
+
+    let h_radiation = pipe_convection
+        .get_radiation_heat_transfer_coefficient(
+            surface_temperature, ambient_temperature);
+
+    approx::assert_relative_eq!(
+        h_convection.get::<watt_per_square_meter_kelvin>(),
+        7.2,
+        max_relative=0.03);
+
+    approx::assert_relative_eq!(
+        h_radiation.get::<watt_per_square_meter_kelvin>(),
+        9.9,
+        max_relative=0.01);
+
+    let h_total = pipe_convection.try_get_heat_transfer_coefficient(
+        surface_temperature, ambient_temperature).unwrap();
+
+    approx::assert_relative_eq!(
+        h_total.get::<watt_per_square_meter_kelvin>(),
+        (h_convection + h_radiation).get::<watt_per_square_meter_kelvin>(),
+        max_relative=1e-12);
+}
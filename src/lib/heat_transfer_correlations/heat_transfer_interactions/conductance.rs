@@ -14,6 +14,7 @@ use uom::si::area::square_meter;
 use uom::si::power::watt;
 use uom::si::temperature_interval;
 use super::heat_transfer_geometry::CylindricalAndSphericalSolidFluidArrangement;
+use super::heat_transfer_geometry::InterfaceThermalResistance;
 
 /// Suppose we have two control volumes of the same materials and  
 /// temperature and we put a 1D thermal resistance between them 
@@ -388,6 +389,90 @@ pub fn get_conductance_single_cylindrical_radial_solid_liquid(
 }
 
 
+/// same as get_conductance_cylindrical_radial_two_materials,
+/// but with a contact resistance at the interface between the 
+/// inner and outer shells
+///
+/// the interface resistance acts over the outer surface area of 
+/// the inner shell, pi * (id + inner_shell_thickness) * L
+#[allow(clippy::too_many_arguments)]
+pub fn get_conductance_cylindrical_radial_two_materials_with_interface_resistance(
+    material_inner_shell: Material,
+    material_outer_shell: Material,
+    material_temperature_inner_shell: ThermodynamicTemperature,
+    material_temperature_outer_shell: ThermodynamicTemperature,
+    material_pressure_inner_shell: Pressure,
+    material_pressure_outer_shell: Pressure,
+    id: InnerDiameterThermalConduction,
+    inner_shell_thickness: RadialCylindricalThicknessThermalConduction,
+    outer_shell_thickness: RadialCylindricalThicknessThermalConduction,
+    l: CylinderLengthThermalConduction,
+    interface_resistance: InterfaceThermalResistance) 
+-> Result<ThermalConductance,TuasLibError> 
+{
+    let two_layer_conductance = get_conductance_cylindrical_radial_two_materials(
+        material_inner_shell,
+        material_outer_shell,
+        material_temperature_inner_shell,
+        material_temperature_outer_shell,
+        material_pressure_inner_shell,
+        material_pressure_outer_shell,
+        id,
+        inner_shell_thickness,
+        outer_shell_thickness,
+        l)?;
+
+    let id: Length = id.into();
+    let inner_shell_thickness: Length = inner_shell_thickness.into();
+    let interim_diameter: Length = id + inner_shell_thickness;
+    let l: Length = l.into();
+    let interface_area: Area = PI * interim_diameter * l;
+
+    Ok(interface_resistance.add_in_series(two_layer_conductance, interface_area))
+}
+
+/// same as get_conductance_single_cylindrical_radial_solid_liquid,
+/// but with a fouling layer between the fluid and the solid surface
+///
+/// the fouling resistance acts over the same surface area as the 
+/// convective heat transfer
+#[allow(clippy::too_many_arguments)]
+pub fn get_conductance_single_cylindrical_radial_solid_liquid_with_fouling(
+    solid: Material,
+    solid_temperature: ThermodynamicTemperature,
+    solid_pressure: Pressure,
+    h: HeatTransfer,
+    id: InnerDiameterThermalConduction,
+    od: OuterDiameterThermalConduction,
+    l: CylinderLengthThermalConduction,
+    solid_liquid_arrangement: CylindricalAndSphericalSolidFluidArrangement,
+    fouling_resistance: InterfaceThermalResistance) 
+-> Result<ThermalConductance,TuasLibError> 
+{
+    let clean_conductance = get_conductance_single_cylindrical_radial_solid_liquid(
+        solid,
+        solid_temperature,
+        solid_pressure,
+        h,
+        id,
+        od,
+        l,
+        solid_liquid_arrangement)?;
+
+    let id: Length = id.into();
+    let od: Length = od.into();
+    let l: Length = l.into();
+    let surface_area_for_solid_liquid_boundary: Area = match 
+        solid_liquid_arrangement {
+            CylindricalAndSphericalSolidFluidArrangement::FluidOnInnerSurfaceOfSolidShell => PI * id * l,
+            CylindricalAndSphericalSolidFluidArrangement::FluidOnOuterSurfaceOfSolidShell => PI * od * l,
+        };
+
+    Ok(fouling_resistance.add_in_series(clean_conductance, 
+            surface_area_for_solid_liquid_boundary))
+}
+
+
 /// H = sigma * coefficient * (T_hot^2 + T_cold^2)*(T_hot + T_cold) 
 /// where sigma is the stefan boltzmann constant
 /// in W m^(-2) T^(-4)
@@ -1,5 +1,10 @@
 use crate::control_volume_dimensions::*;
 use crate::boussinesq_thermophysical_properties::Material;
+use crate::tuas_lib_error::TuasLibError;
+use uom::si::f64::*;
+use uom::si::area::square_meter;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::thermal_resistance::kelvin_per_watt;
 
 
 /// for a curved surface, be it cylindrical or spherical,
@@ -42,3 +47,168 @@ pub struct DataDualCartesianThermalConductanceThreeDimension{
     pub thickness_2: XThicknessThermalConduction,
 
 }
+
+/// thermal resistance per unit area (m^2 K/W) at an interface 
+/// between two layers
+///
+/// this represents imperfect contact (gap conductance) between 
+/// layered solids, eg. a pipe shell and its insulation, or a fouling 
+/// layer on a heat exchanger tube surface. The default is 
+/// perfect contact or a clean surface, ie. zero resistance
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+/// use uom::si::area::square_meter;
+/// use uom::si::thermal_resistance::kelvin_per_watt;
+/// use tuas_boussinesq_solver::heat_transfer_correlations::
+/// heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
+///
+/// // a fouling factor of 0.0002 m^2 K/W is the same as a 
+/// // contact conductance of 5000 W/(m^2 K)
+/// let fouling = InterfaceThermalResistance::new_fouling_factor(0.0002)
+///     .unwrap();
+/// let contact = InterfaceThermalResistance::new_contact_conductance(
+///     HeatTransfer::new::<watt_per_square_meter_kelvin>(5000.0)).unwrap();
+///
+/// let area = Area::new::<square_meter>(0.5);
+///
+/// approx::assert_relative_eq!(
+///     fouling.get_thermal_resistance(area).get::<kelvin_per_watt>(),
+///     contact.get_thermal_resistance(area).get::<kelvin_per_watt>(),
+///     max_relative = 1e-12);
+/// approx::assert_relative_eq!(
+///     fouling.get_thermal_resistance(area).get::<kelvin_per_watt>(),
+///     0.0004,
+///     max_relative = 1e-12);
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub struct InterfaceThermalResistance {
+    // resistance per unit area in m^2 K/W
+    fouling_factor_square_meter_kelvin_per_watt: f64,
+}
+
+impl InterfaceThermalResistance {
+
+    /// perfect contact or clean surface, no interface resistance
+    pub fn new_perfect_contact() -> Self {
+        Self::default()
+    }
+
+    /// constructs an interface resistance from a fouling factor 
+    /// (or contact resistance) in m^2 K/W, which must not be negative
+    pub fn new_fouling_factor(
+        fouling_factor_square_meter_kelvin_per_watt: f64) 
+        -> Result<Self, TuasLibError> {
+
+        if !(fouling_factor_square_meter_kelvin_per_watt >= 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                format!("fouling factor must not be negative, got {} m^2 K/W",
+                    fouling_factor_square_meter_kelvin_per_watt)));
+        }
+
+        Ok(Self { fouling_factor_square_meter_kelvin_per_watt })
+    }
+
+    /// constructs an interface resistance from a contact (gap) 
+    /// conductance, which must be positive
+    pub fn new_contact_conductance(
+        contact_conductance: HeatTransfer) -> Result<Self, TuasLibError> {
+
+        let contact_conductance_value = 
+            contact_conductance.get::<watt_per_square_meter_kelvin>();
+
+        if !(contact_conductance_value > 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                format!("contact conductance must be positive, got {} W/(m^2 K)",
+                    contact_conductance_value)));
+        }
+
+        Ok(Self { 
+            fouling_factor_square_meter_kelvin_per_watt: 
+                1.0/contact_conductance_value 
+        })
+    }
+
+    /// returns the resistance per unit area in m^2 K/W
+    pub fn get_fouling_factor_square_meter_kelvin_per_watt(&self) -> f64 {
+        self.fouling_factor_square_meter_kelvin_per_watt
+    }
+
+    /// returns true if there is no interface resistance
+    pub fn is_perfect_contact(&self) -> bool {
+        self.fouling_factor_square_meter_kelvin_per_watt == 0.0
+    }
+
+    /// thermal resistance of the interface over a given area
+    pub fn get_thermal_resistance(&self, interface_area: Area) -> ThermalResistance {
+        ThermalResistance::new::<kelvin_per_watt>(
+            self.fouling_factor_square_meter_kelvin_per_watt
+            / interface_area.get::<square_meter>())
+    }
+
+    /// adds the interface resistance over the given area in series 
+    /// with a conductance
+    ///
+    /// for perfect contact, the conductance is returned unchanged
+    pub fn add_in_series(&self, 
+        conductance: ThermalConductance,
+        interface_area: Area) -> ThermalConductance {
+
+        if self.is_perfect_contact() {
+            return conductance;
+        }
+
+        (conductance.recip() + self.get_thermal_resistance(interface_area)).recip()
+    }
+}
+
+/// data for conduction through two cylindrical shells in series 
+/// with a contact resistance at the interface between them,
+/// eg. a pipe shell and its insulation
+///
+/// as with DualCylindricalThermalConductance, the inner 
+/// and outer shell thicknesses are added to the inner diameter
+/// to obtain the interface and outer diameters 
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct DataDualCylindricalInterfaceResistance {
+    /// material of the inner shell (cv_1)
+    pub inner_material: Material,
+    /// thickness of the inner shell
+    pub inner_shell_thickness: RadialCylindricalThicknessThermalConduction,
+    /// material of the outer shell (cv_2)
+    pub outer_material: Material,
+    /// thickness of the outer shell
+    pub outer_shell_thickness: RadialCylindricalThicknessThermalConduction,
+    /// inner diameter of the inner shell
+    pub inner_diameter: InnerDiameterThermalConduction,
+    /// axial length of the shells
+    pub cylinder_length: CylinderLengthThermalConduction,
+    /// resistance at the interface between inner and outer shells
+    pub interface_resistance: InterfaceThermalResistance,
+}
+
+/// data for conduction through a cylindrical shell and convection 
+/// to a fluid at one of its surfaces, with a fouling layer 
+/// between the fluid and the shell surface
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct DataCylindricalConductionConvectionFouling {
+    /// material of the solid shell
+    pub solid_material: Material,
+    /// temperature of the solid shell
+    pub solid_temperature: ThermodynamicTemperature,
+    /// pressure of the solid shell
+    pub solid_pressure: Pressure,
+    /// heat transfer coefficient at the clean surface
+    pub heat_transfer_coeff: HeatTransfer,
+    /// inner diameter of the solid shell
+    pub inner_diameter: InnerDiameterThermalConduction,
+    /// outer diameter of the solid shell
+    pub outer_diameter: OuterDiameterThermalConduction,
+    /// axial length of the shell
+    pub cylinder_length: CylinderLengthThermalConduction,
+    /// whether the fluid is on the inner or outer surface
+    pub solid_liquid_arrangement: CylindricalAndSphericalSolidFluidArrangement,
+    /// fouling resistance at the fluid side surface
+    pub fouling_resistance: InterfaceThermalResistance,
+}
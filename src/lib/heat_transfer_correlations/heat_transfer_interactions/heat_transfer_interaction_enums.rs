@@ -234,6 +234,26 @@ pub enum HeatTransferInteractionType {
     ),
 
 
+    /// same as DualCylindricalThermalConductance, but with a contact 
+    /// (gap) resistance at the interface between the inner and outer 
+    /// shells, eg. imperfect contact between a pipe shell and 
+    /// its insulation
+    ///
+    /// cv_1 is the inner shell and cv_2 is the outer shell
+    DualCylindricalThermalConductanceWithInterfaceResistance(
+        DataDualCylindricalInterfaceResistance
+    ),
+
+    /// same as CylindricalConductionConvectionLiquidInside or 
+    /// CylindricalConductionConvectionLiquidOutside, but with a 
+    /// fouling layer between the fluid and the solid surface
+    ///
+    /// the fouling resistance (m^2 K/W) is added in series with 
+    /// the convection resistance over the wetted surface area
+    CylindricalConductionConvectionWithFouling(
+        DataCylindricalConductionConvectionFouling
+    ),
+
     /// The user Specifies a heat Addition for the BC
     /// The uom type is Power
     UserSpecifiedHeatAddition,
@@ -442,6 +462,74 @@ impl TryFrom<HeatTransferInteractionType> for DataAdvection {
 
 impl HeatTransferInteractionType {
 
+    /// for interactions with a contact or fouling resistance, returns 
+    /// the equivalent interaction with perfect contact or a clean 
+    /// surface. Other interactions are returned unchanged
+    ///
+    /// this is used for mesh stability timesteps, interface 
+    /// resistances only lower the conductance, so the timestep 
+    /// without them is conservative
+    pub fn get_interaction_without_interface_resistance(&self) -> Self {
+
+        match *self {
+            HeatTransferInteractionType::
+                DualCylindricalThermalConductanceWithInterfaceResistance(
+                    data_interface_resistance) => {
+
+                    let id: Length = data_interface_resistance.inner_diameter.into();
+                    let inner_thickness: Length = 
+                        data_interface_resistance.inner_shell_thickness.into();
+                    let outer_thickness: Length = 
+                        data_interface_resistance.outer_shell_thickness.into();
+                    let od: Length = id + inner_thickness + outer_thickness;
+
+                    HeatTransferInteractionType::DualCylindricalThermalConductance(
+                        (data_interface_resistance.inner_material,
+                         data_interface_resistance.inner_shell_thickness),
+                        (data_interface_resistance.outer_material,
+                         data_interface_resistance.outer_shell_thickness),
+                        (data_interface_resistance.inner_diameter,
+                         od.into(),
+                         data_interface_resistance.cylinder_length)
+                    )
+                },
+            HeatTransferInteractionType::
+                CylindricalConductionConvectionWithFouling(data_fouling) => {
+
+                    let id: Length = data_fouling.inner_diameter.into();
+                    let od: Length = data_fouling.outer_diameter.into();
+                    let shell_thickness: RadialCylindricalThicknessThermalConduction = 
+                        (od - id).into();
+
+                    match data_fouling.solid_liquid_arrangement {
+                        CylindricalAndSphericalSolidFluidArrangement::
+                            FluidOnInnerSurfaceOfSolidShell => {
+                                HeatTransferInteractionType::
+                                    CylindricalConductionConvectionLiquidInside(
+                                        (data_fouling.solid_material, shell_thickness,
+                                         data_fouling.solid_temperature, 
+                                         data_fouling.solid_pressure),
+                                        (data_fouling.heat_transfer_coeff,
+                                         data_fouling.inner_diameter,
+                                         data_fouling.cylinder_length))
+                            },
+                        CylindricalAndSphericalSolidFluidArrangement::
+                            FluidOnOuterSurfaceOfSolidShell => {
+                                HeatTransferInteractionType::
+                                    CylindricalConductionConvectionLiquidOutside(
+                                        (data_fouling.solid_material, shell_thickness,
+                                         data_fouling.solid_temperature, 
+                                         data_fouling.solid_pressure),
+                                        (data_fouling.heat_transfer_coeff,
+                                         data_fouling.outer_diameter,
+                                         data_fouling.cylinder_length))
+                            },
+                    }
+                },
+            interaction => interaction,
+        }
+    }
+
     /// based on the heat transfer interaction type,
     /// we can calculate a thermal conductance given certain parameters
     /// 
@@ -591,6 +679,40 @@ impl HeatTransferInteractionType {
                         cylinder_length,
                     )?
                 },
+                HeatTransferInteractionType::
+                    DualCylindricalThermalConductanceWithInterfaceResistance(
+                        data_interface_resistance) => {
+
+                        get_conductance_cylindrical_radial_two_materials_with_interface_resistance(
+                            data_interface_resistance.inner_material,
+                            data_interface_resistance.outer_material,
+                            temperature_1, //convention, 1 is inner shell
+                            temperature_2, // convention 2, is outer shell
+                            pressure_1,
+                            pressure_2,
+                            data_interface_resistance.inner_diameter,
+                            data_interface_resistance.inner_shell_thickness,
+                            data_interface_resistance.outer_shell_thickness,
+                            data_interface_resistance.cylinder_length,
+                            data_interface_resistance.interface_resistance,
+                        )?
+                    },
+                HeatTransferInteractionType::
+                    CylindricalConductionConvectionWithFouling(
+                        data_fouling) => {
+
+                        get_conductance_single_cylindrical_radial_solid_liquid_with_fouling(
+                            data_fouling.solid_material,
+                            data_fouling.solid_temperature,
+                            data_fouling.solid_pressure,
+                            data_fouling.heat_transfer_coeff,
+                            data_fouling.inner_diameter,
+                            data_fouling.outer_diameter,
+                            data_fouling.cylinder_length,
+                            data_fouling.solid_liquid_arrangement,
+                            data_fouling.fouling_resistance,
+                        )?
+                    },
                 HeatTransferInteractionType::UserSpecifiedHeatAddition  
                     => {
                         return Err(TuasLibError::
@@ -255,3 +255,165 @@ pub fn radial_conductance_solid_test(){
 
 }
 
+
+/// with perfect contact, the dual cylindrical conductance with 
+/// interface resistance should be the same as the dual cylindrical 
+/// conductance without it
+///
+/// with a contact conductance h_c at the interface diameter d_i,
+/// the resistances add in series:
+///
+/// 1/G = 1/G_perfect_contact + 1/(h_c PI d_i L)
+#[test]
+pub fn dual_cylindrical_interface_resistance_test(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+
+    use super::heat_transfer_geometry::{DataDualCylindricalInterfaceResistance, InterfaceThermalResistance};
+    use super::heat_transfer_interaction_enums::HeatTransferInteractionType;
+
+    let steel = SolidMaterial::SteelSS304L;
+    let fiberglass = SolidMaterial::Fiberglass;
+    let atmospheric_pressure = Pressure::new::<atmosphere>(1.0);
+    let room_temp = ThermodynamicTemperature::new::<degree_celsius>(25.0);
+
+    let pipe_length = Length::new::<meter>(1.0);
+    let shell_id = Length::new::<meter>(0.0254);
+    let shell_thickness = Length::new::<meter>(0.002);
+    let insulation_thickness = Length::new::<meter>(0.02);
+    let interface_diameter = shell_id + shell_thickness;
+
+    let contact_conductance = 
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(500.0);
+
+    let interaction_with_resistance = |interface_resistance| {
+        HeatTransferInteractionType::
+            DualCylindricalThermalConductanceWithInterfaceResistance(
+                DataDualCylindricalInterfaceResistance {
+                    inner_material: steel.into(),
+                    inner_shell_thickness: shell_thickness.into(),
+                    outer_material: fiberglass.into(),
+                    outer_shell_thickness: insulation_thickness.into(),
+                    inner_diameter: shell_id.into(),
+                    cylinder_length: pipe_length.into(),
+                    interface_resistance,
+                })
+    };
+
+    let perfect_contact_interaction = 
+        interaction_with_resistance(InterfaceThermalResistance::new_perfect_contact());
+    let imperfect_contact_interaction = 
+        interaction_with_resistance(
+            InterfaceThermalResistance::new_contact_conductance(
+                contact_conductance).unwrap());
+
+    let reference_conductance: ThermalConductance = 
+        perfect_contact_interaction.get_interaction_without_interface_resistance()
+        .get_thermal_conductance_based_on_interaction(
+            room_temp, room_temp, 
+            atmospheric_pressure, atmospheric_pressure).unwrap();
+
+    let perfect_contact_conductance: ThermalConductance = 
+        perfect_contact_interaction
+        .get_thermal_conductance_based_on_interaction(
+            room_temp, room_temp, 
+            atmospheric_pressure, atmospheric_pressure).unwrap();
+
+    let imperfect_contact_conductance: ThermalConductance = 
+        imperfect_contact_interaction
+        .get_thermal_conductance_based_on_interaction(
+            room_temp, room_temp, 
+            atmospheric_pressure, atmospheric_pressure).unwrap();
+
+    approx::assert_relative_eq!(
+        perfect_contact_conductance.get::<watt_per_kelvin>(),
+        reference_conductance.get::<watt_per_kelvin>(),
+        max_relative=1e-12
+        );
+
+    let expected_resistance = 1.0/reference_conductance.get::<watt_per_kelvin>()
+        + 1.0/(contact_conductance.get::<watt_per_square_meter_kelvin>() 
+            * PI * interface_diameter.get::<meter>() * pipe_length.get::<meter>());
+
+    approx::assert_relative_eq!(
+        1.0/imperfect_contact_conductance.get::<watt_per_kelvin>(),
+        expected_resistance,
+        max_relative=1e-10
+        );
+
+}
+
+/// a fouling layer with fouling factor R_f on the fluid side 
+/// is the same as using an effective heat transfer coefficient 
+///
+/// 1/h_eff = 1/h + R_f
+///
+/// in the clean conduction-convection interaction
+#[test]
+pub fn cylindrical_conduction_convection_fouling_test(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+
+    use super::heat_transfer_geometry::{DataCylindricalConductionConvectionFouling, InterfaceThermalResistance};
+
+    let steel = SolidMaterial::SteelSS304L;
+    let atmospheric_pressure = Pressure::new::<atmosphere>(1.0);
+    let room_temp = ThermodynamicTemperature::new::<degree_celsius>(25.0);
+
+    let pipe_length = Length::new::<meter>(0.5);
+    let tube_id = Length::new::<meter>(0.01);
+    let tube_od = Length::new::<meter>(0.0127);
+
+    let htc = HeatTransfer::new::<watt_per_square_meter_kelvin>(2000.0);
+    let fouling_factor = 0.00035;
+    let effective_htc = HeatTransfer::new::<watt_per_square_meter_kelvin>(
+        1.0/(1.0/2000.0 + fouling_factor));
+
+    for solid_liquid_arrangement in [
+        CylindricalAndSphericalSolidFluidArrangement::FluidOnInnerSurfaceOfSolidShell,
+        CylindricalAndSphericalSolidFluidArrangement::FluidOnOuterSurfaceOfSolidShell] {
+
+        let fouled_interaction = super::heat_transfer_interaction_enums::
+            HeatTransferInteractionType::CylindricalConductionConvectionWithFouling(
+                DataCylindricalConductionConvectionFouling {
+                    solid_material: steel.into(),
+                    solid_temperature: room_temp,
+                    solid_pressure: atmospheric_pressure,
+                    heat_transfer_coeff: htc,
+                    inner_diameter: tube_id.into(),
+                    outer_diameter: tube_od.into(),
+                    cylinder_length: pipe_length.into(),
+                    solid_liquid_arrangement,
+                    fouling_resistance: 
+                        InterfaceThermalResistance::new_fouling_factor(
+                            fouling_factor).unwrap(),
+                });
+
+        let fouled_conductance: ThermalConductance = fouled_interaction
+            .get_thermal_conductance_based_on_interaction(
+                room_temp, room_temp, 
+                atmospheric_pressure, atmospheric_pressure).unwrap();
+
+        let reference_conductance: ThermalConductance = 
+            get_conductance_single_cylindrical_radial_solid_liquid(
+                steel.into(), 
+                room_temp, 
+                atmospheric_pressure, 
+                effective_htc, 
+                tube_id.into(), 
+                tube_od.into(), 
+                pipe_length.into(), 
+                solid_liquid_arrangement).unwrap();
+
+        approx::assert_relative_eq!(
+            fouled_conductance.get::<watt_per_kelvin>(),
+            reference_conductance.get::<watt_per_kelvin>(),
+            max_relative=1e-10
+            );
+    }
+
+}
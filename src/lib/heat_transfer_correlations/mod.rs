@@ -1,6 +1,10 @@
 /// correlations for convective heat transfer
 pub mod nusselt_number_correlations;
 
+/// heat transfer coefficients for natural convection and radiation
+/// from the outer surface of components to ambient air
+pub mod external_natural_convection;
+
 /// basic calculation functions for thermal resistance
 pub mod thermal_resistance;
 
@@ -10,6 +14,9 @@ pub mod view_factors;
 
 /// calculations for parallel piped heat exchangers 
 pub mod parallel_heat_exchangers;
+/// shell side heat transfer and pressure drop correlations
+/// (Kern and Bell-Delaware) for baffled shell and tube heat exchangers
+pub mod shell_side_correlations;
 
 /// heat transfer interactions between different shapes 
 /// of control volumes are calculated here
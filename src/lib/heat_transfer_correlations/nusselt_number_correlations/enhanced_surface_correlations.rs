@@ -0,0 +1,293 @@
+//! nusselt number correlations for tubes with enhanced surfaces
+//! or inserts, such as twisted tapes and helical coils
+//!
+//! the matching friction factors are in
+//! fluid_mechanics_correlations::enhanced_surface_friction_factor
+use std::f64::consts::PI;
+
+use crate::fluid_mechanics_correlations::enhanced_surface_friction_factor::*;
+use crate::tuas_lib_error::TuasLibError;
+
+/// laminar nusselt number for a tube with a twisted tape insert
+/// based on Manglik and Bergles,
+///
+/// Nu = 4.612 [(1 + 0.0951 Gz^0.894)^2.5
+/// + 6.413 x 10^(-9) (Sw Pr^0.391)^3.835]^0.2 (Pr_bulk/Pr_wall)^0.14
+///
+/// where Gz = Re Pr d/L is the Graetz number and Sw is the swirl
+/// number. The free convection term of the original correlation is
+/// neglected.
+///
+/// Manglik and Bergles used the viscosity ratio (mu_bulk/mu_wall)^0.14
+/// for the wall correction. For liquids, heat capacity and thermal
+/// conductivity change little with temperature compared to viscosity,
+/// so the prandtl number ratio is used in its place as with the
+/// other correlations in this library
+///
+/// Manglik, R. M., & Bergles, A. E. (1993). Heat transfer and
+/// pressure drop correlations for twisted-tape inserts in
+/// isothermal tubes: Part I—Laminar flows. Journal of Heat
+/// Transfer, 115(4), 881-889.
+pub fn manglik_bergles_twisted_tape_nusselt_laminar(reynolds_number: f64,
+    prandtl_number_bulk: f64,
+    prandtl_number_wall: f64,
+    twist_ratio: f64,
+    length_to_diameter: f64) -> f64 {
+
+    let reynolds_number = reynolds_number.abs();
+    let graetz_number = reynolds_number * prandtl_number_bulk
+        / length_to_diameter;
+    let swirl_number = twisted_tape_swirl_number(reynolds_number,
+        twist_ratio);
+
+    let developing_flow_term = (1.0 + 0.0951 * graetz_number.powf(0.894))
+        .powf(2.5);
+    let swirl_flow_term = 6.413e-9
+        * (swirl_number * prandtl_number_bulk.powf(0.391)).powf(3.835);
+
+    let wall_correction = (prandtl_number_bulk/prandtl_number_wall).powf(0.14);
+
+    4.612 * (developing_flow_term + swirl_flow_term).powf(0.2)
+        * wall_correction
+}
+
+/// turbulent nusselt number for a tube with a twisted tape insert
+/// based on Manglik and Bergles,
+///
+/// Nu = 0.023 Re^0.8 Pr^0.4 (1 + 0.769/y)
+/// (pi/(pi - 4 delta/d))^0.8
+/// ((pi + 2 - 2 delta/d)/(pi - 4 delta/d))^0.2
+/// (Pr_bulk/Pr_wall)^n
+///
+/// where n = 0.18 for heating liquids (Pr_bulk > Pr_wall) and
+/// n = 0.30 for cooling liquids. The prandtl number ratio is used in
+/// place of the viscosity ratio in the original correlation
+///
+/// Manglik, R. M., & Bergles, A. E. (1993). Heat transfer and
+/// pressure drop correlations for twisted-tape inserts in
+/// isothermal tubes: Part II—Transition and turbulent flows.
+/// Journal of Heat Transfer, 115(4), 890-896.
+pub fn manglik_bergles_twisted_tape_nusselt_turbulent(reynolds_number: f64,
+    prandtl_number_bulk: f64,
+    prandtl_number_wall: f64,
+    twist_ratio: f64,
+    tape_thickness_to_diameter: f64) -> f64 {
+
+    let reynolds_number = reynolds_number.abs();
+
+    let blockage_factor = PI/(PI - 4.0 * tape_thickness_to_diameter);
+    let perimeter_factor = (PI + 2.0 - 2.0 * tape_thickness_to_diameter)
+        /(PI - 4.0 * tape_thickness_to_diameter);
+
+    let prandtl_ratio = prandtl_number_bulk/prandtl_number_wall;
+    let wall_correction_exponent = if prandtl_ratio > 1.0 {
+        0.18
+    } else {
+        0.30
+    };
+
+    0.023 * reynolds_number.powf(0.8) * prandtl_number_bulk.powf(0.4)
+        * (1.0 + 0.769/twist_ratio)
+        * blockage_factor.powf(0.8)
+        * perimeter_factor.powf(0.2)
+        * prandtl_ratio.powf(wall_correction_exponent)
+}
+
+/// nusselt number for a tube with a twisted tape insert based on
+/// Manglik and Bergles, for laminar, transition and turbulent flow
+///
+/// the reynolds and nusselt numbers are based on the tube inner
+/// diameter d, the twist ratio y = H/d is the length of a 180 degree
+/// twist divided by the tube inner diameter
+///
+/// the laminar correlation is used below Re = 2000 and the
+/// turbulent correlation above Re = 10000, in between, the
+/// nusselt number is linearly interpolated
+///
+/// ```rust
+/// use tuas_boussinesq_solver::heat_transfer_correlations::
+/// nusselt_number_correlations::enhanced_surface_correlations::
+/// manglik_bergles_twisted_tape_nusselt;
+///
+/// // a very loosely twisted and thin tape behaves like a straight 
+/// // plate splitting the tube in two, which reduces to the 
+/// // Dittus-Boelter correlation with extra wetted perimeter
+/// let perimeter_factor = (std::f64::consts::PI + 2.0)/std::f64::consts::PI;
+/// let nusselt = manglik_bergles_twisted_tape_nusselt(
+///     20000.0, 5.0, 5.0, 1.0e6, 0.0, 100.0).unwrap();
+///
+/// approx::assert_relative_eq!(nusselt,
+///     0.023 * 20000.0_f64.powf(0.8) * 5.0_f64.powf(0.4) 
+///     * perimeter_factor.powf(0.2),
+///     max_relative=0.001);
+/// ```
+pub fn manglik_bergles_twisted_tape_nusselt(reynolds_number: f64,
+    prandtl_number_bulk: f64,
+    prandtl_number_wall: f64,
+    twist_ratio: f64,
+    tape_thickness_to_diameter: f64,
+    length_to_diameter: f64) -> Result<f64, TuasLibError> {
+
+    try_validate_twisted_tape_geometry(twist_ratio,
+        tape_thickness_to_diameter)?;
+
+    if !(length_to_diameter > 0.0) {
+        return Err(TuasLibError::GenericStringError(
+            "length to diameter ratio must be positive".to_owned()));
+    }
+
+    let reynolds_number = reynolds_number.abs();
+
+    if reynolds_number <= TWISTED_TAPE_LAMINAR_REYNOLDS_LIMIT {
+        return Ok(manglik_bergles_twisted_tape_nusselt_laminar(
+            reynolds_number, prandtl_number_bulk, prandtl_number_wall,
+            twist_ratio, length_to_diameter));
+    }
+
+    if reynolds_number >= TWISTED_TAPE_TURBULENT_REYNOLDS_LIMIT {
+        return Ok(manglik_bergles_twisted_tape_nusselt_turbulent(
+            reynolds_number, prandtl_number_bulk, prandtl_number_wall,
+            twist_ratio, tape_thickness_to_diameter));
+    }
+
+    let laminar_nusselt = manglik_bergles_twisted_tape_nusselt_laminar(
+        TWISTED_TAPE_LAMINAR_REYNOLDS_LIMIT, prandtl_number_bulk,
+        prandtl_number_wall, twist_ratio, length_to_diameter);
+    let turbulent_nusselt = manglik_bergles_twisted_tape_nusselt_turbulent(
+        TWISTED_TAPE_TURBULENT_REYNOLDS_LIMIT, prandtl_number_bulk,
+        prandtl_number_wall, twist_ratio, tape_thickness_to_diameter);
+
+    let interpolation_fraction = (reynolds_number - TWISTED_TAPE_LAMINAR_REYNOLDS_LIMIT)
+        /(TWISTED_TAPE_TURBULENT_REYNOLDS_LIMIT - TWISTED_TAPE_LAMINAR_REYNOLDS_LIMIT);
+
+    Ok(laminar_nusselt + interpolation_fraction * (turbulent_nusselt - laminar_nusselt))
+}
+
+/// laminar nusselt number for fully developed flow in a helical
+/// coil with constant wall temperature based on Manlapaz and
+/// Churchill, in terms of the dean number De = Re (d/D)^0.5,
+///
+/// Nu = [(3.657 + 4.343/x_1)^3 + 1.158 (De/x_2)^1.5]^(1/3)
+///
+/// x_1 = (1 + 957/(De^2 Pr))^2
+///
+/// x_2 = 1 + 0.477/Pr
+///
+/// for De approaching zero, this reduces to the straight tube
+/// value of 3.657
+///
+/// Manlapaz, R. L., & Churchill, S. W. (1981). Fully developed
+/// laminar convection from a helical coil. Chemical Engineering
+/// Communications, 9(1-6), 185-200.
+pub fn manlapaz_churchill_helical_coil_nusselt_laminar(dean_number: f64,
+    prandtl_number: f64) -> f64 {
+
+    let dean_number = dean_number.abs();
+
+    if dean_number == 0.0 {
+        return 3.657;
+    }
+
+    let x_1 = (1.0 + 957.0/(dean_number * dean_number * prandtl_number))
+        .powi(2);
+    let x_2 = 1.0 + 0.477/prandtl_number;
+
+    ((3.657 + 4.343/x_1).powi(3) + 1.158 * (dean_number/x_2).powf(1.5))
+        .powf(1.0/3.0)
+}
+
+/// turbulent nusselt number in a helical coil based on Gnielinski,
+///
+/// Nu = (f/8) Re Pr / (1 + 12.7 (f/8)^0.5 (Pr^(2/3) - 1))
+///
+/// with the turbulent coil friction factor
+/// f = 0.3164/Re^0.25 + 0.03 (d/D)^0.5
+///
+/// Gnielinski, V. (1986). Correlations for the pressure drop in
+/// helically coiled tubes. International Chemical Engineering,
+/// 26(1), 36-44.
+pub fn gnielinski_helical_coil_nusselt_turbulent(reynolds_number: f64,
+    prandtl_number: f64,
+    tube_to_coil_diameter_ratio: f64) -> f64 {
+
+    let reynolds_number = reynolds_number.abs();
+
+    let darcy_friction_factor = 0.3164/reynolds_number.powf(0.25)
+        + 0.03 * tube_to_coil_diameter_ratio.sqrt();
+
+    let friction_term = darcy_friction_factor/8.0;
+
+    friction_term * reynolds_number * prandtl_number
+        /(1.0 + 12.7 * friction_term.sqrt()
+            * (prandtl_number.powf(2.0/3.0) - 1.0))
+}
+
+/// above this reynolds number, flow in a helical coil is fully
+/// turbulent
+pub const HELICAL_COIL_TURBULENT_REYNOLDS_LIMIT: f64 = 22000.0;
+
+/// nusselt number for a helical coil for laminar, transition and
+/// turbulent flow
+///
+/// below the critical reynolds number of Schmidt,
+/// Re_crit = 2300 (1 + 8.6 (d/D)^0.45),
+/// the laminar dean number correlation of Manlapaz and Churchill
+/// is used. Above Re = 22000, the turbulent correlation of Gnielinski
+/// is used. In between, the nusselt number is interpolated between
+/// the laminar value at Re_crit and the turbulent value at Re = 22000
+/// as recommended by Gnielinski
+///
+/// a wall correction of (Pr_bulk/Pr_wall)^0.14 is applied
+/// throughout
+///
+/// ```rust
+/// use tuas_boussinesq_solver::heat_transfer_correlations::
+/// nusselt_number_correlations::enhanced_surface_correlations::
+/// helical_coil_nusselt;
+///
+/// // a gently curved coil in laminar flow is close to a straight tube
+/// let nusselt = helical_coil_nusselt(
+///     100.0, 5.0, 5.0, 1.0e-8).unwrap();
+///
+/// approx::assert_relative_eq!(nusselt, 3.657, max_relative=0.001);
+/// ```
+pub fn helical_coil_nusselt(reynolds_number: f64,
+    prandtl_number_bulk: f64,
+    prandtl_number_wall: f64,
+    tube_to_coil_diameter_ratio: f64) -> Result<f64, TuasLibError> {
+
+    try_validate_helical_coil_geometry(tube_to_coil_diameter_ratio)?;
+
+    let reynolds_number = reynolds_number.abs();
+    let critical_reynolds_number =
+        helical_coil_critical_reynolds_number(tube_to_coil_diameter_ratio);
+
+    let wall_correction = (prandtl_number_bulk/prandtl_number_wall).powf(0.14);
+
+    let laminar_nusselt = |reynolds: f64| -> f64 {
+        manlapaz_churchill_helical_coil_nusselt_laminar(
+            dean_number(reynolds, tube_to_coil_diameter_ratio),
+            prandtl_number_bulk)
+    };
+
+    if reynolds_number <= critical_reynolds_number {
+        return Ok(laminar_nusselt(reynolds_number) * wall_correction);
+    }
+
+    if reynolds_number >= HELICAL_COIL_TURBULENT_REYNOLDS_LIMIT {
+        return Ok(gnielinski_helical_coil_nusselt_turbulent(
+            reynolds_number, prandtl_number_bulk,
+            tube_to_coil_diameter_ratio) * wall_correction);
+    }
+
+    let laminar_fraction = (HELICAL_COIL_TURBULENT_REYNOLDS_LIMIT - reynolds_number)
+        /(HELICAL_COIL_TURBULENT_REYNOLDS_LIMIT - critical_reynolds_number);
+
+    let transition_nusselt = laminar_fraction
+        * laminar_nusselt(critical_reynolds_number)
+        + (1.0 - laminar_fraction) * gnielinski_helical_coil_nusselt_turbulent(
+            HELICAL_COIL_TURBULENT_REYNOLDS_LIMIT, prandtl_number_bulk,
+            tube_to_coil_diameter_ratio);
+
+    Ok(transition_nusselt * wall_correction)
+}
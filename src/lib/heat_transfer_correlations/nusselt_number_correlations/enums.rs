@@ -3,6 +3,7 @@ use uom::si::{f64::*, ratio::ratio};
 use crate::tuas_lib_error::TuasLibError;
 
 use super::input_structs::{NusseltPrandtlReynoldsData, WakaoData, GnielinskiData};
+use super::input_structs::{TwistedTapeData, HelicalCoilData};
 
 /// Contains a collection of nusselt number correlations for use 
 ///
@@ -211,6 +212,20 @@ pub enum NusseltCorrelation {
 
     /// Fixed nusselt number,
     FixedNusselt(Ratio),
+
+    /// nusselt number for tubes with twisted tape inserts 
+    /// based on Manglik and Bergles, for laminar, transition 
+    /// and turbulent flow, parameterised by twist ratio and 
+    /// tape thickness
+    ///
+    /// the reynolds number is based on the tube inner diameter 
+    /// and the mean velocity in the tape blocked cross section
+    TwistedTapeManglikBergles(TwistedTapeData),
+
+    /// nusselt number for helical coils based on the 
+    /// dean number De = Re (d/D)^0.5, for laminar, transition 
+    /// and turbulent flow
+    HelicalCoilDean(HelicalCoilData),
 }
 
 impl NusseltCorrelation {
@@ -263,6 +278,12 @@ impl NusseltCorrelation {
             NusseltCorrelation::PipeGnielinskiGenericPrandtlFilm(data) => {
                 return data.get_nusselt_for_developing_flow();
             },
+            NusseltCorrelation::TwistedTapeManglikBergles(data) => {
+                return data.get_nusselt();
+            },
+            NusseltCorrelation::HelicalCoilDean(data) => {
+                return data.get_nusselt();
+            },
             NusseltCorrelation::CustomGnielinskiGenericPrandtlBulk(
                 data, correlation_coefficient_c, reynolds_exponent_m
             ) => 
@@ -421,6 +442,20 @@ impl NusseltCorrelation {
                 modified_data.reynolds = reynolds_number_input;
                 return modified_data.get_nusselt_for_developing_flow_bulk_fluid_prandtl();
             },
+            NusseltCorrelation::TwistedTapeManglikBergles(data) => {
+                let mut modified_data = data.clone();
+                modified_data.prandtl_wall = bulk_prandtl_number_input;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt();
+            },
+            NusseltCorrelation::HelicalCoilDean(data) => {
+                let mut modified_data = data.clone();
+                modified_data.prandtl_wall = bulk_prandtl_number_input;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt();
+            },
 
         };
 
@@ -527,6 +562,20 @@ impl NusseltCorrelation {
                 // takes the prandtl_film = (prandtl_wall + prandtl_bulk)/2
                 return modified_data.get_nusselt_for_developing_flow();
             },
+            NusseltCorrelation::TwistedTapeManglikBergles(data) => {
+                let mut modified_data = data.clone();
+                modified_data.prandtl_wall = wall_prandtl_number_input;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt();
+            },
+            NusseltCorrelation::HelicalCoilDean(data) => {
+                let mut modified_data = data.clone();
+                modified_data.prandtl_wall = wall_prandtl_number_input;
+                modified_data.prandtl_bulk = bulk_prandtl_number_input;
+                modified_data.reynolds = reynolds_number_input;
+                return modified_data.get_nusselt();
+            },
         };
 
         return Ok(nusselt_number);
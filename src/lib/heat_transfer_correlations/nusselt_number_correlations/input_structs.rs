@@ -3,6 +3,7 @@ use uom::{si::{f64::*, ratio::ratio}, ConstZero};
 use crate::tuas_lib_error::TuasLibError;
 
 use super::pipe_correlations::*;
+use super::enhanced_surface_correlations::*;
 /// contains information Nusselt Prandtl Reynold's
 /// correlation
 /// usually in the form:
@@ -381,3 +382,85 @@ impl GnielinskiData {
 
 }
 
+
+/// contains data for the Manglik and Bergles twisted tape 
+/// correlation
+///
+/// Manglik, R. M., & Bergles, A. E. (1993). Heat transfer and
+/// pressure drop correlations for twisted-tape inserts in
+/// isothermal tubes: Part I—Laminar flows. Journal of Heat
+/// Transfer, 115(4), 881-889.
+///
+/// Manglik, R. M., & Bergles, A. E. (1993). Heat transfer and
+/// pressure drop correlations for twisted-tape inserts in
+/// isothermal tubes: Part II—Transition and turbulent flows.
+/// Journal of Heat Transfer, 115(4), 890-896.
+#[derive(Clone,Copy,Debug, PartialEq)]
+pub struct TwistedTapeData {
+    /// reynolds number based on tube inner diameter
+    pub reynolds: Ratio,
+    /// bulk fluid prandtl number
+    pub prandtl_bulk: Ratio,
+    /// wall prandtl number based on wall temperature
+    pub prandtl_wall: Ratio,
+    /// twist ratio y = H/d, the length of a 180 degree twist 
+    /// divided by the tube inner diameter
+    pub twist_ratio: Ratio,
+    /// tape thickness divided by the tube inner diameter
+    pub tape_thickness_to_diameter: Ratio,
+    /// tube length to diameter ratio, used for the graetz number 
+    /// in laminar flow
+    pub length_to_diameter: Ratio,
+}
+
+impl TwistedTapeData {
+
+    /// nusselt number for laminar, transition and turbulent flow
+    /// through a tube with a twisted tape insert
+    #[inline]
+    pub fn get_nusselt(&self) -> Result<Ratio,TuasLibError>{
+
+        let nusselt_value = manglik_bergles_twisted_tape_nusselt(
+            self.reynolds.get::<ratio>(),
+            self.prandtl_bulk.get::<ratio>(),
+            self.prandtl_wall.get::<ratio>(),
+            self.twist_ratio.get::<ratio>(),
+            self.tape_thickness_to_diameter.get::<ratio>(),
+            self.length_to_diameter.get::<ratio>(),
+        )?;
+
+        return Ok(Ratio::new::<ratio>(nusselt_value));
+    }
+}
+
+/// contains data for helical coil correlations based on 
+/// the dean number, De = Re (d/D)^0.5
+#[derive(Clone,Copy,Debug, PartialEq)]
+pub struct HelicalCoilData {
+    /// reynolds number based on tube inner diameter
+    pub reynolds: Ratio,
+    /// bulk fluid prandtl number
+    pub prandtl_bulk: Ratio,
+    /// wall prandtl number based on wall temperature
+    pub prandtl_wall: Ratio,
+    /// tube inner diameter divided by the coil diameter, d/D
+    pub tube_to_coil_diameter_ratio: Ratio,
+}
+
+impl HelicalCoilData {
+
+    /// nusselt number for laminar, transition and turbulent flow
+    /// through a helical coil
+    #[inline]
+    pub fn get_nusselt(&self) -> Result<Ratio,TuasLibError>{
+
+        let nusselt_value = helical_coil_nusselt(
+            self.reynolds.get::<ratio>(),
+            self.prandtl_bulk.get::<ratio>(),
+            self.prandtl_wall.get::<ratio>(),
+            self.tube_to_coil_diameter_ratio.get::<ratio>(),
+        )?;
+
+        return Ok(Ratio::new::<ratio>(nusselt_value));
+    }
+}
@@ -4,6 +4,14 @@
 /// These are nusselt correlations for pipes
 pub mod pipe_correlations;
 
+/// These are nusselt correlations for external natural convection
+/// from cylinders and plates, eg. pipes losing heat to ambient air
+pub mod natural_convection_correlations;
+
+/// These are nusselt correlations for tubes with enhanced surfaces
+/// or inserts, eg. twisted tapes and helical coils
+pub mod enhanced_surface_correlations;
+
 
 /// contains data types used for nusselt number correlation 
 /// enums
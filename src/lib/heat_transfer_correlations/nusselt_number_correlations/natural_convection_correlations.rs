@@ -0,0 +1,172 @@
+/// Churchill and Chu correlation for free convection from a
+/// long isothermal horizontal cylinder
+///
+/// Nu_D = {0.60 + 0.387 Ra_D^(1/6) / [1 + (0.559/Pr)^(9/16)]^(8/27)}^2
+///
+/// valid for Ra_D <= 1e12, the Rayleigh number is based on
+/// the cylinder outer diameter, and properties are evaluated at
+/// the film temperature
+///
+/// Churchill, S. W., & Chu, H. H. (1975). Correlating equations for
+/// laminar and turbulent free convection from a horizontal cylinder.
+/// International journal of heat and mass transfer, 18(9), 1049-1053.
+///
+/// ```rust
+/// use tuas_boussinesq_solver::heat_transfer_correlations::
+/// nusselt_number_correlations::natural_convection_correlations::
+/// churchill_chu_horizontal_cylinder;
+///
+/// let nusselt = churchill_chu_horizontal_cylinder(1.0e6, 0.7);
+///
+/// approx::assert_relative_eq!(nusselt, 14.51, max_relative=0.001);
+/// ```
+pub fn churchill_chu_horizontal_cylinder(rayleigh_number: f64,
+    prandtl_number: f64) -> f64 {
+
+    let rayleigh_number = rayleigh_number.abs();
+
+    let prandtl_function: f64 =
+        (1.0 + (0.559/prandtl_number).powf(9.0/16.0)).powf(8.0/27.0);
+
+    let nusselt_sqrt: f64 = 0.60 +
+        0.387 * rayleigh_number.powf(1.0/6.0) / prandtl_function;
+
+    return nusselt_sqrt * nusselt_sqrt;
+}
+
+/// Morgan correlation for free convection from a
+/// long isothermal horizontal cylinder
+///
+/// Nu_D = C Ra_D^n
+///
+/// with the constants C and n tabulated by Rayleigh number range
+/// from 1e-10 to 1e12
+///
+/// Morgan, V. T. (1975). The overall convective heat transfer from
+/// smooth circular cylinders. In Advances in heat transfer
+/// (Vol. 11, pp. 199-264). Elsevier.
+///
+/// ```rust
+/// use tuas_boussinesq_solver::heat_transfer_correlations::
+/// nusselt_number_correlations::natural_convection_correlations::
+/// morgan_horizontal_cylinder;
+///
+/// // C = 0.480, n = 0.250 for 1e4 < Ra_D < 1e7
+/// let nusselt = morgan_horizontal_cylinder(1.0e6);
+///
+/// approx::assert_relative_eq!(nusselt, 15.18, max_relative=0.001);
+/// ```
+pub fn morgan_horizontal_cylinder(rayleigh_number: f64) -> f64 {
+
+    let rayleigh_number = rayleigh_number.abs();
+
+    let (c, n): (f64, f64) =
+    if rayleigh_number < 1.0e-2 {
+        (0.675, 0.058)
+    } else if rayleigh_number < 1.0e2 {
+        (1.02, 0.148)
+    } else if rayleigh_number < 1.0e4 {
+        (0.850, 0.188)
+    } else if rayleigh_number < 1.0e7 {
+        (0.480, 0.250)
+    } else {
+        (0.125, 0.333)
+    };
+
+    return c * rayleigh_number.powf(n);
+}
+
+/// Churchill and Chu correlation for free convection from a
+/// vertical isothermal plate, applicable over the entire range
+/// of Rayleigh numbers (laminar and turbulent)
+///
+/// Nu_L = {0.825 + 0.387 Ra_L^(1/6) / [1 + (0.492/Pr)^(9/16)]^(8/27)}^2
+///
+/// the Rayleigh number is based on the plate height L.
+/// This may be used for vertical cylinders so long as the
+/// boundary layer is thin compared to the diameter, which is when:
+///
+/// D/L >= 35/Gr_L^(1/4)
+///
+/// Churchill, S. W., & Chu, H. H. (1975). Correlating equations for
+/// laminar and turbulent free convection from a vertical plate.
+/// International journal of heat and mass transfer, 18(11), 1323-1329.
+pub fn churchill_chu_vertical_plate(rayleigh_number: f64,
+    prandtl_number: f64) -> f64 {
+
+    let rayleigh_number = rayleigh_number.abs();
+
+    let prandtl_function: f64 =
+        (1.0 + (0.492/prandtl_number).powf(9.0/16.0)).powf(8.0/27.0);
+
+    let nusselt_sqrt: f64 = 0.825 +
+        0.387 * rayleigh_number.powf(1.0/6.0) / prandtl_function;
+
+    return nusselt_sqrt * nusselt_sqrt;
+}
+
+/// Churchill and Chu correlation for free convection from a
+/// vertical isothermal plate, laminar form
+///
+/// Nu_L = 0.68 + 0.670 Ra_L^(1/4) / [1 + (0.492/Pr)^(9/16)]^(4/9)
+///
+/// this is slightly more accurate than the full range form
+/// for Ra_L <= 1e9, but should not be used above that.
+/// The same vertical cylinder criterion applies, D/L >= 35/Gr_L^(1/4)
+///
+/// Churchill, S. W., & Chu, H. H. (1975). Correlating equations for
+/// laminar and turbulent free convection from a vertical plate.
+/// International journal of heat and mass transfer, 18(11), 1323-1329.
+pub fn churchill_chu_vertical_plate_laminar(rayleigh_number: f64,
+    prandtl_number: f64) -> f64 {
+
+    let rayleigh_number = rayleigh_number.abs();
+
+    let prandtl_function: f64 =
+        (1.0 + (0.492/prandtl_number).powf(9.0/16.0)).powf(4.0/9.0);
+
+    return 0.68 + 0.670 * rayleigh_number.powf(0.25) / prandtl_function;
+}
+
+/// Churchill-Chu and Morgan correlations for horizontal cylinders
+/// should agree with each other to within about 10%
+/// for air (Pr about 0.7) in the range of Rayleigh numbers
+/// typical of pipes losing heat to ambient air
+#[test]
+pub fn horizontal_cylinder_churchill_chu_and_morgan_agree(){
+
+    let prandtl_air = 0.7;
+
+    for rayleigh_number in [1.0e5, 1.0e6, 1.0e7, 1.0e8] {
+
+        let nusselt_churchill_chu = churchill_chu_horizontal_cylinder(
+            rayleigh_number, prandtl_air);
+        let nusselt_morgan = morgan_horizontal_cylinder(rayleigh_number);
+
+        approx::assert_relative_eq!(
+            nusselt_churchill_chu,
+            nusselt_morgan,
+            max_relative=0.1);
+    }
+}
+
+/// laminar and full range forms of the vertical plate correlation
+/// should agree to within about 7% in the laminar range
+#[test]
+pub fn vertical_plate_churchill_chu_laminar_and_full_range_agree(){
+
+    let prandtl_air = 0.71;
+
+    for rayleigh_number in [1.0e4, 1.0e5, 1.0e6, 1.0e7] {
+
+        let nusselt_laminar = churchill_chu_vertical_plate_laminar(
+            rayleigh_number, prandtl_air);
+        let nusselt_full_range = churchill_chu_vertical_plate(
+            rayleigh_number, prandtl_air);
+
+        approx::assert_relative_eq!(
+            nusselt_laminar,
+            nusselt_full_range,
+            max_relative=0.07);
+    }
+}
@@ -534,3 +534,170 @@ pub fn du_nusselt_enum_correlation_empirical_test(){
     test_fn(3510.033, 42.582 ,0.08);
 
 }
+
+/// twisted tape inserts should enhance heat transfer over a smooth 
+/// tube (Dittus-Boelter), and tighter twists should enhance heat 
+/// transfer more
+///
+/// for y = 3 and delta/d = 0.05, at Re = 20000 and Pr = 5,
+/// the Manglik and Bergles correlation gives Nu = 178.195
+/// compared to 120.820 for Dittus-Boelter
+#[test]
+pub fn twisted_tape_manglik_bergles_turbulent_enhancement(){
+    use uom::si::ratio::ratio;
+    use uom::si::f64::*;
+
+    use crate::heat_transfer_correlations::nusselt_number_correlations::
+        enums::NusseltCorrelation;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::
+        input_structs::TwistedTapeData;
+
+    let prandtl = Ratio::new::<ratio>(5.0);
+    let reynolds = Ratio::new::<ratio>(20000.0);
+
+    let twisted_tape_data = TwistedTapeData {
+        reynolds: Ratio::new::<ratio>(0.0),
+        prandtl_bulk: Ratio::new::<ratio>(0.0),
+        prandtl_wall: Ratio::new::<ratio>(0.0),
+        twist_ratio: Ratio::new::<ratio>(3.0),
+        tape_thickness_to_diameter: Ratio::new::<ratio>(0.05),
+        length_to_diameter: Ratio::new::<ratio>(100.0),
+    };
+
+    let twisted_tape_correlation = 
+        NusseltCorrelation::TwistedTapeManglikBergles(twisted_tape_data);
+
+    let nusselt = twisted_tape_correlation
+        .estimate_based_on_prandtl_and_reynolds_no_wall_correction(
+            prandtl, reynolds).unwrap().get::<ratio>();
+
+    approx::assert_relative_eq!(nusselt, 178.195, max_relative=1e-4);
+
+    let dittus_boelter_nusselt: f64 = 0.023 * 20000.0_f64.powf(0.8) 
+        * 5.0_f64.powf(0.4);
+
+    assert!(nusselt > dittus_boelter_nusselt);
+
+    // a looser twist gives less enhancement
+    let mut loose_twist_data = twisted_tape_data;
+    loose_twist_data.twist_ratio = Ratio::new::<ratio>(6.0);
+
+    let loose_twist_nusselt = 
+        NusseltCorrelation::TwistedTapeManglikBergles(loose_twist_data)
+        .estimate_based_on_prandtl_and_reynolds_no_wall_correction(
+            prandtl, reynolds).unwrap().get::<ratio>();
+
+    assert!(loose_twist_nusselt < nusselt);
+    assert!(loose_twist_nusselt > dittus_boelter_nusselt);
+}
+
+/// in laminar flow, a loosely twisted tape in a long tube 
+/// gives the fully developed value of Nu = 4.612, 
+/// and the nusselt number is continuous across the transition 
+/// regime between Re = 2000 and Re = 10000
+#[test]
+pub fn twisted_tape_manglik_bergles_laminar_and_transition(){
+
+    use crate::heat_transfer_correlations::nusselt_number_correlations::
+        enhanced_surface_correlations::*;
+
+    let nusselt_laminar = manglik_bergles_twisted_tape_nusselt(
+        100.0, 5.0, 5.0, 1.0e6, 0.0, 1.0e6).unwrap();
+
+    approx::assert_relative_eq!(nusselt_laminar, 4.612, max_relative=1e-3);
+
+    let twist_ratio = 3.0;
+    let tape_thickness_to_diameter = 0.05;
+    let length_to_diameter = 100.0;
+
+    let nusselt_at = |reynolds: f64| -> f64 {
+        manglik_bergles_twisted_tape_nusselt(reynolds, 5.0, 5.0, 
+            twist_ratio, tape_thickness_to_diameter, 
+            length_to_diameter).unwrap()
+    };
+
+    approx::assert_relative_eq!(nusselt_at(2000.0),
+        manglik_bergles_twisted_tape_nusselt_laminar(2000.0, 5.0, 5.0, 
+            twist_ratio, length_to_diameter),
+        max_relative=1e-12);
+    approx::assert_relative_eq!(nusselt_at(10000.0 - 1e-6),
+        manglik_bergles_twisted_tape_nusselt_turbulent(10000.0, 5.0, 5.0, 
+            twist_ratio, tape_thickness_to_diameter),
+        max_relative=1e-6);
+
+    // nusselt number increases with reynolds number throughout
+    let mut previous_nusselt = 0.0;
+    for reynolds in [500.0, 1000.0, 2000.0, 4000.0, 6000.0, 10000.0, 20000.0] {
+        let nusselt = nusselt_at(reynolds);
+        assert!(nusselt > previous_nusselt);
+        previous_nusselt = nusselt;
+    }
+
+    // unphysical tapes give errors
+    assert!(manglik_bergles_twisted_tape_nusselt(
+        5000.0, 5.0, 5.0, 0.0, 0.05, 100.0).is_err());
+    assert!(manglik_bergles_twisted_tape_nusselt(
+        5000.0, 5.0, 5.0, 3.0, 0.8, 100.0).is_err());
+}
+
+/// helical coils in laminar flow follow the Manlapaz and 
+/// Churchill dean number correlation,
+///
+/// for De = 100 and Pr = 5, Nu = 11.426 
+///
+/// secondary flow means the coil nusselt number is higher than the 
+/// straight tube, and the transition regime joins the laminar 
+/// and turbulent correlations continuously
+#[test]
+pub fn helical_coil_dean_number_correlation(){
+    use uom::si::ratio::ratio;
+    use uom::si::f64::*;
+
+    use crate::heat_transfer_correlations::nusselt_number_correlations::
+        enums::NusseltCorrelation;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::
+        input_structs::HelicalCoilData;
+    use crate::heat_transfer_correlations::nusselt_number_correlations::
+        enhanced_surface_correlations::*;
+    use crate::fluid_mechanics_correlations::
+        enhanced_surface_friction_factor::*;
+
+    approx::assert_relative_eq!(
+        manlapaz_churchill_helical_coil_nusselt_laminar(100.0, 5.0),
+        11.426, max_relative=1e-4);
+
+    // d/D = 0.04, so Re = 500 gives De = 100
+    let tube_to_coil_diameter_ratio = 0.04;
+    let coil_data = HelicalCoilData {
+        reynolds: Ratio::new::<ratio>(500.0),
+        prandtl_bulk: Ratio::new::<ratio>(5.0),
+        prandtl_wall: Ratio::new::<ratio>(5.0),
+        tube_to_coil_diameter_ratio: Ratio::new::<ratio>(
+            tube_to_coil_diameter_ratio),
+    };
+
+    let coil_nusselt = NusseltCorrelation::HelicalCoilDean(coil_data)
+        .try_get_nusselt().unwrap().get::<ratio>();
+
+    approx::assert_relative_eq!(coil_nusselt, 11.426, max_relative=1e-4);
+    assert!(coil_nusselt > 3.657);
+
+    // continuity at the critical reynolds number and at the 
+    // fully turbulent limit
+    let critical_reynolds = helical_coil_critical_reynolds_number(
+        tube_to_coil_diameter_ratio);
+
+    let nusselt_at = |reynolds: f64| -> f64 {
+        helical_coil_nusselt(reynolds, 5.0, 5.0, 
+            tube_to_coil_diameter_ratio).unwrap()
+    };
+
+    approx::assert_relative_eq!(nusselt_at(critical_reynolds + 1e-6),
+        nusselt_at(critical_reynolds), max_relative=1e-6);
+    approx::assert_relative_eq!(nusselt_at(22000.0 - 1e-6),
+        nusselt_at(22000.0), max_relative=1e-6);
+
+    assert!(nusselt_at(30000.0) > nusselt_at(critical_reynolds));
+
+    assert!(helical_coil_nusselt(1000.0, 5.0, 5.0, 0.0).is_err());
+}
@@ -0,0 +1,390 @@
+use std::f64::consts::PI;
+
+use uom::si::f64::*;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::ratio::ratio;
+use uom::ConstZero;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::{get_minimum_heat_transfer_coeff, get_reynolds_from_mass_velocity, BaffledShellGeometry, ShellSideFluidProperties, TubeLayout};
+
+/// below this shell side reynolds number, flow is treated as
+/// laminar in the correction factors
+const LAMINAR_REYNOLDS_LIMIT: f64 = 100.0;
+
+// coefficients a1, a2, b1, b2 for the ideal tube bank j and f
+// factors (Taborek), for reynolds numbers above 10^4, 10^3,
+// 10^2, 10 and below 10 respectively
+struct IdealTubeBankCoefficients {
+    a1: f64,
+    a2: f64,
+    a3: f64,
+    a4: f64,
+    b1: f64,
+    b2: f64,
+    b3: f64,
+    b4: f64,
+}
+
+// (a1, a2, b1, b2) for each reynolds number range
+type ReynoldsRangeCoefficients = [(f64, f64, f64, f64); 5];
+
+fn get_ideal_tube_bank_coefficients(tube_layout: TubeLayout,
+    reynolds: f64) -> IdealTubeBankCoefficients {
+
+    // index of the reynolds number range
+    let range = if reynolds >= 1.0e4 {
+        0
+    } else if reynolds >= 1.0e3 {
+        1
+    } else if reynolds >= 1.0e2 {
+        2
+    } else if reynolds >= 10.0 {
+        3
+    } else {
+        4
+    };
+
+    // (a1, a2, b1, b2) for each range, then (a3, a4, b3, b4)
+    let (ranges, a3, a4, b3, b4): (ReynoldsRangeCoefficients, f64, f64, f64, f64) =
+        match tube_layout {
+            TubeLayout::Triangular30 | TubeLayout::RotatedTriangular60 => ([
+                (0.321, -0.388, 0.372, -0.123),
+                (0.321, -0.388, 0.486, -0.152),
+                (0.593, -0.477, 4.570, -0.476),
+                (1.360, -0.657, 45.10, -0.973),
+                (1.400, -0.667, 48.00, -1.000),
+            ], 1.450, 0.519, 7.00, 0.500),
+            TubeLayout::RotatedSquare45 => ([
+                (0.370, -0.396, 0.303, -0.126),
+                (0.370, -0.396, 0.333, -0.136),
+                (0.730, -0.500, 3.500, -0.476),
+                (0.498, -0.656, 26.20, -0.913),
+                (1.550, -0.667, 32.00, -1.000),
+            ], 1.930, 0.500, 6.59, 0.520),
+            TubeLayout::Square90 => ([
+                (0.370, -0.395, 0.391, -0.148),
+                (0.107, -0.266, 0.0815, 0.022),
+                (0.408, -0.460, 6.090, -0.602),
+                (0.900, -0.631, 32.10, -0.963),
+                (0.970, -0.667, 35.00, -1.000),
+            ], 1.187, 0.370, 6.30, 0.378),
+        };
+
+    let (a1, a2, b1, b2) = ranges[range];
+
+    IdealTubeBankCoefficients { a1, a2, a3, a4, b1, b2, b3, b4 }
+}
+
+/// shell side reynolds number for the Bell-Delaware method,
+/// based on the tube outer diameter and the mass velocity at the
+/// crossflow area at the shell centreline
+pub fn get_bell_delaware_reynolds_number(geometry: &BaffledShellGeometry,
+    mass_flowrate: MassRate,
+    fluid: &ShellSideFluidProperties) -> f64 {
+
+    get_reynolds_from_mass_velocity(mass_flowrate,
+        geometry.get_bell_delaware_crossflow_area(),
+        geometry.tube_od,
+        fluid.dynamic_viscosity)
+}
+
+/// Colburn j factor for an ideal tube bank,
+///
+/// j_i = a1 (1.33/(p_t/d_o))^a Re^a2
+/// a = a3/(1 + 0.14 Re^a4)
+pub fn get_ideal_tube_bank_colburn_j_factor(tube_layout: TubeLayout,
+    pitch_to_diameter_ratio: f64,
+    reynolds: f64) -> f64 {
+
+    let coefficients = get_ideal_tube_bank_coefficients(tube_layout, reynolds);
+    let a = coefficients.a3 / (1.0 + 0.14 * reynolds.powf(coefficients.a4));
+
+    coefficients.a1 * (1.33/pitch_to_diameter_ratio).powf(a)
+        * reynolds.powf(coefficients.a2)
+}
+
+/// fanning type friction factor for an ideal tube bank,
+///
+/// f_i = b1 (1.33/(p_t/d_o))^b Re^b2
+/// b = b3/(1 + 0.14 Re^b4)
+pub fn get_ideal_tube_bank_friction_factor(tube_layout: TubeLayout,
+    pitch_to_diameter_ratio: f64,
+    reynolds: f64) -> f64 {
+
+    let coefficients = get_ideal_tube_bank_coefficients(tube_layout, reynolds);
+    let b = coefficients.b3 / (1.0 + 0.14 * reynolds.powf(coefficients.b4));
+
+    coefficients.b1 * (1.33/pitch_to_diameter_ratio).powf(b)
+        * reynolds.powf(coefficients.b2)
+}
+
+/// correction factors for the ideal tube bank heat transfer
+/// coefficient in the Bell-Delaware method
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct BellDelawareCorrectionFactors {
+    /// baffle cut and spacing correction, J_c
+    pub baffle_cut: f64,
+    /// baffle leakage correction, J_l
+    pub baffle_leakage: f64,
+    /// bundle bypass correction, J_b
+    pub bundle_bypass: f64,
+    /// unequal inlet and outlet baffle spacing correction, J_s
+    pub unequal_baffle_spacing: f64,
+    /// laminar flow adverse temperature gradient correction, J_r
+    pub laminar_flow: f64,
+}
+
+impl BellDelawareCorrectionFactors {
+
+    /// product of all correction factors
+    pub fn get_total(&self) -> f64 {
+        self.baffle_cut * self.baffle_leakage * self.bundle_bypass
+            * self.unequal_baffle_spacing * self.laminar_flow
+    }
+}
+
+// ratios of leakage areas used in both J_l and R_l,
+// (r_s, r_lm), r_s is the shell to baffle fraction of leakage area,
+// r_lm is the leakage area divided by the crossflow area
+//
+// returns None if there is no leakage area at all
+fn get_leakage_area_ratios(geometry: &BaffledShellGeometry) -> Option<(f64, f64)> {
+
+    let shell_to_baffle_area = geometry.get_shell_to_baffle_leakage_area();
+    let total_leakage_area = shell_to_baffle_area
+        + geometry.get_tube_to_baffle_leakage_area();
+
+    if total_leakage_area <= Area::ZERO {
+        return None;
+    }
+
+    Some(((shell_to_baffle_area/total_leakage_area).get::<ratio>(),
+        (total_leakage_area/geometry.get_bell_delaware_crossflow_area()).get::<ratio>()))
+}
+
+// exp(-C F_sbp (1 - (2 r_ss)^(1/3))) form shared by J_b and R_b
+fn get_bypass_factor(geometry: &BaffledShellGeometry,
+    coefficient: f64) -> f64 {
+
+    let sealing_strip_ratio = geometry.number_of_sealing_strip_pairs as f64
+        / geometry.get_tube_rows_crossed_in_crossflow();
+
+    if sealing_strip_ratio >= 0.5 {
+        return 1.0;
+    }
+
+    let bypass_area_fraction: f64 = (geometry.get_bundle_bypass_area()
+        / geometry.get_bell_delaware_crossflow_area()).get::<ratio>();
+
+    (-coefficient * bypass_area_fraction
+        * (1.0 - (2.0 * sealing_strip_ratio).cbrt())).exp()
+}
+
+/// obtains the heat transfer correction factors for the
+/// Bell-Delaware method at a given shell side reynolds number
+pub fn try_get_bell_delaware_correction_factors(
+    geometry: &BaffledShellGeometry,
+    reynolds: f64) -> Result<BellDelawareCorrectionFactors, TuasLibError> {
+
+    let number_of_baffles = geometry.get_number_of_baffles()? as f64;
+    let laminar = reynolds < LAMINAR_REYNOLDS_LIMIT;
+
+    // baffle cut, J_c = 0.55 + 0.72 F_c
+    let fraction_of_tubes_in_crossflow =
+        1.0 - 2.0 * geometry.get_fraction_of_tubes_in_window();
+    let baffle_cut = 0.55 + 0.72 * fraction_of_tubes_in_crossflow;
+
+    // baffle leakage
+    let baffle_leakage = match get_leakage_area_ratios(geometry) {
+        Some((r_s, r_lm)) => {
+            0.44 * (1.0 - r_s) + (1.0 - 0.44 * (1.0 - r_s)) * (-2.2 * r_lm).exp()
+        },
+        None => 1.0,
+    };
+
+    // bundle bypass
+    let bypass_coefficient = if laminar { 1.35 } else { 1.25 };
+    let bundle_bypass = get_bypass_factor(geometry, bypass_coefficient);
+
+    // unequal baffle spacing at the inlet and outlet
+    let n: f64 = if laminar { 1.0/3.0 } else { 0.6 };
+    let inlet_spacing_ratio: f64 = (geometry.inlet_baffle_spacing
+        / geometry.central_baffle_spacing).get::<ratio>();
+    let outlet_spacing_ratio: f64 = (geometry.outlet_baffle_spacing
+        / geometry.central_baffle_spacing).get::<ratio>();
+
+    let unequal_baffle_spacing = (number_of_baffles - 1.0
+        + inlet_spacing_ratio.powf(1.0 - n)
+        + outlet_spacing_ratio.powf(1.0 - n))
+        / (number_of_baffles - 1.0 + inlet_spacing_ratio + outlet_spacing_ratio);
+
+    // adverse temperature gradient in laminar flow
+    let laminar_flow = if laminar {
+        let rows_crossed = (geometry.get_tube_rows_crossed_in_crossflow()
+            + geometry.get_tube_rows_crossed_in_window())
+            * (number_of_baffles + 1.0);
+        let laminar_flow_at_re_20 = (10.0/rows_crossed).powf(0.18);
+
+        let laminar_flow_correction = if reynolds <= 20.0 {
+            laminar_flow_at_re_20
+        } else {
+            laminar_flow_at_re_20
+                + (20.0 - reynolds)/80.0 * (laminar_flow_at_re_20 - 1.0)
+        };
+
+        laminar_flow_correction.clamp(0.4, 1.0)
+    } else {
+        1.0
+    };
+
+    Ok(BellDelawareCorrectionFactors {
+        baffle_cut,
+        baffle_leakage,
+        bundle_bypass,
+        unequal_baffle_spacing,
+        laminar_flow,
+    })
+}
+
+/// shell side heat transfer coefficient using the
+/// Bell-Delaware method,
+///
+/// h = j_i c_p G Pr^(-2/3) (mu/mu_w)^0.14 J_c J_l J_b J_s J_r
+///
+/// floored at the minimum heat transfer coefficient for
+/// stagnant or very slow flow
+pub fn try_get_bell_delaware_heat_transfer_coeff(
+    geometry: &BaffledShellGeometry,
+    mass_flowrate: MassRate,
+    fluid: &ShellSideFluidProperties) -> Result<HeatTransfer, TuasLibError> {
+
+    let minimum_heat_transfer_coeff =
+        get_minimum_heat_transfer_coeff(geometry, fluid);
+
+    if mass_flowrate.get::<kilogram_per_second>() == 0.0 {
+        return Ok(minimum_heat_transfer_coeff);
+    }
+
+    let reynolds = get_bell_delaware_reynolds_number(geometry, mass_flowrate, fluid);
+    let pitch_to_diameter_ratio: f64 =
+        (geometry.tube_pitch/geometry.tube_od).get::<ratio>();
+
+    let colburn_j_factor = get_ideal_tube_bank_colburn_j_factor(
+        geometry.tube_layout, pitch_to_diameter_ratio, reynolds);
+
+    let prandtl = fluid.get_prandtl().get::<ratio>();
+
+    let ideal_heat_transfer_coeff: HeatTransfer = colburn_j_factor * fluid.cp
+        * mass_flowrate / geometry.get_bell_delaware_crossflow_area()
+        * prandtl.powf(-2.0/3.0) * fluid.get_viscosity_ratio().powf(0.14);
+
+    let correction_factors =
+        try_get_bell_delaware_correction_factors(geometry, reynolds)?;
+
+    let heat_transfer_coeff: HeatTransfer =
+        ideal_heat_transfer_coeff * correction_factors.get_total();
+
+    Ok(heat_transfer_coeff.max(minimum_heat_transfer_coeff))
+}
+
+/// shell side pressure drop using the Bell-Delaware method,
+/// which is the sum of the crossflow, window and end zone
+/// pressure drops,
+///
+/// dP = (N_b - 1) dP_bi R_b R_l + N_b dP_wi R_l
+/// + 2 dP_bi (1 + N_tcw/N_tcc) R_b R_s
+///
+/// where dP_bi is the ideal tube bank pressure drop over one
+/// crossflow section and dP_wi is the ideal window pressure drop
+pub fn try_get_bell_delaware_pressure_drop(
+    geometry: &BaffledShellGeometry,
+    mass_flowrate: MassRate,
+    fluid: &ShellSideFluidProperties) -> Result<Pressure, TuasLibError> {
+
+    if mass_flowrate.get::<kilogram_per_second>() == 0.0 {
+        return Ok(Pressure::ZERO);
+    }
+
+    let reynolds = get_bell_delaware_reynolds_number(geometry, mass_flowrate, fluid);
+    let laminar = reynolds < LAMINAR_REYNOLDS_LIMIT;
+    let number_of_baffles = geometry.get_number_of_baffles()? as f64;
+    let pitch_to_diameter_ratio: f64 =
+        (geometry.tube_pitch/geometry.tube_od).get::<ratio>();
+
+    let rows_crossed_in_crossflow = geometry.get_tube_rows_crossed_in_crossflow();
+    let rows_crossed_in_window = geometry.get_tube_rows_crossed_in_window();
+
+    let crossflow_area = geometry.get_bell_delaware_crossflow_area();
+    let window_area = geometry.get_window_flow_area();
+
+    // ideal tube bank pressure drop for one crossflow section
+    let friction_factor = get_ideal_tube_bank_friction_factor(
+        geometry.tube_layout, pitch_to_diameter_ratio, reynolds);
+
+    let ideal_crossflow_pressure_drop: Pressure = 2.0 * friction_factor
+        * rows_crossed_in_crossflow
+        * mass_flowrate * mass_flowrate / (crossflow_area * crossflow_area)
+        / fluid.density
+        * fluid.get_viscosity_ratio().powf(-0.14);
+
+    // ideal pressure drop for one window, based on the geometric
+    // mean of the crossflow and window mass velocities
+    let window_mass_velocity_squared = mass_flowrate * mass_flowrate
+        / (crossflow_area * window_area);
+
+    let ideal_window_pressure_drop: Pressure = if laminar {
+
+        let theta_ds = geometry.get_baffle_window_angle_shell();
+        let tubes_in_window = geometry.number_of_tubes as f64
+            * geometry.get_fraction_of_tubes_in_window();
+        let window_hydraulic_diameter: Length = 4.0 * window_area
+            / (PI * geometry.tube_od * tubes_in_window
+                + 0.5 * theta_ds * geometry.shell_id);
+
+        let window_mass_velocity = mass_flowrate
+            / (crossflow_area * window_area).sqrt();
+
+        26.0 * fluid.dynamic_viscosity * window_mass_velocity / fluid.density
+            * (rows_crossed_in_window / (geometry.tube_pitch - geometry.tube_od)
+                + geometry.central_baffle_spacing
+                / (window_hydraulic_diameter * window_hydraulic_diameter))
+            + window_mass_velocity_squared / fluid.density
+    } else {
+        (2.0 + 0.6 * rows_crossed_in_window)
+            * window_mass_velocity_squared / (2.0 * fluid.density)
+    };
+
+    // correction factors
+    let baffle_leakage = match get_leakage_area_ratios(geometry) {
+        Some((r_s, r_lm)) => {
+            let p = 0.8 - 0.15 * (1.0 + r_s);
+            (-1.33 * (1.0 + r_s) * r_lm.powf(p)).exp()
+        },
+        None => 1.0,
+    };
+
+    let bypass_coefficient = if laminar { 4.5 } else { 3.7 };
+    let bundle_bypass = get_bypass_factor(geometry, bypass_coefficient);
+
+    let n: f64 = if laminar { 1.0 } else { 0.2 };
+    let inlet_spacing_ratio: f64 = (geometry.central_baffle_spacing
+        / geometry.inlet_baffle_spacing).get::<ratio>();
+    let outlet_spacing_ratio: f64 = (geometry.central_baffle_spacing
+        / geometry.outlet_baffle_spacing).get::<ratio>();
+    let unequal_baffle_spacing = 0.5 * (inlet_spacing_ratio.powf(2.0 - n)
+        + outlet_spacing_ratio.powf(2.0 - n));
+
+    let crossflow_pressure_drop = (number_of_baffles - 1.0)
+        * ideal_crossflow_pressure_drop * bundle_bypass * baffle_leakage;
+
+    let window_pressure_drop = number_of_baffles
+        * ideal_window_pressure_drop * baffle_leakage;
+
+    let end_zone_pressure_drop = 2.0 * ideal_crossflow_pressure_drop
+        * (1.0 + rows_crossed_in_window/rows_crossed_in_crossflow)
+        * bundle_bypass * unequal_baffle_spacing;
+
+    Ok(crossflow_pressure_drop + window_pressure_drop + end_zone_pressure_drop)
+}
@@ -0,0 +1,89 @@
+use uom::si::f64::*;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::ratio::ratio;
+use uom::ConstZero;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::{get_minimum_heat_transfer_coeff, get_reynolds_from_mass_velocity, BaffledShellGeometry, ShellSideFluidProperties};
+
+/// shell side reynolds number for Kern's method, based on the
+/// equivalent diameter and the mass velocity at the crossflow area
+/// at the shell centreline
+pub fn get_kern_reynolds_number(geometry: &BaffledShellGeometry,
+    mass_flowrate: MassRate,
+    fluid: &ShellSideFluidProperties) -> f64 {
+
+    get_reynolds_from_mass_velocity(mass_flowrate,
+        geometry.get_kern_crossflow_area(),
+        geometry.get_kern_equivalent_diameter(),
+        fluid.dynamic_viscosity)
+}
+
+/// shell side nusselt number based on the equivalent diameter
+/// using Kern's correlation,
+///
+/// Nu = 0.36 Re^0.55 Pr^(1/3) (mu/mu_w)^0.14
+///
+/// this is meant for 2000 < Re < 10^6, lower reynolds numbers
+/// are extrapolated
+pub fn get_kern_nusselt_number(reynolds: f64,
+    prandtl: f64,
+    viscosity_ratio: f64) -> f64 {
+
+    0.36 * reynolds.powf(0.55) * prandtl.powf(1.0/3.0)
+        * viscosity_ratio.powf(0.14)
+}
+
+/// shell side friction factor for Kern's method,
+///
+/// f = exp(0.576 - 0.19 ln Re)
+pub fn get_kern_friction_factor(reynolds: f64) -> f64 {
+    (0.576 - 0.19 * reynolds.ln()).exp()
+}
+
+/// shell side heat transfer coefficient using Kern's method,
+/// floored at the minimum heat transfer coefficient for
+/// stagnant or very slow flow
+pub fn try_get_kern_heat_transfer_coeff(geometry: &BaffledShellGeometry,
+    mass_flowrate: MassRate,
+    fluid: &ShellSideFluidProperties) -> Result<HeatTransfer, TuasLibError> {
+
+    let reynolds = get_kern_reynolds_number(geometry, mass_flowrate, fluid);
+    let prandtl = fluid.get_prandtl().get::<ratio>();
+
+    let nusselt = get_kern_nusselt_number(reynolds,
+        prandtl,
+        fluid.get_viscosity_ratio());
+
+    let heat_transfer_coeff: HeatTransfer = nusselt * fluid.thermal_conductivity
+        / geometry.get_kern_equivalent_diameter();
+
+    Ok(heat_transfer_coeff.max(get_minimum_heat_transfer_coeff(geometry, fluid)))
+}
+
+/// shell side pressure drop using Kern's method,
+///
+/// dP = f G_s^2 D_s (N_b + 1) / (2 rho D_e (mu/mu_w)^0.14)
+pub fn try_get_kern_pressure_drop(geometry: &BaffledShellGeometry,
+    mass_flowrate: MassRate,
+    fluid: &ShellSideFluidProperties) -> Result<Pressure, TuasLibError> {
+
+    if mass_flowrate.get::<kilogram_per_second>() == 0.0 {
+        return Ok(Pressure::ZERO);
+    }
+
+    let reynolds = get_kern_reynolds_number(geometry, mass_flowrate, fluid);
+    let friction_factor = get_kern_friction_factor(reynolds);
+    let number_of_baffles = geometry.get_number_of_baffles()? as f64;
+
+    let crossflow_area: Area = geometry.get_kern_crossflow_area();
+
+    let pressure_drop: Pressure = friction_factor
+        * mass_flowrate * mass_flowrate / (crossflow_area * crossflow_area)
+        * geometry.shell_id * (number_of_baffles + 1.0)
+        / (2.0 * fluid.density * geometry.get_kern_equivalent_diameter()
+            * fluid.get_viscosity_ratio().powf(0.14));
+
+    Ok(pressure_drop)
+}
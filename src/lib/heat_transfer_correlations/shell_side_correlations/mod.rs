@@ -0,0 +1,538 @@
+//! this module contains shell side heat transfer and pressure drop
+//! correlations for baffled (segmental baffle) shell and tube
+//! heat exchangers
+//!
+//! unlike the generic nusselt correlations used for the shell side
+//! of the SimpleShellAndTubeHeatExchanger, these are predictive,
+//! ie. they only require the shell geometry (baffle spacing,
+//! baffle cut, tube pitch and layout, clearances) and fluid
+//! properties, so no experimental calibration is required
+//!
+//! two methods are available:
+//!
+//! 1. Kern's method, which treats the shell side as crossflow
+//! over an ideal tube bank based on an equivalent diameter.
+//! It is simple but does not account for leakage and bypass streams
+//!
+//! Kern, D. Q. (1950). Process heat transfer. McGraw-Hill.
+//!
+//! 2. the Bell-Delaware method, where the ideal tube bank heat
+//! transfer coefficient and pressure drop are corrected for
+//! baffle window flow, baffle leakage, bundle bypass, unequal
+//! end baffle spacing and laminar flow
+//!
+//! Taborek, J. (1983). Shell-and-tube heat exchangers:
+//! single-phase flow. Heat exchanger design handbook, 3(3.3).
+//!
+//! both methods assume a single shell pass with single segmental
+//! baffles, nozzle pressure losses are not included
+//!
+//! both correlations give a heat transfer coefficient tending to
+//! zero as the flow stops, so the heat transfer coefficient is
+//! floored at that of a single cylinder in crossflow as the
+//! reynolds number tends to zero (Nu = 0.3 based on the tube od),
+//! which accounts for conduction and natural convection to the
+//! tubes during stagnant flow
+//!
+//! Churchill, S. W., & Bernstein, M. (1977). A correlating equation
+//! for forced convection from gases and liquids to a circular
+//! cylinder in crossflow. Journal of Heat Transfer, 99(2), 300-306.
+use std::f64::consts::PI;
+
+use uom::si::f64::*;
+use uom::si::length::millimeter;
+use uom::si::ratio::ratio;
+
+use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::tuas_lib_error::TuasLibError;
+
+/// Kern's method for shell side heat transfer and pressure drop
+pub mod kern;
+
+/// Bell-Delaware method for shell side heat transfer and
+/// pressure drop
+pub mod bell_delaware;
+
+/// tests for shell side correlations
+#[cfg(test)]
+pub mod tests;
+
+/// tube layout angle with respect to the crossflow direction
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum TubeLayout {
+    /// 30 degree triangular layout
+    Triangular30,
+    /// 60 degree rotated triangular layout
+    RotatedTriangular60,
+    /// 90 degree square (inline) layout
+    Square90,
+    /// 45 degree rotated square layout
+    RotatedSquare45,
+}
+
+impl TubeLayout {
+
+    /// tube pitch parallel to the flow direction divided by the
+    /// tube pitch, used to obtain the number of tube rows crossed
+    pub fn get_parallel_pitch_ratio(&self) -> f64 {
+        match self {
+            TubeLayout::Triangular30 => 0.866,
+            TubeLayout::RotatedTriangular60 => 0.5,
+            TubeLayout::Square90 => 1.0,
+            TubeLayout::RotatedSquare45 => 0.707,
+        }
+    }
+
+    /// effective tube pitch normal to the flow direction divided
+    /// by the tube pitch, used to obtain the crossflow area
+    pub fn get_normal_pitch_ratio(&self) -> f64 {
+        match self {
+            TubeLayout::Triangular30 => 1.0,
+            TubeLayout::RotatedTriangular60 => 0.866,
+            TubeLayout::Square90 => 1.0,
+            TubeLayout::RotatedSquare45 => 0.707,
+        }
+    }
+
+    /// returns true for triangular layouts
+    pub fn is_triangular(&self) -> bool {
+        matches!(self,
+            TubeLayout::Triangular30 | TubeLayout::RotatedTriangular60)
+    }
+}
+
+/// geometry of the shell side of a single pass, single segmental
+/// baffle shell and tube heat exchanger
+///
+/// clearances are diametral clearances, ie. the difference in
+/// diameters, not the gap width
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct BaffledShellGeometry {
+    /// shell inner diameter
+    pub shell_id: Length,
+    /// tube outer diameter
+    pub tube_od: Length,
+    /// centre to centre tube pitch
+    pub tube_pitch: Length,
+    /// tube layout angle
+    pub tube_layout: TubeLayout,
+    /// total number of tubes in the shell
+    pub number_of_tubes: u32,
+    /// tube length between tubesheets
+    pub tube_length: Length,
+    /// spacing between central baffles
+    pub central_baffle_spacing: Length,
+    /// spacing between the inlet tubesheet and the first baffle
+    pub inlet_baffle_spacing: Length,
+    /// spacing between the last baffle and the outlet tubesheet
+    pub outlet_baffle_spacing: Length,
+    /// baffle cut as a fraction of the shell inner diameter
+    pub baffle_cut: Ratio,
+    /// diametral clearance between the shell and baffles
+    pub shell_to_baffle_clearance: Length,
+    /// diametral clearance between the tubes and baffle holes
+    pub tube_to_baffle_clearance: Length,
+    /// diametral clearance between the shell and the outer
+    /// tube limit of the bundle
+    pub bundle_to_shell_clearance: Length,
+    /// number of sealing strip pairs
+    pub number_of_sealing_strip_pairs: u32,
+}
+
+impl BaffledShellGeometry {
+
+    /// constructs a shell side geometry with equal inlet, outlet
+    /// and central baffle spacings, no sealing strips, and
+    /// typical clearances for a fixed tubesheet exchanger (Taborek):
+    ///
+    /// shell to baffle: 3.1 mm + 0.004 D_s
+    /// tube to baffle: 0.4 mm
+    /// bundle to shell: 12 mm + 0.005 D_s
+    ///
+    /// the clearances may be changed afterwards if known
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_typical_clearances(shell_id: Length,
+        tube_od: Length,
+        tube_pitch: Length,
+        tube_layout: TubeLayout,
+        number_of_tubes: u32,
+        tube_length: Length,
+        baffle_spacing: Length,
+        baffle_cut: Ratio) -> Self {
+
+        Self {
+            shell_id,
+            tube_od,
+            tube_pitch,
+            tube_layout,
+            number_of_tubes,
+            tube_length,
+            central_baffle_spacing: baffle_spacing,
+            inlet_baffle_spacing: baffle_spacing,
+            outlet_baffle_spacing: baffle_spacing,
+            baffle_cut,
+            shell_to_baffle_clearance:
+                Length::new::<millimeter>(3.1) + 0.004 * shell_id,
+            tube_to_baffle_clearance: Length::new::<millimeter>(0.4),
+            bundle_to_shell_clearance:
+                Length::new::<millimeter>(12.0) + 0.005 * shell_id,
+            number_of_sealing_strip_pairs: 0,
+        }
+    }
+
+    /// checks that the geometry is physically sensible
+    pub fn try_validate(&self) -> Result<(), TuasLibError> {
+
+        let baffle_cut = self.baffle_cut.get::<ratio>();
+
+        if !(baffle_cut > 0.0 && baffle_cut < 0.5) {
+            return Err(TuasLibError::GenericStringError(
+                format!("baffle cut must be between 0 and 0.5 of the shell id, got {}",
+                    baffle_cut)));
+        }
+
+        if self.tube_pitch <= self.tube_od {
+            return Err(TuasLibError::GenericStringError(
+                "tube pitch must be larger than the tube od".to_owned()));
+        }
+
+        if self.number_of_tubes == 0 {
+            return Err(TuasLibError::GenericStringError(
+                "shell must contain at least one tube".to_owned()));
+        }
+
+        if self.get_outer_tube_limit_diameter() <= self.tube_od {
+            return Err(TuasLibError::GenericStringError(
+                "bundle to shell clearance is too large for the shell id".to_owned()));
+        }
+
+        if self.get_number_of_baffles()? == 0 {
+            return Err(TuasLibError::GenericStringError(
+                "baffle spacings are too large for the tube length".to_owned()));
+        }
+
+        Ok(())
+    }
+
+    /// number of baffles,
+    ///
+    /// N_b = (L - L_bi - L_bo)/L_bc + 1
+    ///
+    /// rounded to the nearest integer
+    pub fn get_number_of_baffles(&self) -> Result<u32, TuasLibError> {
+
+        let central_length: f64 = ((self.tube_length
+            - self.inlet_baffle_spacing
+            - self.outlet_baffle_spacing)
+            /self.central_baffle_spacing).get::<ratio>();
+
+        if central_length < 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "inlet and outlet baffle spacings exceed the tube length".to_owned()));
+        }
+
+        Ok((central_length + 1.0).round() as u32)
+    }
+
+    /// diameter of the outer tube limit of the bundle,
+    /// D_otl = D_s - L_bb
+    pub fn get_outer_tube_limit_diameter(&self) -> Length {
+        self.shell_id - self.bundle_to_shell_clearance
+    }
+
+    /// diameter of the circle through the centres of the
+    /// outermost tubes, D_ctl = D_otl - d_o
+    pub fn get_central_tube_limit_diameter(&self) -> Length {
+        self.get_outer_tube_limit_diameter() - self.tube_od
+    }
+
+    /// baffle cut length, L_c = B_c D_s
+    pub fn get_baffle_cut_length(&self) -> Length {
+        self.baffle_cut * self.shell_id
+    }
+
+    /// crossflow area at the shell centreline for Kern's method,
+    ///
+    /// A_s = D_s L_bc (p_t - d_o)/p_t
+    pub fn get_kern_crossflow_area(&self) -> Area {
+        self.shell_id * self.central_baffle_spacing
+            * (self.tube_pitch - self.tube_od) / self.tube_pitch
+    }
+
+    /// shell side equivalent diameter for Kern's method
+    ///
+    /// square layouts:
+    /// D_e = 4 (p_t^2 - pi d_o^2/4) / (pi d_o)
+    ///
+    /// triangular layouts:
+    /// D_e = 4 (sqrt(3)/4 p_t^2 - pi d_o^2/8) / (pi d_o/2)
+    pub fn get_kern_equivalent_diameter(&self) -> Length {
+
+        let pitch = self.tube_pitch;
+        let od = self.tube_od;
+
+        if self.tube_layout.is_triangular() {
+            4.0 * (3.0_f64.sqrt()/4.0 * pitch * pitch - PI * od * od/8.0)
+                / (0.5 * PI * od)
+        } else {
+            4.0 * (pitch * pitch - PI * od * od/4.0) / (PI * od)
+        }
+    }
+
+    /// crossflow area at the shell centreline for the
+    /// Bell-Delaware method,
+    ///
+    /// S_m = L_bc [L_bb + D_ctl/p_t,eff (p_t - d_o)]
+    pub fn get_bell_delaware_crossflow_area(&self) -> Area {
+
+        let effective_pitch = self.tube_pitch
+            * self.tube_layout.get_normal_pitch_ratio();
+
+        self.central_baffle_spacing * (self.bundle_to_shell_clearance
+            + self.get_central_tube_limit_diameter() / effective_pitch
+            * (self.tube_pitch - self.tube_od))
+    }
+
+    /// angle subtended by the baffle cut at the shell wall
+    /// in radians
+    pub fn get_baffle_window_angle_shell(&self) -> f64 {
+        let cut_fraction = self.baffle_cut.get::<ratio>();
+        2.0 * (1.0 - 2.0 * cut_fraction).acos()
+    }
+
+    /// angle subtended by the baffle cut at the central tube
+    /// limit diameter in radians
+    pub fn get_baffle_window_angle_tube_limit(&self) -> f64 {
+        let cosine: f64 = ((self.shell_id - 2.0 * self.get_baffle_cut_length())
+            / self.get_central_tube_limit_diameter()).get::<ratio>();
+
+        2.0 * cosine.clamp(-1.0, 1.0).acos()
+    }
+
+    /// fraction of tubes in one baffle window
+    pub fn get_fraction_of_tubes_in_window(&self) -> f64 {
+        let theta_ctl = self.get_baffle_window_angle_tube_limit();
+        theta_ctl/(2.0 * PI) - theta_ctl.sin()/(2.0 * PI)
+    }
+
+    /// net flow area in one baffle window, which is the
+    /// gross window area minus the area taken up by tubes
+    pub fn get_window_flow_area(&self) -> Area {
+        let theta_ds = self.get_baffle_window_angle_shell();
+        let gross_window_area: Area =
+            self.shell_id * self.shell_id / 8.0 * (theta_ds - theta_ds.sin());
+        let tube_area: Area = self.number_of_tubes as f64
+            * self.get_fraction_of_tubes_in_window()
+            * PI * self.tube_od * self.tube_od / 4.0;
+
+        gross_window_area - tube_area
+    }
+
+    /// number of tube rows crossed between the baffle tips
+    /// in one crossflow section
+    pub fn get_tube_rows_crossed_in_crossflow(&self) -> f64 {
+        let parallel_pitch = self.tube_pitch
+            * self.tube_layout.get_parallel_pitch_ratio();
+
+        ((self.shell_id - 2.0 * self.get_baffle_cut_length())
+            / parallel_pitch).get::<ratio>()
+    }
+
+    /// effective number of tube rows crossed in one baffle window
+    pub fn get_tube_rows_crossed_in_window(&self) -> f64 {
+        let parallel_pitch = self.tube_pitch
+            * self.tube_layout.get_parallel_pitch_ratio();
+
+        let rows: f64 = (0.8 * (self.get_baffle_cut_length()
+            - 0.5 * (self.shell_id - self.get_central_tube_limit_diameter()))
+            / parallel_pitch).get::<ratio>();
+
+        rows.max(0.0)
+    }
+
+    /// shell to baffle leakage area for one baffle
+    pub fn get_shell_to_baffle_leakage_area(&self) -> Area {
+        let theta_ds = self.get_baffle_window_angle_shell();
+        PI * self.shell_id * self.shell_to_baffle_clearance / 2.0
+            * (1.0 - theta_ds/(2.0 * PI))
+    }
+
+    /// tube to baffle leakage area for one baffle
+    pub fn get_tube_to_baffle_leakage_area(&self) -> Area {
+        let hole_diameter = self.tube_od + self.tube_to_baffle_clearance;
+        let tubes_through_baffle = self.number_of_tubes as f64
+            * (1.0 - self.get_fraction_of_tubes_in_window());
+
+        PI/4.0 * (hole_diameter * hole_diameter - self.tube_od * self.tube_od)
+            * tubes_through_baffle
+    }
+
+    /// bypass flow area between the bundle and shell in one
+    /// crossflow section (no pass lanes for single tube pass)
+    pub fn get_bundle_bypass_area(&self) -> Area {
+        self.central_baffle_spacing * self.bundle_to_shell_clearance
+    }
+
+    /// total tube outer surface area on the shell side
+    pub fn get_tube_outer_surface_area(&self) -> Area {
+        self.number_of_tubes as f64 * PI * self.tube_od * self.tube_length
+    }
+}
+
+/// fluid properties needed for shell side correlations
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct ShellSideFluidProperties {
+    /// density at the bulk temperature
+    pub density: MassDensity,
+    /// dynamic viscosity at the bulk temperature
+    pub dynamic_viscosity: DynamicViscosity,
+    /// dynamic viscosity at the wall temperature,
+    /// used for the (mu/mu_w)^0.14 viscosity correction
+    pub wall_dynamic_viscosity: DynamicViscosity,
+    /// specific heat capacity at the bulk temperature
+    pub cp: SpecificHeatCapacity,
+    /// thermal conductivity at the bulk temperature
+    pub thermal_conductivity: ThermalConductivity,
+}
+
+impl ShellSideFluidProperties {
+
+    /// obtains properties of a liquid at the bulk temperature,
+    ///
+    /// if a wall temperature is given, the wall viscosity is
+    /// obtained at the wall temperature (clamped within the
+    /// range of the property correlations), otherwise no
+    /// viscosity correction is made
+    pub fn try_new(liquid: LiquidMaterial,
+        bulk_temperature: ThermodynamicTemperature,
+        wall_temperature: Option<ThermodynamicTemperature>)
+        -> Result<Self, TuasLibError> {
+
+        let dynamic_viscosity = liquid.try_get_dynamic_viscosity(bulk_temperature)?;
+
+        let wall_dynamic_viscosity = match wall_temperature {
+            Some(wall_temperature) => {
+                let wall_temperature_estimate = wall_temperature
+                    .max(liquid.min_temperature())
+                    .min(liquid.max_temperature());
+                liquid.try_get_dynamic_viscosity(wall_temperature_estimate)?
+            },
+            None => dynamic_viscosity,
+        };
+
+        Ok(Self {
+            density: liquid.try_get_density(bulk_temperature)?,
+            dynamic_viscosity,
+            wall_dynamic_viscosity,
+            cp: liquid.try_get_cp(bulk_temperature)?,
+            thermal_conductivity: liquid.try_get_thermal_conductivity(bulk_temperature)?,
+        })
+    }
+
+    /// prandtl number at the bulk temperature
+    pub fn get_prandtl(&self) -> Ratio {
+        self.dynamic_viscosity * self.cp / self.thermal_conductivity
+    }
+
+    /// viscosity ratio mu/mu_w
+    pub fn get_viscosity_ratio(&self) -> f64 {
+        (self.dynamic_viscosity / self.wall_dynamic_viscosity).get::<ratio>()
+    }
+}
+
+/// selects the shell side method
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum ShellSideMethod {
+    /// Kern's method
+    Kern,
+    /// Bell-Delaware method
+    BellDelaware,
+}
+
+/// a shell side model, which is a method together with the
+/// shell side geometry
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct BaffledShellSideModel {
+    /// Kern or Bell-Delaware
+    pub method: ShellSideMethod,
+    /// shell side geometry
+    pub geometry: BaffledShellGeometry,
+}
+
+impl BaffledShellSideModel {
+
+    /// constructs a model using Kern's method
+    pub fn new_kern(geometry: BaffledShellGeometry) -> Self {
+        Self { method: ShellSideMethod::Kern, geometry }
+    }
+
+    /// constructs a model using the Bell-Delaware method
+    pub fn new_bell_delaware(geometry: BaffledShellGeometry) -> Self {
+        Self { method: ShellSideMethod::BellDelaware, geometry }
+    }
+
+    /// shell side heat transfer coefficient to the tube outer
+    /// surface, the direction of flow does not matter
+    pub fn try_get_heat_transfer_coeff(&self,
+        mass_flowrate: MassRate,
+        fluid: &ShellSideFluidProperties) -> Result<HeatTransfer, TuasLibError> {
+
+        self.geometry.try_validate()?;
+
+        match self.method {
+            ShellSideMethod::Kern =>
+                kern::try_get_kern_heat_transfer_coeff(
+                    &self.geometry, mass_flowrate.abs(), fluid),
+            ShellSideMethod::BellDelaware =>
+                bell_delaware::try_get_bell_delaware_heat_transfer_coeff(
+                    &self.geometry, mass_flowrate.abs(), fluid),
+        }
+    }
+
+    /// shell side pressure drop from inlet to outlet nozzle,
+    /// excluding the nozzles themselves
+    ///
+    /// this is returned as a positive value regardless of flow
+    /// direction
+    pub fn try_get_pressure_drop(&self,
+        mass_flowrate: MassRate,
+        fluid: &ShellSideFluidProperties) -> Result<Pressure, TuasLibError> {
+
+        self.geometry.try_validate()?;
+
+        match self.method {
+            ShellSideMethod::Kern =>
+                kern::try_get_kern_pressure_drop(
+                    &self.geometry, mass_flowrate.abs(), fluid),
+            ShellSideMethod::BellDelaware =>
+                bell_delaware::try_get_bell_delaware_pressure_drop(
+                    &self.geometry, mass_flowrate.abs(), fluid),
+        }
+    }
+}
+
+/// shell side nusselt number based on the tube od below which
+/// the heat transfer coefficient is floored, this is the
+/// Churchill-Bernstein correlation for a cylinder in crossflow
+/// at zero reynolds number
+pub const MINIMUM_TUBE_NUSSELT_NUMBER: f64 = 0.3;
+
+/// lower bound for the shell side heat transfer coefficient
+/// for stagnant or very slow flow,
+///
+/// h_min = Nu_min k / d_o
+pub fn get_minimum_heat_transfer_coeff(geometry: &BaffledShellGeometry,
+    fluid: &ShellSideFluidProperties) -> HeatTransfer {
+
+    MINIMUM_TUBE_NUSSELT_NUMBER * fluid.thermal_conductivity / geometry.tube_od
+}
+
+/// reynolds number based on a mass velocity (mass flux)
+/// and length scale
+pub(crate) fn get_reynolds_from_mass_velocity(mass_flowrate: MassRate,
+    flow_area: Area,
+    length_scale: Length,
+    dynamic_viscosity: DynamicViscosity) -> f64 {
+
+    (mass_flowrate / flow_area * length_scale / dynamic_viscosity).get::<ratio>()
+}
@@ -0,0 +1,356 @@
+use uom::si::area::square_meter;
+use uom::si::dynamic_viscosity::pascal_second;
+use uom::si::f64::*;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::length::{inch, meter, millimeter};
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::pressure::pascal;
+use uom::si::ratio::ratio;
+use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+
+use super::bell_delaware::*;
+use super::kern::*;
+use super::*;
+
+// a typical single pass exchanger, 3/4 inch tubes on a 1 inch
+// square pitch in a 0.489 m shell
+fn typical_geometry(tube_layout: TubeLayout) -> BaffledShellGeometry {
+    BaffledShellGeometry::new_with_typical_clearances(
+        Length::new::<meter>(0.489),
+        Length::new::<inch>(0.75),
+        Length::new::<inch>(1.0),
+        tube_layout,
+        270,
+        Length::new::<meter>(4.88),
+        Length::new::<meter>(0.2),
+        Ratio::new::<ratio>(0.25))
+}
+
+// water-like properties, without viscosity correction
+fn water_like_properties() -> ShellSideFluidProperties {
+    ShellSideFluidProperties {
+        density: MassDensity::new::<kilogram_per_cubic_meter>(995.0),
+        dynamic_viscosity: DynamicViscosity::new::<pascal_second>(8.0e-4),
+        wall_dynamic_viscosity: DynamicViscosity::new::<pascal_second>(8.0e-4),
+        cp: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(4180.0),
+        thermal_conductivity: ThermalConductivity::new::<watt_per_meter_kelvin>(0.62),
+    }
+}
+
+/// Kern tabulates the equivalent diameters for common layouts,
+///
+/// 3/4 in tubes on 1 in square pitch: D_e = 0.95 in
+/// 3/4 in tubes on 1 in triangular pitch: D_e = 0.73 in
+///
+/// Kern, D. Q. (1950). Process heat transfer. McGraw-Hill. Fig 28
+#[test]
+pub fn kern_equivalent_diameter_matches_tabulated_values(){
+
+    let square_pitch = typical_geometry(TubeLayout::Square90);
+    let triangular_pitch = typical_geometry(TubeLayout::Triangular30);
+
+    approx::assert_relative_eq!(
+        square_pitch.get_kern_equivalent_diameter().get::<inch>(),
+        0.95,
+        max_relative=0.01
+        );
+
+    approx::assert_relative_eq!(
+        triangular_pitch.get_kern_equivalent_diameter().get::<inch>(),
+        0.73,
+        max_relative=0.02
+        );
+}
+
+/// checks Kern's heat transfer coefficient and pressure drop
+/// against a hand calculation
+///
+/// A_s = D_s B (p_t - d_o)/p_t
+/// Re = m D_e/(A_s mu)
+/// h = 0.36 k/D_e Re^0.55 Pr^(1/3)
+/// dP = exp(0.576 - 0.19 ln Re) G^2 D_s (N_b + 1)/(2 rho D_e)
+#[test]
+pub fn kern_method_hand_calculation(){
+
+    let geometry = typical_geometry(TubeLayout::Square90);
+    let fluid = water_like_properties();
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(20.0);
+
+    let shell_id: f64 = 0.489;
+    let tube_od: f64 = 0.75 * 0.0254;
+    let tube_pitch: f64 = 0.0254;
+    let baffle_spacing: f64 = 0.2;
+    let number_of_baffles = (4.88_f64/0.2 - 1.0).round();
+
+    let crossflow_area = shell_id * baffle_spacing * (tube_pitch - tube_od)/tube_pitch;
+    let equivalent_diameter = 4.0 * (tube_pitch * tube_pitch
+        - std::f64::consts::PI * tube_od * tube_od/4.0)
+        / (std::f64::consts::PI * tube_od);
+    let mass_velocity = 20.0/crossflow_area;
+    let reynolds = mass_velocity * equivalent_diameter/8.0e-4;
+    let prandtl: f64 = 8.0e-4 * 4180.0/0.62;
+
+    let expected_htc = 0.36 * 0.62/equivalent_diameter
+        * reynolds.powf(0.55) * prandtl.powf(1.0/3.0);
+    let expected_pressure_drop = (0.576 - 0.19 * reynolds.ln()).exp()
+        * mass_velocity * mass_velocity * shell_id * (number_of_baffles + 1.0)
+        / (2.0 * 995.0 * equivalent_diameter);
+
+    approx::assert_relative_eq!(
+        get_kern_reynolds_number(&geometry, mass_flowrate, &fluid),
+        reynolds,
+        max_relative=1e-10
+        );
+
+    let model = BaffledShellSideModel::new_kern(geometry);
+
+    approx::assert_relative_eq!(
+        model.try_get_heat_transfer_coeff(mass_flowrate, &fluid)
+            .unwrap().get::<watt_per_square_meter_kelvin>(),
+        expected_htc,
+        max_relative=1e-10
+        );
+
+    approx::assert_relative_eq!(
+        model.try_get_pressure_drop(mass_flowrate, &fluid)
+            .unwrap().get::<pascal>(),
+        expected_pressure_drop,
+        max_relative=1e-10
+        );
+
+    // reversing the flow does not change the magnitudes
+    approx::assert_relative_eq!(
+        model.try_get_pressure_drop(-mass_flowrate, &fluid)
+            .unwrap().get::<pascal>(),
+        expected_pressure_drop,
+        max_relative=1e-10
+        );
+}
+
+/// with no leakage or bypass clearances and equal baffle spacings,
+/// in turbulent flow, the only Bell-Delaware correction left is the
+/// baffle cut correction J_c, and the pressure drop correction
+/// factors are all one
+///
+/// the pressure drop is then:
+///
+/// dP = (N_b + 1) dP_bi + N_b dP_wi + 2 dP_bi N_tcw/N_tcc
+#[test]
+pub fn bell_delaware_without_leakage_or_bypass(){
+
+    let mut geometry = typical_geometry(TubeLayout::Square90);
+    geometry.shell_to_baffle_clearance = Length::new::<meter>(0.0);
+    geometry.tube_to_baffle_clearance = Length::new::<meter>(0.0);
+    geometry.bundle_to_shell_clearance = Length::new::<meter>(0.0);
+
+    let fluid = water_like_properties();
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(20.0);
+
+    let reynolds = get_bell_delaware_reynolds_number(&geometry, mass_flowrate, &fluid);
+    assert!(reynolds > 1.0e3);
+
+    let correction_factors =
+        try_get_bell_delaware_correction_factors(&geometry, reynolds).unwrap();
+
+    assert_eq!(correction_factors.baffle_leakage, 1.0);
+    assert_eq!(correction_factors.bundle_bypass, 1.0);
+    approx::assert_relative_eq!(correction_factors.unequal_baffle_spacing, 1.0,
+        max_relative=1e-12);
+    assert_eq!(correction_factors.laminar_flow, 1.0);
+    approx::assert_relative_eq!(correction_factors.get_total(),
+        correction_factors.baffle_cut, max_relative=1e-12);
+
+    // ideal tube bank heat transfer coefficient
+    let crossflow_area = geometry.get_bell_delaware_crossflow_area();
+    let colburn_j_factor = get_ideal_tube_bank_colburn_j_factor(
+        TubeLayout::Square90, 1.0/0.75, reynolds);
+    let prandtl = fluid.get_prandtl().get::<ratio>();
+    let ideal_htc: HeatTransfer = colburn_j_factor * fluid.cp
+        * mass_flowrate / crossflow_area * prandtl.powf(-2.0/3.0);
+
+    let htc = try_get_bell_delaware_heat_transfer_coeff(
+        &geometry, mass_flowrate, &fluid).unwrap();
+
+    approx::assert_relative_eq!(
+        htc.get::<watt_per_square_meter_kelvin>(),
+        ideal_htc.get::<watt_per_square_meter_kelvin>()
+            * correction_factors.baffle_cut,
+        max_relative=1e-10
+        );
+
+    // pressure drop
+    let number_of_baffles = geometry.get_number_of_baffles().unwrap() as f64;
+    let rows_crossflow = geometry.get_tube_rows_crossed_in_crossflow();
+    let rows_window = geometry.get_tube_rows_crossed_in_window();
+    let friction_factor = get_ideal_tube_bank_friction_factor(
+        TubeLayout::Square90, 1.0/0.75, reynolds);
+
+    let mass_flowrate_value: f64 = 20.0;
+    let crossflow_area_value = crossflow_area.get::<square_meter>();
+    let window_area_value = geometry.get_window_flow_area()
+        .get::<square_meter>();
+
+    let ideal_crossflow = 2.0 * friction_factor * rows_crossflow
+        * (mass_flowrate_value/crossflow_area_value).powi(2) / 995.0;
+    let ideal_window = (2.0 + 0.6 * rows_window)
+        * mass_flowrate_value.powi(2)/(crossflow_area_value * window_area_value)
+        / (2.0 * 995.0);
+
+    let expected_pressure_drop = (number_of_baffles + 1.0) * ideal_crossflow
+        + number_of_baffles * ideal_window
+        + 2.0 * ideal_crossflow * rows_window/rows_crossflow;
+
+    approx::assert_relative_eq!(
+        try_get_bell_delaware_pressure_drop(&geometry, mass_flowrate, &fluid)
+            .unwrap().get::<pascal>(),
+        expected_pressure_drop,
+        max_relative=1e-10
+        );
+}
+
+/// with typical clearances, the leakage and bypass streams lower
+/// the heat transfer coefficient, the overall correction for a
+/// well designed exchanger is usually about 0.6 (Taborek)
+///
+/// sealing strips block the bypass stream and raise J_b
+#[test]
+pub fn bell_delaware_typical_correction_factors(){
+
+    let mut geometry = typical_geometry(TubeLayout::Triangular30);
+    let fluid = water_like_properties();
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(20.0);
+
+    let reynolds = get_bell_delaware_reynolds_number(&geometry, mass_flowrate, &fluid);
+    let correction_factors =
+        try_get_bell_delaware_correction_factors(&geometry, reynolds).unwrap();
+
+    assert!(correction_factors.baffle_cut > 0.9 && correction_factors.baffle_cut < 1.15);
+    assert!(correction_factors.baffle_leakage > 0.6 && correction_factors.baffle_leakage < 0.9);
+    assert!(correction_factors.bundle_bypass > 0.7 && correction_factors.bundle_bypass < 1.0);
+    assert!(correction_factors.get_total() > 0.45 && correction_factors.get_total() < 0.9);
+
+    geometry.number_of_sealing_strip_pairs = 2;
+    let sealed_correction_factors =
+        try_get_bell_delaware_correction_factors(&geometry, reynolds).unwrap();
+
+    assert!(sealed_correction_factors.bundle_bypass > correction_factors.bundle_bypass);
+}
+
+/// in turbulent flow, the shell side pressure drop goes roughly
+/// as the mass flowrate to the power of 1.8 to 2
+///
+/// in laminar flow, the laminar correction J_r is less than one
+#[test]
+pub fn bell_delaware_flow_regimes(){
+
+    let geometry = typical_geometry(TubeLayout::Square90);
+    let fluid = water_like_properties();
+    let model = BaffledShellSideModel::new_bell_delaware(geometry);
+
+    let low_flow = MassRate::new::<kilogram_per_second>(20.0);
+    let high_flow = MassRate::new::<kilogram_per_second>(40.0);
+
+    let pressure_drop_ratio: f64 = (model.try_get_pressure_drop(high_flow, &fluid).unwrap()
+        / model.try_get_pressure_drop(low_flow, &fluid).unwrap()).get::<ratio>();
+
+    assert!(pressure_drop_ratio > 2.0_f64.powf(1.7));
+    assert!(pressure_drop_ratio < 4.0);
+
+    let htc_ratio: f64 = (model.try_get_heat_transfer_coeff(high_flow, &fluid).unwrap()
+        / model.try_get_heat_transfer_coeff(low_flow, &fluid).unwrap()).get::<ratio>();
+
+    assert!(htc_ratio > 2.0_f64.powf(0.5));
+    assert!(htc_ratio < 2.0_f64.powf(0.7));
+
+    // a viscous oil in laminar flow
+    let mut viscous_fluid = fluid;
+    viscous_fluid.dynamic_viscosity = DynamicViscosity::new::<pascal_second>(2.0);
+    viscous_fluid.wall_dynamic_viscosity = DynamicViscosity::new::<pascal_second>(2.0);
+
+    let laminar_reynolds = get_bell_delaware_reynolds_number(
+        &geometry, low_flow, &viscous_fluid);
+    assert!(laminar_reynolds < 20.0);
+
+    let laminar_correction_factors = try_get_bell_delaware_correction_factors(
+        &geometry, laminar_reynolds).unwrap();
+    assert!(laminar_correction_factors.laminar_flow < 1.0);
+    assert!(laminar_correction_factors.laminar_flow >= 0.4);
+
+    let laminar_pressure_drop = model.try_get_pressure_drop(
+        low_flow, &viscous_fluid).unwrap();
+    assert!(laminar_pressure_drop.get::<pascal>() > 0.0);
+    assert!(laminar_pressure_drop.get::<pascal>().is_finite());
+
+    // no flow means no pressure drop
+    assert_eq!(model.try_get_pressure_drop(
+        MassRate::new::<kilogram_per_second>(0.0), &fluid).unwrap().get::<pascal>(), 0.0);
+}
+
+/// at zero and very low flow, both methods give the minimum
+/// heat transfer coefficient rather than zero, so the shell side
+/// stays thermally coupled to the tubes when the flow stops
+#[test]
+pub fn stagnant_shell_side_heat_transfer_is_floored(){
+
+    let geometry = typical_geometry(TubeLayout::Square90);
+    let fluid = water_like_properties();
+
+    // Nu = 0.3 based on the 3/4 inch tube od
+    let expected_minimum_htc: f64 = 0.3 * 0.62 / Length::new::<inch>(0.75)
+        .get::<meter>();
+
+    approx::assert_relative_eq!(
+        get_minimum_heat_transfer_coeff(&geometry, &fluid)
+        .get::<watt_per_square_meter_kelvin>(),
+        expected_minimum_htc, max_relative=1e-12);
+
+    for model in [BaffledShellSideModel::new_kern(geometry),
+        BaffledShellSideModel::new_bell_delaware(geometry)] {
+
+        let zero_flow_htc = model.try_get_heat_transfer_coeff(
+            MassRate::new::<kilogram_per_second>(0.0), &fluid).unwrap()
+            .get::<watt_per_square_meter_kelvin>();
+
+        approx::assert_relative_eq!(zero_flow_htc,
+            expected_minimum_htc, max_relative=1e-12);
+
+        let trickle_flow_htc = model.try_get_heat_transfer_coeff(
+            MassRate::new::<kilogram_per_second>(1.0e-9), &fluid).unwrap()
+            .get::<watt_per_square_meter_kelvin>();
+
+        approx::assert_relative_eq!(trickle_flow_htc,
+            expected_minimum_htc, max_relative=1e-12);
+
+        // the floor does not affect normal operation
+        let design_flow_htc = model.try_get_heat_transfer_coeff(
+            MassRate::new::<kilogram_per_second>(20.0), &fluid).unwrap()
+            .get::<watt_per_square_meter_kelvin>();
+
+        assert!(design_flow_htc > 100.0 * expected_minimum_htc);
+    }
+}
+
+/// nonsensical geometries are rejected
+#[test]
+pub fn invalid_baffled_shell_geometry(){
+
+    let mut geometry = typical_geometry(TubeLayout::Square90);
+    geometry.baffle_cut = Ratio::new::<ratio>(0.6);
+    assert!(geometry.try_validate().is_err());
+
+    let mut geometry = typical_geometry(TubeLayout::Square90);
+    geometry.tube_pitch = Length::new::<millimeter>(10.0);
+    assert!(geometry.try_validate().is_err());
+
+    let mut geometry = typical_geometry(TubeLayout::Square90);
+    geometry.inlet_baffle_spacing = Length::new::<meter>(3.0);
+    geometry.outlet_baffle_spacing = Length::new::<meter>(3.0);
+    assert!(geometry.try_validate().is_err());
+
+    let fluid = water_like_properties();
+    let model = BaffledShellSideModel::new_kern(geometry);
+    assert!(model.try_get_heat_transfer_coeff(
+        MassRate::new::<kilogram_per_second>(1.0), &fluid).is_err());
+}
@@ -177,3 +177,116 @@ pub mod array_control_vol_and_fluid_component_collections;
 /// You don't want to write everything from scratch right? 
 #[warn(missing_docs)]
 pub mod pre_built_components;
+
+/// Module for point reactor kinetics, reactivity feedback and 
+/// decay heat
+///
+/// heater power in CIET and FLiBe loops is usually set directly, 
+/// this module provides a time dependent power for using these 
+/// loops as surrogates for reactor transients. The power can be 
+/// fed to the q_vector of a FluidArray or SolidColumn
+#[warn(missing_docs)]
+pub mod reactor_kinetics;
+
+/// Module for calibrating component parameters (eg. insulation 
+/// thickness, heat transfer to ambient, nusselt correction factors) 
+/// against experimental data using least squares
+///
+/// users declare tunable parameters with bounds bound to component 
+/// setters, supply observations, and run a Nelder-Mead or 
+/// Levenberg-Marquardt optimiser over steady state or transient runs
+#[warn(missing_docs)]
+pub mod calibration;
+
+/// Module for uncertainty quantification of component and loop 
+/// models
+///
+/// uncertain parameters (eg. insulation thickness, heat transfer to 
+/// ambient, nusselt correction factors, property uncertainties) are 
+/// bound to the same setters as calibration, sampled with Monte Carlo 
+/// or Latin hypercube sampling, and propagated through the model, 
+/// optionally in parallel, to give output statistics and Sobol or 
+/// Morris sensitivity indices
+#[warn(missing_docs)]
+pub mod uncertainty_quantification;
+
+/// Module for validation against experimental data
+///
+/// experiment tables (eg. CIET heater power, TCHX outlet temperature 
+/// and mass flowrates) are loaded from CSV, a user supplied model 
+/// is run for each row, and per point relative errors, RMS and max 
+/// errors are reported against tolerances
+#[warn(missing_docs)]
+pub mod validation;
+
+/// Module for auditing energy conservation across coupled 
+/// HeatTransferEntities
+///
+/// heat added by sources, heat lost to ambient, enthalpy advected 
+/// across component boundaries and stored enthalpy changes are 
+/// tallied per timestep and cumulatively, so that linkage bugs 
+/// show up as an energy imbalance
+#[warn(missing_docs)]
+pub mod energy_audit;
+
+/// Module for scripting transients on plant models
+///
+/// scenarios are sequences of timed actions (steps, ramps, sinusoids, 
+/// valve closures and trips) and condition triggered actions (eg. 
+/// trip the pump when the heater outlet temperature is too high) 
+/// which can be loaded from a simple text file and replayed 
+/// deterministically
+#[warn(missing_docs)]
+pub mod scenario;
+
+/// Module for feedback control of simulated plants
+///
+/// PI/PID controllers (with anti windup, derivative filtering and 
+/// output limits), lead-lag compensators and on/off controllers 
+/// read a probe on the plant (eg. a node temperature or flowrate) 
+/// and actuate a plant parameter (eg. heat transfer coefficient to 
+/// ambient, heater power, pump pressure or valve opening) every 
+/// timestep, alongside the scenario driver
+#[warn(missing_docs)]
+pub mod controls;
+
+/// Module for exporting plant models to other tools
+///
+/// plants are exposed through a plain C API (create, set inputs, 
+/// do_step, get outputs, free) and the FMI 2.0 co-simulation 
+/// interface, with modelDescription.xml generation and FMU 
+/// packaging, so that loops built from components can be coupled 
+/// to Modelica or Simulink models
+#[warn(missing_docs)]
+pub mod fmi_export;
+
+/// Module for python bindings, built with the python feature
+///
+/// liquid and solid property lookups, friction factor and Nusselt
+/// number correlations, and pre-built components (insulated fluid
+/// components and shell and tube heat exchangers) are exposed to
+/// python with SI floats at the boundary, so that models can be
+/// built and post-processed from notebooks
+#[cfg(feature = "python")]
+#[warn(missing_docs)]
+pub mod python_bindings;
+
+/// Module for serving plant models to other programs on localhost
+///
+/// the inputs and outputs of a plant which is being simulated are 
+/// exposed over TCP with a line delimited json protocol, so that 
+/// external HMIs, data historians or classroom clients can read 
+/// sensors and write set points while the simulation thread 
+/// advances the plant
+#[warn(missing_docs)]
+pub mod plant_server;
+
+/// Module for describing loops in TOML or JSON files
+///
+/// components are described with the same parameters as the hand 
+/// written constructors (materials, dimensions, loss model and 
+/// nodalisation), and the network by branches of components between 
+/// a top and a bottom mixing node, so that new loops can be set up 
+/// and modified without recompiling
+#[warn(missing_docs)]
+pub mod loop_description;
@@ -0,0 +1,760 @@
+use std::f64::consts::PI;
+
+use uom::si::angle::degree;
+use uom::si::area::square_meter;
+use uom::si::f64::*;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::length::meter;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::power::watt;
+use uom::si::pressure::{atmosphere, pascal};
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::time::second;
+use uom::ConstZero;
+
+use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_collection::{FluidComponentCollection, FluidComponentCollectionMethods};
+use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_super_collection::FluidComponentSuperCollection;
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::boussinesq_thermophysical_properties::material_names::{try_get_liquid_material_from_name, try_get_solid_material_from_name};
+use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+use crate::pre_built_components::insulated_pipes_and_fluid_components::InsulatedFluidComponent;
+use crate::pre_built_components::non_insulated_fluid_components::NonInsulatedFluidComponent;
+use crate::scenario::ScenarioPlant;
+use crate::single_control_vol::SingleCVNode;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::{ComponentDescription, ComponentKind, LoopDescription};
+
+/// largest difference in height change between branches (m)
+/// accepted when a description is built
+const BRANCH_HEIGHT_CHANGE_TOLERANCE: f64 = 1.0e-6;
+
+/// a component built from a description
+#[derive(Debug, Clone, PartialEq)]
+pub enum DescribedComponent {
+    /// built with the insulated pipe or custom component
+    /// constructors
+    Insulated(Box<InsulatedFluidComponent>),
+    /// built with the bare pipe or custom component constructors
+    NonInsulated(Box<NonInsulatedFluidComponent>),
+}
+
+impl DescribedComponent {
+
+    /// the fluid array of the component
+    pub fn get_pipe_fluid_array_mut(&mut self) -> &mut HeatTransferEntity {
+        match self {
+            Self::Insulated(component) => &mut component.pipe_fluid_array,
+            Self::NonInsulated(component) => &mut component.pipe_fluid_array,
+        }
+    }
+
+    /// heat transfer coefficient to ambient
+    pub fn get_heat_transfer_to_ambient(&self) -> HeatTransfer {
+        match self {
+            Self::Insulated(component) => component.heat_transfer_to_ambient,
+            Self::NonInsulated(component) => component.heat_transfer_to_ambient,
+        }
+    }
+
+    /// sets the heat transfer coefficient to ambient
+    pub fn set_heat_transfer_to_ambient(&mut self, htc_to_ambient: HeatTransfer) {
+        match self {
+            Self::Insulated(component) => component.heat_transfer_to_ambient = htc_to_ambient,
+            Self::NonInsulated(component) => component.heat_transfer_to_ambient = htc_to_ambient,
+        }
+    }
+
+    /// ambient temperature
+    pub fn get_ambient_temperature(&self) -> ThermodynamicTemperature {
+        match self {
+            Self::Insulated(component) => component.ambient_temperature,
+            Self::NonInsulated(component) => component.ambient_temperature,
+        }
+    }
+
+    /// sets the ambient temperature
+    pub fn set_ambient_temperature(&mut self, ambient_temperature: ThermodynamicTemperature) {
+        match self {
+            Self::Insulated(component) => component.ambient_temperature = ambient_temperature,
+            Self::NonInsulated(component) => component.ambient_temperature = ambient_temperature,
+        }
+    }
+
+    /// pressure supplied by a pump within the component
+    pub fn try_get_pump_pressure(&self) -> Result<Pressure, TuasLibError> {
+        let fluid_array: FluidArray = match self {
+            Self::Insulated(component) => component.pipe_fluid_array.clone().try_into()?,
+            Self::NonInsulated(component) => component.pipe_fluid_array.clone().try_into()?,
+        };
+        Ok(fluid_array.get_internal_pressure_source_immutable())
+    }
+
+    /// sets the pressure supplied by a pump within the component,
+    /// this is set on the fluid array directly so that both
+    /// component types behave the same way
+    pub fn try_set_pump_pressure(&mut self, pump_pressure: Pressure) -> Result<(), TuasLibError> {
+        let pipe_fluid_array = self.get_pipe_fluid_array_mut();
+        let mut fluid_array: FluidArray = pipe_fluid_array.clone().try_into()?;
+        fluid_array.set_internal_pressure_source(pump_pressure);
+        *pipe_fluid_array = fluid_array.into();
+        Ok(())
+    }
+
+    /// bulk temperature of the fluid in the component
+    pub fn try_get_bulk_fluid_temperature(&mut self) -> Result<ThermodynamicTemperature, TuasLibError> {
+        self.get_pipe_fluid_array_mut().try_get_bulk_temperature()
+    }
+
+    /// connects the fluid, shell and insulation laterally, without
+    /// correcting the Nusselt number for wall temperatures
+    pub fn try_lateral_and_miscellaneous_connections(&mut self,
+        mass_flowrate: MassRate,
+        heater_power: Power) -> Result<(), TuasLibError> {
+        match self {
+            Self::Insulated(component) => component
+                .lateral_and_miscellaneous_connections_no_wall_correction(
+                    mass_flowrate, heater_power),
+            Self::NonInsulated(component) => component
+                .lateral_and_miscellaneous_connections_no_wall_correction(
+                    mass_flowrate, heater_power),
+        }
+    }
+
+    /// advances every heat transfer entity of the component
+    pub fn try_advance_timestep(&mut self, timestep: Time) -> Result<(), TuasLibError> {
+        match self {
+            Self::Insulated(component) => component.advance_timestep(timestep),
+            Self::NonInsulated(component) => component.advance_timestep(timestep),
+        }
+    }
+
+    /// adds a clone of the component to a series collection for
+    /// the fluid mechanics calculations
+    fn clone_into_collection(&self, collection: &mut FluidComponentCollection) {
+        match self {
+            Self::Insulated(component) => collection.clone_and_add_component(component.as_ref()),
+            Self::NonInsulated(component) => collection.clone_and_add_component(component.as_ref()),
+        }
+    }
+}
+
+/// a named component of a described loop with its heater power
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoopComponent {
+    /// name given in the description
+    pub name: String,
+    /// the component itself
+    pub component: DescribedComponent,
+    /// heater power supplied to the pipe shell
+    pub heater_power: Power,
+}
+
+/// a branch of a described loop, running from the top mixing node
+/// to the bottom mixing node
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescribedBranch {
+    /// name given in the description
+    pub name: String,
+    /// indices of the components in [DescribedLoop::components],
+    /// from the top mixing node to the bottom mixing node
+    pub component_indices: Vec<usize>,
+    /// mass flowrate from the top to the bottom mixing node, from
+    /// the last timestep
+    pub mass_flowrate: MassRate,
+}
+
+/// a loop built from a [LoopDescription]
+///
+/// every timestep, the branch mass flowrates are solved as
+/// parallel branches with zero net flow, the components are linked
+/// by advection through the top and bottom mixing nodes, and every
+/// heat transfer entity is advanced
+///
+/// the loop is a [ScenarioPlant], with inputs named
+/// `<component>.heater_power` (W), `<component>.pump_pressure` (Pa),
+/// `<component>.htc_to_ambient` (W/(m2 K)) and
+/// `<component>.ambient_temperature` (K), and outputs named
+/// `simulation_time` (s), `<component>.bulk_temperature` (K) and
+/// `<branch>.mass_flowrate` (kg/s). Inputs can be read as outputs
+/// too
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescribedLoop {
+    /// components in the order of the description
+    pub components: Vec<LoopComponent>,
+    /// branches in the order of the description
+    pub branches: Vec<DescribedBranch>,
+    /// mixing node where the branches start
+    pub top_mixing_node: HeatTransferEntity,
+    /// mixing node where the branches end
+    pub bottom_mixing_node: HeatTransferEntity,
+    /// fluid in the loop
+    pub fluid: LiquidMaterial,
+    /// simulation time elapsed
+    pub simulation_time: Time,
+}
+
+impl LoopDescription {
+
+    /// instantiates the components and the network
+    pub fn try_build(&self) -> Result<DescribedLoop, TuasLibError> {
+
+        let components = self.components.iter()
+            .map(|component| component.try_build(&self.defaults))
+            .collect::<Result<Vec<LoopComponent>, TuasLibError>>()?;
+
+        // the mixing nodes share the fluid of the loop
+        let fluid_names: Vec<String> = self.components.iter()
+            .map(|component| component.fluid.clone()
+                .or(self.defaults.fluid.clone())
+                .unwrap_or_default())
+            .collect();
+        if fluid_names.windows(2).any(|pair| pair[0] != pair[1]) {
+            return Err(TuasLibError::GenericStringError(format!(
+                "invalid loop description: all components must have the same fluid, got {:?}",
+                fluid_names)));
+        }
+        let fluid = try_get_liquid_material_from_name(&fluid_names[0])?;
+
+        let mixing_node_diameter = self.network.mixing_node_diameter;
+        if !mixing_node_diameter.is_finite() || mixing_node_diameter <= 0.0 {
+            return Err(TuasLibError::GenericStringError(format!(
+                "invalid loop description: mixing_node_diameter must be positive, got {}",
+                mixing_node_diameter)));
+        }
+
+        // every branch must change height by the same amount between
+        // the mixing nodes, otherwise the parallel branch flow
+        // solver fails to converge
+        let branch_height_changes = self.network.branches.iter()
+            .map(|branch| branch.components.iter()
+                .map(|name| {
+                    let component = self.components.iter()
+                        .find(|component| component.name.as_ref() == Some(name))
+                        .expect("names are checked when the description is parsed");
+                    let length = component.try_get_key("length",
+                        component.length, self.defaults.length)?;
+                    let incline_angle = component.try_get_key("incline_angle",
+                        component.incline_angle, self.defaults.incline_angle)?;
+                    Ok(length * incline_angle.to_radians().sin())
+                })
+                .sum::<Result<f64, TuasLibError>>())
+            .collect::<Result<Vec<f64>, TuasLibError>>()?;
+
+        let mismatched_branches: Vec<String> = self.network.branches.iter()
+            .zip(branch_height_changes.iter())
+            .filter(|(_, height_change)|
+                (*height_change - branch_height_changes[0]).abs()
+                > BRANCH_HEIGHT_CHANGE_TOLERANCE)
+            .map(|(branch, height_change)| format!("{} ({} m)", branch.name, height_change))
+            .collect();
+        if !mismatched_branches.is_empty() {
+            return Err(TuasLibError::GenericStringError(format!(
+                "invalid loop description: every branch must change height by the same \
+                amount, branch {} changes by {} m but branches {} do not",
+                self.network.branches[0].name, branch_height_changes[0],
+                mismatched_branches.join(", "))));
+        }
+
+        // the mixing nodes start at the initial temperature of the
+        // first component of the first branch
+        let first_component = &self.network.branches[0].components[0];
+        let first_component = self.components.iter()
+            .find(|component| component.name.as_ref() == Some(first_component))
+            .expect("names are checked when the description is parsed");
+        let mixing_node_temperature = ThermodynamicTemperature::new::<kelvin>(
+            first_component.try_get_key("initial_temperature",
+                first_component.initial_temperature, self.defaults.initial_temperature)?);
+        let mixing_node_pressure = Pressure::new::<pascal>(
+            first_component.fluid_pressure.or(self.defaults.fluid_pressure)
+            .unwrap_or(Pressure::new::<atmosphere>(1.0).get::<pascal>()));
+
+        let mixing_node = SingleCVNode::new_sphere(
+            Length::new::<meter>(mixing_node_diameter),
+            fluid.into(),
+            mixing_node_temperature,
+            mixing_node_pressure)?;
+
+        let branches = self.network.branches.iter()
+            .map(|branch| DescribedBranch {
+                name: branch.name.clone(),
+                component_indices: branch.components.iter()
+                    .map(|name| components.iter()
+                        .position(|component| &component.name == name)
+                        .expect("names are checked when the description is parsed"))
+                    .collect(),
+                mass_flowrate: MassRate::ZERO,
+            })
+            .collect();
+
+        Ok(DescribedLoop {
+            components,
+            branches,
+            top_mixing_node: mixing_node.clone().into(),
+            bottom_mixing_node: mixing_node.into(),
+            fluid,
+            simulation_time: Time::ZERO,
+        })
+    }
+}
+
+impl ComponentDescription {
+
+    /// value of a key, from the component or else from the
+    /// defaults
+    fn try_get_key<T>(&self, key: &str,
+        component_value: Option<T>,
+        default_value: Option<T>) -> Result<T, TuasLibError> {
+
+        component_value.or(default_value).ok_or_else(|| TuasLibError::GenericStringError(
+            format!("invalid loop description: component {} has no {} and there is no default",
+                self.name.as_deref().unwrap_or("without a name"), key)))
+    }
+
+    /// builds the component, taking missing keys from the defaults
+    pub fn try_build(&self, defaults: &ComponentDescription) -> Result<LoopComponent, TuasLibError> {
+
+        let name = self.try_get_key("name", self.name.clone(), None)?;
+        let invalid = |message: String| Err(TuasLibError::GenericStringError(
+            format!("invalid loop description: component {} {}", name, message)));
+
+        let kind = self.try_get_key("type", self.kind, defaults.kind)?;
+
+        let initial_temperature = ThermodynamicTemperature::new::<kelvin>(
+            self.try_get_key("initial_temperature",
+                self.initial_temperature, defaults.initial_temperature)?);
+        let ambient_temperature = ThermodynamicTemperature::new::<kelvin>(
+            self.try_get_key("ambient_temperature",
+                self.ambient_temperature, defaults.ambient_temperature)?);
+        let one_atmosphere = Pressure::new::<atmosphere>(1.0);
+        let fluid_pressure = self.fluid_pressure.or(defaults.fluid_pressure)
+            .map(Pressure::new::<pascal>).unwrap_or(one_atmosphere);
+        let solid_pressure = self.solid_pressure.or(defaults.solid_pressure)
+            .map(Pressure::new::<pascal>).unwrap_or(one_atmosphere);
+
+        let length = self.try_get_key("length", self.length, defaults.length)?;
+        let hydraulic_diameter = self.try_get_key("hydraulic_diameter",
+            self.hydraulic_diameter, defaults.hydraulic_diameter)?;
+        if !length.is_finite() || length <= 0.0
+            || !hydraulic_diameter.is_finite() || hydraulic_diameter <= 0.0 {
+            return invalid("must have a positive length and hydraulic diameter".to_owned());
+        }
+        let component_length = Length::new::<meter>(length);
+        let hydraulic_diameter = Length::new::<meter>(hydraulic_diameter);
+        let flow_area = match self.flow_area.or(defaults.flow_area) {
+            Some(flow_area) => Area::new::<square_meter>(flow_area),
+            None => PI/4.0 * hydraulic_diameter * hydraulic_diameter,
+        };
+        if !flow_area.value.is_finite() || flow_area.value <= 0.0 {
+            return invalid("must have a positive flow area".to_owned());
+        }
+        let incline_angle = Angle::new::<degree>(self.try_get_key("incline_angle",
+            self.incline_angle, defaults.incline_angle)?);
+        let form_loss = Ratio::new::<ratio>(
+            self.form_loss.or(defaults.form_loss).unwrap_or(0.0));
+
+        // the shell outer diameter is either given or calculated
+        // from the wall thickness, as in the hand written
+        // constructors
+        let shell_id = self.shell_id.or(defaults.shell_id)
+            .map(Length::new::<meter>).unwrap_or(hydraulic_diameter);
+        let shell_od = match (self.shell_od, self.wall_thickness) {
+            (Some(_), Some(_)) => return invalid(
+                "sets both shell_od and wall_thickness".to_owned()),
+            (Some(shell_od), None) => Length::new::<meter>(shell_od),
+            (None, Some(wall_thickness)) => shell_id + 2.0 * Length::new::<meter>(wall_thickness),
+            (None, None) => match (defaults.shell_od, defaults.wall_thickness) {
+                (Some(shell_od), None) => Length::new::<meter>(shell_od),
+                (None, Some(wall_thickness)) => shell_id + 2.0 * Length::new::<meter>(wall_thickness),
+                _ => return invalid(
+                    "needs exactly one of shell_od or wall_thickness".to_owned()),
+            },
+        };
+        if !shell_od.value.is_finite() || shell_od <= shell_id {
+            return invalid("must have shell_od larger than shell_id".to_owned());
+        }
+
+        let pipe_fluid = try_get_liquid_material_from_name(
+            &self.try_get_key("fluid", self.fluid.clone(), defaults.fluid.clone())?)?;
+        let pipe_shell_material = try_get_solid_material_from_name(
+            &self.try_get_key("pipe_shell_material",
+                self.pipe_shell_material.clone(), defaults.pipe_shell_material.clone())?)?;
+        let htc_to_ambient = HeatTransfer::new::<watt_per_square_meter_kelvin>(
+            self.try_get_key("htc_to_ambient", self.htc_to_ambient, defaults.htc_to_ambient)?);
+        let inner_nodes = self.try_get_key("inner_nodes",
+            self.inner_nodes, defaults.inner_nodes)?;
+
+        // the loss model is chosen by the keys the component sets
+        // itself, and otherwise by the keys of the defaults
+        let custom_loss = |description: &ComponentDescription| {
+            description.reynolds_coefficient.is_some() || description.reynolds_power.is_some()
+        };
+        let is_custom_component = if custom_loss(self) || self.surface_roughness.is_some() {
+            if custom_loss(self) && self.surface_roughness.is_some() {
+                return invalid("sets both a surface_roughness and reynolds coefficients"
+                    .to_owned());
+            }
+            custom_loss(self)
+        } else if custom_loss(defaults) != defaults.surface_roughness.is_some() {
+            custom_loss(defaults)
+        } else {
+            return invalid("needs either a surface_roughness or a reynolds_coefficient \
+                and reynolds_power".to_owned());
+        };
+
+        // insulation keys in the defaults are meant for the insulated
+        // components only
+        if kind == ComponentKind::NonInsulated &&
+            (self.insulation_thickness.is_some() || self.insulation_material.is_some()) {
+            return invalid("is non_insulated but sets insulation keys".to_owned());
+        }
+
+        let component = match (kind, is_custom_component) {
+            (ComponentKind::Insulated, custom) => {
+                let insulation_thickness = Length::new::<meter>(
+                    self.try_get_key("insulation_thickness",
+                        self.insulation_thickness, defaults.insulation_thickness)?);
+                let insulation_material = try_get_solid_material_from_name(
+                    &self.try_get_key("insulation_material",
+                        self.insulation_material.clone(),
+                        defaults.insulation_material.clone())?)?;
+
+                let component = if custom {
+                    InsulatedFluidComponent::new_custom_component(
+                        initial_temperature,
+                        ambient_temperature,
+                        fluid_pressure,
+                        solid_pressure,
+                        flow_area,
+                        incline_angle,
+                        form_loss,
+                        Ratio::new::<ratio>(self.try_get_key("reynolds_coefficient",
+                            self.reynolds_coefficient, defaults.reynolds_coefficient)?),
+                        self.try_get_key("reynolds_power",
+                            self.reynolds_power, defaults.reynolds_power)?,
+                        shell_id,
+                        shell_od,
+                        insulation_thickness,
+                        component_length,
+                        hydraulic_diameter,
+                        pipe_shell_material,
+                        insulation_material,
+                        pipe_fluid,
+                        htc_to_ambient,
+                        inner_nodes)
+                } else {
+                    InsulatedFluidComponent::new_insulated_pipe(
+                        initial_temperature,
+                        ambient_temperature,
+                        fluid_pressure,
+                        solid_pressure,
+                        flow_area,
+                        incline_angle,
+                        form_loss,
+                        shell_id,
+                        shell_od,
+                        insulation_thickness,
+                        component_length,
+                        hydraulic_diameter,
+                        pipe_shell_material,
+                        insulation_material,
+                        pipe_fluid,
+                        htc_to_ambient,
+                        inner_nodes,
+                        Length::new::<meter>(self.try_get_key("surface_roughness",
+                            self.surface_roughness, defaults.surface_roughness)?))
+                };
+                DescribedComponent::Insulated(Box::new(component))
+            },
+            (ComponentKind::NonInsulated, custom) => {
+                let component = if custom {
+                    NonInsulatedFluidComponent::new_custom_component(
+                        initial_temperature,
+                        ambient_temperature,
+                        fluid_pressure,
+                        solid_pressure,
+                        flow_area,
+                        incline_angle,
+                        form_loss,
+                        Ratio::new::<ratio>(self.try_get_key("reynolds_coefficient",
+                            self.reynolds_coefficient, defaults.reynolds_coefficient)?),
+                        self.try_get_key("reynolds_power",
+                            self.reynolds_power, defaults.reynolds_power)?,
+                        shell_id,
+                        shell_od,
+                        component_length,
+                        hydraulic_diameter,
+                        pipe_shell_material,
+                        pipe_fluid,
+                        htc_to_ambient,
+                        inner_nodes)
+                } else {
+                    NonInsulatedFluidComponent::new_bare_pipe(
+                        initial_temperature,
+                        ambient_temperature,
+                        fluid_pressure,
+                        solid_pressure,
+                        flow_area,
+                        incline_angle,
+                        form_loss,
+                        shell_id,
+                        shell_od,
+                        component_length,
+                        hydraulic_diameter,
+                        Length::new::<meter>(self.try_get_key("surface_roughness",
+                            self.surface_roughness, defaults.surface_roughness)?),
+                        pipe_shell_material,
+                        pipe_fluid,
+                        htc_to_ambient,
+                        inner_nodes)
+                };
+                DescribedComponent::NonInsulated(Box::new(component))
+            },
+        };
+
+        let mut loop_component = LoopComponent {
+            name,
+            component,
+            heater_power: Power::new::<watt>(
+                self.heater_power.or(defaults.heater_power).unwrap_or(0.0)),
+        };
+        if let Some(pump_pressure) = self.pump_pressure.or(defaults.pump_pressure) {
+            loop_component.component.try_set_pump_pressure(
+                Pressure::new::<pascal>(pump_pressure))?;
+        }
+
+        Ok(loop_component)
+    }
+}
+
+impl DescribedLoop {
+
+    /// finds a component by name
+    pub fn try_get_component_mut(&mut self, name: &str)
+        -> Result<&mut LoopComponent, TuasLibError> {
+        self.components.iter_mut()
+            .find(|component| component.name == name)
+            .ok_or_else(|| TuasLibError::GenericStringError(
+                format!("loop has no component named {}", name)))
+    }
+
+    /// finds a branch by name
+    pub fn try_get_branch(&self, name: &str) -> Result<&DescribedBranch, TuasLibError> {
+        self.branches.iter()
+            .find(|branch| branch.name == name)
+            .ok_or_else(|| TuasLibError::GenericStringError(
+                format!("loop has no branch named {}", name)))
+    }
+
+    /// the branches as parallel series collections, for the fluid
+    /// mechanics calculations
+    pub fn get_fluid_component_super_collection(&self) -> FluidComponentSuperCollection {
+
+        let mut loop_branches = FluidComponentSuperCollection::default();
+        loop_branches.set_orientation_to_parallel();
+
+        for branch in &self.branches {
+            let mut series_branch =
+                FluidComponentCollection::new_series_component_collection();
+            for index in &branch.component_indices {
+                self.components[*index].component
+                    .clone_into_collection(&mut series_branch);
+            }
+            loop_branches.fluid_component_super_vector.push(series_branch);
+        }
+
+        loop_branches
+    }
+
+    /// mass flowrates from the top to the bottom mixing node in each
+    /// branch, in the order of the branches, with zero net flow
+    /// through the loop
+    pub fn get_branch_mass_flowrates(&self) -> Vec<MassRate> {
+
+        let loop_branches = self.get_fluid_component_super_collection();
+        let pressure_change_across_each_branch =
+            loop_branches.get_pressure_change(MassRate::ZERO);
+
+        loop_branches.get_mass_flowrate_across_each_parallel_branch(
+            pressure_change_across_each_branch)
+    }
+
+    /// names of the inputs of the loop as a [ScenarioPlant]
+    pub fn get_input_names(&self) -> Vec<String> {
+        self.components.iter()
+            .flat_map(|component| ["heater_power", "pump_pressure",
+                "htc_to_ambient", "ambient_temperature"].into_iter()
+                .map(move |input| format!("{}.{}", component.name, input)))
+            .collect()
+    }
+
+    /// names of the outputs of the loop as a [ScenarioPlant]
+    pub fn get_output_names(&self) -> Vec<String> {
+        let mut output_names = vec!["simulation_time".to_owned()];
+        output_names.extend(self.components.iter()
+            .map(|component| format!("{}.bulk_temperature", component.name)));
+        output_names.extend(self.branches.iter()
+            .map(|branch| format!("{}.mass_flowrate", branch.name)));
+        output_names
+    }
+
+    /// advances the loop by one timestep
+    ///
+    /// the branch mass flowrates are solved from the temperatures at
+    /// the start of the timestep, and the density for advection is
+    /// taken at the average temperature of the mixing nodes
+    pub fn try_advance_timestep(&mut self, timestep: Time) -> Result<(), TuasLibError> {
+
+        if !timestep.value.is_finite() || timestep <= Time::ZERO {
+            return Err(TuasLibError::GenericStringError(
+                "described loop timestep must be positive".to_owned()));
+        }
+
+        let branch_mass_flowrates = self.get_branch_mass_flowrates();
+        for (branch, mass_flowrate) in self.branches.iter_mut().zip(branch_mass_flowrates) {
+            branch.mass_flowrate = mass_flowrate;
+        }
+
+        let average_temperature_for_density_calcs = {
+            let top_temperature = self.top_mixing_node.try_get_bulk_temperature()?;
+            let bottom_temperature = self.bottom_mixing_node.try_get_bulk_temperature()?;
+            ThermodynamicTemperature::new::<kelvin>(0.5 *
+                (top_temperature.get::<kelvin>() + bottom_temperature.get::<kelvin>()))
+        };
+        let average_density = self.fluid
+            .try_get_density(average_temperature_for_density_calcs)?;
+
+        // link the branches from the top to the bottom mixing node
+        for branch in &self.branches {
+            let advection_heat_transfer_interaction =
+                HeatTransferInteractionType::new_advection_interaction(
+                    branch.mass_flowrate, average_density, average_density);
+
+            let first_index = branch.component_indices[0];
+            self.top_mixing_node.link_to_front(
+                self.components[first_index].component.get_pipe_fluid_array_mut(),
+                advection_heat_transfer_interaction)?;
+
+            for pair in branch.component_indices.windows(2) {
+                let (upstream, downstream) = get_two_components_mut(
+                    &mut self.components, pair[0], pair[1]);
+                upstream.get_pipe_fluid_array_mut().link_to_front(
+                    downstream.get_pipe_fluid_array_mut(),
+                    advection_heat_transfer_interaction)?;
+            }
+
+            let last_index = *branch.component_indices.last()
+                .expect("branches have at least one component");
+            self.components[last_index].component.get_pipe_fluid_array_mut()
+                .link_to_front(&mut self.bottom_mixing_node,
+                    advection_heat_transfer_interaction)?;
+        }
+
+        // lateral connections use the branch mass flowrates
+        for branch in &self.branches {
+            for index in &branch.component_indices {
+                let loop_component = &mut self.components[*index];
+                loop_component.component.try_lateral_and_miscellaneous_connections(
+                    branch.mass_flowrate, loop_component.heater_power)?;
+            }
+        }
+
+        for loop_component in self.components.iter_mut() {
+            loop_component.component.try_advance_timestep(timestep)?;
+        }
+        self.top_mixing_node.advance_timestep_mut_self(timestep)?;
+        self.bottom_mixing_node.advance_timestep_mut_self(timestep)?;
+
+        self.simulation_time += timestep;
+
+        Ok(())
+    }
+}
+
+/// mutable references to two different components of the loop
+fn get_two_components_mut(components: &mut [LoopComponent],
+    upstream_index: usize,
+    downstream_index: usize) -> (&mut DescribedComponent, &mut DescribedComponent) {
+
+    assert_ne!(upstream_index, downstream_index, "a component cannot be linked to itself");
+    if upstream_index < downstream_index {
+        let (left, right) = components.split_at_mut(downstream_index);
+        (&mut left[upstream_index].component, &mut right[0].component)
+    } else {
+        let (left, right) = components.split_at_mut(upstream_index);
+        (&mut right[0].component, &mut left[downstream_index].component)
+    }
+}
+
+/// splits `<name>.<quantity>` plant variable names
+fn try_split_variable_name(variable_name: &str) -> Result<(&str, &str), TuasLibError> {
+    variable_name.rsplit_once('.').ok_or_else(|| TuasLibError::GenericStringError(
+        format!("{} is not of the form <name>.<quantity>", variable_name)))
+}
+
+impl ScenarioPlant for DescribedLoop {
+
+    fn try_set_input(&mut self, input_name: &str, value: f64)
+        -> Result<(), TuasLibError> {
+
+        let (component_name, quantity) = try_split_variable_name(input_name)?;
+        let loop_component = self.try_get_component_mut(component_name)?;
+
+        match quantity {
+            "heater_power" => loop_component.heater_power = Power::new::<watt>(value),
+            "pump_pressure" => loop_component.component
+                .try_set_pump_pressure(Pressure::new::<pascal>(value))?,
+            "htc_to_ambient" => loop_component.component.set_heat_transfer_to_ambient(
+                HeatTransfer::new::<watt_per_square_meter_kelvin>(value)),
+            "ambient_temperature" => loop_component.component.set_ambient_temperature(
+                ThermodynamicTemperature::new::<kelvin>(value)),
+            _ => return Err(TuasLibError::GenericStringError(
+                format!("no input named {}", input_name))),
+        }
+        Ok(())
+    }
+
+    fn try_get_input(&self, input_name: &str) -> Result<f64, TuasLibError> {
+
+        let (component_name, quantity) = try_split_variable_name(input_name)?;
+        let loop_component = self.components.iter()
+            .find(|component| component.name == component_name)
+            .ok_or_else(|| TuasLibError::GenericStringError(
+                format!("no input named {}", input_name)))?;
+
+        match quantity {
+            "heater_power" => Ok(loop_component.heater_power.get::<watt>()),
+            "pump_pressure" => Ok(loop_component.component
+                .try_get_pump_pressure()?.get::<pascal>()),
+            "htc_to_ambient" => Ok(loop_component.component
+                .get_heat_transfer_to_ambient().get::<watt_per_square_meter_kelvin>()),
+            "ambient_temperature" => Ok(loop_component.component
+                .get_ambient_temperature().get::<kelvin>()),
+            _ => Err(TuasLibError::GenericStringError(
+                format!("no input named {}", input_name))),
+        }
+    }
+
+    fn try_get_output(&mut self, output_name: &str) -> Result<f64, TuasLibError> {
+
+        if output_name == "simulation_time" {
+            return Ok(self.simulation_time.get::<second>());
+        }
+
+        let (name, quantity) = try_split_variable_name(output_name)?;
+        match quantity {
+            "bulk_temperature" => Ok(self.try_get_component_mut(name)?
+                .component.try_get_bulk_fluid_temperature()?.get::<kelvin>()),
+            "mass_flowrate" => Ok(self.try_get_branch(name)?
+                .mass_flowrate.get::<kilogram_per_second>()),
+            _ => self.try_get_input(output_name),
+        }
+    }
+
+    fn try_advance_timestep(&mut self, timestep: Time) -> Result<(), TuasLibError> {
+        DescribedLoop::try_advance_timestep(self, timestep)
+    }
+}
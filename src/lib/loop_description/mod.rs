@@ -0,0 +1,324 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// instantiates components and the loop network from a
+/// description
+pub mod described_loop;
+pub use described_loop::*;
+
+/// a loop described in a file, with its components and how they
+/// are connected
+///
+/// descriptions are written in TOML or JSON, with numbers in SI
+/// units (K, Pa, m, m2, W, W/(m2 K)) except for incline angles,
+/// which are in degrees as in the hand written component
+/// constructors. A natural circulation loop with a heated riser
+/// and an air cooled downcomer looks like:
+///
+/// ```toml
+/// # values used by every component unless it sets its own
+/// [defaults]
+/// initial_temperature = 300.0
+/// ambient_temperature = 293.15
+/// fluid = "TherminolVP1"
+/// pipe_shell_material = "SteelSS304L"
+/// insulation_material = "Fiberglass"
+/// hydraulic_diameter = 2.79e-2
+/// flow_area = 6.11e-4
+/// wall_thickness = 2.7686e-3
+/// insulation_thickness = 5.08e-2
+/// htc_to_ambient = 20.0
+/// surface_roughness = 1.5e-5
+/// inner_nodes = 3
+///
+/// [[components]]
+/// name = "heater"
+/// type = "insulated"
+/// length = 1.0
+/// incline_angle = -90.0
+/// heater_power = 500.0
+///
+/// [[components]]
+/// name = "static_mixer"
+/// type = "insulated"
+/// length = 0.33
+/// incline_angle = -90.0
+/// form_loss = 21.0
+/// reynolds_coefficient = 4000.0
+/// reynolds_power = -1.0
+/// inner_nodes = 0
+///
+/// [[components]]
+/// name = "cooler"
+/// type = "non_insulated"
+/// length = 0.67
+/// incline_angle = -90.0
+/// htc_to_ambient = 200.0
+///
+/// # branches join a top and a bottom mixing node
+/// [network]
+/// mixing_node_diameter = 3.84e-2
+///
+/// [[network.branches]]
+/// name = "hot_leg"
+/// components = ["heater"]
+///
+/// [[network.branches]]
+/// name = "cold_leg"
+/// components = ["static_mixer", "cooler"]
+/// ```
+///
+/// see [ComponentDescription] for the keys of a component, and
+/// [NetworkDescription] for how branches are connected
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LoopDescription {
+    /// values for any component key except name, used when a
+    /// component does not set the key itself
+    #[serde(default)]
+    pub defaults: ComponentDescription,
+    /// the components of the loop
+    #[serde(default)]
+    pub components: Vec<ComponentDescription>,
+    /// how the components are connected
+    pub network: NetworkDescription,
+}
+
+/// which pre-built component a description builds
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentKind {
+    /// an [InsulatedFluidComponent](crate::pre_built_components::insulated_pipes_and_fluid_components::InsulatedFluidComponent),
+    /// a pipe shell wrapped in insulation
+    Insulated,
+    /// a [NonInsulatedFluidComponent](crate::pre_built_components::non_insulated_fluid_components::NonInsulatedFluidComponent),
+    /// a bare pipe shell exposed to ambient, eg. an air cooler
+    NonInsulated,
+}
+
+/// parameters of one component, in the same terms as
+/// `InsulatedFluidComponent::new_custom_component` and
+/// `new_insulated_pipe` (or their non insulated counterparts)
+///
+/// every key is optional here so that it can be taken from the
+/// defaults table, keys which are still missing once the defaults
+/// are applied are reported when the loop is built
+///
+/// the loss model is either a pipe, where the Churchill friction
+/// factor is used with a surface roughness:
+///
+/// fLDK = f_darcy L/D + K
+///
+/// or a custom component correlated as
+///
+/// fLDK = K + b Re^(c)
+///
+/// with K the form loss, b the reynolds coefficient and c the
+/// reynolds power
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ComponentDescription {
+    /// unique name of the component, used in the network and as
+    /// the prefix of its plant inputs and outputs
+    pub name: Option<String>,
+    /// insulated or non_insulated
+    #[serde(rename = "type")]
+    pub kind: Option<ComponentKind>,
+
+    /// initial temperature of fluid and solid (K)
+    pub initial_temperature: Option<f64>,
+    /// ambient temperature (K)
+    pub ambient_temperature: Option<f64>,
+    /// fluid pressure (Pa), 1 atm if not given
+    pub fluid_pressure: Option<f64>,
+    /// solid pressure (Pa), 1 atm if not given
+    pub solid_pressure: Option<f64>,
+
+    /// component length (m)
+    pub length: Option<f64>,
+    /// hydraulic diameter (m)
+    pub hydraulic_diameter: Option<f64>,
+    /// flow area (m2), that of a circle with the hydraulic
+    /// diameter if not given
+    pub flow_area: Option<f64>,
+    /// incline angle (degrees) along the branch direction, which
+    /// runs from the top to the bottom mixing node, so vertical
+    /// components are usually at -90
+    pub incline_angle: Option<f64>,
+
+    /// form loss K, zero if not given
+    pub form_loss: Option<f64>,
+    /// b in K + b Re^(c), for custom components
+    pub reynolds_coefficient: Option<f64>,
+    /// c in K + b Re^(c), for custom components
+    pub reynolds_power: Option<f64>,
+    /// surface roughness (m), for pipes
+    pub surface_roughness: Option<f64>,
+
+    /// pipe shell inner diameter (m), the hydraulic diameter if not
+    /// given
+    pub shell_id: Option<f64>,
+    /// pipe shell outer diameter (m), give either this or the
+    /// wall thickness
+    pub shell_od: Option<f64>,
+    /// pipe shell thickness (m), the outer diameter is the inner
+    /// diameter plus twice this
+    pub wall_thickness: Option<f64>,
+    /// insulation thickness (m), insulated components only
+    pub insulation_thickness: Option<f64>,
+
+    /// name of the fluid, eg. "TherminolVP1", see
+    /// [LIQUID_MATERIALS](crate::boussinesq_thermophysical_properties::material_names::LIQUID_MATERIALS)
+    pub fluid: Option<String>,
+    /// name of the pipe shell material, eg. "SteelSS304L"
+    pub pipe_shell_material: Option<String>,
+    /// name of the insulation material, eg. "Fiberglass",
+    /// insulated components only
+    pub insulation_material: Option<String>,
+    /// heat transfer coefficient to ambient (W/(m2 K))
+    pub htc_to_ambient: Option<f64>,
+
+    /// number of inner axial nodes, the component has two more
+    /// nodes at its ends
+    pub inner_nodes: Option<usize>,
+
+    /// initial heater power (W), zero if not given
+    pub heater_power: Option<f64>,
+    /// initial pump pressure (Pa), zero if not given
+    pub pump_pressure: Option<f64>,
+}
+
+/// how the components are connected
+///
+/// the loop has a top and a bottom mixing node, and every branch
+/// runs from the top mixing node to the bottom mixing node through
+/// its components in the order they are listed. Positive branch
+/// mass flowrates are in that direction, so that incline angles are
+/// measured along it, and the branch flowrates are solved as
+/// parallel branches with zero net flow, as for the CIET and
+/// FLiBe loops. Every branch must rise or fall by the same height
+/// between the mixing nodes, otherwise the flow solver does not
+/// converge, so descriptions where they differ are rejected when
+/// built. A simple loop has two branches, one of them usually
+/// flowing backwards (upwards)
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkDescription {
+    /// diameter of the spherical mixing nodes (m)
+    pub mixing_node_diameter: f64,
+    /// the parallel branches, at least two
+    pub branches: Vec<BranchDescription>,
+}
+
+/// a branch of components in series
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BranchDescription {
+    /// unique name of the branch, used as the prefix of its plant
+    /// outputs
+    pub name: String,
+    /// names of the components, from the top mixing node to the
+    /// bottom mixing node
+    pub components: Vec<String>,
+}
+
+impl LoopDescription {
+
+    /// parses a description written in TOML
+    pub fn try_from_toml_str(description: &str) -> Result<Self, TuasLibError> {
+        let loop_description: Self = toml::from_str(description)
+            .map_err(|error| TuasLibError::GenericStringError(
+                format!("invalid loop description: {}", error)))?;
+        loop_description.try_check_names()?;
+        Ok(loop_description)
+    }
+
+    /// parses a description written in JSON, with the same keys as
+    /// the TOML format
+    pub fn try_from_json_str(description: &str) -> Result<Self, TuasLibError> {
+        let loop_description: Self = serde_json::from_str(description)
+            .map_err(|error| TuasLibError::GenericStringError(
+                format!("invalid loop description: {}", error)))?;
+        loop_description.try_check_names()?;
+        Ok(loop_description)
+    }
+
+    /// loads a description from a .toml or .json file
+    pub fn try_load<P: AsRef<Path>>(path: P) -> Result<Self, TuasLibError> {
+        let path = path.as_ref();
+        let description = std::fs::read_to_string(path)
+            .map_err(|error| TuasLibError::GenericStringError(
+                format!("cannot read {}: {}", path.display(), error)))?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::try_from_toml_str(&description),
+            Some("json") => Self::try_from_json_str(&description),
+            _ => Err(TuasLibError::GenericStringError(format!(
+                "{} is neither a .toml nor a .json file", path.display()))),
+        }
+    }
+
+    /// checks that every component has a unique name and is used
+    /// in exactly one branch, and that branch names are unique
+    fn try_check_names(&self) -> Result<(), TuasLibError> {
+
+        let error = |message: String| Err(TuasLibError::GenericStringError(
+            format!("invalid loop description: {}", message)));
+
+        if self.defaults.name.is_some() {
+            return error("defaults cannot have a name".to_owned());
+        }
+
+        let mut names: HashSet<&str> = HashSet::new();
+        for component in &self.components {
+            let Some(name) = component.name.as_deref() else {
+                return error("every component needs a name".to_owned());
+            };
+            if !names.insert(name) {
+                return error(format!("component {} is declared twice", name));
+            }
+        }
+
+        if self.network.branches.len() < 2 {
+            return error("at least two branches are needed to close the loop"
+                .to_owned());
+        }
+
+        let mut used_names: HashSet<&str> = HashSet::new();
+        let mut branch_names: HashSet<&str> = HashSet::new();
+        for branch in &self.network.branches {
+            if !branch_names.insert(&branch.name) || names.contains(branch.name.as_str()) {
+                return error(format!("branch name {} is not unique", branch.name));
+            }
+            if branch.components.is_empty() {
+                return error(format!("branch {} has no components", branch.name));
+            }
+            for name in &branch.components {
+                if !names.contains(name.as_str()) {
+                    return error(format!("branch {} uses undeclared component {}",
+                        branch.name, name));
+                }
+                if !used_names.insert(name) {
+                    return error(format!("component {} is used more than once", name));
+                }
+            }
+        }
+
+        if let Some(unused) = self.components.iter()
+            .filter_map(|component| component.name.as_deref())
+            .find(|name| !used_names.contains(name)) {
+            return error(format!("component {} is not in any branch", unused));
+        }
+
+        Ok(())
+    }
+}
+
+/// tests comparing described components with the hand written
+/// constructors, and running a described loop
+#[cfg(test)]
+pub mod tests;
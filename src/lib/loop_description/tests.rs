@@ -0,0 +1,337 @@
+use uom::si::f64::*;
+use uom::si::length::{meter, millimeter};
+use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+use uom::si::time::second;
+
+use crate::pre_built_components::ciet_steady_state_natural_circulation_test_components::dracs_loop_components::{new_pipe_34, new_static_mixer_61_label_31};
+use crate::scenario::ScenarioPlant;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::*;
+
+/// the DRACS loop pipe 34 and static mixer 61 written as a TOML
+/// description, with values formatted so that they parse to the
+/// same floats as in the hand written constructors
+///
+/// pipe 34 is horizontal, so a vertical downcomer is added after it
+/// for both branches to fall by the same height
+fn dracs_components_toml() -> String {
+    let ambient_temperature = ThermodynamicTemperature::new::<degree_celsius>(20.0)
+        .get::<kelvin>();
+    let surface_roughness = Length::new::<millimeter>(0.015).get::<meter>();
+
+    format!(r#"
+        [defaults]
+        type = "insulated"
+        initial_temperature = 350.0
+        ambient_temperature = {ambient_temperature:?}
+        fluid = "TherminolVP1"
+        pipe_shell_material = "SteelSS304L"
+        insulation_material = "Fiberglass"
+        hydraulic_diameter = 2.79e-2
+        flow_area = 6.11e-4
+        wall_thickness = 0.0027686
+        insulation_thickness = 0.0508
+        htc_to_ambient = 20.0
+
+        [[components]]
+        name = "pipe_34"
+        length = 0.55245
+        incline_angle = 0.0
+        form_loss = 4.25
+        surface_roughness = {surface_roughness:?}
+        inner_nodes = 3
+
+        [[components]]
+        name = "static_mixer_61"
+        length = 0.33
+        incline_angle = -90.0
+        form_loss = 21.0
+        reynolds_coefficient = 4000.0
+        reynolds_power = -1.0
+        inner_nodes = 0
+
+        [[components]]
+        name = "downcomer"
+        length = 3.3e-1
+        incline_angle = -90.0
+        surface_roughness = {surface_roughness:?}
+        inner_nodes = 1
+
+        [network]
+        mixing_node_diameter = 3.84e-2
+
+        [[network.branches]]
+        name = "left"
+        components = ["pipe_34", "downcomer"]
+
+        [[network.branches]]
+        name = "right"
+        components = ["static_mixer_61"]
+    "#)
+}
+
+/// described components are the same as those from the hand written
+/// constructors, for both the pipe and the custom loss models
+#[test]
+pub fn described_components_match_hand_written_constructors(){
+
+    let description = LoopDescription::try_from_toml_str(
+        &dracs_components_toml()).unwrap();
+    let described_loop = description.try_build().unwrap();
+
+    let initial_temperature = ThermodynamicTemperature::new::<kelvin>(350.0);
+
+    assert_eq!(described_loop.components[0].name, "pipe_34");
+    assert_eq!(described_loop.components[0].component,
+        DescribedComponent::Insulated(Box::new(new_pipe_34(initial_temperature))));
+
+    assert_eq!(described_loop.components[1].name, "static_mixer_61");
+    assert_eq!(described_loop.components[1].component,
+        DescribedComponent::Insulated(Box::new(
+            new_static_mixer_61_label_31(initial_temperature))));
+
+    assert_eq!(described_loop.branches[1].component_indices, vec![1]);
+}
+
+/// the JSON format has the same keys as the TOML format
+#[test]
+pub fn json_and_toml_descriptions_are_equivalent(){
+
+    let json_description = r#"{
+        "defaults": {
+            "type": "non_insulated",
+            "initial_temperature": 300.0,
+            "ambient_temperature": 293.15,
+            "fluid": "TherminolVP1",
+            "pipe_shell_material": "SteelSS304L",
+            "hydraulic_diameter": 0.0279,
+            "shell_od": 0.0334,
+            "htc_to_ambient": 20.0,
+            "surface_roughness": 1.5e-5,
+            "inner_nodes": 2
+        },
+        "components": [
+            {"name": "riser", "length": 1.0, "incline_angle": -90.0},
+            {"name": "downcomer", "length": 1.0, "incline_angle": -90.0}
+        ],
+        "network": {
+            "mixing_node_diameter": 0.0384,
+            "branches": [
+                {"name": "up", "components": ["riser"]},
+                {"name": "down", "components": ["downcomer"]}
+            ]
+        }
+    }"#;
+
+    let toml_description = r#"
+        [defaults]
+        type = "non_insulated"
+        initial_temperature = 300.0
+        ambient_temperature = 293.15
+        fluid = "TherminolVP1"
+        pipe_shell_material = "SteelSS304L"
+        hydraulic_diameter = 0.0279
+        shell_od = 0.0334
+        htc_to_ambient = 20.0
+        surface_roughness = 1.5e-5
+        inner_nodes = 2
+
+        [[components]]
+        name = "riser"
+        length = 1.0
+        incline_angle = -90.0
+
+        [[components]]
+        name = "downcomer"
+        length = 1.0
+        incline_angle = -90.0
+
+        [network]
+        mixing_node_diameter = 0.0384
+        branches = [
+            { name = "up", components = ["riser"] },
+            { name = "down", components = ["downcomer"] },
+        ]
+    "#;
+
+    let from_json = LoopDescription::try_from_json_str(json_description).unwrap();
+    let from_toml = LoopDescription::try_from_toml_str(toml_description).unwrap();
+    assert_eq!(from_json, from_toml);
+    assert_eq!(from_json.try_build().unwrap(), from_toml.try_build().unwrap());
+
+    // files are read by their extension
+    let directory = std::env::temp_dir();
+    let json_path = directory.join("tuas_loop_description_test.json");
+    std::fs::write(&json_path, json_description).unwrap();
+    assert_eq!(LoopDescription::try_load(&json_path).unwrap(), from_json);
+    std::fs::remove_file(&json_path).unwrap();
+
+    assert!(LoopDescription::try_load(directory.join("loop.yaml")).is_err());
+}
+
+/// mistakes in a description are reported with the component or
+/// key at fault
+#[test]
+pub fn invalid_descriptions_are_rejected(){
+
+    let error_message = |description: String| -> String {
+        let result = LoopDescription::try_from_toml_str(&description)
+            .and_then(|description| description.try_build());
+        let error: TuasLibError = result.err()
+            .unwrap_or_else(|| panic!("accepted invalid description {}", description));
+        error.into()
+    };
+    let valid = dracs_components_toml();
+
+    // network mistakes are found when parsing
+    assert!(error_message(valid.replace(r#"["static_mixer_61"]"#, r#"["static_mixer_62"]"#))
+        .contains("undeclared component static_mixer_62"));
+    assert!(error_message(valid.replace(r#"["static_mixer_61"]"#,
+        r#"["static_mixer_61", "pipe_34"]"#))
+        .contains("pipe_34 is used more than once"));
+    assert!(error_message(valid.replace(r#"name = "right""#, r#"name = "left""#))
+        .contains("branch name left is not unique"));
+    assert!(error_message(valid.replace("inner_nodes = 0", "inner_nodes = 0\nvalve = 1.0"))
+        .contains("unknown field"));
+
+    // component mistakes are found when building
+    assert!(error_message(valid.replace("length = 0.33", ""))
+        .contains("component static_mixer_61 has no length"));
+    assert!(error_message(valid.replace("\"TherminolVP1\"", "\"Brine\""))
+        .contains("Brine"));
+    assert!(error_message(valid.replace("inner_nodes = 0",
+        "inner_nodes = 0\nsurface_roughness = 1e-5"))
+        .contains("both a surface_roughness and reynolds coefficients"));
+    assert!(error_message(valid.replace("wall_thickness = 0.0027686", ""))
+        .contains("shell_od or wall_thickness"));
+
+    // branches which change height by different amounts are found
+    // when building, rather than when the flow solver fails
+    let mismatched_heights = error_message(valid.replace("length = 3.3e-1", "length = 0.5"));
+    assert!(mismatched_heights.contains("every branch must change height by the same amount"));
+    assert!(mismatched_heights.contains("branch left"));
+    assert!(mismatched_heights.contains("right (-0.33 m)"));
+}
+
+/// a natural circulation loop with a heated branch and a cooled
+/// branch: the fluid rises through the heater, which is flow
+/// against the branch direction, falls through the cooler, and
+/// the loop heats up
+#[test]
+pub fn described_natural_circulation_loop_heats_up_and_flows(){
+
+    let description = r#"
+        [defaults]
+        initial_temperature = 300.0
+        ambient_temperature = 293.15
+        fluid = "TherminolVP1"
+        pipe_shell_material = "SteelSS304L"
+        insulation_material = "Fiberglass"
+        hydraulic_diameter = 2.79e-2
+        flow_area = 6.11e-4
+        wall_thickness = 2.7686e-3
+        insulation_thickness = 5.08e-2
+        htc_to_ambient = 20.0
+        surface_roughness = 1.5e-5
+        inner_nodes = 3
+
+        [[components]]
+        name = "heater"
+        type = "insulated"
+        length = 2.0
+        incline_angle = -90.0
+        heater_power = 2000.0
+
+        [[components]]
+        name = "static_mixer"
+        type = "insulated"
+        length = 0.33
+        incline_angle = -90.0
+        form_loss = 21.0
+        reynolds_coefficient = 4000.0
+        reynolds_power = -1.0
+        inner_nodes = 0
+
+        [[components]]
+        name = "cooler"
+        type = "non_insulated"
+        length = 1.67
+        incline_angle = -90.0
+        htc_to_ambient = 200.0
+
+        [network]
+        mixing_node_diameter = 3.84e-2
+
+        [[network.branches]]
+        name = "hot_leg"
+        components = ["heater"]
+
+        [[network.branches]]
+        name = "cold_leg"
+        components = ["static_mixer", "cooler"]
+    "#;
+
+    let mut described_loop = LoopDescription::try_from_toml_str(description)
+        .unwrap().try_build().unwrap();
+
+    // the flow solver returns zero flow in a branch whose pressure
+    // loss is within a manometer reading (9 Pa), so the flow starts
+    // once the hot leg has warmed up, and at these small flows the
+    // branches only roughly balance
+    let timestep = Time::new::<second>(0.1);
+    let mut hot_leg_flows = vec![];
+    for _ in 0..3000 {
+        described_loop.try_advance_timestep(timestep).unwrap();
+
+        let hot_leg_flow = described_loop.try_get_output("hot_leg.mass_flowrate")
+            .unwrap();
+        let cold_leg_flow = described_loop.try_get_output("cold_leg.mass_flowrate")
+            .unwrap();
+        assert!(hot_leg_flow <= 0.0 && cold_leg_flow >= 0.0);
+        hot_leg_flows.push(hot_leg_flow);
+    }
+
+    let fastest_flow = hot_leg_flows.iter().cloned().fold(0.0, f64::min);
+    assert!(fastest_flow < -0.01, "fastest hot leg flow {}", fastest_flow);
+
+    let heater_temperature = described_loop.try_get_output("heater.bulk_temperature")
+        .unwrap();
+    let cooler_temperature = described_loop.try_get_output("cooler.bulk_temperature")
+        .unwrap();
+    assert!(heater_temperature > cooler_temperature);
+    assert!(heater_temperature > 300.0);
+    approx::assert_relative_eq!(
+        described_loop.try_get_output("simulation_time").unwrap(), 300.0,
+        max_relative = 1e-9);
+}
+
+/// the inputs of a described loop can be set and read back by name
+#[test]
+pub fn described_loop_inputs_and_outputs(){
+
+    let mut described_loop = LoopDescription::try_from_toml_str(
+        &dracs_components_toml()).unwrap().try_build().unwrap();
+
+    described_loop.try_set_input("pipe_34.heater_power", 250.0).unwrap();
+    described_loop.try_set_input("pipe_34.pump_pressure", 1000.0).unwrap();
+    described_loop.try_set_input("static_mixer_61.htc_to_ambient", 35.0).unwrap();
+    described_loop.try_set_input("static_mixer_61.ambient_temperature", 310.0).unwrap();
+
+    assert_eq!(described_loop.try_get_input("pipe_34.heater_power").unwrap(), 250.0);
+    assert_eq!(described_loop.try_get_input("pipe_34.pump_pressure").unwrap(), 1000.0);
+    assert_eq!(described_loop.try_get_output("static_mixer_61.htc_to_ambient").unwrap(),
+        35.0);
+    assert_eq!(described_loop.try_get_input("static_mixer_61.ambient_temperature")
+        .unwrap(), 310.0);
+    assert_eq!(described_loop.try_get_output("pipe_34.bulk_temperature").unwrap(), 350.0);
+
+    assert!(described_loop.try_set_input("pipe_35.heater_power", 1.0).is_err());
+    assert!(described_loop.try_set_input("pipe_34.valve_opening", 1.0).is_err());
+    assert!(described_loop.try_get_output("left.pressure_drop").is_err());
+
+    assert_eq!(described_loop.get_input_names().len(), 12);
+    assert_eq!(described_loop.get_output_names(), vec![
+        "simulation_time", "pipe_34.bulk_temperature", "static_mixer_61.bulk_temperature",
+        "downcomer.bulk_temperature", "left.mass_flowrate", "right.mass_flowrate"]);
+}
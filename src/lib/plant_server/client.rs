@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+
+use serde_json::{Map, Value};
+use uom::si::f64::*;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::PlantRequest;
+
+/// connects to a [PlantServer](super::PlantServer) and sends one
+/// request at a time, waiting for each reply
+pub struct PlantServerClient {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl PlantServerClient {
+
+    /// connects to a running plant server
+    pub fn try_connect<A: ToSocketAddrs>(address: A) -> Result<Self, TuasLibError> {
+        let writer = TcpStream::connect(address).map_err(io_error)?;
+        let reader = BufReader::new(writer.try_clone().map_err(io_error)?);
+        Ok(Self { reader, writer })
+    }
+
+    /// sends a request and returns the reply fields, replies with
+    /// ok false are returned as errors with the server's message
+    pub fn try_send_request(&mut self, request: &PlantRequest)
+        -> Result<Map<String, Value>, TuasLibError> {
+
+        let mut line = request.to_json_line();
+        line.push('\n');
+        self.writer.write_all(line.as_bytes()).map_err(io_error)?;
+        self.writer.flush().map_err(io_error)?;
+
+        let mut reply_line = String::new();
+        if self.reader.read_line(&mut reply_line).map_err(io_error)? == 0 {
+            return Err(TuasLibError::GenericStringError(
+                "plant server closed the connection".to_owned()));
+        }
+
+        let reply: Value = serde_json::from_str(&reply_line)
+            .map_err(|error| TuasLibError::GenericStringError(
+                format!("invalid reply from plant server: {}", error)))?;
+        let Value::Object(mut fields) = reply else {
+            return Err(TuasLibError::GenericStringError(
+                format!("invalid reply from plant server: {}", reply_line)));
+        };
+
+        match fields.remove("ok") {
+            Some(Value::Bool(true)) => Ok(fields),
+            _ => {
+                let message = fields.get("error").and_then(Value::as_str)
+                    .unwrap_or("plant server returned an error");
+                Err(TuasLibError::GenericStringError(message.to_owned()))
+            },
+        }
+    }
+
+    /// names of the exposed inputs and outputs
+    pub fn try_list(&mut self) -> Result<(Vec<String>, Vec<String>), TuasLibError> {
+        let fields = self.try_send_request(&PlantRequest::List)?;
+        let get_names = |key: &str| -> Vec<String> {
+            fields.get(key).and_then(Value::as_array)
+                .map(|names| names.iter()
+                    .filter_map(|name| name.as_str().map(str::to_owned))
+                    .collect())
+                .unwrap_or_default()
+        };
+        Ok((get_names("inputs"), get_names("outputs")))
+    }
+
+    /// reads inputs or outputs by name, values which are not
+    /// finite are returned as NaN
+    pub fn try_get(&mut self, names: &[&str]) -> Result<HashMap<String, f64>, TuasLibError> {
+        let request = PlantRequest::Get {
+            names: names.iter().map(|name| name.to_string()).collect(),
+        };
+        let fields = self.try_send_request(&request)?;
+        let values = fields.get("values").and_then(Value::as_object)
+            .ok_or_else(|| TuasLibError::GenericStringError(
+                "plant server reply has no values".to_owned()))?;
+
+        Ok(values.iter()
+            .map(|(name, value)| (name.clone(), value.as_f64().unwrap_or(f64::NAN)))
+            .collect())
+    }
+
+    /// reads one input or output by name
+    pub fn try_get_value(&mut self, name: &str) -> Result<f64, TuasLibError> {
+        self.try_get(&[name])?.remove(name)
+            .ok_or_else(|| TuasLibError::GenericStringError(
+                format!("plant server did not return {}", name)))
+    }
+
+    /// writes inputs by name
+    pub fn try_set(&mut self, values: &[(&str, f64)]) -> Result<(), TuasLibError> {
+        let request = PlantRequest::Set {
+            values: values.iter()
+                .map(|(name, value)| (name.to_string(), *value))
+                .collect(),
+        };
+        self.try_send_request(&request)?;
+        Ok(())
+    }
+
+    /// advances the plant by a number of timesteps, if the server
+    /// allows clients to step the plant
+    pub fn try_step(&mut self, timestep: Time, steps: usize) -> Result<(), TuasLibError> {
+        self.try_send_request(&PlantRequest::Step { timestep, steps })?;
+        Ok(())
+    }
+}
+
+fn io_error(error: std::io::Error) -> TuasLibError {
+    TuasLibError::GenericStringError(format!("plant server client: {}", error))
+}
@@ -0,0 +1,302 @@
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::fmi_export::{Fmi2Model, FmiCausality};
+use crate::scenario::ScenarioPlant;
+use crate::tuas_lib_error::TuasLibError;
+
+/// parses requests and formats replies of the line delimited json
+/// protocol
+pub mod protocol;
+pub use protocol::*;
+
+/// a blocking client for the plant server, for HMIs, data
+/// historians and tests written in rust
+pub mod client;
+pub use client::*;
+
+/// how often the listener and connections check whether the
+/// server was shut down
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// default limit on the number of timesteps in one step request
+pub const DEFAULT_MAX_STEPS_PER_REQUEST: usize = 10_000;
+
+/// default limit on the length of one request line in bytes,
+/// excluding the newline
+pub const DEFAULT_MAX_REQUEST_LINE_LENGTH: usize = 64 * 1024;
+
+/// which plant inputs and outputs the server exposes
+///
+/// clients may read any input or output in these lists, but may
+/// only write to the inputs, so that a classroom client cannot
+/// change anything the instructor has not exposed
+///
+/// requests are also limited in size, so that one client cannot
+/// hold the plant lock for a long time with a large step request,
+/// or fill the server's memory with a line that never ends
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlantServerVariables {
+    /// names of the plant inputs clients may read and write
+    pub inputs: Vec<String>,
+    /// names of the plant outputs clients may read
+    pub outputs: Vec<String>,
+    /// whether clients may advance the plant with the step
+    /// command, this should be false when the plant is advanced by
+    /// its own simulation thread
+    pub allow_client_stepping: bool,
+    /// step requests with more timesteps than this get an error
+    /// reply
+    pub max_steps_per_request: usize,
+    /// request lines longer than this in bytes, excluding the
+    /// newline, get an error reply and are discarded
+    pub max_request_line_length: usize,
+}
+
+impl Default for PlantServerVariables {
+    fn default() -> Self {
+        Self {
+            inputs: vec![],
+            outputs: vec![],
+            allow_client_stepping: false,
+            max_steps_per_request: DEFAULT_MAX_STEPS_PER_REQUEST,
+            max_request_line_length: DEFAULT_MAX_REQUEST_LINE_LENGTH,
+        }
+    }
+}
+
+impl PlantServerVariables {
+
+    /// exposes the given inputs and outputs
+    pub fn new(inputs: &[&str], outputs: &[&str]) -> Self {
+        Self {
+            inputs: inputs.iter().map(|name| name.to_string()).collect(),
+            outputs: outputs.iter().map(|name| name.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// exposes the inputs and outputs of a model exported through
+    /// FMI, parameters are not exposed as they are fixed once the
+    /// plant is constructed
+    pub fn from_fmi_model<M: Fmi2Model>() -> Self {
+        let mut variables = Self::default();
+        for variable in M::get_model_variables() {
+            match variable.causality {
+                FmiCausality::Input => variables.inputs.push(variable.name),
+                FmiCausality::Output => variables.outputs.push(variable.name),
+                FmiCausality::Parameter => (),
+            }
+        }
+        variables
+    }
+
+    /// whether clients may read this input or output
+    pub fn is_readable(&self, name: &str) -> bool {
+        self.is_writable(name) || self.outputs.iter().any(|output| output == name)
+    }
+
+    /// whether clients may write to this input
+    pub fn is_writable(&self, name: &str) -> bool {
+        self.inputs.iter().any(|input| input == name)
+    }
+}
+
+/// serves the inputs and outputs of a plant to clients on
+/// localhost
+///
+/// the plant is shared through an `Arc<Mutex<_>>`, as the CIET
+/// state is shared between the GUI and calculation threads of the
+/// educational simulator, so the plant can be advanced by its own
+/// simulation thread while clients read sensors and write set
+/// points. Each request locks the plant once, so a set request
+/// with several values is applied between two timesteps
+///
+/// the protocol is line delimited json, see [PlantRequest]. Only
+/// loopback addresses are accepted, as there is no authentication
+///
+/// ```ignore
+/// let plant = Arc::new(Mutex::new(CIETPlant::try_new(...)?));
+/// let server = PlantServer::try_start(plant.clone(), "127.0.0.1:5020",
+///     PlantServerVariables::from_fmi_model::<CIETPlant>())?;
+///
+/// // simulation thread
+/// loop {
+///     plant.lock().unwrap().try_advance_timestep(timestep)?;
+/// }
+/// ```
+pub struct PlantServer {
+    local_address: SocketAddr,
+    stop_flag: Arc<AtomicBool>,
+    listener_thread: Option<JoinHandle<()>>,
+}
+
+impl PlantServer {
+
+    /// binds to a loopback address and starts serving the plant on
+    /// a background thread, use port 0 to let the operating system
+    /// pick a free port
+    pub fn try_start<P, A>(plant: Arc<Mutex<P>>, address: A,
+        variables: PlantServerVariables) -> Result<Self, TuasLibError>
+    where P: ScenarioPlant + Send + 'static,
+          A: ToSocketAddrs {
+
+        let io_error = |error: std::io::Error| TuasLibError::GenericStringError(
+            format!("plant server: {}", error));
+
+        let socket_addresses: Vec<SocketAddr> = address.to_socket_addrs()
+            .map_err(io_error)?
+            .collect();
+        if socket_addresses.is_empty() ||
+            socket_addresses.iter().any(|address| !address.ip().is_loopback()) {
+            return Err(TuasLibError::GenericStringError(
+                format!("plant server: only loopback addresses are allowed, got {:?}",
+                    socket_addresses)));
+        }
+
+        let listener = TcpListener::bind(&socket_addresses[..]).map_err(io_error)?;
+        listener.set_nonblocking(true).map_err(io_error)?;
+        let local_address = listener.local_addr().map_err(io_error)?;
+
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let listener_stop_flag = stop_flag.clone();
+        let variables = Arc::new(variables);
+
+        let listener_thread = thread::spawn(move || {
+            let mut connection_threads: Vec<JoinHandle<()>> = vec![];
+
+            while !listener_stop_flag.load(Ordering::Relaxed) {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        let plant = plant.clone();
+                        let variables = variables.clone();
+                        let stop_flag = listener_stop_flag.clone();
+                        connection_threads.push(thread::spawn(move || {
+                            // a broken connection only ends that
+                            // client's session
+                            let _ = serve_connection(stream, plant,
+                                &variables, &stop_flag);
+                        }));
+                    },
+                    Err(error) if error.kind() == ErrorKind::WouldBlock => {
+                        thread::sleep(POLL_INTERVAL);
+                    },
+                    Err(_) => thread::sleep(POLL_INTERVAL),
+                }
+                connection_threads.retain(|handle| !handle.is_finished());
+            }
+
+            for handle in connection_threads {
+                let _ = handle.join();
+            }
+        });
+
+        Ok(Self {
+            local_address,
+            stop_flag,
+            listener_thread: Some(listener_thread),
+        })
+    }
+
+    /// address the server is listening on, including the port
+    /// picked by the operating system
+    pub fn get_local_address(&self) -> SocketAddr {
+        self.local_address
+    }
+
+    /// stops accepting clients, closes open connections and waits
+    /// for the server threads to finish
+    pub fn shutdown(&mut self) {
+        self.stop_flag.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.listener_thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for PlantServer {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// reads requests line by line and writes one reply line per
+/// request until the client disconnects or the server stops
+fn serve_connection<P: ScenarioPlant>(stream: TcpStream,
+    plant: Arc<Mutex<P>>,
+    variables: &PlantServerVariables,
+    stop_flag: &AtomicBool) -> std::io::Result<()> {
+
+    // the listener is non blocking, connections block with a
+    // timeout so that they notice the stop flag
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(POLL_INTERVAL))?;
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+
+    // a line may arrive over several reads, bytes read before a
+    // timeout are kept in the buffer
+    let mut line: Vec<u8> = vec![];
+    // set once a line is too long, the rest of that line is read
+    // and discarded
+    let mut discarding_long_line = false;
+
+    while !stop_flag.load(Ordering::Relaxed) {
+        // reads at most one byte more than the longest line allowed,
+        // including the newline
+        let read_limit = (variables.max_request_line_length + 1)
+            .saturating_sub(line.len()) as u64;
+
+        match (&mut reader).take(read_limit).read_until(b'\n', &mut line) {
+            Ok(0) => return Ok(()),
+            Ok(_) => {
+                if line.last() != Some(&b'\n') {
+                    if line.len() <= variables.max_request_line_length {
+                        // end of stream without a newline
+                        return Ok(());
+                    }
+                    // one error reply per long line
+                    if !discarding_long_line {
+                        let reply = error_reply_line(&format!(
+                            "request line is longer than {} bytes",
+                            variables.max_request_line_length));
+                        writer.write_all(reply.as_bytes())?;
+                        writer.write_all(b"\n")?;
+                        writer.flush()?;
+                        discarding_long_line = true;
+                    }
+                    line.clear();
+                    continue;
+                }
+                if discarding_long_line {
+                    // the end of a line which was too long
+                    discarding_long_line = false;
+                    line.clear();
+                    continue;
+                }
+                let request = String::from_utf8_lossy(&line);
+                if !request.trim().is_empty() {
+                    let reply = handle_request_line(request.trim(),
+                        &plant, variables);
+                    writer.write_all(reply.as_bytes())?;
+                    writer.write_all(b"\n")?;
+                    writer.flush()?;
+                }
+                line.clear();
+            },
+            Err(error) if error.kind() == ErrorKind::WouldBlock
+                || error.kind() == ErrorKind::TimedOut
+                || error.kind() == ErrorKind::Interrupted => (),
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(())
+}
+
+/// tests with an in process client and a lumped plant
+#[cfg(test)]
+pub mod tests;
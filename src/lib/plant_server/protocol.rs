@@ -0,0 +1,248 @@
+use std::sync::Mutex;
+
+use serde_json::{json, Map, Value};
+use uom::si::f64::*;
+use uom::si::time::second;
+
+use crate::scenario::ScenarioPlant;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::PlantServerVariables;
+
+/// a request from a client, one json object per line
+///
+/// ```text
+/// {"command": "list"}
+/// {"command": "get", "names": ["bt_12_heater_outlet", "heater_power"]}
+/// {"command": "set", "values": {"heater_power": 8000.0}}
+/// {"command": "step", "timestep": 0.2, "steps": 10}
+/// ```
+///
+/// every request gets one reply line, `{"ok": true, ...}` on
+/// success and `{"ok": false, "error": "..."}` otherwise. Values are
+/// plain numbers in SI units as in [ScenarioPlant], values which are
+/// not finite are sent as null
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlantRequest {
+    /// lists the exposed inputs and outputs, the reply has the
+    /// fields inputs and outputs
+    List,
+    /// reads inputs or outputs, the reply has a values object
+    /// mapping names to values. Without names, every exposed
+    /// output is read
+    Get {
+        /// names of the inputs or outputs to read
+        names: Vec<String>,
+    },
+    /// writes inputs, all names are checked before any input is
+    /// changed
+    Set {
+        /// names and values of the inputs to write
+        values: Vec<(String, f64)>,
+    },
+    /// advances the plant, only allowed if
+    /// [PlantServerVariables::allow_client_stepping] is set, and
+    /// for at most [PlantServerVariables::max_steps_per_request]
+    /// timesteps
+    Step {
+        /// timestep of the plant
+        timestep: Time,
+        /// number of timesteps, 1 if not given
+        steps: usize,
+    },
+}
+
+impl PlantRequest {
+
+    /// parses one line of the protocol
+    pub fn try_parse(line: &str) -> Result<Self, TuasLibError> {
+
+        let parse_error = |message: String| TuasLibError::GenericStringError(message);
+
+        let request: Value = serde_json::from_str(line)
+            .map_err(|error| parse_error(format!("invalid json: {}", error)))?;
+        let command = request.get("command")
+            .and_then(Value::as_str)
+            .ok_or_else(|| parse_error("request has no command".to_owned()))?;
+
+        match command {
+            "list" => Ok(Self::List),
+            "get" => {
+                let names = match request.get("names") {
+                    None => vec![],
+                    Some(Value::Array(names)) => names.iter()
+                        .map(|name| name.as_str().map(str::to_owned)
+                            .ok_or_else(|| parse_error(
+                                "names must be strings".to_owned())))
+                        .collect::<Result<Vec<String>, TuasLibError>>()?,
+                    Some(_) => return Err(parse_error(
+                        "names must be an array".to_owned())),
+                };
+                Ok(Self::Get { names })
+            },
+            "set" => {
+                let values = request.get("values")
+                    .and_then(Value::as_object)
+                    .ok_or_else(|| parse_error(
+                        "set needs a values object".to_owned()))?;
+                let values = values.iter()
+                    .map(|(name, value)| value.as_f64()
+                        .map(|value| (name.clone(), value))
+                        .ok_or_else(|| parse_error(
+                            format!("value of {} is not a number", name))))
+                    .collect::<Result<Vec<(String, f64)>, TuasLibError>>()?;
+                Ok(Self::Set { values })
+            },
+            "step" => {
+                let timestep_seconds = request.get("timestep")
+                    .and_then(Value::as_f64)
+                    .ok_or_else(|| parse_error(
+                        "step needs a timestep in seconds".to_owned()))?;
+                if !timestep_seconds.is_finite() || timestep_seconds <= 0.0 {
+                    return Err(parse_error(
+                        format!("timestep must be positive, got {}", timestep_seconds)));
+                }
+                let steps = match request.get("steps") {
+                    None => 1,
+                    Some(steps) => steps.as_u64()
+                        .and_then(|steps| usize::try_from(steps).ok())
+                        .ok_or_else(|| parse_error(
+                            "steps must be a non negative integer".to_owned()))?,
+                };
+                Ok(Self::Step {
+                    timestep: Time::new::<second>(timestep_seconds),
+                    steps,
+                })
+            },
+            _ => Err(parse_error(format!("unknown command {}", command))),
+        }
+    }
+
+    /// formats the request as one line of the protocol, without
+    /// the trailing newline
+    pub fn to_json_line(&self) -> String {
+        let request = match self {
+            Self::List => json!({"command": "list"}),
+            Self::Get { names } => json!({"command": "get", "names": names}),
+            Self::Set { values } => {
+                let values: Map<String, Value> = values.iter()
+                    .map(|(name, value)| (name.clone(), json!(value)))
+                    .collect();
+                json!({"command": "set", "values": values})
+            },
+            Self::Step { timestep, steps } => json!({
+                "command": "step",
+                "timestep": timestep.get::<second>(),
+                "steps": steps,
+            }),
+        };
+        request.to_string()
+    }
+}
+
+/// carries out a request on the plant and returns the reply
+/// fields, without the ok field
+pub fn try_handle_request<P: ScenarioPlant>(request: &PlantRequest,
+    plant: &Mutex<P>,
+    variables: &PlantServerVariables) -> Result<Map<String, Value>, TuasLibError> {
+
+    let mut reply = Map::new();
+
+    // checks names before the plant is locked
+    match request {
+        PlantRequest::List => {
+            reply.insert("inputs".to_owned(), json!(variables.inputs));
+            reply.insert("outputs".to_owned(), json!(variables.outputs));
+            return Ok(reply);
+        },
+        PlantRequest::Get { names } => {
+            if let Some(name) = names.iter().find(|name| !variables.is_readable(name)) {
+                return Err(TuasLibError::GenericStringError(
+                    format!("{} is not an exposed input or output", name)));
+            }
+        },
+        PlantRequest::Set { values } => {
+            for (name, value) in values {
+                if !variables.is_writable(name) {
+                    return Err(TuasLibError::GenericStringError(
+                        format!("{} is not a writable input", name)));
+                }
+                if !value.is_finite() {
+                    return Err(TuasLibError::GenericStringError(
+                        format!("value of {} is not finite", name)));
+                }
+            }
+        },
+        PlantRequest::Step { steps, .. } => {
+            if !variables.allow_client_stepping {
+                return Err(TuasLibError::GenericStringError(
+                    "stepping is not allowed, the plant is advanced by its simulation thread"
+                    .to_owned()));
+            }
+            if *steps > variables.max_steps_per_request {
+                return Err(TuasLibError::GenericStringError(
+                    format!("at most {} steps are allowed per request, got {}",
+                        variables.max_steps_per_request, steps)));
+            }
+        },
+    }
+
+    let mut plant = plant.lock().map_err(|_| TuasLibError::GenericStringError(
+        "plant is unavailable, the simulation thread panicked".to_owned()))?;
+
+    match request {
+        PlantRequest::List => (),
+        PlantRequest::Get { names } => {
+            let names = if names.is_empty() { &variables.outputs } else { names };
+            let mut values = Map::new();
+            for name in names {
+                // inputs are read as set, outputs from the probes
+                let value = if variables.is_writable(name) {
+                    plant.try_get_input(name)?
+                } else {
+                    plant.try_get_output(name)?
+                };
+                values.insert(name.clone(), json!(value));
+            }
+            reply.insert("values".to_owned(), Value::Object(values));
+        },
+        PlantRequest::Set { values } => {
+            for (name, value) in values {
+                plant.try_set_input(name, *value)?;
+            }
+        },
+        PlantRequest::Step { timestep, steps } => {
+            for _ in 0..*steps {
+                plant.try_advance_timestep(*timestep)?;
+            }
+        },
+    }
+
+    Ok(reply)
+}
+
+/// parses and handles one request line, returning the reply line
+/// without the trailing newline
+pub fn handle_request_line<P: ScenarioPlant>(line: &str,
+    plant: &Mutex<P>,
+    variables: &PlantServerVariables) -> String {
+
+    let result = PlantRequest::try_parse(line)
+        .and_then(|request| try_handle_request(&request, plant, variables));
+
+    match result {
+        Ok(mut fields) => {
+            fields.insert("ok".to_owned(), Value::Bool(true));
+            Value::Object(fields).to_string()
+        },
+        Err(error) => {
+            let message: String = error.into();
+            error_reply_line(&message)
+        },
+    }
+}
+
+/// formats an error reply line without the trailing newline
+pub fn error_reply_line(message: &str) -> String {
+    json!({"ok": false, "error": message}).to_string()
+}
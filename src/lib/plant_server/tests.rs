@@ -0,0 +1,261 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use uom::si::f64::*;
+use uom::si::time::second;
+
+use crate::pre_built_components::ciet_three_branch_plus_dracs::ciet_plant::CIETPlant;
+use crate::scenario::test_plants::HeatedTank;
+use crate::scenario::ScenarioPlant;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::*;
+
+/// starts a server for a tank on a port picked by the operating
+/// system
+fn start_tank_server(allow_client_stepping: bool)
+    -> (Arc<Mutex<HeatedTank>>, PlantServer) {
+
+    let tank = Arc::new(Mutex::new(HeatedTank::default()));
+    let mut variables = PlantServerVariables::new(&["heater_power"],
+        &["temperature", "heat_loss"]);
+    variables.allow_client_stepping = allow_client_stepping;
+
+    let server = PlantServer::try_start(tank.clone(), "127.0.0.1:0", variables)
+        .unwrap();
+    (tank, server)
+}
+
+/// a client lists the variables, writes the heater power, steps the
+/// tank and reads the same temperature as a tank advanced directly
+#[test]
+pub fn client_reads_sensors_writes_set_points_and_steps(){
+
+    let (tank, mut server) = start_tank_server(true);
+    let mut client = PlantServerClient::try_connect(server.get_local_address())
+        .unwrap();
+
+    let (inputs, outputs) = client.try_list().unwrap();
+    assert_eq!(inputs, vec!["heater_power".to_owned()]);
+    assert_eq!(outputs, vec!["temperature".to_owned(), "heat_loss".to_owned()]);
+
+    client.try_set(&[("heater_power", 500.0)]).unwrap();
+    assert_eq!(tank.lock().unwrap().heater_power_watts, 500.0);
+    assert_eq!(client.try_get_value("heater_power").unwrap(), 500.0);
+
+    let timestep = Time::new::<second>(0.5);
+    client.try_step(timestep, 200).unwrap();
+
+    let mut reference_tank = HeatedTank {
+        heater_power_watts: 500.0,
+        ..Default::default()
+    };
+    for _ in 0..200 {
+        reference_tank.try_advance_timestep(timestep).unwrap();
+    }
+
+    // without names, every exposed output is read
+    let values = client.try_get(&[]).unwrap();
+    assert_eq!(values.len(), 2);
+    assert_eq!(values["temperature"], reference_tank.temperature_kelvin);
+    approx::assert_relative_eq!(values["heat_loss"],
+        10.0 * (reference_tank.temperature_kelvin - 300.0));
+    assert!(values["temperature"] > 330.0);
+
+    server.shutdown();
+}
+
+/// bad requests get an error reply and leave the connection open,
+/// and clients cannot write outputs, read hidden variables or step
+/// a plant advanced by its own simulation thread
+#[test]
+pub fn invalid_requests_are_rejected(){
+
+    let (tank, server) = start_tank_server(false);
+    let mut client = PlantServerClient::try_connect(server.get_local_address())
+        .unwrap();
+
+    let error_message = |result: Result<(), TuasLibError>| -> String {
+        result.unwrap_err().into()
+    };
+
+    assert!(error_message(client.try_set(&[("temperature", 400.0)]))
+        .contains("not a writable input"));
+    assert!(error_message(client.try_get(&["pressure"]).map(|_| ()))
+        .contains("not an exposed input or output"));
+    assert!(error_message(client.try_step(Time::new::<second>(0.5), 1))
+        .contains("stepping is not allowed"));
+
+    // one bad name means no input is changed
+    assert!(client.try_set(&[("heater_power", 100.0), ("temperature", 1.0)]).is_err());
+    assert_eq!(tank.lock().unwrap().heater_power_watts, 0.0);
+
+    // raw lines, including malformed json and a request split over
+    // two writes
+    let mut stream = TcpStream::connect(server.get_local_address()).unwrap();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut send_and_receive = |parts: &[&str]| -> String {
+        for part in parts {
+            stream.write_all(part.as_bytes()).unwrap();
+            stream.flush().unwrap();
+            thread::sleep(Duration::from_millis(50));
+        }
+        let mut reply = String::new();
+        reader.read_line(&mut reply).unwrap();
+        reply
+    };
+
+    assert!(send_and_receive(&["{not json\n"]).contains("\"ok\":false"));
+    assert!(send_and_receive(&["{\"command\":\"reboot\"}\n"])
+        .contains("unknown command reboot"));
+    assert!(send_and_receive(&["{\"command\":\"set\",\"values\":{\"heater_power\":\"hot\"}}\n"])
+        .contains("not a number"));
+    let reply = send_and_receive(&["{\"command\":\"get\",", "\"names\":[\"temperature\"]}\n"]);
+    assert_eq!(reply.trim(), "{\"ok\":true,\"values\":{\"temperature\":300.0}}");
+
+    // the first client is still connected
+    assert_eq!(client.try_get_value("temperature").unwrap(), 300.0);
+}
+
+/// step requests with too many timesteps and request lines which
+/// are too long get an error reply, and the connection stays usable
+#[test]
+pub fn oversized_requests_are_rejected(){
+
+    let tank = Arc::new(Mutex::new(HeatedTank::default()));
+    let mut variables = PlantServerVariables::new(&["heater_power"],
+        &["temperature"]);
+    variables.allow_client_stepping = true;
+    variables.max_steps_per_request = 100;
+    variables.max_request_line_length = 256;
+
+    let server = PlantServer::try_start(tank.clone(), "127.0.0.1:0",
+        variables.clone()).unwrap();
+    let mut client = PlantServerClient::try_connect(server.get_local_address())
+        .unwrap();
+
+    // a step request with too many timesteps does not advance the
+    // tank at all
+    let timestep = Time::new::<second>(0.5);
+    let error_message: String = client.try_step(timestep, 101).unwrap_err().into();
+    assert!(error_message.contains("at most 100 steps"));
+    assert_eq!(client.try_get_value("temperature").unwrap(), 300.0);
+    client.try_step(timestep, 100).unwrap();
+
+    // steps beyond the range of usize cannot run the plant either
+    let huge_step_request = format!(
+        "{{\"command\":\"step\",\"timestep\":0.5,\"steps\":{}}}", u64::MAX);
+    assert!(handle_request_line(&huge_step_request, &tank, &variables)
+        .contains("\"ok\":false"));
+
+    // a long line, sent in parts, gets one error reply and the next
+    // request is answered
+    let mut stream = TcpStream::connect(server.get_local_address()).unwrap();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let long_name = "x".repeat(200);
+    let long_request = format!(
+        "{{\"command\":\"get\",\"names\":[\"{}\",\"{}\"]}}\n",
+        long_name, long_name);
+    let (first_part, second_part) = long_request.split_at(300);
+    stream.write_all(first_part.as_bytes()).unwrap();
+    stream.flush().unwrap();
+    thread::sleep(Duration::from_millis(50));
+    stream.write_all(second_part.as_bytes()).unwrap();
+    stream.write_all(b"{\"command\":\"get\",\"names\":[\"heater_power\"]}\n").unwrap();
+    stream.flush().unwrap();
+
+    let mut reply = String::new();
+    reader.read_line(&mut reply).unwrap();
+    assert!(reply.contains("longer than 256 bytes"));
+    reply.clear();
+    reader.read_line(&mut reply).unwrap();
+    assert_eq!(reply.trim(), "{\"ok\":true,\"values\":{\"heater_power\":0.0}}");
+}
+
+/// the server refuses addresses other than loopback, as anyone who
+/// can connect can change the plant inputs
+#[test]
+pub fn server_only_binds_to_loopback(){
+
+    let tank = Arc::new(Mutex::new(HeatedTank::default()));
+    let variables = PlantServerVariables::new(&["heater_power"], &["temperature"]);
+
+    let result = PlantServer::try_start(tank.clone(), "0.0.0.0:0", variables.clone());
+    assert!(result.is_err());
+
+    let server = PlantServer::try_start(tank, "[::1]:0", variables);
+    // some sandboxes have no ipv6 loopback, but if binding succeeds
+    // the address must be loopback
+    if let Ok(server) = server {
+        assert!(server.get_local_address().ip().is_loopback());
+    }
+}
+
+/// several clients watch a tank advanced by its own simulation
+/// thread, one of them changes the heater power, and shutting the
+/// server down closes their connections
+#[test]
+pub fn clients_follow_a_running_simulation(){
+
+    let (tank, mut server) = start_tank_server(false);
+
+    let stop_simulation = Arc::new(AtomicBool::new(false));
+    let simulation_thread = {
+        let tank = tank.clone();
+        let stop_simulation = stop_simulation.clone();
+        thread::spawn(move || {
+            while !stop_simulation.load(Ordering::Relaxed) {
+                tank.lock().unwrap()
+                    .try_advance_timestep(Time::new::<second>(0.5)).unwrap();
+                thread::sleep(Duration::from_millis(1));
+            }
+        })
+    };
+
+    let mut operator = PlantServerClient::try_connect(server.get_local_address())
+        .unwrap();
+    let mut historian = PlantServerClient::try_connect(server.get_local_address())
+        .unwrap();
+
+    operator.try_set(&[("heater_power", 1000.0)]).unwrap();
+
+    // steady state is 300 K + 1000 W / 10 W/K = 400 K
+    let start = Instant::now();
+    let mut temperatures = vec![];
+    loop {
+        let temperature = historian.try_get_value("temperature").unwrap();
+        temperatures.push(temperature);
+        if temperature > 350.0 {
+            break;
+        }
+        assert!(start.elapsed() < Duration::from_secs(30),
+            "tank did not heat up, last temperatures {:?}", temperatures);
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert!(temperatures.windows(2).all(|pair| pair[1] >= pair[0]));
+
+    stop_simulation.store(true, Ordering::Relaxed);
+    simulation_thread.join().unwrap();
+
+    server.shutdown();
+    assert!(historian.try_get_value("temperature").is_err());
+}
+
+/// the variables of a model exported through FMI are its inputs and
+/// outputs, parameters are not exposed
+#[test]
+pub fn ciet_plant_variables_from_fmi_model(){
+
+    let variables = PlantServerVariables::from_fmi_model::<CIETPlant>();
+
+    assert!(variables.is_writable("heater_power"));
+    assert!(variables.is_writable("ctah_branch_blocked"));
+    assert!(!variables.is_writable("bt_12_heater_outlet"));
+    assert!(variables.is_readable("bt_12_heater_outlet"));
+    assert!(!variables.is_readable("initial_temperature"));
+    assert!(!variables.allow_client_stepping);
+}
@@ -0,0 +1,261 @@
+//! axial power shapes for heated components
+//!
+//! by default, heater power is spread uniformly over the nodes of
+//! a heated component, ie. each node gets a q_fraction of 1/N.
+//! An AxialPowerShape describes a relative power density q(z)
+//! along the heated length, which is remapped to the nodalisation
+//! of a component by integrating the shape over the axial extent
+//! of each node:
+//!
+//! q_fraction_i = integral of q(z) over node i / integral of q(z)
+//! over the heated length
+//!
+//! so the total power is conserved regardless of the number of
+//! nodes. The axial coordinate z goes from the back (first node)
+//! to the front (last node) of the component, and shapes are
+//! described in terms of the normalised coordinate z/L from 0 to 1
+use std::f64::consts::PI;
+
+use ndarray::*;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// relative axial power density along the heated length of a
+/// component, only the shape matters, not the magnitude
+#[derive(Debug,Clone,PartialEq,Default)]
+pub enum AxialPowerShape {
+    /// uniform power density, this is the default
+    #[default]
+    Uniform,
+
+    /// chopped cosine shape, typical of reactor cores,
+    ///
+    /// q(z) = cos(pi (z/L - 1/2) L/L_e)
+    ///
+    /// where L_e/L >= 1 is the extrapolated length divided by the
+    /// heated length. The peak is at the centre, and the ends have
+    /// a relative power of cos(pi L/(2 L_e))
+    ChoppedCosine {
+        /// extrapolated length divided by the heated length
+        extrapolated_length_ratio: f64,
+    },
+
+    /// exponential shape,
+    ///
+    /// q(z) = exp(-a z/L)
+    ///
+    /// a positive decay coefficient a puts the peak at the back
+    /// of the component, a negative one puts it at the front
+    Exponential {
+        /// decay coefficient a
+        decay_coefficient: f64,
+    },
+
+    /// piecewise linear shape, given as (z/L, relative power)
+    /// points in increasing z/L, starting at 0 and ending at 1
+    PiecewiseLinear(Vec<(f64, f64)>),
+
+    /// tabulated shape, given as relative powers over equal
+    /// length segments of the heated length, eg. from a
+    /// measurement or a neutronics code with a different
+    /// nodalisation
+    Tabulated(Vec<f64>),
+}
+
+impl AxialPowerShape {
+
+    /// checks that the shape is valid, ie. non negative everywhere
+    /// with a positive total
+    pub fn try_validate(&self) -> Result<(), TuasLibError> {
+
+        match self {
+            AxialPowerShape::Uniform => (),
+            AxialPowerShape::ChoppedCosine { extrapolated_length_ratio } => {
+                if !(*extrapolated_length_ratio >= 1.0) {
+                    return Err(TuasLibError::GenericStringError(
+                        format!("extrapolated length ratio must be at least 1, got {}",
+                            extrapolated_length_ratio)));
+                }
+            },
+            AxialPowerShape::Exponential { decay_coefficient } => {
+                if !decay_coefficient.is_finite() {
+                    return Err(TuasLibError::GenericStringError(
+                        "exponential decay coefficient must be finite".to_owned()));
+                }
+            },
+            AxialPowerShape::PiecewiseLinear(points) => {
+                if points.len() < 2 {
+                    return Err(TuasLibError::GenericStringError(
+                        "piecewise linear power shape needs at least two points".to_owned()));
+                }
+                if points[0].0 != 0.0 || points[points.len()-1].0 != 1.0 {
+                    return Err(TuasLibError::GenericStringError(
+                        "piecewise linear power shape must start at z/L = 0 and end at z/L = 1"
+                        .to_owned()));
+                }
+                if points.windows(2).any(|pair| !(pair[1].0 > pair[0].0)) {
+                    return Err(TuasLibError::GenericStringError(
+                        "piecewise linear power shape points must be in increasing z/L"
+                        .to_owned()));
+                }
+                if points.iter().any(|(_, power)| !(*power >= 0.0)) {
+                    return Err(TuasLibError::GenericStringError(
+                        "relative power must not be negative".to_owned()));
+                }
+            },
+            AxialPowerShape::Tabulated(relative_powers) => {
+                if relative_powers.is_empty() {
+                    return Err(TuasLibError::GenericStringError(
+                        "tabulated power shape is empty".to_owned()));
+                }
+                if relative_powers.iter().any(|power| !(*power >= 0.0)) {
+                    return Err(TuasLibError::GenericStringError(
+                        "relative power must not be negative".to_owned()));
+                }
+            },
+        }
+
+        if !(self.get_cumulative_power(1.0) > 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                "power shape must have a positive total".to_owned()));
+        }
+
+        Ok(())
+    }
+
+    /// relative power density at a normalised axial position z/L
+    pub fn get_relative_power_density(&self, normalised_position: f64) -> f64 {
+
+        let z = normalised_position.clamp(0.0, 1.0);
+
+        match self {
+            AxialPowerShape::Uniform => 1.0,
+            AxialPowerShape::ChoppedCosine { extrapolated_length_ratio } => {
+                (PI * (z - 0.5) / extrapolated_length_ratio).cos()
+            },
+            AxialPowerShape::Exponential { decay_coefficient } => {
+                (-decay_coefficient * z).exp()
+            },
+            AxialPowerShape::PiecewiseLinear(points) => {
+                let segment = points.windows(2)
+                    .find(|pair| z <= pair[1].0)
+                    .unwrap_or(&points[points.len()-2..]);
+                let (z_start, power_start) = segment[0];
+                let (z_end, power_end) = segment[1];
+
+                power_start + (power_end - power_start)
+                    * (z - z_start)/(z_end - z_start)
+            },
+            AxialPowerShape::Tabulated(relative_powers) => {
+                let number_of_segments = relative_powers.len();
+                let segment = ((z * number_of_segments as f64) as usize)
+                    .min(number_of_segments - 1);
+
+                relative_powers[segment]
+            },
+        }
+    }
+
+    /// integral of the relative power density from the back of
+    /// the component (z/L = 0) to a normalised axial position
+    fn get_cumulative_power(&self, normalised_position: f64) -> f64 {
+
+        let z = normalised_position.clamp(0.0, 1.0);
+
+        match self {
+            AxialPowerShape::Uniform => z,
+            AxialPowerShape::ChoppedCosine { extrapolated_length_ratio } => {
+                let r = *extrapolated_length_ratio;
+                r/PI * ((PI * (z - 0.5)/r).sin() - (-PI * 0.5/r).sin())
+            },
+            AxialPowerShape::Exponential { decay_coefficient } => {
+                let a = *decay_coefficient;
+                if a == 0.0 {
+                    z
+                } else {
+                    (1.0 - (-a * z).exp())/a
+                }
+            },
+            AxialPowerShape::PiecewiseLinear(points) => {
+                let mut cumulative_power = 0.0;
+
+                for pair in points.windows(2) {
+                    let (z_start, power_start) = pair[0];
+                    let (z_end, power_end) = pair[1];
+
+                    if z <= z_start {
+                        break;
+                    }
+
+                    let z_upper = z.min(z_end);
+                    let power_upper = power_start + (power_end - power_start)
+                        * (z_upper - z_start)/(z_end - z_start);
+
+                    // trapezoidal rule is exact for linear segments
+                    cumulative_power += 0.5 * (power_start + power_upper)
+                        * (z_upper - z_start);
+                }
+
+                cumulative_power
+            },
+            AxialPowerShape::Tabulated(relative_powers) => {
+                let segment_length = 1.0/relative_powers.len() as f64;
+
+                relative_powers.iter().enumerate().map(|(segment, power)| {
+                    let z_start = segment as f64 * segment_length;
+                    let overlap = (z - z_start).clamp(0.0, segment_length);
+                    power * overlap
+                }).sum()
+            },
+        }
+    }
+
+    /// fraction of the total power deposited in each of a given
+    /// number of equal length nodes, for use as a q_fraction array
+    ///
+    /// the fractions sum to one
+    pub fn try_get_q_fraction_array(&self,
+        number_of_nodes: usize) -> Result<Array1<f64>, TuasLibError> {
+
+        if number_of_nodes == 0 {
+            return Err(TuasLibError::GenericStringError(
+                "power shape needs at least one node".to_owned()));
+        }
+
+        // the default uniform shape gives exactly the same
+        // fractions as before power shapes were introduced
+        if *self == AxialPowerShape::Uniform {
+            let mut q_frac_arr: Array1<f64> = Array::default(number_of_nodes);
+            q_frac_arr.fill(1.0/ number_of_nodes as f64);
+            return Ok(q_frac_arr);
+        }
+
+        self.try_validate()?;
+
+        let total_power = self.get_cumulative_power(1.0);
+        let node_length = 1.0/number_of_nodes as f64;
+
+        Ok(Array1::from_shape_fn(number_of_nodes, |node| {
+            let node_start = node as f64 * node_length;
+            let node_end = (node + 1) as f64 * node_length;
+
+            (self.get_cumulative_power(node_end)
+                - self.get_cumulative_power(node_start)) / total_power
+        }))
+    }
+
+    /// ratio of the peak node power to the average node power
+    /// for a given number of nodes
+    pub fn try_get_nodal_peaking_factor(&self,
+        number_of_nodes: usize) -> Result<f64, TuasLibError> {
+
+        let q_frac_arr = self.try_get_q_fraction_array(number_of_nodes)?;
+        let peak_fraction = q_frac_arr.iter().cloned().fold(0.0, f64::max);
+
+        Ok(peak_fraction * number_of_nodes as f64)
+    }
+}
+
+/// tests for axial power shapes
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,141 @@
+use super::AxialPowerShape;
+
+/// the default uniform shape must give exactly 1/N for every node
+/// so that existing results are unchanged
+#[test]
+pub fn uniform_shape_gives_equal_fractions(){
+
+    let q_frac_arr = AxialPowerShape::default()
+        .try_get_q_fraction_array(10).unwrap();
+
+    for q_frac in q_frac_arr.iter() {
+        assert_eq!(*q_frac, 1.0/10.0);
+    }
+}
+
+/// a chopped cosine is symmetric about the centre, its fractions
+/// sum to one and for L_e = L, the peaking factor tends to pi/2
+/// as the number of nodes increases
+#[test]
+pub fn chopped_cosine_shape_is_symmetric_and_conserves_power(){
+
+    use approx::assert_relative_eq;
+    use std::f64::consts::PI;
+
+    let shape = AxialPowerShape::ChoppedCosine { 
+        extrapolated_length_ratio: 1.0 };
+
+    let q_frac_arr = shape.try_get_q_fraction_array(8).unwrap();
+
+    assert_relative_eq!(q_frac_arr.sum(), 1.0, max_relative = 1e-12);
+
+    for node in 0..4 {
+        assert_relative_eq!(q_frac_arr[node], q_frac_arr[7-node], 
+            max_relative = 1e-12);
+    }
+    assert!(q_frac_arr[3] > q_frac_arr[0]);
+
+    let peaking_factor = shape.try_get_nodal_peaking_factor(100).unwrap();
+
+    assert_relative_eq!(peaking_factor, PI/2.0, max_relative = 1e-3);
+
+    // a longer extrapolated length flattens the shape
+    let flatter_shape = AxialPowerShape::ChoppedCosine { 
+        extrapolated_length_ratio: 1.5 };
+
+    assert!(flatter_shape.try_get_nodal_peaking_factor(100).unwrap()
+        < peaking_factor);
+}
+
+/// for q(z) = exp(-a z/L), the fraction of power in the first half
+/// of the component is 1/(1 + exp(-a/2))
+#[test]
+pub fn exponential_shape_first_half_fraction(){
+
+    use approx::assert_relative_eq;
+
+    let decay_coefficient: f64 = 2.0;
+    let shape = AxialPowerShape::Exponential { decay_coefficient };
+
+    let q_frac_arr = shape.try_get_q_fraction_array(2).unwrap();
+
+    assert_relative_eq!(q_frac_arr[0], 
+        1.0/(1.0 + (-decay_coefficient/2.0).exp()), 
+        max_relative = 1e-12);
+    assert_relative_eq!(q_frac_arr.sum(), 1.0, max_relative = 1e-12);
+
+    // a negative coefficient puts the peak at the front
+    let reversed_shape = AxialPowerShape::Exponential { 
+        decay_coefficient: -decay_coefficient };
+    let reversed_q_frac_arr = reversed_shape.try_get_q_fraction_array(2).unwrap();
+
+    assert_relative_eq!(reversed_q_frac_arr[1], q_frac_arr[0], 
+        max_relative = 1e-12);
+}
+
+/// a linear ramp from 0 to 1 over two nodes puts a quarter of 
+/// the power in the first node and three quarters in the second
+#[test]
+pub fn piecewise_linear_ramp_shape(){
+
+    use approx::assert_relative_eq;
+
+    let shape = AxialPowerShape::PiecewiseLinear(
+        vec![(0.0, 0.0), (1.0, 1.0)]);
+
+    let q_frac_arr = shape.try_get_q_fraction_array(2).unwrap();
+
+    assert_relative_eq!(q_frac_arr[0], 0.25, max_relative = 1e-12);
+    assert_relative_eq!(q_frac_arr[1], 0.75, max_relative = 1e-12);
+
+    // the density is interpolated between points 
+    let hat_shape = AxialPowerShape::PiecewiseLinear(
+        vec![(0.0, 0.0), (0.5, 2.0), (1.0, 0.0)]);
+
+    assert_relative_eq!(hat_shape.get_relative_power_density(0.25), 1.0,
+        max_relative = 1e-12);
+}
+
+/// tabulated shapes are remapped onto a finer nodalisation by 
+/// integrating over each node
+#[test]
+pub fn tabulated_shape_remapped_to_finer_nodes(){
+
+    use approx::assert_relative_eq;
+
+    let shape = AxialPowerShape::Tabulated(vec![1.0, 3.0]);
+
+    let q_frac_arr = shape.try_get_q_fraction_array(4).unwrap();
+    let expected_q_frac = [1.0/8.0, 1.0/8.0, 3.0/8.0, 3.0/8.0];
+
+    for (q_frac, expected) in q_frac_arr.iter().zip(expected_q_frac.iter()) {
+        assert_relative_eq!(*q_frac, *expected, max_relative = 1e-12);
+    }
+
+    // and onto a coarser one, with one node spanning both segments
+    let coarse_q_frac_arr = shape.try_get_q_fraction_array(1).unwrap();
+    assert_relative_eq!(coarse_q_frac_arr[0], 1.0, max_relative = 1e-12);
+}
+
+/// invalid shapes should return errors rather than fractions
+#[test]
+pub fn invalid_shapes_return_errors(){
+
+    let invalid_shapes = vec![
+        AxialPowerShape::ChoppedCosine { extrapolated_length_ratio: 0.5 },
+        AxialPowerShape::Exponential { decay_coefficient: f64::NAN },
+        AxialPowerShape::PiecewiseLinear(vec![(0.0, 1.0)]),
+        AxialPowerShape::PiecewiseLinear(vec![(0.0, 1.0), (0.8, 1.0)]),
+        AxialPowerShape::PiecewiseLinear(vec![(0.0, 1.0), (0.6, 1.0), 
+            (0.4, 1.0), (1.0, 1.0)]),
+        AxialPowerShape::PiecewiseLinear(vec![(0.0, -1.0), (1.0, 1.0)]),
+        AxialPowerShape::Tabulated(vec![]),
+        AxialPowerShape::Tabulated(vec![0.0, 0.0]),
+    ];
+
+    for shape in invalid_shapes.iter() {
+        assert!(shape.try_get_q_fraction_array(4).is_err());
+    }
+
+    assert!(AxialPowerShape::Uniform.try_get_q_fraction_array(0).is_err());
+}
@@ -116,6 +116,12 @@ pub mod dataset_c;
 /// based on Zou's specifications
 pub mod dhx_constructor;
 
+/// version 7 of the calibrated coupled dracs loop, which is run 
+/// for every point of datasets A, B and C and compared against 
+/// the CIET experimental data in the validation module
+#[cfg(test)]
+pub mod coupled_dracs_loop_version_7;
+
 /// debugging tests for functions to make natural circulation 
 /// testing easier 
 pub mod debugging;
@@ -3,6 +3,7 @@ use std::f64::consts::PI;
 
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
 use crate::boussinesq_thermophysical_properties::SolidMaterial;
+use crate::heat_transfer_correlations::external_natural_convection::AmbientNaturalConvection;
 
 use super::heat_transfer_entities::HeatTransferEntity;
 use uom::si::f64::*;
@@ -40,6 +41,12 @@ pub struct StructuralSupport {
     /// support, meant for calculating parasitic heat loss
     pub heat_transfer_to_air: HeatTransfer,
 
+    /// natural convection (and radiation) model to ambient,
+    /// if set, the heat transfer coefficient to air is
+    /// calculated from the support temperature every timestep
+    /// and heat_transfer_to_air is not used
+    pub ambient_natural_convection: Option<AmbientNaturalConvection>,
+
     /// representative surface area in contact with surroundings
     /// around the structural 
     /// support, meant for calculating parasitic heat loss
@@ -95,6 +102,7 @@ impl StructuralSupport {
             support_array: steel_shell_array.into(),
             ambient_temperature,
             heat_transfer_to_air: h_to_air,
+            ambient_natural_convection: None,
             total_lateral_surface_area,
         };
     }
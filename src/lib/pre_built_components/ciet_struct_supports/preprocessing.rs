@@ -11,6 +11,7 @@ use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer
 use crate::boussinesq_thermophysical_properties::SolidMaterial;
 use crate::boundary_conditions::BCType;
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
+use crate::tuas_lib_error::TuasLibError;
 
 impl StructuralSupport {
 
@@ -23,15 +24,16 @@ impl StructuralSupport {
     ///
     /// unoptimised in this regard
     #[inline]
-    pub fn lateral_and_miscellaneous_connections(&mut self){
+    pub fn lateral_and_miscellaneous_connections(&mut self) 
+        -> Result<(), TuasLibError>{
 
-        let h_air_to_steel_surf = self.heat_transfer_to_air;
+        let h_air_to_steel_surf = self.try_get_heat_transfer_to_air()?;
         let heater_steady_state_power: Power = Power::ZERO;
 
         // clone each array and set them later
 
         let mut steel_shell_clone: SolidColumn = 
-        self.support_array.clone().try_into() .unwrap();
+        self.support_array.clone().try_into()?;
 
 
         // first let's get all the conductances 
@@ -77,7 +79,7 @@ impl StructuralSupport {
         steel_shell_clone.lateral_link_new_temperature_vector_avg_conductance(
             support_to_air_conductance,
             ambient_temperature_vector.clone()
-        ).unwrap();
+        )?;
 
 
         // we also want to add a heat source to steel shell
@@ -87,7 +89,7 @@ impl StructuralSupport {
         steel_shell_clone.lateral_link_new_power_vector(
             heater_steady_state_power,
             q_frac_arr
-        ).unwrap();
+        )?;
 
         // note, must set mass flowrate first 
         // otherwise there is by default zero flow through 
@@ -98,11 +100,11 @@ impl StructuralSupport {
         // modify the heat transfer entity 
 
 
-        self.support_array.set(steel_shell_clone.into()).unwrap();
+        self.support_array.set(steel_shell_clone.into())?;
 
         // adiabatic bc connections to make things finished 
 
-        self.zero_power_bc_connection();
+        self.zero_power_bc_connection()
     }
 
 
@@ -115,7 +117,7 @@ impl StructuralSupport {
     /// use the link to front or back methods within the 
     /// FluidArray or SolidColumn
     #[inline]
-    fn zero_power_bc_connection(&mut self){
+    fn zero_power_bc_connection(&mut self) -> Result<(), TuasLibError>{
 
         let zero_power: Power = Power::ZERO;
 
@@ -130,15 +132,42 @@ impl StructuralSupport {
         // now connect the twisted tape 
 
         self.support_array.link_to_front(&mut zero_power_bc,
-            interaction).unwrap();
+            interaction)?;
 
         self.support_array.link_to_back(&mut zero_power_bc,
-            interaction).unwrap();
+            interaction)
     }
 
 
 
 
+    /// obtains the heat transfer coefficient from the support 
+    /// to ambient air
+    ///
+    /// if no ambient natural convection model is set, this is just 
+    /// the constant heat_transfer_to_air. Otherwise, it is 
+    /// calculated using the support bulk temperature as the 
+    /// surface temperature
+    #[inline]
+    pub fn try_get_heat_transfer_to_air(&self) 
+        -> Result<HeatTransfer, TuasLibError> {
+
+        let ambient_natural_convection = match self.ambient_natural_convection {
+            Some(ambient_natural_convection) => ambient_natural_convection,
+            None => return Ok(self.heat_transfer_to_air),
+        };
+
+        let mut steel_shell_clone: SolidColumn = 
+        self.support_array.clone().try_into()?;
+
+        let steel_surf_temperature: ThermodynamicTemperature 
+        = steel_shell_clone.try_get_bulk_temperature()?;
+
+        ambient_natural_convection.try_get_heat_transfer_coefficient(
+            steel_surf_temperature, 
+            self.ambient_temperature)
+    }
+
     /// obtains air to steel shell conductance
     #[inline]
     pub fn get_air_to_steel_array_conductance(&mut self,
@@ -212,7 +241,7 @@ impl StructuralSupport {
             move || -> Self {
 
                 // carry out the connection calculations
-                component_clone.lateral_and_miscellaneous_connections();
+                component_clone.lateral_and_miscellaneous_connections().unwrap();
                 
                 component_clone
 
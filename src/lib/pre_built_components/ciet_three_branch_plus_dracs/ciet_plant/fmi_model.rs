@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use uom::si::f64::*;
+use uom::si::power::watt;
+use uom::si::pressure::pascal;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::time::second;
+
+use crate::fmi_export::{Fmi2Model, FmiVariable};
+use crate::tuas_lib_error::TuasLibError;
+
+use super::{CIETPlant, CIETPlantCalibration, CIETPlantInputs};
+
+/// the CIET plant is exported with its scenario plant inputs and
+/// outputs, and its initial temperature as a parameter. The
+/// calibration is the default one
+///
+/// communication steps are split into timesteps of at most 0.2 s,
+/// the timestep used in the educational simulator
+impl Fmi2Model for CIETPlant {
+
+    fn get_model_name() -> String {
+        "CIETPlant".to_owned()
+    }
+
+    fn get_model_description() -> String {
+        "three branch CIET loop coupled to the DRACS loop".to_owned()
+    }
+
+    fn get_model_variables() -> Vec<FmiVariable> {
+
+        let default_inputs = CIETPlantInputs::default();
+        let bool_to_f64 = |is_blocked: bool| if is_blocked { 1.0 } else { 0.0 };
+
+        let mut variables = vec![
+            FmiVariable::new_parameter("initial_temperature", 294.15,
+                "uniform initial temperature of the loops (K)"),
+        ];
+
+        for (input_name, start, description) in [
+            ("heater_power", default_inputs.heater_power.get::<watt>(),
+                "heater power (W)"),
+            ("ctah_pump_pressure", default_inputs.ctah_pump_pressure.get::<pascal>(),
+                "CTAH branch pump pressure (Pa)"),
+            ("ctah_branch_blocked", bool_to_f64(default_inputs.ctah_branch_blocked),
+                "CTAH branch valve, 1 closed and 0 open"),
+            ("dhx_branch_blocked", bool_to_f64(default_inputs.dhx_branch_blocked),
+                "DHX branch valve, 1 closed and 0 open"),
+            ("tchx_outlet_temperature_set_point",
+                default_inputs.tchx_outlet_temperature_set_point.get::<kelvin>(),
+                "TCHX outlet temperature set point (K)"),
+            ("ctah_outlet_temperature_set_point",
+                default_inputs.ctah_outlet_temperature_set_point.get::<kelvin>(),
+                "CTAH outlet temperature set point (K)"),
+        ] {
+            variables.push(FmiVariable::new_input(input_name, start, description));
+        }
+
+        for (output_name, description) in [
+            ("simulation_time", "simulation time (s)"),
+            ("bt_11_heater_inlet", "heater inlet temperature (K)"),
+            ("bt_12_heater_outlet", "heater outlet temperature (K)"),
+            ("bt_41_ctah_outlet", "CTAH outlet temperature (K)"),
+            ("bt_43_ctah_inlet", "CTAH inlet temperature (K)"),
+            ("bt_65_tchx_inlet", "TCHX inlet temperature (K)"),
+            ("bt_66_tchx_outlet", "TCHX outlet temperature (K)"),
+            ("heater_branch_mass_flowrate", "heater branch mass flowrate (kg/s)"),
+            ("dhx_branch_mass_flowrate", "DHX branch mass flowrate (kg/s)"),
+            ("ctah_branch_mass_flowrate", "CTAH branch mass flowrate (kg/s)"),
+            ("dracs_loop_mass_flowrate", "DRACS loop mass flowrate (kg/s)"),
+            ("tchx_heat_transfer_coeff", "TCHX heat transfer coefficient (W/(m^2 K))"),
+            ("ctah_heat_transfer_coeff", "CTAH heat transfer coefficient (W/(m^2 K))"),
+        ] {
+            variables.push(FmiVariable::new_output(output_name, description));
+        }
+
+        variables
+    }
+
+    fn try_new_from_parameters(parameters: &HashMap<String, f64>)
+        -> Result<Self, TuasLibError> {
+
+        let initial_temperature = parameters.get("initial_temperature")
+            .ok_or(TuasLibError::GenericStringError(
+                "initial_temperature parameter is missing".to_owned()))?;
+
+        CIETPlant::try_new(
+            ThermodynamicTemperature::new::<kelvin>(*initial_temperature),
+            CIETPlantInputs::default(),
+            CIETPlantCalibration::default())
+    }
+
+    fn get_maximum_internal_timestep() -> Option<Time> {
+        Some(Time::new::<second>(0.2))
+    }
+}
@@ -0,0 +1,871 @@
+use std::thread;
+
+use uom::si::f64::*;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::length::centimeter;
+use uom::si::pressure::atmosphere;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+use uom::si::time::second;
+use uom::ConstZero;
+
+use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::controls::{FeedbackController, PIDController};
+use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+use crate::pre_built_components::ciet_isothermal_test_components::*;
+use crate::pre_built_components::ciet_steady_state_natural_circulation_test_components::coupled_dracs_loop_tests::dhx_constructor::new_dhx_sthe_version_1;
+use crate::pre_built_components::ciet_steady_state_natural_circulation_test_components::dracs_loop_components::*;
+use crate::pre_built_components::insulated_pipes_and_fluid_components::InsulatedFluidComponent;
+use crate::pre_built_components::non_insulated_fluid_components::NonInsulatedFluidComponent;
+use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+use crate::prelude::beta_testing::{FluidArray, HeatTransferEntity};
+use crate::single_control_vol::SingleCVNode;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::components::{new_active_ctah_horizontal, new_active_ctah_vertical};
+use super::solver_functions::*;
+
+/// user settable inputs to the CIET plant, these may be changed
+/// in between timesteps
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CIETPlantInputs {
+    /// electrical power supplied to the heater
+    pub heater_power: Power,
+    /// pressure supplied by the pump in the CTAH branch
+    pub ctah_pump_pressure: Pressure,
+    /// closes the valve in the CTAH branch
+    pub ctah_branch_blocked: bool,
+    /// closes the valve in the DHX branch
+    pub dhx_branch_blocked: bool,
+    /// set point for the TCHX outlet temperature (BT-66)
+    pub tchx_outlet_temperature_set_point: ThermodynamicTemperature,
+    /// set point for the CTAH outlet temperature (BT-41)
+    pub ctah_outlet_temperature_set_point: ThermodynamicTemperature,
+}
+
+impl Default for CIETPlantInputs {
+    fn default() -> Self {
+        Self {
+            heater_power: Power::ZERO,
+            ctah_pump_pressure: Pressure::ZERO,
+            ctah_branch_blocked: false,
+            dhx_branch_blocked: false,
+            tchx_outlet_temperature_set_point:
+                ThermodynamicTemperature::new::<degree_celsius>(46.0),
+            ctah_outlet_temperature_set_point:
+                ThermodynamicTemperature::new::<degree_celsius>(80.0),
+        }
+    }
+}
+
+/// readings from the CIET plant after each timestep
+///
+/// temperature labels follow the CIET thermocouple labels, the
+/// bulk temperatures of the components nearest the thermocouples
+/// are used as proxies
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CIETPlantOutputs {
+    /// simulation time elapsed
+    pub simulation_time: Time,
+    /// heater inlet temperature (heater bottom head 1b)
+    pub bt_11_heater_inlet: ThermodynamicTemperature,
+    /// heater outlet temperature (static mixer 10 label 2)
+    pub bt_12_heater_outlet: ThermodynamicTemperature,
+    /// CTAH outlet temperature (pipe 8a)
+    pub bt_41_ctah_outlet: ThermodynamicTemperature,
+    /// CTAH inlet temperature (pipe 6a)
+    pub bt_43_ctah_inlet: ThermodynamicTemperature,
+    /// TCHX inlet temperature (pipe 34)
+    pub bt_65_tchx_inlet: ThermodynamicTemperature,
+    /// TCHX outlet temperature (TCHX 35b_2 outlet)
+    pub bt_66_tchx_outlet: ThermodynamicTemperature,
+    /// mass flowrate through the heater branch, upwards is positive
+    pub heater_branch_mass_flowrate: MassRate,
+    /// mass flowrate through the DHX branch, downwards is positive
+    pub dhx_branch_mass_flowrate: MassRate,
+    /// mass flowrate through the CTAH branch
+    pub ctah_branch_mass_flowrate: MassRate,
+    /// absolute mass flowrate through the DRACS loop
+    pub dracs_loop_mass_flowrate: MassRate,
+    /// heat transfer coefficient to ambient set by the TCHX
+    /// outlet temperature controller
+    pub tchx_heat_transfer_coeff: HeatTransfer,
+    /// heat transfer coefficient to ambient set by the CTAH
+    /// outlet temperature controller
+    pub ctah_heat_transfer_coeff: HeatTransfer,
+}
+
+/// calibration settings for the CIET plant
+///
+/// the defaults are those used in the version 3 educational
+/// simulator prototype
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CIETPlantCalibration {
+    /// Gnielinski correction factor for the DHX shell side to tubes
+    pub dhx_shell_side_to_tubes_nusselt_correction_factor: Ratio,
+    /// Gnielinski correction factor for the DHX shell side to
+    /// the shell (parasitic heat loss)
+    pub dhx_shell_side_to_ambient_nusselt_correction_factor: Ratio,
+    /// DHX insulation thickness
+    pub dhx_insulation_thickness: Length,
+    /// DHX heat transfer coefficient to ambient
+    pub dhx_heat_transfer_to_ambient: HeatTransfer,
+    /// primary loop cold leg insulation thickness
+    pub pri_loop_cold_leg_insulation_thickness: Length,
+    /// primary loop hot leg insulation thickness
+    pub pri_loop_hot_leg_insulation_thickness: Length,
+    /// DRACS loop cold leg insulation thickness
+    pub dracs_loop_cold_leg_insulation_thickness: Length,
+    /// DRACS loop hot leg insulation thickness
+    pub dracs_loop_hot_leg_insulation_thickness: Length,
+    /// Gnielinski correction factor for the heater, this prevents
+    /// the steel from overheating at high power
+    pub heater_nusselt_correction_factor: Ratio,
+    /// heat transfer coefficient to ambient for all other components
+    pub ambient_heat_transfer_coeff: HeatTransfer,
+}
+
+impl Default for CIETPlantCalibration {
+    fn default() -> Self {
+        Self {
+            dhx_shell_side_to_tubes_nusselt_correction_factor:
+                Ratio::new::<ratio>(4.7),
+            dhx_shell_side_to_ambient_nusselt_correction_factor:
+                Ratio::new::<ratio>(10.3),
+            dhx_insulation_thickness: Length::new::<centimeter>(0.161),
+            dhx_heat_transfer_to_ambient:
+                HeatTransfer::new::<watt_per_square_meter_kelvin>(33.9),
+            pri_loop_cold_leg_insulation_thickness:
+                Length::new::<centimeter>(0.15),
+            pri_loop_hot_leg_insulation_thickness:
+                Length::new::<centimeter>(0.24),
+            dracs_loop_cold_leg_insulation_thickness:
+                Length::new::<centimeter>(3.00),
+            dracs_loop_hot_leg_insulation_thickness:
+                Length::new::<centimeter>(0.75),
+            heater_nusselt_correction_factor: Ratio::new::<ratio>(10.0),
+            ambient_heat_transfer_coeff:
+                HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0),
+        }
+    }
+}
+
+/// reference heat transfer coefficient of the outlet temperature
+/// controllers, in W/(m^2 K)
+const OUTLET_TEMPERATURE_CONTROLLER_REFERENCE_HTC: f64 = 40.0;
+
+/// lowest controller output, 40 (1 - 0.875) = 5 W/(m^2 K)
+const OUTLET_TEMPERATURE_CONTROLLER_MINIMUM_OUTPUT: f64 = -0.875;
+
+/// proportional integral control of the TCHX and CTAH outlet
+/// temperatures, this adjusts the heat transfer coefficient to
+/// ambient
+///
+/// the controller output is the fractional change of the heat
+/// transfer coefficient from a 40 W/(m^2 K) reference value, and the
+/// error is nondimensionalised by an 80 K temperature interval. The
+/// gain is negative since more heat must be removed when the fluid
+/// is hotter than the set point. The output is limited so that the
+/// heat transfer coefficient does not fall below 5 W/(m^2 K)
+fn new_outlet_temperature_controller(nondimensional_gain: f64)
+    -> Result<PIDController, TuasLibError> {
+
+    let reference_temperature_interval_kelvin = 80.0;
+
+    // same tuning as the version 3 prototype, where the
+    // integral time in seconds equals the gain
+    let mut controller = PIDController::try_new_pi(
+        -nondimensional_gain/reference_temperature_interval_kelvin,
+        Time::new::<second>(nondimensional_gain))?;
+    controller.try_set_output_limits(
+        OUTLET_TEMPERATURE_CONTROLLER_MINIMUM_OUTPUT, f64::INFINITY)?;
+
+    Ok(controller)
+}
+
+/// heat transfer coefficient for a given outlet temperature
+/// controller output
+fn get_outlet_heat_transfer_coeff(controller_output: f64) -> HeatTransfer {
+    HeatTransfer::new::<watt_per_square_meter_kelvin>(
+        OUTLET_TEMPERATURE_CONTROLLER_REFERENCE_HTC * (1.0 + controller_output))
+}
+
+/// the three branch CIET loop with the DRACS loop, as used in the
+/// educational simulator, packaged so that it can be driven without
+/// the GUI
+///
+/// the plant owns all its components, the user sets
+/// [CIETPlantInputs] and calls [CIETPlant::step] to advance the
+/// simulation, which returns the [CIETPlantOutputs]
+///
+/// components are public so that users can calibrate or inspect
+/// them, they are named and labelled as in the CIET diagrams
+#[derive(Debug, Clone)]
+pub struct CIETPlant {
+    /// user settable inputs
+    pub inputs: CIETPlantInputs,
+    /// calibration settings, these are applied when the plant is
+    /// constructed
+    pub calibration: CIETPlantCalibration,
+
+    simulation_time: Time,
+    heater_branch_mass_flowrate: MassRate,
+    dhx_branch_mass_flowrate: MassRate,
+    ctah_branch_mass_flowrate: MassRate,
+    dracs_loop_mass_flowrate: MassRate,
+    tchx_heat_transfer_coeff: HeatTransfer,
+    ctah_heat_transfer_coeff: HeatTransfer,
+    tchx_controller: PIDController,
+    ctah_controller: PIDController,
+
+    // DRACS hot branch
+    /// pipe 34
+    pub pipe_34: InsulatedFluidComponent,
+    /// pipe 33
+    pub pipe_33: InsulatedFluidComponent,
+    /// pipe 32
+    pub pipe_32: InsulatedFluidComponent,
+    /// pipe 31a
+    pub pipe_31a: InsulatedFluidComponent,
+    /// static mixer 61 (label 31)
+    pub static_mixer_61_label_31: InsulatedFluidComponent,
+    /// DHX tube side outlet 30b
+    pub dhx_tube_side_30b: NonInsulatedFluidComponent,
+    /// DHX shell and tube heat exchanger
+    pub dhx_sthe: SimpleShellAndTubeHeatExchanger,
+    /// DHX tube side inlet 30a
+    pub dhx_tube_side_30a: NonInsulatedFluidComponent,
+
+    // DRACS cold branch
+    /// TCHX horizontal section 35a
+    pub tchx_35a: NonInsulatedFluidComponent,
+    /// TCHX vertical section 35b_1
+    pub tchx_35b_1: NonInsulatedFluidComponent,
+    /// TCHX vertical section 35b_2
+    pub tchx_35b_2: NonInsulatedFluidComponent,
+    /// static mixer 60 (label 36)
+    pub static_mixer_60_label_36: InsulatedFluidComponent,
+    /// pipe 36a
+    pub pipe_36a: InsulatedFluidComponent,
+    /// pipe 37
+    pub pipe_37: InsulatedFluidComponent,
+    /// flowmeter 60 (label 37a)
+    pub flowmeter_60_37a: NonInsulatedFluidComponent,
+    /// pipe 38
+    pub pipe_38: InsulatedFluidComponent,
+    /// pipe 39
+    pub pipe_39: InsulatedFluidComponent,
+
+    // primary loop DHX branch
+    /// pipe 5a
+    pub pipe_5a: InsulatedFluidComponent,
+    /// pipe 26
+    pub pipe_26: InsulatedFluidComponent,
+    /// pipe 25a
+    pub pipe_25a: InsulatedFluidComponent,
+    /// static mixer 21 (label 25)
+    pub static_mixer_21_label_25: InsulatedFluidComponent,
+    /// pipe 23a
+    pub pipe_23a: InsulatedFluidComponent,
+    /// static mixer 20 (label 23)
+    pub static_mixer_20_label_23: InsulatedFluidComponent,
+    /// pipe 22
+    pub pipe_22: InsulatedFluidComponent,
+    /// flowmeter 20 (label 21a)
+    pub flowmeter_20_21a: NonInsulatedFluidComponent,
+    /// pipe 21
+    pub pipe_21: InsulatedFluidComponent,
+    /// pipe 20
+    pub pipe_20: InsulatedFluidComponent,
+    /// pipe 19
+    pub pipe_19: InsulatedFluidComponent,
+    /// pipe 17b
+    pub pipe_17b: InsulatedFluidComponent,
+
+    // primary loop heater branch
+    /// pipe 4
+    pub pipe_4: InsulatedFluidComponent,
+    /// pipe 3
+    pub pipe_3: InsulatedFluidComponent,
+    /// pipe 2a
+    pub pipe_2a: InsulatedFluidComponent,
+    /// static mixer 10 (label 2)
+    pub static_mixer_10_label_2: InsulatedFluidComponent,
+    /// heater top head 1a
+    pub heater_top_head_1a: InsulatedFluidComponent,
+    /// heater version 1 (label 1)
+    pub heater_ver_1: InsulatedFluidComponent,
+    /// heater bottom head 1b
+    pub heater_bottom_head_1b: InsulatedFluidComponent,
+    /// pipe 18
+    pub pipe_18: InsulatedFluidComponent,
+
+    // primary loop CTAH branch
+    /// pipe 5b
+    pub pipe_5b: InsulatedFluidComponent,
+    /// static mixer 41 (label 6)
+    pub static_mixer_41_label_6: InsulatedFluidComponent,
+    /// pipe 6a
+    pub pipe_6a: InsulatedFluidComponent,
+    /// CTAH vertical section 7a
+    pub ctah_vertical_label_7a: NonInsulatedFluidComponent,
+    /// CTAH horizontal section 7b
+    pub ctah_horizontal_label_7b: NonInsulatedFluidComponent,
+    /// pipe 8a
+    pub pipe_8a: InsulatedFluidComponent,
+    /// static mixer 40 (label 8)
+    pub static_mixer_40_label_8: InsulatedFluidComponent,
+    /// pipe 9
+    pub pipe_9: InsulatedFluidComponent,
+    /// pipe 10
+    pub pipe_10: InsulatedFluidComponent,
+    /// pipe 11
+    pub pipe_11: InsulatedFluidComponent,
+    /// pipe 12
+    pub pipe_12: InsulatedFluidComponent,
+    /// CTAH pump
+    pub ctah_pump: NonInsulatedFluidComponent,
+    /// pipe 13
+    pub pipe_13: InsulatedFluidComponent,
+    /// pipe 14
+    pub pipe_14: InsulatedFluidComponent,
+    /// flowmeter 40 (label 14a)
+    pub flowmeter_40_14a: NonInsulatedFluidComponent,
+    /// pipe 15
+    pub pipe_15: InsulatedFluidComponent,
+    /// pipe 16
+    pub pipe_16: InsulatedFluidComponent,
+    /// pipe 17a
+    pub pipe_17a: InsulatedFluidComponent,
+
+    /// mixing node joining pipes 5a, 5b and 4
+    pub top_mixing_node_5a_5b_4: HeatTransferEntity,
+    /// mixing node joining pipes 17a, 17b and 18
+    pub bottom_mixing_node_17a_17b_18: HeatTransferEntity,
+}
+
+impl CIETPlant {
+
+    /// constructs the CIET plant with all components at a uniform
+    /// initial temperature, and applies the calibration settings
+    ///
+    /// the controller tuning follows the version 3 prototype
+    pub fn try_new(initial_temperature: ThermodynamicTemperature,
+        inputs: CIETPlantInputs,
+        calibration: CIETPlantCalibration) -> Result<Self, TuasLibError> {
+
+        // mixing node is a sphere about diameter of ping pong ball
+        // (1 in)
+        let mixing_node_diameter = Length::new::<centimeter>(3.84);
+        let mixing_node_material = LiquidMaterial::TherminolVP1;
+        let mixing_node_pressure = Pressure::new::<atmosphere>(1.0);
+        let mixing_node = SingleCVNode::new_sphere(
+            mixing_node_diameter,
+            mixing_node_material.into(),
+            initial_temperature,
+            mixing_node_pressure)?;
+
+        let mut plant = Self {
+            inputs,
+            calibration,
+            simulation_time: Time::ZERO,
+            heater_branch_mass_flowrate: MassRate::ZERO,
+            dhx_branch_mass_flowrate: MassRate::ZERO,
+            ctah_branch_mass_flowrate: MassRate::ZERO,
+            dracs_loop_mass_flowrate: MassRate::ZERO,
+            tchx_heat_transfer_coeff:
+                HeatTransfer::new::<watt_per_square_meter_kelvin>(40.0),
+            ctah_heat_transfer_coeff:
+                HeatTransfer::new::<watt_per_square_meter_kelvin>(40.0),
+            tchx_controller: new_outlet_temperature_controller(1.75)?,
+            ctah_controller: new_outlet_temperature_controller(4.75)?,
+
+            pipe_34: new_pipe_34(initial_temperature),
+            pipe_33: new_pipe_33(initial_temperature),
+            pipe_32: new_pipe_32(initial_temperature),
+            pipe_31a: new_pipe_31a(initial_temperature),
+            static_mixer_61_label_31: new_static_mixer_61_label_31(initial_temperature),
+            dhx_tube_side_30b: new_dhx_tube_side_30b(initial_temperature),
+            dhx_sthe: new_dhx_sthe_version_1(initial_temperature),
+            dhx_tube_side_30a: new_dhx_tube_side_30a(initial_temperature),
+
+            tchx_35a: new_ndhx_tchx_horizontal_35a(initial_temperature),
+            tchx_35b_1: new_ndhx_tchx_vertical_35b_1(initial_temperature),
+            tchx_35b_2: new_ndhx_tchx_vertical_35b_2(initial_temperature),
+            static_mixer_60_label_36: new_static_mixer_60_label_36(initial_temperature),
+            pipe_36a: new_pipe_36a(initial_temperature),
+            pipe_37: new_pipe_37(initial_temperature),
+            flowmeter_60_37a: new_flowmeter_60_37a(initial_temperature),
+            pipe_38: new_pipe_38(initial_temperature),
+            pipe_39: new_pipe_39(initial_temperature),
+
+            pipe_5a: new_branch_5a(initial_temperature),
+            pipe_26: new_pipe_26(initial_temperature),
+            pipe_25a: new_pipe_25a(initial_temperature),
+            static_mixer_21_label_25: new_static_mixer_21_label_25(initial_temperature),
+            pipe_23a: new_pipe_23a(initial_temperature),
+            static_mixer_20_label_23: new_static_mixer_20_label_23(initial_temperature),
+            pipe_22: new_pipe_22_sam_model(initial_temperature),
+            flowmeter_20_21a: new_flowmeter_20_label_21a(initial_temperature),
+            pipe_21: new_pipe_21(initial_temperature),
+            pipe_20: new_pipe_20(initial_temperature),
+            pipe_19: new_pipe_19(initial_temperature),
+            pipe_17b: new_branch_17b(initial_temperature),
+
+            pipe_4: new_pipe_4(initial_temperature),
+            pipe_3: new_pipe_3_sam_model(initial_temperature),
+            pipe_2a: new_pipe_2a(initial_temperature),
+            static_mixer_10_label_2: new_static_mixer_10_label_2(initial_temperature),
+            heater_top_head_1a: new_heater_top_head_1a(initial_temperature),
+            heater_ver_1:
+                new_heated_section_version_1_label_1_without_inner_annular_pipe(
+                    initial_temperature),
+            heater_bottom_head_1b: new_heater_bottom_head_1b(initial_temperature),
+            pipe_18: new_pipe_18(initial_temperature),
+
+            pipe_5b: new_branch_5b(initial_temperature),
+            static_mixer_41_label_6: new_static_mixer_41_label_6(initial_temperature),
+            pipe_6a: new_pipe_6a(initial_temperature),
+            ctah_vertical_label_7a: new_active_ctah_vertical(initial_temperature),
+            ctah_horizontal_label_7b: new_active_ctah_horizontal(initial_temperature),
+            pipe_8a: new_pipe_8a(initial_temperature),
+            static_mixer_40_label_8: new_static_mixer_40_label_8(initial_temperature),
+            pipe_9: new_pipe_9(initial_temperature),
+            pipe_10: new_pipe_10(initial_temperature),
+            pipe_11: new_pipe_11(initial_temperature),
+            pipe_12: new_pipe_12(initial_temperature),
+            ctah_pump: new_ctah_pump(initial_temperature),
+            pipe_13: new_pipe_13(initial_temperature),
+            pipe_14: new_pipe_14(initial_temperature),
+            flowmeter_40_14a: new_flowmeter_40_14a(initial_temperature),
+            pipe_15: new_pipe_15(initial_temperature),
+            pipe_16: new_pipe_16(initial_temperature),
+            pipe_17a: new_branch_17a(initial_temperature),
+
+            top_mixing_node_5a_5b_4: mixing_node.clone().into(),
+            bottom_mixing_node_17a_17b_18: mixing_node.into(),
+        };
+
+        plant.apply_calibration()?;
+
+        Ok(plant)
+    }
+
+    /// applies the calibration settings to the DHX, heater and
+    /// insulation thicknesses of the loop
+    fn apply_calibration(&mut self) -> Result<(), TuasLibError> {
+
+        let calibration = self.calibration;
+
+        calibrate_gnielinski_nusselt_correlation(
+            &mut self.dhx_sthe.shell_side_nusselt_correlation_to_tubes,
+            calibration.dhx_shell_side_to_tubes_nusselt_correction_factor)?;
+        calibrate_gnielinski_nusselt_correlation(
+            &mut self.dhx_sthe.shell_side_nusselt_correlation_parasitic,
+            calibration.dhx_shell_side_to_ambient_nusselt_correction_factor)?;
+        self.dhx_sthe.heat_transfer_to_ambient =
+            calibration.dhx_heat_transfer_to_ambient;
+        self.dhx_sthe.calibrate_insulation_thickness(
+            calibration.dhx_insulation_thickness);
+
+        let mut heater_fluid_array: FluidArray =
+            self.heater_ver_1.pipe_fluid_array.clone().try_into()?;
+        calibrate_gnielinski_nusselt_correlation(
+            &mut heater_fluid_array.nusselt_correlation,
+            calibration.heater_nusselt_correction_factor)?;
+        self.heater_ver_1.pipe_fluid_array = heater_fluid_array.into();
+
+        // note that flowmeters are considered not insulated
+        let pri_loop_cold_leg = [
+            &mut self.static_mixer_20_label_23,
+            &mut self.pipe_23a,
+            &mut self.pipe_22,
+            &mut self.pipe_21,
+            &mut self.pipe_20,
+            &mut self.pipe_19,
+            &mut self.pipe_17b,
+            &mut self.pipe_18,
+            &mut self.heater_bottom_head_1b,
+        ];
+        for component in pri_loop_cold_leg {
+            component.calibrate_insulation_thickness(
+                calibration.pri_loop_cold_leg_insulation_thickness);
+        }
+
+        let pri_loop_hot_leg = [
+            &mut self.heater_top_head_1a,
+            &mut self.static_mixer_10_label_2,
+            &mut self.pipe_2a,
+            &mut self.pipe_3,
+            &mut self.pipe_4,
+            &mut self.pipe_5a,
+            &mut self.pipe_26,
+            &mut self.pipe_25a,
+            &mut self.static_mixer_21_label_25,
+        ];
+        for component in pri_loop_hot_leg {
+            component.calibrate_insulation_thickness(
+                calibration.pri_loop_hot_leg_insulation_thickness);
+        }
+
+        let dracs_loop_cold_leg = [
+            &mut self.static_mixer_60_label_36,
+            &mut self.pipe_36a,
+            &mut self.pipe_37,
+            &mut self.pipe_38,
+            &mut self.pipe_39,
+        ];
+        for component in dracs_loop_cold_leg {
+            component.calibrate_insulation_thickness(
+                calibration.dracs_loop_cold_leg_insulation_thickness);
+        }
+
+        let dracs_loop_hot_leg = [
+            &mut self.pipe_31a,
+            &mut self.static_mixer_61_label_31,
+            &mut self.pipe_32,
+            &mut self.pipe_33,
+            &mut self.pipe_34,
+        ];
+        for component in dracs_loop_hot_leg {
+            component.calibrate_insulation_thickness(
+                calibration.dracs_loop_hot_leg_insulation_thickness);
+        }
+
+        Ok(())
+    }
+
+    /// simulation time elapsed
+    pub fn get_simulation_time(&self) -> Time {
+        self.simulation_time
+    }
+
+    /// advances the plant by one timestep using the current inputs
+    ///
+    /// the mass flowrates in the primary and DRACS loops are
+    /// solved in parallel from the temperatures at the start of
+    /// the timestep. The heat transfer entities are then linked up
+    /// and advanced by one timestep
+    pub fn step(&mut self, timestep: Time) -> Result<CIETPlantOutputs, TuasLibError> {
+
+        if !(timestep > Time::ZERO) {
+            return Err(TuasLibError::GenericStringError(
+                "CIET plant timestep must be positive".to_owned()));
+        }
+
+        let average_temperature_for_density_calcs =
+            ThermodynamicTemperature::new::<degree_celsius>(80.0);
+        let ambient_htc = self.calibration.ambient_heat_transfer_coeff;
+        let inputs = self.inputs;
+
+        // set point control of the TCHX and CTAH outlet temperatures
+        let tchx_outlet_temperature = self.get_tchx_outlet_temperature()?;
+        self.tchx_heat_transfer_coeff = get_outlet_heat_transfer_coeff(
+            self.tchx_controller.try_get_output(
+                inputs.tchx_outlet_temperature_set_point.get::<kelvin>(),
+                tchx_outlet_temperature.get::<kelvin>(),
+                timestep)?);
+
+        let ctah_outlet_temperature =
+            self.pipe_8a.pipe_fluid_array.try_get_bulk_temperature()?;
+        self.ctah_heat_transfer_coeff = get_outlet_heat_transfer_coeff(
+            self.ctah_controller.try_get_output(
+                inputs.ctah_outlet_temperature_set_point.get::<kelvin>(),
+                ctah_outlet_temperature.get::<kelvin>(),
+                timestep)?);
+
+        // fluid mechanics, DRACS and primary loops in parallel
+        let dhx_tube_side_heat_exchanger_30 =
+            self.dhx_sthe.get_clone_of_tube_side_parallel_tube_fluid_component();
+        let dhx_shell_side_pipe_24 =
+            self.dhx_sthe.get_clone_of_shell_side_fluid_component();
+
+        let plant: &Self = self;
+        let (dracs_loop_flow, (dhx_flow, heater_flow, ctah_flow)) =
+            thread::scope(|scope| {
+
+                let dracs_flowrate_join_handle = scope.spawn(|| {
+                    coupled_dracs_fluid_mechanics_calc_abs_mass_rate_sam_tchx_calibration(
+                        &plant.pipe_34,
+                        &plant.pipe_33,
+                        &plant.pipe_32,
+                        &plant.pipe_31a,
+                        &plant.static_mixer_61_label_31,
+                        &plant.dhx_tube_side_30b,
+                        &dhx_tube_side_heat_exchanger_30,
+                        &plant.dhx_tube_side_30a,
+                        &plant.tchx_35a,
+                        &plant.tchx_35b_1,
+                        &plant.tchx_35b_2,
+                        &plant.static_mixer_60_label_36,
+                        &plant.pipe_36a,
+                        &plant.pipe_37,
+                        &plant.flowmeter_60_37a,
+                        &plant.pipe_38,
+                        &plant.pipe_39)
+                });
+
+                // flow should go from up to down in the DHX branch
+                let pri_loop_flowrates = three_branch_pri_loop_flowrates(
+                    inputs.ctah_pump_pressure,
+                    inputs.ctah_branch_blocked,
+                    inputs.dhx_branch_blocked,
+                    &plant.pipe_4,
+                    &plant.pipe_3,
+                    &plant.pipe_2a,
+                    &plant.static_mixer_10_label_2,
+                    &plant.heater_top_head_1a,
+                    &plant.heater_ver_1,
+                    &plant.heater_bottom_head_1b,
+                    &plant.pipe_18,
+                    &plant.pipe_5a,
+                    &plant.pipe_26,
+                    &plant.pipe_25a,
+                    &plant.static_mixer_21_label_25,
+                    &dhx_shell_side_pipe_24,
+                    &plant.static_mixer_20_label_23,
+                    &plant.pipe_23a,
+                    &plant.pipe_22,
+                    &plant.flowmeter_20_21a,
+                    &plant.pipe_21,
+                    &plant.pipe_20,
+                    &plant.pipe_19,
+                    &plant.pipe_17b,
+                    &plant.pipe_5b,
+                    &plant.static_mixer_41_label_6,
+                    &plant.pipe_6a,
+                    &plant.ctah_vertical_label_7a,
+                    &plant.ctah_horizontal_label_7b,
+                    &plant.pipe_8a,
+                    &plant.static_mixer_40_label_8,
+                    &plant.pipe_9,
+                    &plant.pipe_10,
+                    &plant.pipe_11,
+                    &plant.pipe_12,
+                    &plant.ctah_pump,
+                    &plant.pipe_13,
+                    &plant.pipe_14,
+                    &plant.flowmeter_40_14a,
+                    &plant.pipe_15,
+                    &plant.pipe_16,
+                    &plant.pipe_17a);
+
+                (dracs_flowrate_join_handle.join(), pri_loop_flowrates)
+            });
+
+        let dracs_loop_flow = dracs_loop_flow.map_err(|_|
+            TuasLibError::GenericStringError(
+                "DRACS loop mass flowrate calculation panicked".to_owned()))?;
+
+        self.dracs_loop_mass_flowrate = dracs_loop_flow;
+        self.dhx_branch_mass_flowrate = dhx_flow;
+        self.heater_branch_mass_flowrate = heater_flow;
+        self.ctah_branch_mass_flowrate = ctah_flow;
+
+        // heat transfer, natural circulation in the DRACS loop is
+        // likely counter clockwise
+        let counter_clockwise_dracs_flowrate = dracs_loop_flow;
+
+        coupled_dracs_loop_link_up_components_sam_tchx_calibration(
+            counter_clockwise_dracs_flowrate,
+            self.tchx_heat_transfer_coeff,
+            average_temperature_for_density_calcs,
+            ambient_htc,
+            &mut self.pipe_34,
+            &mut self.pipe_33,
+            &mut self.pipe_32,
+            &mut self.pipe_31a,
+            &mut self.static_mixer_61_label_31,
+            &mut self.dhx_tube_side_30b,
+            &mut self.dhx_sthe,
+            &mut self.dhx_tube_side_30a,
+            &mut self.tchx_35a,
+            &mut self.tchx_35b_1,
+            &mut self.tchx_35b_2,
+            &mut self.static_mixer_60_label_36,
+            &mut self.pipe_36a,
+            &mut self.pipe_37,
+            &mut self.flowmeter_60_37a,
+            &mut self.pipe_38,
+            &mut self.pipe_39);
+
+        ciet_pri_loop_three_branch_link_up_components(
+            dhx_flow,
+            heater_flow,
+            ctah_flow,
+            inputs.heater_power,
+            average_temperature_for_density_calcs,
+            ambient_htc,
+            self.ctah_heat_transfer_coeff,
+            &mut self.pipe_4,
+            &mut self.pipe_3,
+            &mut self.pipe_2a,
+            &mut self.static_mixer_10_label_2,
+            &mut self.heater_top_head_1a,
+            &mut self.heater_ver_1,
+            &mut self.heater_bottom_head_1b,
+            &mut self.pipe_18,
+            &mut self.pipe_5a,
+            &mut self.pipe_26,
+            &mut self.pipe_25a,
+            &mut self.static_mixer_21_label_25,
+            &mut self.dhx_sthe,
+            &mut self.static_mixer_20_label_23,
+            &mut self.pipe_23a,
+            &mut self.pipe_22,
+            &mut self.flowmeter_20_21a,
+            &mut self.pipe_21,
+            &mut self.pipe_20,
+            &mut self.pipe_19,
+            &mut self.pipe_17b,
+            &mut self.pipe_5b,
+            &mut self.static_mixer_41_label_6,
+            &mut self.pipe_6a,
+            &mut self.ctah_vertical_label_7a,
+            &mut self.ctah_horizontal_label_7b,
+            &mut self.pipe_8a,
+            &mut self.static_mixer_40_label_8,
+            &mut self.pipe_9,
+            &mut self.pipe_10,
+            &mut self.pipe_11,
+            &mut self.pipe_12,
+            &mut self.ctah_pump,
+            &mut self.pipe_13,
+            &mut self.pipe_14,
+            &mut self.flowmeter_40_14a,
+            &mut self.pipe_15,
+            &mut self.pipe_16,
+            &mut self.pipe_17a,
+            &mut self.top_mixing_node_5a_5b_4,
+            &mut self.bottom_mixing_node_17a_17b_18);
+
+        // the link up functions overwrite the heat transfer to
+        // ambient of the DHX and heater, so these are recalibrated
+        self.dhx_sthe.heat_transfer_to_ambient =
+            self.calibration.dhx_heat_transfer_to_ambient;
+        self.heater_ver_1.calibrate_heat_transfer_to_ambient(
+            HeatTransfer::ZERO);
+
+        dracs_loop_advance_timestep_except_dhx_sam_tchx_calibration(
+            timestep, &mut self.pipe_34, &mut self.pipe_33, &mut self.pipe_32,
+            &mut self.pipe_31a, &mut self.static_mixer_61_label_31,
+            &mut self.dhx_tube_side_30b, &mut self.dhx_tube_side_30a,
+            &mut self.tchx_35a, &mut self.tchx_35b_1, &mut self.tchx_35b_2,
+            &mut self.static_mixer_60_label_36,
+            &mut self.pipe_36a, &mut self.pipe_37, &mut self.flowmeter_60_37a,
+            &mut self.pipe_38, &mut self.pipe_39);
+
+        pri_loop_three_branch_advance_timestep_except_dhx(
+            timestep, &mut self.pipe_4, &mut self.pipe_3,
+            &mut self.pipe_2a, &mut self.static_mixer_10_label_2,
+            &mut self.heater_top_head_1a, &mut self.heater_ver_1,
+            &mut self.heater_bottom_head_1b, &mut self.pipe_18,
+            &mut self.pipe_5a, &mut self.pipe_26, &mut self.pipe_25a,
+            &mut self.static_mixer_21_label_25,
+            &mut self.static_mixer_20_label_23, &mut self.pipe_23a,
+            &mut self.pipe_22, &mut self.flowmeter_20_21a,
+            &mut self.pipe_21, &mut self.pipe_20, &mut self.pipe_19,
+            &mut self.pipe_17b, &mut self.pipe_5b,
+            &mut self.static_mixer_41_label_6, &mut self.pipe_6a,
+            &mut self.ctah_vertical_label_7a,
+            &mut self.ctah_horizontal_label_7b, &mut self.pipe_8a,
+            &mut self.static_mixer_40_label_8, &mut self.pipe_9,
+            &mut self.pipe_10, &mut self.pipe_11, &mut self.pipe_12,
+            &mut self.ctah_pump, &mut self.pipe_13, &mut self.pipe_14,
+            &mut self.flowmeter_40_14a, &mut self.pipe_15, &mut self.pipe_16,
+            &mut self.pipe_17a, &mut self.top_mixing_node_5a_5b_4,
+            &mut self.bottom_mixing_node_17a_17b_18);
+
+        // by default, dhx flowrate is downwards in this setup
+        let prandtl_wall_correction_setting = true;
+        let tube_side_total_mass_flowrate = -counter_clockwise_dracs_flowrate;
+        let shell_side_total_mass_flowrate = dhx_flow;
+
+        self.dhx_sthe.lateral_and_miscellaneous_connections(
+            prandtl_wall_correction_setting,
+            tube_side_total_mass_flowrate,
+            shell_side_total_mass_flowrate)?;
+
+        self.dhx_sthe.advance_timestep(timestep)?;
+
+        self.simulation_time += timestep;
+
+        self.get_outputs()
+    }
+
+    /// TCHX outlet temperature, taken as the front control
+    /// volume of TCHX 35b_2 which is connected to static mixer 60
+    fn get_tchx_outlet_temperature(&self) ->
+    Result<ThermodynamicTemperature, TuasLibError> {
+
+        let tchx_35b_2_fluid_array: FluidArray =
+            self.tchx_35b_2.pipe_fluid_array.clone().try_into()?;
+
+        Ok(tchx_35b_2_fluid_array.front_single_cv.temperature)
+    }
+
+    /// gets the plant readings at the current simulation time
+    pub fn get_outputs(&mut self) -> Result<CIETPlantOutputs, TuasLibError> {
+
+        let bt_65_tchx_inlet = *self.pipe_34
+            .pipe_fluid_array_temperature()?
+            .first()
+            .ok_or(TuasLibError::GenericStringError(
+                "pipe 34 has no fluid temperatures".to_owned()))?;
+
+        Ok(CIETPlantOutputs {
+            simulation_time: self.simulation_time,
+            bt_11_heater_inlet: self.heater_bottom_head_1b
+                .pipe_fluid_array.try_get_bulk_temperature()?,
+            bt_12_heater_outlet: self.static_mixer_10_label_2
+                .pipe_fluid_array.try_get_bulk_temperature()?,
+            bt_41_ctah_outlet: self.pipe_8a
+                .pipe_fluid_array.try_get_bulk_temperature()?,
+            bt_43_ctah_inlet: self.pipe_6a
+                .pipe_fluid_array.try_get_bulk_temperature()?,
+            bt_65_tchx_inlet,
+            bt_66_tchx_outlet: self.get_tchx_outlet_temperature()?,
+            heater_branch_mass_flowrate: self.heater_branch_mass_flowrate,
+            dhx_branch_mass_flowrate: self.dhx_branch_mass_flowrate,
+            ctah_branch_mass_flowrate: self.ctah_branch_mass_flowrate,
+            dracs_loop_mass_flowrate: self.dracs_loop_mass_flowrate,
+            tchx_heat_transfer_coeff: self.tchx_heat_transfer_coeff,
+            ctah_heat_transfer_coeff: self.ctah_heat_transfer_coeff,
+        })
+    }
+}
+
+/// replaces a Gnielinski nusselt correlation with a calibrated
+/// one, multiplied by the calibration ratio
+fn calibrate_gnielinski_nusselt_correlation(
+    nusselt_correlation: &mut NusseltCorrelation,
+    calibration_ratio: Ratio) -> Result<(), TuasLibError> {
+
+    let calibrated_nusselt_correlation = match nusselt_correlation {
+        NusseltCorrelation::PipeGnielinskiGeneric(gnielinski_data) => {
+            NusseltCorrelation::PipeGnielinskiCalibrated(
+                gnielinski_data.clone(), calibration_ratio)
+        },
+        NusseltCorrelation::PipeGnielinskiCalibrated(gnielinski_data, _) => {
+            NusseltCorrelation::PipeGnielinskiCalibrated(
+                gnielinski_data.clone(), calibration_ratio)
+        },
+        _ => {
+            return Err(TuasLibError::GenericStringError(
+                "only Gnielinski nusselt correlations can be calibrated".to_owned()));
+        },
+    };
+    *nusselt_correlation = calibrated_nusselt_correlation;
+
+    Ok(())
+}
+
+/// lets scenarios drive the CIET plant by input and output name
+pub mod scenario_plant;
+
+/// exports the CIET plant as an FMI 2.0 co-simulation model
+pub mod fmi_model;
+
+/// tests for the CIET plant
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,113 @@
+use uom::si::f64::*;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::power::watt;
+use uom::si::pressure::pascal;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::time::second;
+
+use crate::scenario::ScenarioPlant;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::CIETPlant;
+
+/// inputs are
+///
+/// - heater_power (W)
+/// - ctah_pump_pressure (Pa)
+/// - ctah_branch_blocked and dhx_branch_blocked (1.0 closed, 0.0 open)
+/// - tchx_outlet_temperature_set_point and
+/// ctah_outlet_temperature_set_point (K)
+///
+/// outputs are named as the fields of CIETPlantOutputs, with
+/// temperatures in K, mass flowrates in kg/s, heat transfer
+/// coefficients in W/(m^2 K) and the simulation time in s
+impl ScenarioPlant for CIETPlant {
+
+    fn try_set_input(&mut self, input_name: &str, value: f64)
+        -> Result<(), TuasLibError> {
+
+        match input_name {
+            "heater_power" => {
+                self.inputs.heater_power = Power::new::<watt>(value);
+            },
+            "ctah_pump_pressure" => {
+                self.inputs.ctah_pump_pressure = Pressure::new::<pascal>(value);
+            },
+            "ctah_branch_blocked" => {
+                self.inputs.ctah_branch_blocked = value >= 0.5;
+            },
+            "dhx_branch_blocked" => {
+                self.inputs.dhx_branch_blocked = value >= 0.5;
+            },
+            "tchx_outlet_temperature_set_point" => {
+                self.inputs.tchx_outlet_temperature_set_point =
+                    ThermodynamicTemperature::new::<kelvin>(value);
+            },
+            "ctah_outlet_temperature_set_point" => {
+                self.inputs.ctah_outlet_temperature_set_point =
+                    ThermodynamicTemperature::new::<kelvin>(value);
+            },
+            _ => return Err(TuasLibError::GenericStringError(
+                format!("CIET plant has no input named {}", input_name))),
+        }
+
+        Ok(())
+    }
+
+    fn try_get_input(&self, input_name: &str) -> Result<f64, TuasLibError> {
+
+        let bool_to_f64 = |is_blocked: bool| if is_blocked { 1.0 } else { 0.0 };
+
+        let value = match input_name {
+            "heater_power" => self.inputs.heater_power.get::<watt>(),
+            "ctah_pump_pressure" => self.inputs.ctah_pump_pressure.get::<pascal>(),
+            "ctah_branch_blocked" => bool_to_f64(self.inputs.ctah_branch_blocked),
+            "dhx_branch_blocked" => bool_to_f64(self.inputs.dhx_branch_blocked),
+            "tchx_outlet_temperature_set_point" =>
+                self.inputs.tchx_outlet_temperature_set_point.get::<kelvin>(),
+            "ctah_outlet_temperature_set_point" =>
+                self.inputs.ctah_outlet_temperature_set_point.get::<kelvin>(),
+            _ => return Err(TuasLibError::GenericStringError(
+                format!("CIET plant has no input named {}", input_name))),
+        };
+
+        Ok(value)
+    }
+
+    fn try_get_output(&mut self, output_name: &str) -> Result<f64, TuasLibError> {
+
+        let outputs = self.get_outputs()?;
+
+        let value = match output_name {
+            "simulation_time" => outputs.simulation_time.get::<second>(),
+            "bt_11_heater_inlet" => outputs.bt_11_heater_inlet.get::<kelvin>(),
+            "bt_12_heater_outlet" => outputs.bt_12_heater_outlet.get::<kelvin>(),
+            "bt_41_ctah_outlet" => outputs.bt_41_ctah_outlet.get::<kelvin>(),
+            "bt_43_ctah_inlet" => outputs.bt_43_ctah_inlet.get::<kelvin>(),
+            "bt_65_tchx_inlet" => outputs.bt_65_tchx_inlet.get::<kelvin>(),
+            "bt_66_tchx_outlet" => outputs.bt_66_tchx_outlet.get::<kelvin>(),
+            "heater_branch_mass_flowrate" =>
+                outputs.heater_branch_mass_flowrate.get::<kilogram_per_second>(),
+            "dhx_branch_mass_flowrate" =>
+                outputs.dhx_branch_mass_flowrate.get::<kilogram_per_second>(),
+            "ctah_branch_mass_flowrate" =>
+                outputs.ctah_branch_mass_flowrate.get::<kilogram_per_second>(),
+            "dracs_loop_mass_flowrate" =>
+                outputs.dracs_loop_mass_flowrate.get::<kilogram_per_second>(),
+            "tchx_heat_transfer_coeff" =>
+                outputs.tchx_heat_transfer_coeff.get::<watt_per_square_meter_kelvin>(),
+            "ctah_heat_transfer_coeff" =>
+                outputs.ctah_heat_transfer_coeff.get::<watt_per_square_meter_kelvin>(),
+            _ => return Err(TuasLibError::GenericStringError(
+                format!("CIET plant has no output named {}", output_name))),
+        };
+
+        Ok(value)
+    }
+
+    fn try_advance_timestep(&mut self, timestep: Time) -> Result<(), TuasLibError> {
+        self.step(timestep)?;
+        Ok(())
+    }
+}
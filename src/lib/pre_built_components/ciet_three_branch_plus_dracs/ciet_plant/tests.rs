@@ -0,0 +1,143 @@
+use uom::si::f64::*;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::power::watt;
+use uom::si::pressure::pascal;
+use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::time::second;
+
+use super::{CIETPlant, CIETPlantCalibration, CIETPlantInputs};
+
+/// with the heater and pump off and the loop at a uniform
+/// temperature, nothing should happen after a few timesteps
+#[test]
+pub fn ciet_plant_at_rest_stays_at_rest(){
+
+    let initial_temperature = ThermodynamicTemperature::new::<degree_celsius>(21.0);
+
+    let inputs = CIETPlantInputs {
+        tchx_outlet_temperature_set_point: initial_temperature,
+        ctah_outlet_temperature_set_point: initial_temperature,
+        ..Default::default()
+    };
+
+    let mut plant = CIETPlant::try_new(initial_temperature,
+        inputs,
+        CIETPlantCalibration::default()).unwrap();
+
+    let timestep = Time::new::<second>(0.2);
+
+    for _ in 0..5 {
+        let outputs = plant.step(timestep).unwrap();
+
+        approx::assert_abs_diff_eq!(
+            outputs.heater_branch_mass_flowrate.get::<kilogram_per_second>(),
+            0.0, epsilon=1e-6);
+        approx::assert_abs_diff_eq!(
+            outputs.dracs_loop_mass_flowrate.get::<kilogram_per_second>(),
+            0.0, epsilon=1e-6);
+        approx::assert_abs_diff_eq!(
+            outputs.bt_12_heater_outlet.get::<degree_celsius>(),
+            21.0, epsilon=0.01);
+    }
+
+    approx::assert_relative_eq!(
+        plant.get_simulation_time().get::<second>(), 1.0,
+        max_relative=1e-9);
+}
+
+/// forced circulation through the CTAH branch with the DHX branch
+/// blocked, this is the same case as the version 3 prototype
+/// short test with the DHX branch blocked
+///
+/// the plant should reproduce the prototype flowrate and heater
+/// inlet and outlet temperatures after 400 s
+#[test]
+pub fn ciet_plant_ctah_forced_circulation_dhx_blocked(){
+
+    let initial_temperature = ThermodynamicTemperature::new::<degree_celsius>(46.0);
+
+    let inputs = CIETPlantInputs {
+        heater_power: Power::new::<watt>(2220.0),
+        ctah_pump_pressure: Pressure::new::<pascal>(2400.0),
+        ctah_branch_blocked: false,
+        dhx_branch_blocked: true,
+        tchx_outlet_temperature_set_point:
+            ThermodynamicTemperature::new::<degree_celsius>(46.0),
+        ctah_outlet_temperature_set_point:
+            ThermodynamicTemperature::new::<degree_celsius>(80.0),
+    };
+
+    let mut plant = CIETPlant::try_new(initial_temperature,
+        inputs,
+        CIETPlantCalibration::default()).unwrap();
+
+    let timestep = Time::new::<second>(0.2);
+    let max_simulation_time = Time::new::<second>(400.0);
+
+    let mut outputs = plant.get_outputs().unwrap();
+
+    while plant.get_simulation_time() < max_simulation_time {
+        outputs = plant.step(timestep).unwrap();
+    }
+
+    dbg!(&outputs);
+
+    // no flow through the blocked branch
+    approx::assert_abs_diff_eq!(
+        outputs.dhx_branch_mass_flowrate.get::<kilogram_per_second>(),
+        0.0, epsilon=1e-9);
+
+    // mass is conserved at the mixing nodes
+    approx::assert_abs_diff_eq!(
+        (outputs.heater_branch_mass_flowrate
+         + outputs.ctah_branch_mass_flowrate
+         + outputs.dhx_branch_mass_flowrate).get::<kilogram_per_second>(),
+        0.0, epsilon=1e-6);
+
+    approx::assert_relative_eq!(
+        outputs.ctah_branch_mass_flowrate.get::<kilogram_per_second>().abs(),
+        0.07366, max_relative=0.01);
+
+    approx::assert_abs_diff_eq!(
+        outputs.bt_11_heater_inlet.get::<degree_celsius>(),
+        66.33, epsilon=0.5);
+    approx::assert_abs_diff_eq!(
+        outputs.bt_12_heater_outlet.get::<degree_celsius>(),
+        82.21, epsilon=0.5);
+}
+
+/// the CIET plant can be driven by a scenario using its named
+/// inputs and outputs
+#[test]
+pub fn ciet_plant_driven_by_scenario(){
+
+    use crate::scenario::scenario_parser::try_parse_scenario;
+    use crate::scenario::ScenarioPlant;
+
+    let scenario = try_parse_scenario("
+        timestep 0.2 s
+        end_time 4 s
+        record bt_12_heater_outlet
+        record ctah_branch_mass_flowrate
+        at 0 s step ctah_pump_pressure 2.4 kPa
+        at 0 s step heater_power 2 kW
+        at 1 s close dhx_branch_blocked
+        when simulation_time above 2 s trip ctah_pump_pressure
+    ").unwrap();
+
+    let initial_temperature = ThermodynamicTemperature::new::<degree_celsius>(46.0);
+    let mut plant = CIETPlant::try_new(initial_temperature,
+        CIETPlantInputs::default(),
+        CIETPlantCalibration::default()).unwrap();
+
+    let record = scenario.try_run(&mut plant).unwrap();
+
+    assert_eq!(record.fired_events.len(), 4);
+    assert!(plant.inputs.dhx_branch_blocked);
+    assert_eq!(plant.try_get_input("ctah_pump_pressure").unwrap(), 0.0);
+    approx::assert_relative_eq!(
+        plant.inputs.heater_power.get::<watt>(), 2000.0);
+
+    let heater_outlet = record.try_get_output_history("bt_12_heater_outlet").unwrap();
+    assert!(heater_outlet.iter().all(|temperature| temperature.is_finite()));
+}
@@ -35,4 +35,9 @@ pub mod solver_functions;
 /// test and steady state natural circulation modules
 pub mod components;
 
+/// the three branch CIET loop with DRACS packaged as a plant model 
+/// with typed inputs and outputs, so that it can be stepped 
+/// from tests, scripts or other front ends without the GUI
+pub mod ciet_plant;
+
 
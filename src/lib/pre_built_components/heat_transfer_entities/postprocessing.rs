@@ -7,6 +7,7 @@ use super::cv_types::CVType;
 use crate::boundary_conditions::BCType;
 use crate::boussinesq_thermophysical_properties::density::try_get_rho;
 use crate::tuas_lib_error::TuasLibError;
+use crate::energy_audit::energy_tally::EnergyTally;
 
 impl HeatTransferEntity {
 
@@ -210,4 +211,144 @@ impl HeatTransferEntity {
 
 
     }
+
+    /// gets the mass of each node within the control volume
+    ///
+    /// boundary conditions have no mass, so this returns an error
+    #[inline]
+    pub fn try_get_mass_vector(&self) -> Result<Vec<Mass>, TuasLibError> {
+
+        match self {
+            HeatTransferEntity::ControlVolume(cv) => {
+                match cv {
+                    CVType::SingleCV(single_cv) => {
+                        Ok(vec![single_cv.mass_control_volume])
+                    },
+                    CVType::FluidArrayCV(fluid_array_cv) => {
+                        fluid_array_cv.try_get_node_mass_vector()
+                    },
+                    CVType::SolidArrayCV(solid_array_cv) => {
+                        solid_array_cv.try_get_node_mass_vector()
+                    },
+                }
+            },
+            HeatTransferEntity::BoundaryConditions(_) => {
+                Err(TuasLibError::
+                    NotImplementedForBoundaryConditions(
+                        "mass not implemented for BC".to_string()
+                    ))
+            },
+        }
+    }
+
+    /// gets the specific enthalpy of each node within the 
+    /// control volume
+    ///
+    /// boundary conditions have no stored enthalpy, 
+    /// so this returns an error
+    #[inline]
+    pub fn try_get_specific_enthalpy_vector(&self) -> 
+    Result<Vec<AvailableEnergy>, TuasLibError> {
+
+        match self {
+            HeatTransferEntity::ControlVolume(cv) => {
+                match cv {
+                    CVType::SingleCV(single_cv) => {
+                        Ok(vec![single_cv.current_timestep_control_volume_specific_enthalpy])
+                    },
+                    CVType::FluidArrayCV(fluid_array_cv) => {
+                        fluid_array_cv.try_get_node_specific_enthalpy_vector()
+                    },
+                    CVType::SolidArrayCV(solid_array_cv) => {
+                        solid_array_cv.try_get_node_specific_enthalpy_vector()
+                    },
+                }
+            },
+            HeatTransferEntity::BoundaryConditions(_) => {
+                Err(TuasLibError::
+                    NotImplementedForBoundaryConditions(
+                        "enthalpy not implemented for BC".to_string()
+                    ))
+            },
+        }
+    }
+
+    /// gets the enthalpy stored in the control volume, 
+    /// that is the sum of node mass times node specific enthalpy
+    #[inline]
+    pub fn try_get_stored_enthalpy(&self) -> Result<Energy, TuasLibError> {
+
+        let mass_vector = self.try_get_mass_vector()?;
+        let specific_enthalpy_vector = self.try_get_specific_enthalpy_vector()?;
+
+        let stored_enthalpy: Energy = mass_vector.iter()
+            .zip(specific_enthalpy_vector.iter())
+            .map(|(mass, specific_enthalpy)| *mass * *specific_enthalpy)
+            .sum();
+
+        Ok(stored_enthalpy)
+    }
+
+    /// starts tallying the contributions to the rate of enthalpy 
+    /// change of the control volume by source, for energy audits
+    ///
+    /// boundary conditions store no enthalpy, so this returns 
+    /// an error
+    pub fn try_enable_energy_tally(&mut self) -> Result<(), TuasLibError> {
+
+        match self {
+            HeatTransferEntity::ControlVolume(cv) => {
+                match cv {
+                    CVType::SingleCV(single_cv) => {
+                        single_cv.enable_energy_tally()
+                    },
+                    CVType::FluidArrayCV(fluid_array_cv) => {
+                        fluid_array_cv.enable_energy_tally()
+                    },
+                    CVType::SolidArrayCV(solid_array_cv) => {
+                        solid_array_cv.enable_energy_tally()
+                    },
+                }
+                Ok(())
+            },
+            HeatTransferEntity::BoundaryConditions(_) => {
+                Err(TuasLibError::
+                    NotImplementedForBoundaryConditions(
+                        "energy tally not implemented for BC".to_string()
+                    ))
+            },
+        }
+    }
+
+    /// gets the energy tally of the control volume, which is None 
+    /// if the tally was not enabled
+    ///
+    /// boundary conditions store no enthalpy, so this returns 
+    /// an error
+    #[inline]
+    pub fn try_get_energy_tally(&self) -> 
+    Result<Option<EnergyTally>, TuasLibError> {
+
+        match self {
+            HeatTransferEntity::ControlVolume(cv) => {
+                match cv {
+                    CVType::SingleCV(single_cv) => {
+                        Ok(single_cv.energy_tally)
+                    },
+                    CVType::FluidArrayCV(fluid_array_cv) => {
+                        Ok(fluid_array_cv.energy_tally)
+                    },
+                    CVType::SolidArrayCV(solid_array_cv) => {
+                        Ok(solid_array_cv.energy_tally)
+                    },
+                }
+            },
+            HeatTransferEntity::BoundaryConditions(_) => {
+                Err(TuasLibError::
+                    NotImplementedForBoundaryConditions(
+                        "energy tally not implemented for BC".to_string()
+                    ))
+            },
+        }
+    }
 }
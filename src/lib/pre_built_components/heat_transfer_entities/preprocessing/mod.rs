@@ -21,6 +21,7 @@ use self::single_cv_single_cv_interactions::calculate_between_two_singular_cv_no
 
 use super::cv_types::CVType;
 use super::HeatTransferEntity;
+use crate::energy_audit::energy_tally::EnergyTallySource;
 ///// contains scripts to select enums 
 ///// for how heat transfer entities 
 ///// ie control volumes and boundary conditions 
@@ -706,6 +707,20 @@ fn try_get_thermal_conductance_based_on_interaction(
                         cylinder_length,
                     )?
                 },
+            HeatTransferInteractionType::
+                DualCylindricalThermalConductanceWithInterfaceResistance(_) => 
+                interaction.get_thermal_conductance_based_on_interaction(
+                    temperature_1, 
+                    temperature_2, 
+                    pressure_1, 
+                    pressure_2)?,
+            HeatTransferInteractionType::
+                CylindricalConductionConvectionWithFouling(_) => 
+                interaction.get_thermal_conductance_based_on_interaction(
+                    temperature_1, 
+                    temperature_2, 
+                    pressure_1, 
+                    pressure_2)?,
             HeatTransferInteractionType::UserSpecifiedHeatAddition  
                 => {
                     println!("interaction type needs to be \n 
@@ -1065,6 +1080,24 @@ pub fn calculate_constant_heat_flux_front_single_cv_back(
             }
         ,
 
+        HeatTransferInteractionType::
+            DualCylindricalThermalConductanceWithInterfaceResistance(_) => 
+            {
+                println!("please specify interaction type as \n 
+                UserSpecifiedHeatFluxCustomArea or Similar");
+                return Err(TuasLibError::WrongHeatTransferInteractionType);
+            }
+        ,
+
+        HeatTransferInteractionType::
+            CylindricalConductionConvectionWithFouling(_) => 
+            {
+                println!("please specify interaction type as \n 
+                UserSpecifiedHeatFluxCustomArea or Similar");
+                return Err(TuasLibError::WrongHeatTransferInteractionType);
+            }
+        ,
+
         HeatTransferInteractionType::
             UserSpecifiedHeatAddition => 
             {
@@ -1140,8 +1173,9 @@ pub fn calculate_constant_heat_flux_front_single_cv_back(
     let heat_flowrate_into_control_vol: Power = 
         heat_flux_into_control_vol * heat_transfer_area;
 
-    control_vol.rate_enthalpy_change_vector.
-        push(heat_flowrate_into_control_vol);
+    control_vol.push_rate_enthalpy_change(
+        heat_flowrate_into_control_vol,
+        EnergyTallySource::HeatSource);
 
     // auto time stepping doesn't work for constant heat flux 
     // or specified power as well. 
@@ -9,6 +9,7 @@ use crate::boussinesq_thermophysical_properties::Material;
 use crate::single_control_vol::SingleCVNode;
 use crate::tuas_lib_error::TuasLibError;
 use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::DataAdvection;
+use crate::energy_audit::energy_tally::EnergyTallySource;
 
 
 
@@ -118,6 +119,24 @@ pub fn calculate_single_cv_front_heat_flux_back(
             }
         ,
 
+        HeatTransferInteractionType::
+            DualCylindricalThermalConductanceWithInterfaceResistance(_) => 
+            {
+                println!("please specify interaction type as \n 
+                UserSpecifiedHeatFluxCustomArea or Similar");
+                return Err(TuasLibError::WrongHeatTransferInteractionType);
+            }
+        ,
+
+        HeatTransferInteractionType::
+            CylindricalConductionConvectionWithFouling(_) => 
+            {
+                println!("please specify interaction type as \n 
+                UserSpecifiedHeatFluxCustomArea or Similar");
+                return Err(TuasLibError::WrongHeatTransferInteractionType);
+            }
+        ,
+
         HeatTransferInteractionType::
             UserSpecifiedHeatAddition => 
             {
@@ -181,8 +200,9 @@ pub fn calculate_single_cv_front_heat_flux_back(
     let heat_flowrate_into_control_vol: Power = 
     heat_flux_into_control_vol * heat_transfer_area;
 
-    control_vol.rate_enthalpy_change_vector.
-        push(heat_flowrate_into_control_vol);
+    control_vol.push_rate_enthalpy_change(
+        heat_flowrate_into_control_vol,
+        EnergyTallySource::HeatSource);
 
     // auto time stepping doesn't work for constant heat flux 
     // or specified power as well. 
@@ -267,8 +287,9 @@ pub fn calculate_constant_heat_addition_front_single_cv_back(
     };
 
 
-    control_vol.rate_enthalpy_change_vector.
-        push(heat_added_to_control_vol);
+    control_vol.push_rate_enthalpy_change(
+        heat_added_to_control_vol,
+        EnergyTallySource::HeatSource);
 
     // auto time stepping doesn't work for constant heat flux 
     // or specified power as well. 
@@ -357,8 +378,9 @@ pub fn calculate_single_cv_front_constant_heat_addition_back(
     };
 
 
-    control_vol.rate_enthalpy_change_vector.
-        push(heat_added_to_control_vol);
+    control_vol.push_rate_enthalpy_change(
+        heat_added_to_control_vol,
+        EnergyTallySource::HeatSource);
 
     // auto time stepping doesn't work for constant heat flux 
     // or specified power as well. 
@@ -438,6 +460,22 @@ pub fn calculate_mesh_stability_conduction_timestep_for_single_node_and_bc(
 
                 ()
             },
+        HeatTransferInteractionType::
+            DualCylindricalThermalConductanceWithInterfaceResistance(_) |
+        HeatTransferInteractionType::
+            CylindricalConductionConvectionWithFouling(_) => {
+
+                // interface resistances only lower the conductance,
+                // so the timestep without them is conservative
+                let perfect_contact_timestep: Time = 
+                calculate_mesh_stability_conduction_timestep_for_single_node_and_bc(
+                    control_vol,
+                    interaction.get_interaction_without_interface_resistance())?;
+
+                if cv_timestep > perfect_contact_timestep {
+                    cv_timestep = perfect_contact_timestep;
+                }
+            },
         HeatTransferInteractionType::
             UserSpecifiedThermalConductance(_) => {
 
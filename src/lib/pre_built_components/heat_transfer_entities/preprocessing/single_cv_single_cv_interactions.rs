@@ -54,6 +54,20 @@ pub fn calculate_between_two_singular_cv_nodes(
                 single_cv_2,
                 interaction)
         },
+
+        HeatTransferInteractionType::DualCylindricalThermalConductanceWithInterfaceResistance(_) => {
+            calculate_conductance_interaction_between_two_singular_cv_nodes(
+                single_cv_1,
+                single_cv_2,
+                interaction)
+        },
+
+        HeatTransferInteractionType::CylindricalConductionConvectionWithFouling(_) => {
+            calculate_conductance_interaction_between_two_singular_cv_nodes(
+                single_cv_1,
+                single_cv_2,
+                interaction)
+        },
         HeatTransferInteractionType::UserSpecifiedHeatAddition => {
             calculate_conductance_interaction_between_two_singular_cv_nodes(
                 single_cv_1,
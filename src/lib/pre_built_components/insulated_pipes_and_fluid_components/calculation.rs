@@ -13,6 +13,11 @@ impl InsulatedFluidComponent {
     timestep: Time) -> Result<(),TuasLibError> {
 
         self.pipe_fluid_array.advance_timestep_mut_self(timestep)?;
+
+        if self.rz_conduction_wall.is_some() {
+            return self.advance_rz_conduction_wall_timestep(timestep);
+        }
+
         self.pipe_shell.advance_timestep_mut_self(timestep)?;
         self.insulation.advance_timestep_mut_self(timestep)?;
         Ok(())
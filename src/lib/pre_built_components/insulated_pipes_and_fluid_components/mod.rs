@@ -1,8 +1,11 @@
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
+use crate::array_control_vol_and_fluid_component_collections::two_dimension_rz_conducting_medium::RZConduction2DMesh;
 use crate::boussinesq_thermophysical_properties::SolidMaterial;
 use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::heat_transfer_correlations::external_natural_convection::AmbientNaturalConvection;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
 
 use super::heat_transfer_entities::cv_types::CVType;
 use super::heat_transfer_entities::HeatTransferEntity;
@@ -49,6 +52,27 @@ pub struct InsulatedFluidComponent {
     /// pipe heat transfer coefficient to ambient
     pub heat_transfer_to_ambient: HeatTransfer,
 
+    /// natural convection (and radiation) model to ambient,
+    /// if set, the heat transfer coefficient to ambient is
+    /// calculated from the insulation temperature every timestep
+    /// and heat_transfer_to_ambient is not used
+    pub ambient_natural_convection: Option<AmbientNaturalConvection>,
+
+    /// contact resistance between the pipe shell and insulation,
+    /// acting over the pipe shell outer surface. 
+    /// Perfect contact by default
+    pub pipe_shell_to_insulation_contact_resistance: InterfaceThermalResistance,
+
+    /// r-z conduction mesh for the pipe shell and insulation,
+    /// if set, the pipe shell and insulation are solved together 
+    /// on this mesh instead of as two SolidColumns, so that steep
+    /// radial gradients in thick insulation can be resolved
+    ///
+    /// the pipe_shell and insulation temperatures are then set to 
+    /// the volume averaged temperatures of each region of the mesh 
+    /// every timestep. Set with try_use_rz_conduction_wall
+    pub rz_conduction_wall: Option<RZConduction2DMesh>,
+
     /// pipe outer diameter (tube)
     pub tube_od: Length,
 
@@ -179,6 +203,10 @@ impl InsulatedFluidComponent {
             pipe_fluid_array: CVType::FluidArrayCV(fluid_array).into(),
             ambient_temperature,
             heat_transfer_to_ambient: htc_to_ambient,
+            ambient_natural_convection: None,
+            pipe_shell_to_insulation_contact_resistance: 
+                InterfaceThermalResistance::new_perfect_contact(),
+            rz_conduction_wall: None,
             tube_od: shell_od,
             tube_id: shell_id,
             insulation_od: shell_od+2.0*insulation_thickness,
@@ -284,6 +312,10 @@ impl InsulatedFluidComponent {
             pipe_fluid_array: CVType::FluidArrayCV(fluid_array).into(),
             ambient_temperature,
             heat_transfer_to_ambient: htc_to_ambient,
+            ambient_natural_convection: None,
+            pipe_shell_to_insulation_contact_resistance: 
+                InterfaceThermalResistance::new_perfect_contact(),
+            rz_conduction_wall: None,
             tube_od: shell_od,
             tube_id: shell_id,
             insulation_id: shell_od,
@@ -319,6 +351,10 @@ pub mod type_conversion;
 /// nusselt number and insulation thickness 
 pub mod calibration;
 
+/// replaces the pipe shell and insulation with an r-z conduction 
+/// mesh, for thick insulation or fast heat-up transients
+pub mod rz_conduction_wall;
+
 /// validation and verification tests for this component 
 #[cfg(test)]
 mod tests;
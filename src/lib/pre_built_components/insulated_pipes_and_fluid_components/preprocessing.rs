@@ -1,5 +1,6 @@
 use std::thread::JoinHandle;
 use std::thread;
+use std::f64::consts::PI;
 
 use uom::ConstZero;
 use uom::si::pressure::atmosphere;
@@ -95,6 +96,16 @@ impl InsulatedFluidComponent {
         heater_power: Power,
         correct_prandtl_for_wall_temperatures: bool) -> Result<(), TuasLibError>{
 
+        // the r-z conduction wall, if set, replaces the pipe shell 
+        // and insulation
+        if self.rz_conduction_wall.is_some() {
+            self.rz_conduction_wall_lateral_connections(
+                mass_flowrate, 
+                heater_power, 
+                correct_prandtl_for_wall_temperatures)?;
+            self.zero_power_bc_axial_connection()?;
+            return Ok(());
+        }
 
         // first let's get all the conductances 
         
@@ -105,7 +116,8 @@ impl InsulatedFluidComponent {
         // |                        |               |               |
         //
         // 1. we'll need the ambient to insulation midpoint (nodal) thermal conductance
-        let heat_transfer_to_ambient: HeatTransfer = self.heat_transfer_to_ambient;
+        let heat_transfer_to_ambient: HeatTransfer = 
+            self.try_get_heat_transfer_to_ambient()?;
 
 
         let insulation_to_air_nodal_conductance: ThermalConductance 
@@ -285,6 +297,13 @@ impl InsulatedFluidComponent {
         self.pipe_fluid_array.link_to_back(&mut zero_power_bc,
             interaction)?;
 
+        // the axial ends of the r-z conduction wall are already 
+        // adiabatic, and the pipe shell and insulation are not 
+        // advanced when it is used
+        if self.rz_conduction_wall.is_some() {
+            return Ok(());
+        }
+
         self.pipe_shell.link_to_front(&mut zero_power_bc,
             interaction)?;
 
@@ -303,6 +322,33 @@ impl InsulatedFluidComponent {
 
 
 
+    /// obtains the heat transfer coefficient from the insulation 
+    /// to ambient air
+    ///
+    /// if no ambient natural convection model is set, this is just 
+    /// the constant heat_transfer_to_ambient. Otherwise, it is 
+    /// calculated using the insulation bulk temperature as the 
+    /// surface temperature
+    #[inline]
+    pub fn try_get_heat_transfer_to_ambient(&self) 
+        -> Result<HeatTransfer, TuasLibError> {
+
+        let ambient_natural_convection = match self.ambient_natural_convection {
+            Some(ambient_natural_convection) => ambient_natural_convection,
+            None => return Ok(self.heat_transfer_to_ambient),
+        };
+
+        let mut insulation_clone: SolidColumn = 
+        self.insulation.clone().try_into()?;
+
+        let insulation_temperature: ThermodynamicTemperature 
+        = insulation_clone.try_get_bulk_temperature()?;
+
+        ambient_natural_convection.try_get_heat_transfer_coefficient(
+            insulation_temperature, 
+            self.ambient_temperature)
+    }
+
     /// obtains air to insulation shell conductance
     ///
     /// it goes roughly to the middle of the insulation
@@ -355,12 +401,14 @@ impl InsulatedFluidComponent {
     }
 
 
-    /// obtains fluid_array node to pipe_shell shell conductance
+    /// obtains the heat transfer coefficient from the fluid_array 
+    /// to the pipe_shell inner surface, based on the nusselt number 
+    /// correlation of the fluid array
     #[inline]
-    pub fn get_fluid_array_node_to_pipe_shell_conductance(
+    pub fn get_fluid_array_to_pipe_shell_heat_transfer_coefficient(
         &mut self,
         correct_prandtl_for_wall_temperatures: bool) 
-        -> Result<ThermalConductance,TuasLibError> {
+        -> Result<HeatTransfer,TuasLibError> {
 
         // the thermal conductance here should be based on the 
         // nusselt number correlation
@@ -385,9 +433,6 @@ impl InsulatedFluidComponent {
 
         let atmospheric_pressure = Pressure::new::<atmosphere>(1.0);
 
-        let pipe_shell_surf_temperature: ThermodynamicTemperature 
-        = pipe_shell_clone.try_get_bulk_temperature()?;
-
         let hydraulic_diameter = self.get_hydraulic_diameter();
         let flow_area: Area = self.get_cross_sectional_area_immutable();
 
@@ -396,15 +441,12 @@ impl InsulatedFluidComponent {
 
         // flow area and hydraulic diameter are ok
 
-        let mut fluid_array_clone: FluidArray = 
+        let fluid_array_clone: FluidArray = 
             self.pipe_fluid_array.clone().try_into()?;
 
         let fluid_material: LiquidMaterial
             = fluid_array_clone.material_control_volume.try_into()?;
 
-        let solid_material: SolidMaterial
-            = pipe_shell_clone.material_control_volume.try_into()?;
-
         let viscosity: DynamicViscosity = 
             fluid_material.try_get_dynamic_viscosity(fluid_temperature)?;
 
@@ -510,6 +552,36 @@ impl InsulatedFluidComponent {
 
         h_to_fluid_array = nusselt_estimate * k_fluid_average / hydraulic_diameter;
 
+        Ok(h_to_fluid_array)
+    }
+
+    /// obtains fluid_array node to pipe_shell shell conductance
+    #[inline]
+    pub fn get_fluid_array_node_to_pipe_shell_conductance(
+        &mut self,
+        correct_prandtl_for_wall_temperatures: bool) 
+        -> Result<ThermalConductance,TuasLibError> {
+
+        let h_to_fluid_array: HeatTransfer = 
+            self.get_fluid_array_to_pipe_shell_heat_transfer_coefficient(
+                correct_prandtl_for_wall_temperatures)?;
+
+        let mut fluid_array_clone: FluidArray = 
+        self.pipe_fluid_array.clone().try_into()?;
+
+        let mut pipe_shell_clone: SolidColumn = 
+        self.pipe_shell.clone().try_into()?;
+
+        let fluid_temperature: ThermodynamicTemperature 
+        = fluid_array_clone.try_get_bulk_temperature()?;
+
+        let atmospheric_pressure = Pressure::new::<atmosphere>(1.0);
+
+        let pipe_shell_surf_temperature: ThermodynamicTemperature 
+        = pipe_shell_clone.try_get_bulk_temperature()?;
+
+        let solid_material: SolidMaterial
+            = pipe_shell_clone.material_control_volume.try_into()?;
 
         // and then get the convective resistance
         let number_of_temperature_nodes = self.inner_nodes + 2;
@@ -681,8 +753,15 @@ impl InsulatedFluidComponent {
 
         let total_resistance = insulation_material_resistance + solid_pipe_material_resistance;
 
+        // and the contact resistance between pipe shell and insulation 
+        // acts over the pipe shell outer surface
+
+        let pipe_shell_to_insulation_interface_area: Area = 
+        PI * tube_od * node_length;
 
-        return Ok(1.0/total_resistance);
+        return Ok(self.pipe_shell_to_insulation_contact_resistance
+            .add_in_series(1.0/total_resistance, 
+                pipe_shell_to_insulation_interface_area));
     }
 
     /// spawns a thread and moves the clone of the entire heater object into the 
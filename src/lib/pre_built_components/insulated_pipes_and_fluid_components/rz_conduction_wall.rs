@@ -0,0 +1,160 @@
+use uom::si::f64::*;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+
+use super::InsulatedFluidComponent;
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_traits::FluidComponentTrait;
+use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
+use crate::array_control_vol_and_fluid_component_collections::two_dimension_rz_conducting_medium::RZConduction2DMesh;
+use crate::boussinesq_thermophysical_properties::SolidMaterial;
+use crate::tuas_lib_error::TuasLibError;
+
+impl InsulatedFluidComponent {
+
+    /// replaces the pipe shell and insulation SolidColumns with an
+    /// r-z conduction mesh of the same geometry and materials,
+    /// with the given number of radial nodes in the pipe shell and
+    /// insulation
+    ///
+    /// the mesh starts from the current pipe shell and insulation
+    /// temperatures, and the contact resistance between them is
+    /// carried over. Calibrate the insulation thickness before
+    /// calling this, as the mesh geometry is fixed once constructed
+    pub fn try_use_rz_conduction_wall(&mut self,
+        shell_radial_nodes: usize,
+        insulation_radial_nodes: usize) -> Result<(), TuasLibError> {
+
+        let pipe_shell_clone: SolidColumn =
+            self.pipe_shell.clone().try_into()?;
+        let insulation_clone: SolidColumn =
+            self.insulation.clone().try_into()?;
+
+        let pipe_shell_material: SolidMaterial =
+            pipe_shell_clone.material_control_volume.try_into()?;
+        let insulation_material: SolidMaterial =
+            insulation_clone.material_control_volume.try_into()?;
+
+        let pipe_shell_temperature_vector =
+            pipe_shell_clone.get_temperature_vector()?;
+        let insulation_temperature_vector =
+            insulation_clone.get_temperature_vector()?;
+
+        let mut rz_conduction_wall = RZConduction2DMesh::new_insulated_pipe_wall(
+            self.get_component_length_immutable(),
+            self.inner_nodes,
+            self.tube_id,
+            self.tube_od,
+            self.get_insulation_thickness(),
+            pipe_shell_material,
+            insulation_material,
+            shell_radial_nodes,
+            insulation_radial_nodes,
+            pipe_shell_temperature_vector[0],
+            pipe_shell_clone.pressure_control_volume)?;
+
+        // radial nodes take the temperature of the SolidColumn node
+        // of their region at the same axial position
+        let node_region_indices = rz_conduction_wall.get_node_region_indices();
+        for (radial_node, region_index) in node_region_indices.iter().enumerate() {
+            let region_temperature_vector = match region_index {
+                0 => &pipe_shell_temperature_vector,
+                _ => &insulation_temperature_vector,
+            };
+            for (axial_node, temperature) in
+                region_temperature_vector.iter().enumerate() {
+                rz_conduction_wall.temperature_array_current_timestep
+                    [[radial_node, axial_node]] = *temperature;
+            }
+        }
+
+        if !self.pipe_shell_to_insulation_contact_resistance.is_perfect_contact() {
+            let contact_conductance = HeatTransfer::new::<watt_per_square_meter_kelvin>(
+                1.0/self.pipe_shell_to_insulation_contact_resistance
+                .get_fouling_factor_square_meter_kelvin_per_watt());
+            rz_conduction_wall.try_set_interface_contact_conductance(
+                0, contact_conductance)?;
+        }
+
+        self.rz_conduction_wall = Some(rz_conduction_wall);
+
+        Ok(())
+    }
+
+    /// links the fluid array to the inner surface of the r-z
+    /// conduction wall, and the outer surface to ambient,
+    /// the heater power is generated in the pipe shell region
+    ///
+    /// this takes the place of the lateral connections between the
+    /// fluid array, pipe shell, insulation and ambient
+    pub(crate) fn rz_conduction_wall_lateral_connections(&mut self,
+        mass_flowrate: MassRate,
+        heater_power: Power,
+        correct_prandtl_for_wall_temperatures: bool) -> Result<(), TuasLibError>{
+
+        let heat_transfer_to_ambient: HeatTransfer =
+            self.try_get_heat_transfer_to_ambient()?;
+
+        self.set_mass_flowrate(mass_flowrate);
+
+        let heat_transfer_to_fluid_array: HeatTransfer =
+            self.get_fluid_array_to_pipe_shell_heat_transfer_coefficient(
+                correct_prandtl_for_wall_temperatures)?;
+
+        let ambient_temperature = self.ambient_temperature;
+
+        let mut fluid_array_clone: FluidArray =
+            self.pipe_fluid_array.clone().try_into()?;
+
+        fluid_array_clone.set_mass_flowrate(mass_flowrate);
+
+        let rz_conduction_wall = self.rz_conduction_wall.as_mut()
+            .ok_or_else(|| TuasLibError::GenericStringError(
+                "component has no r-z conduction wall".to_owned()))?;
+
+        rz_conduction_wall.try_lateral_link_to_fluid_array(
+            &mut fluid_array_clone,
+            heat_transfer_to_fluid_array)?;
+
+        rz_conduction_wall.lateral_link_to_ambient(
+            heat_transfer_to_ambient,
+            ambient_temperature);
+
+        // the pipe shell is the heating element
+        rz_conduction_wall.try_set_region_power(0, heater_power)?;
+
+        self.pipe_fluid_array.set(fluid_array_clone.into())?;
+
+        Ok(())
+    }
+
+    /// advances the r-z conduction wall, then sets the pipe shell
+    /// and insulation temperatures to the volume averaged
+    /// temperatures of the pipe shell and insulation regions
+    pub(crate) fn advance_rz_conduction_wall_timestep(&mut self,
+        timestep: Time) -> Result<(), TuasLibError> {
+
+        let rz_conduction_wall = self.rz_conduction_wall.as_mut()
+            .ok_or_else(|| TuasLibError::GenericStringError(
+                "component has no r-z conduction wall".to_owned()))?;
+
+        rz_conduction_wall.advance_timestep(timestep)?;
+
+        let pipe_shell_temperature_vector =
+            rz_conduction_wall.try_get_region_mean_temperature_vector(0)?;
+        let insulation_temperature_vector =
+            rz_conduction_wall.try_get_region_mean_temperature_vector(1)?;
+
+        let mut pipe_shell_clone: SolidColumn =
+            self.pipe_shell.clone().try_into()?;
+        let mut insulation_clone: SolidColumn =
+            self.insulation.clone().try_into()?;
+
+        pipe_shell_clone.set_temperature_vector(pipe_shell_temperature_vector)?;
+        insulation_clone.set_temperature_vector(insulation_temperature_vector)?;
+
+        self.pipe_shell.set(pipe_shell_clone.into())?;
+        self.insulation.set(insulation_clone.into())?;
+
+        Ok(())
+    }
+}
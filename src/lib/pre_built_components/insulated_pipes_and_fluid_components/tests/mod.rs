@@ -57,3 +57,8 @@ pub mod length_tests;
 /// the SolidColumn was already verified against analytical solution 
 /// (I added a test)
 pub mod axial_conduction_verification;
+
+/// compares the insulated pipe with an r-z conduction wall against 
+/// the same pipe with the pipe shell and insulation SolidColumns 
+/// during a heat-up transient
+pub mod rz_conduction_wall;
@@ -0,0 +1,166 @@
+use uom::si::angle::degree;
+use uom::si::area::square_meter;
+use uom::si::f64::*;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::length::{meter, millimeter};
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::power::watt;
+use uom::si::pressure::atmosphere;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::time::second;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::boundary_conditions::BCType;
+use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+use crate::pre_built_components::insulated_pipes_and_fluid_components::InsulatedFluidComponent;
+
+/// a 1 m heated pipe with the pipe 6a cross section and fiberglass 
+/// insulation, starting at the inlet temperature
+fn new_heated_insulated_pipe(inlet_temperature: ThermodynamicTemperature) 
+    -> InsulatedFluidComponent {
+
+    let hydraulic_diameter = Length::new::<meter>(2.79e-2);
+    let shell_id = hydraulic_diameter;
+    let shell_od = shell_id + 2.0 * Length::new::<meter>(0.0027686);
+
+    InsulatedFluidComponent::new_insulated_pipe(
+        inlet_temperature, 
+        ThermodynamicTemperature::new::<degree_celsius>(20.0), 
+        Pressure::new::<atmosphere>(1.0), 
+        Pressure::new::<atmosphere>(1.0), 
+        Area::new::<square_meter>(6.11e-4), 
+        Angle::new::<degree>(0.0), 
+        Ratio::new::<ratio>(0.0), 
+        shell_id, 
+        shell_od, 
+        Length::new::<meter>(0.0508), 
+        Length::new::<meter>(1.0), 
+        hydraulic_diameter, 
+        SolidMaterial::SteelSS304L, 
+        SolidMaterial::Fiberglass, 
+        LiquidMaterial::TherminolVP1, 
+        HeatTransfer::new::<watt_per_square_meter_kelvin>(20.0), 
+        8, 
+        Length::new::<millimeter>(0.015))
+}
+
+/// links the fluid array to the inlet and outlet, then makes the 
+/// lateral connections and advances the pipe by one timestep
+fn advance_heated_insulated_pipe(pipe: &mut InsulatedFluidComponent,
+    inlet_bc: &mut HeatTransferEntity,
+    outlet_bc: &mut HeatTransferEntity,
+    inlet_temperature: ThermodynamicTemperature,
+    mass_flowrate: MassRate,
+    heater_power: Power,
+    timestep: Time){
+
+    let fluid = LiquidMaterial::TherminolVP1;
+    let fluid_array: FluidArray = pipe.pipe_fluid_array.clone()
+        .try_into().unwrap();
+    let fluid_temperature_vector = fluid_array.get_temperature_vector()
+        .unwrap();
+
+    let inlet_density = fluid.try_get_density(inlet_temperature).unwrap();
+    let back_cv_density = fluid.try_get_density(
+        fluid_temperature_vector[0]).unwrap();
+    let front_cv_density = fluid.try_get_density(
+        *fluid_temperature_vector.last().unwrap()).unwrap();
+
+    pipe.pipe_fluid_array.link_to_back(inlet_bc,
+        HeatTransferInteractionType::new_advection_interaction(
+            mass_flowrate, inlet_density, back_cv_density)).unwrap();
+    pipe.pipe_fluid_array.link_to_front(outlet_bc,
+        HeatTransferInteractionType::new_advection_interaction(
+            mass_flowrate, front_cv_density, front_cv_density)).unwrap();
+
+    pipe.lateral_and_miscellaneous_connections_no_wall_correction(
+        mass_flowrate, heater_power).unwrap();
+    pipe.advance_timestep(timestep).unwrap();
+}
+
+/// a heated insulated pipe with an r-z conduction wall should heat 
+/// up like the same pipe with the pipe shell and insulation 
+/// SolidColumns, as the steel shell is thin and most of the heater 
+/// power goes into the fluid
+///
+/// the r-z wall also resolves the radial temperature gradient 
+/// within the insulation, which the single insulation layer cannot
+#[test]
+pub fn rz_conduction_wall_heat_up_matches_two_layer_pipe(){
+
+    let inlet_temperature = 
+        ThermodynamicTemperature::new::<degree_celsius>(25.0);
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.18);
+    let heater_power = Power::new::<watt>(2000.0);
+    let timestep = Time::new::<second>(0.5);
+
+    let mut two_layer_pipe = new_heated_insulated_pipe(inlet_temperature);
+    let mut rz_wall_pipe = new_heated_insulated_pipe(inlet_temperature);
+    rz_wall_pipe.try_use_rz_conduction_wall(2, 6).unwrap();
+
+    let mut inlet_bc: HeatTransferEntity = 
+        BCType::new_const_temperature(inlet_temperature).into();
+    let mut outlet_bc: HeatTransferEntity = BCType::new_adiabatic_bc().into();
+
+    let outlet_temperature_degc = |pipe: &mut InsulatedFluidComponent| -> f64 {
+        pipe.pipe_fluid_array_temperature().unwrap()
+            .last().unwrap().get::<degree_celsius>()
+    };
+
+    for step in 1..=400 {
+        for pipe in [&mut two_layer_pipe, &mut rz_wall_pipe] {
+            advance_heated_insulated_pipe(pipe,
+                &mut inlet_bc,
+                &mut outlet_bc,
+                inlet_temperature,
+                mass_flowrate,
+                heater_power,
+                timestep);
+        }
+
+        // the outlet temperatures agree throughout the transient
+        if step % 40 == 0 {
+            approx::assert_abs_diff_eq!(
+                outlet_temperature_degc(&mut rz_wall_pipe),
+                outlet_temperature_degc(&mut two_layer_pipe),
+                epsilon=0.3);
+        }
+    }
+
+    // the pipe has heated up
+    let outlet_temperature_rise = 
+        outlet_temperature_degc(&mut rz_wall_pipe) - 25.0;
+    assert!(outlet_temperature_rise > 3.0);
+
+    // pipe shell temperature rises agree to within the difference 
+    // in radial discretisation, the r-z wall shell temperatures 
+    // are volume averaged over its radial nodes
+    let two_layer_shell_temperatures = two_layer_pipe.pipe_shell_temperature()
+        .unwrap();
+    let rz_wall_shell_temperatures = rz_wall_pipe.pipe_shell_temperature()
+        .unwrap();
+    for (two_layer_temperature, rz_wall_temperature) in 
+        two_layer_shell_temperatures.iter()
+        .zip(rz_wall_shell_temperatures.iter()) {
+        approx::assert_relative_eq!(
+            rz_wall_temperature.get::<degree_celsius>() - 25.0,
+            two_layer_temperature.get::<degree_celsius>() - 25.0,
+            max_relative=0.02);
+    }
+
+    // the insulation next to the shell is much hotter than the 
+    // insulation next to ambient air
+    let radial_temperatures = rz_wall_pipe.rz_conduction_wall.as_ref()
+        .unwrap()
+        .try_get_radial_temperature_vector(9)
+        .unwrap();
+    let inner_insulation_temperature_degc = 
+        radial_temperatures[2].get::<degree_celsius>();
+    let outer_insulation_temperature_degc = 
+        radial_temperatures.last().unwrap().get::<degree_celsius>();
+    assert!(inner_insulation_temperature_degc 
+        - outer_insulation_temperature_degc > 5.0);
+}
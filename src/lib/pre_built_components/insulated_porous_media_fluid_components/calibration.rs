@@ -3,6 +3,8 @@ use uom::si::f64::*;
 use uom::si::thermal_conductivity::watt_per_meter_kelvin;
 
 use crate::heat_transfer_correlations::thermal_resistance::try_get_thermal_conductance_annular_cylinder;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
+use std::f64::consts::PI;
 
 
 impl InsulatedPorousMediaFluidComponent {
@@ -49,4 +51,18 @@ impl InsulatedPorousMediaFluidComponent {
 
         
     }
+
+    /// sets the contact resistance between the pipe shell and 
+    /// insulation, acting over the insulation inner surface
+    /// (pi * insulation_id * pipe_length)
+    pub fn set_pipe_shell_to_insulation_contact_resistance(&mut self,
+        contact_resistance: InterfaceThermalResistance,
+        pipe_length: Length,
+        insulation_id: Length){
+
+        let interface_area: Area = PI * insulation_id * pipe_length;
+
+        self.pipe_shell_to_insulation_contact_thermal_resistance = 
+            contact_resistance.get_thermal_resistance(interface_area);
+    }
 }
@@ -10,8 +10,10 @@ use crate::heat_transfer_correlations::nusselt_number_correlations::enums::Nusse
 use crate::heat_transfer_correlations::nusselt_number_correlations::input_structs::NusseltPrandtlReynoldsData;
 use crate::heat_transfer_correlations::nusselt_number_correlations::input_structs::WakaoData;
 use crate::heat_transfer_correlations::thermal_resistance::try_get_thermal_conductance_annular_cylinder;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
 
 use super::heat_transfer_entities::HeatTransferEntity;
+use super::axial_power_shape::AxialPowerShape;
 use uom::si::angle::degree;
 use uom::si::area::square_inch;
 use uom::si::f64::*;
@@ -184,6 +186,28 @@ pub struct InsulatedPorousMediaFluidComponent {
     /// used to calculate conductance to interior hA
     /// conductance = h A 
     pub convection_heat_transfer_area_fluid_to_interior: Area,
+
+    /// fouling resistance between the fluid and pipe shell, 
+    /// acting over convection_heat_transfer_area_fluid_to_pipe_shell.
+    /// Clean by default
+    pub fluid_to_pipe_shell_fouling: InterfaceThermalResistance,
+
+    /// fouling resistance between the fluid and porous media interior,
+    /// acting over convection_heat_transfer_area_fluid_to_interior.
+    /// Clean by default
+    pub fluid_to_interior_fouling: InterfaceThermalResistance,
+
+    /// contact resistance between pipe shell and insulation for 
+    /// the whole component, zero (perfect contact) by default
+    ///
+    /// use set_pipe_shell_to_insulation_contact_resistance to 
+    /// set this from a contact resistance and interface area
+    pub pipe_shell_to_insulation_contact_thermal_resistance: ThermalResistance,
+
+    /// axial shape of the heater power along the component, for both 
+    /// the pipe shell and porous media interior, uniform by default, 
+    /// set with try_set_axial_power_shape to validate the shape
+    pub axial_power_shape: AxialPowerShape,
 }
 
 impl InsulatedPorousMediaFluidComponent {
@@ -385,6 +409,10 @@ impl InsulatedPorousMediaFluidComponent {
 
 
         return Self{
+            fluid_to_pipe_shell_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            fluid_to_interior_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            axial_power_shape: AxialPowerShape::Uniform,
+            pipe_shell_to_insulation_contact_thermal_resistance: ThermalResistance::ZERO,
             inner_nodes: user_specified_inner_nodes,
             insulation_array: insulation_array.into(),
             interior_solid_array_for_porous_media: inner_pipe_array.into(),
@@ -831,6 +859,10 @@ impl InsulatedPorousMediaFluidComponent {
             = PI * insulation_od * heated_length;
 
         return Self { inner_nodes: user_specified_inner_nodes,
+            fluid_to_pipe_shell_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            fluid_to_interior_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            axial_power_shape: AxialPowerShape::Uniform,
+            pipe_shell_to_insulation_contact_thermal_resistance: ThermalResistance::ZERO,
             interior_solid_array_for_porous_media: twisted_tape.into(),
             pipe_shell: steel_shell_array.into(),
             pipe_fluid_array: therminol_array.into(),
@@ -1087,6 +1119,10 @@ impl InsulatedPorousMediaFluidComponent {
             );
 
         return Self { inner_nodes: user_specified_inner_nodes,
+            fluid_to_pipe_shell_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            fluid_to_interior_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            axial_power_shape: AxialPowerShape::Uniform,
+            pipe_shell_to_insulation_contact_thermal_resistance: ThermalResistance::ZERO,
         insulation_array: insulation.into(),
             pipe_shell: steel_shell_array.into(),
             pipe_fluid_array: therminol_array.into(),
@@ -1339,6 +1375,10 @@ impl InsulatedPorousMediaFluidComponent {
             );
 
         return Self { inner_nodes: user_specified_inner_nodes,
+            fluid_to_pipe_shell_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            fluid_to_interior_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            axial_power_shape: AxialPowerShape::Uniform,
+            pipe_shell_to_insulation_contact_thermal_resistance: ThermalResistance::ZERO,
             insulation_array: insulation.into(),
             pipe_shell: steel_shell_array.into(),
             pipe_fluid_array: therminol_array.into(),
@@ -1584,6 +1624,10 @@ impl InsulatedPorousMediaFluidComponent {
             );
 
         return Self { inner_nodes: user_specified_inner_nodes,
+            fluid_to_pipe_shell_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            fluid_to_interior_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            axial_power_shape: AxialPowerShape::Uniform,
+            pipe_shell_to_insulation_contact_thermal_resistance: ThermalResistance::ZERO,
             insulation_array: insulation.into(),
             pipe_shell: steel_shell_array.into(),
             pipe_fluid_array: therminol_array.into(),
@@ -1828,6 +1872,10 @@ impl InsulatedPorousMediaFluidComponent {
             );
 
         return Self { inner_nodes: user_specified_inner_nodes,
+            fluid_to_pipe_shell_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            fluid_to_interior_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            axial_power_shape: AxialPowerShape::Uniform,
+            pipe_shell_to_insulation_contact_thermal_resistance: ThermalResistance::ZERO,
             insulation_array: insulation.into(),
             pipe_shell: steel_shell_array.into(),
             pipe_fluid_array: therminol_array.into(),
@@ -2069,6 +2117,10 @@ impl InsulatedPorousMediaFluidComponent {
             );
 
         return Self { inner_nodes: user_specified_inner_nodes,
+            fluid_to_pipe_shell_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            fluid_to_interior_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            axial_power_shape: AxialPowerShape::Uniform,
+            pipe_shell_to_insulation_contact_thermal_resistance: ThermalResistance::ZERO,
             insulation_array: insulation.into(),
             pipe_shell: steel_shell_array.into(),
             pipe_fluid_array: therminol_array.into(),
@@ -2310,6 +2362,10 @@ impl InsulatedPorousMediaFluidComponent {
             );
 
         return Self { inner_nodes: user_specified_inner_nodes,
+            fluid_to_pipe_shell_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            fluid_to_interior_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            axial_power_shape: AxialPowerShape::Uniform,
+            pipe_shell_to_insulation_contact_thermal_resistance: ThermalResistance::ZERO,
             insulation_array: insulation.into(),
             pipe_shell: steel_shell_array.into(),
             pipe_fluid_array: therminol_array.into(),
@@ -17,6 +17,7 @@ use crate::boussinesq_thermophysical_properties::LiquidMaterial;
 use crate::boussinesq_thermophysical_properties::SolidMaterial;
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::tuas_lib_error::TuasLibError;
 
 impl InsulatedPorousMediaFluidComponent {
 
@@ -30,7 +31,7 @@ impl InsulatedPorousMediaFluidComponent {
     /// unoptimised in this regard
     #[inline]
     pub fn lateral_and_miscellaneous_connections_mx10(&mut self,
-        mass_flowrate: MassRate){
+        mass_flowrate: MassRate) -> Result<(), TuasLibError>{
 
         let h_air_to_steel_surf = self.heat_transfer_to_ambient;
         let heater_steady_state_power: Power = Power::ZERO;
@@ -63,11 +64,8 @@ impl InsulatedPorousMediaFluidComponent {
 
         // power fraction array
         let number_of_temperature_nodes = self.inner_nodes + 2;
-        let q_fraction_per_node: f64 = 
-        1.0/ number_of_temperature_nodes as f64;
-        let mut q_frac_arr: Array1<f64> = 
-        Array::default(number_of_temperature_nodes);
-        q_frac_arr.fill(q_fraction_per_node);
+        let q_frac_arr: Array1<f64> = self.axial_power_shape
+            .try_get_q_fraction_array(number_of_temperature_nodes)?;
 
         // then get the ambient temperature 
 
@@ -157,6 +155,8 @@ impl InsulatedPorousMediaFluidComponent {
         // adiabatic bc connections to make things finished 
 
         self.zero_power_bc_connection();
+
+        Ok(())
     }
 
 
@@ -335,6 +335,13 @@ impl InsulatedPorousMediaFluidComponent {
         let therminol_to_steel_shell_average_conductance: ThermalConductance 
         = h * heat_transfer_area_per_node;
 
+        // fouling is added in series with convection on the 
+        // therminol side
+        let therminol_to_steel_shell_average_conductance: ThermalConductance 
+        = self.fluid_to_pipe_shell_fouling.add_in_series(
+            therminol_to_steel_shell_average_conductance,
+            heat_transfer_area_per_node);
+
         let therminol_to_steel_shell_surface_node_resistance = 
         1.0/therminol_to_steel_shell_average_conductance;
 
@@ -456,7 +463,14 @@ impl InsulatedPorousMediaFluidComponent {
         let fiberglass_resistance = 1.0/fiberglass_layer_conductance_nodal;
         let steel_resistance = 1.0/steel_layer_conductance_nodal;
 
-        let total_resistance = fiberglass_resistance + steel_resistance;
+        // contact resistance is split evenly amongst the nodes,
+        // which are in parallel
+        let contact_resistance = 
+            self.pipe_shell_to_insulation_contact_thermal_resistance 
+            * number_of_temperature_nodes as f64;
+
+        let total_resistance = fiberglass_resistance + steel_resistance 
+            + contact_resistance;
 
 
         return 1.0/total_resistance;
@@ -480,7 +494,7 @@ impl InsulatedPorousMediaFluidComponent {
                 // carry out the connection calculations
                 component_clone.
                     lateral_and_miscellaneous_connections_mx10(
-                        mass_flowrate);
+                        mass_flowrate).unwrap();
                 
                 component_clone
 
@@ -350,10 +350,10 @@ pub fn steady_state_test_for_heater_v1_eight_nodes_validation(){
 
 
                 static_mixer_mx_10_object.lateral_and_miscellaneous_connections_mx10(
-                    mass_flowrate);
+                    mass_flowrate).unwrap();
 
                 static_mixer_mx_10_pipe.lateral_and_miscellaneous_connections_mx10(
-                    mass_flowrate);
+                    mass_flowrate).unwrap();
 
 
                 //// calculate timestep (serial method)
@@ -471,10 +471,10 @@ pub fn transient_test_stepup_4050s_fig_2_21_zweibaum_thesis_for_heater_v1_eight_
 
 
                 static_mixer_mx_10_object.lateral_and_miscellaneous_connections_mx10(
-                    mass_flowrate);
+                    mass_flowrate).unwrap();
 
                 static_mixer_mx_10_pipe.lateral_and_miscellaneous_connections_mx10(
-                    mass_flowrate);
+                    mass_flowrate).unwrap();
 
 
                 //// calculate timestep (serial method)
@@ -1027,10 +1027,10 @@ pub fn transient_test_stepdown_4050s_fig_2_21_zweibaum_thesis_for_heater_v1_eigh
 
 
                 static_mixer_mx_10_object.lateral_and_miscellaneous_connections_mx10(
-                    mass_flowrate);
+                    mass_flowrate).unwrap();
 
                 static_mixer_mx_10_pipe.lateral_and_miscellaneous_connections_mx10(
-                    mass_flowrate);
+                    mass_flowrate).unwrap();
 
 
                 //// calculate timestep (serial method)
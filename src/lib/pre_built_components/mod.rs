@@ -46,6 +46,11 @@ pub mod shell_and_tube_heat_exchanger;
 /// represents one dimensional solid structure
 pub mod one_d_solid_structure;
 
+/// axial power shapes for heated components, these are remapped 
+/// to the nodalisation of each component so that the total power 
+/// is conserved
+pub mod axial_power_shape;
+
 
 
 /// represents the old CIET struct support codes based on
@@ -3,11 +3,13 @@ use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
 use crate::boussinesq_thermophysical_properties::SolidMaterial;
 use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::heat_transfer_correlations::external_natural_convection::AmbientNaturalConvection;
 use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
 use crate::heat_transfer_correlations::nusselt_number_correlations::input_structs::NusseltPrandtlReynoldsData;
 
 use super::heat_transfer_entities::cv_types::CVType;
 use super::heat_transfer_entities::HeatTransferEntity;
+use super::axial_power_shape::AxialPowerShape;
 use uom::si::area::square_meter;
 use uom::si::f64::*;
 use uom::si::heat_transfer::watt_per_square_meter_kelvin;
@@ -51,6 +53,17 @@ pub struct NonInsulatedFluidComponent {
     /// pipe heat transfer coefficient to ambient
     pub heat_transfer_to_ambient: HeatTransfer,
 
+    /// natural convection (and radiation) model to ambient,
+    /// if set, the heat transfer coefficient to ambient is
+    /// calculated from the pipe shell temperature every timestep
+    /// and heat_transfer_to_ambient is not used
+    pub ambient_natural_convection: Option<AmbientNaturalConvection>,
+
+    /// axial shape of the heater power along the component,
+    /// uniform by default, 
+    /// set with try_set_axial_power_shape to validate the shape
+    pub axial_power_shape: AxialPowerShape,
+
     /// pipe  outer diameter 
     pub od: Length,
 
@@ -152,6 +165,8 @@ impl NonInsulatedFluidComponent {
             pipe_fluid_array: CVType::FluidArrayCV(fluid_array).into(),
             ambient_temperature,
             heat_transfer_to_ambient: htc_to_ambient,
+            ambient_natural_convection: None,
+            axial_power_shape: AxialPowerShape::Uniform,
             od,
             id,
             flow_area,
@@ -247,6 +262,8 @@ impl NonInsulatedFluidComponent {
             pipe_fluid_array: therminol_array.into(), 
             ambient_temperature, 
             heat_transfer_to_ambient: h_to_air, 
+            ambient_natural_convection: None,
+            axial_power_shape: AxialPowerShape::Uniform,
             od: steel_shell_od, 
             id: steel_shell_id, 
             flow_area, 
@@ -329,6 +346,8 @@ impl NonInsulatedFluidComponent {
             pipe_fluid_array: CVType::FluidArrayCV(fluid_array).into(),
             ambient_temperature,
             heat_transfer_to_ambient: htc_to_ambient,
+            ambient_natural_convection: None,
+            axial_power_shape: AxialPowerShape::Uniform,
             od: shell_od,
             id: shell_id,
             flow_area,
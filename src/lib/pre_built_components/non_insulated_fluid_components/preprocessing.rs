@@ -17,9 +17,22 @@ use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_traits::FluidComponentTrait;
 
 use crate::tuas_lib_error::TuasLibError;
+use crate::pre_built_components::axial_power_shape::AxialPowerShape;
 
 impl NonInsulatedFluidComponent {
 
+    /// sets the axial shape of the heater power along the component,
+    /// returning an error if the shape is not valid, eg. a chopped 
+    /// cosine with an extrapolated length shorter than the component
+    pub fn try_set_axial_power_shape(&mut self,
+        axial_power_shape: AxialPowerShape) -> Result<(), TuasLibError>{
+
+        axial_power_shape.try_validate()?;
+        self.axial_power_shape = axial_power_shape;
+
+        Ok(())
+    }
+
 
     /// used to connect the arrays laterally 
     /// you'll need to set the mass flowrate and heater power
@@ -110,7 +123,7 @@ impl NonInsulatedFluidComponent {
 
 
         // first let's get all the conductances 
-        let heat_transfer_to_ambient = self.heat_transfer_to_ambient;
+        let heat_transfer_to_ambient = self.try_get_heat_transfer_to_ambient()?;
 
         let pipe_shell_to_air_nodal_conductance: ThermalConductance 
         = self.get_air_shell_nodal_shell_conductance(
@@ -127,9 +140,8 @@ impl NonInsulatedFluidComponent {
 
         // other stuff 
         let number_of_temperature_nodes = self.inner_nodes + 2;
-        let q_fraction_per_node: f64 = 1.0/ number_of_temperature_nodes as f64;
-        let mut q_frac_arr: Array1<f64> = Array::default(number_of_temperature_nodes);
-        q_frac_arr.fill(q_fraction_per_node);
+        let q_frac_arr: Array1<f64> = self.axial_power_shape
+            .try_get_q_fraction_array(number_of_temperature_nodes)?;
 
         // then get the ambient temperature 
 
@@ -267,6 +279,33 @@ impl NonInsulatedFluidComponent {
 
 
 
+    /// obtains the heat transfer coefficient from the pipe shell 
+    /// to ambient air
+    ///
+    /// if no ambient natural convection model is set, this is just 
+    /// the constant heat_transfer_to_ambient. Otherwise, it is 
+    /// calculated using the pipe shell bulk temperature as the 
+    /// surface temperature
+    #[inline]
+    pub fn try_get_heat_transfer_to_ambient(&self) 
+        -> Result<HeatTransfer, TuasLibError> {
+
+        let ambient_natural_convection = match self.ambient_natural_convection {
+            Some(ambient_natural_convection) => ambient_natural_convection,
+            None => return Ok(self.heat_transfer_to_ambient),
+        };
+
+        let mut pipe_shell_clone: SolidColumn = 
+        self.pipe_shell.clone().try_into()?;
+
+        let pipe_surf_temperature: ThermodynamicTemperature 
+        = pipe_shell_clone.try_get_bulk_temperature()?;
+
+        ambient_natural_convection.try_get_heat_transfer_coefficient(
+            pipe_surf_temperature, 
+            self.ambient_temperature)
+    }
+
     /// obtains air to pipe shell conductance
     #[inline]
     pub fn get_air_shell_nodal_shell_conductance(&mut self,
@@ -0,0 +1,68 @@
+use uom::si::f64::*;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::power::watt;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::time::second;
+
+use crate::heat_transfer_correlations::external_natural_convection::AmbientNaturalConvection;
+use crate::pre_built_components::non_insulated_fluid_components::NonInsulatedFluidComponent;
+
+/// the bare heater should use its constant heat transfer coefficient 
+/// to ambient unless a natural convection model is set, 
+///
+/// once set, the heat transfer coefficient should be calculated 
+/// from the pipe shell temperature, and should fall as the 
+/// heater cools down towards ambient temperature
+#[test]
+pub fn bare_heater_natural_convection_to_ambient(){
+
+    let initial_temperature = 
+        ThermodynamicTemperature::new::<degree_celsius>(78.75);
+    let ambient_air_temp = 
+        ThermodynamicTemperature::new::<degree_celsius>(21.76);
+
+    let mut heater_v2_bare = NonInsulatedFluidComponent::
+        new_dewet_model_heater_v2_no_twisted_tape(
+            initial_temperature,
+            ambient_air_temp,
+            8);
+
+    assert_eq!(
+        heater_v2_bare.try_get_heat_transfer_to_ambient().unwrap(),
+        heater_v2_bare.heat_transfer_to_ambient);
+
+    let ambient_natural_convection = AmbientNaturalConvection::
+        new_horizontal_cylinder(
+            heater_v2_bare.od,
+            Some(Ratio::new::<ratio>(0.3)));
+
+    heater_v2_bare.ambient_natural_convection = 
+        Some(ambient_natural_convection);
+
+    let initial_htc = heater_v2_bare.try_get_heat_transfer_to_ambient()
+        .unwrap();
+
+    approx::assert_relative_eq!(
+        initial_htc.get::<watt_per_square_meter_kelvin>(),
+        ambient_natural_convection.try_get_heat_transfer_coefficient(
+            initial_temperature, ambient_air_temp).unwrap()
+            .get::<watt_per_square_meter_kelvin>(),
+        max_relative=1e-9);
+
+    // stagnant, unheated heater cools down
+    let timestep = Time::new::<second>(1.0);
+    for _ in 0..100 {
+        heater_v2_bare.lateral_and_miscellaneous_connections(
+            MassRate::new::<kilogram_per_second>(0.0),
+            Power::new::<watt>(0.0),
+            false).unwrap();
+        heater_v2_bare.advance_timestep(timestep).unwrap();
+    }
+
+    let final_htc = heater_v2_bare.try_get_heat_transfer_to_ambient()
+        .unwrap();
+
+    assert!(final_htc < initial_htc);
+}
@@ -0,0 +1,96 @@
+use uom::si::f64::*;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::power::kilowatt;
+use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::time::second;
+
+use crate::pre_built_components::axial_power_shape::AxialPowerShape;
+use crate::pre_built_components::non_insulated_fluid_components::NonInsulatedFluidComponent;
+
+/// heats up a stagnant heater v2 with the given axial power shape 
+/// and returns the shell temperatures in degrees celsius
+fn heat_up_stagnant_heater(axial_power_shape: AxialPowerShape) -> Vec<f64> {
+
+    let initial_temperature = 
+        ThermodynamicTemperature::new::<degree_celsius>(21.76);
+    let ambient_air_temp = 
+        ThermodynamicTemperature::new::<degree_celsius>(21.76);
+
+    let mut heater_v2_bare = NonInsulatedFluidComponent::
+        new_dewet_model_heater_v2_no_twisted_tape(
+            initial_temperature,
+            ambient_air_temp,
+            8);
+
+    heater_v2_bare.try_set_axial_power_shape(axial_power_shape)
+        .unwrap();
+
+    let timestep = Time::new::<second>(1.0);
+    for _ in 0..100 {
+        heater_v2_bare.lateral_and_miscellaneous_connections(
+            MassRate::new::<kilogram_per_second>(0.0),
+            Power::new::<kilowatt>(1.0),
+            false).unwrap();
+        heater_v2_bare.advance_timestep(timestep).unwrap();
+    }
+
+    heater_v2_bare.pipe_shell_temperature().unwrap()
+        .iter()
+        .map(|temperature| temperature.get::<degree_celsius>())
+        .collect()
+}
+
+/// a chopped cosine power shape should heat the middle of the 
+/// stagnant heater shell more than its ends, whereas a uniform 
+/// power shape heats the shell nearly evenly
+#[test]
+pub fn chopped_cosine_heater_gives_peaked_shell_temperature(){
+
+    let uniform_shell_temperatures = 
+        heat_up_stagnant_heater(AxialPowerShape::Uniform);
+    let chopped_cosine_shell_temperatures = 
+        heat_up_stagnant_heater(AxialPowerShape::ChoppedCosine { 
+            extrapolated_length_ratio: 1.2 });
+
+    let number_of_nodes = chopped_cosine_shell_temperatures.len();
+    let middle_node = number_of_nodes/2;
+
+    let uniform_peak_to_end = uniform_shell_temperatures[middle_node]
+        - uniform_shell_temperatures[0];
+    let chopped_cosine_peak_to_end = 
+        chopped_cosine_shell_temperatures[middle_node]
+        - chopped_cosine_shell_temperatures[0];
+
+    // the shell heats up in both cases
+    assert!(uniform_shell_temperatures[middle_node] > 25.0);
+
+    // the chopped cosine peaks in the middle, and is symmetric
+    assert!(chopped_cosine_peak_to_end > 1.0);
+    assert!(chopped_cosine_peak_to_end > 10.0 * uniform_peak_to_end.abs());
+    approx::assert_relative_eq!(
+        chopped_cosine_shell_temperatures[0],
+        chopped_cosine_shell_temperatures[number_of_nodes-1],
+        max_relative=1e-3);
+}
+
+/// an invalid axial power shape is rejected when set, and the 
+/// heater keeps its previous shape
+#[test]
+pub fn invalid_axial_power_shape_is_rejected_when_set(){
+
+    let initial_temperature = 
+        ThermodynamicTemperature::new::<degree_celsius>(21.76);
+
+    let mut heater_v2_bare = NonInsulatedFluidComponent::
+        new_dewet_model_heater_v2_no_twisted_tape(
+            initial_temperature,
+            initial_temperature,
+            8);
+
+    let invalid_shape = AxialPowerShape::ChoppedCosine { 
+        extrapolated_length_ratio: 0.5 };
+
+    assert!(heater_v2_bare.try_set_axial_power_shape(invalid_shape)
+        .is_err());
+    assert_eq!(heater_v2_bare.axial_power_shape, AxialPowerShape::Uniform);
+}
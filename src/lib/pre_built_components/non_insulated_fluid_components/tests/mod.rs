@@ -8,3 +8,14 @@
 /// Development. UC Berkeley.
 #[cfg(test)]
 mod heated_section_only;
+
+/// checks that the natural convection model to ambient is used 
+/// in place of the constant heat transfer coefficient when set
+#[cfg(test)]
+mod ambient_natural_convection;
+
+/// checks that a chopped cosine axial power shape set on the heater 
+/// gives a peaked shell temperature profile, and that invalid 
+/// shapes are rejected when set
+#[cfg(test)]
+mod axial_power_shape;
@@ -11,8 +11,10 @@ use crate::heat_transfer_correlations::nusselt_number_correlations::enums::Nusse
 use crate::heat_transfer_correlations::nusselt_number_correlations::input_structs::NusseltPrandtlReynoldsData;
 use crate::heat_transfer_correlations::nusselt_number_correlations::input_structs::WakaoData;
 use crate::heat_transfer_correlations::thermal_resistance::try_get_thermal_conductance_annular_cylinder;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
 
 use super::heat_transfer_entities::HeatTransferEntity;
+use super::axial_power_shape::AxialPowerShape;
 use uom::si::area::square_inch;
 use uom::si::f64::*;
 use uom::si::length::{inch, meter};
@@ -161,6 +163,21 @@ pub struct NonInsulatedPorousMediaFluidComponent {
     /// conductance = h A 
     pub convection_heat_transfer_area_to_interior: Area,
 
+    /// fouling resistance between the fluid and pipe shell, 
+    /// acting over convection_heat_transfer_area_to_pipe.
+    /// Clean by default
+    pub fluid_to_pipe_shell_fouling: InterfaceThermalResistance,
+
+    /// fouling resistance between the fluid and porous media interior,
+    /// acting over convection_heat_transfer_area_to_interior.
+    /// Clean by default
+    pub fluid_to_interior_fouling: InterfaceThermalResistance,
+
+    /// axial shape of the heater power along the component, for both 
+    /// the pipe shell and porous media interior, uniform by default, 
+    /// set with try_set_axial_power_shape to validate the shape
+    pub axial_power_shape: AxialPowerShape,
+
 }
 
 
@@ -355,6 +372,9 @@ impl NonInsulatedPorousMediaFluidComponent {
             = PI * steel_shell_od * heated_length;
 
         return Self { inner_nodes: user_specified_inner_nodes,
+            fluid_to_pipe_shell_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            fluid_to_interior_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            axial_power_shape: AxialPowerShape::Uniform,
             interior_solid_array_for_porous_media: twisted_tape.into(),
             pipe_shell: steel_shell_array.into(),
             pipe_fluid_array: therminol_array.into(),
@@ -545,6 +565,9 @@ impl NonInsulatedPorousMediaFluidComponent {
             = PI * steel_shell_od * heated_length;
 
         return Self { inner_nodes: user_specified_inner_nodes,
+            fluid_to_pipe_shell_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            fluid_to_interior_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            axial_power_shape: AxialPowerShape::Uniform,
             interior_solid_array_for_porous_media: twisted_tape.into(),
             pipe_shell: steel_shell_array.into(),
             pipe_fluid_array: therminol_array.into(),
@@ -734,6 +757,9 @@ impl NonInsulatedPorousMediaFluidComponent {
             = PI * steel_shell_od * heated_length;
 
         return Self { inner_nodes: user_specified_inner_nodes,
+            fluid_to_pipe_shell_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            fluid_to_interior_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            axial_power_shape: AxialPowerShape::Uniform,
             interior_solid_array_for_porous_media: twisted_tape.into(),
             pipe_shell: steel_shell_array.into(),
             pipe_fluid_array: therminol_array.into(),
@@ -7,10 +7,12 @@ use crate::pre_built_components::heat_transfer_entities::preprocessing::try_get_
 use crate::heat_transfer_correlations::nusselt_number_correlations::input_structs::NusseltPrandtlReynoldsData;
 use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
 use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::{CylindricalAndSphericalSolidFluidArrangement, DataCylindricalConductionConvectionFouling};
 use crate::boussinesq_thermophysical_properties::LiquidMaterial;
 use crate::boussinesq_thermophysical_properties::SolidMaterial;
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::tuas_lib_error::TuasLibError;
 
 use uom::si::area::square_inch;
 use uom::si::length::inch;
@@ -31,7 +33,7 @@ impl NonInsulatedPorousMediaFluidComponent {
     #[inline]
     pub fn ciet_heater_v2_lateral_and_miscellaneous_connections(&mut self,
         mass_flowrate: MassRate,
-        heater_steady_state_power: Power){
+        heater_steady_state_power: Power) -> Result<(), TuasLibError>{
 
 
         // first let's get all the conductances 
@@ -52,9 +54,8 @@ impl NonInsulatedPorousMediaFluidComponent {
 
         // other stuff 
         let number_of_temperature_nodes = self.inner_nodes + 2;
-        let q_fraction_per_node: f64 = 1.0/ number_of_temperature_nodes as f64;
-        let mut q_frac_arr: Array1<f64> = Array::default(number_of_temperature_nodes);
-        q_frac_arr.fill(q_fraction_per_node);
+        let q_frac_arr: Array1<f64> = self.axial_power_shape
+            .try_get_q_fraction_array(number_of_temperature_nodes)?;
 
         // then get the ambient temperature 
 
@@ -165,6 +166,8 @@ impl NonInsulatedPorousMediaFluidComponent {
         // axial connections 
 
         self.zero_power_bc_connection();
+
+        Ok(())
     }
     /// obtains air to steel shell conductance
     /// for ciet heater v2
@@ -333,16 +336,24 @@ impl NonInsulatedPorousMediaFluidComponent {
 
 
 
+        // fouling is added in series with convection on the 
+        // therminol side
         let therminol_steel_conductance_interaction: HeatTransferInteractionType
         = HeatTransferInteractionType::
-            CylindricalConductionConvectionLiquidInside(
-                (SolidMaterial::SteelSS304L.into(), 
-                    (cylinder_mid_diameter - id).into(),
-                    steel_surf_temperature,
-                    atmospheric_pressure),
-                (h_to_therminol,
-                    id.into(),
-                    node_length.into())
+            CylindricalConductionConvectionWithFouling(
+                DataCylindricalConductionConvectionFouling {
+                    solid_material: SolidMaterial::SteelSS304L.into(),
+                    solid_temperature: steel_surf_temperature,
+                    solid_pressure: atmospheric_pressure,
+                    heat_transfer_coeff: h_to_therminol,
+                    inner_diameter: id.into(),
+                    outer_diameter: cylinder_mid_diameter.into(),
+                    cylinder_length: node_length.into(),
+                    solid_liquid_arrangement: 
+                        CylindricalAndSphericalSolidFluidArrangement::
+                        FluidOnInnerSurfaceOfSolidShell,
+                    fouling_resistance: self.fluid_to_pipe_shell_fouling,
+                }
             );
 
         // now based on conductance interaction, 
@@ -572,9 +583,11 @@ impl NonInsulatedPorousMediaFluidComponent {
         = h * heat_transfer_area_per_node;
 
         // note that there is nearly no thermal resistance for 
-        // the inner portion
+        // the inner portion, only fouling is added in series
 
-        return average_node_conductance;
+        return self.fluid_to_interior_fouling.add_in_series(
+            average_node_conductance, 
+            heat_transfer_area_per_node);
     }
 
     /// spawns a thread and moves the clone of the entire heater object into the 
@@ -597,7 +610,7 @@ impl NonInsulatedPorousMediaFluidComponent {
                 heater_clone.
                     ciet_heater_v2_lateral_and_miscellaneous_connections(
                         mass_flowrate,
-                        heater_steady_state_power);
+                        heater_steady_state_power).unwrap();
                 
                 heater_clone
 
@@ -10,6 +10,7 @@ use crate::boundary_conditions::BCType;
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
 use crate::tuas_lib_error::TuasLibError;
+use crate::pre_built_components::axial_power_shape::AxialPowerShape;
 
 use super::NonInsulatedPorousMediaFluidComponent;
 use uom::ConstZero;
@@ -19,6 +20,18 @@ use ndarray::*;
 
 impl NonInsulatedPorousMediaFluidComponent {
 
+    /// sets the axial shape of the heater power along the component,
+    /// returning an error if the shape is not valid, eg. a chopped 
+    /// cosine with an extrapolated length shorter than the component
+    pub fn try_set_axial_power_shape(&mut self,
+        axial_power_shape: AxialPowerShape) -> Result<(), TuasLibError>{
+
+        axial_power_shape.try_validate()?;
+        self.axial_power_shape = axial_power_shape;
+
+        Ok(())
+    }
+
     /// NonInsulatedPorousMediaFluidComponent config:
     ///
     /// Firstly with insulation:
@@ -126,12 +139,11 @@ impl NonInsulatedPorousMediaFluidComponent {
             // after this, we are done for the internal connections
 
             // now, add power arrays
-            // assume even power distribution (this can be changed 
-            // in future)
+            // distributed according to the axial power shape, 
+            // which is uniform by default
             let number_of_temperature_nodes = self.inner_nodes + 2;
-            let q_fraction_per_node: f64 = 1.0/ number_of_temperature_nodes as f64;
-            let mut q_frac_arr: Array1<f64> = Array::default(number_of_temperature_nodes);
-            q_frac_arr.fill(q_fraction_per_node);
+            let q_frac_arr: Array1<f64> = self.axial_power_shape
+                .try_get_q_fraction_array(number_of_temperature_nodes)?;
 
             pipe_shell_clone.lateral_link_new_power_vector(
                 shell_side_steady_state_power,
@@ -469,6 +481,12 @@ impl NonInsulatedPorousMediaFluidComponent {
             = (tube_h_to_fluid * self.convection_heat_transfer_area_to_interior)
             / number_of_temperature_nodes;
 
+        // fouling is added in series with convection on the fluid side
+        let nodalised_fluid_side_conductance: ThermalConductance 
+            = self.fluid_to_interior_fouling.add_in_series(
+                nodalised_fluid_side_conductance,
+                self.convection_heat_transfer_area_to_interior / number_of_temperature_nodes);
+
         
         // now solid side nodalised conductance 
         // with large enough conductance lengthscale (eg 1e9 meters)
@@ -674,6 +692,12 @@ impl NonInsulatedPorousMediaFluidComponent {
             = (tube_h_to_fluid * self.convection_heat_transfer_area_to_pipe)
             / number_of_temperature_nodes;
 
+        // fouling is added in series with convection on the fluid side
+        let nodalised_fluid_side_conductance: ThermalConductance 
+            = self.fluid_to_pipe_shell_fouling.add_in_series(
+                nodalised_fluid_side_conductance,
+                self.convection_heat_transfer_area_to_pipe / number_of_temperature_nodes);
+
         
         // now solid side nodalised conductance 
         let nodalised_solid_side_conductance: ThermalConductance 
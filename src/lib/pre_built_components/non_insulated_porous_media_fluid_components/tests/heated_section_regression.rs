@@ -143,7 +143,7 @@ pub fn example_heated_section_regression_new_and_old(){
         heater_v2_bare_original.
             ciet_heater_v2_lateral_and_miscellaneous_connections(
                 mass_flowrate,
-                heater_power);
+                heater_power).unwrap();
 
 
         heater_v2_bare_original.advance_timestep(timestep);
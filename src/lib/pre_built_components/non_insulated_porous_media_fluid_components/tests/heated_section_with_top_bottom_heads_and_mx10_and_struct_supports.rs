@@ -381,7 +381,7 @@ pub fn heater_plus_mx_10_with_supports(){
                     structural_support_heater_bottom_head.lateral_connection_thread_spawn();
 
                     structural_support_mx_10.
-                        lateral_and_miscellaneous_connections();
+                        lateral_and_miscellaneous_connections().unwrap();
 
                     structural_support_heater_top_head = 
                         struct_support_top_head_join_handle.join().unwrap();
@@ -11,6 +11,7 @@ use crate::boundary_conditions::BCType;
 use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
 use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::{DataUserSpecifiedConvectionResistance, HeatTransferInteractionType};
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
+use crate::heat_transfer_correlations::external_natural_convection::AmbientNaturalConvection;
 
 use crate::tuas_lib_error::TuasLibError;
 
@@ -220,6 +221,32 @@ impl SolidStructure {
     }
 
 
+    /// obtains the heat transfer coefficient from the structure 
+    /// to ambient air using a natural convection (and radiation) 
+    /// model, the structure bulk temperature is taken as the 
+    /// surface temperature
+    ///
+    /// the result can be supplied to 
+    /// get_ambient_surroundings_to_cylinder_thermal_conductance or 
+    /// get_ambient_surroundings_to_hollow_cylinder_thermal_conductance
+    /// in place of a constant heat transfer coefficient
+    #[inline]
+    pub fn try_get_natural_convection_heat_transfer_to_ambient(&self,
+    ambient_natural_convection: &AmbientNaturalConvection,
+    ambient_temp: ThermodynamicTemperature) 
+        -> Result<HeatTransfer,TuasLibError> {
+
+        let mut structure_clone: SolidColumn = 
+        self.solid_array.clone().try_into()?;
+
+        let structure_temperature: ThermodynamicTemperature 
+        = structure_clone.try_get_bulk_temperature()?;
+
+        ambient_natural_convection.try_get_heat_transfer_coefficient(
+            structure_temperature, 
+            ambient_temp)
+    }
+
     /// obtains ambient (usually air) to structure conductance
     ///
     /// this conductance assumes that we transfer heat to a cylinder 
@@ -5,6 +5,9 @@ use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component::FluidComponent;
 
+use crate::heat_transfer_correlations::shell_side_correlations::ShellSideFluidProperties;
+use crate::tuas_lib_error::TuasLibError;
+
 use super::SimpleShellAndTubeHeatExchanger;
 
 impl SimpleShellAndTubeHeatExchanger {
@@ -175,4 +178,39 @@ impl SimpleShellAndTubeHeatExchanger {
         thermal_conductivity_shell_side_fluid
     }
 
+    /// returns the shell side pressure drop predicted by the 
+    /// baffled shell side model (Kern or Bell-Delaware) at the 
+    /// current shell side mass flowrate and bulk temperature
+    ///
+    /// this is meant for design estimates, the pressure change in 
+    /// loop calculations still comes from the loss correlation of 
+    /// the shell side fluid array
+    pub fn try_get_baffled_shell_side_pressure_drop(&self) -> 
+        Result<Pressure, TuasLibError> {
+
+        let baffle_model = match self.shell_side_baffle_model {
+            Some(baffle_model) => baffle_model,
+            None => return Err(TuasLibError::GenericStringError(
+                "shell side baffle model is not set".to_owned())),
+        };
+
+        let mut shell_side_fluid_array: FluidArray = 
+            self.shell_side_fluid_array.clone().try_into()?;
+
+        let shell_side_temperature: ThermodynamicTemperature = 
+            shell_side_fluid_array.try_get_bulk_temperature()?;
+
+        let shell_fluid_material: LiquidMaterial = 
+            shell_side_fluid_array.material_control_volume.try_into()?;
+
+        let shell_side_fluid_properties = ShellSideFluidProperties::try_new(
+            shell_fluid_material, 
+            shell_side_temperature, 
+            None)?;
+
+        baffle_model.try_get_pressure_drop(
+            shell_side_fluid_array.get_mass_flowrate(), 
+            &shell_side_fluid_properties)
+    }
+
 }
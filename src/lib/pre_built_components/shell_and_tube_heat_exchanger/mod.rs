@@ -128,6 +128,8 @@ use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_
 use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
 
 use uom::si::f64::*;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
+use crate::heat_transfer_correlations::shell_side_correlations::BaffledShellSideModel;
 
 
 /// Single pass, no baffle, parrallel flow shell and tube 
@@ -233,6 +235,20 @@ pub struct SimpleShellAndTubeHeatExchanger {
     /// the shell side
     pub insulation_thickness: Length,
 
+    /// fouling resistance on the tube side, acting over the 
+    /// inner surface of the tubes. Clean by default
+    pub tube_side_fouling: InterfaceThermalResistance,
+
+    /// fouling resistance on the shell side, acting over the 
+    /// outer surface of the tubes. Clean by default
+    pub shell_side_fouling: InterfaceThermalResistance,
+
+    /// optional baffled shell side model (Kern or Bell-Delaware).
+    /// If set, the shell side fluid to tubes heat transfer 
+    /// coefficient is obtained from the baffle geometry instead of 
+    /// shell_side_nusselt_correlation_to_tubes. None by default
+    pub shell_side_baffle_model: Option<BaffledShellSideModel>,
+
 
 }
 
@@ -417,6 +433,9 @@ impl SimpleShellAndTubeHeatExchanger {
                 shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
                 tube_side_nusselt_correlation: tube_side_nusselt_correlation.clone(), 
                 insulation_thickness,
+                tube_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+                shell_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+                shell_side_baffle_model: None,
             };
 
         sthe
@@ -739,6 +758,9 @@ impl SimpleShellAndTubeHeatExchanger {
                 shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
                 tube_side_nusselt_correlation: tube_side_nusselt_correlation.clone(), 
                 insulation_thickness: dummy_insulation_thickness,
+                tube_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+                shell_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+                shell_side_baffle_model: None,
             };
 
         du_heat_exchanger
@@ -15,10 +15,12 @@ use crate::boussinesq_thermophysical_properties::SolidMaterial;
 use crate::boundary_conditions::BCType;
 use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
 use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::{CylindricalAndSphericalSolidFluidArrangement, DataCylindricalConductionConvectionFouling};
 use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
 use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
 use crate::array_control_vol_and_fluid_component_collections::fluid_component_collection::fluid_component_traits::FluidComponentTrait;
 
+use crate::heat_transfer_correlations::shell_side_correlations::ShellSideFluidProperties;
 use crate::tuas_lib_error::TuasLibError;
 
 // preprocessing is where heat transfer entities 
@@ -725,16 +727,24 @@ impl SimpleShellAndTubeHeatExchanger {
 
 
 
+        // tube side fouling is added in series with the convection 
+        // resistance at the tube inner surface
         let fluid_pipe_shell_conductance_interaction: HeatTransferInteractionType
             = HeatTransferInteractionType::
-            CylindricalConductionConvectionLiquidInside(
-                (solid_material.into(), 
-                 (cylinder_mid_diameter - id).into(),
-                 pipe_shell_surf_temperature,
-                 atmospheric_pressure),
-                 (tube_h_to_fluid,
-                  id.into(),
-                  node_length.into())
+            CylindricalConductionConvectionWithFouling(
+                DataCylindricalConductionConvectionFouling {
+                    solid_material: solid_material.into(),
+                    solid_temperature: pipe_shell_surf_temperature,
+                    solid_pressure: atmospheric_pressure,
+                    heat_transfer_coeff: tube_h_to_fluid,
+                    inner_diameter: id.into(),
+                    outer_diameter: cylinder_mid_diameter.into(),
+                    cylinder_length: node_length.into(),
+                    solid_liquid_arrangement: 
+                        CylindricalAndSphericalSolidFluidArrangement::
+                        FluidOnInnerSurfaceOfSolidShell,
+                    fouling_resistance: self.tube_side_fouling,
+                }
             );
 
         // now based on conductance interaction, 
@@ -824,129 +834,156 @@ impl SimpleShellAndTubeHeatExchanger {
         let viscosity: DynamicViscosity = 
             fluid_material.try_get_dynamic_viscosity(fluid_temperature)?;
 
-        // need to convert hydraulic diameter to an equivalent 
-        // spherical diameter
-        //
-        // but for now, I'm going to use Re and Nu using hydraulic diameter 
-        // and live with it for the time being
-        //
-        let reynolds_number_shell_side: Ratio = 
-            shell_side_mass_flowrate/
-            shell_side_cross_sectional_flow_area
-            *shell_side_fluid_hydraulic_diameter / viscosity;
+        let shell_h_to_fluid: HeatTransfer;
 
-        // the reynolds number here is used for nusselt number estimates 
-        // so I'm going to have an aboslute value of reynolds number 
-        // for nusselt estimates
+        if let Some(baffle_model) = self.shell_side_baffle_model {
+
+            // the baffled shell side model (Kern or Bell-Delaware) 
+            // obtains the heat transfer coefficient from the baffle 
+            // geometry, the wall temperature is only used for the 
+            // viscosity correction
+            let wall_temperature_for_viscosity_correction = 
+                if correct_prandtl_for_wall_temperatures {
+                    Some(wall_temperature)
+                } else {
+                    None
+                };
+
+            let shell_side_fluid_properties = 
+                ShellSideFluidProperties::try_new(
+                    fluid_material, 
+                    fluid_temperature, 
+                    wall_temperature_for_viscosity_correction)?;
+
+            shell_h_to_fluid = baffle_model.try_get_heat_transfer_coeff(
+                shell_side_mass_flowrate, 
+                &shell_side_fluid_properties)?;
 
-        let reynolds_number_abs_for_nusselt_estimate: Ratio 
-            = reynolds_number_shell_side.abs();
+        } else {
+
+            // need to convert hydraulic diameter to an equivalent 
+            // spherical diameter
+            //
+            // but for now, I'm going to use Re and Nu using hydraulic diameter 
+            // and live with it for the time being
+            //
+            let reynolds_number_shell_side: Ratio = 
+                shell_side_mass_flowrate/
+                shell_side_cross_sectional_flow_area
+                *shell_side_fluid_hydraulic_diameter / viscosity;
+
+            // the reynolds number here is used for nusselt number estimates 
+            // so I'm going to have an aboslute value of reynolds number 
+            // for nusselt estimates
+
+            let reynolds_number_abs_for_nusselt_estimate: Ratio 
+                = reynolds_number_shell_side.abs();
         
 
-        // next, bulk prandtl number 
+            // next, bulk prandtl number 
 
-        let bulk_prandtl_number: Ratio 
-            = fluid_material.try_get_prandtl_liquid(
-                fluid_temperature,
-                atmospheric_pressure
-            )?;
+            let bulk_prandtl_number: Ratio 
+                = fluid_material.try_get_prandtl_liquid(
+                    fluid_temperature,
+                    atmospheric_pressure
+                )?;
 
 
 
-        let shell_side_fluid_to_inner_tube_surf_nusselt_correlation: NusseltCorrelation
-            = self.shell_side_nusselt_correlation_to_tubes;
+            let shell_side_fluid_to_inner_tube_surf_nusselt_correlation: NusseltCorrelation
+                = self.shell_side_nusselt_correlation_to_tubes;
 
 
-        // now, for gnielinski type correlations, we require the 
-        // darcy friction factor
-        //
-        // However, the darcy friction factor for other components 
-        // will come in the form:
-        //
-        // (f_darcy L/D + K)
-        //
-        // the next best thing we can get is:
-        //
-        // (f_darcy + D/L  K)
+            // now, for gnielinski type correlations, we require the 
+            // darcy friction factor
+            //
+            // However, the darcy friction factor for other components 
+            // will come in the form:
+            //
+            // (f_darcy L/D + K)
+            //
+            // the next best thing we can get is:
+            //
+            // (f_darcy + D/L  K)
 
-        // (f_darcy L/D + K)
-        let fldk: Ratio = self
-            .shell_side_custom_component_loss_correlation
-            .fldk_based_on_darcy_friction_factor(reynolds_number_abs_for_nusselt_estimate)
-            .unwrap();
+            // (f_darcy L/D + K)
+            let fldk: Ratio = self
+                .shell_side_custom_component_loss_correlation
+                .fldk_based_on_darcy_friction_factor(reynolds_number_abs_for_nusselt_estimate)
+                .unwrap();
 
-        let length_to_diameter: Ratio = 
-            shell_side_fluid_array_clone.get_component_length_immutable()/
-            shell_side_fluid_hydraulic_diameter;
+            let length_to_diameter: Ratio = 
+                shell_side_fluid_array_clone.get_component_length_immutable()/
+                shell_side_fluid_hydraulic_diameter;
 
-        // (f_darcy + D/L  K)
-        // then let's scale it by length to diameter 
-        let modified_darcy_friction_factor: Ratio = 
-            fldk/length_to_diameter;
+            // (f_darcy + D/L  K)
+            // then let's scale it by length to diameter 
+            let modified_darcy_friction_factor: Ratio = 
+                fldk/length_to_diameter;
 
-        // I need to use Nusselt correlations present in this struct 
-        //
-        // wall correction is optionally done here
-        //
-        // this uses the gnielinski correlation for pipes or tubes
+            // I need to use Nusselt correlations present in this struct 
+            //
+            // wall correction is optionally done here
+            //
+            // this uses the gnielinski correlation for pipes or tubes
 
-        let nusselt_estimate_shell: Ratio;
+            let nusselt_estimate_shell: Ratio;
 
-        if correct_prandtl_for_wall_temperatures {
+            if correct_prandtl_for_wall_temperatures {
 
-            // then wall prandtl number
-            // if the number falls outside the range of correlations,
-            // then use the prandtl number at the max or min 
+                // then wall prandtl number
+                // if the number falls outside the range of correlations,
+                // then use the prandtl number at the max or min 
 
-            let mut wall_temperature_estimate = wall_temperature;
+                let mut wall_temperature_estimate = wall_temperature;
 
-            if wall_temperature_estimate > fluid_material.max_temperature() {
+                if wall_temperature_estimate > fluid_material.max_temperature() {
 
-                wall_temperature_estimate = fluid_material.max_temperature();
+                    wall_temperature_estimate = fluid_material.max_temperature();
 
-            } else if wall_temperature_estimate < fluid_material.min_temperature() {
+                } else if wall_temperature_estimate < fluid_material.min_temperature() {
 
-                wall_temperature_estimate = fluid_material.min_temperature();
+                    wall_temperature_estimate = fluid_material.min_temperature();
 
-            }
+                }
 
 
-            let wall_prandtl_number: Ratio 
-                = fluid_material.try_get_prandtl_liquid(
-                    wall_temperature_estimate,
-                    atmospheric_pressure
-                )?;
+                let wall_prandtl_number: Ratio 
+                    = fluid_material.try_get_prandtl_liquid(
+                        wall_temperature_estimate,
+                        atmospheric_pressure
+                    )?;
 
-            nusselt_estimate_shell = shell_side_fluid_to_inner_tube_surf_nusselt_correlation.
-            estimate_based_on_prandtl_darcy_and_reynolds_wall_correction(
-                bulk_prandtl_number, 
-                wall_prandtl_number,
-                modified_darcy_friction_factor,
-                reynolds_number_abs_for_nusselt_estimate)?;
+                nusselt_estimate_shell = shell_side_fluid_to_inner_tube_surf_nusselt_correlation.
+                estimate_based_on_prandtl_darcy_and_reynolds_wall_correction(
+                    bulk_prandtl_number, 
+                    wall_prandtl_number,
+                    modified_darcy_friction_factor,
+                    reynolds_number_abs_for_nusselt_estimate)?;
 
-        } else {
-            nusselt_estimate_shell = shell_side_fluid_to_inner_tube_surf_nusselt_correlation.
-            estimate_based_on_prandtl_darcy_and_reynolds_no_wall_correction(
-                bulk_prandtl_number, 
-                modified_darcy_friction_factor,
-                reynolds_number_abs_for_nusselt_estimate)?;
+            } else {
+                nusselt_estimate_shell = shell_side_fluid_to_inner_tube_surf_nusselt_correlation.
+                estimate_based_on_prandtl_darcy_and_reynolds_no_wall_correction(
+                    bulk_prandtl_number, 
+                    modified_darcy_friction_factor,
+                    reynolds_number_abs_for_nusselt_estimate)?;
 
-        }
+            }
 
-        // for debugging
-        //dbg!(&nusselt_estimate_shell);
+            // for debugging
+            //dbg!(&nusselt_estimate_shell);
 
 
 
-        // now we can get the heat transfer coeff, 
+            // now we can get the heat transfer coeff, 
 
-        let shell_h_to_fluid: HeatTransfer;
+            let k_fluid_average: ThermalConductivity = 
+                fluid_material.try_get_thermal_conductivity(
+                    fluid_temperature)?;
 
-        let k_fluid_average: ThermalConductivity = 
-            fluid_material.try_get_thermal_conductivity(
-                fluid_temperature)?;
+            shell_h_to_fluid = nusselt_estimate_shell * k_fluid_average / shell_side_fluid_hydraulic_diameter;
 
-        shell_h_to_fluid = nusselt_estimate_shell * k_fluid_average / shell_side_fluid_hydraulic_diameter;
+        }
 
 
         // and then get the convective resistance from shell side fluid 
@@ -970,16 +1007,25 @@ impl SimpleShellAndTubeHeatExchanger {
 
         // conductance calculations assumes a cylinder with 
         // liquid on the outside, solid on the inside
+        //
+        // shell side fouling is added in series with the convection 
+        // resistance at the tube outer surface
         let shell_fluid_to_inner_tube_surf_conductance_interaction: HeatTransferInteractionType
             = HeatTransferInteractionType::
-            CylindricalConductionConvectionLiquidOutside(
-                (solid_material.into(), 
-                 (od - cylinder_mid_diameter).into(),
-                 pipe_shell_surf_temperature,
-                 atmospheric_pressure),
-                 (shell_h_to_fluid,
-                  od.into(),
-                  node_length.into())
+            CylindricalConductionConvectionWithFouling(
+                DataCylindricalConductionConvectionFouling {
+                    solid_material: solid_material.into(),
+                    solid_temperature: pipe_shell_surf_temperature,
+                    solid_pressure: atmospheric_pressure,
+                    heat_transfer_coeff: shell_h_to_fluid,
+                    inner_diameter: cylinder_mid_diameter.into(),
+                    outer_diameter: od.into(),
+                    cylinder_length: node_length.into(),
+                    solid_liquid_arrangement: 
+                        CylindricalAndSphericalSolidFluidArrangement::
+                        FluidOnOuterSurfaceOfSolidShell,
+                    fouling_resistance: self.shell_side_fouling,
+                }
             );
 
         // now based on conductance interaction, 
@@ -0,0 +1,102 @@
+/// when a baffled shell side model is set, the shell side 
+/// fluid to tube conductance should use the heat transfer 
+/// coefficient from the baffle geometry instead of the shell side 
+/// nusselt correlation
+///
+/// the conductance is checked against convection over the outer 
+/// surface of one node of a single tube in series with conduction 
+/// through the outer half of the tube wall
+#[test]
+pub fn kern_shell_side_model_sets_shell_side_conductance(){
+
+    use uom::si::f64::*;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::atmosphere;
+    use uom::si::ratio::ratio;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::array_control_vol_and_fluid_component_collections::one_d_solid_array_with_lateral_coupling::SolidColumn;
+    use crate::boussinesq_thermophysical_properties::{LiquidMaterial, SolidMaterial};
+    use crate::heat_transfer_correlations::heat_transfer_interactions::get_conductance_single_cylindrical_radial_solid_liquid;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::CylindricalAndSphericalSolidFluidArrangement;
+    use crate::heat_transfer_correlations::shell_side_correlations::{BaffledShellGeometry, BaffledShellSideModel, ShellSideFluidProperties, TubeLayout};
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+
+    let mut sthe = SimpleShellAndTubeHeatExchanger::new_du_et_al_sthe();
+    let shell_side_mass_flowrate = MassRate::new::<kilogram_per_second>(0.5);
+    sthe.set_shell_side_total_mass_flowrate(shell_side_mass_flowrate);
+
+    // without a baffle model, there is no baffled pressure drop
+    assert!(sthe.try_get_baffled_shell_side_pressure_drop().is_err());
+
+    let nusselt_based_conductance: ThermalConductance = sthe
+        .get_shell_side_fluid_to_single_inner_pipe_shell_nodal_conductance(
+            false).unwrap();
+
+    let geometry = BaffledShellGeometry::new_with_typical_clearances(
+        sthe.shell_side_id,
+        sthe.tube_side_od,
+        Length::new::<millimeter>(19.0),
+        TubeLayout::Triangular30,
+        sthe.number_of_tubes,
+        Length::new::<meter>(1.95),
+        Length::new::<meter>(0.15),
+        Ratio::new::<ratio>(0.25));
+    let baffle_model = BaffledShellSideModel::new_kern(geometry);
+
+    sthe.shell_side_baffle_model = Some(baffle_model);
+
+    let baffle_model_conductance: ThermalConductance = sthe
+        .get_shell_side_fluid_to_single_inner_pipe_shell_nodal_conductance(
+            false).unwrap();
+
+    assert_ne!(baffle_model_conductance, nusselt_based_conductance);
+
+    // expected conductance
+    let mut shell_side_fluid_array: FluidArray = 
+        sthe.shell_side_fluid_array.clone().try_into().unwrap();
+    let mut pipe_shell: SolidColumn = 
+        sthe.inner_pipe_shell_array_for_single_tube.clone().try_into().unwrap();
+
+    let fluid_temperature = shell_side_fluid_array.try_get_bulk_temperature().unwrap();
+    let wall_temperature = pipe_shell.try_get_bulk_temperature().unwrap();
+
+    let fluid_properties = ShellSideFluidProperties::try_new(
+        LiquidMaterial::HITEC, fluid_temperature, None).unwrap();
+    let shell_side_htc = baffle_model.try_get_heat_transfer_coeff(
+        shell_side_mass_flowrate, &fluid_properties).unwrap();
+
+    let node_length: Length = 
+        shell_side_fluid_array.get_component_length_immutable()
+        / (sthe.inner_nodes + 2) as f64;
+    let tube_mid_diameter: Length = 0.5 * (sthe.tube_side_id + sthe.tube_side_od);
+
+    let expected_conductance: ThermalConductance = 
+        get_conductance_single_cylindrical_radial_solid_liquid(
+            SolidMaterial::SteelSS304L.into(),
+            wall_temperature,
+            Pressure::new::<atmosphere>(1.0),
+            shell_side_htc,
+            tube_mid_diameter.into(),
+            sthe.tube_side_od.into(),
+            node_length.into(),
+            CylindricalAndSphericalSolidFluidArrangement::
+            FluidOnOuterSurfaceOfSolidShell).unwrap();
+
+    approx::assert_relative_eq!(
+        baffle_model_conductance.get::<watt_per_kelvin>(),
+        expected_conductance.get::<watt_per_kelvin>(),
+        max_relative=1e-10
+        );
+
+    // the baffled pressure drop is the same as that of the model 
+    // at the shell side flowrate and bulk temperature
+    let expected_pressure_drop = baffle_model.try_get_pressure_drop(
+        shell_side_mass_flowrate, &fluid_properties).unwrap();
+
+    assert_eq!(sthe.try_get_baffled_shell_side_pressure_drop().unwrap(),
+        expected_pressure_drop);
+
+}
@@ -27,6 +27,7 @@ pub fn overall_htc_postprocessing_basic_test_shell_and_tube_heat_exchanger(){
     use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
     use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
     use uom::si::f64::*;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
     let number_of_tubes = 1_u32;
     let fluid_pressure = Pressure::new::<atmosphere>(1.0);
     let solid_pressure = Pressure::new::<atmosphere>(1.0);
@@ -190,6 +191,9 @@ pub fn overall_htc_postprocessing_basic_test_shell_and_tube_heat_exchanger(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation, 
             insulation_thickness: dummy_insulation_thickness,
+            tube_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_baffle_model: None,
         };
 
     let correct_for_prandtl_wall_temperatures = true;
@@ -248,6 +252,7 @@ pub fn shell_side_tube_bundle_area_basic_test_shell_and_tube_heat_exchanger(){
     use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
     use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
     use uom::si::f64::*;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
     let number_of_tubes = 1_u32;
     let fluid_pressure = Pressure::new::<atmosphere>(1.0);
     let solid_pressure = Pressure::new::<atmosphere>(1.0);
@@ -410,6 +415,9 @@ pub fn shell_side_tube_bundle_area_basic_test_shell_and_tube_heat_exchanger(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation, 
             insulation_thickness: dummy_insulation_thickness,
+            tube_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_baffle_model: None,
         };
 
 
@@ -25,6 +25,7 @@ pub fn du_heat_exchanger_constructor_test(){
     use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
 
     use uom::si::f64::*;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
     let number_of_tubes = 19_u32;
     let fluid_pressure = Pressure::new::<atmosphere>(1.0);
     let solid_pressure = Pressure::new::<atmosphere>(1.0);
@@ -234,6 +235,9 @@ pub fn du_heat_exchanger_constructor_test(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation: tube_side_nusselt_correlation.clone(), 
             insulation_thickness: dummy_insulation_thickness,
+            tube_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_baffle_model: None,
         };
 
     let du_heat_exchanger_test = 
@@ -272,6 +276,7 @@ pub fn insulated_sthe_constructor_test(){
     use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
 
     use uom::si::f64::*;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
     let number_of_tubes = 19_u32;
     let fluid_pressure = Pressure::new::<atmosphere>(1.0);
     let solid_pressure = Pressure::new::<atmosphere>(1.0);
@@ -490,6 +495,9 @@ pub fn insulated_sthe_constructor_test(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation: tube_side_nusselt_correlation.clone(), 
             insulation_thickness: dummy_insulation_thickness,
+            tube_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_baffle_model: None,
         };
 
     let sthe_length = pipe_length;
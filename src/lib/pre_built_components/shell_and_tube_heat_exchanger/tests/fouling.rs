@@ -0,0 +1,98 @@
+/// tube side fouling is added in series with the tube side 
+/// convection resistance over the tube inner surface of one node
+///
+/// so the difference between the fouled and clean nodal 
+/// resistances should be:
+///
+/// R_f / (PI * tube_id * node_length)
+#[test]
+pub fn tube_side_fouling_adds_series_resistance(){
+
+    use std::f64::consts::PI;
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::thermal_conductance::watt_per_kelvin;
+
+    use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+
+    let fouling_factor = 0.0002;
+
+    let mut clean_sthe = SimpleShellAndTubeHeatExchanger::new_du_et_al_sthe();
+    let mut fouled_sthe = clean_sthe.clone();
+    fouled_sthe.tube_side_fouling = 
+        InterfaceThermalResistance::new_fouling_factor(fouling_factor).unwrap();
+
+    let clean_conductance: ThermalConductance = clean_sthe
+        .get_single_tube_side_fluid_array_node_to_inner_pipe_shell_nodal_conductance(
+            false).unwrap();
+    let fouled_conductance: ThermalConductance = fouled_sthe
+        .get_single_tube_side_fluid_array_node_to_inner_pipe_shell_nodal_conductance(
+            false).unwrap();
+
+    let tube_side_fluid_array: FluidArray = 
+        clean_sthe.tube_side_fluid_array_for_single_tube.clone()
+        .try_into().unwrap();
+    let node_length: Length = 
+        tube_side_fluid_array.get_component_length_immutable()
+        / (clean_sthe.inner_nodes + 2) as f64;
+
+    let expected_fouling_resistance = fouling_factor / 
+        (PI * clean_sthe.tube_side_id.get::<meter>() * node_length.get::<meter>());
+
+    approx::assert_relative_eq!(
+        1.0/fouled_conductance.get::<watt_per_kelvin>()
+        - 1.0/clean_conductance.get::<watt_per_kelvin>(),
+        expected_fouling_resistance,
+        max_relative=1e-9
+        );
+
+    // a clean heat exchanger should give the same conductance 
+    // whether or not the fouling field is explicitly set to 
+    // perfect contact
+    fouled_sthe.tube_side_fouling = InterfaceThermalResistance::new_perfect_contact();
+    let reset_conductance: ThermalConductance = fouled_sthe
+        .get_single_tube_side_fluid_array_node_to_inner_pipe_shell_nodal_conductance(
+            false).unwrap();
+
+    assert_eq!(reset_conductance, clean_conductance);
+
+}
+
+/// shell side fouling acts over the outer surface of the tubes, 
+/// so it lowers the shell side fluid to tube conductance 
+/// but leaves the tube side conductance alone
+#[test]
+pub fn shell_side_fouling_only_affects_shell_side(){
+
+    use uom::si::f64::*;
+
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
+    use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+
+    let mut clean_sthe = SimpleShellAndTubeHeatExchanger::new_du_et_al_sthe();
+    let mut fouled_sthe = clean_sthe.clone();
+    fouled_sthe.shell_side_fouling = 
+        InterfaceThermalResistance::new_fouling_factor(0.0005).unwrap();
+
+    let clean_shell_side: ThermalConductance = clean_sthe
+        .get_shell_side_fluid_to_single_inner_pipe_shell_nodal_conductance(
+            false).unwrap();
+    let fouled_shell_side: ThermalConductance = fouled_sthe
+        .get_shell_side_fluid_to_single_inner_pipe_shell_nodal_conductance(
+            false).unwrap();
+
+    assert!(fouled_shell_side < clean_shell_side);
+
+    let clean_tube_side: ThermalConductance = clean_sthe
+        .get_single_tube_side_fluid_array_node_to_inner_pipe_shell_nodal_conductance(
+            false).unwrap();
+    let fouled_tube_side: ThermalConductance = fouled_sthe
+        .get_single_tube_side_fluid_array_node_to_inner_pipe_shell_nodal_conductance(
+            false).unwrap();
+
+    assert_eq!(clean_tube_side, fouled_tube_side);
+
+}
@@ -40,6 +40,7 @@ pub fn basic_test_shell_and_tube_heat_exchanger(){
     use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
     use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
     use uom::si::f64::*;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
     let number_of_tubes = 1_u32;
     let fluid_pressure = Pressure::new::<atmosphere>(1.0);
     let solid_pressure = Pressure::new::<atmosphere>(1.0);
@@ -202,6 +203,9 @@ pub fn basic_test_shell_and_tube_heat_exchanger(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation, 
             insulation_thickness: dummy_insulation_thickness,
+            tube_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_baffle_model: None,
         };
 
     //let correct_for_prandtl_wall_temperatures = true;
@@ -639,6 +643,7 @@ pub fn basic_test_shell_and_tube_heat_exchanger_set_two(){
     use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
     use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
     use uom::si::f64::*;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
     let number_of_tubes = 1_u32;
     let fluid_pressure = Pressure::new::<atmosphere>(1.0);
     let solid_pressure = Pressure::new::<atmosphere>(1.0);
@@ -801,6 +806,9 @@ pub fn basic_test_shell_and_tube_heat_exchanger_set_two(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation, 
             insulation_thickness: dummy_insulation_thickness,
+            tube_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_baffle_model: None,
         };
 
     //let correct_for_prandtl_wall_temperatures = true;
@@ -1237,6 +1245,7 @@ pub fn basic_test_shell_and_tube_heat_exchanger_set_three(){
     use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
     use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
     use uom::si::f64::*;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
     let number_of_tubes = 19_u32;
     let fluid_pressure = Pressure::new::<atmosphere>(1.0);
     let solid_pressure = Pressure::new::<atmosphere>(1.0);
@@ -1401,6 +1410,9 @@ pub fn basic_test_shell_and_tube_heat_exchanger_set_three(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation, 
             insulation_thickness: dummy_insulation_thickness,
+            tube_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_baffle_model: None,
         };
 
     //let correct_for_prandtl_wall_temperatures = true;
@@ -61,6 +61,7 @@ pub fn du_test_shell_and_tube_heat_exchanger_set_a(){
     use crate::heat_transfer_correlations::nusselt_number_correlations::pipe_correlations::custom_gnielinski_turbulent_nusselt_correlation;
 
     use uom::si::f64::*;
+    use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_geometry::InterfaceThermalResistance;
     let number_of_tubes = 19_u32;
     let fluid_pressure = Pressure::new::<atmosphere>(1.0);
     let solid_pressure = Pressure::new::<atmosphere>(1.0);
@@ -270,6 +271,9 @@ pub fn du_test_shell_and_tube_heat_exchanger_set_a(){
             shell_side_nusselt_correlation_parasitic: shell_side_nusselt_correlation_to_outer_shell, 
             tube_side_nusselt_correlation: tube_side_nusselt_correlation.clone(), 
             insulation_thickness: dummy_insulation_thickness,
+            tube_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_fouling: InterfaceThermalResistance::new_perfect_contact(),
+            shell_side_baffle_model: None,
         };
 
     // from data, is set A1 
@@ -22,3 +22,9 @@ pub mod constructor_tests;
 
 /// calibration function tests 
 pub mod calibration_functions;
+
+/// tube side and shell side fouling resistance tests
+pub mod fouling;
+
+/// baffled shell side model (Kern and Bell-Delaware) tests
+pub mod baffled_shell_side;
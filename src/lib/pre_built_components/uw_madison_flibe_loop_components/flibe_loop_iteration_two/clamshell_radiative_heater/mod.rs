@@ -44,7 +44,8 @@ pub struct ClamshellRadiativeHeater {
     /// it is coupled to the pipe shell and radiative heating element
     /// via a Nusselt Number based
     /// thermal resistance, this must be specified by the user 
-    /// this is usually air
+    /// this is usually air, which can be modelled using 
+    /// LiquidMaterial::Air as an ideal gas
     pub annular_air_array: HeatTransferEntity,
 
     /// this HeatTransferEntity represents the 
@@ -0,0 +1,611 @@
+use std::panic::AssertUnwindSafe;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use uom::si::angle::radian;
+use uom::si::area::square_meter;
+use uom::si::f64::*;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::length::meter;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::power::watt;
+use uom::si::pressure::pascal;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::time::second;
+use uom::ConstZero;
+
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::fluid_component_calculation::DimensionlessDarcyLossCorrelations;
+use crate::array_control_vol_and_fluid_component_collections::one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::boundary_conditions::BCType;
+use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
+use crate::heat_transfer_correlations::nusselt_number_correlations::enums::NusseltCorrelation;
+use crate::heat_transfer_correlations::nusselt_number_correlations::input_structs::GnielinskiData;
+use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+use crate::pre_built_components::insulated_pipes_and_fluid_components::InsulatedFluidComponent;
+use crate::pre_built_components::shell_and_tube_heat_exchanger::SimpleShellAndTubeHeatExchanger;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::{to_py_err, try_catch_panic, PyLiquidMaterial, PySolidMaterial};
+
+/// temperatures of the nodes of a fluid or solid array in K
+fn try_get_temperatures_kelvin(entity: &mut HeatTransferEntity)
+    -> Result<Vec<f64>, TuasLibError> {
+    Ok(entity.get_temperature_vector()?
+        .iter()
+        .map(|temperature| temperature.get::<kelvin>())
+        .collect())
+}
+
+/// liquid within a fluid array
+fn try_get_fluid_array_liquid(entity: &HeatTransferEntity)
+    -> Result<LiquidMaterial, TuasLibError> {
+    let fluid_array: FluidArray = entity.clone().try_into()?;
+    fluid_array.material_control_volume.try_into()
+}
+
+fn check_mass_flowrate(mass_flowrate: f64) -> PyResult<MassRate> {
+    if mass_flowrate.is_nan() || mass_flowrate < 0.0 {
+        return Err(PyValueError::new_err(format!(
+            "mass flowrate must be zero or positive, got {} kg/s", mass_flowrate)));
+    }
+    Ok(MassRate::new::<kilogram_per_second>(mass_flowrate))
+}
+
+fn check_timestep(timestep: f64) -> PyResult<Time> {
+    if timestep.is_nan() || timestep <= 0.0 {
+        return Err(PyValueError::new_err(format!(
+            "timestep must be positive, got {} s", timestep)));
+    }
+    Ok(Time::new::<second>(timestep))
+}
+
+/// insulated pipe (or other fluid component) with fluid flowing
+/// from a constant temperature inlet at the back to an adiabatic
+/// outlet at the front
+///
+/// set inlet_temperature (K), mass_flowrate (kg/s) and
+/// heater_power (W), then call advance_timestep(timestep) with the
+/// timestep in seconds. Lengths are in m, areas in m2, angles in
+/// rad and heat transfer coefficients in W/(m2 K)
+#[pyclass(name = "InsulatedFluidComponent", module = "tuas_python", skip_from_py_object)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PyInsulatedFluidComponent {
+    /// the component being simulated
+    pub component: InsulatedFluidComponent,
+    /// the liquid flowing through the component
+    pub fluid: LiquidMaterial,
+    /// inlet temperature (K)
+    #[pyo3(get, set)]
+    pub inlet_temperature: f64,
+    /// mass flowrate from back to front (kg/s)
+    #[pyo3(get, set)]
+    pub mass_flowrate: f64,
+    /// power dumped into the pipe shell (W)
+    #[pyo3(get, set)]
+    pub heater_power: f64,
+    /// whether the Nusselt number is corrected for the wall Prandtl
+    /// number
+    #[pyo3(get, set)]
+    pub correct_prandtl_for_wall_temperatures: bool,
+    /// simulated time (s)
+    #[pyo3(get)]
+    pub simulation_time: f64,
+}
+
+#[pymethods]
+impl PyInsulatedFluidComponent {
+
+    /// builds an insulated pipe, the inlet starts at the initial
+    /// temperature with no flow and no heating
+    #[new]
+    #[pyo3(signature = (initial_temperature, ambient_temperature,
+        flow_area, incline_angle, form_loss, shell_id, shell_od,
+        insulation_thickness, pipe_length, hydraulic_diameter,
+        pipe_shell_material, insulation_material, pipe_fluid,
+        htc_to_ambient, inner_nodes, surface_roughness,
+        fluid_pressure = 101325.0, solid_pressure = 101325.0))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn py_new(initial_temperature: f64,
+        ambient_temperature: f64,
+        flow_area: f64,
+        incline_angle: f64,
+        form_loss: f64,
+        shell_id: f64,
+        shell_od: f64,
+        insulation_thickness: f64,
+        pipe_length: f64,
+        hydraulic_diameter: f64,
+        pipe_shell_material: PyRef<'_, PySolidMaterial>,
+        insulation_material: PyRef<'_, PySolidMaterial>,
+        pipe_fluid: PyRef<'_, PyLiquidMaterial>,
+        htc_to_ambient: f64,
+        inner_nodes: usize,
+        surface_roughness: f64,
+        fluid_pressure: f64,
+        solid_pressure: f64) -> PyResult<Self> {
+
+        if !(shell_od > shell_id && shell_id > 0.0 && insulation_thickness > 0.0
+            && pipe_length > 0.0 && hydraulic_diameter > 0.0 && flow_area > 0.0) {
+            return Err(PyValueError::new_err(
+                "lengths and areas must be positive, and the shell od larger than the shell id"));
+        }
+
+        let component = InsulatedFluidComponent::new_insulated_pipe(
+            ThermodynamicTemperature::new::<kelvin>(initial_temperature),
+            ThermodynamicTemperature::new::<kelvin>(ambient_temperature),
+            Pressure::new::<pascal>(fluid_pressure),
+            Pressure::new::<pascal>(solid_pressure),
+            Area::new::<square_meter>(flow_area),
+            Angle::new::<radian>(incline_angle),
+            Ratio::new::<ratio>(form_loss),
+            Length::new::<meter>(shell_id),
+            Length::new::<meter>(shell_od),
+            Length::new::<meter>(insulation_thickness),
+            Length::new::<meter>(pipe_length),
+            Length::new::<meter>(hydraulic_diameter),
+            pipe_shell_material.solid,
+            insulation_material.solid,
+            pipe_fluid.liquid,
+            HeatTransfer::new::<watt_per_square_meter_kelvin>(htc_to_ambient),
+            inner_nodes,
+            Length::new::<meter>(surface_roughness));
+
+        Ok(Self {
+            component,
+            fluid: pipe_fluid.liquid,
+            inlet_temperature: initial_temperature,
+            mass_flowrate: 0.0,
+            heater_power: 0.0,
+            correct_prandtl_for_wall_temperatures: false,
+            simulation_time: 0.0,
+        })
+    }
+
+    /// ambient temperature (K)
+    #[getter]
+    pub fn get_ambient_temperature(&self) -> f64 {
+        self.component.ambient_temperature.get::<kelvin>()
+    }
+
+    /// ambient temperature (K)
+    #[setter]
+    pub fn set_ambient_temperature(&mut self, ambient_temperature: f64) {
+        self.component.ambient_temperature =
+            ThermodynamicTemperature::new::<kelvin>(ambient_temperature);
+    }
+
+    /// heat transfer coefficient to ambient (W/(m2 K))
+    #[getter]
+    pub fn get_htc_to_ambient(&self) -> f64 {
+        self.component.heat_transfer_to_ambient.get::<watt_per_square_meter_kelvin>()
+    }
+
+    /// heat transfer coefficient to ambient (W/(m2 K))
+    #[setter]
+    pub fn set_htc_to_ambient(&mut self, htc_to_ambient: f64) {
+        self.component.heat_transfer_to_ambient =
+            HeatTransfer::new::<watt_per_square_meter_kelvin>(htc_to_ambient);
+    }
+
+    /// connects the component to its inlet and outlet, calculates
+    /// the lateral heat transfer and advances the temperatures by
+    /// one timestep (s)
+    pub fn advance_timestep(&mut self, timestep: f64) -> PyResult<()> {
+        let mass_flowrate = check_mass_flowrate(self.mass_flowrate)?;
+        let timestep_quantity = check_timestep(timestep)?;
+        try_catch_panic(AssertUnwindSafe(
+            || self.try_advance_timestep(mass_flowrate, timestep_quantity)))?;
+        self.simulation_time += timestep;
+        Ok(())
+    }
+
+    /// fluid node temperatures from back (inlet) to front (outlet) in K
+    pub fn fluid_temperatures(&mut self) -> PyResult<Vec<f64>> {
+        try_get_temperatures_kelvin(&mut self.component.pipe_fluid_array)
+            .map_err(to_py_err)
+    }
+
+    /// pipe shell node temperatures from back to front in K
+    pub fn shell_temperatures(&mut self) -> PyResult<Vec<f64>> {
+        try_get_temperatures_kelvin(&mut self.component.pipe_shell)
+            .map_err(to_py_err)
+    }
+
+    /// insulation node temperatures from back to front in K
+    pub fn insulation_temperatures(&mut self) -> PyResult<Vec<f64>> {
+        try_get_temperatures_kelvin(&mut self.component.insulation)
+            .map_err(to_py_err)
+    }
+
+    /// temperature of the last fluid node in K
+    pub fn outlet_temperature(&mut self) -> PyResult<f64> {
+        Ok(*self.fluid_temperatures()?.last().unwrap_or(&f64::NAN))
+    }
+}
+
+impl PyInsulatedFluidComponent {
+
+    /// advances the component by one timestep, in the same way as
+    /// the insulated component tests
+    pub fn try_advance_timestep(&mut self, mass_flowrate: MassRate,
+        timestep: Time) -> Result<(), TuasLibError> {
+
+        let inlet_temperature = ThermodynamicTemperature::new::<kelvin>(
+            self.inlet_temperature);
+        let fluid_temperatures = self.component.pipe_fluid_array
+            .get_temperature_vector()?;
+        let back_cv_temperature = fluid_temperatures[0];
+        let front_cv_temperature = *fluid_temperatures.last().unwrap();
+
+        let inlet_density = self.fluid.try_get_density(inlet_temperature)?;
+        let back_cv_density = self.fluid.try_get_density(back_cv_temperature)?;
+        let front_cv_density = self.fluid.try_get_density(front_cv_temperature)?;
+
+        let mut inlet_bc: HeatTransferEntity =
+            BCType::new_const_temperature(inlet_temperature).into();
+        let mut outlet_bc: HeatTransferEntity = BCType::new_adiabatic_bc().into();
+
+        let inlet_interaction = HeatTransferInteractionType::new_advection_interaction(
+            mass_flowrate, inlet_density, back_cv_density);
+        let outlet_interaction = HeatTransferInteractionType::new_advection_interaction(
+            mass_flowrate, front_cv_density, front_cv_density);
+
+        self.component.pipe_fluid_array.link_to_back(&mut inlet_bc, inlet_interaction)?;
+        self.component.pipe_fluid_array.link_to_front(&mut outlet_bc, outlet_interaction)?;
+
+        self.component.lateral_and_miscellaneous_connections(
+            mass_flowrate,
+            Power::new::<watt>(self.heater_power),
+            self.correct_prandtl_for_wall_temperatures)?;
+
+        self.component.advance_timestep(timestep)
+    }
+}
+
+/// single pass counterflow shell and tube heat exchanger, the tube
+/// side flows from back to front and the shell side from front to
+/// back, both from constant temperature inlets
+///
+/// set the inlet temperatures (K) and mass flowrates (kg/s) of both
+/// sides, then call advance_timestep(timestep) with the timestep in
+/// seconds. Lengths are in m, areas in m2, angles in rad and heat
+/// transfer coefficients in W/(m2 K)
+///
+/// like the rust heat exchanger tests, timesteps must be short
+/// compared to the time fluid takes to pass through one node, or
+/// the temperatures oscillate and run out of the property ranges
+#[pyclass(name = "SimpleShellAndTubeHeatExchanger", module = "tuas_python", skip_from_py_object)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PySimpleShellAndTubeHeatExchanger {
+    /// the heat exchanger being simulated
+    pub heat_exchanger: SimpleShellAndTubeHeatExchanger,
+    /// tube side inlet temperature (K)
+    #[pyo3(get, set)]
+    pub tube_inlet_temperature: f64,
+    /// shell side inlet temperature (K)
+    #[pyo3(get, set)]
+    pub shell_inlet_temperature: f64,
+    /// total tube side mass flowrate (kg/s)
+    #[pyo3(get, set)]
+    pub tube_mass_flowrate: f64,
+    /// shell side mass flowrate (kg/s)
+    #[pyo3(get, set)]
+    pub shell_mass_flowrate: f64,
+    /// whether the Nusselt numbers are corrected for the wall
+    /// Prandtl number
+    #[pyo3(get, set)]
+    pub correct_prandtl_for_wall_temperatures: bool,
+    /// simulated time (s)
+    #[pyo3(get)]
+    pub simulation_time: f64,
+}
+
+#[pymethods]
+impl PySimpleShellAndTubeHeatExchanger {
+
+    /// builds an insulated single pass heat exchanger using
+    /// Gnielinski correlations for the tube side and for the shell
+    /// side to the tubes, while the shell side to outer shell heat
+    /// transfer is neglected
+    ///
+    /// the inlets start at the initial temperatures with no flow
+    #[new]
+    #[pyo3(signature = (number_of_tubes, inner_nodes, tube_side_od,
+        tube_side_id, shell_side_od, shell_side_id, length,
+        insulation_thickness, tube_side_liquid, shell_side_liquid,
+        tube_material, shell_material, insulation_material,
+        tube_side_initial_temperature, shell_side_initial_temperature,
+        ambient_temperature, htc_to_ambient, incline_angle = 0.0,
+        tube_side_form_loss = 0.0, shell_side_form_loss = 0.0,
+        fluid_pressure = 101325.0, solid_pressure = 101325.0))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn py_new(number_of_tubes: u32,
+        inner_nodes: usize,
+        tube_side_od: f64,
+        tube_side_id: f64,
+        shell_side_od: f64,
+        shell_side_id: f64,
+        length: f64,
+        insulation_thickness: f64,
+        tube_side_liquid: PyRef<'_, PyLiquidMaterial>,
+        shell_side_liquid: PyRef<'_, PyLiquidMaterial>,
+        tube_material: PyRef<'_, PySolidMaterial>,
+        shell_material: PyRef<'_, PySolidMaterial>,
+        insulation_material: PyRef<'_, PySolidMaterial>,
+        tube_side_initial_temperature: f64,
+        shell_side_initial_temperature: f64,
+        ambient_temperature: f64,
+        htc_to_ambient: f64,
+        incline_angle: f64,
+        tube_side_form_loss: f64,
+        shell_side_form_loss: f64,
+        fluid_pressure: f64,
+        solid_pressure: f64) -> PyResult<Self> {
+
+        let tube_bundle_diameter_squared =
+            number_of_tubes as f64 * tube_side_od * tube_side_od;
+        if !(number_of_tubes > 0 && tube_side_od > tube_side_id && tube_side_id > 0.0
+            && shell_side_od > shell_side_id
+            && shell_side_id * shell_side_id > tube_bundle_diameter_squared
+            && length > 0.0 && insulation_thickness > 0.0) {
+            return Err(PyValueError::new_err(
+                "tubes must fit inside the shell, and lengths must be positive"));
+        }
+
+        let tube_side_od = Length::new::<meter>(tube_side_od);
+        let tube_side_id = Length::new::<meter>(tube_side_id);
+        let shell_side_od = Length::new::<meter>(shell_side_od);
+        let shell_side_id = Length::new::<meter>(shell_side_id);
+        let length = Length::new::<meter>(length);
+
+        let tube_side_flow_area: Area =
+            std::f64::consts::PI * 0.25 * tube_side_id * tube_side_id;
+        let shell_side_flow_area: Area =
+            std::f64::consts::PI * 0.25 * shell_side_id * shell_side_id
+            - number_of_tubes as f64 * std::f64::consts::PI * 0.25 * tube_side_od * tube_side_od;
+        let shell_side_hydraulic_diameter: Length =
+            (shell_side_id * shell_side_id - number_of_tubes as f64 *
+             tube_side_od * tube_side_od)/
+            (shell_side_id + number_of_tubes as f64 * tube_side_od);
+
+        let surface_roughness = tube_material.solid.surface_roughness()
+            .map_err(to_py_err)?;
+        let tube_side_form_loss = Ratio::new::<ratio>(tube_side_form_loss);
+        let shell_side_form_loss = Ratio::new::<ratio>(shell_side_form_loss);
+
+        let shell_loss_correlations = DimensionlessDarcyLossCorrelations::new_pipe(
+            length, surface_roughness, shell_side_hydraulic_diameter,
+            shell_side_form_loss);
+        let tube_loss_correlations = DimensionlessDarcyLossCorrelations::new_pipe(
+            length, surface_roughness, tube_side_id, tube_side_form_loss);
+
+        // reynolds, prandtl and friction factors are overwritten
+        // every timestep
+        let dummy_ratio = Ratio::new::<ratio>(0.1);
+        let tube_side_gnielinski_data = GnielinskiData {
+            reynolds: dummy_ratio,
+            prandtl_bulk: dummy_ratio,
+            prandtl_wall: dummy_ratio,
+            darcy_friction_factor: dummy_ratio,
+            length_to_diameter: length/tube_side_id,
+        };
+        let shell_side_gnielinski_data = GnielinskiData {
+            length_to_diameter: length/shell_side_hydraulic_diameter,
+            ..tube_side_gnielinski_data
+        };
+
+        let incline_angle = Angle::new::<radian>(incline_angle);
+
+        let heat_exchanger = SimpleShellAndTubeHeatExchanger::
+            new_custom_circular_single_pass_sthe_with_insulation(
+                number_of_tubes,
+                inner_nodes,
+                Pressure::new::<pascal>(fluid_pressure),
+                Pressure::new::<pascal>(solid_pressure),
+                tube_side_od,
+                tube_side_id,
+                tube_side_id,
+                tube_side_flow_area,
+                shell_side_od,
+                shell_side_id,
+                shell_side_hydraulic_diameter,
+                shell_side_flow_area,
+                length,
+                tube_side_form_loss,
+                shell_side_form_loss,
+                Length::new::<meter>(insulation_thickness),
+                incline_angle,
+                incline_angle,
+                shell_side_liquid.liquid,
+                tube_side_liquid.liquid,
+                tube_material.solid,
+                shell_material.solid,
+                insulation_material.solid,
+                ThermodynamicTemperature::new::<kelvin>(ambient_temperature),
+                HeatTransfer::new::<watt_per_square_meter_kelvin>(htc_to_ambient),
+                ThermodynamicTemperature::new::<kelvin>(tube_side_initial_temperature),
+                ThermodynamicTemperature::new::<kelvin>(shell_side_initial_temperature),
+                shell_loss_correlations,
+                tube_loss_correlations,
+                NusseltCorrelation::PipeGnielinskiGeneric(tube_side_gnielinski_data),
+                NusseltCorrelation::PipeGnielinskiGeneric(shell_side_gnielinski_data),
+                NusseltCorrelation::FixedNusselt(Ratio::ZERO));
+
+        Ok(Self::new(heat_exchanger,
+            tube_side_initial_temperature,
+            shell_side_initial_temperature))
+    }
+
+    /// the molten salt (HITEC, shell side) to oil (YD325, tube side)
+    /// heat exchanger of Du et al. (2018), starting at its inlet
+    /// temperatures of 74.49 C (tube side) and 214.93 C (shell side)
+    #[staticmethod]
+    pub fn new_du_et_al_sthe() -> Self {
+        Self::new(SimpleShellAndTubeHeatExchanger::new_du_et_al_sthe(),
+            273.15 + 74.49, 273.15 + 214.93)
+    }
+
+    /// ambient temperature (K)
+    #[getter]
+    pub fn get_ambient_temperature(&self) -> f64 {
+        self.heat_exchanger.ambient_temperature.get::<kelvin>()
+    }
+
+    /// ambient temperature (K)
+    #[setter]
+    pub fn set_ambient_temperature(&mut self, ambient_temperature: f64) {
+        self.heat_exchanger.ambient_temperature =
+            ThermodynamicTemperature::new::<kelvin>(ambient_temperature);
+    }
+
+    /// heat transfer coefficient to ambient (W/(m2 K))
+    #[getter]
+    pub fn get_htc_to_ambient(&self) -> f64 {
+        self.heat_exchanger.heat_transfer_to_ambient.get::<watt_per_square_meter_kelvin>()
+    }
+
+    /// heat transfer coefficient to ambient (W/(m2 K))
+    #[setter]
+    pub fn set_htc_to_ambient(&mut self, htc_to_ambient: f64) {
+        self.heat_exchanger.heat_transfer_to_ambient =
+            HeatTransfer::new::<watt_per_square_meter_kelvin>(htc_to_ambient);
+    }
+
+    /// connects both sides to their inlets and outlets, calculates
+    /// the lateral heat transfer and advances the temperatures by
+    /// one timestep (s)
+    pub fn advance_timestep(&mut self, timestep: f64) -> PyResult<()> {
+        let tube_mass_flowrate = check_mass_flowrate(self.tube_mass_flowrate)?;
+        let shell_mass_flowrate = check_mass_flowrate(self.shell_mass_flowrate)?;
+        let timestep_quantity = check_timestep(timestep)?;
+        try_catch_panic(AssertUnwindSafe(|| self.try_advance_timestep(
+            tube_mass_flowrate, shell_mass_flowrate, timestep_quantity)))?;
+        self.simulation_time += timestep;
+        Ok(())
+    }
+
+    /// tube side fluid node temperatures from back to front in K
+    pub fn tube_side_fluid_temperatures(&mut self) -> PyResult<Vec<f64>> {
+        try_get_temperatures_kelvin(
+            &mut self.heat_exchanger.tube_side_fluid_array_for_single_tube)
+            .map_err(to_py_err)
+    }
+
+    /// shell side fluid node temperatures from back to front in K
+    pub fn shell_side_fluid_temperatures(&mut self) -> PyResult<Vec<f64>> {
+        try_get_temperatures_kelvin(&mut self.heat_exchanger.shell_side_fluid_array)
+            .map_err(to_py_err)
+    }
+
+    /// inner tube node temperatures from back to front in K
+    pub fn inner_tube_temperatures(&mut self) -> PyResult<Vec<f64>> {
+        try_get_temperatures_kelvin(
+            &mut self.heat_exchanger.inner_pipe_shell_array_for_single_tube)
+            .map_err(to_py_err)
+    }
+
+    /// outer shell node temperatures from back to front in K
+    pub fn outer_shell_temperatures(&mut self) -> PyResult<Vec<f64>> {
+        try_get_temperatures_kelvin(&mut self.heat_exchanger.outer_shell)
+            .map_err(to_py_err)
+    }
+
+    /// tube side outlet (front node) temperature in K
+    pub fn tube_outlet_temperature(&mut self) -> PyResult<f64> {
+        Ok(*self.tube_side_fluid_temperatures()?.last().unwrap_or(&f64::NAN))
+    }
+
+    /// shell side outlet (back node) temperature in K
+    pub fn shell_outlet_temperature(&mut self) -> PyResult<f64> {
+        Ok(*self.shell_side_fluid_temperatures()?.first().unwrap_or(&f64::NAN))
+    }
+}
+
+impl PySimpleShellAndTubeHeatExchanger {
+
+    /// wraps a heat exchanger with no flow through it
+    pub fn new(heat_exchanger: SimpleShellAndTubeHeatExchanger,
+        tube_inlet_temperature: f64,
+        shell_inlet_temperature: f64) -> Self {
+        Self {
+            heat_exchanger,
+            tube_inlet_temperature,
+            shell_inlet_temperature,
+            tube_mass_flowrate: 0.0,
+            shell_mass_flowrate: 0.0,
+            correct_prandtl_for_wall_temperatures: false,
+            simulation_time: 0.0,
+        }
+    }
+
+    /// advances the heat exchanger by one timestep, in the same way
+    /// as the heat exchanger tests
+    pub fn try_advance_timestep(&mut self,
+        tube_mass_flowrate: MassRate,
+        shell_mass_flowrate: MassRate,
+        timestep: Time) -> Result<(), TuasLibError> {
+
+        let tube_inlet_temperature = ThermodynamicTemperature::new::<kelvin>(
+            self.tube_inlet_temperature);
+        let shell_inlet_temperature = ThermodynamicTemperature::new::<kelvin>(
+            self.shell_inlet_temperature);
+
+        let tube_liquid = try_get_fluid_array_liquid(
+            &self.heat_exchanger.tube_side_fluid_array_for_single_tube)?;
+        let shell_liquid = try_get_fluid_array_liquid(
+            &self.heat_exchanger.shell_side_fluid_array)?;
+
+        let tube_temperatures = self.heat_exchanger
+            .tube_side_fluid_array_for_single_tube.get_temperature_vector()?;
+        let shell_temperatures = self.heat_exchanger
+            .shell_side_fluid_array.get_temperature_vector()?;
+
+        let tube_inlet_density = tube_liquid.try_get_density(tube_inlet_temperature)?;
+        let tube_back_cv_density = tube_liquid.try_get_density(tube_temperatures[0])?;
+        let tube_front_cv_density = tube_liquid.try_get_density(
+            *tube_temperatures.last().unwrap())?;
+        let shell_inlet_density = shell_liquid.try_get_density(shell_inlet_temperature)?;
+        let shell_back_cv_density = shell_liquid.try_get_density(shell_temperatures[0])?;
+        let shell_front_cv_density = shell_liquid.try_get_density(
+            *shell_temperatures.last().unwrap())?;
+
+        let mut tube_inlet_bc: HeatTransferEntity =
+            BCType::new_const_temperature(tube_inlet_temperature).into();
+        let mut shell_inlet_bc: HeatTransferEntity =
+            BCType::new_const_temperature(shell_inlet_temperature).into();
+        let mut outlet_bc: HeatTransferEntity = BCType::new_adiabatic_bc().into();
+
+        // tube side goes back -> front
+        self.heat_exchanger.tube_side_fluid_array_for_single_tube.link_to_back(
+            &mut tube_inlet_bc,
+            HeatTransferInteractionType::new_advection_interaction(
+                tube_mass_flowrate, tube_inlet_density, tube_back_cv_density))?;
+        self.heat_exchanger.tube_side_fluid_array_for_single_tube.link_to_front(
+            &mut outlet_bc,
+            HeatTransferInteractionType::new_advection_interaction(
+                tube_mass_flowrate, tube_front_cv_density, tube_front_cv_density))?;
+
+        // shell side goes front -> back, which is a negative
+        // flowrate along the shell side fluid array
+        let shell_mass_flowrate_back_to_front = -shell_mass_flowrate;
+        self.heat_exchanger.shell_side_fluid_array.link_to_front(
+            &mut shell_inlet_bc,
+            HeatTransferInteractionType::new_advection_interaction(
+                shell_mass_flowrate_back_to_front,
+                shell_front_cv_density, shell_inlet_density))?;
+        self.heat_exchanger.shell_side_fluid_array.link_to_back(
+            &mut outlet_bc,
+            HeatTransferInteractionType::new_advection_interaction(
+                shell_mass_flowrate_back_to_front,
+                shell_back_cv_density, shell_back_cv_density))?;
+
+        self.heat_exchanger.lateral_and_miscellaneous_connections(
+            self.correct_prandtl_for_wall_temperatures,
+            tube_mass_flowrate,
+            shell_mass_flowrate_back_to_front)?;
+
+        self.heat_exchanger.advance_timestep(timestep)
+    }
+}
@@ -0,0 +1,77 @@
+use pyo3::prelude::*;
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+
+use crate::fluid_mechanics_correlations::churchill_friction_factor;
+use crate::heat_transfer_correlations::nusselt_number_correlations::input_structs::GnielinskiData;
+use crate::heat_transfer_correlations::nusselt_number_correlations::natural_convection_correlations;
+use crate::heat_transfer_correlations::nusselt_number_correlations::pipe_correlations;
+
+use super::try_catch_panic;
+
+/// Darcy friction factor from the Churchill correlation, valid for
+/// laminar, transition and turbulent flow
+#[pyfunction]
+pub fn churchill_darcy_friction_factor(reynolds: f64, roughness_ratio: f64) -> PyResult<f64> {
+    try_catch_panic(|| churchill_friction_factor::darcy(reynolds, roughness_ratio))
+}
+
+/// fLDK = f L/D + K from the Churchill correlation and a form loss K
+#[pyfunction]
+pub fn churchill_fldk(reynolds: f64, roughness_ratio: f64,
+    length_to_diameter: f64, form_loss: f64) -> PyResult<f64> {
+    try_catch_panic(|| churchill_friction_factor::f_ldk(reynolds,
+        roughness_ratio, length_to_diameter, form_loss))
+}
+
+/// Dittus-Boelter Nusselt number for turbulent pipe flow, heating
+/// is True when the fluid is being heated
+#[pyfunction]
+#[pyo3(signature = (reynolds, prandtl, heating = true))]
+pub fn dittus_boelter_nusselt(reynolds: f64, prandtl: f64, heating: bool) -> f64 {
+    pipe_correlations::dittus_boelter_correlation(reynolds, prandtl, heating)
+}
+
+/// Sieder-Tate Nusselt number for turbulent pipe flow, with the
+/// ratio of bulk fluid to wall viscosity
+#[pyfunction]
+pub fn sieder_tate_nusselt(reynolds: f64, prandtl: f64,
+    viscosity_ratio_fluid_over_wall: f64) -> PyResult<f64> {
+    try_catch_panic(|| Ok(pipe_correlations::sieder_tate_correlation(
+        reynolds, prandtl, viscosity_ratio_fluid_over_wall)))
+}
+
+/// Gnielinski Nusselt number for developing pipe flow using the
+/// bulk fluid and wall Prandtl numbers, valid for laminar,
+/// transition and turbulent flow
+#[pyfunction]
+#[pyo3(signature = (reynolds, prandtl_bulk, prandtl_wall,
+    darcy_friction_factor, length_to_diameter = 1.0))]
+pub fn gnielinski_nusselt(reynolds: f64, prandtl_bulk: f64, prandtl_wall: f64,
+    darcy_friction_factor: f64, length_to_diameter: f64) -> PyResult<f64> {
+
+    let gnielinski_data = GnielinskiData {
+        reynolds: Ratio::new::<ratio>(reynolds),
+        prandtl_bulk: Ratio::new::<ratio>(prandtl_bulk),
+        prandtl_wall: Ratio::new::<ratio>(prandtl_wall),
+        darcy_friction_factor: Ratio::new::<ratio>(darcy_friction_factor),
+        length_to_diameter: Ratio::new::<ratio>(length_to_diameter),
+    };
+
+    try_catch_panic(|| gnielinski_data.get_nusselt_for_developing_flow_bulk_fluid_prandtl())
+        .map(|nusselt| nusselt.get::<ratio>())
+}
+
+/// Churchill and Chu Nusselt number for natural convection from a
+/// long horizontal cylinder, based on the outer diameter
+#[pyfunction]
+pub fn churchill_chu_horizontal_cylinder_nusselt(rayleigh: f64, prandtl: f64) -> f64 {
+    natural_convection_correlations::churchill_chu_horizontal_cylinder(rayleigh, prandtl)
+}
+
+/// Churchill and Chu Nusselt number for natural convection from a
+/// vertical plate, based on the plate height
+#[pyfunction]
+pub fn churchill_chu_vertical_plate_nusselt(rayleigh: f64, prandtl: f64) -> f64 {
+    natural_convection_correlations::churchill_chu_vertical_plate(rayleigh, prandtl)
+}
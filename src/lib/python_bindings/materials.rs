@@ -0,0 +1,255 @@
+use pyo3::prelude::*;
+use uom::si::available_energy::joule_per_kilogram;
+use uom::si::dynamic_viscosity::pascal_second;
+use uom::si::f64::*;
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::pressure::pascal;
+use uom::si::ratio::ratio;
+use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+use uom::si::thermodynamic_temperature::kelvin;
+
+use crate::boussinesq_thermophysical_properties::density::try_get_rho;
+use crate::boussinesq_thermophysical_properties::dynamic_viscosity::try_get_mu_viscosity;
+use crate::boussinesq_thermophysical_properties::prandtl::try_get_prandtl;
+use crate::boussinesq_thermophysical_properties::specific_enthalpy::{try_get_h, try_get_temperature_from_h};
+use crate::boussinesq_thermophysical_properties::specific_heat_capacity::try_get_cp;
+use crate::boussinesq_thermophysical_properties::thermal_conductivity::try_get_kappa_thermal_conductivity;
+use crate::boussinesq_thermophysical_properties::{LiquidMaterial, Material, SolidMaterial};
+use crate::boussinesq_thermophysical_properties::material_names::*;
+
+use super::{to_py_err, try_catch_panic};
+
+/// liquid from the property database, eg. LiquidMaterial("HITEC")
+///
+/// temperatures are in K and pressures in Pa, and the pressure
+/// defaults to one atmosphere
+#[pyclass(name = "LiquidMaterial", module = "tuas_python", frozen, skip_from_py_object)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PyLiquidMaterial {
+    /// the liquid in the database
+    pub liquid: LiquidMaterial,
+    /// name of the liquid's variant
+    pub name: &'static str,
+}
+
+#[pymethods]
+impl PyLiquidMaterial {
+
+    /// looks up a liquid by name
+    #[new]
+    pub fn py_new(name: &str) -> PyResult<Self> {
+        let liquid = try_get_liquid_material_from_name(name).map_err(to_py_err)?;
+        let name = LIQUID_MATERIALS.iter()
+            .find(|(_, database_liquid)| *database_liquid == liquid)
+            .map(|(liquid_name, _)| *liquid_name)
+            .unwrap_or_default();
+        Ok(Self { liquid, name })
+    }
+
+    /// names of the liquids in the database
+    #[staticmethod]
+    pub fn available_liquids() -> Vec<&'static str> {
+        LIQUID_MATERIALS.iter().map(|(name, _)| *name).collect()
+    }
+
+    /// name of the liquid
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// lowest temperature the correlations are valid for (K)
+    #[getter]
+    pub fn min_temperature(&self) -> f64 {
+        self.liquid.min_temperature().get::<kelvin>()
+    }
+
+    /// highest temperature the correlations are valid for (K)
+    #[getter]
+    pub fn max_temperature(&self) -> f64 {
+        self.liquid.max_temperature().get::<kelvin>()
+    }
+
+    /// density (kg/m3)
+    #[pyo3(signature = (temperature, pressure = 101325.0))]
+    pub fn try_get_rho(&self, temperature: f64, pressure: f64) -> PyResult<f64> {
+        let (material, temperature, pressure) = self.get_state(temperature, pressure);
+        try_catch_panic(|| try_get_rho(material, temperature, pressure))
+            .map(|rho| rho.get::<kilogram_per_cubic_meter>())
+    }
+
+    /// specific heat capacity (J/(kg K))
+    #[pyo3(signature = (temperature, pressure = 101325.0))]
+    pub fn try_get_cp(&self, temperature: f64, pressure: f64) -> PyResult<f64> {
+        let (material, temperature, pressure) = self.get_state(temperature, pressure);
+        try_catch_panic(|| try_get_cp(material, temperature, pressure))
+            .map(|cp| cp.get::<joule_per_kilogram_kelvin>())
+    }
+
+    /// thermal conductivity (W/(m K))
+    #[pyo3(signature = (temperature, pressure = 101325.0))]
+    pub fn try_get_k(&self, temperature: f64, pressure: f64) -> PyResult<f64> {
+        let (material, temperature, pressure) = self.get_state(temperature, pressure);
+        try_catch_panic(|| try_get_kappa_thermal_conductivity(material, temperature, pressure))
+            .map(|k| k.get::<watt_per_meter_kelvin>())
+    }
+
+    /// dynamic viscosity (Pa s)
+    #[pyo3(signature = (temperature, pressure = 101325.0))]
+    pub fn try_get_mu(&self, temperature: f64, pressure: f64) -> PyResult<f64> {
+        let (material, temperature, pressure) = self.get_state(temperature, pressure);
+        try_catch_panic(|| try_get_mu_viscosity(material, temperature, pressure))
+            .map(|mu| mu.get::<pascal_second>())
+    }
+
+    /// Prandtl number
+    #[pyo3(signature = (temperature, pressure = 101325.0))]
+    pub fn try_get_prandtl(&self, temperature: f64, pressure: f64) -> PyResult<f64> {
+        let (material, temperature, pressure) = self.get_state(temperature, pressure);
+        try_catch_panic(|| try_get_prandtl(material, temperature, pressure))
+            .map(|prandtl| prandtl.get::<ratio>())
+    }
+
+    /// specific enthalpy (J/kg)
+    #[pyo3(signature = (temperature, pressure = 101325.0))]
+    pub fn try_get_h(&self, temperature: f64, pressure: f64) -> PyResult<f64> {
+        let (material, temperature, pressure) = self.get_state(temperature, pressure);
+        try_catch_panic(|| try_get_h(material, temperature, pressure))
+            .map(|h| h.get::<joule_per_kilogram>())
+    }
+
+    /// temperature (K) from specific enthalpy (J/kg)
+    #[pyo3(signature = (specific_enthalpy, pressure = 101325.0))]
+    pub fn try_get_temperature_from_h(&self, specific_enthalpy: f64,
+        pressure: f64) -> PyResult<f64> {
+        let material: Material = self.liquid.into();
+        let specific_enthalpy = AvailableEnergy::new::<joule_per_kilogram>(specific_enthalpy);
+        let pressure = Pressure::new::<pascal>(pressure);
+        try_catch_panic(|| try_get_temperature_from_h(material, specific_enthalpy, pressure))
+            .map(|temperature| temperature.get::<kelvin>())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("LiquidMaterial(\"{}\")", self.name)
+    }
+}
+
+impl PyLiquidMaterial {
+
+    fn get_state(&self, temperature: f64, pressure: f64)
+        -> (Material, ThermodynamicTemperature, Pressure) {
+        (self.liquid.into(),
+        ThermodynamicTemperature::new::<kelvin>(temperature),
+        Pressure::new::<pascal>(pressure))
+    }
+}
+
+/// solid from the property database, eg. SolidMaterial("SteelSS304L")
+///
+/// temperatures are in K and pressures in Pa, and the pressure
+/// defaults to one atmosphere
+#[pyclass(name = "SolidMaterial", module = "tuas_python", frozen, skip_from_py_object)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PySolidMaterial {
+    /// the solid in the database
+    pub solid: SolidMaterial,
+    /// name of the solid's variant
+    pub name: &'static str,
+}
+
+#[pymethods]
+impl PySolidMaterial {
+
+    /// looks up a solid by name
+    #[new]
+    pub fn py_new(name: &str) -> PyResult<Self> {
+        let solid = try_get_solid_material_from_name(name).map_err(to_py_err)?;
+        let name = SOLID_MATERIALS.iter()
+            .find(|(_, database_solid)| *database_solid == solid)
+            .map(|(solid_name, _)| *solid_name)
+            .unwrap_or_default();
+        Ok(Self { solid, name })
+    }
+
+    /// names of the solids in the database
+    #[staticmethod]
+    pub fn available_solids() -> Vec<&'static str> {
+        SOLID_MATERIALS.iter().map(|(name, _)| *name).collect()
+    }
+
+    /// name of the solid
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// lowest temperature the correlations are valid for (K)
+    #[getter]
+    pub fn min_temperature(&self) -> f64 {
+        self.solid.min_temperature().get::<kelvin>()
+    }
+
+    /// highest temperature the correlations are valid for (K)
+    #[getter]
+    pub fn max_temperature(&self) -> f64 {
+        self.solid.max_temperature().get::<kelvin>()
+    }
+
+    /// density (kg/m3)
+    #[pyo3(signature = (temperature, pressure = 101325.0))]
+    pub fn try_get_rho(&self, temperature: f64, pressure: f64) -> PyResult<f64> {
+        let (material, temperature, pressure) = self.get_state(temperature, pressure);
+        try_catch_panic(|| try_get_rho(material, temperature, pressure))
+            .map(|rho| rho.get::<kilogram_per_cubic_meter>())
+    }
+
+    /// specific heat capacity (J/(kg K))
+    #[pyo3(signature = (temperature, pressure = 101325.0))]
+    pub fn try_get_cp(&self, temperature: f64, pressure: f64) -> PyResult<f64> {
+        let (material, temperature, pressure) = self.get_state(temperature, pressure);
+        try_catch_panic(|| try_get_cp(material, temperature, pressure))
+            .map(|cp| cp.get::<joule_per_kilogram_kelvin>())
+    }
+
+    /// thermal conductivity (W/(m K))
+    #[pyo3(signature = (temperature, pressure = 101325.0))]
+    pub fn try_get_k(&self, temperature: f64, pressure: f64) -> PyResult<f64> {
+        let (material, temperature, pressure) = self.get_state(temperature, pressure);
+        try_catch_panic(|| try_get_kappa_thermal_conductivity(material, temperature, pressure))
+            .map(|k| k.get::<watt_per_meter_kelvin>())
+    }
+
+    /// specific enthalpy (J/kg)
+    #[pyo3(signature = (temperature, pressure = 101325.0))]
+    pub fn try_get_h(&self, temperature: f64, pressure: f64) -> PyResult<f64> {
+        let (material, temperature, pressure) = self.get_state(temperature, pressure);
+        try_catch_panic(|| try_get_h(material, temperature, pressure))
+            .map(|h| h.get::<joule_per_kilogram>())
+    }
+
+    /// temperature (K) from specific enthalpy (J/kg)
+    #[pyo3(signature = (specific_enthalpy, pressure = 101325.0))]
+    pub fn try_get_temperature_from_h(&self, specific_enthalpy: f64,
+        pressure: f64) -> PyResult<f64> {
+        let material: Material = self.solid.into();
+        let specific_enthalpy = AvailableEnergy::new::<joule_per_kilogram>(specific_enthalpy);
+        let pressure = Pressure::new::<pascal>(pressure);
+        try_catch_panic(|| try_get_temperature_from_h(material, specific_enthalpy, pressure))
+            .map(|temperature| temperature.get::<kelvin>())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SolidMaterial(\"{}\")", self.name)
+    }
+}
+
+impl PySolidMaterial {
+
+    fn get_state(&self, temperature: f64, pressure: f64)
+        -> (Material, ThermodynamicTemperature, Pressure) {
+        (self.solid.into(),
+        ThermodynamicTemperature::new::<kelvin>(temperature),
+        Pressure::new::<pascal>(pressure))
+    }
+}
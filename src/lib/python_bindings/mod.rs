@@ -0,0 +1,88 @@
+use std::panic::{catch_unwind, UnwindSafe};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// python classes for the liquid and solid property database
+pub mod materials;
+pub use materials::*;
+
+/// python functions for the friction factor and Nusselt number
+/// correlations
+pub mod correlations;
+pub use correlations::*;
+
+/// python classes for pre-built components, which own their inlet
+/// and outlet boundary conditions
+pub mod components;
+pub use components::*;
+
+/// adds the classes and functions of the bindings to a python
+/// module
+///
+/// the python extension module itself is built from
+/// examples/tuas_python, which calls this in its #[pymodule]
+/// function:
+///
+/// ```ignore
+/// use pyo3::prelude::*;
+///
+/// #[pymodule]
+/// fn tuas_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
+///     tuas_boussinesq_solver::python_bindings::register_python_module(m)
+/// }
+/// ```
+///
+/// all quantities cross the boundary as floats in SI units
+/// (K, Pa, kg/m3, J/(kg K), W/(m K), Pa s, J/kg, m, m2, kg/s, W, s
+/// and rad)
+pub fn register_python_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+
+    m.add_class::<PyLiquidMaterial>()?;
+    m.add_class::<PySolidMaterial>()?;
+
+    m.add_function(wrap_pyfunction!(churchill_darcy_friction_factor, m)?)?;
+    m.add_function(wrap_pyfunction!(churchill_fldk, m)?)?;
+    m.add_function(wrap_pyfunction!(dittus_boelter_nusselt, m)?)?;
+    m.add_function(wrap_pyfunction!(sieder_tate_nusselt, m)?)?;
+    m.add_function(wrap_pyfunction!(gnielinski_nusselt, m)?)?;
+    m.add_function(wrap_pyfunction!(churchill_chu_horizontal_cylinder_nusselt, m)?)?;
+    m.add_function(wrap_pyfunction!(churchill_chu_vertical_plate_nusselt, m)?)?;
+
+    m.add_class::<PyInsulatedFluidComponent>()?;
+    m.add_class::<PySimpleShellAndTubeHeatExchanger>()?;
+
+    Ok(())
+}
+
+/// converts library errors into python ValueErrors
+pub(crate) fn to_py_err(error: TuasLibError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// some correlations panic when used outside their range, this
+/// turns those panics into python ValueErrors so that they can be
+/// caught in scripts
+pub(crate) fn try_catch_panic<T, F: FnOnce() -> Result<T, TuasLibError> + UnwindSafe>(
+    function: F) -> PyResult<T> {
+
+    match catch_unwind(function) {
+        Ok(result) => result.map_err(to_py_err),
+        Err(payload) => {
+            let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                message.to_string()
+            } else if let Some(message) = payload.downcast_ref::<String>() {
+                message.clone()
+            } else {
+                "correlation failed".to_owned()
+            };
+            Err(PyValueError::new_err(message))
+        },
+    }
+}
+
+/// tests calling the bindings from an embedded python interpreter
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,188 @@
+use std::ffi::CStr;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use uom::si::f64::*;
+use uom::si::mass_density::kilogram_per_cubic_meter;
+use uom::si::thermodynamic_temperature::kelvin;
+
+use crate::boussinesq_thermophysical_properties::LiquidMaterial;
+use crate::fluid_mechanics_correlations::churchill_friction_factor;
+
+use super::register_python_module;
+
+/// runs a python script with the bindings imported as tuas, and
+/// returns the value of result
+fn run_python_script(script: &CStr) -> f64 {
+    Python::initialize();
+    Python::attach(|py| {
+        let module = PyModule::new(py, "tuas_python").unwrap();
+        register_python_module(&module).unwrap();
+
+        let locals = PyDict::new(py);
+        locals.set_item("tuas", module).unwrap();
+
+        if let Err(error) = py.run(script, None, Some(&locals)) {
+            error.print(py);
+            panic!("python script failed");
+        }
+
+        locals.get_item("result").unwrap().unwrap().extract::<f64>().unwrap()
+    })
+}
+
+/// property lookups take and return SI floats and agree with the
+/// rust functions, while unknown names and temperatures outside the
+/// correlation ranges raise ValueError
+#[test]
+pub fn property_lookups_from_python(){
+
+    let rho = run_python_script(c"
+hitec = tuas.LiquidMaterial('HITEC')
+assert repr(hitec) == 'LiquidMaterial(\"HITEC\")'
+assert 'TherminolVP1' in tuas.LiquidMaterial.available_liquids()
+result = hitec.try_get_rho(500.0)
+");
+    let expected_rho = LiquidMaterial::HITEC
+        .try_get_density(ThermodynamicTemperature::new::<kelvin>(500.0))
+        .unwrap();
+    approx::assert_relative_eq!(rho, expected_rho.get::<kilogram_per_cubic_meter>());
+
+    // enthalpy and temperature round trip
+    let temperature = run_python_script(c"
+steel = tuas.SolidMaterial('SteelSS304L')
+h = steel.try_get_h(400.0)
+assert steel.try_get_cp(400.0) > 400.0
+assert steel.min_temperature < 400.0 < steel.max_temperature
+result = steel.try_get_temperature_from_h(h)
+");
+    approx::assert_relative_eq!(temperature, 400.0, max_relative=1e-6);
+
+    let errors_raised = run_python_script(c"
+result = 0
+try:
+    tuas.LiquidMaterial('Mercury')
+except ValueError as error:
+    assert 'available liquids' in str(error)
+    result += 1
+try:
+    tuas.LiquidMaterial('TherminolVP1').try_get_mu(1000.0)
+except ValueError:
+    result += 1
+try:
+    tuas.SolidMaterial('SteelSS304L').try_get_k(2000.0)
+except ValueError:
+    result += 1
+");
+    assert_eq!(errors_raised, 3.0);
+}
+
+/// friction factor and Nusselt correlations are callable from python,
+/// and correlations which panic outside their range raise ValueError
+#[test]
+pub fn correlations_from_python(){
+
+    let darcy = run_python_script(c"
+result = tuas.churchill_darcy_friction_factor(1.0e5, 1.0e-4)
+");
+    approx::assert_relative_eq!(darcy,
+        churchill_friction_factor::darcy(1.0e5, 1.0e-4).unwrap());
+
+    let laminar_fldk = run_python_script(c"
+result = tuas.churchill_fldk(100.0, 0.0, 10.0, 2.0)
+");
+    approx::assert_relative_eq!(laminar_fldk, 64.0/100.0*10.0 + 2.0, max_relative=0.01);
+
+    let nusselt = run_python_script(c"
+f = tuas.churchill_darcy_friction_factor(1.0e4, 0.0)
+result = tuas.gnielinski_nusselt(1.0e4, 5.0, 5.0, f, length_to_diameter=1.0e4)
+assert abs(tuas.dittus_boelter_nusselt(1.0e4, 5.0) / result - 1.0) < 0.3
+assert tuas.churchill_chu_horizontal_cylinder_nusselt(1.0e6, 0.7) > 14.0
+try:
+    tuas.sieder_tate_nusselt(1.0e4, 0.1, 1.0)
+    raise AssertionError('expected ValueError')
+except ValueError as error:
+    assert 'Pr < 0.7' in str(error)
+");
+    assert!(nusselt > 50.0 && nusselt < 100.0, "Nu = {}", nusselt);
+}
+
+/// an unheated insulated pipe fed by hot therminol heats up toward
+/// the inlet temperature, and negative flowrates are rejected
+#[test]
+pub fn insulated_fluid_component_from_python(){
+
+    let outlet_temperature = run_python_script(c"
+import math
+pipe = tuas.InsulatedFluidComponent(
+    initial_temperature=300.0, ambient_temperature=300.0,
+    flow_area=math.pi*0.25*0.0279**2, incline_angle=0.0, form_loss=0.0,
+    shell_id=0.0279, shell_od=0.0335, insulation_thickness=0.0508,
+    pipe_length=0.5, hydraulic_diameter=0.0279,
+    pipe_shell_material=tuas.SolidMaterial('SteelSS304L'),
+    insulation_material=tuas.SolidMaterial('Fiberglass'),
+    pipe_fluid=tuas.LiquidMaterial('TherminolVP1'),
+    htc_to_ambient=0.0, inner_nodes=3, surface_roughness=1.5e-5)
+assert len(pipe.fluid_temperatures()) == 5
+assert pipe.htc_to_ambient == 0.0
+
+pipe.inlet_temperature = 350.0
+pipe.mass_flowrate = 0.05
+for _ in range(3000):
+    pipe.advance_timestep(0.1)
+assert abs(pipe.simulation_time - 300.0) < 1e-6
+
+pipe.mass_flowrate = -0.1
+try:
+    pipe.advance_timestep(0.5)
+    raise AssertionError('expected ValueError')
+except ValueError:
+    pass
+result = pipe.outlet_temperature()
+");
+    approx::assert_relative_eq!(outlet_temperature, 350.0, max_relative=1e-3);
+}
+
+/// the heat exchanger cools the hot shell side and heats the cold
+/// tube side, with outlets between the inlet temperatures
+#[test]
+pub fn shell_and_tube_heat_exchanger_from_python(){
+
+    let effectiveness = run_python_script(c"
+sthe = tuas.SimpleShellAndTubeHeatExchanger.new_du_et_al_sthe()
+# flowrates of Du et al. (2018) set A
+sthe.tube_mass_flowrate = 3.47
+sthe.shell_mass_flowrate = 6.6
+for _ in range(1000):
+    sthe.advance_timestep(0.036)
+
+tube_out = sthe.tube_outlet_temperature()
+shell_out = sthe.shell_outlet_temperature()
+t_in, s_in = sthe.tube_inlet_temperature, sthe.shell_inlet_temperature
+assert t_in < tube_out < s_in
+assert t_in < shell_out < s_in
+assert len(sthe.tube_side_fluid_temperatures()) == 14
+
+custom = tuas.SimpleShellAndTubeHeatExchanger(
+    number_of_tubes=7, inner_nodes=4, tube_side_od=0.014,
+    tube_side_id=0.01, shell_side_od=0.108, shell_side_id=0.1,
+    length=1.0, insulation_thickness=0.02,
+    tube_side_liquid=tuas.LiquidMaterial('TherminolVP1'),
+    shell_side_liquid=tuas.LiquidMaterial('HITEC'),
+    tube_material=tuas.SolidMaterial('SteelSS304L'),
+    shell_material=tuas.SolidMaterial('SteelSS304L'),
+    insulation_material=tuas.SolidMaterial('Fiberglass'),
+    tube_side_initial_temperature=350.0,
+    shell_side_initial_temperature=500.0,
+    ambient_temperature=300.0, htc_to_ambient=10.0)
+custom.tube_mass_flowrate = 0.1
+custom.shell_mass_flowrate = 0.1
+for _ in range(1000):
+    custom.advance_timestep(0.01)
+assert custom.tube_outlet_temperature() > 350.0
+
+result = (tube_out - t_in)/(s_in - t_in)
+");
+    assert!(effectiveness > 0.0 && effectiveness < 1.0,
+        "effectiveness = {}", effectiveness);
+}
@@ -0,0 +1,314 @@
+use uom::si::f64::*;
+use uom::si::energy::megaelectronvolt;
+use uom::si::frequency::hertz;
+use uom::si::power::watt;
+use uom::si::ratio::ratio;
+use uom::si::time::second;
+
+/// energy release rate per fission (alpha_j, MeV/s per fission)
+/// for the 23 group exponential fit of U-235 thermal fission
+/// product decay heat
+///
+/// ANS-5.1-1994, Decay Heat Power in Light Water Reactors,
+/// American Nuclear Society.
+const U235_DECAY_HEAT_ALPHA_MEV_PER_SECOND: [f64; 23] = [
+    6.5057E-01, 5.1264E-01, 2.4384E-01, 1.3850E-01, 5.5440E-02,
+    2.2225E-02, 3.3088E-03, 9.3015E-04, 8.0943E-04, 1.9567E-04,
+    3.2535E-05, 7.5595E-06, 2.5232E-06, 4.9948E-07, 1.8531E-07,
+    2.6608E-08, 2.2398E-09, 8.1641E-12, 8.7797E-11, 2.5131E-14,
+    3.2176E-16, 4.5038E-17, 7.4791E-17,
+];
+
+/// decay constants (lambda_j, 1/s) for the 23 group exponential
+/// fit of U-235 thermal fission product decay heat
+///
+/// ANS-5.1-1994, Decay Heat Power in Light Water Reactors,
+/// American Nuclear Society.
+const U235_DECAY_HEAT_LAMBDA_PER_SECOND: [f64; 23] = [
+    2.2138E+01, 5.1587E-01, 1.9594E-01, 1.0314E-01, 3.3656E-02,
+    1.1681E-02, 3.5870E-03, 1.3930E-03, 6.2630E-04, 1.8906E-04,
+    5.4988E-05, 2.0958E-05, 1.0010E-05, 2.5438E-06, 6.6361E-07,
+    1.2290E-07, 2.7213E-08, 4.3714E-09, 7.5780E-10, 2.4786E-10,
+    2.2384E-13, 2.4600E-14, 1.5699E-14,
+];
+
+/// one group of an exponential decay heat fit,
+///
+/// f(t) = sum_j alpha_j exp(-lambda_j t)
+///
+/// where f(t) is the decay power at time t after a single fission
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct DecayHeatGroup {
+    /// alpha_j, energy release rate per fission
+    pub energy_release_rate_per_fission: Power,
+    /// lambda_j, decay constant
+    pub decay_constant: Frequency,
+}
+
+/// returns the 23 group ANS-5.1 decay heat fit for
+/// U-235 thermal fission
+pub fn u235_ans_5_1_decay_heat_groups() -> Vec<DecayHeatGroup> {
+
+    let mev_per_second_to_watt: f64 =
+        Energy::new::<megaelectronvolt>(1.0).value;
+
+    U235_DECAY_HEAT_ALPHA_MEV_PER_SECOND.iter()
+        .zip(U235_DECAY_HEAT_LAMBDA_PER_SECOND.iter())
+        .map(|(alpha, lambda)| {
+            DecayHeatGroup {
+                energy_release_rate_per_fission:
+                    Power::new::<watt>(
+                        alpha * mev_per_second_to_watt),
+                decay_constant: Frequency::new::<hertz>(*lambda),
+            }
+        }).collect()
+}
+
+/// recoverable energy per fission for U-235 thermal fission,
+/// 200 MeV, used to convert the fission rate into thermal power
+pub fn u235_recoverable_energy_per_fission() -> Energy {
+    Energy::new::<megaelectronvolt>(200.0)
+}
+
+/// decay heat as a fraction of operating power, P_d/P_0, after
+/// operating at constant power P_0 for some operating time
+/// and then shutting down
+///
+/// P_d/P_0 = (1/Q) sum_j (alpha_j/lambda_j)
+///     exp(-lambda_j t_s) [1 - exp(-lambda_j T_op)]
+///
+/// this is the ANS-5.1 form without the neutron capture correction,
+/// where Q is the recoverable energy per fission,
+/// t_s is time after shutdown and T_op is the operating time
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::time::{second, day};
+/// use uom::si::ratio::ratio;
+/// use tuas_boussinesq_solver::reactor_kinetics::decay_heat::*;
+///
+/// // after operating for about three years, decay heat is about
+/// // 6.5% of operating power at shutdown
+/// let decay_heat_fraction = ans_5_1_decay_heat_fraction(
+///     &u235_ans_5_1_decay_heat_groups(),
+///     u235_recoverable_energy_per_fission(),
+///     Time::new::<second>(0.0),
+///     Time::new::<day>(1000.0));
+///
+/// approx::assert_relative_eq!(decay_heat_fraction.get::<ratio>(),
+///     0.065, max_relative=0.01);
+/// ```
+pub fn ans_5_1_decay_heat_fraction(
+    decay_heat_groups: &[DecayHeatGroup],
+    recoverable_energy_per_fission: Energy,
+    time_after_shutdown: Time,
+    operating_time: Time) -> Ratio {
+
+    let t_s = time_after_shutdown.get::<second>();
+    let t_op = operating_time.get::<second>();
+
+    let decay_heat_fraction: f64 = decay_heat_groups.iter()
+        .map(|group| {
+            let lambda = group.decay_constant.get::<hertz>();
+            let alpha_over_q: f64 =
+                (group.energy_release_rate_per_fission
+                 / recoverable_energy_per_fission).get::<hertz>();
+
+            // 1 - exp(-lambda T_op) = -expm1(-lambda T_op),
+            // which keeps precision for the long lived groups
+            alpha_over_q / lambda * (-lambda * t_s).exp()
+                * (-(-lambda * t_op).exp_m1())
+        }).sum();
+
+    Ratio::new::<ratio>(decay_heat_fraction)
+}
+
+/// tracks decay heat during a transient using the exponential
+/// group fit, each group's decay power d_j (as a fraction of
+/// nominal power) obeys:
+///
+/// dd_j/dt = (alpha_j/Q) p(t) - lambda_j d_j
+///
+/// where p(t) is the fission power normalised by nominal power
+///
+/// at steady state after infinite operation, the decay heat
+/// fraction is the sum of (alpha_j/Q)/lambda_j
+#[derive(Debug,Clone,PartialEq)]
+pub struct DecayHeat {
+    /// exponential fit groups
+    pub decay_heat_groups: Vec<DecayHeatGroup>,
+    /// recoverable energy per fission
+    pub recoverable_energy_per_fission: Energy,
+    /// decay power of each group as a fraction of nominal power
+    group_decay_power_fractions: Vec<Ratio>,
+    /// decay power as a fraction of nominal power at the start,
+    /// after the operating time given at construction
+    initial_decay_heat_fraction: Ratio,
+}
+
+impl DecayHeat {
+
+    /// decay heat for U-235 thermal fission (ANS-5.1), after
+    /// operating at nominal power for the given operating time
+    pub fn new_u235(operating_time: Time) -> Self {
+        Self::new(u235_ans_5_1_decay_heat_groups(),
+            u235_recoverable_energy_per_fission(),
+            operating_time)
+    }
+
+    /// decay heat from user supplied groups, after operating
+    /// at nominal power for the given operating time
+    pub fn new(decay_heat_groups: Vec<DecayHeatGroup>,
+        recoverable_energy_per_fission: Energy,
+        operating_time: Time) -> Self {
+
+        let t_op = operating_time.get::<second>();
+
+        let group_decay_power_fractions: Vec<Ratio> =
+            decay_heat_groups.iter().map(|group| {
+                let lambda = group.decay_constant.get::<hertz>();
+                let alpha_over_q: f64 =
+                    (group.energy_release_rate_per_fission
+                     / recoverable_energy_per_fission).get::<hertz>();
+
+                Ratio::new::<ratio>(
+                    alpha_over_q / lambda * (-(-lambda * t_op).exp_m1()))
+            }).collect();
+
+        let initial_decay_heat_fraction = group_decay_power_fractions.iter()
+            .fold(Ratio::new::<ratio>(0.0), |sum, fraction| sum + *fraction);
+
+        Self {
+            decay_heat_groups,
+            recoverable_energy_per_fission,
+            group_decay_power_fractions,
+            initial_decay_heat_fraction,
+        }
+    }
+
+    /// decay heat fraction at equilibrium (infinite operation at
+    /// nominal power), this is the fraction of nominal power
+    /// which comes from decay heat rather than prompt fission energy
+    pub fn get_equilibrium_decay_heat_fraction(&self) -> Ratio {
+        let fraction: f64 = self.decay_heat_groups.iter()
+            .map(|group| {
+                (group.energy_release_rate_per_fission
+                 / self.recoverable_energy_per_fission
+                 / group.decay_constant).get::<ratio>()
+            }).sum();
+
+        Ratio::new::<ratio>(fraction)
+    }
+
+    /// decay power as a fraction of nominal power at the start,
+    /// after operating at nominal power for the operating time
+    /// given at construction
+    ///
+    /// for short operating times this is less than the equilibrium
+    /// decay heat fraction
+    pub fn get_initial_decay_heat_fraction(&self) -> Ratio {
+        self.initial_decay_heat_fraction
+    }
+
+    /// current decay power as a fraction of nominal power
+    pub fn get_decay_heat_fraction(&self) -> Ratio {
+        self.group_decay_power_fractions.iter()
+            .fold(Ratio::new::<ratio>(0.0), |sum, fraction| sum + *fraction)
+    }
+
+    /// advances the decay heat groups by one timestep,
+    /// assuming the normalised fission power is constant over
+    /// the timestep
+    ///
+    /// the group equations are integrated exactly over the timestep,
+    /// so this is stable for any timestep size
+    pub fn advance_timestep(&mut self, timestep: Time,
+        normalised_fission_power: Ratio){
+
+        let dt = timestep.get::<second>();
+        let p = normalised_fission_power.get::<ratio>();
+
+        for (group, decay_power_fraction) in self.decay_heat_groups.iter()
+            .zip(self.group_decay_power_fractions.iter_mut()) {
+
+            let lambda = group.decay_constant.get::<hertz>();
+            let alpha_over_q: f64 =
+                (group.energy_release_rate_per_fission
+                 / self.recoverable_energy_per_fission).get::<hertz>();
+
+            let decay_factor = (-lambda * dt).exp();
+
+            let new_fraction = decay_power_fraction.get::<ratio>()
+                * decay_factor
+                + alpha_over_q / lambda * p * (-(-lambda * dt).exp_m1());
+
+            *decay_power_fraction = Ratio::new::<ratio>(new_fraction);
+        }
+    }
+}
+
+/// decay heat after shutdown from long operation should be
+/// about 1.3% of operating power after one hour and
+/// about 0.5% after one day
+///
+/// the ANS-5.1 standard uses 1e13 s as the operating time for
+/// infinite operation
+///
+/// Todreas, N. E., & Kazimi, M. S. (2021). Nuclear systems volume I:
+/// Thermal hydraulic fundamentals. CRC press.
+#[test]
+pub fn decay_heat_one_hour_and_one_day_after_shutdown(){
+    use uom::si::time::{hour, day};
+
+    let groups = u235_ans_5_1_decay_heat_groups();
+    let q = u235_recoverable_energy_per_fission();
+    let operating_time = Time::new::<second>(1.0e13);
+
+    let one_hour_fraction = ans_5_1_decay_heat_fraction(
+        &groups, q, Time::new::<hour>(1.0), operating_time);
+    let one_day_fraction = ans_5_1_decay_heat_fraction(
+        &groups, q, Time::new::<day>(1.0), operating_time);
+
+    approx::assert_relative_eq!(one_hour_fraction.get::<ratio>(),
+        0.013, max_relative=0.05);
+    approx::assert_relative_eq!(one_day_fraction.get::<ratio>(),
+        0.005, max_relative=0.05);
+}
+
+/// integrating the decay heat groups through a shutdown
+/// (fission power set to zero) should reproduce the
+/// analytical ANS-5.1 shutdown curve
+#[test]
+pub fn decay_heat_transient_matches_shutdown_curve(){
+    use uom::si::time::day;
+
+    let operating_time = Time::new::<day>(30.0);
+    let mut decay_heat = DecayHeat::new_u235(operating_time);
+
+    // at shutdown
+    approx::assert_relative_eq!(
+        decay_heat.get_decay_heat_fraction().get::<ratio>(),
+        ans_5_1_decay_heat_fraction(
+            &decay_heat.decay_heat_groups,
+            decay_heat.recoverable_energy_per_fission,
+            Time::new::<second>(0.0),
+            operating_time).get::<ratio>(),
+        max_relative=1e-12);
+
+    let timestep = Time::new::<second>(0.5);
+    let mut time_after_shutdown = Time::new::<second>(0.0);
+
+    for _ in 0..200 {
+        decay_heat.advance_timestep(timestep, Ratio::new::<ratio>(0.0));
+        time_after_shutdown += timestep;
+    }
+
+    approx::assert_relative_eq!(
+        decay_heat.get_decay_heat_fraction().get::<ratio>(),
+        ans_5_1_decay_heat_fraction(
+            &decay_heat.decay_heat_groups,
+            decay_heat.recoverable_energy_per_fission,
+            time_after_shutdown,
+            operating_time).get::<ratio>(),
+        max_relative=1e-9);
+}
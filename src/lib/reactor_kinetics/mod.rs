@@ -0,0 +1,8 @@
+/// delayed neutron data and the point kinetics equations
+pub mod point_kinetics;
+
+/// ANS-5.1 style decay heat after shutdown and during transients
+pub mod decay_heat;
+
+/// reactivity feedback from temperatures of HeatTransferEntity nodes
+pub mod reactivity_feedback;
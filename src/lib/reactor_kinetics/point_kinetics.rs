@@ -0,0 +1,499 @@
+use ndarray::Array1;
+use uom::si::f64::*;
+use uom::si::frequency::hertz;
+use uom::si::power::watt;
+use uom::si::ratio::ratio;
+use uom::si::time::second;
+
+use crate::array_control_vol_and_fluid_component_collections::
+one_d_fluid_array_with_lateral_coupling::FluidArray;
+use crate::array_control_vol_and_fluid_component_collections::
+one_d_solid_array_with_lateral_coupling::SolidColumn;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::decay_heat::DecayHeat;
+
+/// one group of delayed neutron precursors
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct DelayedNeutronGroup {
+    /// beta_i, delayed neutron fraction of this group
+    pub delayed_neutron_fraction: Ratio,
+    /// lambda_i, precursor decay constant of this group
+    pub decay_constant: Frequency,
+}
+
+/// six group delayed neutron data for thermal fission of U-235,
+/// total delayed neutron fraction is about 0.0065
+///
+/// Keepin, G. R. (1965). Physics of nuclear kinetics.
+/// Addison-Wesley.
+pub fn u235_thermal_delayed_neutron_groups() -> Vec<DelayedNeutronGroup> {
+
+    let total_delayed_neutron_fraction: f64 = 0.0065;
+    let relative_abundances: [f64; 6] =
+        [0.033, 0.219, 0.196, 0.395, 0.115, 0.042];
+    let decay_constants_per_second: [f64; 6] =
+        [0.0124, 0.0305, 0.111, 0.301, 1.14, 3.01];
+
+    relative_abundances.iter()
+        .zip(decay_constants_per_second.iter())
+        .map(|(abundance, lambda)| {
+            DelayedNeutronGroup {
+                delayed_neutron_fraction: Ratio::new::<ratio>(
+                    abundance * total_delayed_neutron_fraction),
+                decay_constant: Frequency::new::<hertz>(*lambda),
+            }
+        }).collect()
+}
+
+/// point reactor kinetics with delayed neutron groups,
+///
+/// dn/dt = (rho - beta)/Lambda n + sum_i lambda_i c_i
+///
+/// dc_i/dt = beta_i/Lambda n - lambda_i c_i
+///
+/// n is the neutron population normalised to 1 at nominal power,
+/// and c_i are the precursor concentrations in the same units.
+///
+/// The thermal power is the nominal power multiplied by n.
+/// If decay heat is included, only the prompt fraction of the nominal
+/// power follows n, and the rest comes from the decay heat groups,
+/// so that at the start the total power is still the nominal power.
+/// After a finite operating time, the decay heat then slowly rises 
+/// towards its equilibrium level while n stays at 1
+#[derive(Debug,Clone,PartialEq)]
+pub struct PointKinetics {
+    /// delayed neutron precursor groups
+    pub delayed_neutron_groups: Vec<DelayedNeutronGroup>,
+    /// Lambda, prompt neutron generation time
+    pub prompt_neutron_generation_time: Time,
+    /// thermal power when n = 1 at equilibrium
+    pub nominal_power: Power,
+    /// decay heat model, None if decay heat is neglected
+    pub decay_heat: Option<DecayHeat>,
+    /// normalised neutron population
+    neutron_population: Ratio,
+    /// normalised precursor concentrations
+    precursor_concentrations: Vec<Ratio>,
+    /// reactivity used in the last timestep
+    reactivity: Ratio,
+}
+
+impl PointKinetics {
+
+    /// point kinetics at nominal power and equilibrium precursor
+    /// concentrations, c_i = beta_i/(lambda_i Lambda)
+    pub fn new(delayed_neutron_groups: Vec<DelayedNeutronGroup>,
+        prompt_neutron_generation_time: Time,
+        nominal_power: Power,
+        decay_heat: Option<DecayHeat>) -> Self {
+
+        let precursor_concentrations: Vec<Ratio> =
+            delayed_neutron_groups.iter().map(|group| {
+                group.delayed_neutron_fraction
+                    / (group.decay_constant * prompt_neutron_generation_time)
+            }).collect();
+
+        Self {
+            delayed_neutron_groups,
+            prompt_neutron_generation_time,
+            nominal_power,
+            decay_heat,
+            neutron_population: Ratio::new::<ratio>(1.0),
+            precursor_concentrations,
+            reactivity: Ratio::new::<ratio>(0.0),
+        }
+    }
+
+    /// six group U-235 thermal fission point kinetics at nominal
+    /// power, with ANS-5.1 decay heat after operating at nominal
+    /// power for the given operating time
+    pub fn new_u235_with_decay_heat(
+        prompt_neutron_generation_time: Time,
+        nominal_power: Power,
+        operating_time: Time) -> Self {
+
+        Self::new(u235_thermal_delayed_neutron_groups(),
+            prompt_neutron_generation_time,
+            nominal_power,
+            Some(DecayHeat::new_u235(operating_time)))
+    }
+
+    /// total delayed neutron fraction, beta
+    pub fn get_total_delayed_neutron_fraction(&self) -> Ratio {
+        self.delayed_neutron_groups.iter()
+            .fold(Ratio::new::<ratio>(0.0),
+            |sum, group| sum + group.delayed_neutron_fraction)
+    }
+
+    /// normalised neutron population, n
+    pub fn get_normalised_neutron_population(&self) -> Ratio {
+        self.neutron_population
+    }
+
+    /// reactivity used in the last timestep
+    pub fn get_reactivity(&self) -> Ratio {
+        self.reactivity
+    }
+
+    /// power from prompt fission energy release
+    ///
+    /// the prompt fraction of nominal power is what is left over
+    /// from the decay heat at the start, so that fission and decay 
+    /// power add up to nominal power initially, whatever the 
+    /// operating time before the transient
+    pub fn get_fission_power(&self) -> Power {
+        let prompt_fraction: Ratio = match &self.decay_heat {
+            Some(decay_heat) => {
+                Ratio::new::<ratio>(1.0)
+                    - decay_heat.get_initial_decay_heat_fraction()
+            },
+            None => Ratio::new::<ratio>(1.0),
+        };
+
+        self.nominal_power * prompt_fraction * self.neutron_population
+    }
+
+    /// power from fission product decay
+    pub fn get_decay_power(&self) -> Power {
+        match &self.decay_heat {
+            Some(decay_heat) => {
+                self.nominal_power * decay_heat.get_decay_heat_fraction()
+            },
+            None => Power::new::<watt>(0.0),
+        }
+    }
+
+    /// total thermal power (fission and decay heat)
+    pub fn get_total_power(&self) -> Power {
+        self.get_fission_power() + self.get_decay_power()
+    }
+
+    /// advances the point kinetics equations by one timestep
+    /// given the total reactivity (external and feedback)
+    ///
+    /// the equations are solved with the implicit (backward) Euler
+    /// method, where the precursor equations are substituted into
+    /// the neutron population equation, so no matrix inversion is
+    /// needed. This is stable for timesteps much larger than
+    /// the prompt neutron lifetime, and then behaves like the
+    /// prompt jump approximation. To resolve the prompt response
+    /// itself, the timestep should be smaller than Lambda/(beta - rho)
+    ///
+    /// reactivity feedback is usually calculated from temperatures
+    /// at the start of the timestep (see TemperatureReactivityFeedback),
+    /// so the coupling to the thermal hydraulics is explicit
+    ///
+    /// the timestep must be positive, and for prompt supercritical
+    /// reactivity (rho > beta) it must be small enough that the
+    /// implicit neutron population stays positive, otherwise an
+    /// error is returned and the state is left unchanged
+    pub fn try_advance_timestep(&mut self, timestep: Time,
+        reactivity: Ratio) -> Result<(), TuasLibError> {
+
+        let dt = timestep.get::<second>();
+
+        if !dt.is_finite() || dt <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "point kinetics timestep must be positive and finite".to_string()));
+        }
+
+        let generation_time = self.prompt_neutron_generation_time
+            .get::<second>();
+        let rho = reactivity.get::<ratio>();
+        let beta = self.get_total_delayed_neutron_fraction().get::<ratio>();
+
+        // c_i^(k+1) = (c_i^k/dt + beta_i/Lambda n^(k+1))/(1/dt + lambda_i)
+        //
+        // substituting into the neutron equation gives
+        // n^(k+1) [1/dt - (rho - beta)/Lambda
+        //      - sum_i lambda_i beta_i/Lambda / (1/dt + lambda_i)]
+        // = n^k/dt + sum_i lambda_i c_i^k/dt / (1/dt + lambda_i)
+        let mut lhs_coefficient: f64 = 1.0/dt - (rho - beta)/generation_time;
+        let mut rhs: f64 = self.neutron_population.get::<ratio>()/dt;
+
+        for (group, concentration) in self.delayed_neutron_groups.iter()
+            .zip(self.precursor_concentrations.iter()) {
+
+            let lambda = group.decay_constant.get::<hertz>();
+            let beta_i = group.delayed_neutron_fraction.get::<ratio>();
+            let denominator = 1.0/dt + lambda;
+
+            lhs_coefficient -= lambda * beta_i / generation_time / denominator;
+            rhs += lambda * concentration.get::<ratio>() / dt / denominator;
+        }
+
+        // for prompt supercritical reactivity and large timesteps,
+        // the implicit solution gives a negative neutron population
+        if lhs_coefficient.is_nan() || lhs_coefficient <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                "point kinetics timestep too large for prompt supercritical \
+                reactivity, reduce the timestep".to_string()));
+        }
+
+        let new_neutron_population: f64 = rhs/lhs_coefficient;
+
+        for (group, concentration) in self.delayed_neutron_groups.iter()
+            .zip(self.precursor_concentrations.iter_mut()) {
+
+            let lambda = group.decay_constant.get::<hertz>();
+            let beta_i = group.delayed_neutron_fraction.get::<ratio>();
+
+            let new_concentration: f64 =
+                (concentration.get::<ratio>()/dt
+                 + beta_i/generation_time * new_neutron_population)
+                / (1.0/dt + lambda);
+
+            *concentration = Ratio::new::<ratio>(new_concentration);
+        }
+
+        self.neutron_population = Ratio::new::<ratio>(new_neutron_population);
+        self.reactivity = reactivity;
+
+        if let Some(decay_heat) = self.decay_heat.as_mut() {
+            decay_heat.advance_timestep(timestep, self.neutron_population);
+        }
+
+        Ok(())
+    }
+
+    /// adds the total power as a heat source to a FluidArray,
+    /// distributed over the nodes according to q_fraction_arr
+    ///
+    /// this should be called once per timestep, since the FluidArray
+    /// clears its power vectors after each timestep
+    pub fn try_link_power_to_fluid_array(&self,
+        fluid_array: &mut FluidArray,
+        q_fraction_arr: Array1<f64>) -> Result<(), TuasLibError> {
+
+        fluid_array.lateral_link_new_power_vector(
+            self.get_total_power(), q_fraction_arr)
+    }
+
+    /// adds the total power as a heat source to a SolidColumn,
+    /// distributed over the nodes according to q_fraction_arr
+    ///
+    /// this should be called once per timestep, since the SolidColumn
+    /// clears its power vectors after each timestep
+    pub fn try_link_power_to_solid_column(&self,
+        solid_column: &mut SolidColumn,
+        q_fraction_arr: Array1<f64>) -> Result<(), TuasLibError> {
+
+        solid_column.lateral_link_new_power_vector(
+            self.get_total_power(), q_fraction_arr)
+    }
+}
+
+/// one group point kinetics with a step reactivity insertion
+/// has an exact solution,
+///
+/// n(t) = A_1 exp(omega_1 t) + A_2 exp(omega_2 t)
+///
+/// where omega are the roots of the inhour equation
+///
+/// Lambda omega^2 + (beta - rho + lambda Lambda) omega - lambda rho = 0
+///
+/// and n(0) = 1, dn/dt(0) = rho/Lambda since the precursors start
+/// at equilibrium
+#[test]
+pub fn one_group_step_reactivity_matches_exact_solution(){
+
+    let beta: f64 = 0.0065;
+    let lambda: f64 = 0.08;
+    let generation_time: f64 = 1.0e-4;
+    let rho: f64 = 0.001;
+
+    let mut point_kinetics = PointKinetics::new(
+        vec![DelayedNeutronGroup {
+            delayed_neutron_fraction: Ratio::new::<ratio>(beta),
+            decay_constant: Frequency::new::<hertz>(lambda),
+        }],
+        Time::new::<second>(generation_time),
+        Power::new::<watt>(1000.0),
+        None);
+
+    let b = beta - rho + lambda * generation_time;
+    let discriminant = (b * b + 4.0 * generation_time * lambda * rho).sqrt();
+    let omega_1 = (-b + discriminant)/(2.0 * generation_time);
+    let omega_2 = (-b - discriminant)/(2.0 * generation_time);
+
+    // A_1 + A_2 = 1, A_1 omega_1 + A_2 omega_2 = rho/Lambda
+    let a_1 = (rho/generation_time - omega_2)/(omega_1 - omega_2);
+    let a_2 = 1.0 - a_1;
+
+    let timestep = Time::new::<second>(1.0e-4);
+    let mut time: f64 = 0.0;
+
+    for _ in 0..50000 {
+        point_kinetics.try_advance_timestep(timestep,
+            Ratio::new::<ratio>(rho)).unwrap();
+        time += timestep.get::<second>();
+    }
+
+    let exact_population = a_1 * (omega_1 * time).exp()
+        + a_2 * (omega_2 * time).exp();
+
+    approx::assert_relative_eq!(
+        point_kinetics.get_normalised_neutron_population().get::<ratio>(),
+        exact_population,
+        max_relative=0.005);
+
+    approx::assert_relative_eq!(
+        point_kinetics.get_total_power().get::<watt>(),
+        1000.0 * exact_population,
+        max_relative=0.005);
+}
+
+/// a zero or negative timestep, or a timestep too large for
+/// prompt supercritical reactivity, should return an error
+/// and leave the neutron population and precursors unchanged
+#[test]
+pub fn invalid_timesteps_return_errors(){
+
+    let mut point_kinetics = PointKinetics::new(
+        u235_thermal_delayed_neutron_groups(),
+        Time::new::<second>(1.0e-4),
+        Power::new::<watt>(1000.0),
+        None);
+
+    let initial_state = point_kinetics.clone();
+
+    assert!(point_kinetics.try_advance_timestep(
+        Time::new::<second>(0.0), Ratio::new::<ratio>(0.0)).is_err());
+    assert!(point_kinetics.try_advance_timestep(
+        Time::new::<second>(-0.01), Ratio::new::<ratio>(0.0)).is_err());
+    assert!(point_kinetics.try_advance_timestep(
+        Time::new::<second>(f64::NAN), Ratio::new::<ratio>(0.0)).is_err());
+
+    // two dollars of reactivity, (rho - beta)/Lambda is 65 per second,
+    // so a 0.1 s timestep gives a negative implicit population
+    assert!(point_kinetics.try_advance_timestep(
+        Time::new::<second>(0.1), Ratio::new::<ratio>(0.013)).is_err());
+
+    assert_eq!(point_kinetics, initial_state);
+
+    // a small enough timestep is fine
+    point_kinetics.try_advance_timestep(
+        Time::new::<second>(1.0e-4), Ratio::new::<ratio>(0.013)).unwrap();
+    assert!(point_kinetics.get_normalised_neutron_population()
+        .get::<ratio>() > 1.0);
+}
+
+/// with zero reactivity, six group point kinetics with decay heat
+/// after long operation should stay at nominal power.
+/// On a large negative reactivity insertion (scram), the power
+/// should drop to the decay heat level, about 6% of nominal power,
+/// within a few seconds
+#[test]
+pub fn six_group_equilibrium_and_scram_with_decay_heat(){
+    use uom::si::power::megawatt;
+
+    // the ANS-5.1 standard uses 1e13 s as the operating time for
+    // infinite operation
+    let mut point_kinetics = PointKinetics::new_u235_with_decay_heat(
+        Time::new::<second>(1.0e-4),
+        Power::new::<megawatt>(10.0),
+        Time::new::<second>(1.0e13));
+
+    let timestep = Time::new::<second>(0.01);
+
+    for _ in 0..1000 {
+        point_kinetics.try_advance_timestep(timestep,
+            Ratio::new::<ratio>(0.0)).unwrap();
+    }
+
+    approx::assert_relative_eq!(
+        point_kinetics.get_total_power().get::<megawatt>(),
+        10.0,
+        max_relative=1e-4);
+
+    // scram, about -10 dollars of reactivity
+    for _ in 0..300 {
+        point_kinetics.try_advance_timestep(timestep,
+            Ratio::new::<ratio>(-0.065)).unwrap();
+    }
+
+    let decay_power = point_kinetics.get_decay_power().get::<megawatt>();
+    let fission_power = point_kinetics.get_fission_power().get::<megawatt>();
+
+    // 3 s after scram, decay heat is about 5.5% of nominal power
+    assert!(decay_power > 0.5 && decay_power < 0.65);
+    // fission power is dominated by the longest lived precursors,
+    // after the prompt drop, n is about beta/(beta - rho) = 0.09
+    assert!(fission_power < 0.1 * 10.0);
+    assert!(fission_power > 0.0);
+}
+
+/// after a short operating time, decay heat is well below its 
+/// equilibrium level, and the prompt fission power should make up 
+/// the difference so that the total power starts at nominal power
+#[test]
+pub fn total_power_is_nominal_at_start_after_short_operation(){
+    use uom::si::power::megawatt;
+
+    let point_kinetics = PointKinetics::new_u235_with_decay_heat(
+        Time::new::<second>(1.0e-4),
+        Power::new::<megawatt>(10.0),
+        Time::new::<second>(100.0));
+
+    let decay_heat = point_kinetics.decay_heat.as_ref().unwrap();
+    assert!(decay_heat.get_initial_decay_heat_fraction() < 
+        decay_heat.get_equilibrium_decay_heat_fraction());
+
+    approx::assert_relative_eq!(
+        point_kinetics.get_total_power().get::<megawatt>(),
+        10.0,
+        max_relative=1e-12);
+}
+
+/// a positive reactivity step with negative temperature feedback
+/// on a lumped heat capacity should settle where the feedback
+/// cancels the reactivity step,
+///
+/// rho_ext + alpha_T (T - T_ref) = 0
+///
+/// and the power then equals the heat removed, hA (T - T_inlet)
+#[test]
+pub fn step_reactivity_with_temperature_feedback_settles(){
+    use uom::si::temperature_coefficient::per_kelvin;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use super::reactivity_feedback::TemperatureReactivityFeedback;
+
+    let feedback = TemperatureReactivityFeedback::new(
+        TemperatureCoefficient::new::<per_kelvin>(-2.0e-5),
+        ThermodynamicTemperature::new::<kelvin>(600.0));
+
+    let mut point_kinetics = PointKinetics::new(
+        u235_thermal_delayed_neutron_groups(),
+        Time::new::<second>(1.0e-4),
+        Power::new::<watt>(1.0e6),
+        None);
+
+    // heat capacity (J/K), conductance to coolant (W/K)
+    // and coolant temperature (K), chosen so that the
+    // initial state is at equilibrium at 600 K
+    let heat_capacity: f64 = 1.0e5;
+    let conductance: f64 = 1.0e4;
+    let coolant_temperature: f64 = 500.0;
+    let mut temperature: f64 = 600.0;
+
+    let external_reactivity = Ratio::new::<ratio>(0.001);
+    let timestep = Time::new::<second>(0.01);
+
+    for _ in 0..30000 {
+        let feedback_reactivity = feedback.get_reactivity_from_temperature(
+            ThermodynamicTemperature::new::<kelvin>(temperature));
+
+        point_kinetics.try_advance_timestep(timestep,
+            external_reactivity + feedback_reactivity).unwrap();
+
+        let power = point_kinetics.get_total_power().get::<watt>();
+        temperature += timestep.get::<second>() / heat_capacity
+            * (power - conductance * (temperature - coolant_temperature));
+    }
+
+    approx::assert_relative_eq!(temperature, 650.0, max_relative=1e-3);
+    approx::assert_relative_eq!(
+        point_kinetics.get_total_power().get::<watt>(),
+        1.5e6, max_relative=1e-2);
+}
@@ -0,0 +1,138 @@
+use uom::si::f64::*;
+use uom::si::ratio::ratio;
+use uom::si::temperature_coefficient::per_kelvin;
+use uom::si::thermodynamic_temperature::kelvin;
+
+use crate::pre_built_components::heat_transfer_entities::HeatTransferEntity;
+use crate::tuas_lib_error::TuasLibError;
+
+/// temperature reactivity feedback from a HeatTransferEntity,
+/// for example, the fuel (a SolidColumn) or the coolant
+/// (a FluidArray) of a heater
+///
+/// rho_feedback = alpha_T (T - T_ref)
+///
+/// where T is the average temperature of the chosen nodes
+/// in the HeatTransferEntity, alpha_T is the temperature
+/// coefficient of reactivity (usually negative) and
+/// T_ref is the temperature at which the feedback reactivity is zero
+#[derive(Debug,Clone,PartialEq)]
+pub struct TemperatureReactivityFeedback {
+    /// temperature coefficient of reactivity (dk/k per kelvin)
+    pub temperature_coefficient: TemperatureCoefficient,
+    /// temperature at which feedback reactivity is zero
+    pub reference_temperature: ThermodynamicTemperature,
+    /// indices of the nodes whose temperatures are averaged,
+    /// if empty, all nodes of the HeatTransferEntity are used
+    pub node_indices: Vec<usize>,
+}
+
+impl TemperatureReactivityFeedback {
+
+    /// feedback based on the average temperature of all nodes
+    pub fn new(temperature_coefficient: TemperatureCoefficient,
+        reference_temperature: ThermodynamicTemperature) -> Self {
+        Self {
+            temperature_coefficient,
+            reference_temperature,
+            node_indices: vec![],
+        }
+    }
+
+    /// feedback based on the average temperature of selected nodes
+    pub fn new_with_node_indices(
+        temperature_coefficient: TemperatureCoefficient,
+        reference_temperature: ThermodynamicTemperature,
+        node_indices: Vec<usize>) -> Self {
+        Self {
+            temperature_coefficient,
+            reference_temperature,
+            node_indices,
+        }
+    }
+
+    /// feedback reactivity given a temperature
+    pub fn get_reactivity_from_temperature(&self,
+        temperature: ThermodynamicTemperature) -> Ratio {
+
+        let temperature_difference: f64 = temperature.get::<kelvin>()
+            - self.reference_temperature.get::<kelvin>();
+
+        Ratio::new::<ratio>(
+            self.temperature_coefficient.get::<per_kelvin>()
+            * temperature_difference)
+    }
+
+    /// feedback reactivity from the temperatures of a
+    /// HeatTransferEntity
+    ///
+    /// returns an error if any of the node indices is out of
+    /// range for the HeatTransferEntity
+    pub fn try_get_reactivity(&self,
+        entity: &mut HeatTransferEntity) -> Result<Ratio, TuasLibError> {
+
+        let temperature_vector: Vec<ThermodynamicTemperature> =
+            entity.get_temperature_vector()?;
+
+        let selected_temperatures: Vec<ThermodynamicTemperature> =
+        if self.node_indices.is_empty() {
+            temperature_vector
+        } else {
+            let mut selected_temperatures = vec![];
+            for index in self.node_indices.iter() {
+                match temperature_vector.get(*index) {
+                    Some(temperature) => selected_temperatures.push(*temperature),
+                    None => {
+                        return Err(TuasLibError::GenericStringError(
+                            format!("reactivity feedback node index {} out of \
+                                range for entity with {} nodes",
+                                index, temperature_vector.len())));
+                    },
+                }
+            }
+            selected_temperatures
+        };
+
+        if selected_temperatures.is_empty() {
+            return Err(TuasLibError::GenericStringError(
+                "no temperatures available for reactivity feedback".to_owned()));
+        }
+
+        let average_temperature_kelvin: f64 = selected_temperatures.iter()
+            .map(|temperature| temperature.get::<kelvin>())
+            .sum::<f64>() / selected_temperatures.len() as f64;
+
+        Ok(self.get_reactivity_from_temperature(
+            ThermodynamicTemperature::new::<kelvin>(average_temperature_kelvin)))
+    }
+}
+
+/// feedback from a constant temperature boundary condition entity
+/// should be alpha_T (T - T_ref), and out of range node
+/// indices should return an error
+#[test]
+pub fn reactivity_feedback_from_boundary_condition_entity(){
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use crate::boundary_conditions::BCType;
+
+    let mut entity = HeatTransferEntity::BoundaryConditions(
+        BCType::new_const_temperature(
+            ThermodynamicTemperature::new::<degree_celsius>(110.0)));
+
+    let feedback = TemperatureReactivityFeedback::new(
+        TemperatureCoefficient::new::<per_kelvin>(-2.0e-5),
+        ThermodynamicTemperature::new::<degree_celsius>(80.0));
+
+    let reactivity = feedback.try_get_reactivity(&mut entity).unwrap();
+
+    approx::assert_relative_eq!(reactivity.get::<ratio>(),
+        -6.0e-4, max_relative=1e-9);
+
+    let out_of_range_feedback =
+        TemperatureReactivityFeedback::new_with_node_indices(
+            TemperatureCoefficient::new::<per_kelvin>(-2.0e-5),
+            ThermodynamicTemperature::new::<degree_celsius>(80.0),
+            vec![3]);
+
+    assert!(out_of_range_feedback.try_get_reactivity(&mut entity).is_err());
+}
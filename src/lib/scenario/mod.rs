@@ -0,0 +1,394 @@
+use std::path::Path;
+
+use uom::si::f64::*;
+use uom::si::time::second;
+use uom::ConstZero;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// parses scenarios from a simple line based text format
+pub mod scenario_parser;
+
+/// a plant model which a scenario can drive
+///
+/// inputs and outputs are addressed by name and exchanged as plain
+/// f64 in SI units (eg. W for heater power, Pa for pump pressure,
+/// K for temperatures, kg/s for mass flowrates). Valves are inputs
+/// which are 1.0 when closed and 0.0 when open
+pub trait ScenarioPlant {
+
+    /// sets a named input of the plant
+    fn try_set_input(&mut self, input_name: &str, value: f64)
+        -> Result<(), TuasLibError>;
+
+    /// gets the current value of a named input of the plant
+    fn try_get_input(&self, input_name: &str) -> Result<f64, TuasLibError>;
+
+    /// gets the current value of a named output (probe) of the plant
+    fn try_get_output(&mut self, output_name: &str) -> Result<f64, TuasLibError>;
+
+    /// advances the plant by one timestep
+    fn try_advance_timestep(&mut self, timestep: Time) -> Result<(), TuasLibError>;
+}
+
+/// what a scenario event does to a plant input once it fires
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScenarioAction {
+    /// sets the input to a new value immediately
+    Step {
+        /// name of the plant input
+        input_name: String,
+        /// new value (SI units)
+        value: f64,
+    },
+    /// changes the input linearly from its value when the event
+    /// fires to the target value over the given duration
+    Ramp {
+        /// name of the plant input
+        input_name: String,
+        /// value at the end of the ramp (SI units)
+        target_value: f64,
+        /// duration of the ramp
+        duration: Time,
+    },
+    /// sets the input to
+    ///
+    /// mean + amplitude * sin (omega * (t - t_fired))
+    ///
+    /// as in the frequency response settings of the educational
+    /// simulator, except that the phase starts from zero when the
+    /// event fires. If a duration is given, the input is returned to
+    /// the mean value afterwards
+    Sinusoid {
+        /// name of the plant input
+        input_name: String,
+        /// mean value (SI units)
+        mean: f64,
+        /// amplitude (SI units)
+        amplitude: f64,
+        /// angular velocity
+        angular_velocity: AngularVelocity,
+        /// how long the perturbation lasts, None means indefinitely
+        duration: Option<Time>,
+    },
+    /// closes a valve, ie. sets the input to 1.0
+    CloseValve {
+        /// name of the valve input
+        valve_name: String,
+    },
+    /// opens a valve, ie. sets the input to 0.0
+    OpenValve {
+        /// name of the valve input
+        valve_name: String,
+    },
+    /// trips a pump or heater, ie. sets the input to zero
+    Trip {
+        /// name of the plant input
+        input_name: String,
+    },
+}
+
+impl ScenarioAction {
+
+    /// name of the plant input this action changes
+    pub fn get_input_name(&self) -> &str {
+        match self {
+            Self::Step { input_name, .. } => input_name,
+            Self::Ramp { input_name, .. } => input_name,
+            Self::Sinusoid { input_name, .. } => input_name,
+            Self::CloseValve { valve_name } => valve_name,
+            Self::OpenValve { valve_name } => valve_name,
+            Self::Trip { input_name } => input_name,
+        }
+    }
+
+    /// value of the input a time after the action started, and
+    /// whether the action is complete
+    ///
+    /// the start value is the input value when the event fired
+    fn get_input_value(&self, start_value: f64, time_since_start: Time) -> (f64, bool) {
+        match self {
+            Self::Step { value, .. } => (*value, true),
+            Self::CloseValve { .. } => (1.0, true),
+            Self::OpenValve { .. } => (0.0, true),
+            Self::Trip { .. } => (0.0, true),
+            Self::Ramp { target_value, duration, .. } => {
+                if time_since_start >= *duration {
+                    return (*target_value, true);
+                }
+                let fraction: f64 = (time_since_start/ *duration).into();
+                (start_value + fraction * (target_value - start_value), false)
+            },
+            Self::Sinusoid { mean, amplitude, angular_velocity, duration, .. } => {
+                if let Some(duration) = duration {
+                    if time_since_start >= *duration {
+                        return (*mean, true);
+                    }
+                }
+                let angular_phase: f64 =
+                    (*angular_velocity * time_since_start).into();
+                (mean + amplitude * angular_phase.sin(), false)
+            },
+        }
+    }
+}
+
+/// decides when a scenario event fires
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScenarioTrigger {
+    /// fires once the simulation time reaches this time
+    AtTime(Time),
+    /// fires once the named output exceeds the threshold (SI units)
+    WhenAbove {
+        /// name of the plant output
+        output_name: String,
+        /// threshold (SI units)
+        threshold: f64,
+    },
+    /// fires once the named output falls below the threshold (SI units)
+    WhenBelow {
+        /// name of the plant output
+        output_name: String,
+        /// threshold (SI units)
+        threshold: f64,
+    },
+}
+
+/// an action together with the trigger which starts it,
+/// each event fires at most once
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioEvent {
+    /// when the event fires
+    pub trigger: ScenarioTrigger,
+    /// what happens when the event fires
+    pub action: ScenarioAction,
+}
+
+impl ScenarioEvent {
+
+    /// creates a new event
+    pub fn new(trigger: ScenarioTrigger, action: ScenarioAction) -> Self {
+        Self { trigger, action }
+    }
+}
+
+/// a sequence of timed and condition triggered events applied to a
+/// plant, together with the timestep and end time of the run
+///
+/// scenarios contain no wall clock or random elements, so replaying
+/// the same scenario on the same plant gives the same results
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scenario {
+    /// timestep used to advance the plant
+    pub timestep: Time,
+    /// simulation time at which the run stops
+    pub end_time: Time,
+    /// events, these are checked in order every timestep
+    pub events: Vec<ScenarioEvent>,
+    /// plant outputs recorded every timestep
+    pub recorded_outputs: Vec<String>,
+}
+
+/// a record of when an event fired
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiredEvent {
+    /// simulation time at which the event fired
+    pub simulation_time: Time,
+    /// index of the event in the scenario
+    pub event_index: usize,
+}
+
+/// results of a scenario run
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScenarioRecord {
+    /// simulation times at which outputs were recorded
+    pub simulation_times: Vec<Time>,
+    /// names of the recorded outputs
+    pub output_names: Vec<String>,
+    /// recorded output values, one row per recorded time, in the
+    /// order of the output names (SI units)
+    pub output_values: Vec<Vec<f64>>,
+    /// events in the order in which they fired
+    pub fired_events: Vec<FiredEvent>,
+}
+
+impl ScenarioRecord {
+
+    /// gets the time history of a recorded output
+    pub fn try_get_output_history(&self, output_name: &str)
+        -> Result<Vec<f64>, TuasLibError> {
+
+        let column = self.output_names.iter()
+            .position(|name| name == output_name)
+            .ok_or(TuasLibError::GenericStringError(
+                format!("output {} was not recorded", output_name)))?;
+
+        Ok(self.output_values.iter().map(|row| row[column]).collect())
+    }
+}
+
+/// an action which has fired and is still changing its input
+struct ActiveAction {
+    action: ScenarioAction,
+    start_time: Time,
+    start_value: f64,
+}
+
+impl Scenario {
+
+    /// creates an empty scenario
+    pub fn new(timestep: Time, end_time: Time) -> Result<Self, TuasLibError> {
+
+        if !(timestep.get::<second>() > 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                "scenario timestep must be positive".to_owned()));
+        }
+
+        if !(end_time.get::<second>() >= 0.0) {
+            return Err(TuasLibError::GenericStringError(
+                "scenario end time must not be negative".to_owned()));
+        }
+
+        Ok(Self {
+            timestep,
+            end_time,
+            events: vec![],
+            recorded_outputs: vec![],
+        })
+    }
+
+    /// adds an event to the scenario
+    pub fn add_event(&mut self, trigger: ScenarioTrigger, action: ScenarioAction) {
+        self.events.push(ScenarioEvent::new(trigger, action));
+    }
+
+    /// records an output every timestep
+    pub fn add_recorded_output(&mut self, output_name: &str) {
+        self.recorded_outputs.push(output_name.to_owned());
+    }
+
+    /// loads a scenario from a text file,
+    /// see [scenario_parser::try_parse_scenario] for the format
+    pub fn try_load<P: AsRef<Path>>(path: P) -> Result<Self, TuasLibError> {
+
+        let scenario_text = std::fs::read_to_string(path)
+            .map_err(|error| TuasLibError::GenericStringError(
+                format!("could not read scenario file: {}", error)))?;
+
+        scenario_parser::try_parse_scenario(&scenario_text)
+    }
+
+    /// runs the scenario on the plant from simulation time zero
+    /// until the end time
+    ///
+    /// each timestep, the triggers are checked against the plant
+    /// outputs at the start of the timestep, fired and active actions
+    /// set the plant inputs, the outputs are recorded, and then the
+    /// plant is advanced. A later action on the same input replaces
+    /// an earlier one which is still active
+    pub fn try_run<P: ScenarioPlant>(&self, plant: &mut P)
+        -> Result<ScenarioRecord, TuasLibError> {
+
+        let mut record = ScenarioRecord {
+            output_names: self.recorded_outputs.clone(),
+            ..Default::default()
+        };
+
+        let mut has_fired = vec![false; self.events.len()];
+        let mut active_actions: Vec<ActiveAction> = vec![];
+
+        // time triggers fire if they fall within half a timestep,
+        // so that round off in the accumulated time does not delay
+        // them by a whole timestep
+        let time_tolerance = self.timestep * 0.5;
+
+        let mut timestep_number: u64 = 0;
+        let mut simulation_time = Time::ZERO;
+
+        loop {
+
+            for (event_index, event) in self.events.iter().enumerate() {
+
+                if has_fired[event_index] {
+                    continue;
+                }
+
+                let should_fire = match &event.trigger {
+                    ScenarioTrigger::AtTime(trigger_time) => {
+                        simulation_time + time_tolerance > *trigger_time
+                    },
+                    ScenarioTrigger::WhenAbove { output_name, threshold } => {
+                        plant.try_get_output(output_name)? > *threshold
+                    },
+                    ScenarioTrigger::WhenBelow { output_name, threshold } => {
+                        plant.try_get_output(output_name)? < *threshold
+                    },
+                };
+
+                if !should_fire {
+                    continue;
+                }
+
+                has_fired[event_index] = true;
+                record.fired_events.push(FiredEvent {
+                    simulation_time,
+                    event_index,
+                });
+
+                let input_name = event.action.get_input_name();
+                active_actions.retain(|active| active.action.get_input_name() != input_name);
+                active_actions.push(ActiveAction {
+                    action: event.action.clone(),
+                    start_time: simulation_time,
+                    start_value: plant.try_get_input(input_name)?,
+                });
+            }
+
+            let mut completed_actions = vec![];
+            for (action_index, active) in active_actions.iter().enumerate() {
+
+                let (value, is_complete) = active.action.get_input_value(
+                    active.start_value,
+                    simulation_time - active.start_time);
+
+                plant.try_set_input(active.action.get_input_name(), value)?;
+
+                if is_complete {
+                    completed_actions.push(action_index);
+                }
+            }
+            for action_index in completed_actions.into_iter().rev() {
+                active_actions.remove(action_index);
+            }
+
+            let mut output_row = Vec::with_capacity(self.recorded_outputs.len());
+            for output_name in self.recorded_outputs.iter() {
+                output_row.push(plant.try_get_output(output_name)?);
+            }
+            record.simulation_times.push(simulation_time);
+            record.output_values.push(output_row);
+
+            if simulation_time + time_tolerance > self.end_time {
+                break;
+            }
+
+            plant.try_advance_timestep(self.timestep)?;
+
+            // computing the time from the timestep number rather than
+            // by accumulation keeps event times reproducible
+            timestep_number += 1;
+            simulation_time = self.timestep * (timestep_number as f64);
+        }
+
+        Ok(record)
+    }
+}
+
+/// tests for scenarios and the scenario parser
+#[cfg(test)]
+pub mod tests;
+
+/// plants shared by the scenario, controls, fmi export and plant
+/// server tests
+#[cfg(test)]
+pub(crate) mod test_plants;
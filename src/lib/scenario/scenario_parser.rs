@@ -0,0 +1,281 @@
+//! scenarios are written one statement per line, words are
+//! separated by whitespace and anything after a # is a comment
+//!
+//! ```text
+//! # run settings
+//! timestep 0.2 s
+//! end_time 600 s
+//! record bt_12_heater_outlet
+//!
+//! # timed events
+//! at 10 s step heater_power 2 kW
+//! at 100 s ramp ctah_pump_pressure 0 Pa over 20 s
+//! at 200 s sinusoid heater_power mean 2 kW amplitude 0.5 kW omega 0.1 rad/s for 100 s
+//! at 300 s close dhx_branch_blocked
+//! at 400 s open dhx_branch_blocked
+//!
+//! # condition triggered events
+//! when bt_12_heater_outlet above 110 degC trip ctah_pump_pressure
+//! ```
+//!
+//! numbers may be followed by a unit, they are converted to SI units
+//! (s, W, Pa, K, kg/s, rad/s). Without a unit, numbers are taken
+//! to be in SI units already. Temperatures in degC are absolute
+//! temperatures, except for sinusoid amplitudes where 1 degC is
+//! a difference of 1 K
+use std::f64::consts::PI;
+
+use uom::si::angular_velocity::radian_per_second;
+use uom::si::f64::*;
+use uom::si::time::second;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::{Scenario, ScenarioAction, ScenarioTrigger};
+
+/// whether a quantity is an absolute value or a difference,
+/// this only matters for temperatures in degC
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum QuantityKind {
+    Absolute,
+    Difference,
+}
+
+/// converts a value with a unit into SI units, returns None if
+/// the unit is not known
+fn try_convert_to_si(value: f64, unit: &str, kind: QuantityKind) -> Option<f64> {
+
+    let si_value = match unit {
+        "s" | "W" | "Pa" | "K" | "kg/s" | "rad/s" => value,
+        "ms" => value * 1.0e-3,
+        "min" => value * 60.0,
+        "h" => value * 3600.0,
+        "kW" | "kPa" => value * 1.0e3,
+        "MW" | "MPa" => value * 1.0e6,
+        "bar" => value * 1.0e5,
+        "Hz" => value * 2.0 * PI,
+        "degC" => match kind {
+            QuantityKind::Absolute => value + 273.15,
+            QuantityKind::Difference => value,
+        },
+        _ => return None,
+    };
+
+    Some(si_value)
+}
+
+/// reads words of a single statement
+struct StatementReader<'a> {
+    words: Vec<&'a str>,
+    position: usize,
+    line_number: usize,
+}
+
+impl<'a> StatementReader<'a> {
+
+    fn error(&self, message: &str) -> TuasLibError {
+        TuasLibError::GenericStringError(
+            format!("scenario line {}: {}", self.line_number, message))
+    }
+
+    fn try_next_word(&mut self, expected: &str) -> Result<&'a str, TuasLibError> {
+        let word = self.words.get(self.position)
+            .ok_or(self.error(&format!("expected {}", expected)))?;
+        self.position += 1;
+        Ok(word)
+    }
+
+    fn peek_word(&self) -> Option<&'a str> {
+        self.words.get(self.position).copied()
+    }
+
+    fn try_expect_keyword(&mut self, keyword: &str) -> Result<(), TuasLibError> {
+        let word = self.try_next_word(keyword)?;
+        if word != keyword {
+            return Err(self.error(&format!("expected {}, got {}", keyword, word)));
+        }
+        Ok(())
+    }
+
+    /// reads a number and an optional unit
+    fn try_next_quantity(&mut self, kind: QuantityKind) -> Result<f64, TuasLibError> {
+        let number_word = self.try_next_word("a number")?;
+        let value: f64 = number_word.parse()
+            .map_err(|_| self.error(&format!("expected a number, got {}", number_word)))?;
+
+        if let Some(unit) = self.peek_word() {
+            if let Some(si_value) = try_convert_to_si(value, unit, kind) {
+                self.position += 1;
+                return Ok(si_value);
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn try_next_time(&mut self) -> Result<Time, TuasLibError> {
+        Ok(Time::new::<second>(self.try_next_quantity(QuantityKind::Absolute)?))
+    }
+
+    fn try_finish(&self) -> Result<(), TuasLibError> {
+        match self.peek_word() {
+            Some(word) => Err(self.error(&format!("unexpected {}", word))),
+            None => Ok(()),
+        }
+    }
+
+    fn try_next_action(&mut self) -> Result<ScenarioAction, TuasLibError> {
+
+        let action_word = self.try_next_word("an action")?;
+
+        let action = match action_word {
+            "step" => {
+                let input_name = self.try_next_word("an input name")?.to_owned();
+                let value = self.try_next_quantity(QuantityKind::Absolute)?;
+                ScenarioAction::Step { input_name, value }
+            },
+            "ramp" => {
+                let input_name = self.try_next_word("an input name")?.to_owned();
+                let target_value = self.try_next_quantity(QuantityKind::Absolute)?;
+                self.try_expect_keyword("over")?;
+                let duration = self.try_next_time()?;
+                ScenarioAction::Ramp { input_name, target_value, duration }
+            },
+            "sinusoid" => {
+                let input_name = self.try_next_word("an input name")?.to_owned();
+                self.try_expect_keyword("mean")?;
+                let mean = self.try_next_quantity(QuantityKind::Absolute)?;
+                self.try_expect_keyword("amplitude")?;
+                let amplitude = self.try_next_quantity(QuantityKind::Difference)?;
+                self.try_expect_keyword("omega")?;
+                let angular_velocity = AngularVelocity::new::<radian_per_second>(
+                    self.try_next_quantity(QuantityKind::Absolute)?);
+                let duration = match self.peek_word() {
+                    Some("for") => {
+                        self.position += 1;
+                        Some(self.try_next_time()?)
+                    },
+                    _ => None,
+                };
+                ScenarioAction::Sinusoid {
+                    input_name, mean, amplitude, angular_velocity, duration
+                }
+            },
+            "close" => ScenarioAction::CloseValve {
+                valve_name: self.try_next_word("a valve name")?.to_owned()
+            },
+            "open" => ScenarioAction::OpenValve {
+                valve_name: self.try_next_word("a valve name")?.to_owned()
+            },
+            "trip" => ScenarioAction::Trip {
+                input_name: self.try_next_word("an input name")?.to_owned()
+            },
+            _ => return Err(self.error(&format!("unknown action {}", action_word))),
+        };
+
+        Ok(action)
+    }
+}
+
+/// parses a scenario from text, the timestep and end time must be
+/// given
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::time::second;
+/// use tuas_boussinesq_solver::scenario::{ScenarioAction, ScenarioTrigger};
+/// use tuas_boussinesq_solver::scenario::scenario_parser::try_parse_scenario;
+///
+/// let scenario = try_parse_scenario("
+///     timestep 0.5 s
+///     end_time 2 min
+///     at 10 s ramp heater_power 2 kW over 20 s
+///     when bt_12_heater_outlet above 110 degC trip ctah_pump_pressure
+/// ").unwrap();
+///
+/// assert_eq!(scenario.end_time, Time::new::<second>(120.0));
+/// match &scenario.events[1].trigger {
+///     ScenarioTrigger::WhenAbove { output_name, threshold } => {
+///         assert_eq!(output_name, "bt_12_heater_outlet");
+///         // degC is converted to kelvin
+///         approx::assert_relative_eq!(*threshold, 383.15, max_relative=1e-12);
+///     },
+///     _ => panic!("expected a WhenAbove trigger"),
+/// }
+/// assert_eq!(scenario.events[1].action, ScenarioAction::Trip {
+///     input_name: "ctah_pump_pressure".to_owned(),
+/// });
+/// ```
+pub fn try_parse_scenario(scenario_text: &str) -> Result<Scenario, TuasLibError> {
+
+    let mut timestep: Option<Time> = None;
+    let mut end_time: Option<Time> = None;
+    let mut events = vec![];
+    let mut recorded_outputs = vec![];
+
+    for (line_index, line) in scenario_text.lines().enumerate() {
+
+        let statement = line.split('#').next().unwrap_or("");
+
+        let mut reader = StatementReader {
+            words: statement.split_whitespace().collect(),
+            position: 0,
+            line_number: line_index + 1,
+        };
+
+        let keyword = match reader.peek_word() {
+            Some(keyword) => keyword,
+            None => continue,
+        };
+        reader.position += 1;
+
+        match keyword {
+            "timestep" => {
+                timestep = Some(reader.try_next_time()?);
+            },
+            "end_time" => {
+                end_time = Some(reader.try_next_time()?);
+            },
+            "record" => {
+                recorded_outputs.push(
+                    reader.try_next_word("an output name")?.to_owned());
+            },
+            "at" => {
+                let trigger = ScenarioTrigger::AtTime(reader.try_next_time()?);
+                let action = reader.try_next_action()?;
+                events.push((trigger, action));
+            },
+            "when" => {
+                let output_name = reader.try_next_word("an output name")?.to_owned();
+                let comparison = reader.try_next_word("above or below")?;
+                let threshold = reader.try_next_quantity(QuantityKind::Absolute)?;
+                let trigger = match comparison {
+                    "above" => ScenarioTrigger::WhenAbove { output_name, threshold },
+                    "below" => ScenarioTrigger::WhenBelow { output_name, threshold },
+                    _ => return Err(reader.error(
+                        &format!("expected above or below, got {}", comparison))),
+                };
+                let action = reader.try_next_action()?;
+                events.push((trigger, action));
+            },
+            _ => return Err(reader.error(&format!("unknown statement {}", keyword))),
+        }
+
+        reader.try_finish()?;
+    }
+
+    let timestep = timestep.ok_or(TuasLibError::GenericStringError(
+        "scenario must specify a timestep".to_owned()))?;
+    let end_time = end_time.ok_or(TuasLibError::GenericStringError(
+        "scenario must specify an end_time".to_owned()))?;
+
+    let mut scenario = Scenario::new(timestep, end_time)?;
+    for (trigger, action) in events {
+        scenario.add_event(trigger, action);
+    }
+    for output_name in recorded_outputs.iter() {
+        scenario.add_recorded_output(output_name);
+    }
+
+    Ok(scenario)
+}
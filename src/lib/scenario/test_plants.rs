@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use uom::si::f64::*;
+use uom::si::time::second;
+
+use crate::fmi_export::{Fmi2Model, FmiVariable};
+use crate::tuas_lib_error::TuasLibError;
+
+use super::ScenarioPlant;
+
+/// a lumped heated tank with a cooler, used as a cheap stand in
+/// for a loop
+///
+/// C dT/dt = P - hA (T - T_amb)
+///
+/// with C = 1000 J/K, T_amb = 300 K and hA = 10 W/K plus whatever
+/// the cooler adds
+///
+/// the pump pressure and valve do nothing to the tank, they are
+/// only there to be set and read back. The tank also counts its
+/// timesteps
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeatedTank {
+    /// heater power (W)
+    pub heater_power_watts: f64,
+    /// pump pressure (Pa)
+    pub pump_pressure_pascals: f64,
+    /// 1.0 when closed and 0.0 when open
+    pub valve_closed: f64,
+    /// heat loss coefficient added by the cooler (W/K)
+    pub cooler_heat_loss_coefficient: f64,
+    /// tank temperature (K)
+    pub temperature_kelvin: f64,
+    /// number of timesteps taken
+    pub number_of_timesteps: usize,
+}
+
+impl Default for HeatedTank {
+    fn default() -> Self {
+        Self {
+            heater_power_watts: 0.0,
+            pump_pressure_pascals: 1000.0,
+            valve_closed: 0.0,
+            cooler_heat_loss_coefficient: 0.0,
+            temperature_kelvin: 300.0,
+            number_of_timesteps: 0,
+        }
+    }
+}
+
+impl HeatedTank {
+
+    /// heat lost to ambient and the cooler (W)
+    pub fn get_heat_loss_watts(&self) -> f64 {
+        let heat_loss_coefficient = 10.0 + self.cooler_heat_loss_coefficient;
+        let ambient_temperature = 300.0;
+
+        heat_loss_coefficient * (self.temperature_kelvin - ambient_temperature)
+    }
+
+    /// explicit euler step of the tank temperature
+    pub fn advance(&mut self, timestep: Time) {
+        let heat_capacity = 1000.0;
+
+        self.temperature_kelvin += timestep.get::<second>()
+            * (self.heater_power_watts - self.get_heat_loss_watts())
+            / heat_capacity;
+        self.number_of_timesteps += 1;
+    }
+}
+
+impl ScenarioPlant for HeatedTank {
+
+    fn try_set_input(&mut self, input_name: &str, value: f64)
+        -> Result<(), TuasLibError> {
+        match input_name {
+            "heater_power" => self.heater_power_watts = value,
+            "pump_pressure" => self.pump_pressure_pascals = value,
+            "valve" => self.valve_closed = value,
+            "cooler_heat_loss_coefficient" => self.cooler_heat_loss_coefficient = value,
+            _ => return Err(TuasLibError::GenericStringError(
+                format!("no input named {}", input_name))),
+        }
+        Ok(())
+    }
+
+    fn try_get_input(&self, input_name: &str) -> Result<f64, TuasLibError> {
+        match input_name {
+            "heater_power" => Ok(self.heater_power_watts),
+            "pump_pressure" => Ok(self.pump_pressure_pascals),
+            "valve" => Ok(self.valve_closed),
+            "cooler_heat_loss_coefficient" => Ok(self.cooler_heat_loss_coefficient),
+            _ => Err(TuasLibError::GenericStringError(
+                format!("no input named {}", input_name))),
+        }
+    }
+
+    fn try_get_output(&mut self, output_name: &str) -> Result<f64, TuasLibError> {
+        match output_name {
+            "temperature" => Ok(self.temperature_kelvin),
+            "heat_loss" => Ok(self.get_heat_loss_watts()),
+            "number_of_timesteps" => Ok(self.number_of_timesteps as f64),
+            _ => self.try_get_input(output_name),
+        }
+    }
+
+    fn try_advance_timestep(&mut self, timestep: Time) -> Result<(), TuasLibError> {
+        self.advance(timestep);
+        Ok(())
+    }
+}
+
+/// only the heater power input, the temperature and the number of
+/// timesteps are exposed, with the initial temperature as a
+/// parameter
+impl Fmi2Model for HeatedTank {
+
+    fn get_model_name() -> String {
+        "Heated <Tank>".to_owned()
+    }
+
+    fn get_model_variables() -> Vec<FmiVariable> {
+        vec![
+            FmiVariable::new_parameter("initial_temperature", 300.0, "(K)"),
+            FmiVariable::new_input("heater_power", 0.0, "(W)"),
+            FmiVariable::new_output("temperature", "(K)"),
+            FmiVariable::new_output("number_of_timesteps", "timesteps taken"),
+        ]
+    }
+
+    fn try_new_from_parameters(parameters: &HashMap<String, f64>)
+        -> Result<Self, TuasLibError> {
+        Ok(Self {
+            temperature_kelvin: parameters["initial_temperature"],
+            ..Default::default()
+        })
+    }
+
+    fn get_maximum_internal_timestep() -> Option<Time> {
+        Some(Time::new::<second>(0.5))
+    }
+}
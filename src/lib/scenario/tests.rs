@@ -0,0 +1,159 @@
+use uom::si::time::second;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::scenario_parser::try_parse_scenario;
+use super::test_plants::HeatedTank;
+
+/// steps, ramps, valve closures and trips set the inputs at the
+/// scheduled times
+#[test]
+pub fn timed_steps_ramps_and_valves(){
+
+    let scenario = try_parse_scenario("
+        # settings
+        timestep 0.5 s
+        end_time 30 s
+        record heater_power
+        record pump_pressure
+        record valve
+
+        at 5 s step heater_power 2 kW     # step up
+        at 10 s ramp pump_pressure 0 Pa over 10 s
+        at 12 s close valve
+        at 25 s open valve
+    ").unwrap();
+
+    let mut tank = HeatedTank::default();
+    let record = scenario.try_run(&mut tank).unwrap();
+
+    // recorded from 0 s to 30 s inclusive
+    assert_eq!(record.simulation_times.len(), 61);
+    assert_eq!(record.fired_events.len(), 4);
+
+    let heater_power = record.try_get_output_history("heater_power").unwrap();
+    let pump_pressure = record.try_get_output_history("pump_pressure").unwrap();
+    let valve = record.try_get_output_history("valve").unwrap();
+
+    // index = time/0.5 s
+    assert_eq!(heater_power[9], 0.0);
+    assert_eq!(heater_power[10], 2000.0);
+
+    assert_eq!(pump_pressure[20], 1000.0);
+    approx::assert_relative_eq!(pump_pressure[30], 500.0, max_relative=1e-12);
+    assert_eq!(pump_pressure[40], 0.0);
+    assert_eq!(pump_pressure[60], 0.0);
+
+    assert_eq!(valve[23], 0.0);
+    assert_eq!(valve[24], 1.0);
+    assert_eq!(valve[50], 0.0);
+}
+
+/// the heater is tripped when the temperature exceeds a threshold,
+/// 300 K + 100 K * (1 - exp(-t/100 s)) first exceeds 30 degC
+/// (303.15 K) at about 3.2 s
+#[test]
+pub fn condition_triggered_trip(){
+
+    let scenario = try_parse_scenario("
+        timestep 0.1 s
+        end_time 20 s
+        record temperature
+        at 0 s step heater_power 1 kW
+        when temperature above 30 degC trip heater_power
+    ").unwrap();
+
+    let mut tank = HeatedTank::default();
+    let record = scenario.try_run(&mut tank).unwrap();
+
+    assert_eq!(record.fired_events.len(), 2);
+    let trip_time = record.fired_events[1].simulation_time.get::<second>();
+    approx::assert_abs_diff_eq!(trip_time, 3.2, epsilon=0.11);
+
+    assert_eq!(tank.heater_power_watts, 0.0);
+
+    // the tank cools once tripped
+    let temperature = record.try_get_output_history("temperature").unwrap();
+    assert!(temperature.last().unwrap() < &303.3);
+}
+
+/// a sinusoid starts from its mean with zero phase and returns to
+/// the mean once its duration is over
+#[test]
+pub fn sinusoid_returns_to_mean(){
+
+    let scenario = try_parse_scenario("
+        timestep 0.25 s
+        end_time 10 s
+        record heater_power
+        at 1 s sinusoid heater_power mean 1 kW amplitude 0.5 kW omega 0.25 Hz for 4 s
+    ").unwrap();
+
+    let mut tank = HeatedTank::default();
+    let record = scenario.try_run(&mut tank).unwrap();
+    let heater_power = record.try_get_output_history("heater_power").unwrap();
+
+    // one second into the sinusoid (index 8) is a quarter period
+    approx::assert_relative_eq!(heater_power[4], 1000.0, max_relative=1e-12);
+    approx::assert_relative_eq!(heater_power[8], 1500.0, max_relative=1e-12);
+    approx::assert_relative_eq!(heater_power[16], 500.0, max_relative=1e-12);
+    assert_eq!(heater_power[20], 1000.0);
+    assert_eq!(heater_power[40], 1000.0);
+}
+
+/// running the same scenario twice gives identical records
+#[test]
+pub fn scenario_replay_is_deterministic(){
+
+    let scenario = try_parse_scenario("
+        timestep 0.1 s
+        end_time 60 s
+        record temperature
+        at 0 s sinusoid heater_power mean 2 kW amplitude 1 kW omega 0.3 rad/s
+        at 20 s ramp pump_pressure 0 kPa over 15 s
+        when temperature above 35 degC trip heater_power
+    ").unwrap();
+
+    let mut first_tank = HeatedTank::default();
+    let mut second_tank = HeatedTank::default();
+
+    let first_record = scenario.try_run(&mut first_tank).unwrap();
+    let second_record = scenario.try_run(&mut second_tank).unwrap();
+
+    assert_eq!(first_record, second_record);
+    assert_eq!(first_tank, second_tank);
+}
+
+/// malformed scenarios give errors with the offending line
+#[test]
+pub fn scenario_parse_errors(){
+
+    let missing_end_time = try_parse_scenario("timestep 1 s");
+    assert!(missing_end_time.is_err());
+
+    let unknown_action = try_parse_scenario("
+        timestep 1 s
+        end_time 10 s
+        at 1 s explode heater_power
+    ");
+    match unknown_action {
+        Err(TuasLibError::GenericStringError(message)) => {
+            assert!(message.contains("line 4"));
+        },
+        _ => panic!("expected a parse error"),
+    }
+
+    let trailing_words = try_parse_scenario("
+        timestep 1 s
+        end_time 10 s extra
+    ");
+    assert!(trailing_words.is_err());
+
+    // unknown inputs are only found when the scenario is run
+    let scenario = try_parse_scenario("
+        timestep 1 s
+        end_time 10 s
+        at 1 s step coolant_pump 5 kPa
+    ").unwrap();
+    assert!(scenario.try_run(&mut HeatedTank::default()).is_err());
+}
@@ -8,6 +8,7 @@ use crate::heat_transfer_correlations::heat_transfer_interactions::advection_hea
 use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::DataAdvection;
 use crate::single_control_vol::SingleCVNode;
 use crate::tuas_lib_error::TuasLibError;
+use crate::energy_audit::energy_tally::EnergyTallySource;
 
 
 impl SingleCVNode {
@@ -55,8 +56,9 @@ impl SingleCVNode {
                 specific_enthalpy_cv,)?;
 
         // push to cv
-        self.rate_enthalpy_change_vector.
-            push(heat_flowrate_from_bc_to_cv);
+        self.push_rate_enthalpy_change(
+            heat_flowrate_from_bc_to_cv,
+            EnergyTallySource::BoundaryAdvection);
 
 
         let density_cv = advection_data.fluid_density_heat_transfer_entity_2;
@@ -140,8 +142,9 @@ impl SingleCVNode {
                 specific_enthalpy_bc_zero_gradient,)?;
 
         // push to cv
-        self.rate_enthalpy_change_vector.
-            push(-heat_flowrate_from_bc_to_cv);
+        self.push_rate_enthalpy_change(
+            -heat_flowrate_from_bc_to_cv,
+            EnergyTallySource::BoundaryAdvection);
 
 
         let density_cv = advection_data.fluid_density_heat_transfer_entity_2;
@@ -222,8 +225,9 @@ impl SingleCVNode {
                 specific_enthalpy_cv,)?;
 
         // push to cv
-        self.rate_enthalpy_change_vector.
-            push(heat_flowrate_from_bc_to_cv);
+        self.push_rate_enthalpy_change(
+            heat_flowrate_from_bc_to_cv,
+            EnergyTallySource::BoundaryAdvection);
 
 
         let density_cv = advection_data.fluid_density_heat_transfer_entity_2;
@@ -302,8 +306,9 @@ impl SingleCVNode {
                                   specific_enthalpy_bc,)?;
 
         // push to cv
-        self.rate_enthalpy_change_vector.
-            push(-heat_flowrate_from_bc_to_cv);
+        self.push_rate_enthalpy_change(
+            -heat_flowrate_from_bc_to_cv,
+            EnergyTallySource::BoundaryAdvection);
 
 
         let density_cv = advection_data.fluid_density_heat_transfer_entity_2;
@@ -10,6 +10,7 @@ use crate::boussinesq_thermophysical_properties::Material;
 use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
 use crate::single_control_vol::SingleCVNode;
 use crate::tuas_lib_error::TuasLibError;
+use crate::energy_audit::energy_tally::EnergyTallySource;
 
 impl SingleCVNode {
     /// calculates a conductance interaction between the constant 
@@ -71,8 +72,9 @@ impl SingleCVNode {
         // to the control volume 
         //
 
-        self.rate_enthalpy_change_vector.
-            push(-heat_flowrate_from_cv_to_bc);
+        self.push_rate_enthalpy_change(
+            -heat_flowrate_from_cv_to_bc,
+            EnergyTallySource::BoundaryHeatTransfer);
 
         // for constant temperature BC in interaction with CV,
         // we only need take into consideration the CV 
@@ -157,6 +159,22 @@ impl SingleCVNode {
                     ()
                 }
             ,
+            HeatTransferInteractionType::
+                DualCylindricalThermalConductanceWithInterfaceResistance(_) |
+            HeatTransferInteractionType::
+                CylindricalConductionConvectionWithFouling(_) => {
+
+                    // interface resistances only lower the conductance,
+                    // so the timestep without them is conservative
+                    let perfect_contact_timestep: Time = 
+                        self.calculate_mesh_stability_conduction_timestep_for_single_node_and_bc(
+                            interaction.get_interaction_without_interface_resistance())?;
+
+                    if cv_timestep > perfect_contact_timestep {
+                        cv_timestep = perfect_contact_timestep;
+                    }
+                }
+            ,
             HeatTransferInteractionType::
                 UserSpecifiedThermalConductance(_) => {
 
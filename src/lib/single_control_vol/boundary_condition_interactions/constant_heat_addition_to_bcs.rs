@@ -5,6 +5,7 @@ use crate::single_control_vol::SingleCVNode;
 use crate::heat_transfer_correlations::heat_transfer_interactions::heat_transfer_interaction_enums::HeatTransferInteractionType;
 
 use crate::tuas_lib_error::TuasLibError;
+use crate::energy_audit::energy_tally::EnergyTallySource;
 
 /// calculates the interaction between a heat addition BC and 
 /// a control volume 
@@ -50,8 +51,9 @@ pub fn calculate_constant_heat_addition_front_single_cv_back(
     };
 
 
-    control_vol.rate_enthalpy_change_vector.
-        push(heat_added_to_control_vol);
+    control_vol.push_rate_enthalpy_change(
+        heat_added_to_control_vol,
+        EnergyTallySource::HeatSource);
 
     // auto time stepping doesn't work for constant heat flux 
     // or specified power as well. 
@@ -134,8 +136,9 @@ pub fn calculate_single_cv_front_constant_heat_addition_back(
     };
 
 
-    control_vol.rate_enthalpy_change_vector.
-        push(heat_added_to_control_vol);
+    control_vol.push_rate_enthalpy_change(
+        heat_added_to_control_vol,
+        EnergyTallySource::HeatSource);
 
     // auto time stepping doesn't work for constant heat flux 
     // or specified power as well. 
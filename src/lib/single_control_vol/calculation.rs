@@ -1,10 +1,33 @@
 use crate::tuas_lib_error::TuasLibError;
 
+use crate::energy_audit::energy_tally::{EnergyTally, EnergyTallyRates, EnergyTallySource};
+
 use super::SingleCVNode;
 use uom::si::f64::*;
 use uom::si::power::watt;
 
 impl SingleCVNode {
+    /// adds a contribution to the rate of enthalpy change of 
+    /// the control volume, and tallies it by source if an 
+    /// energy tally is kept
+    #[inline]
+    pub fn push_rate_enthalpy_change(&mut self, 
+        enthalpy_chg_rate: Power,
+        source: EnergyTallySource){
+
+        self.rate_enthalpy_change_vector.push(enthalpy_chg_rate);
+
+        if let Some(energy_tally) = self.energy_tally.as_mut() {
+            energy_tally.pending.add(source, enthalpy_chg_rate);
+        }
+    }
+
+    /// starts tallying the contributions to the rate of enthalpy 
+    /// change by source, for use in energy audits
+    pub fn enable_energy_tally(&mut self){
+        self.energy_tally = Some(EnergyTally::default());
+    }
+
     /// this function performs necessary calculations to move 
     /// the state of the control volume to the next time step
     ///
@@ -51,6 +74,12 @@ impl SingleCVNode {
         // okay
 
         self.set_liquid_cv_mass_from_temperature()?;
+
+        // report what was applied this timestep before the 
+        // vectors are cleared
+        if let Some(energy_tally) = self.energy_tally.as_mut() {
+            energy_tally.finish_timestep(total_enthalpy_rate_change);
+        }
         // clear the enthalpy change vector and timestep vector 
         // also the mass flowrate vector
 
@@ -73,6 +102,10 @@ impl SingleCVNode {
         self.max_timestep_vector.clear();
         self.volumetric_flowrate_vector.clear();
 
+        if let Some(energy_tally) = self.energy_tally.as_mut() {
+            energy_tally.pending = EnergyTallyRates::default();
+        }
+
         Ok(())
     }
 
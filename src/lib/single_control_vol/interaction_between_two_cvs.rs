@@ -17,6 +17,7 @@ use uom::si::f64::*;
 use uom::si::length::meter;
 use uom::si::thermodynamic_temperature::kelvin;
 use uom::num_traits::Zero;
+use crate::energy_audit::energy_tally::EnergyTallySource;
 
 impl SingleCVNode {
 
@@ -133,10 +134,12 @@ impl SingleCVNode {
         // using timestep
         // the signs should cancel out
 
-        self.rate_enthalpy_change_vector.
-            push(-heat_flowrate_from_cv_1_to_cv_2);
-        single_cv_2.rate_enthalpy_change_vector.
-            push(heat_flowrate_from_cv_1_to_cv_2);
+        self.push_rate_enthalpy_change(
+            -heat_flowrate_from_cv_1_to_cv_2,
+            EnergyTallySource::ExchangeWithEntities);
+        single_cv_2.push_rate_enthalpy_change(
+            heat_flowrate_from_cv_1_to_cv_2,
+            EnergyTallySource::ExchangeWithEntities);
 
 
         // for solids mesh fourier number need only 
@@ -228,10 +231,12 @@ impl SingleCVNode {
         // by default, cv 1 is on the left, cv2 is on the right 
         //
 
-        self.rate_enthalpy_change_vector.
-            push(-heat_flowrate_from_cv_1_to_cv_2);
-        single_cv_2.rate_enthalpy_change_vector.
-            push(heat_flowrate_from_cv_1_to_cv_2);
+        self.push_rate_enthalpy_change(
+            -heat_flowrate_from_cv_1_to_cv_2,
+            EnergyTallySource::ExchangeWithEntities);
+        single_cv_2.push_rate_enthalpy_change(
+            heat_flowrate_from_cv_1_to_cv_2,
+            EnergyTallySource::ExchangeWithEntities);
 
         // relevant timescale here is courant number
         //
@@ -363,6 +368,26 @@ impl SingleCVNode {
         // with this minimum timestep
 
         match interaction {
+            HeatTransferInteractionType::
+                DualCylindricalThermalConductanceWithInterfaceResistance(_) |
+            HeatTransferInteractionType::
+                CylindricalConductionConvectionWithFouling(_) => {
+
+                // interface resistances only lower the conductance,
+                // so the timestep without them is conservative
+                let perfect_contact_timestep: Time = 
+                    self.calculate_mesh_stability_timestep_for_two_single_cv_nodes(
+                        single_cv_2,
+                        interaction.get_interaction_without_interface_resistance())?;
+
+                if cv_1_timestep > perfect_contact_timestep {
+                    cv_1_timestep = perfect_contact_timestep;
+                }
+
+                if cv_2_timestep > perfect_contact_timestep {
+                    cv_2_timestep = perfect_contact_timestep;
+                }
+            },
             HeatTransferInteractionType::UserSpecifiedThermalConductance(user_specified_conductance) => {
 
                 let lengthscale_stability_vec_1 = 
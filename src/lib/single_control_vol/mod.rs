@@ -2,8 +2,10 @@ use std::f64::consts::PI;
 use uom::si::area::square_meter;
 use uom::si::f64::*;
 use uom::si::pressure::atmosphere;
+use uom::si::ratio::ratio;
 
 use super::boussinesq_thermophysical_properties::Material;
+use super::boussinesq_thermophysical_properties::LiquidMaterial;
 use crate::tuas_lib_error::TuasLibError;
 use crate::boussinesq_thermophysical_properties::
 density::try_get_rho;
@@ -11,9 +13,15 @@ use crate::boussinesq_thermophysical_properties::
 specific_enthalpy::try_get_h;
 use crate::boussinesq_thermophysical_properties::
 specific_enthalpy::try_get_temperature_from_h;
+use crate::boussinesq_thermophysical_properties::
+phase_change::PhaseChangeProperties;
+use crate::boussinesq_thermophysical_properties::
+weakly_compressible::try_get_pressure_work_rate;
 
 
 use super::control_volume_dimensions::*;
+use crate::energy_audit::energy_tally::EnergyTally;
+
 /// SingleCVNode (single control volume node) represents 
 /// the control volume with a fixed point
 ///
@@ -87,6 +95,20 @@ pub struct SingleCVNode {
     /// at current timestep
     pub temperature: ThermodynamicTemperature,
 
+    /// freezing and melting properties of the control volume,
+    /// if None, the control volume is not allowed to freeze 
+    /// and the liquid correlations are used throughout
+    pub phase_change_properties: Option<PhaseChangeProperties>,
+
+    /// liquid fraction of the control volume at current timestep,
+    /// only meaningful if phase change is enabled
+    pub liquid_fraction: Ratio,
+
+    /// tally of the contributions to the rate of enthalpy change 
+    /// by source, for energy audits, 
+    /// if None, contributions are not tallied
+    pub energy_tally: Option<EnergyTally>,
+
 }
 
 /// here, we have mostly constructors
@@ -142,6 +164,9 @@ impl SingleCVNode {
             volumetric_flowrate_vector:
             vec![],
             temperature: cv_temperature,
+            phase_change_properties: None,
+            liquid_fraction: Ratio::new::<ratio>(1.0),
+            energy_tally: None,
         }
 
     }
@@ -153,6 +178,21 @@ impl SingleCVNode {
     pub fn get_temperature_from_enthalpy_and_set(&mut self) -> 
     Result<ThermodynamicTemperature, TuasLibError>{
 
+        // for control volumes which can freeze, the enthalpy 
+        // also determines how much of the cv is liquid
+        if let Some(phase_change) = self.phase_change_properties {
+            let (cv_temperature, liquid_fraction) = phase_change.
+                try_get_temperature_and_liquid_fraction_from_h(
+                    self.material_control_volume, 
+                    self.current_timestep_control_volume_specific_enthalpy, 
+                    self.pressure_control_volume)?;
+
+            self.temperature = cv_temperature;
+            self.liquid_fraction = liquid_fraction;
+
+            return Ok(cv_temperature);
+        }
+
         let cv_temperature = try_get_temperature_from_h(
             self.material_control_volume, 
             self.current_timestep_control_volume_specific_enthalpy, 
@@ -163,6 +203,97 @@ impl SingleCVNode {
         return Ok(cv_temperature);
     }
 
+    /// allows the control volume to freeze and melt using the 
+    /// enthalpy method 
+    ///
+    /// the specific enthalpy of the cv is recalculated from its 
+    /// current temperature so that latent heat is accounted for,
+    /// only liquids can have phase change enabled
+    pub fn enable_phase_change(&mut self,
+        phase_change_properties: PhaseChangeProperties) 
+    -> Result<(), TuasLibError>{
+
+        match self.material_control_volume {
+            Material::Solid(_) => {
+                return Err(TuasLibError::TypeConversionErrorMaterial);
+            },
+            Material::Liquid(_) => (),
+        }
+
+        let liquid_fraction = phase_change_properties.
+            get_liquid_fraction(self.temperature);
+
+        let cv_enthalpy = phase_change_properties.
+            try_get_h_from_temperature_and_liquid_fraction(
+                self.material_control_volume, 
+                self.temperature, 
+                liquid_fraction,
+                self.pressure_control_volume)?;
+
+        self.current_timestep_control_volume_specific_enthalpy = cv_enthalpy;
+        self.next_timestep_specific_enthalpy = cv_enthalpy;
+        self.liquid_fraction = liquid_fraction;
+        self.phase_change_properties = Some(phase_change_properties);
+
+        self.set_liquid_cv_mass_from_temperature()?;
+
+        return Ok(());
+    }
+
+    /// allows the control volume to freeze and melt using 
+    /// freezing data from the liquid database
+    pub fn enable_phase_change_from_database(&mut self) 
+    -> Result<(), TuasLibError>{
+
+        let liquid: crate::boussinesq_thermophysical_properties::LiquidMaterial
+        = self.material_control_volume.try_into()?;
+
+        self.enable_phase_change(liquid.try_get_phase_change_properties()?)
+    }
+
+    /// temperature at which thermophysical properties (density, 
+    /// heat capacity etc.) are evaluated
+    ///
+    /// when phase change is enabled, the cv may be colder than 
+    /// the range of the liquid correlations, so the properties 
+    /// are evaluated at the lower bound of the correlations instead
+    #[inline]
+    pub fn get_property_evaluation_temperature(&self) -> ThermodynamicTemperature {
+
+        match self.phase_change_properties {
+            Some(_) => PhaseChangeProperties::get_property_evaluation_temperature(
+                self.material_control_volume, self.temperature),
+            None => self.temperature,
+        }
+    }
+
+    /// sets the pressure of the control volume, and adds the 
+    /// pressure work V dp/dt done on it over the next timestep to 
+    /// the enthalpy change vector
+    ///
+    /// pressure work is only added for weakly compressible liquids, 
+    /// whose specific enthalpy depends on pressure, for other 
+    /// materials, only the pressure is set
+    pub fn try_set_pressure_with_pressure_work(&mut self,
+        pressure: Pressure,
+        timestep: Time) -> Result<(), TuasLibError>{
+
+        if let Material::Liquid(LiquidMaterial::WeaklyCompressible(_)) 
+            = self.material_control_volume {
+
+            let pressure_work_rate = try_get_pressure_work_rate(
+                self.volume, 
+                pressure - self.pressure_control_volume, 
+                timestep)?;
+
+            self.rate_enthalpy_change_vector.push(pressure_work_rate);
+        }
+
+        self.pressure_control_volume = pressure;
+
+        Ok(())
+    }
+
     /// this function takes the temperature of the control volume 
     /// to find its density and set its mass
     ///
@@ -172,7 +303,7 @@ impl SingleCVNode {
     fn set_liquid_cv_mass_from_temperature(&mut self) 
     -> Result<(), TuasLibError>{
 
-        let cv_temperature = self.temperature;
+        let cv_temperature = self.get_property_evaluation_temperature();
 
         let cv_density = try_get_rho(
             self.material_control_volume, 
@@ -254,6 +385,9 @@ impl SingleCVNode {
                     volumetric_flowrate_vector:
                         vec![],
                         temperature: cv_temperature,
+                        phase_change_properties: None,
+                        liquid_fraction: Ratio::new::<ratio>(1.0),
+                        energy_tally: None,
         };
 
 
@@ -324,6 +458,9 @@ impl SingleCVNode {
                         volumetric_flowrate_vector:
                             vec![],
                             temperature: cv_temperature,
+                            phase_change_properties: None,
+                            liquid_fraction: Ratio::new::<ratio>(1.0),
+                            energy_tally: None,
             };
 
 
@@ -396,6 +533,9 @@ impl SingleCVNode {
                     volumetric_flowrate_vector:
                         vec![],
                         temperature: cv_temperature,
+                        phase_change_properties: None,
+                        liquid_fraction: Ratio::new::<ratio>(1.0),
+                        energy_tally: None,
         };
 
 
@@ -473,6 +613,9 @@ impl SingleCVNode {
                         volumetric_flowrate_vector:
                             vec![],
                             temperature: cv_temperature,
+                            phase_change_properties: None,
+                            liquid_fraction: Ratio::new::<ratio>(1.0),
+                            energy_tally: None,
         };
 
 
@@ -558,6 +701,9 @@ impl SingleCVNode {
                     volumetric_flowrate_vector:
                         vec![],
                         temperature: cv_temperature,
+                        phase_change_properties: None,
+                        liquid_fraction: Ratio::new::<ratio>(1.0),
+                        energy_tally: None,
         };
 
 
@@ -627,6 +773,9 @@ impl SingleCVNode {
                     volumetric_flowrate_vector:
                         vec![],
                         temperature: cv_temperature,
+                        phase_change_properties: None,
+                        liquid_fraction: Ratio::new::<ratio>(1.0),
+                        energy_tally: None,
         };
 
 
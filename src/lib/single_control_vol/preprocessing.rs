@@ -54,7 +54,7 @@ impl SingleCVNode {
 
         let control_vol_material = self.material_control_volume.clone();
         let control_vol_pressure = self.pressure_control_volume.clone();
-        let cv_temperature = self.temperature;
+        let cv_temperature = self.get_property_evaluation_temperature();
 
 
         let thermal_diffusivity_coeff: DiffusionCoefficient = 
@@ -106,7 +106,7 @@ impl SingleCVNode {
         // let's get the volume of this control volume 
 
         let cv_material_reference = &self.material_control_volume;
-        let cv_temperature = self.temperature;
+        let cv_temperature = self.get_property_evaluation_temperature();
         let cv_pressure_reference = &self.pressure_control_volume;
 
         let cv_density: MassDensity = 
@@ -150,10 +150,13 @@ impl SingleCVNode {
 
         let control_vol_material = self.material_control_volume.clone();
         let control_vol_pressure = self.pressure_control_volume.clone();
-        let cv_temperature = try_get_temperature_from_h(
-            self.material_control_volume, 
-            self.current_timestep_control_volume_specific_enthalpy, 
-            self.pressure_control_volume)?;
+        let cv_temperature = match self.phase_change_properties {
+            Some(_) => self.get_property_evaluation_temperature(),
+            None => try_get_temperature_from_h(
+                self.material_control_volume, 
+                self.current_timestep_control_volume_specific_enthalpy, 
+                self.pressure_control_volume)?,
+        };
 
 
         let thermal_diffusivity_coeff: DiffusionCoefficient = 
@@ -208,7 +211,7 @@ impl SingleCVNode {
 
         let cv_mass_clone = self.mass_control_volume.clone();
         let cv_material = self.material_control_volume.clone();
-        let cv_temperature = self.temperature;
+        let cv_temperature = self.get_property_evaluation_temperature();
         let cv_pressure = self.pressure_control_volume.clone();
 
         let cv_heat_capacity = try_get_cp(
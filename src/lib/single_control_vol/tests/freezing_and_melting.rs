@@ -0,0 +1,77 @@
+use uom::si::available_energy::joule_per_kilogram;
+use uom::si::f64::*;
+use uom::si::power::watt;
+use uom::si::pressure::atmosphere;
+use uom::si::ratio::ratio;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::time::second;
+use uom::si::volume::cubic_meter;
+use uom::si::mass::kilogram;
+
+use crate::boussinesq_thermophysical_properties::{LiquidMaterial, Material};
+use crate::single_control_vol::SingleCVNode;
+
+/// a litre of water is cooled at constant power from 275 K,
+///
+/// it should cool down to 273.15 K and stay there while
+/// it freezes, and the liquid fraction should match the
+/// heat removed
+#[test]
+pub fn water_cv_freezes_at_constant_temperature(){
+
+    let water: Material = LiquidMaterial::Water.into();
+    let pressure = Pressure::new::<atmosphere>(1.0);
+
+    let mut water_cv = SingleCVNode::new(
+        ThermodynamicTemperature::new::<kelvin>(275.0),
+        water,
+        Mass::new::<kilogram>(1.0),
+        Volume::new::<cubic_meter>(1.0e-3));
+
+    water_cv.enable_phase_change_from_database().unwrap();
+
+    let phase_change = water_cv.phase_change_properties.unwrap();
+    let initial_enthalpy =
+        water_cv.current_timestep_control_volume_specific_enthalpy;
+    let h_liquidus = phase_change.try_get_h(water,
+        phase_change.liquidus_temperature, pressure).unwrap();
+
+    let cooling_power = Power::new::<watt>(-1000.0);
+    let timestep = Time::new::<second>(1.0);
+
+    let mut specific_heat_removed = AvailableEnergy::new::<joule_per_kilogram>(0.0);
+
+    // remove 100 kJ or so, which is enough to freeze about
+    // a quarter of the water
+    for _ in 0..100 {
+        specific_heat_removed += -cooling_power * timestep
+            / water_cv.mass_control_volume;
+        water_cv.rate_enthalpy_change_vector.push(cooling_power);
+        water_cv.advance_timestep(timestep).unwrap();
+    }
+
+    approx::assert_abs_diff_eq!(
+        water_cv.temperature.get::<kelvin>(),
+        273.15,
+        epsilon=1e-9);
+
+    let expected_liquid_fraction = 1.0 -
+        ((specific_heat_removed - (initial_enthalpy - h_liquidus))
+        /phase_change.latent_heat_of_fusion).get::<ratio>();
+
+    approx::assert_relative_eq!(
+        water_cv.liquid_fraction.get::<ratio>(),
+        expected_liquid_fraction,
+        max_relative=1e-6);
+
+    // remove enough heat to freeze it completely
+    for _ in 0..300 {
+        water_cv.rate_enthalpy_change_vector.push(cooling_power);
+        water_cv.advance_timestep(timestep).unwrap();
+    }
+
+    approx::assert_abs_diff_eq!(
+        water_cv.liquid_fraction.get::<ratio>(),
+        0.0);
+    assert!(water_cv.temperature.get::<kelvin>() < 273.15);
+}
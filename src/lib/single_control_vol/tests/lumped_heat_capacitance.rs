@@ -23,6 +23,7 @@ use uom::si::f64::*;
 use uom::si::length::centimeter;
 use uom::si::power::watt;
 use uom::si::pressure::atmosphere;
+use uom::si::ratio::ratio;
 use uom::si::heat_transfer::watt_per_square_meter_kelvin;
 use uom::si::thermodynamic_temperature::degree_celsius;
 use uom::si::time::second;
@@ -368,6 +369,9 @@ fn lumped_heat_capacitance_steel_ball_in_air() -> Result<(), TuasLibError>{
                 volumetric_flowrate_vector:
                 vec![],
                 temperature: steel_initial_temperature,
+                phase_change_properties: None,
+                liquid_fraction: Ratio::new::<ratio>(1.0),
+                energy_tally: None,
             }
         )
     );
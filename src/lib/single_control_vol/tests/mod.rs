@@ -21,3 +21,13 @@ mod semi_infinite_one_dimension_transient_conduction;
 /// carrying fluid come to a single control vol, and 
 /// the outlet temperature should be at a correct temperature
 pub mod mixing_joint;
+
+#[cfg(test)]
+/// contains tests for freezing and melting of liquids 
+/// in a single control volume
+mod freezing_and_melting;
+
+#[cfg(test)]
+/// contains tests for pressure work and thermal expansion of 
+/// weakly compressible liquids in a single control volume
+mod weakly_compressible;
@@ -0,0 +1,196 @@
+use uom::si::f64::*;
+use uom::si::area::square_meter;
+use uom::si::length::meter;
+use uom::si::mass::kilogram;
+use uom::si::power::watt;
+use uom::si::pressure::{atmosphere, bar};
+use uom::si::ratio::ratio;
+use uom::si::temperature_coefficient::per_kelvin;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::time::second;
+use uom::si::volume::cubic_meter;
+
+use crate::boussinesq_thermophysical_properties::{LiquidMaterial, Material};
+use crate::boussinesq_thermophysical_properties::density::try_get_rho;
+use crate::boussinesq_thermophysical_properties::specific_heat_capacity::try_get_cp;
+use crate::boussinesq_thermophysical_properties::weakly_compressible::*;
+use crate::single_control_vol::SingleCVNode;
+
+/// a cubic metre of water at 300 K with a given material
+fn new_water_cv(water: Material) -> SingleCVNode {
+
+    let mut water_cv = SingleCVNode::new(
+        ThermodynamicTemperature::new::<kelvin>(300.0),
+        water,
+        Mass::new::<kilogram>(1000.0),
+        Volume::new::<cubic_meter>(1.0));
+
+    water_cv.set_liquid_cv_mass_from_temperature().unwrap();
+
+    water_cv
+}
+
+/// water in a rigid control volume is compressed adiabatically
+/// from 1 atm by 150 bar over 10 s,
+///
+/// the pressure work should heat it by beta T dp/(rho cp), about
+/// 0.28 K, and it should cool back to about 300 K when the pressure 
+/// is released. Its mass should be that of water at the new
+/// temperature and pressure
+#[test]
+pub fn adiabatic_compression_of_weakly_compressible_water(){
+
+    let compressible_water = WeaklyCompressibleLiquid::try_new_from_database(
+        LiquidMaterial::Water).unwrap();
+    let water: Material = LiquidMaterial::from(compressible_water).into();
+
+    let mut water_cv = new_water_cv(water);
+
+    let initial_temperature = water_cv.temperature;
+    let initial_pressure = water_cv.pressure_control_volume;
+    let pressure_rise = Pressure::new::<bar>(150.0);
+    let timestep = Time::new::<second>(1.0);
+    let number_of_steps = 10;
+
+    for step in 1..=number_of_steps {
+        let pressure = initial_pressure
+            + pressure_rise * (step as f64 / number_of_steps as f64);
+        water_cv.try_set_pressure_with_pressure_work(pressure, timestep).unwrap();
+        water_cv.advance_timestep(timestep).unwrap();
+    }
+
+    // the cv mass is set using the temperature of the previous 
+    // timestep, so one more timestep lets it catch up
+    water_cv.advance_timestep(timestep).unwrap();
+
+    let thermal_expansion = compressible_water
+        .try_get_thermal_expansion_coefficient(initial_temperature).unwrap();
+    let density = try_get_rho(water, initial_temperature, initial_pressure).unwrap();
+    let cp = try_get_cp(water, initial_temperature, initial_pressure).unwrap();
+
+    let expected_temperature_rise: f64 = thermal_expansion.get::<per_kelvin>()
+        * initial_temperature.get::<kelvin>()
+        * (pressure_rise / density / cp).value;
+
+    approx::assert_relative_eq!(
+        water_cv.temperature.get::<kelvin>() - 300.0,
+        expected_temperature_rise,
+        max_relative=0.02);
+
+    approx::assert_relative_eq!(
+        water_cv.mass_control_volume.get::<kilogram>(),
+        (try_get_rho(water, water_cv.temperature,
+            initial_pressure + pressure_rise).unwrap() * water_cv.volume)
+            .get::<kilogram>(),
+        max_relative=1e-12);
+
+    // compressing water adds about 0.68% to its mass
+    approx::assert_relative_eq!(
+        water_cv.mass_control_volume.get::<kilogram>()
+        / (density * water_cv.volume).get::<kilogram>(),
+        1.0068,
+        max_relative=2e-4);
+
+    // releasing the pressure cools it back down, to within the 
+    // error of the explicit pressure work
+    for step in (0..number_of_steps).rev() {
+        let pressure = initial_pressure
+            + pressure_rise * (step as f64 / number_of_steps as f64);
+        water_cv.try_set_pressure_with_pressure_work(pressure, timestep).unwrap();
+        water_cv.advance_timestep(timestep).unwrap();
+    }
+
+    approx::assert_abs_diff_eq!(
+        water_cv.temperature.get::<kelvin>(),
+        300.0,
+        epsilon=0.02*expected_temperature_rise);
+}
+
+/// for incompressible (Boussinesq) water, pressure work is not
+/// added, so compressing it does not change its temperature
+/// or mass
+#[test]
+pub fn compressing_boussinesq_water_does_no_work(){
+
+    let water: Material = LiquidMaterial::Water.into();
+    let mut water_cv = new_water_cv(water);
+    let initial_mass = water_cv.mass_control_volume;
+
+    let timestep = Time::new::<second>(1.0);
+    water_cv.try_set_pressure_with_pressure_work(
+        Pressure::new::<atmosphere>(1.0) + Pressure::new::<bar>(150.0),
+        timestep).unwrap();
+
+    assert!(water_cv.rate_enthalpy_change_vector.is_empty());
+
+    water_cv.advance_timestep(timestep).unwrap();
+
+    approx::assert_abs_diff_eq!(
+        water_cv.temperature.get::<kelvin>(),
+        300.0,
+        epsilon=1e-9);
+    assert_eq!(water_cv.mass_control_volume, initial_mass);
+
+    // the timestep is only needed for the pressure work
+    assert!(water_cv.try_set_pressure_with_pressure_work(
+        Pressure::new::<atmosphere>(1.0),
+        Time::new::<second>(0.0)).is_ok());
+
+    let compressible_water: Material = LiquidMaterial::from(
+        WeaklyCompressibleLiquid::try_new_from_database(LiquidMaterial::Water)
+        .unwrap()).into();
+    let mut compressible_water_cv = new_water_cv(compressible_water);
+    assert!(compressible_water_cv.try_set_pressure_with_pressure_work(
+        Pressure::new::<atmosphere>(2.0),
+        Time::new::<second>(0.0)).is_err());
+}
+
+/// water in a rigid control volume is heated from 300 K to
+/// about 350 K at 1 atm,
+///
+/// the water which expands out of the control volume goes into an
+/// expansion tank of 0.1 m2, and the level of the tank should rise
+/// by the expanded volume of the water divided by the tank area,
+/// about 0.2 m
+#[test]
+pub fn expansion_tank_level_rises_as_water_is_heated(){
+
+    let water: Material = LiquidMaterial::from(
+        WeaklyCompressibleLiquid::try_new_from_database(LiquidMaterial::Water)
+        .unwrap()).into();
+
+    let mut water_cv = new_water_cv(water);
+    let reference_mass = water_cv.mass_control_volume;
+    let pressure = water_cv.pressure_control_volume;
+    let cold_density = try_get_rho(water, water_cv.temperature, pressure).unwrap();
+
+    // about 210 MJ heats a cubic metre of water by 50 K
+    let heating_power = Power::new::<watt>(2.1e6);
+    let timestep = Time::new::<second>(1.0);
+    for _ in 0..100 {
+        water_cv.rate_enthalpy_change_vector.push(heating_power);
+        water_cv.advance_timestep(timestep).unwrap();
+    }
+    water_cv.advance_timestep(timestep).unwrap();
+
+    assert!(water_cv.temperature.get::<kelvin>() > 345.0);
+    assert!(water_cv.temperature.get::<kelvin>() < 355.0);
+
+    let hot_density = try_get_rho(water, water_cv.temperature, pressure).unwrap();
+    let tank_area = Area::new::<square_meter>(0.1);
+
+    let level_change = get_expansion_tank_level_change(
+        reference_mass,
+        water_cv.mass_control_volume,
+        hot_density,
+        tank_area);
+
+    let expanded_volume: Volume = water_cv.volume 
+        * ((cold_density / hot_density).get::<ratio>() - 1.0);
+
+    approx::assert_relative_eq!(
+        level_change.get::<meter>(),
+        (expanded_volume / tank_area).get::<meter>(),
+        max_relative=1e-9);
+    approx::assert_relative_eq!(level_change.get::<meter>(), 0.2, max_relative=0.25);
+}
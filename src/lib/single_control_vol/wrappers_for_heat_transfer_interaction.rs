@@ -8,6 +8,7 @@ use crate::single_control_vol::SingleCVNode;
 use uom::si::thermodynamic_temperature::kelvin;
 use uom::si::f64::*;
 use uom::num_traits::Zero;
+use crate::energy_audit::energy_tally::EnergyTallySource;
 
 
 impl SingleCVNode {
@@ -65,6 +66,20 @@ impl SingleCVNode {
                     single_cv_2,
                     interaction)
             },
+
+            HeatTransferInteractionType::DualCylindricalThermalConductanceWithInterfaceResistance(_) => {
+                Self::calculate_conductance_interaction_between_two_singular_cv_nodes(
+                    single_cv_1,
+                    single_cv_2,
+                    interaction)
+            },
+
+            HeatTransferInteractionType::CylindricalConductionConvectionWithFouling(_) => {
+                Self::calculate_conductance_interaction_between_two_singular_cv_nodes(
+                    single_cv_1,
+                    single_cv_2,
+                    interaction)
+            },
             HeatTransferInteractionType::UserSpecifiedHeatAddition => {
                 Self::calculate_conductance_interaction_between_two_singular_cv_nodes(
                     single_cv_1,
@@ -232,10 +247,12 @@ impl SingleCVNode {
         // using timestep
         // the signs should cancel out
 
-        single_cv_1.rate_enthalpy_change_vector.
-            push(-heat_flowrate_from_cv_1_to_cv_2);
-        single_cv_2.rate_enthalpy_change_vector.
-            push(heat_flowrate_from_cv_1_to_cv_2);
+        single_cv_1.push_rate_enthalpy_change(
+            -heat_flowrate_from_cv_1_to_cv_2,
+            EnergyTallySource::ExchangeWithEntities);
+        single_cv_2.push_rate_enthalpy_change(
+            heat_flowrate_from_cv_1_to_cv_2,
+            EnergyTallySource::ExchangeWithEntities);
 
 
         // for solids mesh fourier number need only 
@@ -299,10 +316,12 @@ impl SingleCVNode {
         // by default, cv 1 is on the left, cv2 is on the right 
         //
 
-        single_cv_1.rate_enthalpy_change_vector.
-            push(-heat_flowrate_from_cv_1_to_cv_2);
-        single_cv_2.rate_enthalpy_change_vector.
-            push(heat_flowrate_from_cv_1_to_cv_2);
+        single_cv_1.push_rate_enthalpy_change(
+            -heat_flowrate_from_cv_1_to_cv_2,
+            EnergyTallySource::ExchangeWithEntities);
+        single_cv_2.push_rate_enthalpy_change(
+            heat_flowrate_from_cv_1_to_cv_2,
+            EnergyTallySource::ExchangeWithEntities);
 
         // relevant timescale here is courant number
         //
@@ -49,6 +49,10 @@ pub enum TuasLibError {
     /// wrong heat transfer interaction type
     #[error("Wrong Heat Transfer Interaction Type")]
     WrongHeatTransferInteractionType,
+
+    /// error reading or writing csv files
+    #[error("csv error")]
+    CsvError(#[from] csv::Error),
     
 }
 
@@ -89,6 +93,9 @@ impl Into<String> for TuasLibError {
             TuasLibError::WrongHeatTransferInteractionType => {
                 self.to_string()
             },
+            TuasLibError::CsvError(_) => {
+                self.to_string()
+            },
 
 
         }
@@ -0,0 +1,21 @@
+/// uncertain parameters, their distributions, and Monte Carlo and
+/// Latin hypercube sampling
+pub mod sampling;
+pub use sampling::*;
+
+/// the uncertainty quantification problem, which binds uncertain
+/// parameters to a model and runs it, optionally in parallel
+pub mod uq_problem;
+pub use uq_problem::*;
+
+/// summary statistics of sampled outputs
+pub mod statistics;
+pub use statistics::*;
+
+/// Sobol indices and Morris screening
+pub mod sensitivity;
+pub use sensitivity::*;
+
+/// tests for sampling, statistics and sensitivity indices
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,248 @@
+use crate::calibration::TunableParameter;
+use crate::tuas_lib_error::TuasLibError;
+
+/// how samples are drawn from the unit hypercube
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum SamplingMethod {
+    /// independent uniform samples
+    MonteCarlo,
+    /// Latin hypercube, every parameter has exactly one sample in
+    /// each of the n equally probable strata, which covers the
+    /// parameter ranges with far fewer runs than Monte Carlo
+    LatinHypercube,
+}
+
+/// a small seeded pseudo random number generator (SplitMix64),
+/// so that studies can be repeated exactly
+///
+/// it is not suitable for cryptography
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct UqRandomNumberGenerator {
+    state: u64,
+}
+
+impl UqRandomNumberGenerator {
+
+    /// creates a generator from a seed
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// next 64 random bits
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// uniform sample in [0,1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    /// uniform integer in [0, upper_bound)
+    pub fn next_index(&mut self, upper_bound: usize) -> usize {
+        ((self.next_f64() * upper_bound as f64) as usize).min(upper_bound - 1)
+    }
+}
+
+/// draws samples from the unit hypercube, returned as one row of
+/// length dimensions per sample
+pub fn get_unit_hypercube_samples(method: SamplingMethod,
+    number_of_samples: usize,
+    dimensions: usize,
+    random_number_generator: &mut UqRandomNumberGenerator) -> Vec<Vec<f64>> {
+
+    let mut samples = vec![vec![0.0; dimensions]; number_of_samples];
+
+    match method {
+        SamplingMethod::MonteCarlo => {
+            for sample in samples.iter_mut() {
+                for value in sample.iter_mut() {
+                    *value = random_number_generator.next_f64();
+                }
+            }
+        },
+        SamplingMethod::LatinHypercube => {
+            for dimension in 0..dimensions {
+                // Fisher-Yates shuffle of the strata
+                let mut strata: Vec<usize> = (0..number_of_samples).collect();
+                for index in (1..number_of_samples).rev() {
+                    let swap_index = random_number_generator.next_index(index + 1);
+                    strata.swap(index, swap_index);
+                }
+                for (sample, stratum) in samples.iter_mut().zip(strata) {
+                    sample[dimension] = (stratum as f64
+                        + random_number_generator.next_f64())
+                        / number_of_samples as f64;
+                }
+            }
+        },
+    }
+
+    samples
+}
+
+/// probability distribution of an uncertain parameter
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum ParameterDistribution {
+    /// uniform between the bounds of the parameter
+    Uniform,
+    /// normal with a mean and standard deviation (SI units),
+    /// values beyond the bounds of the parameter are clamped to
+    /// them
+    Normal {
+        /// mean of the distribution
+        mean: f64,
+        /// standard deviation of the distribution
+        standard_deviation: f64,
+    },
+    /// normal with a mean and a relative standard deviation, eg.
+    /// 0.1 for 10% of the mean, for uncertainties quoted as
+    /// percentages. Values beyond the bounds are clamped to them
+    NormalRelative {
+        /// mean of the distribution
+        mean: f64,
+        /// standard deviation divided by the mean
+        relative_standard_deviation: f64,
+    },
+}
+
+/// a parameter with an uncertain value, bound to a model setter
+/// through a [TunableParameter], so that the setters used for
+/// calibration (eg. calibrate_insulation_thickness,
+/// calibrate_heat_transfer_to_ambient or
+/// try_calibrate_gnielinski_nusselt) are reused for uncertainty
+/// studies
+///
+/// the initial value of the tunable parameter is the nominal value
+pub struct UncertainParameter<M> {
+    /// the parameter and its setter
+    pub parameter: TunableParameter<M>,
+    /// distribution of the parameter
+    pub distribution: ParameterDistribution,
+}
+
+impl<M> UncertainParameter<M> {
+
+    /// a parameter uniformly distributed between the bounds of the
+    /// tunable parameter
+    pub fn new_uniform(parameter: TunableParameter<M>) -> Self {
+        Self {
+            parameter,
+            distribution: ParameterDistribution::Uniform,
+        }
+    }
+
+    /// a normally distributed parameter, clamped to the bounds of
+    /// the tunable parameter
+    pub fn try_new_normal(parameter: TunableParameter<M>,
+        mean: f64,
+        standard_deviation: f64) -> Result<Self, TuasLibError> {
+
+        if !standard_deviation.is_finite() || standard_deviation <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                format!("uncertain parameter {}: standard deviation must be \
+                    positive, got {}", parameter.name, standard_deviation)));
+        }
+
+        Ok(Self {
+            parameter,
+            distribution: ParameterDistribution::Normal {
+                mean,
+                standard_deviation,
+            },
+        })
+    }
+
+    /// a normally distributed parameter with a standard deviation
+    /// relative to its mean, clamped to the bounds of the tunable
+    /// parameter
+    pub fn try_new_normal_relative(parameter: TunableParameter<M>,
+        mean: f64,
+        relative_standard_deviation: f64) -> Result<Self, TuasLibError> {
+
+        if !relative_standard_deviation.is_finite() || relative_standard_deviation <= 0.0 {
+            return Err(TuasLibError::GenericStringError(
+                format!("uncertain parameter {}: relative standard deviation \
+                    must be positive, got {}", parameter.name,
+                    relative_standard_deviation)));
+        }
+
+        Ok(Self {
+            parameter,
+            distribution: ParameterDistribution::NormalRelative {
+                mean,
+                relative_standard_deviation,
+            },
+        })
+    }
+
+    /// maps a sample from [0,1] to a parameter value through the
+    /// inverse cumulative distribution
+    pub fn get_value_from_unit_sample(&self, unit_sample: f64) -> f64 {
+        let lower_bound = self.parameter.lower_bound;
+        let upper_bound = self.parameter.upper_bound;
+
+        let value = match self.distribution {
+            ParameterDistribution::Uniform => {
+                lower_bound + unit_sample * (upper_bound - lower_bound)
+            },
+            ParameterDistribution::Normal { mean, standard_deviation } => {
+                mean + standard_deviation * get_standard_normal_quantile(unit_sample)
+            },
+            ParameterDistribution::NormalRelative { mean, relative_standard_deviation } => {
+                mean + (relative_standard_deviation * mean).abs()
+                    * get_standard_normal_quantile(unit_sample)
+            },
+        };
+
+        self.parameter.clamp(value)
+    }
+}
+
+/// inverse of the standard normal cumulative distribution,
+/// using Acklam's rational approximation (relative error
+/// about 1.15e-9)
+///
+/// returns -infinity at 0 and infinity at 1
+pub fn get_standard_normal_quantile(probability: f64) -> f64 {
+
+    const A: [f64; 6] = [-3.969683028665376e+01, 2.209460984245205e+02,
+        -2.759285104469687e+02, 1.38357751867269e+02,
+        -3.066479806614716e+01, 2.50662827745924e+00];
+    const B: [f64; 5] = [-5.447609879822406e+01, 1.615858368580409e+02,
+        -1.556989798598866e+02, 6.680131188771972e+01,
+        -1.328068155288572e+01];
+    const C: [f64; 6] = [-7.784894002430293e-03, -3.223964580411365e-01,
+        -2.400758277161838e+00, -2.549732539343734e+00,
+        4.374664141464968e+00, 2.938163982698783e+00];
+    const D: [f64; 4] = [7.784695709041462e-03, 3.224671290700398e-01,
+        2.445134137142996e+00, 3.754408661907416e+00];
+
+    const LOWER_TAIL: f64 = 0.02425;
+
+    if probability <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if probability >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    if probability < LOWER_TAIL {
+        let q = (-2.0 * probability.ln()).sqrt();
+        (((((C[0]*q + C[1])*q + C[2])*q + C[3])*q + C[4])*q + C[5])
+            / ((((D[0]*q + D[1])*q + D[2])*q + D[3])*q + 1.0)
+    } else if probability <= 1.0 - LOWER_TAIL {
+        let q = probability - 0.5;
+        let r = q * q;
+        (((((A[0]*r + A[1])*r + A[2])*r + A[3])*r + A[4])*r + A[5]) * q
+            / (((((B[0]*r + B[1])*r + B[2])*r + B[3])*r + B[4])*r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - probability).ln()).sqrt();
+        -(((((C[0]*q + C[1])*q + C[2])*q + C[3])*q + C[4])*q + C[5])
+            / ((((D[0]*q + D[1])*q + D[2])*q + D[3])*q + 1.0)
+    }
+}
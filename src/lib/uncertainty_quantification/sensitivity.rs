@@ -0,0 +1,300 @@
+use crate::tuas_lib_error::TuasLibError;
+
+use super::sampling::{get_unit_hypercube_samples, UqRandomNumberGenerator};
+use super::statistics::{get_mean, get_sample_variance};
+use super::uq_problem::{UncertaintyQuantificationProblem, UqSettings};
+
+/// variance based sensitivity indices of each output to each
+/// parameter
+///
+/// indices are stored as [output][parameter]. First order indices
+/// are the fraction of the output variance due to a parameter
+/// alone, total order indices also include its interactions with
+/// the other parameters, so a parameter with a total order index
+/// near zero can be fixed at its nominal value. Indices of outputs
+/// which do not vary are zero
+#[derive(Debug,Clone,PartialEq)]
+pub struct SobolIndices {
+    /// names of the uncertain parameters
+    pub parameter_names: Vec<String>,
+    /// names of the outputs
+    pub output_names: Vec<String>,
+    /// first order indices, [output][parameter]
+    pub first_order: Vec<Vec<f64>>,
+    /// total order indices, [output][parameter]
+    pub total_order: Vec<Vec<f64>>,
+    /// number of model runs used
+    pub simulation_runs: usize,
+}
+
+/// settings for Morris screening
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct MorrisSettings {
+    /// number of trajectories, the model is run
+    /// trajectories*(parameters+1) times
+    pub trajectories: usize,
+    /// number of levels of the grid each parameter is sampled on,
+    /// an even number
+    pub levels: usize,
+    /// seed of the random number generator
+    pub seed: u64,
+    /// number of threads running the model
+    pub threads: usize,
+}
+
+impl Default for MorrisSettings {
+    fn default() -> Self {
+        Self {
+            trajectories: 10,
+            levels: 4,
+            seed: 0,
+            threads: 1,
+        }
+    }
+}
+
+/// statistics of the elementary effects of each parameter on each
+/// output, stored as [output][parameter]
+///
+/// effects are the change in output per unit change of the
+/// parameter mapped onto [0,1], so for a uniform parameter and a
+/// linear model, an effect is the change in output over the whole
+/// range of the parameter. Parameters with a small mean absolute
+/// effect are unimportant, a standard deviation which is large
+/// compared to the mean absolute effect points to nonlinearity or
+/// interactions
+#[derive(Debug,Clone,PartialEq)]
+pub struct MorrisScreening {
+    /// names of the uncertain parameters
+    pub parameter_names: Vec<String>,
+    /// names of the outputs
+    pub output_names: Vec<String>,
+    /// mean of the elementary effects (mu), [output][parameter]
+    pub mean: Vec<Vec<f64>>,
+    /// mean of the absolute elementary effects (mu*),
+    /// [output][parameter]
+    pub mean_absolute: Vec<Vec<f64>>,
+    /// standard deviation of the elementary effects (sigma),
+    /// [output][parameter]
+    pub standard_deviation: Vec<Vec<f64>>,
+    /// number of model runs used
+    pub simulation_runs: usize,
+}
+
+impl<M: Clone + Send> UncertaintyQuantificationProblem<M> {
+
+    /// estimates first and total order Sobol indices with the
+    /// Saltelli (first order) and Jansen (total order) estimators
+    ///
+    /// two sample matrices A and B are drawn, and for every
+    /// parameter i the model is also run on A with column i taken
+    /// from B, so the model is run samples*(parameters+2) times
+    pub fn try_get_sobol_indices(&self, settings: UqSettings)
+        -> Result<SobolIndices, TuasLibError> {
+
+        self.try_check_problem()?;
+        if settings.samples < 2 {
+            return Err(TuasLibError::GenericStringError(
+                "at least two samples are needed".to_owned()));
+        }
+
+        let number_of_parameters = self.parameters.len();
+        let number_of_samples = settings.samples;
+
+        let mut random_number_generator = UqRandomNumberGenerator::new(settings.seed);
+        let unit_samples = get_unit_hypercube_samples(
+            settings.sampling_method,
+            number_of_samples,
+            2 * number_of_parameters,
+            &mut random_number_generator);
+
+        let matrix_a: Vec<Vec<f64>> = unit_samples.iter()
+            .map(|sample| sample[..number_of_parameters].to_vec())
+            .collect();
+        let matrix_b: Vec<Vec<f64>> = unit_samples.iter()
+            .map(|sample| sample[number_of_parameters..].to_vec())
+            .collect();
+
+        // A, B, then A with column i from B for each parameter i
+        let mut all_unit_samples = matrix_a.clone();
+        all_unit_samples.extend(matrix_b.iter().cloned());
+        for parameter_index in 0..number_of_parameters {
+            all_unit_samples.extend(matrix_a.iter().zip(matrix_b.iter())
+                .map(|(row_a, row_b)| {
+                    let mut row = row_a.clone();
+                    row[parameter_index] = row_b[parameter_index];
+                    row
+                }));
+        }
+
+        let parameter_samples: Vec<Vec<f64>> = all_unit_samples.iter()
+            .map(|unit_sample| self.get_parameter_values_from_unit_sample(unit_sample))
+            .collect();
+        let output_samples = self.try_run_parameter_samples(
+            &parameter_samples, settings.threads)?;
+
+        let mut first_order = vec![];
+        let mut total_order = vec![];
+
+        for output_index in 0..self.output_names.len() {
+            let block = |block_index: usize| -> Vec<f64> {
+                output_samples[block_index * number_of_samples..
+                    (block_index + 1) * number_of_samples].iter()
+                    .map(|outputs| outputs[output_index])
+                    .collect()
+            };
+            let outputs_a = block(0);
+            let outputs_b = block(1);
+
+            let variance = get_sample_variance(
+                &[outputs_a.as_slice(), outputs_b.as_slice()].concat());
+
+            let mut output_first_order = vec![];
+            let mut output_total_order = vec![];
+            for parameter_index in 0..number_of_parameters {
+                let outputs_ab = block(2 + parameter_index);
+
+                if variance <= 0.0 {
+                    output_first_order.push(0.0);
+                    output_total_order.push(0.0);
+                    continue;
+                }
+
+                let first_order_variance = outputs_b.iter()
+                    .zip(outputs_a.iter().zip(outputs_ab.iter()))
+                    .map(|(b, (a, ab))| b * (ab - a))
+                    .sum::<f64>() / number_of_samples as f64;
+                let total_order_variance = outputs_a.iter().zip(outputs_ab.iter())
+                    .map(|(a, ab)| (a - ab).powi(2))
+                    .sum::<f64>() / (2.0 * number_of_samples as f64);
+
+                output_first_order.push(first_order_variance / variance);
+                output_total_order.push(total_order_variance / variance);
+            }
+            first_order.push(output_first_order);
+            total_order.push(output_total_order);
+        }
+
+        Ok(SobolIndices {
+            parameter_names: self.get_parameter_names(),
+            output_names: self.output_names.clone(),
+            first_order,
+            total_order,
+            simulation_runs: output_samples.len(),
+        })
+    }
+
+    /// Morris elementary effects screening, a cheap way to rank
+    /// parameters before a Sobol study
+    ///
+    /// each trajectory starts at a random point of a grid on the
+    /// unit hypercube and moves one parameter at a time, in random
+    /// order, by levels/(2(levels-1))
+    pub fn try_get_morris_screening(&self, settings: MorrisSettings)
+        -> Result<MorrisScreening, TuasLibError> {
+
+        self.try_check_problem()?;
+        if settings.levels < 2 || !settings.levels.is_multiple_of(2) {
+            return Err(TuasLibError::GenericStringError(format!(
+                "morris screening needs an even number of levels, got {}",
+                settings.levels)));
+        }
+        if settings.trajectories < 2 {
+            return Err(TuasLibError::GenericStringError(
+                "morris screening needs at least two trajectories".to_owned()));
+        }
+
+        let number_of_parameters = self.parameters.len();
+        let grid_spacing = 1.0 / (settings.levels - 1) as f64;
+        let step = settings.levels as f64 * grid_spacing / 2.0;
+
+        let mut random_number_generator = UqRandomNumberGenerator::new(settings.seed);
+
+        // the points of each trajectory, and the parameter moved and
+        // the signed step between consecutive points
+        let mut unit_samples: Vec<Vec<f64>> = vec![];
+        let mut moves: Vec<Vec<(usize, f64)>> = vec![];
+
+        for _ in 0..settings.trajectories {
+            let mut point: Vec<f64> = (0..number_of_parameters)
+                .map(|_| random_number_generator.next_index(settings.levels) as f64
+                    * grid_spacing)
+                .collect();
+
+            let mut order: Vec<usize> = (0..number_of_parameters).collect();
+            for index in (1..number_of_parameters).rev() {
+                let swap_index = random_number_generator.next_index(index + 1);
+                order.swap(index, swap_index);
+            }
+
+            unit_samples.push(point.clone());
+            let mut trajectory_moves = vec![];
+            for parameter_index in order {
+                let can_go_up = point[parameter_index] + step <= 1.0 + 1e-12;
+                let can_go_down = point[parameter_index] - step >= -1e-12;
+                let signed_step = match (can_go_up, can_go_down) {
+                    (true, true) => if random_number_generator.next_f64() < 0.5 {
+                        step
+                    } else {
+                        -step
+                    },
+                    (true, false) => step,
+                    _ => -step,
+                };
+                point[parameter_index] = (point[parameter_index] + signed_step)
+                    .clamp(0.0, 1.0);
+                unit_samples.push(point.clone());
+                trajectory_moves.push((parameter_index, signed_step));
+            }
+            moves.push(trajectory_moves);
+        }
+
+        let parameter_samples: Vec<Vec<f64>> = unit_samples.iter()
+            .map(|unit_sample| self.get_parameter_values_from_unit_sample(unit_sample))
+            .collect();
+        let output_samples = self.try_run_parameter_samples(
+            &parameter_samples, settings.threads)?;
+
+        let mut mean = vec![];
+        let mut mean_absolute = vec![];
+        let mut standard_deviation = vec![];
+
+        let output_columns: Vec<Vec<f64>> = (0..self.output_names.len())
+            .map(|output_index| output_samples.iter()
+                .map(|outputs| outputs[output_index])
+                .collect())
+            .collect();
+
+        for outputs in &output_columns {
+            let mut elementary_effects = vec![vec![]; number_of_parameters];
+
+            for (trajectory, trajectory_moves) in moves.iter().enumerate() {
+                let first_run = trajectory * (number_of_parameters + 1);
+                for (move_index, (parameter_index, signed_step)) in
+                    trajectory_moves.iter().enumerate() {
+                    let before = outputs[first_run + move_index];
+                    let after = outputs[first_run + move_index + 1];
+                    elementary_effects[*parameter_index].push((after - before) / signed_step);
+                }
+            }
+
+            mean.push(elementary_effects.iter()
+                .map(|effects| get_mean(effects)).collect());
+            mean_absolute.push(elementary_effects.iter()
+                .map(|effects| effects.iter().map(|effect| effect.abs()).sum::<f64>()
+                    / effects.len() as f64)
+                .collect());
+            standard_deviation.push(elementary_effects.iter()
+                .map(|effects| get_sample_variance(effects).sqrt()).collect());
+        }
+
+        Ok(MorrisScreening {
+            parameter_names: self.get_parameter_names(),
+            output_names: self.output_names.clone(),
+            mean,
+            mean_absolute,
+            standard_deviation,
+            simulation_runs: output_samples.len(),
+        })
+    }
+}
@@ -0,0 +1,80 @@
+/// summary statistics of one model output over the samples
+#[derive(Debug,Clone,PartialEq)]
+pub struct OutputStatistics {
+    /// name of the output
+    pub name: String,
+    /// sample mean
+    pub mean: f64,
+    /// sample standard deviation (with n-1 in the denominator)
+    pub standard_deviation: f64,
+    /// smallest sample
+    pub minimum: f64,
+    /// largest sample
+    pub maximum: f64,
+    /// 5th percentile
+    pub percentile_5: f64,
+    /// 50th percentile
+    pub median: f64,
+    /// 95th percentile
+    pub percentile_95: f64,
+}
+
+impl OutputStatistics {
+
+    /// statistics of a set of samples, percentiles are linearly
+    /// interpolated between the sorted samples
+    ///
+    /// samples must be finite and there must be at least one
+    pub fn from_samples(name: &str, samples: &[f64]) -> Self {
+
+        let mut sorted_samples = samples.to_vec();
+        sorted_samples.sort_by(f64::total_cmp);
+
+        let mean = get_mean(samples);
+
+        Self {
+            name: name.to_owned(),
+            mean,
+            standard_deviation: get_sample_variance(samples).sqrt(),
+            minimum: sorted_samples[0],
+            maximum: sorted_samples[sorted_samples.len() - 1],
+            percentile_5: get_percentile_of_sorted(&sorted_samples, 5.0),
+            median: get_percentile_of_sorted(&sorted_samples, 50.0),
+            percentile_95: get_percentile_of_sorted(&sorted_samples, 95.0),
+        }
+    }
+
+    /// standard deviation relative to the mean, eg. to compare
+    /// against the tolerances of regression tests
+    pub fn get_coefficient_of_variation(&self) -> f64 {
+        self.standard_deviation / self.mean.abs()
+    }
+}
+
+/// arithmetic mean
+pub fn get_mean(samples: &[f64]) -> f64 {
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+/// sample variance, with n-1 in the denominator, zero for a
+/// single sample
+pub fn get_sample_variance(samples: &[f64]) -> f64 {
+    if samples.len() < 2 {
+        return 0.0;
+    }
+    let mean = get_mean(samples);
+    samples.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>()
+        / (samples.len() - 1) as f64
+}
+
+/// percentile (0 to 100) of samples sorted in ascending order
+pub fn get_percentile_of_sorted(sorted_samples: &[f64], percentile: f64) -> f64 {
+    let position = percentile.clamp(0.0, 100.0) / 100.0
+        * (sorted_samples.len() - 1) as f64;
+    let lower_index = position.floor() as usize;
+    let upper_index = (lower_index + 1).min(sorted_samples.len() - 1);
+    let fraction = position - lower_index as f64;
+
+    sorted_samples[lower_index]
+        + fraction * (sorted_samples[upper_index] - sorted_samples[lower_index])
+}
@@ -0,0 +1,286 @@
+use std::f64::consts::PI;
+
+use uom::si::f64::*;
+use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+use uom::si::length::meter;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::time::second;
+use uom::ConstZero;
+
+use crate::calibration::TunableParameter;
+use crate::pre_built_components::ciet_steady_state_natural_circulation_test_components::dracs_loop_components::new_pipe_34;
+use crate::pre_built_components::insulated_pipes_and_fluid_components::InsulatedFluidComponent;
+use crate::tuas_lib_error::TuasLibError;
+
+use super::*;
+
+/// a model with three inputs, evaluated by a closure, used to
+/// compare sensitivity indices with analytical values
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+struct AnalyticalModel {
+    inputs: [f64; 3],
+}
+
+/// a problem on an analytical function with three uniform inputs
+/// between the given bounds
+fn analytical_problem(lower_bound: f64, upper_bound: f64,
+    function: fn(&[f64; 3]) -> f64) -> UncertaintyQuantificationProblem<AnalyticalModel> {
+
+    let mut problem = UncertaintyQuantificationProblem::new(
+        AnalyticalModel::default(),
+        move |model: &mut AnalyticalModel| Ok(vec![function(&model.inputs)]));
+
+    for index in 0..3 {
+        problem.add_parameter(UncertainParameter::new_uniform(TunableParameter::new(
+            &format!("x{}", index + 1),
+            lower_bound, upper_bound, 0.5 * (lower_bound + upper_bound),
+            move |model: &mut AnalyticalModel, value: f64| {
+                model.inputs[index] = value;
+                Ok(())
+            }).unwrap()));
+    }
+    problem.add_output("y");
+
+    problem
+}
+
+/// the Ishigami function, a standard test for sensitivity analysis
+/// with a strong interaction between x1 and x3
+fn ishigami(inputs: &[f64; 3]) -> f64 {
+    let [x1, x2, x3] = *inputs;
+    x1.sin() + 7.0 * x2.sin().powi(2) + 0.1 * x3.powi(4) * x1.sin()
+}
+
+/// Latin hypercube samples have exactly one sample in each stratum
+/// of every parameter, and the same seed gives the same samples
+#[test]
+pub fn latin_hypercube_samples_are_stratified_and_repeatable(){
+
+    let number_of_samples = 50;
+    let samples = get_unit_hypercube_samples(SamplingMethod::LatinHypercube,
+        number_of_samples, 4, &mut UqRandomNumberGenerator::new(7));
+
+    for dimension in 0..4 {
+        let mut strata: Vec<usize> = samples.iter()
+            .map(|sample| (sample[dimension] * number_of_samples as f64) as usize)
+            .collect();
+        strata.sort();
+        assert_eq!(strata, (0..number_of_samples).collect::<Vec<usize>>());
+    }
+
+    let repeated_samples = get_unit_hypercube_samples(SamplingMethod::LatinHypercube,
+        number_of_samples, 4, &mut UqRandomNumberGenerator::new(7));
+    assert_eq!(samples, repeated_samples);
+
+    let monte_carlo_samples = get_unit_hypercube_samples(SamplingMethod::MonteCarlo,
+        1000, 2, &mut UqRandomNumberGenerator::new(7));
+    assert!(monte_carlo_samples.iter().flatten()
+        .all(|value| (0.0..1.0).contains(value)));
+    let mean = get_mean(&monte_carlo_samples.concat());
+    approx::assert_abs_diff_eq!(mean, 0.5, epsilon = 0.02);
+}
+
+/// normal parameters use the inverse cumulative distribution, and
+/// are clamped to their bounds
+#[test]
+pub fn normal_parameters_map_through_the_quantile_function(){
+
+    approx::assert_relative_eq!(get_standard_normal_quantile(0.975),
+        1.959963985, max_relative = 1e-8);
+    approx::assert_relative_eq!(get_standard_normal_quantile(0.01),
+        -2.326347874, max_relative = 1e-8);
+    assert_eq!(get_standard_normal_quantile(0.5), 0.0);
+
+    let parameter = UncertainParameter::try_new_normal_relative(
+        TunableParameter::new("htc (W/(m^2 K))", 10.0, 30.0, 20.0,
+            |_: &mut AnalyticalModel, _: f64| Ok(())).unwrap(),
+        20.0, 0.1).unwrap();
+
+    approx::assert_relative_eq!(parameter.get_value_from_unit_sample(0.975),
+        20.0 + 2.0 * 1.959963985, max_relative = 1e-8);
+    assert_eq!(parameter.get_value_from_unit_sample(0.0), 10.0);
+    assert_eq!(parameter.get_value_from_unit_sample(1.0), 30.0);
+
+    assert!(UncertainParameter::try_new_normal(
+        TunableParameter::new("htc (W/(m^2 K))", 10.0, 30.0, 20.0,
+            |_: &mut AnalyticalModel, _: f64| Ok(())).unwrap(),
+        20.0, -1.0).is_err());
+}
+
+/// propagating uniform inputs through y = x1 + 2 x2 gives the
+/// analytical mean and standard deviation
+#[test]
+pub fn propagation_statistics_of_a_linear_model(){
+
+    let problem = analytical_problem(0.0, 1.0,
+        |inputs| inputs[0] + 2.0 * inputs[1]);
+
+    let result = problem.try_propagate(UqSettings {
+        samples: 2000,
+        ..Default::default()
+    }).unwrap();
+
+    let statistics = result.get_output_statistics("y").unwrap();
+    approx::assert_relative_eq!(statistics.mean, 1.5, max_relative = 1e-3);
+    approx::assert_relative_eq!(statistics.standard_deviation,
+        (5.0_f64 / 12.0).sqrt(), max_relative = 1e-2);
+    assert!(statistics.minimum >= 0.0 && statistics.maximum <= 3.0);
+    assert!(statistics.percentile_5 < statistics.median
+        && statistics.median < statistics.percentile_95);
+    assert_eq!(result.parameter_samples.len(), 2000);
+    assert_eq!(result.get_output_column(0).len(), 2000);
+}
+
+/// Sobol indices of the Ishigami function match the analytical
+/// values, S1 = 0.314, S2 = 0.442, S3 = 0, and total order
+/// ST1 = 0.558, ST2 = 0.442, ST3 = 0.244
+#[test]
+pub fn sobol_indices_of_the_ishigami_function(){
+
+    let problem = analytical_problem(-PI, PI, ishigami);
+
+    let sobol_indices = problem.try_get_sobol_indices(UqSettings {
+        samples: 20000,
+        sampling_method: SamplingMethod::MonteCarlo,
+        seed: 3,
+        threads: 4,
+    }).unwrap();
+
+    assert_eq!(sobol_indices.simulation_runs, 20000 * 5);
+
+    let first_order = &sobol_indices.first_order[0];
+    let total_order = &sobol_indices.total_order[0];
+
+    for (estimate, analytical) in first_order.iter().zip([0.3139, 0.4424, 0.0]) {
+        approx::assert_abs_diff_eq!(*estimate, analytical, epsilon = 0.03);
+    }
+    for (estimate, analytical) in total_order.iter().zip([0.5576, 0.4424, 0.2437]) {
+        approx::assert_abs_diff_eq!(*estimate, analytical, epsilon = 0.03);
+    }
+}
+
+/// for a linear model, every elementary effect equals the change in
+/// output over the range of the parameter, so the mean absolute
+/// effects rank the parameters and their standard deviations vanish
+#[test]
+pub fn morris_screening_of_a_linear_model(){
+
+    let problem = analytical_problem(0.0, 2.0,
+        |inputs| inputs[0] - 10.0 * inputs[1]);
+
+    let screening = problem.try_get_morris_screening(MorrisSettings {
+        trajectories: 20,
+        ..Default::default()
+    }).unwrap();
+
+    assert_eq!(screening.simulation_runs, 20 * 4);
+    for (parameter_index, effect) in [2.0, -20.0, 0.0].iter().enumerate() {
+        approx::assert_abs_diff_eq!(screening.mean[0][parameter_index],
+            effect, epsilon = 1e-9);
+        approx::assert_abs_diff_eq!(screening.mean_absolute[0][parameter_index],
+            effect.abs(), epsilon = 1e-9);
+        approx::assert_abs_diff_eq!(screening.standard_deviation[0][parameter_index],
+            0.0, epsilon = 1e-9);
+    }
+
+    assert!(problem.try_get_morris_screening(MorrisSettings {
+        levels: 5,
+        ..Default::default()
+    }).is_err());
+}
+
+/// a hot pipe from the DRACS loop cooling with stagnant fluid, with
+/// uncertain insulation thickness and heat transfer to ambient set
+/// through the calibration setters: thicker insulation keeps the
+/// fluid warmer, a larger heat transfer coefficient cools it, and
+/// running on several threads gives the same results
+#[test]
+pub fn insulated_pipe_cooldown_uncertainty(){
+
+    let initial_temperature = ThermodynamicTemperature::new::<kelvin>(350.0);
+
+    let mut problem = UncertaintyQuantificationProblem::new(
+        new_pipe_34(initial_temperature),
+        |pipe: &mut InsulatedFluidComponent| {
+            let timestep = Time::new::<second>(1.0);
+            for _ in 0..60 {
+                pipe.lateral_and_miscellaneous_connections_no_wall_correction(
+                    MassRate::ZERO, Power::ZERO)?;
+                pipe.advance_timestep(timestep)?;
+            }
+            let bulk_temperature = pipe.pipe_fluid_array.try_get_bulk_temperature()?;
+            Ok(vec![bulk_temperature.get::<kelvin>()])
+        });
+
+    problem.add_parameter(UncertainParameter::new_uniform(TunableParameter::new(
+        "insulation thickness (m)",
+        0.0254, 0.0762, 0.0508,
+        |pipe: &mut InsulatedFluidComponent, thickness: f64| {
+            pipe.calibrate_insulation_thickness(Length::new::<meter>(thickness));
+            Ok(())
+        }).unwrap()));
+    problem.add_parameter(UncertainParameter::try_new_normal_relative(
+        TunableParameter::new(
+            "heat transfer to ambient (W/(m^2 K))",
+            5.0, 40.0, 20.0,
+            |pipe: &mut InsulatedFluidComponent, htc: f64| {
+                pipe.calibrate_heat_transfer_to_ambient(
+                    HeatTransfer::new::<watt_per_square_meter_kelvin>(htc));
+                Ok(())
+            }).unwrap(),
+        20.0, 0.25).unwrap());
+    problem.add_output("fluid bulk temperature (K)");
+
+    let settings = UqSettings {
+        samples: 8,
+        ..Default::default()
+    };
+    let serial_result = problem.try_propagate(settings).unwrap();
+    let parallel_result = problem.try_propagate(UqSettings {
+        threads: 3,
+        ..settings
+    }).unwrap();
+    assert_eq!(serial_result, parallel_result);
+
+    let statistics = &serial_result.output_statistics[0];
+    assert!(statistics.maximum < 350.0);
+    assert!(statistics.minimum < statistics.maximum);
+
+    let screening = problem.try_get_morris_screening(MorrisSettings {
+        trajectories: 4,
+        threads: 2,
+        ..Default::default()
+    }).unwrap();
+    assert!(screening.mean[0][0] > 0.0);
+    assert!(screening.mean[0][1] < 0.0);
+}
+
+/// runs which fail are reported with their index and error
+#[test]
+pub fn failed_runs_are_reported(){
+
+    let mut failing_problem = UncertaintyQuantificationProblem::new(
+        AnalyticalModel::default(),
+        |model: &mut AnalyticalModel| {
+            if model.inputs[0] > 0.5 {
+                return Err(TuasLibError::GenericStringError(
+                    "diverged".to_owned()));
+            }
+            Ok(vec![model.inputs[0]])
+        });
+    failing_problem.add_parameter(UncertainParameter::new_uniform(TunableParameter::new(
+        "x1", 0.0, 1.0, 0.5,
+        |model: &mut AnalyticalModel, value: f64| {
+            model.inputs[0] = value;
+            Ok(())
+        }).unwrap()));
+    failing_problem.add_output("y");
+    let error: String = failing_problem.try_propagate(UqSettings::default())
+        .unwrap_err().into();
+    assert!(error.contains("failed: diverged"));
+
+    let empty_problem = UncertaintyQuantificationProblem::new(
+        AnalyticalModel::default(),
+        |_: &mut AnalyticalModel| Ok(vec![]));
+    assert!(empty_problem.try_propagate(UqSettings::default()).is_err());
+}
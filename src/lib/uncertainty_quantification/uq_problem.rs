@@ -0,0 +1,270 @@
+use std::thread;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::sampling::{get_unit_hypercube_samples, SamplingMethod,
+    UncertainParameter, UqRandomNumberGenerator};
+use super::statistics::OutputStatistics;
+
+/// settings for sampling and running the model
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct UqSettings {
+    /// how samples are drawn
+    pub sampling_method: SamplingMethod,
+    /// number of samples, for Sobol indices this is the number of
+    /// base samples, and the model is run samples*(parameters+2)
+    /// times
+    pub samples: usize,
+    /// seed of the random number generator, the same seed gives
+    /// the same samples
+    pub seed: u64,
+    /// number of threads running the model, 1 runs every sample on
+    /// the calling thread
+    pub threads: usize,
+}
+
+impl Default for UqSettings {
+    fn default() -> Self {
+        Self {
+            sampling_method: SamplingMethod::LatinHypercube,
+            samples: 100,
+            seed: 0,
+            threads: 1,
+        }
+    }
+}
+
+/// result of propagating the parameter uncertainties to the
+/// outputs
+#[derive(Debug,Clone,PartialEq)]
+pub struct UqResult {
+    /// names of the uncertain parameters
+    pub parameter_names: Vec<String>,
+    /// names of the outputs
+    pub output_names: Vec<String>,
+    /// parameter values of every run (SI units), one row per run
+    pub parameter_samples: Vec<Vec<f64>>,
+    /// outputs of every run, one row per run
+    pub output_samples: Vec<Vec<f64>>,
+    /// statistics of each output, in the order of the outputs
+    pub output_statistics: Vec<OutputStatistics>,
+}
+
+impl UqResult {
+
+    /// statistics of an output by name
+    pub fn get_output_statistics(&self, name: &str) -> Option<&OutputStatistics> {
+        self.output_statistics.iter()
+            .find(|statistics| statistics.name == name)
+    }
+
+    /// samples of one output, in the order of the runs
+    pub fn get_output_column(&self, output_index: usize) -> Vec<f64> {
+        self.output_samples.iter()
+            .map(|outputs| outputs[output_index])
+            .collect()
+    }
+}
+
+/// simulation run on each sampled model, returning its outputs
+type UqSimulation<M> = Box<dyn Fn(&mut M) -> Result<Vec<f64>, TuasLibError> + Send + Sync>;
+
+/// an uncertainty quantification problem, which consists of a
+/// model (eg. a component or a whole loop), uncertain parameters
+/// bound to its setters, and a simulation closure which runs the
+/// model (steady state or transient) and returns the outputs in
+/// the order of the output names
+///
+/// as for calibration, the model is cloned for every run so that
+/// every run starts from the same initial state. The parameters are
+/// applied on the calling thread, so setters need not be thread
+/// safe, while the simulation may run on several threads
+pub struct UncertaintyQuantificationProblem<M: Clone + Send> {
+    /// the model in its initial state
+    pub model: M,
+    /// uncertain parameters
+    pub parameters: Vec<UncertainParameter<M>>,
+    /// names of the outputs returned by the simulation
+    pub output_names: Vec<String>,
+    simulation: UqSimulation<M>,
+}
+
+impl<M: Clone + Send> UncertaintyQuantificationProblem<M> {
+
+    /// creates a new problem with no parameters or outputs
+    pub fn new<F>(model: M, simulation: F) -> Self
+    where F: Fn(&mut M) -> Result<Vec<f64>, TuasLibError> + Send + Sync + 'static {
+        Self {
+            model,
+            parameters: vec![],
+            output_names: vec![],
+            simulation: Box::new(simulation),
+        }
+    }
+
+    /// adds an uncertain parameter
+    pub fn add_parameter(&mut self, parameter: UncertainParameter<M>) {
+        self.parameters.push(parameter);
+    }
+
+    /// adds the name of the next output returned by the simulation
+    pub fn add_output(&mut self, name: &str) {
+        self.output_names.push(name.to_owned());
+    }
+
+    /// names of the uncertain parameters
+    pub fn get_parameter_names(&self) -> Vec<String> {
+        self.parameters.iter()
+            .map(|parameter| parameter.parameter.name.clone())
+            .collect()
+    }
+
+    /// maps a sample of the unit hypercube to parameter values
+    pub fn get_parameter_values_from_unit_sample(&self, unit_sample: &[f64]) -> Vec<f64> {
+        self.parameters.iter().zip(unit_sample.iter())
+            .map(|(parameter, value)| parameter.get_value_from_unit_sample(*value))
+            .collect()
+    }
+
+    /// checks that the problem has parameters and outputs
+    pub(crate) fn try_check_problem(&self) -> Result<(), TuasLibError> {
+        if self.parameters.is_empty() {
+            return Err(TuasLibError::GenericStringError(
+                "uncertainty quantification problem has no uncertain parameters"
+                .to_owned()));
+        }
+        if self.output_names.is_empty() {
+            return Err(TuasLibError::GenericStringError(
+                "uncertainty quantification problem has no outputs".to_owned()));
+        }
+        Ok(())
+    }
+
+    /// runs the model for every row of parameter values and returns
+    /// the outputs of every run
+    ///
+    /// runs are done in batches, a few per thread, so that only a
+    /// batch of models is held in memory at once
+    pub fn try_run_parameter_samples(&self,
+        parameter_samples: &[Vec<f64>],
+        threads: usize) -> Result<Vec<Vec<f64>>, TuasLibError> {
+
+        let threads = threads.max(1);
+        let batch_size = 8 * threads;
+        let mut output_samples = Vec::with_capacity(parameter_samples.len());
+
+        for (batch_number, batch) in parameter_samples.chunks(batch_size).enumerate() {
+
+            let mut models = Vec::with_capacity(batch.len());
+            for parameter_values in batch {
+                if parameter_values.len() != self.parameters.len() {
+                    return Err(TuasLibError::GenericStringError(
+                        format!("expected {} parameter values, got {}",
+                            self.parameters.len(), parameter_values.len())));
+                }
+                let mut model = self.model.clone();
+                for (parameter, value) in self.parameters.iter()
+                    .zip(parameter_values.iter()) {
+                    parameter.parameter.try_apply(&mut model, *value)?;
+                }
+                models.push(model);
+            }
+
+            let batch_outputs = self.run_models(models, threads);
+
+            for (index, outputs) in batch_outputs.into_iter().enumerate() {
+                let run = batch_number * batch_size + index;
+                let outputs = outputs.map_err(|error| {
+                    let message: String = error.into();
+                    TuasLibError::GenericStringError(
+                        format!("run {} failed: {}", run, message))
+                })?;
+                if outputs.len() != self.output_names.len() {
+                    return Err(TuasLibError::GenericStringError(
+                        format!("run {} returned {} values for {} outputs",
+                            run, outputs.len(), self.output_names.len())));
+                }
+                if let Some(index) = outputs.iter().position(|value| !value.is_finite()) {
+                    return Err(TuasLibError::GenericStringError(
+                        format!("run {} returned {} for output {}",
+                            run, outputs[index], self.output_names[index])));
+                }
+                output_samples.push(outputs);
+            }
+        }
+
+        Ok(output_samples)
+    }
+
+    /// runs the simulation on each model, splitting the models
+    /// evenly between threads
+    fn run_models(&self, mut models: Vec<M>, threads: usize)
+        -> Vec<Result<Vec<f64>, TuasLibError>> {
+
+        let simulation = &self.simulation;
+
+        if threads == 1 || models.len() < 2 {
+            return models.iter_mut().map(simulation).collect();
+        }
+
+        let models_per_thread = models.len().div_ceil(threads);
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = models.chunks_mut(models_per_thread)
+                .map(|chunk| (chunk.len(), scope.spawn(move || {
+                    chunk.iter_mut().map(simulation)
+                        .collect::<Vec<_>>()
+                })))
+                .collect();
+
+            // a panicking run fails every run of its thread
+            handles.into_iter().flat_map(|(chunk_size, handle)| {
+                handle.join().unwrap_or_else(|_| (0..chunk_size)
+                    .map(|_| Err(TuasLibError::GenericStringError(
+                        "simulation panicked".to_owned())))
+                    .collect())
+            }).collect()
+        })
+    }
+
+    /// samples the uncertain parameters, runs the model for every
+    /// sample and reports the statistics of each output
+    pub fn try_propagate(&self, settings: UqSettings) -> Result<UqResult, TuasLibError> {
+
+        self.try_check_problem()?;
+        if settings.samples < 2 {
+            return Err(TuasLibError::GenericStringError(
+                "at least two samples are needed".to_owned()));
+        }
+
+        let mut random_number_generator = UqRandomNumberGenerator::new(settings.seed);
+        let parameter_samples: Vec<Vec<f64>> = get_unit_hypercube_samples(
+            settings.sampling_method,
+            settings.samples,
+            self.parameters.len(),
+            &mut random_number_generator)
+            .iter()
+            .map(|unit_sample| self.get_parameter_values_from_unit_sample(unit_sample))
+            .collect();
+
+        let output_samples = self.try_run_parameter_samples(
+            &parameter_samples, settings.threads)?;
+
+        let output_statistics = self.output_names.iter().enumerate()
+            .map(|(output_index, name)| {
+                let samples: Vec<f64> = output_samples.iter()
+                    .map(|outputs| outputs[output_index])
+                    .collect();
+                OutputStatistics::from_samples(name, &samples)
+            })
+            .collect();
+
+        Ok(UqResult {
+            parameter_names: self.get_parameter_names(),
+            output_names: self.output_names.clone(),
+            parameter_samples,
+            output_samples,
+            output_statistics,
+        })
+    }
+}
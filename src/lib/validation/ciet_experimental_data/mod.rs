@@ -0,0 +1,30 @@
+use crate::tuas_lib_error::TuasLibError;
+
+use super::experiment_table::ExperimentTable;
+
+/// CIET coupled primary loop and DRACS loop natural circulation
+/// data (datasets A, B and C), with heater power, TCHX outlet
+/// temperature, experimental DRACS and primary loop mass flowrates,
+/// experimental heater average surface temperature and its tolerance,
+/// and the values obtained from version 7 of the coupled loop in
+/// coupled_dracs_loop_tests for regression
+///
+/// columns:
+/// test_label, heater_power_watts, tchx_outlet_temp_degc,
+/// experimental_dracs_mass_flowrate_kg_per_s,
+/// experimental_pri_mass_flowrate_kg_per_s,
+/// simulated_expected_dracs_mass_flowrate_kg_per_s,
+/// simulated_expected_pri_mass_flowrate_kg_per_s,
+/// experimental_heater_surf_temp_avg_degc,
+/// simulated_expected_heater_surf_temp_degc,
+/// heater_surf_temp_tolerance_degc
+///
+/// Zou, L., Hu, G., O'Grady, D., & Hu, R. (2021). Code validation
+/// of SAM using natural-circulation experimental data from the
+/// compact integral effects test (CIET) facility.
+/// Nuclear Engineering and Design, 377, 111144.
+pub fn ciet_coupled_dracs_natural_circulation_table()
+    -> Result<ExperimentTable, TuasLibError> {
+    ExperimentTable::try_from_csv_str(
+        include_str!("coupled_dracs_natural_circulation.csv"))
+}
@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::Path;
+
+use crate::tuas_lib_error::TuasLibError;
+
+/// one row (experimental point) of an experiment table
+///
+/// numeric columns are stored as f64, any column which cannot be
+/// parsed as a number (eg. a test label) is stored as text
+#[derive(Debug,Clone,PartialEq,Default)]
+pub struct ExperimentRow {
+    /// numeric values by column name
+    pub numeric_values: BTreeMap<String, f64>,
+    /// text values by column name
+    pub text_values: BTreeMap<String, String>,
+}
+
+impl ExperimentRow {
+
+    /// gets a numeric value by column name, returns an error
+    /// if the column is missing or not numeric
+    pub fn try_get(&self, column_name: &str) -> Result<f64, TuasLibError> {
+        match self.numeric_values.get(column_name) {
+            Some(value) => Ok(*value),
+            None => Err(TuasLibError::GenericStringError(
+                format!("experiment row has no numeric column {}",
+                    column_name))),
+        }
+    }
+
+    /// gets a text value by column name
+    pub fn get_text(&self, column_name: &str) -> Option<&str> {
+        self.text_values.get(column_name).map(|text| text.as_str())
+    }
+}
+
+/// a table of experimental points loaded from CSV, where the
+/// first row is the header containing column names
+///
+/// for example, CIET natural circulation data with heater power,
+/// TCHX outlet temperature and primary/DRACS mass flowrates
+#[derive(Debug,Clone,PartialEq,Default)]
+pub struct ExperimentTable {
+    /// column names in the order of the header
+    pub column_names: Vec<String>,
+    /// experimental points
+    pub rows: Vec<ExperimentRow>,
+}
+
+impl ExperimentTable {
+
+    /// loads an experiment table from a CSV file
+    pub fn try_load_csv<P: AsRef<Path>>(path: P)
+        -> Result<Self, TuasLibError> {
+        let reader = csv::Reader::from_path(path)?;
+        Self::try_from_csv_reader(reader)
+    }
+
+    /// loads an experiment table from a CSV string
+    ///
+    /// ```rust
+    /// use tuas_boussinesq_solver::validation::ExperimentTable;
+    ///
+    /// let table = ExperimentTable::try_from_csv_str(
+    ///     "test_label,heater_power_watts\nA1,1479.86\nA2,1653.90\n")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(table.rows.len(), 2);
+    /// assert_eq!(table.rows[1].try_get("heater_power_watts").unwrap(),
+    ///     1653.90);
+    /// assert_eq!(table.rows[0].get_text("test_label"), Some("A1"));
+    /// ```
+    pub fn try_from_csv_str(csv_text: &str) -> Result<Self, TuasLibError> {
+        let reader = csv::Reader::from_reader(csv_text.as_bytes());
+        Self::try_from_csv_reader(reader)
+    }
+
+    fn try_from_csv_reader<R: Read>(mut reader: csv::Reader<R>)
+        -> Result<Self, TuasLibError> {
+
+        let column_names: Vec<String> = reader.headers()?
+            .iter()
+            .map(|name| name.trim().to_owned())
+            .collect();
+
+        let mut rows: Vec<ExperimentRow> = vec![];
+
+        for record in reader.records() {
+            let record = record?;
+            let mut row = ExperimentRow::default();
+
+            for (column_name, field) in column_names.iter()
+                .zip(record.iter()) {
+                let field = field.trim();
+
+                match field.parse::<f64>() {
+                    Ok(value) => {
+                        row.numeric_values.insert(column_name.clone(), value);
+                    },
+                    Err(_) => {
+                        row.text_values.insert(column_name.clone(),
+                            field.to_owned());
+                    },
+                }
+            }
+            rows.push(row);
+        }
+
+        Ok(Self { column_names, rows })
+    }
+
+    /// table with only the rows for which the predicate is true,
+    /// eg. one dataset of a table containing several
+    pub fn get_filtered_table<P>(&self, predicate: P) -> Self
+    where P: Fn(&ExperimentRow) -> bool {
+        Self {
+            column_names: self.column_names.clone(),
+            rows: self.rows.iter()
+                .filter(|row| predicate(row))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// gets all values of a numeric column
+    pub fn try_get_column(&self, column_name: &str)
+        -> Result<Vec<f64>, TuasLibError> {
+        self.rows.iter().map(|row| row.try_get(column_name)).collect()
+    }
+}
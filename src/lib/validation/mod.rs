@@ -0,0 +1,15 @@
+/// loading experiment tables from CSV
+pub mod experiment_table;
+pub use experiment_table::*;
+
+/// running a model over an experiment table and reporting
+/// per point relative errors, RMS and max errors and pass/fail
+pub mod validation_report;
+pub use validation_report::*;
+
+/// CIET experimental data shipped with the library
+pub mod ciet_experimental_data;
+
+/// tests for the validation harness
+#[cfg(test)]
+pub mod tests;
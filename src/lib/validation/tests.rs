@@ -0,0 +1,198 @@
+use super::*;
+use super::ciet_experimental_data::ciet_coupled_dracs_natural_circulation_table;
+
+/// the CIET coupled DRACS table should contain datasets A, B and C
+/// (7, 9 and 9 points) with every column numeric except the label,
+/// so that each dataset can be run through the coupled loop
+/// (see coupled_dracs_loop_tests)
+#[test]
+pub fn ciet_coupled_dracs_table_contains_datasets_a_b_and_c(){
+
+    let table = ciet_coupled_dracs_natural_circulation_table().unwrap();
+    assert_eq!(table.rows.len(), 25);
+    assert_eq!(table.column_names.len(), 10);
+
+    for (dataset_label, number_of_points, tchx_outlet_temp_degc) in
+        [("A", 7, 46.0), ("B", 9, 35.0), ("C", 9, 40.0)] {
+
+        let dataset = table.get_filtered_table(|row| {
+            row.get_text("test_label")
+                .is_some_and(|label| label.starts_with(dataset_label))
+        });
+
+        assert_eq!(dataset.rows.len(), number_of_points);
+
+        for temperature in dataset.try_get_column(
+            "tchx_outlet_temp_degc").unwrap() {
+            assert_eq!(temperature, tchx_outlet_temp_degc);
+        }
+    }
+
+    for row in table.rows.iter() {
+        assert_eq!(row.numeric_values.len(), 9);
+        assert_eq!(row.text_values.len(), 1);
+    }
+}
+
+/// points outside tolerance should be reported as failed,
+/// and the CSV report should be readable as an experiment table
+#[test]
+pub fn validation_report_failures_and_csv_output(){
+
+    let table = ExperimentTable::try_from_csv_str(
+        "test_label,heater_power_watts,outlet_temp_degc\n\
+        P1,1000.0,50.0\n\
+        P2,2000.0,60.0\n").unwrap();
+
+    let quantities = vec![
+        ValidatedQuantity::new("outlet temperature",
+            "outlet_temp_degc", 0.05),
+    ];
+
+    // 50 + 0.005 * power, which is 55 degC (10% error) at 1000 W
+    // and 60 degC (no error) at 2000 W
+    let report = try_run_validation(&table, &quantities,
+        |row: &ExperimentRow| {
+            Ok(vec![50.0 + 0.005 * row.try_get("heater_power_watts")?])
+        }).unwrap();
+
+    assert!(!report.all_passed());
+
+    let failed = report.get_failed_comparisons();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].row_label, "P1");
+    approx::assert_relative_eq!(failed[0].error, 0.1,
+        max_relative=1e-12);
+    approx::assert_relative_eq!(report.summaries[0].rms_error,
+        0.1/2.0_f64.sqrt(), max_relative=1e-12);
+
+    let csv_report = report.to_csv_string().unwrap();
+    let report_table = ExperimentTable::try_from_csv_str(&csv_report)
+        .unwrap();
+
+    assert_eq!(report_table.rows.len(), 2);
+    assert_eq!(report_table.rows[0].get_text("passed"), Some("false"));
+    assert_eq!(report_table.rows[1].get_text("passed"), Some("true"));
+    approx::assert_relative_eq!(
+        report_table.rows[0].try_get("simulated_value").unwrap(),
+        55.0, max_relative=1e-12);
+    approx::assert_relative_eq!(
+        report_table.rows[0].try_get("tolerance").unwrap(),
+        0.05, max_relative=1e-12);
+
+    // missing columns are errors rather than silently skipped
+    let missing_column = vec![
+        ValidatedQuantity::new("flowrate", "mass_flowrate_kg_per_s", 0.05),
+    ];
+    assert!(try_run_validation(&table, &missing_column,
+        |_row: &ExperimentRow| Ok(vec![0.0])).is_err());
+}
+
+/// a relative error is undefined where the experimental value is
+/// zero, so that should be an error rather than an infinite or NaN
+/// error, while an absolute tolerance (fixed or read per point)
+/// can compare such points
+#[test]
+pub fn zero_experimental_values_need_absolute_tolerances(){
+
+    let table = ExperimentTable::try_from_csv_str(
+        "test_label,heater_power_watts,temperature_rise_degc,uncertainty_degc\n\
+        P1,0.0,0.0,0.1\n\
+        P2,1000.0,10.0,1.0\n").unwrap();
+
+    // 0.0105 degC per watt, so 10.5 degC at 1000 W
+    let model = |row: &ExperimentRow| {
+        Ok(vec![0.0105 * row.try_get("heater_power_watts")?])
+    };
+
+    let relative = vec![
+        ValidatedQuantity::new("temperature rise",
+            "temperature_rise_degc", 0.1),
+    ];
+    assert!(try_run_validation(&table, &relative, model).is_err());
+
+    let absolute = vec![
+        ValidatedQuantity::new_with_tolerance("temperature rise",
+            "temperature_rise_degc", ValidationTolerance::Absolute(0.6)),
+    ];
+    let report = try_run_validation(&table, &absolute, model).unwrap();
+
+    assert!(report.all_passed());
+    approx::assert_relative_eq!(report.comparisons[1].error, 0.5,
+        max_relative=1e-9);
+    approx::assert_relative_eq!(report.summaries[0].max_error, 0.5,
+        max_relative=1e-9);
+
+    // 0.5 degC is outside the measurement uncertainty of P2
+    let uncertainty_column = vec![
+        ValidatedQuantity::new_with_tolerance("temperature rise",
+            "temperature_rise_degc",
+            ValidationTolerance::AbsoluteFromColumn(
+                "uncertainty_degc".to_owned())),
+    ];
+    let report = try_run_validation(&table, &uncertainty_column, model)
+        .unwrap();
+
+    assert!(report.comparisons[0].passed);
+    assert!(report.comparisons[1].passed);
+
+    let tight_table = ExperimentTable::try_from_csv_str(
+        "test_label,heater_power_watts,temperature_rise_degc,uncertainty_degc\n\
+        P1,0.0,0.0,0.1\n\
+        P2,1000.0,10.0,0.2\n").unwrap();
+    let report = try_run_validation(&tight_table, &uncertainty_column,
+        model).unwrap();
+
+    assert!(report.comparisons[0].passed);
+    assert!(!report.comparisons[1].passed);
+    assert_eq!(report.comparisons[1].tolerance, 0.2);
+
+    // non finite simulated values are errors too
+    assert!(try_run_validation(&table, &absolute,
+        |_row: &ExperimentRow| Ok(vec![f64::NAN])).is_err());
+}
+
+/// running rows on several threads should give the same report
+/// as running them one after another, and model errors should
+/// name the row which failed
+#[test]
+pub fn validation_on_threads_matches_sequential_validation(){
+
+    let table = ExperimentTable::try_from_csv_str(
+        "test_label,heater_power_watts,outlet_temp_degc\n\
+        P1,1000.0,55.0\n\
+        P2,2000.0,60.0\n\
+        P3,3000.0,64.0\n\
+        P4,4000.0,71.0\n\
+        P5,5000.0,75.0\n").unwrap();
+
+    let quantities = vec![
+        ValidatedQuantity::new("outlet temperature",
+            "outlet_temp_degc", 0.02),
+    ];
+
+    let model = |row: &ExperimentRow| {
+        Ok(vec![50.0 + 0.005 * row.try_get("heater_power_watts")?])
+    };
+
+    let sequential_report = try_run_validation(&table, &quantities,
+        model).unwrap();
+
+    for threads in [1, 2, 3, 5, 8] {
+        let threaded_report = try_run_validation_on_threads(&table,
+            &quantities, model, threads).unwrap();
+        assert_eq!(threaded_report, sequential_report);
+    }
+
+    let failing_model = |row: &ExperimentRow| {
+        if row.get_text("test_label") == Some("P4") {
+            return Err(crate::tuas_lib_error::TuasLibError::
+                GenericStringError("diverged".to_owned()));
+        }
+        Ok(vec![0.0])
+    };
+
+    let error: String = try_run_validation_on_threads(&table,
+        &quantities, failing_model, 2).unwrap_err().into();
+    assert!(error.contains("P4"));
+}
@@ -0,0 +1,335 @@
+use std::path::Path;
+use std::thread;
+
+use crate::tuas_lib_error::TuasLibError;
+
+use super::experiment_table::{ExperimentRow, ExperimentTable};
+
+/// maximum allowable difference between simulation and experiment
+#[derive(Debug,Clone,PartialEq)]
+pub enum ValidationTolerance {
+    /// maximum relative error, |sim - expt|/|expt|,
+    /// the experimental values must be nonzero
+    Relative(f64),
+    /// maximum absolute error, |sim - expt|, in the units of the
+    /// experimental column
+    Absolute(f64),
+    /// maximum absolute error read from the given column, for
+    /// experiments where the measurement uncertainty differs from
+    /// point to point
+    AbsoluteFromColumn(String),
+}
+
+/// a quantity compared between simulation and experiment,
+/// the experimental value is read from the given column
+#[derive(Debug,Clone,PartialEq)]
+pub struct ValidatedQuantity {
+    /// name of the quantity, used in reporting
+    pub name: String,
+    /// column containing the experimental value
+    pub experimental_column: String,
+    /// maximum allowable error
+    pub tolerance: ValidationTolerance,
+}
+
+impl ValidatedQuantity {
+    /// creates a new validated quantity with a relative tolerance,
+    /// |sim - expt|/|expt|
+    pub fn new(name: &str, experimental_column: &str,
+        relative_tolerance: f64) -> Self {
+        Self::new_with_tolerance(name, experimental_column,
+            ValidationTolerance::Relative(relative_tolerance))
+    }
+
+    /// creates a new validated quantity with any kind of tolerance,
+    /// absolute tolerances are needed where experimental values can
+    /// be zero, eg. temperature differences
+    pub fn new_with_tolerance(name: &str, experimental_column: &str,
+        tolerance: ValidationTolerance) -> Self {
+        Self {
+            name: name.to_owned(),
+            experimental_column: experimental_column.to_owned(),
+            tolerance,
+        }
+    }
+}
+
+/// comparison of one quantity at one experimental point
+#[derive(Debug,Clone,PartialEq)]
+pub struct PointComparison {
+    /// index of the row within the experiment table
+    pub row_index: usize,
+    /// label of the row, taken from the first text column if any
+    pub row_label: String,
+    /// name of the quantity
+    pub quantity_name: String,
+    /// experimental value
+    pub experimental_value: f64,
+    /// simulated value
+    pub simulated_value: f64,
+    /// (sim - expt)/|expt| for relative tolerances,
+    /// sim - expt for absolute tolerances
+    pub error: f64,
+    /// tolerance at this point, relative or absolute
+    pub tolerance: f64,
+    /// whether |error| is within tolerance
+    pub passed: bool,
+}
+
+/// error metrics of one quantity over all experimental points
+#[derive(Debug,Clone,PartialEq)]
+pub struct QuantitySummary {
+    /// name of the quantity
+    pub quantity_name: String,
+    /// number of points compared
+    pub number_of_points: usize,
+    /// root mean square error, relative or absolute
+    /// depending on the tolerance
+    pub rms_error: f64,
+    /// maximum absolute value of the error
+    pub max_error: f64,
+    /// tolerance used
+    pub tolerance: ValidationTolerance,
+    /// whether all points are within tolerance
+    pub passed: bool,
+}
+
+/// result of running a model over an experiment table
+#[derive(Debug,Clone,PartialEq)]
+pub struct ValidationReport {
+    /// per point comparisons, row by row
+    pub comparisons: Vec<PointComparison>,
+    /// per quantity error metrics
+    pub summaries: Vec<QuantitySummary>,
+}
+
+impl ValidationReport {
+
+    /// whether every point of every quantity is within tolerance
+    pub fn all_passed(&self) -> bool {
+        self.summaries.iter().all(|summary| summary.passed)
+    }
+
+    /// point comparisons which are outside tolerance
+    pub fn get_failed_comparisons(&self) -> Vec<&PointComparison> {
+        self.comparisons.iter()
+            .filter(|comparison| !comparison.passed)
+            .collect()
+    }
+
+    /// machine readable report in CSV format, one line per
+    /// point comparison
+    pub fn to_csv_string(&self) -> Result<String, TuasLibError> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        self.write_records(&mut writer)?;
+
+        let bytes = writer.into_inner().map_err(|error| {
+            TuasLibError::GenericStringError(error.to_string())
+        })?;
+
+        String::from_utf8(bytes).map_err(|error| {
+            TuasLibError::GenericStringError(error.to_string())
+        })
+    }
+
+    /// writes the machine readable CSV report to a file
+    pub fn try_write_csv<P: AsRef<Path>>(&self, path: P)
+        -> Result<(), TuasLibError> {
+        let mut writer = csv::Writer::from_path(path)?;
+        self.write_records(&mut writer)?;
+        writer.flush().map_err(csv::Error::from)?;
+        Ok(())
+    }
+
+    fn write_records<W: std::io::Write>(&self,
+        writer: &mut csv::Writer<W>) -> Result<(), TuasLibError> {
+
+        writer.write_record(["row_index",
+            "row_label",
+            "quantity",
+            "experimental_value",
+            "simulated_value",
+            "error",
+            "tolerance",
+            "passed"])?;
+
+        for comparison in self.comparisons.iter() {
+            writer.write_record(&[
+                comparison.row_index.to_string(),
+                comparison.row_label.clone(),
+                comparison.quantity_name.clone(),
+                comparison.experimental_value.to_string(),
+                comparison.simulated_value.to_string(),
+                comparison.error.to_string(),
+                comparison.tolerance.to_string(),
+                comparison.passed.to_string()])?;
+        }
+
+        Ok(())
+    }
+}
+
+/// runs a model closure for every row of an experiment table and
+/// compares its outputs to the experimental values
+///
+/// the model receives the experiment row (eg. heater power and TCHX
+/// outlet temperature) and returns the simulated value of each
+/// validated quantity, in the same order as the quantities
+pub fn try_run_validation<F>(table: &ExperimentTable,
+    quantities: &[ValidatedQuantity],
+    mut model: F) -> Result<ValidationReport, TuasLibError>
+where F: FnMut(&ExperimentRow) -> Result<Vec<f64>, TuasLibError> {
+
+    let simulated_rows: Vec<Result<Vec<f64>, TuasLibError>> =
+        table.rows.iter().map(&mut model).collect();
+
+    try_get_validation_report(table, quantities, simulated_rows)
+}
+
+/// runs a model closure for every row of an experiment table on
+/// several threads, and compares its outputs to the experimental
+/// values
+///
+/// every row is independent, so for models which take a long time
+/// per point (eg. a loop run to steady state), the rows are split
+/// evenly between threads
+pub fn try_run_validation_on_threads<F>(table: &ExperimentTable,
+    quantities: &[ValidatedQuantity],
+    model: F,
+    threads: usize) -> Result<ValidationReport, TuasLibError>
+where F: Fn(&ExperimentRow) -> Result<Vec<f64>, TuasLibError> + Sync {
+
+    let threads = threads.max(1);
+
+    if threads == 1 || table.rows.len() < 2 {
+        return try_run_validation(table, quantities, model);
+    }
+
+    let rows_per_thread = table.rows.len().div_ceil(threads);
+    let model = &model;
+
+    let simulated_rows: Vec<Result<Vec<f64>, TuasLibError>> =
+        thread::scope(|scope| {
+            let handles: Vec<_> = table.rows.chunks(rows_per_thread)
+                .map(|chunk| (chunk.len(), scope.spawn(move || {
+                    chunk.iter().map(model).collect::<Vec<_>>()
+                })))
+                .collect();
+
+            // a panicking row fails every row of its thread
+            handles.into_iter().flat_map(|(chunk_size, handle)| {
+                handle.join().unwrap_or_else(|_| (0..chunk_size)
+                    .map(|_| Err(TuasLibError::GenericStringError(
+                        "model panicked".to_owned())))
+                    .collect())
+            }).collect()
+        });
+
+    try_get_validation_report(table, quantities, simulated_rows)
+}
+
+/// compares the simulated values of every row to the experimental
+/// values and summarises the errors of each quantity
+fn try_get_validation_report(table: &ExperimentTable,
+    quantities: &[ValidatedQuantity],
+    simulated_rows: Vec<Result<Vec<f64>, TuasLibError>>)
+    -> Result<ValidationReport, TuasLibError> {
+
+    let mut comparisons: Vec<PointComparison> = vec![];
+
+    for (row_index, (row, simulated_values)) in table.rows.iter()
+        .zip(simulated_rows).enumerate() {
+
+        let row_label: String = table.column_names.iter()
+            .find_map(|name| row.get_text(name))
+            .map(|label| label.to_owned())
+            .unwrap_or_else(|| row_index.to_string());
+
+        let simulated_values = simulated_values.map_err(|error| {
+            let message: String = error.into();
+            TuasLibError::GenericStringError(
+                format!("model failed for row {}: {}", row_label, message))
+        })?;
+
+        if simulated_values.len() != quantities.len() {
+            return Err(TuasLibError::GenericStringError(
+                format!("model returned {} values for {} quantities",
+                    simulated_values.len(), quantities.len())));
+        }
+
+        for (quantity, simulated_value) in quantities.iter()
+            .zip(simulated_values.iter()) {
+
+            let experimental_value = row.try_get(
+                &quantity.experimental_column)?;
+
+            if !simulated_value.is_finite() {
+                return Err(TuasLibError::GenericStringError(
+                    format!("model returned {} for {} in row {}",
+                        simulated_value, quantity.name, row_label)));
+            }
+
+            let (error, tolerance) = match &quantity.tolerance {
+                ValidationTolerance::Relative(relative_tolerance) => {
+                    if experimental_value == 0.0 || !experimental_value.is_finite() {
+                        return Err(TuasLibError::GenericStringError(
+                            format!("relative error of {} is undefined for \
+                                experimental value {} in row {}, use an \
+                                absolute tolerance",
+                                quantity.name, experimental_value, row_label)));
+                    }
+                    ((simulated_value - experimental_value)
+                     / experimental_value.abs(), *relative_tolerance)
+                },
+                ValidationTolerance::Absolute(absolute_tolerance) => {
+                    (simulated_value - experimental_value, *absolute_tolerance)
+                },
+                ValidationTolerance::AbsoluteFromColumn(tolerance_column) => {
+                    (simulated_value - experimental_value,
+                     row.try_get(tolerance_column)?)
+                },
+            };
+
+            comparisons.push(PointComparison {
+                row_index,
+                row_label: row_label.clone(),
+                quantity_name: quantity.name.clone(),
+                experimental_value,
+                simulated_value: *simulated_value,
+                error,
+                tolerance,
+                passed: error.abs() <= tolerance,
+            });
+        }
+    }
+
+    let summaries: Vec<QuantitySummary> = quantities.iter().map(|quantity| {
+        let quantity_comparisons: Vec<&PointComparison> = comparisons.iter()
+            .filter(|comparison| comparison.quantity_name == quantity.name)
+            .collect();
+
+        let number_of_points = quantity_comparisons.len();
+        let rms_error = if number_of_points == 0 {
+            0.0
+        } else {
+            (quantity_comparisons.iter()
+             .map(|comparison| comparison.error * comparison.error)
+             .sum::<f64>() / number_of_points as f64).sqrt()
+        };
+        let max_error = quantity_comparisons.iter()
+            .fold(0.0_f64, |max, comparison| max.max(comparison.error.abs()));
+        let passed = quantity_comparisons.iter()
+            .all(|comparison| comparison.passed);
+
+        QuantitySummary {
+            quantity_name: quantity.name.clone(),
+            number_of_points,
+            rms_error,
+            max_error,
+            tolerance: quantity.tolerance.clone(),
+            passed,
+        }
+    }).collect();
+
+    Ok(ValidationReport { comparisons, summaries })
+}
@@ -0,0 +1,264 @@
+//! loads the CIET plant FMU the way an importing tool would: the
+//! FMU is unzipped, the model description is read for the model
+//! identifier, guid and value references, and the shared library is
+//! loaded and driven through its fmi2* entry points
+//!
+//! the shared library is the ciet_fmu example, which cargo test
+//! builds along with the other examples
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::fs;
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::{Path, PathBuf};
+
+use libloading::{Library, Symbol};
+
+use tuas_boussinesq_solver::fmi_export::ffi::{
+    Fmi2Boolean, Fmi2CallbackFunctions, Fmi2Status, Fmi2Type,
+    FMI2_CO_SIMULATION, FMI2_OK};
+use tuas_boussinesq_solver::fmi_export::{get_fmi2_platform,
+    get_shared_library_extension, try_write_fmu};
+use tuas_boussinesq_solver::pre_built_components::
+    ciet_three_branch_plus_dracs::ciet_plant::CIETPlant;
+
+type Fmi2Instantiate = unsafe extern "C" fn(*const c_char, Fmi2Type,
+    *const c_char, *const c_char, *const Fmi2CallbackFunctions,
+    Fmi2Boolean, Fmi2Boolean) -> *mut c_void;
+type Fmi2SetupExperiment = unsafe extern "C" fn(*mut c_void, Fmi2Boolean,
+    f64, f64, Fmi2Boolean, f64) -> Fmi2Status;
+type Fmi2Component = unsafe extern "C" fn(*mut c_void) -> Fmi2Status;
+type Fmi2FreeInstance = unsafe extern "C" fn(*mut c_void);
+type Fmi2SetReal = unsafe extern "C" fn(*mut c_void, *const u32, usize,
+    *const f64) -> Fmi2Status;
+type Fmi2GetReal = unsafe extern "C" fn(*mut c_void, *const u32, usize,
+    *mut f64) -> Fmi2Status;
+type Fmi2DoStep = unsafe extern "C" fn(*mut c_void, f64, f64,
+    Fmi2Boolean) -> Fmi2Status;
+type Fmi2GetVersion = unsafe extern "C" fn() -> *const c_char;
+
+type TuasPlantCreate = unsafe extern "C" fn() -> *mut c_void;
+type TuasPlantSet = unsafe extern "C" fn(*mut c_void, *const c_char, f64) -> c_int;
+type TuasPlantGet = unsafe extern "C" fn(*mut c_void, *const c_char, *mut f64) -> c_int;
+type TuasPlantDoStep = unsafe extern "C" fn(*mut c_void, f64) -> c_int;
+type TuasPlantFree = unsafe extern "C" fn(*mut c_void);
+
+/// the ciet_fmu example library, next to the test executable's
+/// deps folder
+fn get_ciet_fmu_library_path() -> PathBuf {
+    let test_executable = std::env::current_exe().unwrap();
+    let profile_directory = test_executable.parent().unwrap().parent().unwrap();
+    let library_path = profile_directory.join("examples").join(format!("{}ciet_fmu{}",
+        std::env::consts::DLL_PREFIX, std::env::consts::DLL_SUFFIX));
+
+    assert!(library_path.exists(),
+        "{} not found, build it with cargo build --example ciet_fmu",
+        library_path.display());
+
+    library_path
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> usize {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]]) as usize
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> usize {
+    u32::from_le_bytes([bytes[offset], bytes[offset + 1],
+        bytes[offset + 2], bytes[offset + 3]]) as usize
+}
+
+/// extracts a zip archive of stored (uncompressed) files by reading
+/// its central directory
+fn unzip_stored_archive(archive: &[u8], destination: &Path) {
+
+    let end_record = archive.len() - 22;
+    assert_eq!(read_u32(archive, end_record), 0x0605_4b50);
+    let number_of_entries = read_u16(archive, end_record + 10);
+    let mut entry = read_u32(archive, end_record + 16);
+
+    for _ in 0..number_of_entries {
+        assert_eq!(read_u32(archive, entry), 0x0201_4b50);
+        assert_eq!(read_u16(archive, entry + 10), 0, "only stored files are supported");
+        let compressed_size = read_u32(archive, entry + 20);
+        let name_length = read_u16(archive, entry + 28);
+        let extra_length = read_u16(archive, entry + 30);
+        let comment_length = read_u16(archive, entry + 32);
+        let local_header = read_u32(archive, entry + 42);
+        let name = std::str::from_utf8(&archive[entry + 46..entry + 46 + name_length]).unwrap();
+
+        assert_eq!(read_u32(archive, local_header), 0x0403_4b50);
+        let data_start = local_header + 30 + read_u16(archive, local_header + 26)
+            + read_u16(archive, local_header + 28);
+        let data = &archive[data_start..data_start + compressed_size];
+
+        let path = destination.join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, data).unwrap();
+
+        entry += 46 + name_length + extra_length + comment_length;
+    }
+}
+
+/// value of an attribute in the first element which has it
+fn get_xml_attribute<'a>(xml: &'a str, attribute: &str) -> &'a str {
+    let start = xml.find(&format!(" {}=\"", attribute)).unwrap() + attribute.len() + 3;
+    let length = xml[start..].find('"').unwrap();
+    &xml[start..start + length]
+}
+
+/// value references by variable name
+fn get_value_references(xml: &str) -> HashMap<String, u32> {
+    xml.split("<ScalarVariable").skip(1)
+        .map(|variable| (get_xml_attribute(variable, "name").to_owned(),
+            get_xml_attribute(variable, "valueReference").parse().unwrap()))
+        .collect()
+}
+
+/// the CIET plant FMU can be unzipped, loaded and run with forced
+/// circulation through the CTAH branch
+#[test]
+pub fn ciet_fmu_co_simulation(){
+
+    let working_directory = std::env::temp_dir().join(
+        format!("tuas_ciet_fmu_test_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&working_directory);
+    fs::create_dir_all(&working_directory).unwrap();
+
+    let fmu_path = working_directory.join("ciet_fmu.fmu");
+    try_write_fmu::<CIETPlant, _, _>(&fmu_path, "ciet_fmu",
+        get_ciet_fmu_library_path()).unwrap();
+
+    // import
+    let extracted = working_directory.join("extracted");
+    unzip_stored_archive(&fs::read(&fmu_path).unwrap(), &extracted);
+
+    let model_description = fs::read_to_string(
+        extracted.join("modelDescription.xml")).unwrap();
+    assert_eq!(get_xml_attribute(&model_description, "fmiVersion"), "2.0");
+    let model_identifier = get_xml_attribute(&model_description, "modelIdentifier");
+    let guid = CString::new(get_xml_attribute(&model_description, "guid")).unwrap();
+    let value_references = get_value_references(&model_description);
+
+    let binary_path = extracted.join("binaries").join(get_fmi2_platform())
+        .join(format!("{}.{}", model_identifier, get_shared_library_extension()));
+
+    unsafe {
+        let library = Library::new(&binary_path).unwrap();
+
+        let get_version: Symbol<Fmi2GetVersion> = library.get(b"fmi2GetVersion").unwrap();
+        let instantiate: Symbol<Fmi2Instantiate> = library.get(b"fmi2Instantiate").unwrap();
+        let setup_experiment: Symbol<Fmi2SetupExperiment> =
+            library.get(b"fmi2SetupExperiment").unwrap();
+        let enter_initialization_mode: Symbol<Fmi2Component> =
+            library.get(b"fmi2EnterInitializationMode").unwrap();
+        let exit_initialization_mode: Symbol<Fmi2Component> =
+            library.get(b"fmi2ExitInitializationMode").unwrap();
+        let set_real: Symbol<Fmi2SetReal> = library.get(b"fmi2SetReal").unwrap();
+        let get_real: Symbol<Fmi2GetReal> = library.get(b"fmi2GetReal").unwrap();
+        let do_step: Symbol<Fmi2DoStep> = library.get(b"fmi2DoStep").unwrap();
+        let terminate: Symbol<Fmi2Component> = library.get(b"fmi2Terminate").unwrap();
+        let free_instance: Symbol<Fmi2FreeInstance> =
+            library.get(b"fmi2FreeInstance").unwrap();
+
+        assert_eq!(std::ffi::CStr::from_ptr(get_version()).to_str().unwrap(), "2.0");
+
+        let callbacks = Fmi2CallbackFunctions {
+            logger: None,
+            allocate_memory: None,
+            free_memory: None,
+            step_finished: None,
+            component_environment: std::ptr::null_mut(),
+        };
+        let instance_name = CString::new("ciet").unwrap();
+        let resource_location = CString::new("file:///").unwrap();
+
+        let component = instantiate(instance_name.as_ptr(), FMI2_CO_SIMULATION,
+            guid.as_ptr(), resource_location.as_ptr(), &callbacks, 0, 0);
+        assert!(!component.is_null());
+
+        assert_eq!(setup_experiment(component, 0, 0.0, 0.0, 0, 0.0), FMI2_OK);
+        assert_eq!(enter_initialization_mode(component), FMI2_OK);
+
+        let inputs = [
+            ("initial_temperature", 319.15),
+            ("heater_power", 2220.0),
+            ("ctah_pump_pressure", 2400.0),
+            ("dhx_branch_blocked", 1.0),
+        ];
+        let input_references: Vec<u32> = inputs.iter()
+            .map(|(name, _)| value_references[*name]).collect();
+        let input_values: Vec<f64> = inputs.iter().map(|(_, value)| *value).collect();
+        assert_eq!(set_real(component, input_references.as_ptr(), inputs.len(),
+            input_values.as_ptr()), FMI2_OK);
+
+        assert_eq!(exit_initialization_mode(component), FMI2_OK);
+
+        // ten communication steps of 1 s, each split into 0.2 s
+        // timesteps by the FMU
+        let communication_step = 1.0;
+        for step_number in 0..10 {
+            assert_eq!(do_step(component, step_number as f64 * communication_step,
+                communication_step, 1), FMI2_OK);
+        }
+
+        let output_names = ["simulation_time", "ctah_branch_mass_flowrate",
+            "dhx_branch_mass_flowrate", "bt_11_heater_inlet", "bt_12_heater_outlet"];
+        let output_references: Vec<u32> = output_names.iter()
+            .map(|name| value_references[*name]).collect();
+        let mut outputs = vec![0.0; output_names.len()];
+        assert_eq!(get_real(component, output_references.as_ptr(), outputs.len(),
+            outputs.as_mut_ptr()), FMI2_OK);
+
+        approx::assert_relative_eq!(outputs[0], 10.0, max_relative=1e-9);
+        assert!(outputs[1].abs() > 0.01);
+        approx::assert_abs_diff_eq!(outputs[2], 0.0, epsilon=1e-9);
+        assert!(outputs[4] > outputs[3]);
+
+        // parameters cannot be changed after initialisation
+        let initial_temperature_reference = [value_references["initial_temperature"]];
+        assert_ne!(set_real(component, initial_temperature_reference.as_ptr(), 1,
+            [300.0].as_ptr()), FMI2_OK);
+
+        assert_eq!(terminate(component), FMI2_OK);
+        free_instance(component);
+    }
+
+    fs::remove_dir_all(&working_directory).unwrap();
+}
+
+/// the plain C API of the same library sets and gets variables by
+/// name
+#[test]
+pub fn ciet_plain_c_api(){
+
+    unsafe {
+        let library = Library::new(get_ciet_fmu_library_path()).unwrap();
+
+        let create: Symbol<TuasPlantCreate> = library.get(b"tuas_plant_create").unwrap();
+        let set: Symbol<TuasPlantSet> = library.get(b"tuas_plant_set").unwrap();
+        let get: Symbol<TuasPlantGet> = library.get(b"tuas_plant_get").unwrap();
+        let do_step: Symbol<TuasPlantDoStep> = library.get(b"tuas_plant_do_step").unwrap();
+        let free: Symbol<TuasPlantFree> = library.get(b"tuas_plant_free").unwrap();
+
+        let name = |text: &str| CString::new(text).unwrap();
+
+        let plant = create();
+        assert!(!plant.is_null());
+        assert_eq!(set(plant, name("initial_temperature").as_ptr(), 300.0), 0);
+        assert_eq!(set(plant, name("heater_power").as_ptr(), 1000.0), 0);
+        assert_eq!(set(plant, name("ctah_pump_pressure").as_ptr(), 2400.0), 0);
+        assert_ne!(set(plant, name("reactor_power").as_ptr(), 1000.0), 0);
+
+        assert_eq!(do_step(plant, 10.0), 0);
+
+        let mut simulation_time = 0.0;
+        assert_eq!(get(plant, name("simulation_time").as_ptr(), &mut simulation_time), 0);
+        approx::assert_relative_eq!(simulation_time, 10.0, max_relative=1e-9);
+
+        let mut ctah_branch_mass_flowrate = 0.0;
+        assert_eq!(get(plant, name("ctah_branch_mass_flowrate").as_ptr(),
+            &mut ctah_branch_mass_flowrate), 0);
+        assert!(ctah_branch_mass_flowrate.abs() > 0.01);
+
+        free(plant);
+    }
+}